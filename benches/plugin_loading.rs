@@ -0,0 +1,88 @@
+//! Benchmark of `load_plugins` against a directory of generated plugins
+//!
+//! Run with `cargo bench --bench plugin_loading`. Prints the mean load time over several
+//! rounds, set `SYNTROPY_BENCH_PLUGINS` to change the plugin count (default 24).
+
+use std::{
+    env, fs,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use syntropy::{Config, create_lua_vm, load_plugins};
+use tokio::sync::Mutex;
+
+const DEFAULT_PLUGIN_COUNT: usize = 24;
+const ROUNDS: u32 = 20;
+
+fn plugin_source(index: usize) -> String {
+    format!(
+        r#"
+return {{
+    metadata = {{
+        name = "bench-plugin-{index}",
+        version = "1.0.0",
+        description = "Generated plugin {index}",
+        platforms = {{"macos", "linux"}},
+    }},
+    tasks = {{
+        list = {{
+            description = "List numbers",
+            mode = "multi",
+            item_sources = {{
+                numbers = {{
+                    tag = "n",
+                    items = function()
+                        local items = {{}}
+                        for i = 1, 100 do items[i] = tostring(i) end
+                        return items
+                    end,
+                    execute = function(items) return table.concat(items, ","), 0 end,
+                }},
+            }},
+        }},
+        run = {{
+            description = "Run",
+            execute = function() return "done", 0 end,
+        }},
+    }},
+}}
+"#
+    )
+}
+
+fn main() {
+    let plugin_count = env::var("SYNTROPY_BENCH_PLUGINS")
+        .ok()
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(DEFAULT_PLUGIN_COUNT);
+
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let plugins_dir = temp_dir.path().join("plugins");
+    for index in 0..plugin_count {
+        let plugin_dir = plugins_dir.join(format!("bench-plugin-{}", index));
+        fs::create_dir_all(&plugin_dir).expect("Failed to create plugin dir");
+        fs::write(plugin_dir.join("plugin.lua"), plugin_source(index))
+            .expect("Failed to write plugin");
+    }
+
+    let config = Config::default();
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let lua = Arc::new(Mutex::new(
+            create_lua_vm().expect("Failed to create Lua VM"),
+        ));
+        let start = Instant::now();
+        let plugins =
+            load_plugins(std::slice::from_ref(&plugins_dir), &config, lua).expect("Loading failed");
+        total += start.elapsed();
+        assert_eq!(plugins.len(), plugin_count);
+    }
+
+    println!(
+        "load_plugins: {} plugins, mean {:?} over {} rounds",
+        plugin_count,
+        total / ROUNDS,
+        ROUNDS
+    );
+}
@@ -14,29 +14,42 @@
 ---@field description string? Override description
 ---@field platforms string[]? Override platforms
 
+---@class Item
+---@field display string Shown in the list, matched by the search and used for selection
+---@field value? string Optional: Passed to execute and preview instead of display
+---@field preview? string Optional: Shown as the preview instead of calling preview
+
+---@class Preview
+---@field content string Preview text
+---@field syntax? string Optional: Language name or file extension the TUI highlights content as, e.g. "rust" or "json"
+
 ---@class ItemSource
 ---@field tag string Short tag used for UI display when multiple item sources exist
----@field items fun(): string[] Returns the list of items for this item source
+---@field color? string Optional: Color of the tag in the item list, a color name or hex value
+---@field items fun(): (string|Item)[] Returns the list of items for this item source
 ---@field preselected_items? fun(): string[] Optional: Returns the list of preselected items
----@field preview? fun(item: string): string Optional: Returns preview content for the given item
----@field execute? fun(items: string[]): string, integer Optional: Executes the task for the given items, returns output and exit code
+---@field preview? fun(item: string): string|Preview Optional: Returns preview content for the given item
+---@field execute? fun(items: string[]): string, integer, table<string, integer>? Optional: Executes the task for the given items, returns output, exit code and optionally a map of item to exit code
 
----@alias Mode "multi"|"none"
+---@alias Mode "multi"|"single"|"background"
 
 ---@class Task
 ---@field name string Display name for this task
 ---@field description string Description of what this task does (displayed in preview pane)
----@field mode? Mode Optional: Selection mode - "multi" or "none" (default)
+---@field icon? string Optional: Single-cell icon shown before the task in the task list
+---@field mode? Mode Optional: Selection mode - "multi", "single" (default) or "background"
 ---@field exit_on_execute? boolean Optional: Whether to exit after executing this task
 ---@field execution_confirmation_message? string Optional: If set, shows a confirmation modal with this message before executing. User must confirm to proceed.
----@field suppress_success_notification? boolean Optional: If true, suppresses the success modal after execution (errors are still shown). Useful with invoke_editor and invoke_tui. Default: false.
+---@field suppress_success_notification? boolean Optional: If true, suppresses the success toast after execution (errors are still shown). Useful with invoke_editor and invoke_tui. Default: false.
+---@field order? integer Optional: Position in the task list. Tasks are sorted by order, then by task key; tasks without order come last.
+---@field group? string Optional: Tasks sharing a group are listed together under a header in the task list
 ---@field item_polling_interval? integer Optional: Milliseconds between item cache refreshes (0 = no polling, default: 0)
 ---@field preview_polling_interval? integer Optional: Milliseconds between preview cache refreshes (0 = no polling, default: 0)
 ---@field item_sources table<string, ItemSource>? Map of item source key to ItemSource. If no item_sources are declared this task is considered as executable only and requires task level `execute` function to be declared.
 ---@field pre_run? fun() Optional: Runs before items are fetched
 ---@field post_run? fun() Optional: Runs after execution completes
----@field preview? fun(item: string): string Optional: Returns preview content for the given item (task-level fallback)
----@field execute? fun(items: string[]): string, integer Optional: Executes the task for the given items (task-level fallback), returns output and exit code. This function needs to be declared if no `item_sources` are declared for this task.
+---@field preview? fun(item: string): string|Preview Optional: Returns preview content for the given item (task-level fallback)
+---@field execute? fun(items: string[]): string, integer, table<string, integer>? Optional: Executes the task for the given items (task-level fallback), returns output, exit code and optionally a map of item to exit code. This function needs to be declared if no `item_sources` are declared for this task.
 
 ---@class PluginDefinition
 ---@field metadata Metadata Plugin metadata
@@ -5,10 +5,20 @@
 ---@field invoke_tui fun(command: string, args: string[]): integer Launch external TUI app with full terminal control, returns exit code
 ---@field invoke_editor fun(path: string): integer Open file in $EDITOR (or $VISUAL, or vim), returns exit code
 ---@field expand_path fun(path: string): string Expand ~, env vars, and ./ (plugin-relative) in paths
+---@field glob fun(pattern: string): string[] List paths matching a glob pattern (supports **), sorted
+---@field git_status fun(path: string): {path: string, status: string}[] Changed and untracked files of a git repository
+---@field git_branches fun(path: string): {name: string, current: boolean}[] Local branches of a git repository
+---@field git_checkout fun(path: string, branch: string): boolean, string? Check out a local branch, false and a message on failure
+---@field plugin_dir fun(): string Directory of the running plugin
+---@field plugin_name fun(): string Name of the running plugin
+---@field plugins fun(): {name: string, version: string, icon: string, description: string, platforms: string[], tasks: string[]}[] Metadata and task keys of the loaded plugins
+---@field confirm fun(message: string): boolean Ask a yes/no question, returns the answer (false when stdin is not a TTY)
+---@field prompt fun(message: string, default?: string): string Ask for a line of text, returns the answer or default
 ---
 --- **syntropy.shell(cmd):**
 --- Executes a shell command and returns the output and exit code.
 --- - Async function (blocks until command completes)
+--- - Other plugin calls can use the Lua runtime while the command runs
 --- - Uses `sh -c` to support pipes, redirects, and other shell features
 --- - Returns: (output: string, exit_code: integer)
 --- - Output combines stdout and stderr
@@ -32,7 +42,8 @@
 --- **syntropy.invoke_editor(path):**
 --- Opens a file in the user's configured editor.
 --- - Async function (blocks until the editor exits)
---- - Convenience wrapper that uses $EDITOR (falls back to $VISUAL, then vim)
+--- - Convenience wrapper that uses $EDITOR (falls back to $VISUAL, the config's editor and
+---   editor_fallbacks, then vim; editor_overrides_env puts the config first)
 --- - In TUI mode: suspends syntropy's TUI, gives full terminal to editor, then restores syntropy's TUI
 --- - In CLI mode: runs editor directly with inherited stdin/stdout/stderr
 --- - The plugin execution pauses and resumes exactly where it left off (all state preserved)
@@ -43,6 +54,43 @@
 ---   `local code = syntropy.invoke_editor("/tmp/notes.txt")`
 ---   `local code = syntropy.invoke_editor(syntropy.expand_path("./config.json"))`
 ---
+--- **syntropy.confirm(message):**
+--- Asks the user a yes/no question.
+--- - Async function (blocks until the user answers)
+--- - In TUI mode: shows a modal dialog (y or confirm key = yes, n or back key = no)
+--- - In CLI mode: prints "message [y/N]" to stderr and reads stdin ("y"/"yes" = true)
+--- - When stdin is not a TTY: returns false without prompting
+--- - Returns: answer: boolean
+--- - Example: `if not syntropy.confirm("Delete files?") then return "Cancelled", 0 end`
+---
+--- **syntropy.prompt(message, default):**
+--- Asks the user for a line of text.
+--- - Async function (blocks until the user answers)
+--- - In TUI mode: shows a modal dialog with a text field (confirm key submits, back key returns default)
+--- - In CLI mode: prints "message [default]" to stderr and reads stdin (empty line = default)
+--- - When stdin is not a TTY: returns default without prompting
+--- - Parameters:
+---   - message: string - Question shown to the user
+---   - default: string? - Fallback answer (defaults to "")
+--- - Returns: answer: string
+--- - Example: `local name = syntropy.prompt("Branch name:", "main")`
+---
+--- **syntropy.sleep(ms):**
+--- Waits ms milliseconds, e.g. between polls or to rate-limit requests.
+--- - Async function, awaits a timer instead of blocking a thread like `sleep` in a shell
+--- - Other plugin calls can use the Lua runtime while the plugin function sleeps
+--- - Raises an error for negative values and values above one hour (3600000)
+--- - Example: `syntropy.sleep(500)`
+---
+--- **syntropy.log(message, level):**
+--- Reports progress or problems without corrupting the TUI or mixing with a task's output.
+--- - In TUI mode: shown in the status bar for a few seconds (as a toast without status bar)
+--- - In CLI mode: printed to stderr as "[level] message"
+--- - Parameters:
+---   - message: string - Text to report
+---   - level: string? - "info" (default), "warn" or "error"
+--- - Example: `syntropy.log("Remote is slow, retrying", "warn")`
+---
 --- **syntropy.expand_path(path):**
 --- Expands paths with special handling for ~, environment variables, and plugin-relative paths.
 --- - Synchronous function (non-blocking)
@@ -55,5 +103,43 @@
 --- - Examples:
 ---   `local config = syntropy.expand_path("~/.config/app/config.toml")`
 ---   `local data = syntropy.expand_path("./plugin-data.json")  -- plugin's directory`
+---
+--- **syntropy.glob(pattern):**
+--- Lists paths matching a glob pattern.
+--- - Synchronous function (non-blocking)
+--- - ~ and environment variables are expanded first
+--- - Supports `*`, `?`, `[...]` and recursive `**`
+--- - Returns matching paths sorted, or an empty table when nothing matches
+--- - Raises an error for invalid patterns
+--- - Example: `local files = syntropy.glob("~/notes/**/*.md")`
+---
+--- **syntropy.git_status(path):**
+--- Lists the changed and untracked files of the repository containing path.
+--- - Synchronous function, runs `git status`
+--- - ~ and environment variables are expanded first
+--- - Entries are `{path, status}`, path relative to the repository root
+--- - status is "modified", "added", "deleted" or "untracked"
+--- - Raises an error when path is not inside a git repository
+--- - Example: `for _, e in ipairs(syntropy.git_status("~/notes")) do ... end`
+---
+--- **syntropy.git_branches(path) / syntropy.git_checkout(path, branch):**
+--- List the local branches of a repository and switch between them.
+--- - Synchronous functions, run `git`
+--- - git_branches returns `{name, current}` sorted by name, no branch is current on a detached HEAD
+--- - git_checkout returns true, or false and a message for missing branches and refused checkouts
+--- - Example: `local ok, err = syntropy.git_checkout("~/notes", "main")`
+---
+--- **syntropy.plugin_dir() / syntropy.plugin_name():**
+--- Return the directory and name of the plugin whose function is running.
+--- - Synchronous functions (non-blocking)
+--- - Raise an error when called at module level, outside plugin execution
+--- - Example: `local icon = syntropy.plugin_dir() .. "/assets/icon.png"`
+---
+--- **syntropy.plugins():**
+--- Returns the loaded plugins as `{name, version, icon, description, platforms, tasks}` tables.
+--- - Synchronous function (non-blocking)
+--- - `tasks` holds task keys, the plugins' functions are not exposed
+--- - Plugins that failed to load are left out
+--- - Example: `for _, p in ipairs(syntropy.plugins()) do print(p.name, #p.tasks) end`
 
 syntropy = {}
@@ -4,11 +4,14 @@ pub use run::run;
 
 use std::sync::Arc;
 
+use anyhow::Result;
 use mlua::Lua;
 use tokio::sync::Mutex;
 
 use crate::{
+    cli::execute::{find_task_by_alias, match_name, select_items},
     configs::Config,
+    execution::{ExecutionError, ExecutionResult, run_execute_pipeline},
     plugins::{Plugin, Task},
 };
 
@@ -37,4 +40,105 @@ impl App {
         self.get_plugin(plugin_idx)
             .and_then(|plugin| plugin.tasks.get(task_key))
     }
+
+    /// Iterates over loaded plugins in load order
+    pub fn plugins_iter(&self) -> impl Iterator<Item = &Plugin> {
+        self.plugins.iter()
+    }
+
+    /// Iterates over every task of every plugin as `(plugin_name, task_key, task)`.
+    /// Plugins are visited in load order, tasks in `Plugin::tasks_sorted` order.
+    pub fn tasks_iter(&self) -> impl Iterator<Item = (&str, &str, &Arc<Task>)> {
+        self.plugins_iter().flat_map(|plugin| {
+            plugin
+                .tasks_sorted()
+                .map(|(task_key, task)| (plugin.metadata.name.as_str(), task_key, task))
+        })
+    }
+}
+
+impl App {
+    /// Finds a task by plugin name and task key, returned as `(plugin_name, task_key, task)`
+    ///
+    /// Names fall back to a unique case-insensitive match, see [`match_name`], and the task
+    /// may also be given by one of its aliases. An unknown name is an [`ExecutionError::PluginNotFound`]
+    /// or [`ExecutionError::TaskNotFound`] suggesting close names.
+    pub fn find_task(&self, plugin: &str, task: &str) -> Result<(&str, &str, &Arc<Task>)> {
+        let (plugin_name, found_plugin) = match_name(
+            "plugin",
+            plugin,
+            self.plugins.iter().map(|p| (p.metadata.name.as_str(), p)),
+        )?
+        .ok_or_else(|| ExecutionError::PluginNotFound {
+            plugin: plugin.to_string(),
+            available: self
+                .plugins
+                .iter()
+                .map(|p| p.metadata.name.clone())
+                .collect(),
+        })?;
+
+        let task_match = match find_task_by_alias(&found_plugin.tasks, task) {
+            Some(task_match) => Some(task_match),
+            None => match_name(
+                "task",
+                task,
+                found_plugin
+                    .tasks
+                    .iter()
+                    .map(|(key, task)| (key.as_str(), task)),
+            )?,
+        };
+        let (task_key, found_task) = task_match.ok_or_else(|| {
+            let mut available: Vec<_> = found_plugin.tasks.keys().cloned().collect();
+            // Sort task names alphabetically (case-insensitive) for consistent error messages
+            available.sort_by_key(|a| a.to_lowercase());
+            ExecutionError::TaskNotFound {
+                plugin: plugin_name.to_string(),
+                task: task.to_string(),
+                available,
+            }
+        })?;
+
+        Ok((plugin_name, task_key, found_task))
+    }
+
+    /// Runs a task like `syntropy execute --plugin <plugin> --task <task> --foreground`
+    ///
+    /// Plugin and task are resolved with [`App::find_task`]. `items` are matched against the
+    /// items of the task like `--items`, `None` picks them by task mode. Background tasks run
+    /// to completion before this returns.
+    ///
+    /// Returns [`ExecutionResult::Output`] once `execute` ran, whatever its exit code, and
+    /// [`ExecutionResult::Error`] when resolving the task or its items failed or `execute`
+    /// raised an error.
+    pub async fn run_task(
+        &self,
+        plugin: &str,
+        task: &str,
+        items: Option<Vec<String>>,
+    ) -> ExecutionResult {
+        let (task, selected_items) = match self.resolve_task_items(plugin, task, items).await {
+            Ok(resolved) => resolved,
+            Err(e) => return ExecutionResult::Error(format!("{:#}", e)),
+        };
+
+        let output =
+            run_execute_pipeline(self.lua_runtime.clone(), &task, &selected_items, None).await;
+        ExecutionResult::from_execute_output(output)
+    }
+
+    // Task to run and the items it executes on, see `run_task`
+    async fn resolve_task_items(
+        &self,
+        plugin: &str,
+        task: &str,
+        items: Option<Vec<String>>,
+    ) -> Result<(Arc<Task>, Vec<String>)> {
+        let (_, _, task) = self.find_task(plugin, task)?;
+        let items = items.unwrap_or_default();
+        let items_arg: Vec<&str> = items.iter().map(String::as_str).collect();
+        let (selected_items, _) = select_items(&self.lua_runtime, task, &items_arg, false).await?;
+        Ok((Arc::clone(task), selected_items))
+    }
 }
@@ -1,28 +1,58 @@
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, bail, ensure};
 use clap::{CommandFactory, Parser};
-use std::{path::PathBuf, process::exit, sync::Arc};
-use tokio::{runtime::Builder, sync::Mutex};
+use std::{
+    env,
+    path::PathBuf,
+    process::exit,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+use tokio::{
+    runtime::{Builder, Runtime},
+    sync::Mutex,
+};
+use tracing::warn;
 
 use crate::{
     Config,
     app::App,
     cli::{
-        Args, Commands,
-        completions::generate_completions,
-        execute::execute_task_cli,
+        Args, Commands, ConfigArgs, ConfigCommand, OutputFormat, SandboxMode, TestArgs,
+        ValidateArgs,
+        completions::{generate_completions, generate_dynamic_completions},
+        config::config_init_cli,
+        doctor::doctor_cli,
+        execute::{apply_env_overrides, execute_task_cli, print_json_error},
         handle_plugins_command,
         init::create_plugin_scaffold,
         list_cli,
-        validate::{validate_config_cli, validate_plugin_cli},
+        logging::init_logging,
+        sync_due_plugins,
+        test::test_plugins_cli,
+        validate::{
+            plugin_watch_paths, validate_config_cli, validate_plugin_cli, validate_plugin_json,
+        },
+        watch::watch_lua_files,
     },
     configs::{
-        expand_path, find_config_file, get_default_config_dir, load_config, resolve_plugin_paths,
-        validate_config,
+        PluginWatcher, expand_path, find_config_file, find_workspace_config_file,
+        get_default_config_dir, get_system_config_dirs, load_config, load_config_with_workspace,
+        resolve_plugin_paths, validate_config,
+    },
+    execution::{
+        EXIT_FAILURE, EXIT_SIGINT, EXIT_SIGTERM, EXIT_SUCCESS, ExecutionError,
+        set_warn_on_exit_clamp,
+    },
+    lua::{
+        create_lua_vm_with_sandbox,
+        editor::{EditorSettings, set_editor_settings},
+        load_global_hooks, set_plugin_configs,
     },
-    execution::EXIT_SIGINT,
-    lua::create_lua_vm,
-    plugins::load_plugins,
-    signal::Cancellation,
+    plugins::{load_plugins, plugin_schema},
+    signal::{CHILD_TERMINATION_GRACE, Cancellation, terminate_children},
     tui::TuiApp,
 };
 
@@ -33,7 +63,7 @@ use crate::{
 /// validation pipeline before initializing the application environment:
 ///
 /// 1. **Parse CLI arguments**: Uses clap to parse and validate command-line arguments
-/// 2. **Handle subcommands**: Processes `init`, `completions`, `validate`, and `plugins` commands (exits early if present)
+/// 2. **Handle subcommands**: Processes `init`, `completions`, `validate`, `config`, `test`, `doctor`, and `plugins` commands (exits early if present)
 /// 3. **Setup and run**: Initializes application environment and runs TUI or `execute` subcommand
 ///
 /// # Execution Flow
@@ -64,6 +94,16 @@ use crate::{
 pub fn run() -> Result<()> {
     let cli_args = Args::parse();
 
+    init_logging(
+        cli_args.log_level,
+        cli_args.log_file.clone(),
+        cli_args.command.is_none(),
+    )?;
+
+    if cli_args.tui && cli_args.command.is_some() {
+        bail!("--tui cannot be combined with a subcommand");
+    }
+
     if handle_cli_commands(&cli_args.command, &cli_args)? {
         return Ok(());
     }
@@ -79,66 +119,144 @@ pub fn run() -> Result<()> {
 fn setup_the_environment_and_run(cli_args: &Args) -> Result<()> {
     let (config, _config_path) = handle_config(cli_args)?;
 
-    let plugin_paths = resolve_plugin_paths().context("Failed to resolve plugin paths")?;
+    set_editor_settings(EditorSettings::from(&config));
+    set_warn_on_exit_clamp(config.warn_on_exit_clamp);
+
+    if let Some(Commands::Execute(execute_args)) = &cli_args.command {
+        apply_env_overrides(execute_args)?;
+    }
 
-    let lua_runtime = Arc::new(Mutex::new(create_lua_vm()?));
+    // Only the interactive TUI pulls due plugins, `list`, `execute` and the `--foreground`
+    // re-exec load what is installed without touching the network.
+    if cli_args.command.is_none() {
+        sync_due_plugins(&config);
+    }
+    let plugin_paths = handle_plugin_paths(cli_args, &config)?;
+
+    let lua = create_lua_vm_with_sandbox(cli_args.sandbox == SandboxMode::On)?;
+    set_plugin_configs(&lua, config.plugin_config.clone());
+    load_global_hooks(
+        &lua,
+        config.global_pre_run.as_deref(),
+        config.global_post_run.as_deref(),
+    )?;
+    let lua_runtime = Arc::new(Mutex::new(lua));
 
     let plugins = load_plugins(&plugin_paths, &config, Arc::clone(&lua_runtime))
         .context("Failed to load plugins")?;
 
     let app = App::new(config, plugins, lua_runtime);
 
-    if let Some(Commands::List(list_args)) = &cli_args.command {
-        return list_cli(&app, list_args);
-    }
-
     let runtime = Builder::new_multi_thread()
         .enable_all()
         .build()
         .context("Failed to create tokio runtime")?;
 
-    if let Some(Commands::Execute(execute_args)) = &cli_args.command {
-        let cancellation = {
-            let cancel = Cancellation::new();
-            let cancel_clone = cancel.clone();
-
-            runtime.spawn(async move {
-                loop {
-                    match tokio::signal::ctrl_c().await {
-                        Ok(()) => {
-                            cancel_clone.request_cancel();
-                            if cancel_clone.should_force_quit() {
-                                std::process::exit(EXIT_SIGINT);
-                            } else {
-                                eprintln!("^C\nCancelling task... running cleanup");
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Error installing signal handler: {}", e);
-                            break;
-                        }
-                    }
-                }
-            });
+    if let Some(Commands::List(list_args)) = &cli_args.command {
+        return list_cli(&app, list_args, &runtime);
+    }
 
-            Some(cancel)
-        };
+    if let Some(Commands::Execute(execute_args)) = &cli_args.command {
+        let cancel = Cancellation::new();
+        let terminated = spawn_signal_handlers(&runtime, cancel.clone());
+        let cancellation = Some(cancel);
 
         let exit_code =
-            runtime.block_on(execute_task_cli(app, execute_args, cancellation.as_ref()))?;
+            match runtime.block_on(execute_task_cli(app, execute_args, cancellation.as_ref())) {
+                Ok(exit_code) => exit_code,
+                Err(error) => {
+                    if execute_args.format == OutputFormat::Json {
+                        print_json_error(&error);
+                    } else {
+                        eprintln!("Error: {:?}", error);
+                    }
+                    exit(
+                        ExecutionError::find(&error)
+                            .map_or(EXIT_FAILURE, ExecutionError::exit_code),
+                    );
+                }
+            };
+        if terminated.load(Ordering::SeqCst) {
+            exit(EXIT_SIGTERM);
+        }
         if exit_code != 0 {
             exit(exit_code);
         }
     } else {
         let mut tui_app = TuiApp::new(app, runtime.handle().clone())
             .context("Failed to initialize TUI application")?;
-        tui_app.run()?;
+        if !cli_args.no_watch {
+            // Without a watcher the TUI still works, only without reloading
+            match PluginWatcher::new(&plugin_paths) {
+                Ok((watcher, changes)) => {
+                    tui_app = tui_app.with_plugin_reload(watcher, changes, plugin_paths);
+                }
+                Err(e) => warn!(error = %format!("{:#}", e), "Failed to watch plugin directories"),
+            }
+        }
+        let result = tui_app.run();
+        // Tasks still running when the TUI quits must not leave their commands behind
+        runtime.block_on(terminate_children(CHILD_TERMINATION_GRACE));
+        result?;
     }
 
     Ok(())
 }
 
-// Loads and validates the config file using XDG-compliant path resolution.
+// Ctrl+C cancels the task so post_run can clean up and terminates its running commands,
+// a second Ctrl+C quits immediately. SIGTERM does the same as Ctrl+C but quits once the
+// commands are gone, the returned flag is set when it was received.
+fn spawn_signal_handlers(runtime: &Runtime, cancel: Cancellation) -> Arc<AtomicBool> {
+    let terminated = Arc::new(AtomicBool::new(false));
+
+    let sigint_cancel = cancel.clone();
+    runtime.spawn(async move {
+        loop {
+            match tokio::signal::ctrl_c().await {
+                Ok(()) => {
+                    sigint_cancel.request_cancel();
+                    if sigint_cancel.should_force_quit() {
+                        terminate_children(Duration::ZERO).await;
+                        exit(EXIT_SIGINT);
+                    } else {
+                        eprintln!("^C\nCancelling task... running cleanup");
+                        tokio::spawn(terminate_children(CHILD_TERMINATION_GRACE));
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error installing signal handler: {}", e);
+                    break;
+                }
+            }
+        }
+    });
+
+    #[cfg(unix)]
+    runtime.spawn({
+        let terminated = terminated.clone();
+        async move {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            match signal(SignalKind::terminate()) {
+                Ok(mut sigterm) => {
+                    if sigterm.recv().await.is_some() {
+                        // Set first, the task may finish as soon as its commands are gone
+                        terminated.store(true, Ordering::SeqCst);
+                        cancel.request_cancel();
+                        terminate_children(CHILD_TERMINATION_GRACE).await;
+                        exit(EXIT_SIGTERM);
+                    }
+                }
+                Err(e) => eprintln!("Error installing SIGTERM handler: {}", e),
+            }
+        }
+    });
+
+    terminated
+}
+
+// Loads and validates the config file using XDG-compliant path resolution, with the
+// workspace config of the current directory merged on top unless --no-workspace-config is set.
 // Applies CLI overrides: --plugin sets default_plugin, --task sets default_task,
 // and boolean flags override their respective config values.
 // Returns error if --task is specified without --plugin.
@@ -153,12 +271,28 @@ fn handle_config(cli_args: &Args) -> Result<(Config, PathBuf)> {
     let config_path_opt =
         find_config_file(expanded_config).context("Failed to find config file")?;
 
-    let (mut config, config_path) = match config_path_opt {
-        Some(path) => {
+    let workspace_config_path = if cli_args.no_workspace_config {
+        None
+    } else {
+        env::current_dir()
+            .ok()
+            .and_then(|dir| find_workspace_config_file(&dir))
+    };
+
+    let (mut config, config_path) = match (config_path_opt, workspace_config_path) {
+        (config_path, Some(workspace_path)) => {
+            let config = load_config_with_workspace(config_path.as_deref(), &workspace_path)
+                .context("Failed to load workspace config file")?;
+            (
+                config,
+                config_path.unwrap_or_else(|| PathBuf::from("<no config file>")),
+            )
+        }
+        (Some(path), None) => {
             let config = load_config(path.clone()).context("Failed to load config file")?;
             (config, path)
         }
-        None => {
+        (None, None) => {
             // No config file found - use defaults (expected for new users)
             (Config::default(), PathBuf::from("<no config file>"))
         }
@@ -200,6 +334,38 @@ fn handle_config(cli_args: &Args) -> Result<(Config, PathBuf)> {
     Ok((config, config_path))
 }
 
+// Resolves the plugin directories of this invocation. --plugin-dir replaces the configured
+// and default directories, or is searched after the configured ones with --plugin-dir-append.
+// Returns error if a --plugin-dir directory doesn't exist.
+fn handle_plugin_paths(cli_args: &Args, config: &Config) -> Result<Vec<PathBuf>> {
+    let plugin_dirs = cli_args
+        .plugin_dir
+        .iter()
+        .cloned()
+        .map(expand_path)
+        .collect::<Result<Vec<_>>>()
+        .context("Failed to expand plugin directory path")?;
+    for plugin_dir in &plugin_dirs {
+        ensure!(
+            plugin_dir.is_dir(),
+            "Plugin directory {} does not exist",
+            plugin_dir.display()
+        );
+    }
+
+    if plugin_dirs.is_empty() || cli_args.plugin_dir_append {
+        let plugin_paths: Vec<PathBuf> = config
+            .plugin_paths
+            .iter()
+            .cloned()
+            .chain(plugin_dirs)
+            .collect();
+        resolve_plugin_paths(&plugin_paths).context("Failed to resolve plugin paths")
+    } else {
+        Ok(plugin_dirs)
+    }
+}
+
 // Handles subcommands that exit immediately without launching TUI.
 // Returns Ok(false) if no subcommand or if subcommand needs environment (Execute)
 // Returns Ok(true) if subcommand was handled and app should exit
@@ -216,22 +382,44 @@ fn handle_cli_commands(command: &Option<Commands>, cli_args: &Args) -> Result<bo
             create_plugin_scaffold()?;
             Ok(true)
         }
-        Commands::Completions { shell } => {
-            generate_completions(*shell, &mut Args::command());
+        Commands::Completions { shell, dynamic } => {
+            if *dynamic {
+                generate_dynamic_completions(*shell, &mut Args::command())?;
+            } else {
+                generate_completions(*shell, &mut Args::command());
+            }
             Ok(true)
         }
-        Commands::Validate { plugin, config } => {
+        Commands::Validate(ValidateArgs {
+            plugin,
+            config,
+            format,
+            watch,
+        }) => {
             if let Some(plugin_path) = plugin {
-                validate_plugin_cli(plugin_path.clone())?;
+                if *watch {
+                    let watch_paths = plugin_watch_paths(plugin_path.clone())?;
+                    watch_lua_files(&watch_paths, || validate_plugin_cli(plugin_path.clone()))?;
+                } else if *format == OutputFormat::Json {
+                    if !validate_plugin_json(plugin_path.clone())? {
+                        exit(EXIT_FAILURE);
+                    }
+                } else {
+                    validate_plugin_cli(plugin_path.clone())?;
+                }
             } else if let Some(config_paths) = config {
                 let config_path = if config_paths.is_empty() {
                     match find_config_file(cli_args.config.clone())? {
                         Some(path) => path,
                         None => {
-                            let xdg_path = get_default_config_dir()?.join("syntropy.toml");
+                            let searched: Vec<String> = [get_default_config_dir()?]
+                                .into_iter()
+                                .chain(get_system_config_dirs())
+                                .map(|dir| format!("  - {:?}", dir.join("syntropy.toml")))
+                                .collect();
                             bail!(
-                                "No config file found to validate. Searched:\n  - {:?}\n  - ./syntropy.toml",
-                                xdg_path
+                                "No config file found to validate. Searched:\n{}\n  - ./syntropy.toml",
+                                searched.join("\n")
                             );
                         }
                     }
@@ -244,9 +432,39 @@ fn handle_cli_commands(command: &Option<Commands>, cli_args: &Args) -> Result<bo
             }
             Ok(true)
         }
+        Commands::Config(ConfigArgs {
+            command: ConfigCommand::Init { output, force },
+        }) => {
+            config_init_cli(output.clone(), *force)?;
+            Ok(true)
+        }
+        Commands::Test(TestArgs { plugin }) => {
+            let (config, _config_path) = handle_config(cli_args)?;
+            let plugin_paths = handle_plugin_paths(cli_args, &config)?;
+            let summary = test_plugins_cli(
+                &plugin_paths,
+                plugin.as_deref(),
+                cli_args.sandbox == SandboxMode::On,
+            )?;
+            if summary.failed > 0 {
+                exit(EXIT_FAILURE);
+            }
+            Ok(true)
+        }
+        Commands::Schema => {
+            println!("{}", serde_json::to_string_pretty(&plugin_schema())?);
+            Ok(true)
+        }
+        Commands::Doctor { json } => {
+            doctor_cli(cli_args.config.clone(), *json)?;
+            Ok(true)
+        }
         Commands::Plugins(plugin_params) => {
             let (config, _config_path) = handle_config(cli_args)?;
-            handle_plugins_command(plugin_params, config)?;
+            let exit_code = handle_plugins_command(plugin_params, config)?;
+            if exit_code != EXIT_SUCCESS {
+                exit(exit_code);
+            }
             Ok(true)
         }
     }
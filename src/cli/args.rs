@@ -1,4 +1,4 @@
-use clap::{Args as ClapArgs, Parser, Subcommand};
+use clap::{ArgGroup, Args as ClapArgs, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 use std::path::PathBuf;
 
@@ -10,6 +10,11 @@ pub struct Args {
     #[arg(long, global = true, value_name = "PATH")]
     pub config: Option<PathBuf>,
 
+    /// Open the TUI, the default without a subcommand. Combine with --plugin and --task to
+    /// open a task directly
+    #[arg(long)]
+    pub tui: bool,
+
     /// Navigate to specific plugin (without executing)
     #[arg(long, value_name = "NAME")]
     pub plugin: Option<String>,
@@ -34,6 +39,36 @@ pub struct Args {
     #[arg(long, value_name = "BOOL")]
     pub exit_on_execute: Option<bool>,
 
+    /// Don't reload the plugins in the TUI when their .lua files change
+    #[arg(long)]
+    pub no_watch: bool,
+
+    /// Load plugins from this directory instead of the configured plugin directories
+    /// (repeatable)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub plugin_dir: Vec<PathBuf>,
+
+    /// Search the --plugin-dir directories in addition to the configured plugin directories
+    #[arg(long, global = true, requires = "plugin_dir")]
+    pub plugin_dir_append: bool,
+
+    /// Ignore `.syntropy.toml` workspace configs in the current and parent directories
+    #[arg(long, global = true)]
+    pub no_workspace_config: bool,
+
+    /// Log verbosity (default: off, or info when --log-file is set)
+    #[arg(long, global = true, value_enum, value_name = "LEVEL")]
+    pub log_level: Option<LogLevel>,
+
+    /// Write logs to this file. Without it the TUI logs to the data directory, commands to stderr
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Restrict plugins to the Lua sandbox (no os.exit, os.execute, io.popen, or require
+    /// outside plugin directories). `off` trusts every installed plugin with full access
+    #[arg(long, global = true, value_enum, value_name = "MODE", default_value_t = SandboxMode::On)]
+    pub sandbox: SandboxMode,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -41,35 +76,111 @@ pub struct Args {
 #[derive(ClapArgs, Debug)]
 pub struct ExecuteArgs {
     /// Plugin name
-    #[arg(long, value_name = "NAME")]
-    pub plugin: String,
+    #[arg(long, value_name = "NAME", required_unless_present = "plan")]
+    pub plugin: Option<String>,
 
     /// Task name
-    #[arg(long, value_name = "NAME")]
-    pub task: String,
+    #[arg(long, value_name = "NAME", required_unless_present = "plan")]
+    pub task: Option<String>,
+
+    /// Run the plugin, task and items recorded in a plan file (dumped from the TUI)
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["plugin", "task", "items", "source", "produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews", "preview"])]
+    pub plan: Option<PathBuf>,
 
     /// Specify specific items to execute on (comma-separated)
-    #[arg(long, value_name = "NAMES", conflicts_with_all = ["produce_items", "produce_preselected_items", "produce_preselection_matches"])]
+    #[arg(long, value_name = "NAMES", conflicts_with_all = ["produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews"])]
     pub items: Option<String>,
 
     /// Output items list (for debugging/scripting)
-    #[arg(long, conflicts_with_all = ["items", "produce_preselected_items", "produce_preselection_matches"])]
+    #[arg(long, group = "produce", conflicts_with_all = ["items", "produce_preselected_items", "produce_preselection_matches", "produce_previews"])]
     pub produce_items: bool,
 
     /// Output preselected items list
-    #[arg(long, conflicts_with_all = ["items", "produce_items", "produce_preselection_matches"])]
+    #[arg(long, group = "produce", conflicts_with_all = ["items", "produce_items", "produce_preselection_matches", "produce_previews"])]
     pub produce_preselected_items: bool,
 
     /// Output items matching preselection
-    #[arg(long, conflicts_with_all = ["items", "produce_items", "produce_preselected_items"])]
+    #[arg(long, group = "produce", conflicts_with_all = ["items", "produce_items", "produce_preselected_items", "produce_previews"])]
     pub produce_preselection_matches: bool,
 
+    /// Output the preview of every item, each after a `==> item <==` header
+    #[arg(long, group = "produce", conflicts_with_all = ["items", "produce_items", "produce_preselected_items", "produce_preselection_matches"])]
+    pub produce_previews: bool,
+
     /// Generate preview for an item
-    #[arg(long, conflicts_with_all = ["items", "produce_items", "produce_preselected_items", "produce_preselection_matches"])]
+    #[arg(long, conflicts_with_all = ["items", "produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews"])]
     pub preview: Option<String>,
+
+    /// Match --items and --preview names with exact case only, without the case-insensitive fallback
+    #[arg(long)]
+    pub case_sensitive: bool,
+
+    /// Set an environment variable for this run (repeatable)
+    #[arg(long = "env", value_name = "KEY=VALUE")]
+    pub env: Vec<String>,
+
+    /// Load environment variables from a .env file, --env values take precedence
+    #[arg(long, value_name = "PATH")]
+    pub env_file: Option<PathBuf>,
+
+    /// Only use the item source with this tag of a multi-source task
+    #[arg(long, value_name = "TAG")]
+    pub source: Option<String>,
+
+    /// Run a mode="background" task attached to the terminal instead of detaching it
+    #[arg(long)]
+    pub foreground: bool,
+
+    /// Sort --produce-* output (default keeps the order items were produced in)
+    #[arg(long, value_enum, default_value_t = ItemSort::None, requires = "produce")]
+    pub sort: ItemSort,
+
+    /// Compare items without their [tag] prefix (item) or exactly as printed (tagged)
+    #[arg(long, value_enum, default_value_t = SortKey::Item, requires = "sort")]
+    pub sort_by: SortKey,
+
+    /// After executing, re-fetch the items every INTERVAL_MS milliseconds (default 2000) and
+    /// execute again whenever they changed, until interrupted with Ctrl-C
+    #[arg(long, value_name = "INTERVAL_MS", num_args = 0..=1, default_missing_value = "2000", value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews", "preview"])]
+    pub watch: Option<u64>,
+
+    /// Output format of the execution result
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human, conflicts_with_all = ["produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews", "preview"])]
+    pub format: OutputFormat,
+
+    /// Exit with 3 instead of 0 when the task has no items to execute
+    #[arg(long, conflicts_with_all = ["produce_items", "produce_preselected_items", "produce_preselection_matches", "produce_previews", "preview"])]
+    pub fail_on_empty: bool,
+}
+
+/// Ordering applied to `--produce-*` output
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ItemSort {
+    #[default]
+    None,
+    Alpha,
+    AlphaDesc,
+}
+
+/// What `--sort` compares for tagged items of multi-source tasks
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortKey {
+    /// The item with its `[tag] ` prefix stripped
+    #[default]
+    Item,
+    /// The full tagged string
+    Tagged,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct TestArgs {
+    /// Only run the tests of the plugin in this directory name
+    #[arg(long, value_name = "NAME")]
+    pub plugin: Option<String>,
 }
 
 #[derive(ClapArgs, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct PluginsArgs {
     /// Remove installed plugins not present in config file
     #[arg(long)]
@@ -83,6 +194,11 @@ pub struct PluginsArgs {
     #[arg(long)]
     pub list: bool,
 
+    /// Load every plugin of the configured plugin directories and report its status and load
+    /// time. Exits with 1 when a plugin fails to load, 2 when one only has warnings
+    #[arg(long)]
+    pub check: bool,
+
     /// Upgrade selected plugin to the version declared in config file. If no plugin is specified, tries to upgrade all plugins
     #[arg(long)]
     pub upgrade: bool,
@@ -90,6 +206,62 @@ pub struct PluginsArgs {
     /// Plugin to upgrade (requires --upgrade)
     #[arg(long, value_name = "NAME")]
     pub plugin: Option<String>,
+
+    /// Don't color the --list output, also set by the NO_COLOR environment variable
+    #[arg(long, requires = "list")]
+    pub no_color: bool,
+
+    #[command(subcommand)]
+    pub command: Option<PluginsCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PluginsCommand {
+    /// Create a new plugin skeleton in the managed plugins directory
+    Scaffold {
+        /// Name of the plugin and its directory
+        name: String,
+
+        /// Only write the required fields and a single execute-only task
+        #[arg(long)]
+        minimal: bool,
+    },
+    /// Pull the branch of every plugin declared with `branch`, cloning missing ones
+    Sync {
+        /// Only sync this plugin
+        #[arg(long, value_name = "NAME")]
+        plugin: Option<String>,
+    },
+}
+
+/// Whether `--sandbox` restricts the plugin Lua VM
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum SandboxMode {
+    #[default]
+    On,
+    Off,
+}
+
+/// Verbosity of `--log-level`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum LogLevel {
+    #[default]
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Output format of `list` listings, `execute` results and `validate --plugin`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum OutputFormat {
+    /// Plain text, tab-separated columns for listings
+    #[default]
+    Human,
+    /// JSON on stdout, errors as JSON on stderr
+    Json,
 }
 
 /// Arguments for the `list` subcommand.
@@ -97,7 +269,10 @@ pub struct PluginsArgs {
 /// - No flags: lists all loaded plugins with version and description
 /// - `--plugin NAME`: lists all task keys and descriptions for that plugin
 /// - `--plugin NAME --task KEY`: shows full details of a specific task
+/// - `--plugins`, `--tasks PLUGIN`, `--sources PLUGIN TASK`: script friendly listings,
+///   tab-separated or JSON (`--format json`)
 #[derive(ClapArgs, Debug)]
+#[command(group = ArgGroup::new("listing").args(["plugins", "tasks", "sources"]))]
 pub struct ListArgs {
     /// Plugin to inspect. Omit to list all loaded plugins.
     #[arg(long, value_name = "NAME")]
@@ -106,6 +281,61 @@ pub struct ListArgs {
     /// Task key to inspect (requires --plugin). Omit to list all tasks in the plugin.
     #[arg(long, value_name = "KEY", requires = "plugin")]
     pub task: Option<String>,
+
+    /// List plugins as `name<TAB>description` lines
+    #[arg(long, conflicts_with = "plugin")]
+    pub plugins: bool,
+
+    /// List tasks of a plugin as `key<TAB>description<TAB>mode` lines
+    #[arg(long, value_name = "PLUGIN", conflicts_with = "plugin")]
+    pub tasks: Option<String>,
+
+    /// List item sources of a task as `tag<TAB>item-count` lines. Runs the sources' items()
+    #[arg(long, num_args = 2, value_names = ["PLUGIN", "TASK"], conflicts_with = "plugin")]
+    pub sources: Option<Vec<String>>,
+
+    /// Output format for --plugins, --tasks and --sources
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human, requires = "listing")]
+    pub format: OutputFormat,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct ValidateArgs {
+    /// Validate a plugin file
+    #[arg(long, value_name = "PATH", conflicts_with = "config")]
+    pub plugin: Option<PathBuf>,
+
+    /// Validate configuration file. If no path provided, validates the default config
+    #[arg(long, value_name = "PATH", num_args = 0..=1, conflicts_with = "plugin")]
+    pub config: Option<Vec<PathBuf>>,
+
+    /// Output format of plugin validation, json reports every error and warning with a code
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human, requires = "plugin")]
+    pub format: OutputFormat,
+
+    /// Validate the plugin again whenever a .lua file in its directory changes
+    #[arg(long, requires = "plugin", conflicts_with = "format")]
+    pub watch: bool,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Write a fully commented syntropy.toml with every default value
+    Init {
+        /// Write the config to this path instead of the default config directory
+        #[arg(long, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -121,22 +351,35 @@ pub enum Commands {
         /// The shell to generate completions for
         #[arg(value_enum)]
         shell: Shell,
+
+        /// Also complete plugin and task names, queried from `syntropy list` while completing
+        /// (bash, zsh and fish)
+        #[arg(long)]
+        dynamic: bool,
     },
 
     /// Validate plugin or configuration files
-    Validate {
-        /// Validate a plugin file
-        #[arg(long, value_name = "PATH", conflicts_with = "config")]
-        plugin: Option<PathBuf>,
-
-        /// Validate configuration file. If no path provided, validates the default config
-        #[arg(long, value_name = "PATH", num_args = 0..=1, conflicts_with = "plugin")]
-        config: Option<Vec<PathBuf>>,
-    },
+    Validate(ValidateArgs),
 
     /// List loaded plugins, tasks for a plugin, or details of a specific task
     List(ListArgs),
 
+    /// Manage the configuration file
+    Config(ConfigArgs),
+
+    /// Run the Lua tests in the tests/*.lua files of plugin directories
+    Test(TestArgs),
+
+    /// Print the JSON Schema of the table returned by plugin.lua, for editor tooling
+    Schema,
+
+    /// Report config and plugin load diagnostics
+    Doctor {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Manage plugins (install, remove, upgrade, list)
     ///
     /// - Managed plugins: Installed at XDG_DATA_HOME, managed by config file with [plugins] declaration
@@ -1,7 +1,118 @@
+use anyhow::{Context, Result, bail};
 use clap::Command;
 use clap_complete::{Shell, generate};
+use regex::Regex;
 use std::io;
 
+/// Bash functions completing `--plugin` and `--task` values, wrapping the generated
+/// `_syntropy` completion function
+const BASH_DYNAMIC: &str = r#"
+_syntropy_dynamic() {
+    local cur prev word plugin i
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    prev="${COMP_WORDS[COMP_CWORD-1]}"
+
+    # validate --plugin takes a path, and without syntropy on PATH there is nothing to query
+    for word in "${COMP_WORDS[@]}"; do
+        if [[ "${word}" == validate ]]; then
+            _syntropy "$@"
+            return
+        fi
+    done
+    if ! command -v syntropy >/dev/null 2>&1; then
+        _syntropy "$@"
+        return
+    fi
+
+    case "${prev}" in
+        --plugin|--tasks)
+            COMPREPLY=($(compgen -W "$(syntropy list --plugins 2>/dev/null | cut -f1)" -- "${cur}"))
+            return 0
+            ;;
+        --task)
+            for ((i = 1; i < COMP_CWORD - 1; i++)); do
+                if [[ "${COMP_WORDS[i]}" == --plugin ]]; then
+                    plugin="${COMP_WORDS[i+1]}"
+                fi
+            done
+            if [[ -n "${plugin}" ]]; then
+                COMPREPLY=($(compgen -W "$(syntropy list --tasks "${plugin}" 2>/dev/null | cut -f1)" -- "${cur}"))
+                return 0
+            fi
+            ;;
+    esac
+    _syntropy "$@"
+}
+
+if [[ "${BASH_VERSINFO[0]}" -eq 4 && "${BASH_VERSINFO[1]}" -ge 4 || "${BASH_VERSINFO[0]}" -gt 4 ]]; then
+    complete -F _syntropy_dynamic -o nosort -o bashdefault -o default syntropy
+else
+    complete -F _syntropy_dynamic -o bashdefault -o default syntropy
+fi
+"#;
+
+/// Zsh functions completing `--plugin` and `--task` values, the generated argument specs
+/// are rewritten to call them
+const ZSH_DYNAMIC: &str = r#"(( $+functions[_syntropy_plugin_names] )) ||
+_syntropy_plugin_names() {
+    (( $+commands[syntropy] )) || return 1
+    local -a plugins
+    plugins=(${(f)"$(syntropy list --plugins 2>/dev/null)"})
+    compadd -- ${plugins%%$'\t'*}
+}
+
+(( $+functions[_syntropy_task_names] )) ||
+_syntropy_task_names() {
+    (( $+commands[syntropy] )) || return 1
+    local plugin i
+    for (( i = 1; i < CURRENT; i++ )); do
+        case $words[i] in
+            --plugin) plugin=$words[i+1] ;;
+            --plugin=*) plugin=${words[i]#--plugin=} ;;
+        esac
+    done
+    [[ -n $plugin ]] || return 1
+    local -a tasks
+    tasks=(${(f)"$(syntropy list --tasks $plugin 2>/dev/null)"})
+    compadd -- ${tasks%%$'\t'*}
+}
+
+"#;
+
+/// Fish functions and completions adding `--plugin` and `--task` values to the generated
+/// completions
+const FISH_DYNAMIC: &str = r#"
+function __fish_syntropy_plugin_names
+    command -q syntropy; or return
+    syntropy list --plugins 2>/dev/null
+end
+
+function __fish_syntropy_task_names
+    command -q syntropy; or return
+    set -l tokens (commandline -opc)
+    set -l plugin
+    for i in (seq (count $tokens))
+        switch $tokens[$i]
+            case --plugin
+                if test $i -lt (count $tokens)
+                    set plugin $tokens[(math $i + 1)]
+                end
+            case '--plugin=*'
+                set plugin (string replace -- --plugin= '' $tokens[$i])
+        end
+    end
+    test -n "$plugin"; or return
+    syntropy list --tasks $plugin 2>/dev/null | cut -f1,2
+end
+
+complete -c syntropy -n "not __fish_seen_subcommand_from validate" -l plugin -r -f -a "(__fish_syntropy_plugin_names)"
+complete -c syntropy -n "__fish_syntropy_using_subcommand list" -l tasks -r -f -a "(__fish_syntropy_plugin_names)"
+complete -c syntropy -n "not __fish_seen_subcommand_from validate" -l task -r -f -a "(__fish_syntropy_task_names)"
+"#;
+
+/// Line of the generated zsh dispatch that [`ZSH_DYNAMIC`] is inserted in front of
+const ZSH_DISPATCH: &str = "if [ \"$funcstack[1]\" = \"_syntropy\" ]; then";
+
 /// Generates shell completion scripts to stdout
 ///
 /// Outputs shell-specific completion scripts that can be redirected to the
@@ -22,3 +133,54 @@ use std::io;
 pub fn generate_completions(shell: Shell, cmd: &mut Command) {
     generate(shell, cmd, "syntropy", &mut io::stdout());
 }
+
+/// Generates completion scripts that also complete plugin and task names to stdout
+///
+/// The names are queried with `syntropy list --plugins` and `syntropy list --tasks` while
+/// completing, so they follow installed plugins without regenerating the script. Without
+/// `syntropy` on `PATH` the script falls back to the static completions.
+pub fn generate_dynamic_completions(shell: Shell, cmd: &mut Command) -> Result<()> {
+    print!("{}", dynamic_completion_script(shell, cmd)?);
+    Ok(())
+}
+
+fn dynamic_completion_script(shell: Shell, cmd: &mut Command) -> Result<String> {
+    let mut buffer = Vec::new();
+    generate(shell, cmd, "syntropy", &mut buffer);
+    let script = String::from_utf8(buffer).context("Generated completion script is not UTF-8")?;
+
+    match shell {
+        Shell::Bash => Ok(script + BASH_DYNAMIC),
+        Shell::Fish => Ok(script + FISH_DYNAMIC),
+        Shell::Zsh => {
+            // '--plugin=[Plugin name]:NAME:_default' \ and the like, validate takes a path
+            // (:PATH:_files) and is left alone
+            let name_spec = Regex::new(
+                r"^('(?:\([^)]*\))?--(plugin|tasks|task)=\[.*\]:[A-Z]+:)_default(' \\)$",
+            )
+            .context("Invalid completion spec pattern")?;
+            let mut rewritten: Vec<String> = script
+                .lines()
+                .map(|line| match name_spec.captures(line) {
+                    Some(captures) => {
+                        let completer = if &captures[2] == "task" {
+                            "_syntropy_task_names"
+                        } else {
+                            "_syntropy_plugin_names"
+                        };
+                        format!("{}{}{}", &captures[1], completer, &captures[3])
+                    }
+                    None => line.to_string(),
+                })
+                .collect();
+
+            let dispatch = rewritten
+                .iter()
+                .position(|line| line == ZSH_DISPATCH)
+                .context("Generated zsh script has no dispatch block")?;
+            rewritten.insert(dispatch, ZSH_DYNAMIC.to_string());
+            Ok(rewritten.join("\n") + "\n")
+        }
+        _ => bail!("Dynamic completions are only available for bash, zsh and fish"),
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::configs::{Config, get_default_config_dir, validate_config};
+
+const CONFIG_FILE_NAME: &str = "syntropy.toml";
+
+/// Fully commented `syntropy.toml` written by `syntropy config init`.
+///
+/// Every value equals the built-in default, so the generated file behaves exactly like
+/// having no config at all. Keys without a default value are left commented out.
+pub const DEFAULT_CONFIG_TEMPLATE: &str = r##"# Syntropy configuration
+#
+# Every value below is the built-in default. Uncomment or change what you need,
+# see docs/config-reference.md for the full reference.
+
+# Config files merged underneath this one, relative to this file. Later files win
+# include = ["base.toml"]
+
+# Plugin directories searched in addition to the default ones, relative to this file
+# plugin_paths = ["~/src/syntropy-plugins"]
+
+# Plugin opened on startup, skipping the plugin list
+# default_plugin = "packages"
+
+# Task opened on startup, requires default_plugin
+# default_task = "list"
+
+# Icon shown for plugins that don't declare one, must be a single terminal cell
+default_plugin_icon = "⚒"
+
+# Icon shown for tasks that don't declare one, a single terminal cell or empty for none
+default_task_icon = ""
+
+# Mode of tasks that don't declare one: "single", "multi" or "background"
+default_task_mode = "single"
+
+# Show the status bar with breadcrumbs and job status
+status_bar = true
+
+# Show the fuzzy search bar
+search_bar = true
+
+# Save search queries to the data directory to recall them in later sessions
+search_history = false
+
+# Show the preview pane next to item lists
+show_preview_pane = true
+
+# Show the first line of each task's description under its name in the task list
+show_task_description = true
+
+# Quit syntropy after a task finished executing
+exit_on_execute = false
+
+# Lua file returning a function run before the pre_run of every task, relative to this file
+# global_pre_run = "hooks/pre_run.lua"
+
+# Lua file returning a function run after the post_run of every task
+# global_post_run = "hooks/post_run.lua"
+
+# Move the list cursor from the last item to the first one and back
+list_wrap = false
+
+# Use all configured colors even when NO_COLOR is set or TERM is a limited terminal
+force_color = false
+
+# Previews kept per item list, the least recently viewed ones are dropped first
+preview_cache_size = 50
+
+# Editor used by syntropy.invoke_editor(), ~ and environment variables are expanded
+# editor = "nvim"
+
+# Editors tried in order when neither editor nor $VISUAL/$EDITOR is available
+editor_fallbacks = []
+
+# Prefer `editor` over $VISUAL and $EDITOR
+editor_overrides_env = false
+
+# Warn when an exit code outside 0-255 is clamped
+warn_on_exit_clamp = true
+
+# Ignore unknown keys with a warning instead of rejecting the config
+allow_unknown_config_keys = false
+
+# Key bindings, e.g. "q", "<esc>", "<C-s>", "<S-tab>", "<A-x>"
+# Bind several keys to an action with a list: select_next = ["<down>", "j"]
+[keybindings]
+# Go back to the previous screen, or quit on the plugin list
+back = "<esc>"
+# Move the selection up
+select_previous = "<up>"
+# Move the selection down
+select_next = "<down>"
+# Move the selection up by the height of the visible list
+page_up = "<pageup>"
+# Move the selection down by the height of the visible list
+page_down = "<pagedown>"
+# Replace the search query with the previous one from the search history
+history_previous = "<A-up>"
+# Replace the search query with the next one from the search history
+history_next = "<A-down>"
+# Scroll the preview pane up
+scroll_preview_up = "<C-up>"
+# Scroll the preview pane down
+scroll_preview_down = "<C-down>"
+# Pan the preview pane left while it doesn't wrap lines
+scroll_preview_left = "<A-left>"
+# Pan the preview pane right while it doesn't wrap lines
+scroll_preview_right = "<A-right>"
+# Show or hide the preview pane
+toggle_preview = "<C-p>"
+# Switch the item preview between wrapping long lines and panning them
+toggle_preview_wrap = "<A-w>"
+# Mark or unmark the focused item of a multi-select task
+select = "<tab>"
+# Open the focused entry or execute the task
+confirm = "<enter>"
+# Show background jobs
+jobs = "<C-j>"
+# Kill the focused background job
+kill_job = "<C-k>"
+# Restart the focused background job
+restart_job = "<A-r>"
+# Cycle the item sort order
+sort_items = "<C-s>"
+# Save the current selection as a plan for `syntropy execute --plan`
+dump_plan = "<C-w>"
+# Return to the first screen
+go_home = "<C-home>"
+# Cycle the search mode between fuzzy, prefix and regex
+toggle_search_mode = "<C-f>"
+# Match the search query case-sensitively, turned off again on every screen change
+toggle_case_sensitive = "<C-t>"
+# Copy the value of the focused item to the clipboard
+copy = "<C-y>"
+# Copy the preview of the focused item to the clipboard
+copy_preview = "<A-y>"
+# Mark every item matching the search (multi-select tasks)
+select_all = "<C-a>"
+# Unmark every item matching the search (multi-select tasks)
+select_none = "<A-a>"
+# Flip the marks of the items matching the search (multi-select tasks)
+invert_selection = "<A-i>"
+# Switch the plugin list between all plugins and the ones declaring the current platform
+cycle_plugin_filter = "<A-l>"
+
+# Run a task from any screen, items are matched like `syntropy execute --items`
+# [[keybindings.quick_actions]]
+# key = "<f5>"
+# plugin = "packages"
+# task = "upgrade"
+# items = ["git"]
+
+# Plugins installed and upgraded by `syntropy plugins`, pin a tag or a commit, or track a
+# branch pulled by `syntropy plugins sync` and, with sync_interval (seconds), on TUI startup
+# [plugins.packages]
+# git = "https://github.com/user/syntropy-plugin-packages"
+# tag = "v1.0.0"
+
+# Settings read by a plugin with syntropy.config(), the keys are up to the plugin
+# [plugin_config.notes]
+# directory = "~/notes"
+
+[styles]
+# Built-in color preset: dark, gruvbox, light, nord, solarized-dark
+# theme = "nord"
+
+# Width of the list and preview panes in percent, must amount to 100
+[styles.screen_scaffold]
+left_split = 50
+right_split = 50
+# Border characters of the list, search bar and status bar: "plain", "rounded", "double",
+# "thick", "ascii", "none" or { custom = { top_left = "+", ... } }
+border_style = "plain"
+
+[styles.list]
+# Symbol in front of the focused item
+highlight_symbol = "→"
+# Icons of marked and unmarked items in multi-select tasks
+icon_marked = "▣"
+icon_unmarked = "□"
+# Font weight: "regular" or "bold"
+font_weight = "regular"
+# Borders: "all", "top", "bottom", "left", "right"
+borders = ["all"]
+
+[styles.preview]
+borders = ["all"]
+border_style = "plain"
+font_weight = "regular"
+# Show the item name as the pane title
+show_title = true
+# Lines scrolled per key press
+scroll_offset = 2
+# "overlay" draws the preview over the list, "split" shows it beside the list
+preview_mode = "overlay"
+# Share of the screen taken by the preview in split mode, between 0 and 1
+preview_split_ratio = 0.4
+
+[styles.modal]
+borders = ["all"]
+border_style = "plain"
+font_weight = "regular"
+show_title = true
+scroll_offset = 2
+# Modal size in percent of the screen, must be below 100
+vertical_size = 60
+horizontal_size = 60
+
+[styles.status]
+# Width of the breadcrumbs and job status sections in percent, must amount to 100
+left_split = 50
+right_split = 50
+borders = ["all"]
+font_weight = "bold"
+breadcrumbs_separator = " → "
+# Icons cycled through for each job state
+idle_icons = ["✔"]
+error_icons = ["⛌"]
+complete_icons = ["✔"]
+running_icons = ["✴", "✵"]
+
+[styles.search_bar]
+borders = ["all"]
+font_weight = "bold"
+# Prompt shown in front of the query
+search_hint = ">"
+
+# Colors: "terminal", a named color like "red", or a hex value like "#ff8800".
+# Setting a color here overrides the theme. Per-widget colors fall back to the
+# general ones when empty.
+# [styles.colors]
+# highlights_text = "terminal"
+# highlights_background = "terminal"
+# search_highlight = "terminal"
+# tag = "terminal"
+# borders = "terminal"
+# text = "terminal"
+# background = "terminal"
+# borders_list = ""
+# borders_preview = ""
+# borders_search = ""
+# borders_status = ""
+# borders_modal = ""
+# text_list = ""
+# text_preview = ""
+# text_search = ""
+# text_status = ""
+# text_modal = ""
+# background_list = ""
+# background_preview = ""
+# background_search = ""
+# background_status = ""
+# background_modal = ""
+"##;
+
+/// Writes [`DEFAULT_CONFIG_TEMPLATE`] to `output`, or to `syntropy.toml` in the default
+/// config directory
+///
+/// Refuses to replace an existing file unless `force` is set.
+pub fn config_init_cli(output: Option<PathBuf>, force: bool) -> Result<()> {
+    let path = match output {
+        Some(path) => path,
+        None => get_default_config_dir()
+            .context("Failed to get config directory")?
+            .join(CONFIG_FILE_NAME),
+    };
+
+    if path.exists() && !force {
+        bail!(
+            "Config file already exists at {}. Use --force to overwrite it",
+            path.display()
+        );
+    }
+
+    let config: Config =
+        toml::from_str(DEFAULT_CONFIG_TEMPLATE).context("Failed to parse config template")?;
+    validate_config(&config).context("Config template is invalid")?;
+
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    fs::write(&path, DEFAULT_CONFIG_TEMPLATE)
+        .with_context(|| format!("Failed to write config to {}", path.display()))?;
+
+    println!("✓ Config file written to {}", path.display());
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::Mutex;
+
+use crate::{
+    Config,
+    configs::{expand_path, find_config_file, load_config, resolve_plugin_paths, validate_config},
+    lua::{create_lua_vm, set_plugin_configs},
+    plugins::{PluginLoadRecord, PluginLoadStatus, load_plugins_with_report},
+};
+
+/// Plugin load diagnostics produced by `syntropy doctor`
+#[derive(Debug, Serialize)]
+pub struct DoctorReport {
+    /// Resolved config file, `None` when no config file was found and defaults are used
+    pub config_file: Option<PathBuf>,
+    /// Set when the config file exists but fails to load or validate
+    pub config_error: Option<String>,
+    pub plugin_directories: Vec<PluginDirectoryReport>,
+    pub plugins: Vec<PluginReport>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PluginDirectoryReport {
+    pub path: PathBuf,
+    pub exists: bool,
+    /// plugin.lua files found directly below this directory
+    pub plugin_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PluginReport {
+    pub name: String,
+    pub paths: Vec<PathBuf>,
+    /// One of `ok`, `error` or `platform_unsupported`
+    pub status: &'static str,
+    pub error: Option<String>,
+}
+
+pub fn doctor_cli(cli_config: Option<PathBuf>, json: bool) -> Result<()> {
+    let report = build_report(cli_config)?;
+
+    if json {
+        let output =
+            serde_json::to_string_pretty(&report).context("Failed to serialize doctor report")?;
+        println!("{}", output);
+    } else {
+        print_report(&report);
+    }
+
+    Ok(())
+}
+
+fn build_report(cli_config: Option<PathBuf>) -> Result<DoctorReport> {
+    let expanded_config = cli_config
+        .map(expand_path)
+        .transpose()
+        .context("Failed to expand config path")?;
+    let config_file = find_config_file(expanded_config).context("Failed to find config file")?;
+
+    // A broken config should not hide the plugin diagnostics, fall back to defaults instead
+    let (config, config_error) = match &config_file {
+        Some(path) => match load_config(path.clone()).and_then(|config| {
+            validate_config(&config)?;
+            Ok(config)
+        }) {
+            Ok(config) => (config, None),
+            Err(e) => (Config::default(), Some(format!("{:#}", e))),
+        },
+        None => (Config::default(), None),
+    };
+
+    let plugin_paths =
+        resolve_plugin_paths(&config.plugin_paths).context("Failed to resolve plugin paths")?;
+    let plugin_directories = plugin_paths
+        .iter()
+        .map(|path| scan_plugin_directory(path))
+        .collect::<Result<Vec<_>>>()?;
+
+    let lua = create_lua_vm()?;
+    set_plugin_configs(&lua, config.plugin_config.clone());
+    let lua_runtime = Arc::new(Mutex::new(lua));
+    let (_plugins, records) = load_plugins_with_report(&plugin_paths, &config, lua_runtime)
+        .context("Failed to load plugins")?;
+
+    let warnings = collect_warnings(&records);
+    let plugins = records.into_iter().map(PluginReport::from).collect();
+
+    Ok(DoctorReport {
+        config_file,
+        config_error,
+        plugin_directories,
+        plugins,
+        warnings,
+    })
+}
+
+fn scan_plugin_directory(path: &Path) -> Result<PluginDirectoryReport> {
+    if !path.exists() {
+        return Ok(PluginDirectoryReport {
+            path: path.to_path_buf(),
+            exists: false,
+            plugin_files: Vec::new(),
+        });
+    }
+
+    let mut plugin_files: Vec<PathBuf> = fs::read_dir(path)
+        .with_context(|| format!("Failed to read plugins directory at: {}", path.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read directory entries")?
+        .into_iter()
+        .map(|entry| entry.path().join("plugin.lua"))
+        .filter(|plugin_file| plugin_file.is_file())
+        .collect();
+    plugin_files.sort();
+
+    Ok(PluginDirectoryReport {
+        path: path.to_path_buf(),
+        exists: true,
+        plugin_files,
+    })
+}
+
+fn collect_warnings(records: &[PluginLoadRecord]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for record in records {
+        if record.paths.len() > 1 {
+            let paths: Vec<_> = record
+                .paths
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            warnings.push(format!(
+                "Plugin '{}' found in multiple directories, merged with the first taking precedence: {}",
+                record.name,
+                paths.join(", ")
+            ));
+        }
+        if let PluginLoadStatus::PlatformUnsupported(reason) = &record.status {
+            warnings.push(format!(
+                "Plugin '{}' skipped on this platform: {}",
+                record.name, reason
+            ));
+        }
+    }
+    warnings
+}
+
+impl From<PluginLoadRecord> for PluginReport {
+    fn from(record: PluginLoadRecord) -> Self {
+        let (status, error) = match record.status {
+            PluginLoadStatus::Ok => ("ok", None),
+            PluginLoadStatus::Error(e) => ("error", Some(e)),
+            PluginLoadStatus::PlatformUnsupported(e) => ("platform_unsupported", Some(e)),
+        };
+        Self {
+            name: record.name,
+            paths: record.paths,
+            status,
+            error,
+        }
+    }
+}
+
+fn print_report(report: &DoctorReport) {
+    match &report.config_file {
+        Some(path) => println!("Config file: {}", path.display()),
+        None => println!("Config file: none found, using defaults"),
+    }
+    if let Some(error) = &report.config_error {
+        println!("  ✗ {}", error);
+    }
+
+    println!();
+    println!("Plugin directories:");
+    for directory in &report.plugin_directories {
+        if !directory.exists {
+            println!("  {} (missing)", directory.path.display());
+            continue;
+        }
+        println!("  {}", directory.path.display());
+        for plugin_file in &directory.plugin_files {
+            println!("    {}", plugin_file.display());
+        }
+    }
+
+    println!();
+    println!("Plugins:");
+    if report.plugins.is_empty() {
+        println!("  No plugins found.");
+    }
+    for plugin in &report.plugins {
+        match &plugin.error {
+            None => println!("  ✓ {}", plugin.name),
+            Some(error) => println!("  ✗ {} ({}): {}", plugin.name, plugin.status, error),
+        }
+    }
+
+    if !report.warnings.is_empty() {
+        println!();
+        println!("Warnings:");
+        for warning in &report.warnings {
+            println!("  ⚠ {}", warning);
+        }
+    }
+}
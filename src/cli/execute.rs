@@ -1,22 +1,81 @@
 use anyhow::{Context, Result, bail, ensure};
-use std::collections::HashSet;
+use indexmap::IndexMap;
+use mlua::Lua;
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{self, Write},
+    process::Stdio,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::Mutex;
 
 use crate::{
     app::App,
-    cli::ExecuteArgs,
+    cli::{ExecuteArgs, ItemSort, OutputFormat, SortKey},
+    configs::get_default_data_dir,
     execution::{
-        EXIT_SIGINT, clamp_exit_code, run_execute_pipeline, run_items_pipeline,
-        run_preview_pipeline, runner::parse_tag,
+        EXIT_FAILURE, EXIT_NO_ITEMS, EXIT_SIGINT, ExecutionError, ExecutionPlan, SourceExecution,
+        clamp_exit_code, run_execute_pipeline, run_items_pipeline, run_items_pipeline_streaming,
+        run_preview_pipeline, run_preview_pipeline_batch, runner::parse_tag,
     },
-    plugins::{Mode, Task},
+    plugins::{DefaultSelect, Mode, Task},
+    signal::Cancellation,
 };
 
-/// Parses comma-separated items with support for escaped commas
+/// Printed between the runs of `execute --watch`
+const WATCH_SEPARATOR: &str = "---";
+
+/// How often `execute --watch` checks for Ctrl-C while waiting for the next poll
+const WATCH_CANCEL_CHECK: Duration = Duration::from_millis(100);
+
+/// Items and preselected items fetched from a task
+type FetchedItems = (Vec<String>, Vec<String>);
+
+/// Result of `execute --format json`
+#[derive(Serialize)]
+struct ExecuteReport<'a> {
+    plugin: &'a str,
+    task: &'a str,
+    sources: &'a [SourceExecution],
+    total_exit_code: i32,
+    duration_ms: u64,
+}
+
+/// Prints an `execute --format json` failure to stderr as
+/// `{"error": ..., "code": ..., "kind": ...}`
+///
+/// `kind` is [`ExecutionError::kind`], or `execution_failed` for other failures.
+pub fn print_json_error(error: &anyhow::Error) {
+    let kind = ExecutionError::find(error);
+    let code = match kind {
+        Some(ExecutionError::PluginNotFound { .. } | ExecutionError::TaskNotFound { .. }) => {
+            "NOT_FOUND"
+        }
+        _ => "EXECUTION_FAILED",
+    };
+    let report = serde_json::json!({
+        "error": format!("{:#}", error),
+        "code": code,
+        "kind": kind.map_or("execution_failed", ExecutionError::kind),
+    });
+    eprintln!("{}", report);
+}
+
+/// Parses comma-separated items with support for escaped commas and quoted segments
 ///
 /// Supports:
 /// - `\,` - escaped comma (becomes part of item name)
+/// - `\"` - escaped double quote (becomes part of item name)
 /// - `\\` - escaped backslash (becomes literal backslash)
-/// - `,` - item separator (unescaped comma)
+/// - `"..."` - quoted segment, commas and whitespace inside are kept and the quotes dropped
+/// - `,` - item separator (unescaped comma outside quotes)
+///
+/// Backslash escapes take precedence and work inside and outside quotes, so `\"` never
+/// opens or closes a segment. A quote left open runs to the end of the input. Whitespace
+/// around an item is trimmed, except inside quotes.
 ///
 /// # Examples
 ///
@@ -29,64 +88,191 @@ use crate::{
 /// let result = parse_comma_separated_with_escapes("item1,backup\\,2024,item3");
 /// assert_eq!(result, vec!["item1", "backup,2024", "item3"]);
 ///
+/// let result = parse_comma_separated_with_escapes("\"backup,2024\",other");
+/// assert_eq!(result, vec!["backup,2024", "other"]);
+///
 /// let result = parse_comma_separated_with_escapes("path\\\\to\\\\file,other");
 /// assert_eq!(result, vec!["path\\to\\file", "other"]);
 /// ```
 #[doc(hidden)]
 pub fn parse_comma_separated_with_escapes(input: &str) -> Vec<String> {
     let mut items = Vec::new();
-    let mut current_item = String::new();
+    let mut current_item = ItemBuilder::default();
+    let mut in_quotes = false;
     let mut chars = input.chars().peekable();
 
     while let Some(ch) = chars.next() {
         match (ch, chars.peek()) {
-            // Escaped comma: add comma to current item
-            ('\\', Some(&',')) => {
-                current_item.push(',');
-                chars.next(); // consume the comma
-            }
-            // Escaped backslash: add backslash to current item
-            ('\\', Some(&'\\')) => {
-                current_item.push('\\');
-                chars.next(); // consume the backslash
+            // Escaped comma, quote or backslash: add it to current item
+            ('\\', Some(&escaped @ (',' | '"' | '\\'))) => {
+                current_item.push(escaped, true);
+                chars.next(); // consume the escaped character
             }
             // Trailing backslash or unrecognized escape: keep the backslash
             ('\\', _) => {
-                current_item.push('\\');
+                current_item.push('\\', in_quotes);
+            }
+            // Unescaped quote: opens or closes a quoted segment
+            ('"', _) => {
+                in_quotes = !in_quotes;
             }
-            // Unescaped comma: separator between items
-            (',', _) => {
-                let trimmed = current_item.trim().to_string();
-                if !trimmed.is_empty() {
-                    items.push(trimmed);
+            // Unescaped comma outside quotes: separator between items
+            (',', _) if !in_quotes => {
+                if let Some(item) = current_item.finish() {
+                    items.push(item);
                 }
-                current_item.clear();
             }
             // Regular character: add to current item
             _ => {
-                current_item.push(ch);
+                current_item.push(ch, in_quotes);
             }
         }
     }
 
     // Don't forget the last item
-    let trimmed = current_item.trim().to_string();
-    if !trimmed.is_empty() {
-        items.push(trimmed);
+    if let Some(item) = current_item.finish() {
+        items.push(item);
     }
 
     items
 }
 
+// Collects the characters of one item and remembers which ones came from quotes or
+// escapes, so trimming only removes whitespace the user didn't ask for
+#[derive(Default)]
+struct ItemBuilder {
+    chars: Vec<(char, bool)>,
+}
+
+impl ItemBuilder {
+    fn push(&mut self, ch: char, literal: bool) {
+        self.chars.push((ch, literal));
+    }
+
+    // The trimmed item, None when it is empty
+    fn finish(&mut self) -> Option<String> {
+        let chars = std::mem::take(&mut self.chars);
+        let is_trimmed = |&(ch, literal): &(char, bool)| !literal && ch.is_whitespace();
+        let start = chars.iter().position(|c| !is_trimmed(c))?;
+        let end = chars.iter().rposition(|c| !is_trimmed(c))?;
+        Some(chars[start..=end].iter().map(|&(ch, _)| ch).collect())
+    }
+}
+
+/// Parses a `--env` value of the form `KEY=VALUE`
+///
+/// # Examples
+///
+/// ```
+/// use syntropy::cli::execute::parse_env_assignment;
+///
+/// let (key, value) = parse_env_assignment("STAGE=prod").unwrap();
+/// assert_eq!((key.as_str(), value.as_str()), ("STAGE", "prod"));
+///
+/// assert!(parse_env_assignment("STAGE").is_err());
+/// assert!(parse_env_assignment("A=B=C").is_err());
+/// ```
+#[doc(hidden)]
+pub fn parse_env_assignment(assignment: &str) -> Result<(String, String)> {
+    ensure!(
+        assignment.matches('=').count() == 1,
+        "--env value '{}' must contain exactly one '=' (KEY=VALUE)",
+        assignment
+    );
+    let (key, value) = assignment.split_once('=').unwrap_or_default();
+    validate_env_key(key)?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Parses the contents of a `.env` file
+///
+/// Blank lines and `#` comments are skipped, an optional `export ` prefix is accepted and
+/// values may be wrapped in single or double quotes. Values can contain `=`.
+#[doc(hidden)]
+pub fn parse_env_file(contents: &str) -> Result<Vec<(String, String)>> {
+    let mut variables = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line.split_once('=').with_context(|| {
+            format!(
+                "Line {} is not a KEY=VALUE assignment: {}",
+                line_number + 1,
+                line
+            )
+        })?;
+        let key = key.trim();
+        validate_env_key(key).with_context(|| format!("Line {}", line_number + 1))?;
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|quote| {
+                value
+                    .strip_prefix(*quote)
+                    .and_then(|value| value.strip_suffix(*quote))
+            })
+            .unwrap_or(value);
+        variables.push((key.to_string(), value.to_string()));
+    }
+    Ok(variables)
+}
+
+fn validate_env_key(key: &str) -> Result<()> {
+    ensure!(
+        !key.is_empty() && !key.chars().any(|c| c.is_whitespace() || c == '\0'),
+        "Invalid environment variable name '{}'",
+        key
+    );
+    Ok(())
+}
+
+/// Applies `--env-file` and then `--env` to the process environment
+///
+/// Runs before the Lua runtime and the tokio runtime are created, so the variables are
+/// visible to plugin code and to every process spawned through `syntropy.shell`.
+/// Overriding a variable that was already set prints a warning.
+pub fn apply_env_overrides(execute_args: &ExecuteArgs) -> Result<()> {
+    let mut variables = match &execute_args.env_file {
+        Some(path) => {
+            let contents = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read env file {}", path.display()))?;
+            parse_env_file(&contents)
+                .with_context(|| format!("Failed to parse env file {}", path.display()))?
+        }
+        None => Vec::new(),
+    };
+    for assignment in &execute_args.env {
+        variables.push(parse_env_assignment(assignment)?);
+    }
+
+    for (key, value) in variables {
+        if std::env::var_os(&key).is_some() {
+            eprintln!(
+                "Warning: Overriding existing environment variable '{}'",
+                key
+            );
+        }
+        // SAFETY: called during startup, before any other thread is spawned
+        unsafe {
+            std::env::set_var(&key, &value);
+        }
+    }
+    Ok(())
+}
+
 /// Handles item matching with three-tiered fallback strategy:
 /// 1. Exact case-sensitive match
 /// 2. Tag-stripped match (multi-source only)
-/// 3. Case-insensitive match
+/// 3. Case-insensitive match, skipped when case-sensitive
 #[doc(hidden)]
 pub struct ItemMatcher<'a> {
     available_items: &'a [String],
     is_multi_source: bool,
     task_key: &'a str,
+    case_sensitive: bool,
 }
 
 impl<'a> ItemMatcher<'a> {
@@ -96,9 +282,17 @@ impl<'a> ItemMatcher<'a> {
             available_items,
             is_multi_source,
             task_key,
+            case_sensitive: false,
         }
     }
 
+    /// Disables the case-insensitive fallback (`--case-sensitive`)
+    #[doc(hidden)]
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
     /// Matches a single requested item, returning the matched item or an error
     #[doc(hidden)]
     pub fn match_item(&self, requested_item: &str) -> Result<String> {
@@ -121,17 +315,19 @@ impl<'a> ItemMatcher<'a> {
         }
 
         // Strategy 3: Case-insensitive fallback
-        if let Some(case_insensitive) = self.try_case_insensitive_match(requested_item) {
+        if !self.case_sensitive
+            && let Some(case_insensitive) = self.try_case_insensitive_match(requested_item)
+        {
             return Ok(case_insensitive);
         }
 
         // No match found
-        bail!(
-            "Item '{}' not found in task '{}'. Available items:\n  {}",
-            requested_item,
-            self.task_key,
-            self.available_items.join("\n  ")
-        );
+        Err(ExecutionError::ItemNotFound {
+            task: self.task_key.to_string(),
+            item: requested_item.to_string(),
+            available: self.available_items.to_vec(),
+        }
+        .into())
     }
 
     /// Attempts exact case-sensitive match
@@ -210,6 +406,106 @@ impl<'a> ItemMatcher<'a> {
     }
 }
 
+/// Finds a plugin or task by name, exact matches first.
+///
+/// Falls back to a unique case-insensitive match, noted on stderr. Several case-insensitive
+/// matches are an error listing them, no match at all is `Ok(None)`.
+#[doc(hidden)]
+pub fn match_name<'a, T>(
+    kind: &str,
+    requested: &str,
+    candidates: impl IntoIterator<Item = (&'a str, T)>,
+) -> Result<Option<(&'a str, T)>> {
+    let requested_lower = requested.to_lowercase();
+    let mut case_insensitive_matches = Vec::new();
+    for (name, candidate) in candidates {
+        if name == requested {
+            return Ok(Some((name, candidate)));
+        }
+        if name.to_lowercase() == requested_lower {
+            case_insensitive_matches.push((name, candidate));
+        }
+    }
+
+    if case_insensitive_matches.len() > 1 {
+        let mut names: Vec<_> = case_insensitive_matches
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+        names.sort();
+        bail!(
+            "Ambiguous {} '{}', it matches case-insensitively: {}",
+            kind,
+            requested,
+            names.join(", ")
+        );
+    }
+
+    let matched = case_insensitive_matches.pop();
+    if let Some((name, _)) = &matched {
+        eprintln!(
+            "Info: Matched {} '{}' case-insensitively for '{}'",
+            kind, name, requested
+        );
+    }
+    Ok(matched)
+}
+
+/// Task with the alias `requested`, `None` when a task key equals it exactly since keys
+/// take precedence over aliases
+#[doc(hidden)]
+pub fn find_task_by_alias<'a>(
+    tasks: &'a HashMap<String, Arc<Task>>,
+    requested: &str,
+) -> Option<(&'a str, &'a Arc<Task>)> {
+    if tasks.contains_key(requested) {
+        return None;
+    }
+    tasks
+        .iter()
+        .find(|(_, task)| task.aliases.iter().any(|alias| alias == requested))
+        .map(|(key, task)| (key.as_str(), task))
+}
+
+/// Largest edit distance for which a name is suggested as a typo fix
+const SUGGESTION_MAX_DISTANCE: usize = 2;
+
+/// `"Did you mean 'X'? "` for the closest name within [`SUGGESTION_MAX_DISTANCE`] edits,
+/// empty when nothing is close. Ties go to the alphabetically first name.
+#[doc(hidden)]
+pub fn did_you_mean<'a>(requested: &str, names: impl IntoIterator<Item = &'a str>) -> String {
+    names
+        .into_iter()
+        .map(|name| (strsim::levenshtein(requested, name), name))
+        .filter(|(distance, _)| *distance <= SUGGESTION_MAX_DISTANCE)
+        .min()
+        .map(|(_, name)| format!("Did you mean '{}'? ", name))
+        .unwrap_or_default()
+}
+
+/// Sorts `--produce-*` output in place.
+///
+/// Comparison is case-insensitive with ties broken by exact string, so the order is
+/// deterministic. With `SortKey::Item` the `[tag] ` prefix of multi-source items is ignored
+/// when comparing but kept in the output.
+pub fn sort_items(items: &mut [String], sort: ItemSort, sort_by: SortKey) {
+    let key = |item: &String| -> (String, String) {
+        let compared = match sort_by {
+            SortKey::Item => parse_tag(item).1,
+            SortKey::Tagged => item.as_str(),
+        };
+        (compared.to_lowercase(), item.clone())
+    };
+    match sort {
+        ItemSort::None => {}
+        ItemSort::Alpha => items.sort_by_cached_key(key),
+        ItemSort::AlphaDesc => {
+            items.sort_by_cached_key(key);
+            items.reverse();
+        }
+    }
+}
+
 /// Validates that items_arg is compatible with the task configuration
 fn validate_items_arg_compatibility(
     items_arg: &[&str],
@@ -246,19 +542,31 @@ fn resolve_items_by_mode(
     preselected_items: &[String],
 ) -> Result<Vec<String>> {
     match task.mode {
-        Mode::None => {
+        Mode::Single | Mode::Background => {
             if items.len() > 1 {
                 bail!(
-                    "Task '{}' has mode='none' which requires single-item selection. \
+                    "Task '{}' has mode='{}' which requires single-item selection. \
                      Use --items flag to specify which item to execute.\n  Available items:\n  {}",
                     task.task_key,
+                    task.mode,
                     items.join("\n  ")
                 );
             }
             Ok(items.to_vec())
         }
+        Mode::Multi if task.default_select == DefaultSelect::None => {
+            bail!(
+                "Task '{}' has default_select='none' which selects no items by default. \
+                 Use --items flag to specify which items to execute.\n  Available items:\n  {}",
+                task.task_key,
+                items.join("\n  ")
+            );
+        }
         Mode::Multi => {
-            if !preselected_items.is_empty() {
+            if task.default_select == DefaultSelect::All {
+                eprintln!("Executing with all {} item(s)", items.len());
+                Ok(items.to_vec())
+            } else if !preselected_items.is_empty() {
                 eprintln!(
                     "Executing with {} preselected item(s)",
                     preselected_items.len()
@@ -277,6 +585,7 @@ fn validate_and_resolve_items(
     task: &Task,
     items: &[String],
     preselected_items: &[String],
+    case_sensitive: bool,
 ) -> Result<Vec<String>> {
     // Early validation
     validate_items_arg_compatibility(items_arg, task, preselected_items)?;
@@ -289,7 +598,8 @@ fn validate_and_resolve_items(
             .map(|sources| sources.len() > 1)
             .unwrap_or(false);
 
-        let matcher = ItemMatcher::new(items, is_multi_source, &task.task_key);
+        let matcher = ItemMatcher::new(items, is_multi_source, &task.task_key)
+            .with_case_sensitive(case_sensitive);
         return matcher.match_all(items_arg);
     }
 
@@ -297,11 +607,43 @@ fn validate_and_resolve_items(
     resolve_items_by_mode(task, items, preselected_items)
 }
 
+/// Fetches the items of `task` and picks the ones to execute on, see [`execute_task_cli`]
+/// for the rules
+///
+/// Also returns the fetched items and preselected items, `None` for tasks without item
+/// sources which always execute on no items.
+pub(crate) async fn select_items(
+    lua_runtime: &Arc<Mutex<Lua>>,
+    task: &Task,
+    items_arg: &[&str],
+    case_sensitive: bool,
+) -> Result<(Vec<String>, Option<FetchedItems>)> {
+    if task.item_sources.is_none() {
+        ensure!(
+            items_arg.is_empty(),
+            "Task '{}' has no item sources (standalone execute-only task). The --items flag cannot be used with this task.",
+            task.task_key
+        );
+        return Ok((vec![], None));
+    }
+
+    let (items, preselected_items) = run_items_pipeline(lua_runtime.clone(), task)
+        .await
+        .context("Failed to fetch items from task")?;
+
+    let selected_items =
+        validate_and_resolve_items(items_arg, task, &items, &preselected_items, case_sensitive)?;
+    Ok((selected_items, Some((items, preselected_items))))
+}
+
 /// Executes a task directly from CLI without launching the TUI
 ///
 /// This function provides non-interactive task execution for use in scripts,
 /// cron jobs, and CI/CD pipelines.
 ///
+/// Plugin and task names fall back to a unique case-insensitive match, see [`match_name`].
+/// `--task` also takes task aliases, an exact task key wins over an alias.
+///
 /// # Item Selection Logic
 ///
 /// **With `--items` flag:**
@@ -311,14 +653,34 @@ fn validate_and_resolve_items(
 /// - Overrides `preselected_items()` if present
 ///
 /// **Without `--items` flag:**
-/// - For `mode="none"` tasks with multiple items: Returns error (explicit selection required)
-/// - For `mode="none"` tasks with single item: Executes on that item
+/// - For `mode="single"` tasks with multiple items: Returns error (explicit selection required)
+/// - For `mode="single"` tasks with single item: Executes on that item
 /// - For `mode="multi"` tasks: Uses preselected items if any, otherwise all items
+/// - `default_select = "all"` always uses all items, `default_select = "none"` requires `--items`
 ///
 /// **For execute-only tasks (no item_sources):**
 /// - Executes directly with empty items array
 /// - `--items` flag is not applicable and will return error
 ///
+/// **With `--plan` flag:**
+/// - Plugin, task and items are read from a plan file dumped from the TUI
+/// - The recorded items are matched like `--items`
+///
+/// **With `--source` flag:**
+/// - Only the item source with the given tag is used, for items, preview and execution
+/// - Its items are untagged, so `--items` takes plain item names
+///
+/// **For background tasks (`mode="background"`):**
+/// - Items are fetched and resolved first, an unknown item or an ambiguous selection fails here
+/// - The command is then re-run detached from the terminal and the job id is printed
+/// - `--foreground` runs the task attached, like any other task
+///
+/// **With `--watch` flag:**
+/// - After the first run the items are fetched again every interval
+/// - Whenever they changed, the items are resolved again and the task is executed again,
+///   each run is preceded by a `---` line
+/// - Errors of later runs are printed and watching continues, Ctrl-C stops it
+///
 /// # Arguments
 ///
 /// * `app` - Application context with loaded plugins and configuration
@@ -329,7 +691,8 @@ fn validate_and_resolve_items(
 /// Returns `Ok(exit_code)` on successful execution, `Err` otherwise.
 /// Exit code is propagated from the Lua execute function and clamped to valid POSIX range (0-255).
 /// Invalid exit codes (<0 or >255) are clamped with warnings printed to stderr.
-/// Output is printed to stdout, errors to stderr.
+/// Output is printed to stdout, errors to stderr. With `--format json` a single JSON object
+/// with per-source results is printed instead, see [`print_json_error`] for errors.
 ///
 /// # Examples
 ///
@@ -341,23 +704,33 @@ fn validate_and_resolve_items(
 /// # Execute with default selection (multi mode)
 /// syntropy execute --plugin packages --task export_bundle
 ///
-/// # Error: mode=none requires --items when multiple items exist
+/// # Error: mode=single requires --items when multiple items exist
 /// syntropy execute --plugin packages --task export
 /// ```
 pub async fn execute_task_cli(
     app: App,
     execute_args: &ExecuteArgs,
-    cancellation: Option<&crate::signal::Cancellation>,
+    cancellation: Option<&Cancellation>,
 ) -> Result<i32> {
-    let plugin_name = &execute_args.plugin;
-    let task_key = &execute_args.task;
-
-    // Parse comma-separated items if provided (with escape support for commas in item names)
-    let items_arg: Vec<String> = execute_args
-        .items
-        .as_ref()
-        .map(|s| parse_comma_separated_with_escapes(s))
-        .unwrap_or_default();
+    let (plugin_name, task_key, items_arg) = match &execute_args.plan {
+        // A plan replays a recorded selection, its items are matched exactly like --items
+        Some(plan_path) => {
+            let plan = ExecutionPlan::load(plan_path)?;
+            (plan.plugin, plan.task, plan.items)
+        }
+        None => {
+            let (Some(plugin), Some(task)) = (&execute_args.plugin, &execute_args.task) else {
+                bail!("--plugin and --task are required unless --plan is given");
+            };
+            // Parse comma-separated items if provided (with escape support for commas in item names)
+            let items_arg: Vec<String> = execute_args
+                .items
+                .as_ref()
+                .map(|s| parse_comma_separated_with_escapes(s))
+                .unwrap_or_default();
+            (plugin.clone(), task.clone(), items_arg)
+        }
+    };
 
     // Convert to Vec<&str> for validate_and_resolve_items
     let items_arg_refs: Vec<&str> = items_arg.iter().map(|s| s.as_str()).collect();
@@ -367,33 +740,16 @@ pub async fn execute_task_cli(
         bail!("--items cannot be empty or whitespace-only");
     }
 
-    let plugin = app
-        .plugins
-        .iter()
-        .find(|p| p.metadata.name == *plugin_name)
-        .with_context(|| {
-            let available = app
-                .plugins
-                .iter()
-                .map(|p| p.metadata.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            format!(
-                "Plugin '{}' not found. Available plugins: {}",
-                plugin_name, available
-            )
-        })?;
+    let (plugin_name, task_key, task) = app.find_task(&plugin_name, &task_key)?;
 
-    let task = plugin.tasks.get(task_key).with_context(|| {
-        let mut available: Vec<_> = plugin.tasks.keys().map(|k| k.as_str()).collect();
-        // Sort task names alphabetically (case-insensitive) for consistent error messages
-        available.sort_by_key(|a| a.to_lowercase());
-        let available_str = available.join(", ");
-        format!(
-            "Task '{}' not found in plugin '{}'. Available tasks: {}",
-            task_key, plugin_name, available_str
-        )
-    })?;
+    let source_task;
+    let task = match &execute_args.source {
+        Some(tag) => {
+            source_task = with_single_source(task, tag)?;
+            &source_task
+        }
+        None => task.as_ref(),
+    };
 
     // Handle --preview flag: generate preview for a single item
     if let Some(preview_item) = &execute_args.preview {
@@ -408,14 +764,15 @@ pub async fn execute_task_cli(
             .context("Failed to fetch items from task")?;
 
         let is_multi_source = task.item_sources.as_ref().unwrap().len() > 1;
-        let matcher = ItemMatcher::new(&items, is_multi_source, &task.task_key);
+        let matcher = ItemMatcher::new(&items, is_multi_source, &task.task_key)
+            .with_case_sensitive(execute_args.case_sensitive);
         let matched_item = matcher.match_item(preview_item)?;
 
         let preview_text = run_preview_pipeline(app.lua_runtime.clone(), task, &matched_item)
             .await
             .context("Failed to generate preview")?;
 
-        println!("{}", preview_text);
+        println!("{}", preview_text.content);
         return Ok(0);
     }
 
@@ -427,10 +784,25 @@ pub async fn execute_task_cli(
             task.task_key
         );
 
-        let (items, _) = run_items_pipeline(app.lua_runtime.clone(), task)
+        // Unsorted items are printed as they arrive, `items_command` lines while it still runs
+        if execute_args.sort == ItemSort::None {
+            let mut stdout = io::stdout();
+            run_items_pipeline_streaming(app.lua_runtime.clone(), task, |items| {
+                for item in items {
+                    let _ = writeln!(stdout, "{}", item);
+                }
+                let _ = stdout.flush();
+            })
             .await
             .context("Failed to fetch items from task")?;
+            return Ok(0);
+        }
 
+        let (mut items, _) = run_items_pipeline(app.lua_runtime.clone(), task)
+            .await
+            .context("Failed to fetch items from task")?;
+
+        sort_items(&mut items, execute_args.sort, execute_args.sort_by);
         for item in items {
             println!("{}", item);
         }
@@ -446,10 +818,15 @@ pub async fn execute_task_cli(
             task.task_key
         );
 
-        let (_, preselected_items) = run_items_pipeline(app.lua_runtime.clone(), task)
+        let (_, mut preselected_items) = run_items_pipeline(app.lua_runtime.clone(), task)
             .await
             .context("Failed to fetch items from task")?;
 
+        sort_items(
+            &mut preselected_items,
+            execute_args.sort,
+            execute_args.sort_by,
+        );
         for item in preselected_items {
             println!("{}", item);
         }
@@ -465,12 +842,13 @@ pub async fn execute_task_cli(
             task.task_key
         );
 
-        let (items, preselected_items) = run_items_pipeline(app.lua_runtime.clone(), task)
+        let (mut items, preselected_items) = run_items_pipeline(app.lua_runtime.clone(), task)
             .await
             .context("Failed to fetch items from task")?;
 
         // Calculate intersection: items that appear in both lists
         let preselected_set: HashSet<_> = preselected_items.into_iter().collect();
+        sort_items(&mut items, execute_args.sort, execute_args.sort_by);
         for item in items {
             if preselected_set.contains(&item) {
                 println!("{}", item);
@@ -480,20 +858,61 @@ pub async fn execute_task_cli(
         return Ok(0);
     }
 
-    let selected_items = if task.item_sources.is_some() {
-        let (items, preselected_items) = run_items_pipeline(app.lua_runtime.clone(), task)
+    // Handle --produce-previews flag: output the preview of every item
+    if execute_args.produce_previews {
+        ensure!(
+            task.item_sources.is_some(),
+            "Task '{}' has no item sources. The --produce-previews flag requires a task with item sources.",
+            task.task_key
+        );
+
+        let (mut items, _) = run_items_pipeline(app.lua_runtime.clone(), task)
             .await
             .context("Failed to fetch items from task")?;
+        sort_items(&mut items, execute_args.sort, execute_args.sort_by);
 
-        validate_and_resolve_items(&items_arg_refs, task, &items, &preselected_items)?
-    } else {
+        let mut previews = run_preview_pipeline_batch(app.lua_runtime.clone(), task, &items).await;
+        let mut failed = 0;
+        for item in &items {
+            match previews.remove(item) {
+                Some(Ok(preview)) => println!("==> {} <==\n{}", item, preview.content),
+                Some(Err(e)) => {
+                    eprintln!("Error: Failed to generate preview for '{}': {:#}", item, e);
+                    failed += 1;
+                }
+                None => {}
+            }
+        }
+
+        return Ok(if failed > 0 { EXIT_FAILURE } else { 0 });
+    }
+
+    if execute_args.watch.is_some() {
         ensure!(
-            items_arg_refs.is_empty(),
-            "Task '{}' has no item sources (standalone execute-only task). The --items flag cannot be used with this task.",
+            task.item_sources.is_some(),
+            "Task '{}' has no item sources. The --watch flag requires a task with item sources.",
             task.task_key
         );
-        vec![]
-    };
+        ensure!(
+            task.mode != Mode::Background || execute_args.foreground,
+            "Task '{}' has mode='background'. The --watch flag requires --foreground for it.",
+            task.task_key
+        );
+    }
+
+    let (selected_items, fetched_items) = select_items(
+        &app.lua_runtime,
+        task,
+        &items_arg_refs,
+        execute_args.case_sensitive,
+    )
+    .await?;
+
+    // Background tasks re-run this command in a detached process and return immediately,
+    // once the items resolved so a selection that can't run fails here and not in the job log
+    if task.mode == Mode::Background && !execute_args.foreground {
+        return spawn_background_job(task);
+    }
 
     if let Some(cancel) = cancellation
         && cancel.is_cancelled()
@@ -508,13 +927,61 @@ pub async fn execute_task_cli(
         return Ok(EXIT_SIGINT);
     }
 
-    let (output, exit_code) =
-        run_execute_pipeline(app.lua_runtime.clone(), task, &selected_items, cancellation)
+    let exit_code = execute_and_report(
+        &app,
+        task,
+        &selected_items,
+        execute_args,
+        cancellation,
+        plugin_name,
+        task_key,
+    )
+    .await?;
+
+    match (execute_args.watch, fetched_items) {
+        (Some(interval_ms), Some(fetched_items)) => {
+            watch_and_execute(
+                &app,
+                task,
+                execute_args,
+                &items_arg_refs,
+                cancellation,
+                Duration::from_millis(interval_ms),
+                fetched_items,
+                plugin_name,
+                task_key,
+            )
+            .await
+        }
+        _ => Ok(exit_code),
+    }
+}
+
+// Runs `execute` on the selected items, prints its output in the requested format and
+// returns the clamped exit code
+async fn execute_and_report(
+    app: &App,
+    task: &Task,
+    selected_items: &[String],
+    execute_args: &ExecuteArgs,
+    cancellation: Option<&Cancellation>,
+    plugin_name: &str,
+    task_key: &str,
+) -> Result<i32> {
+    let execute_output =
+        run_execute_pipeline(app.lua_runtime.clone(), task, selected_items, cancellation)
             .await
             .context("Failed to execute task")?;
+    let exit_code = execute_output.exit_code;
 
-    if !output.is_empty() {
-        println!("{}", output);
+    if execute_args.format == OutputFormat::Human {
+        if !execute_output.output.is_empty() {
+            println!("{}", execute_output.output);
+        }
+
+        if let Some(summary) = execute_output.failure_summary() {
+            eprintln!("{}", summary);
+        }
     }
 
     let final_exit_code = if let Some(cancel) = cancellation {
@@ -527,12 +994,209 @@ pub async fn execute_task_cli(
         clamp_exit_code(exit_code)
     };
 
-    if final_exit_code != exit_code && exit_code != EXIT_SIGINT {
+    if final_exit_code != exit_code && exit_code != EXIT_SIGINT && app.config.warn_on_exit_clamp {
         eprintln!(
             "Warning: Exit code {} clamped to {}",
             exit_code, final_exit_code
         );
     }
 
+    // Nothing ran, which scripts asking for it tell apart from a successful run
+    let final_exit_code = if execute_args.fail_on_empty
+        && final_exit_code == 0
+        && task.item_sources.is_some()
+        && selected_items.is_empty()
+    {
+        EXIT_NO_ITEMS
+    } else {
+        final_exit_code
+    };
+
+    if execute_args.format == OutputFormat::Json {
+        let report = ExecuteReport {
+            plugin: plugin_name,
+            task: task_key,
+            sources: &execute_output.sources,
+            total_exit_code: final_exit_code,
+            duration_ms: execute_output.duration.as_millis() as u64,
+        };
+        // One report per line while watching, so every run can be parsed on its own
+        let json = if execute_args.watch.is_some() {
+            serde_json::to_string(&report)
+        } else {
+            serde_json::to_string_pretty(&report)
+        }
+        .context("Failed to serialize execute result")?;
+        println!("{}", json);
+    }
+
     Ok(final_exit_code)
 }
+
+// Polls the items of `task` every `interval` and executes it again whenever they differ
+// from the last fetch. Runs until Ctrl-C is pressed, which exits with `EXIT_SIGINT`.
+async fn watch_and_execute(
+    app: &App,
+    task: &Task,
+    execute_args: &ExecuteArgs,
+    items_arg: &[&str],
+    cancellation: Option<&Cancellation>,
+    interval: Duration,
+    mut fetched_items: FetchedItems,
+    plugin_name: &str,
+    task_key: &str,
+) -> Result<i32> {
+    loop {
+        if sleep_unless_cancelled(interval, cancellation).await {
+            return Ok(EXIT_SIGINT);
+        }
+
+        let current_items = match run_items_pipeline(app.lua_runtime.clone(), task).await {
+            Ok(current_items) => current_items,
+            Err(e) => {
+                eprintln!("Error: Failed to fetch items from task: {:#}", e);
+                continue;
+            }
+        };
+        if current_items == fetched_items {
+            continue;
+        }
+        fetched_items = current_items;
+
+        if execute_args.format == OutputFormat::Human {
+            println!("{}", WATCH_SEPARATOR);
+        }
+        let (items, preselected_items) = &fetched_items;
+        let run = match validate_and_resolve_items(
+            items_arg,
+            task,
+            items,
+            preselected_items,
+            execute_args.case_sensitive,
+        ) {
+            Ok(selected_items) => {
+                execute_and_report(
+                    app,
+                    task,
+                    &selected_items,
+                    execute_args,
+                    cancellation,
+                    plugin_name,
+                    task_key,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = run {
+            eprintln!("Error: {:#}", e);
+        }
+    }
+}
+
+// Sleeps for `duration`, returns early with `true` once cancellation was requested
+async fn sleep_unless_cancelled(duration: Duration, cancellation: Option<&Cancellation>) -> bool {
+    let mut remaining = duration;
+    while !remaining.is_zero() {
+        if cancellation.is_some_and(Cancellation::is_cancelled) {
+            return true;
+        }
+        let step = remaining.min(WATCH_CANCEL_CHECK);
+        tokio::time::sleep(step).await;
+        remaining -= step;
+    }
+    cancellation.is_some_and(Cancellation::is_cancelled)
+}
+
+/// Copy of `task` keeping only the item source tagged `tag`
+///
+/// The copy is a single-source task, so its items carry no `[tag] ` prefix.
+fn with_single_source(task: &Task, tag: &str) -> Result<Task> {
+    let item_sources = task.item_sources.as_ref().with_context(|| {
+        format!(
+            "Task '{}' has no item sources. The --source flag requires a task with item sources.",
+            task.task_key
+        )
+    })?;
+
+    let (source_key, item_source) = item_sources
+        .iter()
+        .find(|(_, item_source)| item_source.tag == tag)
+        .with_context(|| {
+            let mut available: Vec<_> = item_sources
+                .values()
+                .map(|item_source| item_source.tag.as_str())
+                .collect();
+            available.sort_by_key(|tag| tag.to_lowercase());
+            format!(
+                "Source tag '{}' not found in task '{}'. Available tags: {}",
+                tag,
+                task.task_key,
+                available.join(", ")
+            )
+        })?;
+
+    Ok(Task {
+        item_sources: Some(IndexMap::from([(source_key.clone(), item_source.clone())])),
+        ..task.clone()
+    })
+}
+
+/// Detaches a background-mode task from the terminal
+///
+/// Re-runs the current command line with `--foreground` in a new process group, with
+/// stdin closed and stdout/stderr redirected to `<data_dir>/jobs/<job_id>.log`.
+/// The job id (the child's process id) is printed to stdout so scripts can capture it.
+fn spawn_background_job(task: &Task) -> Result<i32> {
+    let jobs_dir = get_default_data_dir()?.join("jobs");
+    fs::create_dir_all(&jobs_dir)
+        .with_context(|| format!("Failed to create jobs directory {}", jobs_dir.display()))?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let pending_log_path = jobs_dir.join(format!(
+        "{}-{}-{}.log",
+        task.plugin_name, task.task_key, timestamp
+    ));
+    let log_file = fs::File::create(&pending_log_path)
+        .with_context(|| format!("Failed to create job log {}", pending_log_path.display()))?;
+
+    let executable = std::env::current_exe().context("Failed to locate syntropy executable")?;
+    let mut command = std::process::Command::new(executable);
+    command
+        .args(std::env::args_os().skip(1))
+        .arg("--foreground")
+        .stdin(Stdio::null())
+        .stdout(log_file.try_clone().context("Failed to open job log")?)
+        .stderr(log_file);
+
+    // Own process group so terminal signals (e.g. Ctrl+C) don't reach the job
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let child = command
+        .spawn()
+        .with_context(|| format!("Failed to start background job for '{}'", task.task_key))?;
+    let job_id = child.id();
+
+    let log_path = jobs_dir.join(format!("{}.log", job_id));
+    let log_path = match fs::rename(&pending_log_path, &log_path) {
+        Ok(()) => log_path,
+        Err(_) => pending_log_path,
+    };
+
+    eprintln!(
+        "Started background job {} for task '{}' (log: {})",
+        job_id,
+        task.task_key,
+        log_path.display()
+    );
+    println!("{}", job_id);
+
+    Ok(0)
+}
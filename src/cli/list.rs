@@ -1,8 +1,46 @@
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
 
-use crate::{app::App, cli::ListArgs};
+use crate::{
+    app::App,
+    cli::{ListArgs, OutputFormat},
+    execution::call_item_source_items,
+    plugins::{Plugin, Task},
+};
+
+#[derive(Serialize)]
+struct PluginEntry<'a> {
+    name: &'a str,
+    description: &'a str,
+}
+
+#[derive(Serialize)]
+struct TaskEntry<'a> {
+    key: &'a str,
+    description: &'a str,
+    mode: String,
+}
+
+#[derive(Serialize)]
+struct SourceEntry<'a> {
+    key: &'a str,
+    tag: &'a str,
+    item_count: usize,
+}
+
+pub fn list_cli(app: &App, args: &ListArgs, runtime: &Runtime) -> Result<()> {
+    if args.plugins {
+        return print_plugin_entries(app, args.format);
+    }
+    if let Some(plugin_name) = &args.tasks {
+        return print_task_entries(app, plugin_name, args.format);
+    }
+    if let Some([plugin_name, task_key]) = args.sources.as_deref() {
+        return print_source_entries(app, plugin_name, task_key, args.format, runtime);
+    }
 
-pub fn list_cli(app: &App, args: &ListArgs) -> Result<()> {
     match (&args.plugin, &args.task) {
         (None, _) => list_plugins(app),
         (Some(plugin_name), None) => list_tasks(app, plugin_name),
@@ -10,27 +48,106 @@ pub fn list_cli(app: &App, args: &ListArgs) -> Result<()> {
     }
 }
 
-fn list_plugins(app: &App) -> Result<()> {
+fn print_plugin_entries(app: &App, format: OutputFormat) -> Result<()> {
     let mut plugins: Vec<_> = app.plugins.iter().collect();
     plugins.sort_by_key(|p| p.metadata.name.to_lowercase());
-    if plugins.is_empty() {
-        println!("No plugins found.");
-        return Ok(());
+
+    let entries: Vec<PluginEntry> = plugins
+        .iter()
+        .map(|plugin| PluginEntry {
+            name: &plugin.metadata.name,
+            description: &plugin.metadata.description,
+        })
+        .collect();
+
+    print_entries(&entries, format, |entry| {
+        vec![entry.name.to_string(), entry.description.to_string()]
+    })
+}
+
+fn print_task_entries(app: &App, plugin_name: &str, format: OutputFormat) -> Result<()> {
+    let (_, plugin) = find_plugin(app, plugin_name)?;
+
+    let entries: Vec<TaskEntry> = plugin
+        .tasks_grouped()
+        .into_iter()
+        .flat_map(|(_, tasks)| tasks)
+        .map(|(task_key, task)| TaskEntry {
+            key: task_key,
+            description: &task.description,
+            mode: task.mode.to_string(),
+        })
+        .collect();
+
+    print_entries(&entries, format, |entry| {
+        vec![
+            entry.key.to_string(),
+            entry.description.to_string(),
+            entry.mode.clone(),
+        ]
+    })
+}
+
+fn print_source_entries(
+    app: &App,
+    plugin_name: &str,
+    task_key: &str,
+    format: OutputFormat,
+    runtime: &Runtime,
+) -> Result<()> {
+    let (plugin_idx, plugin) = find_plugin(app, plugin_name)?;
+    let task = app
+        .get_task(plugin_idx, task_key)
+        .with_context(|| task_not_found_message(plugin, task_key))?;
+
+    let mut entries: Vec<SourceEntry> = Vec::new();
+    for (source_key, item_source) in task.item_sources.iter().flatten() {
+        let items = runtime
+            .block_on(call_item_source_items(
+                &app.lua_runtime,
+                &task.plugin_name,
+                &task.task_key,
+                source_key,
+            ))
+            .with_context(|| format!("Failed to fetch items of source '{}'", source_key))?;
+        entries.push(SourceEntry {
+            key: source_key,
+            tag: &item_source.tag,
+            item_count: items.len(),
+        });
     }
-    for plugin in plugins {
-        println!(
-            "{} (v{}) - {}",
-            plugin.metadata.name, plugin.metadata.version, plugin.metadata.description
-        );
+
+    print_entries(&entries, format, |entry| {
+        vec![entry.tag.to_string(), entry.item_count.to_string()]
+    })
+}
+
+// Prints entries as tab-separated `columns` lines or as a JSON array
+fn print_entries<T, F>(entries: &[T], format: OutputFormat, columns: F) -> Result<()>
+where
+    T: Serialize,
+    F: Fn(&T) -> Vec<String>,
+{
+    match format {
+        OutputFormat::Human => {
+            for entry in entries {
+                println!("{}", columns(entry).join("\t"));
+            }
+        }
+        OutputFormat::Json => {
+            let output =
+                serde_json::to_string_pretty(entries).context("Failed to serialize listing")?;
+            println!("{}", output);
+        }
     }
     Ok(())
 }
 
-fn list_tasks(app: &App, plugin_name: &str) -> Result<()> {
-    let plugin = app
-        .plugins
+fn find_plugin<'a>(app: &'a App, plugin_name: &str) -> Result<(usize, &'a Plugin)> {
+    app.plugins
         .iter()
-        .find(|p| p.metadata.name == plugin_name)
+        .enumerate()
+        .find(|(_, p)| p.metadata.name == plugin_name)
         .with_context(|| {
             let mut names: Vec<_> = app
                 .plugins
@@ -43,46 +160,59 @@ fn list_tasks(app: &App, plugin_name: &str) -> Result<()> {
                 plugin_name,
                 names.join(", ")
             )
-        })?;
+        })
+}
+
+fn find_task<'a>(plugin: &'a Plugin, task_key: &str) -> Result<&'a Arc<Task>> {
+    plugin
+        .tasks
+        .get(task_key)
+        .with_context(|| task_not_found_message(plugin, task_key))
+}
 
-    let mut tasks: Vec<_> = plugin.tasks.values().collect();
-    tasks.sort_by_key(|t| t.task_key.to_lowercase());
+fn task_not_found_message(plugin: &Plugin, task_key: &str) -> String {
+    let mut available: Vec<_> = plugin.tasks.keys().map(|k| k.as_str()).collect();
+    available.sort_by_key(|k| k.to_lowercase());
+    format!(
+        "Task '{}' not found in plugin '{}'. Available tasks: {}",
+        task_key,
+        plugin.metadata.name,
+        available.join(", ")
+    )
+}
+
+fn list_plugins(app: &App) -> Result<()> {
+    let mut plugins: Vec<_> = app.plugins.iter().collect();
+    plugins.sort_by_key(|p| p.metadata.name.to_lowercase());
+    if plugins.is_empty() {
+        println!("No plugins found.");
+        return Ok(());
+    }
+    for plugin in plugins {
+        println!(
+            "{} (v{}) - {}",
+            plugin.metadata.name, plugin.metadata.version, plugin.metadata.description
+        );
+    }
+    Ok(())
+}
+
+fn list_tasks(app: &App, plugin_name: &str) -> Result<()> {
+    let (_, plugin) = find_plugin(app, plugin_name)?;
 
-    for task in tasks {
-        println!("{} - {}", task.task_key, task.description);
+    // Same order as the TUI task list: grouped, then by order and key within each group
+    for (_, tasks) in plugin.tasks_grouped() {
+        for (_, task) in tasks {
+            println!("{} - {}", task.task_key, task.description);
+        }
     }
     Ok(())
 }
 
 fn show_task_detail(app: &App, plugin_name: &str, task_key: &str) -> Result<()> {
-    let plugin = app
-        .plugins
-        .iter()
-        .find(|p| p.metadata.name == plugin_name)
-        .with_context(|| {
-            let mut names: Vec<_> = app
-                .plugins
-                .iter()
-                .map(|p| p.metadata.name.as_str())
-                .collect();
-            names.sort_by_key(|n| n.to_lowercase());
-            format!(
-                "Plugin '{}' not found. Available plugins: {}",
-                plugin_name,
-                names.join(", ")
-            )
-        })?;
-
-    let task = plugin.tasks.get(task_key).with_context(|| {
-        let mut available: Vec<_> = plugin.tasks.keys().map(|k| k.as_str()).collect();
-        available.sort_by_key(|k| k.to_lowercase());
-        format!(
-            "Task '{}' not found in plugin '{}'. Available tasks: {}",
-            task_key,
-            plugin_name,
-            available.join(", ")
-        )
-    })?;
+    let (_, plugin) = find_plugin(app, plugin_name)?;
+
+    let task = find_task(plugin, task_key)?;
 
     let name = if task.name.is_empty() {
         task.task_key.as_str()
@@ -96,5 +226,14 @@ fn show_task_detail(app: &App, plugin_name: &str, task_key: &str) -> Result<()>
     println!("description: {}", task.description);
     println!("mode: {}", task.mode);
     println!("item_sources: {}", item_sources_count);
+    if !task.aliases.is_empty() {
+        println!("aliases: {}", task.aliases.join(", "));
+    }
+    if let Some(group) = &task.group {
+        println!("group: {}", group);
+    }
+    if let Some(order) = task.order {
+        println!("order: {}", order);
+    }
     Ok(())
 }
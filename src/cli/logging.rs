@@ -0,0 +1,74 @@
+use anyhow::{Context, Result, anyhow};
+use std::{
+    fs::{self, OpenOptions},
+    path::PathBuf,
+    sync::Mutex,
+};
+use tracing_subscriber::filter::LevelFilter;
+
+use crate::{
+    cli::LogLevel,
+    configs::{expand_path, get_default_data_dir},
+};
+
+const LOG_FILE_NAME: &str = "syntropy.log";
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::OFF,
+            LogLevel::Error => LevelFilter::ERROR,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Installs the global `tracing` subscriber
+///
+/// Logs go to `log_file` when given. Without one, subcommands log to stderr while the TUI
+/// logs to `<data_dir>/syntropy.log`, as anything written to the terminal would corrupt
+/// the alternate screen. A `log_file` without `level` logs at `info`.
+pub fn init_logging(level: Option<LogLevel>, log_file: Option<PathBuf>, tui: bool) -> Result<()> {
+    let level = level.unwrap_or(if log_file.is_some() {
+        LogLevel::Info
+    } else {
+        LogLevel::Off
+    });
+    if level == LogLevel::Off {
+        return Ok(());
+    }
+
+    let log_file = match log_file {
+        Some(path) => Some(expand_path(path).context("Failed to expand log file path")?),
+        None if tui => Some(get_default_data_dir()?.join(LOG_FILE_NAME)),
+        None => None,
+    };
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::from(level))
+        .with_target(false);
+
+    let result = match log_file {
+        Some(path) => {
+            if let Some(parent) = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+            {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+            }
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))?;
+            subscriber.with_writer(Mutex::new(file)).try_init()
+        }
+        None => subscriber.with_writer(std::io::stderr).try_init(),
+    };
+
+    result.map_err(|e| anyhow!("Failed to initialize logging: {}", e))
+}
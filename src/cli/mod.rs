@@ -1,11 +1,20 @@
 mod args;
 pub mod completions;
+pub mod config;
+pub mod doctor;
 pub mod execute;
 pub mod init;
 pub mod list;
+pub mod logging;
 pub mod plugins;
+pub mod scaffold;
+pub mod test;
 pub mod validate;
+pub mod watch;
 
-pub use args::{Args, Commands, ExecuteArgs, ListArgs, PluginsArgs};
+pub use args::{
+    Args, Commands, ConfigArgs, ConfigCommand, ExecuteArgs, ItemSort, ListArgs, LogLevel,
+    OutputFormat, PluginsArgs, PluginsCommand, SandboxMode, SortKey, TestArgs, ValidateArgs,
+};
 pub use list::list_cli;
-pub use plugins::handle_plugins_command;
+pub use plugins::{handle_plugins_command, sync_due_plugins};
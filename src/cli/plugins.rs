@@ -1,11 +1,30 @@
 use std::{
-    collections::HashSet,
-    fs,
-    io::{self, Write},
+    collections::{HashMap, HashSet},
+    env, fs,
+    io::{self, IsTerminal, Write},
     path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
 
-use crate::{Config, cli::PluginsArgs, configs::paths::resolve_plugin_paths, plugins::git_ops};
+use crossterm::{
+    style::{Color as CrosstermColor, Stylize},
+    terminal,
+};
+use ratatui::{backend::IntoCrossterm, style::Color};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{
+    Config,
+    cli::{PluginsArgs, PluginsCommand, scaffold::scaffold_plugin},
+    configs::paths::resolve_plugin_paths,
+    execution::{EXIT_FAILURE, EXIT_SUCCESS},
+    lua::{create_lua_vm, set_plugin_configs},
+    plugins::{Plugin, PluginLoadStatus, git_ops, load_plugins_with_report},
+    tui::views::style::colors::parse_color,
+};
 use anyhow::{Context, Result, bail, ensure};
 
 struct PluginPaths {
@@ -14,27 +33,43 @@ struct PluginPaths {
 }
 
 fn resolve_plugin_directories() -> Result<PluginPaths> {
-    let resolved = resolve_plugin_paths()?;
-
-    match resolved.len() {
-        1 => Ok(PluginPaths {
-            user: resolved[0].clone(),
-            managed: resolved[0].clone(),
-        }),
-        2 => Ok(PluginPaths {
-            user: resolved[0].clone(),
-            managed: resolved[1].clone(),
+    // Plugins are only installed to and managed in the default directories, the system
+    // config directories in between are left to administrators
+    let resolved = resolve_plugin_paths(&[])?;
+
+    match (resolved.first(), resolved.last()) {
+        (Some(user), Some(managed)) => Ok(PluginPaths {
+            user: user.clone(),
+            managed: managed.clone(),
         }),
         _ => bail!("Invalid plugin path resolution"),
     }
 }
 
-pub fn handle_plugins_command(plugin_params: &PluginsArgs, config: Config) -> Result<()> {
+/// Runs the `plugins` operation and returns the exit code, only `--check` exits non-zero
+/// without an error
+pub fn handle_plugins_command(plugin_params: &PluginsArgs, config: Config) -> Result<i32> {
+    if let Some(PluginsCommand::Scaffold { name, minimal }) = &plugin_params.command {
+        let paths = resolve_plugin_directories()?;
+        let created = scaffold_plugin(&paths.managed, name, &config.default_plugin_icon, *minimal)?;
+        println!("Created plugin '{}':", name);
+        for path in created {
+            println!("  {}", path.display());
+        }
+        return Ok(EXIT_SUCCESS);
+    }
+
+    if let Some(PluginsCommand::Sync { plugin }) = &plugin_params.command {
+        let paths = resolve_plugin_directories()?;
+        return sync_plugins(&config, &paths, plugin.as_deref());
+    }
+
     let flags_set = [
         plugin_params.remove,
         plugin_params.install,
         plugin_params.upgrade,
         plugin_params.list,
+        plugin_params.check,
     ]
     .iter()
     .filter(|&&flag| flag)
@@ -42,13 +77,17 @@ pub fn handle_plugins_command(plugin_params: &PluginsArgs, config: Config) -> Re
 
     ensure!(
         flags_set == 1,
-        "Exactly one operation flag must be specified (--install, --remove, --upgrade, or --list)"
+        "Exactly one operation flag must be specified (--install, --remove, --upgrade, --list, or --check)"
     );
 
     if plugin_params.plugin.is_some() && !plugin_params.upgrade {
         bail!("--plugin can only be used with --upgrade")
     }
 
+    if plugin_params.check {
+        return check_plugins(&config);
+    }
+
     let paths = resolve_plugin_directories()?;
 
     if plugin_params.remove {
@@ -58,10 +97,73 @@ pub fn handle_plugins_command(plugin_params: &PluginsArgs, config: Config) -> Re
     } else if plugin_params.upgrade {
         upgrade_plugins(config, &paths, &plugin_params.plugin)?
     } else if plugin_params.list {
-        list_plugins(config, &paths)?
+        list_plugins(config, &paths, plugin_params.no_color)?
     }
 
-    Ok(())
+    Ok(EXIT_SUCCESS)
+}
+
+const EXIT_CHECK_WARNINGS: i32 = 2;
+
+// Loads the plugins syntropy would load and prints one line per plugin. Failing to load is
+// an error, being skipped on this platform a warning
+fn check_plugins(config: &Config) -> Result<i32> {
+    let plugin_paths =
+        resolve_plugin_paths(&config.plugin_paths).context("Failed to resolve plugin paths")?;
+
+    let lua = create_lua_vm()?;
+    set_plugin_configs(&lua, config.plugin_config.clone());
+    let (_plugins, records) =
+        load_plugins_with_report(&plugin_paths, config, Arc::new(Mutex::new(lua)))
+            .context("Failed to load plugins")?;
+
+    if records.is_empty() {
+        println!("No plugins found.");
+        return Ok(EXIT_SUCCESS);
+    }
+
+    let name_width = records.iter().map(|r| r.name.width()).max().unwrap_or(0);
+    let (mut errors, mut warnings) = (0, 0);
+    for record in &records {
+        let (symbol, status, detail) = match &record.status {
+            PluginLoadStatus::Ok => ("✓", "ok", None),
+            PluginLoadStatus::Error(e) => {
+                errors += 1;
+                ("✗", "error", Some(e))
+            }
+            PluginLoadStatus::PlatformUnsupported(e) => {
+                warnings += 1;
+                ("⚠", "warning", Some(e))
+            }
+        };
+        println!(
+            "{} {}  {:<7}  {:>6.1}ms",
+            symbol,
+            pad(&record.name, name_width),
+            status,
+            record.load_time.as_secs_f64() * 1000.0
+        );
+        if let Some(detail) = detail {
+            println!("    {}", detail);
+        }
+    }
+
+    println!();
+    println!(
+        "{} plugins checked: {} ok, {} with errors, {} with warnings",
+        records.len(),
+        records.len() - errors - warnings,
+        errors,
+        warnings
+    );
+
+    Ok(if errors > 0 {
+        EXIT_FAILURE
+    } else if warnings > 0 {
+        EXIT_CHECK_WARNINGS
+    } else {
+        EXIT_SUCCESS
+    })
 }
 
 fn get_plugin_names_in_dir(dir: &PathBuf) -> Result<Vec<String>> {
@@ -84,7 +186,204 @@ fn get_plugin_names_in_dir(dir: &PathBuf) -> Result<Vec<String>> {
     Ok(plugins)
 }
 
-fn list_plugins(config: Config, paths: &PluginPaths) -> Result<()> {
+// Columns of a `plugins --list` row, "-" for plugins that failed to load
+struct PluginRow {
+    icon: String,
+    name: String,
+    version: String,
+    platforms: String,
+    tasks: String,
+    status: String,
+    description: String,
+}
+
+const PLUGIN_TABLE_HEADER: [&str; 5] = ["NAME", "VERSION", "PLATFORMS", "TASKS", "STATUS"];
+
+// Color of plugin icons and names, and the width descriptions are truncated to. Both are
+// only set when stdout is a terminal
+struct PluginTableStyle {
+    color: Option<CrosstermColor>,
+    width: Option<usize>,
+}
+
+impl PluginTableStyle {
+    fn new(config: &Config, no_color: bool) -> Result<Self> {
+        if !io::stdout().is_terminal() {
+            return Ok(Self {
+                color: None,
+                width: None,
+            });
+        }
+
+        let color = if no_color || env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            None
+        } else {
+            parse_color(&config.styles.colors.highlights_text)
+                .context("Invalid highlights_text color")?
+                .filter(|color| *color != Color::Reset)
+                .map(|color| color.into_crossterm())
+        };
+
+        Ok(Self {
+            color,
+            width: terminal::size().ok().map(|(columns, _)| columns as usize),
+        })
+    }
+}
+
+// Loads every plugin of the user and managed directories, keyed by plugin directory name.
+// A user plugin overriding a managed one is merged with it, as when running syntropy
+fn load_plugin_infos(config: &Config, paths: &PluginPaths) -> Result<HashMap<String, Plugin>> {
+    let lua = create_lua_vm()?;
+    set_plugin_configs(&lua, config.plugin_config.clone());
+    let lua_runtime = Arc::new(Mutex::new(lua));
+    let (plugins, records) = load_plugins_with_report(
+        &[paths.user.clone(), paths.managed.clone()],
+        config,
+        lua_runtime,
+    )?;
+
+    let mut infos = HashMap::new();
+    for record in records {
+        let Some(plugin) = plugins.iter().find(|p| p.metadata.name == record.name) else {
+            continue;
+        };
+        for path in &record.paths {
+            if let Some(dir_name) = path
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .and_then(|name| name.to_str())
+            {
+                infos.insert(dir_name.to_string(), plugin.clone());
+            }
+        }
+    }
+    Ok(infos)
+}
+
+fn plugin_row(name: &str, infos: &HashMap<String, Plugin>, status: String) -> PluginRow {
+    match infos.get(name) {
+        Some(plugin) => PluginRow {
+            icon: plugin.metadata.icon.clone(),
+            name: name.to_string(),
+            version: plugin.metadata.version.clone(),
+            platforms: if plugin.metadata.platforms.is_empty() {
+                "all".to_string()
+            } else {
+                plugin.metadata.platforms.join(",")
+            },
+            tasks: plugin.tasks.len().to_string(),
+            status,
+            description: plugin.metadata.description.clone(),
+        },
+        None => PluginRow {
+            icon: " ".to_string(),
+            name: name.to_string(),
+            version: "-".to_string(),
+            platforms: "-".to_string(),
+            tasks: "-".to_string(),
+            status: if status.is_empty() {
+                "⚠ failed to load".to_string()
+            } else {
+                format!("{}, ⚠ failed to load", status)
+            },
+            description: String::new(),
+        },
+    }
+}
+
+fn pad(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(text.width())))
+}
+
+// Cuts `text` to `width` terminal cells, ending it with an ellipsis when cut
+fn truncate(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    for c in text.chars() {
+        if truncated.width() + c.width().unwrap_or(0) + 1 > width {
+            break;
+        }
+        truncated.push(c);
+    }
+    truncated.push('…');
+    truncated
+}
+
+// Prints the sections as one table, columns aligned across every section
+fn print_plugin_table(sections: &[(&str, Vec<PluginRow>)], style: &PluginTableStyle) {
+    let rows = sections.iter().flat_map(|(_, rows)| rows);
+    let mut widths = PLUGIN_TABLE_HEADER.map(str::width);
+    for row in rows {
+        let columns = [
+            &row.name,
+            &row.version,
+            &row.platforms,
+            &row.tasks,
+            &row.status,
+        ];
+        for (width, column) in widths.iter_mut().zip(columns) {
+            *width = (*width).max(column.width());
+        }
+    }
+
+    // Two spaces of indentation, the single cell icon and a space before the name
+    let prefix_width = 4;
+    let description_offset = prefix_width + widths.iter().map(|width| width + 2).sum::<usize>();
+    let description_width = style
+        .width
+        .map(|width| width.saturating_sub(description_offset));
+
+    let header: Vec<String> = PLUGIN_TABLE_HEADER
+        .iter()
+        .zip(widths)
+        .map(|(title, width)| pad(title, width))
+        .collect();
+    println!("    {}  DESCRIPTION", header.join("  "));
+
+    for (title, rows) in sections {
+        if rows.is_empty() {
+            continue;
+        }
+        println!("{}:", title);
+        for row in rows {
+            let description = match description_width {
+                Some(width) => truncate(&row.description, width),
+                None => row.description.clone(),
+            };
+            let (icon, name) = match style.color {
+                Some(color) => (
+                    row.icon.as_str().with(color).to_string(),
+                    pad(&row.name, widths[0])
+                        .as_str()
+                        .with(color)
+                        .bold()
+                        .to_string(),
+                ),
+                None => (row.icon.clone(), pad(&row.name, widths[0])),
+            };
+            let line = format!(
+                "  {} {}  {}  {}  {}  {}  {}",
+                icon,
+                name,
+                pad(&row.version, widths[1]),
+                pad(&row.platforms, widths[2]),
+                pad(&row.tasks, widths[3]),
+                pad(&row.status, widths[4]),
+                description
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+}
+
+fn list_plugins(config: Config, paths: &PluginPaths, no_color: bool) -> Result<()> {
     let user_plugins = get_plugin_names_in_dir(&paths.user)?;
     let managed_plugins = get_plugin_names_in_dir(&paths.managed)?;
     let declared_plugins: HashSet<_> = config.plugins.keys().collect();
@@ -93,64 +392,64 @@ fn list_plugins(config: Config, paths: &PluginPaths) -> Result<()> {
     println!("Managed plugins installed at: {:?}", &paths.managed);
     println!();
 
-    if !user_plugins.is_empty() {
-        println!("User plugins:");
-        for plugin in &user_plugins {
-            let warning = if managed_plugins.contains(plugin) {
-                " ⚠ overrides managed plugin"
-            } else {
-                ""
-            };
-            println!("  {}{}", plugin, warning);
-        }
-        println!();
+    if user_plugins.is_empty() && managed_plugins.is_empty() {
+        println!("No plugins found.");
+        return Ok(());
     }
 
-    let managed_with_decl: Vec<_> = managed_plugins
+    let style = PluginTableStyle::new(&config, no_color)?;
+    let infos = load_plugin_infos(&config, paths)?;
+
+    let user_rows = user_plugins
         .iter()
-        .filter(|name| declared_plugins.contains(name))
+        .map(|plugin| {
+            let status = if managed_plugins.contains(plugin) {
+                "⚠ overrides managed plugin".to_string()
+            } else {
+                String::new()
+            };
+            plugin_row(plugin, &infos, status)
+        })
         .collect();
 
-    if !managed_with_decl.is_empty() {
-        println!("Managed plugins:");
-        for plugin in managed_with_decl {
+    let managed_rows = managed_plugins
+        .iter()
+        .filter(|name| declared_plugins.contains(name))
+        .map(|plugin| {
             let decl = &config.plugins[plugin];
-            let version_info = match (&decl.tag, &decl.commit) {
-                (Some(tag), None) => format!("tag={}", tag),
-                (None, Some(commit)) => {
+            let version_info = match (&decl.tag, &decl.commit, &decl.branch) {
+                (Some(tag), None, None) => format!("tag={}", tag),
+                (None, Some(commit), None) => {
                     let short_commit = &commit[..8.min(commit.len())];
                     format!("commit={}", short_commit)
                 }
+                (None, None, Some(branch)) => format!("branch={}", branch),
                 _ => "unknown".to_string(),
             };
 
-            let override_warning = if user_plugins.contains(plugin) {
-                " (overridden by user plugin)"
+            let status = if user_plugins.contains(plugin) {
+                format!("{} (overridden by user plugin)", version_info)
             } else {
-                ""
+                version_info
             };
+            plugin_row(plugin, &infos, status)
+        })
+        .collect();
 
-            println!("  {} ({}){}", plugin, version_info, override_warning);
-        }
-        println!();
-    }
-
-    let orphaned: Vec<_> = managed_plugins
+    let orphaned_rows = managed_plugins
         .iter()
         .filter(|name| !declared_plugins.contains(name))
+        .map(|plugin| plugin_row(plugin, &infos, "← candidate for removal".to_string()))
         .collect();
 
-    if !orphaned.is_empty() {
-        println!("Orphaned plugins:");
-        for plugin in orphaned {
-            println!("  {} ← candidate for removal", plugin);
-        }
-        println!();
-    }
-
-    if user_plugins.is_empty() && managed_plugins.is_empty() {
-        println!("No plugins found.");
-    }
+    print_plugin_table(
+        &[
+            ("User plugins", user_rows),
+            ("Managed plugins", managed_rows),
+            ("Orphaned plugins", orphaned_rows),
+        ],
+        &style,
+    );
 
     Ok(())
 }
@@ -180,13 +479,9 @@ fn install_plugins(config: Config, paths: &PluginPaths) -> Result<()> {
 
         let plugin_dir = data_dir.join(name);
 
-        let ref_spec = match (&decl.tag, &decl.commit) {
-            (Some(tag), None) => tag,
-            (None, Some(commit)) => commit,
-            _ => {
-                println!("ERROR: must specify either tag or commit");
-                continue;
-            }
+        let Some(ref_spec) = decl.ref_spec() else {
+            println!("ERROR: must specify either tag or commit, or a branch");
+            continue;
         };
 
         match git_ops::clone_plugin(&decl.git, &plugin_dir, ref_spec) {
@@ -291,6 +586,14 @@ fn upgrade_plugins(config: Config, paths: &PluginPaths, plugin: &Option<String>)
             continue;
         }
 
+        if decl.branch.is_some() {
+            println!(
+                "  {} - declaration tracks a branch, use `syntropy plugins sync`",
+                name
+            );
+            continue;
+        }
+
         let declared_tag = decl
             .tag
             .as_ref()
@@ -334,6 +637,86 @@ fn upgrade_plugins(config: Config, paths: &PluginPaths, plugin: &Option<String>)
     Ok(())
 }
 
+// Pulls the tracked branch of every declared plugin, or only of `plugin`. Plugins pinned
+// to a tag or commit are left to --upgrade.
+fn sync_plugins(config: &Config, paths: &PluginPaths, plugin: Option<&str>) -> Result<i32> {
+    let mut names: Vec<&String> = match plugin {
+        Some(name) => {
+            let (name, _) = config
+                .plugins
+                .get_key_value(name)
+                .with_context(|| format!("Plugin '{}' not declared in config", name))?;
+            vec![name]
+        }
+        None => config.plugins.keys().collect(),
+    };
+    names.sort();
+
+    fs::create_dir_all(&paths.managed).context("Failed to create data plugins directory")?;
+    println!("Syncing {} plugin(s)...", names.len());
+
+    let mut failed = 0;
+    for name in names {
+        let decl = &config.plugins[name];
+        let Some(branch) = &decl.branch else {
+            println!(
+                "  {} - pinned to {}, skipping",
+                name,
+                decl.ref_spec().unwrap_or("nothing")
+            );
+            continue;
+        };
+
+        print!("  {} ... ", name);
+        io::stdout().flush()?;
+        match git_ops::clone_or_update_plugin(&decl.git, &paths.managed.join(name), branch) {
+            Ok(git_ops::SyncOutcome::Cloned) => println!("✓ installed ({})", branch),
+            Ok(git_ops::SyncOutcome::Updated) => println!("✓ updated ({})", branch),
+            Err(e) => {
+                failed += 1;
+                println!("✗ failed: {:#}", e);
+            }
+        }
+    }
+
+    Ok(if failed > 0 {
+        EXIT_FAILURE
+    } else {
+        EXIT_SUCCESS
+    })
+}
+
+/// Pulls installed plugins tracking a branch whose `sync_interval` elapsed since their last
+/// fetch. Runs before plugins load when the TUI starts, failures are logged and the plugin
+/// loads as it is.
+pub fn sync_due_plugins(config: &Config) {
+    let Ok(paths) = resolve_plugin_directories() else {
+        return;
+    };
+    let now = SystemTime::now();
+
+    for (name, decl) in &config.plugins {
+        let (Some(branch), Some(interval)) = (&decl.branch, decl.sync_interval) else {
+            continue;
+        };
+        let plugin_dir = paths.managed.join(name);
+        if !git_ops::is_git_repo(&plugin_dir)
+            || !git_ops::sync_due(
+                git_ops::last_sync_time(&plugin_dir),
+                now,
+                Duration::from_secs(interval),
+            )
+        {
+            continue;
+        }
+
+        match git_ops::clone_or_update_plugin(&decl.git, &plugin_dir, branch) {
+            Ok(_) => debug!(plugin = %name, branch = %branch, "Synced plugin"),
+            Err(e) => warn!(plugin = %name, error = %format!("{:#}", e), "Failed to sync plugin"),
+        }
+    }
+}
+
 pub fn compare_tags(tag1: &str, tag2: &str) -> std::cmp::Ordering {
     use semver::Version;
 
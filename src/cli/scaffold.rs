@@ -0,0 +1,167 @@
+use anyhow::{Context, Result, bail, ensure};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const PLUGIN_LUA_FILE: &str = "plugin.lua";
+const UTIL_MODULE_FILE: &str = "util.lua";
+const TEST_FILE: &str = "test_plugin.lua";
+
+// `{name}` and `{icon}` are replaced with the plugin name and icon
+const PLUGIN_TEMPLATE: &str = r#"local util = require("{name}.util")
+
+---@type PluginDefinition
+return {
+    metadata = {
+        name = "{name}",
+        version = "0.1.0",
+        icon = "{icon}",
+        description = "Describe what {name} does",
+        -- Platforms the plugin runs on, it is skipped on the others
+        platforms = { "macos", "linux" },
+    },
+    tasks = {
+        example = {
+            name = "Example",
+            description = "Lists example items and echoes the selected ones",
+            -- "multi" lets the user select several items, "single" executes one item
+            mode = "multi",
+
+            -- Runs before items are fetched
+            pre_run = function() end,
+
+            -- Runs after execution completes
+            post_run = function() end,
+
+            item_sources = {
+                examples = {
+                    tag = "ex",
+
+                    -- Returns the items listed for this source
+                    items = function()
+                        return util.example_items()
+                    end,
+
+                    -- Returns the preview shown for the highlighted item
+                    preview = function(item)
+                        return "Preview of " .. item
+                    end,
+
+                    -- Runs the task on the selected items, returns output and exit code
+                    execute = function(items)
+                        return util.describe_selection(items), 0
+                    end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const MINIMAL_PLUGIN_TEMPLATE: &str = r#"---@type PluginDefinition
+return {
+    metadata = {
+        name = "{name}",
+        version = "0.1.0",
+    },
+    tasks = {
+        example = {
+            description = "Runs the example task",
+            execute = function()
+                return "Hello from {name}", 0
+            end,
+        },
+    },
+}
+"#;
+
+const UTIL_MODULE_TEMPLATE: &str = r#"-- Modules in lua/{name}/ are required as require("{name}.<module>")
+local M = {}
+
+function M.example_items()
+    return { "first", "second", "third" }
+end
+
+function M.describe_selection(items)
+    return "Selected: " .. table.concat(items, ", ")
+end
+
+return M
+"#;
+
+const TEST_TEMPLATE: &str = r#"-- Run with: syntropy test --plugin {name}
+local util = require("{name}.util")
+
+describe("util", function()
+    it("lists example items", function()
+        assert_contains(util.example_items(), "first")
+    end)
+
+    it("describes the selection", function()
+        assert_equal(util.describe_selection({ "a", "b" }), "Selected: a, b")
+    end)
+end)
+"#;
+
+/// Creates the skeleton of plugin `name` in `plugins_dir`: `plugin.lua`, a module in
+/// `lua/<name>/` and `tests/test_plugin.lua`. `minimal` writes a plugin with only the
+/// required fields.
+///
+/// Returns the created files, fails without writing anything when the plugin directory
+/// already exists.
+pub fn scaffold_plugin(
+    plugins_dir: &Path,
+    name: &str,
+    icon: &str,
+    minimal: bool,
+) -> Result<Vec<PathBuf>> {
+    validate_plugin_name(name)?;
+
+    let plugin_dir = plugins_dir.join(name);
+    if plugin_dir.exists() {
+        bail!(
+            "Plugin directory already exists at {}",
+            plugin_dir.display()
+        );
+    }
+
+    let plugin_template = if minimal {
+        MINIMAL_PLUGIN_TEMPLATE
+    } else {
+        PLUGIN_TEMPLATE
+    };
+    let files = [
+        (plugin_dir.join(PLUGIN_LUA_FILE), plugin_template),
+        (
+            plugin_dir.join("lua").join(name).join(UTIL_MODULE_FILE),
+            UTIL_MODULE_TEMPLATE,
+        ),
+        (plugin_dir.join("tests").join(TEST_FILE), TEST_TEMPLATE),
+    ];
+
+    let mut created = Vec::new();
+    for (path, template) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let content = template.replace("{name}", name).replace("{icon}", icon);
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+// The name is a directory and the namespace of `require("<name>.module")`
+fn validate_plugin_name(name: &str) -> Result<()> {
+    ensure!(!name.is_empty(), "Plugin name must not be empty");
+    ensure!(
+        name.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "Invalid plugin name '{}': use only letters, digits, '-' and '_'",
+        name
+    );
+    Ok(())
+}
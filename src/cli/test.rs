@@ -0,0 +1,283 @@
+use anyhow::{Context, Result, ensure};
+use mlua::{Function, Lua, Table};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{lua::create_lua_vm_with_sandbox, plugins::ModulePathBuilder};
+
+const TEST_DIR: &str = "tests";
+
+/// Test framework injected into every test file: `describe`, `it`, `assert_equal` and
+/// `assert_contains`. Returns the list `it` registers `{name, fn}` entries in.
+const TEST_FRAMEWORK_LUA: &str = r#"
+local tests = {}
+local scopes = {}
+
+local function describe_value(value)
+    if type(value) == "string" then
+        return string.format("%q", value)
+    end
+    return tostring(value)
+end
+
+local function deep_equal(a, b)
+    if a == b then
+        return true
+    end
+    if type(a) ~= "table" or type(b) ~= "table" then
+        return false
+    end
+    for k, v in pairs(a) do
+        if not deep_equal(v, b[k]) then
+            return false
+        end
+    end
+    for k in pairs(b) do
+        if a[k] == nil then
+            return false
+        end
+    end
+    return true
+end
+
+local function fail(message, detail)
+    if message then
+        detail = message .. ": " .. detail
+    end
+    error(detail, 3)
+end
+
+-- Groups the tests registered by fn under name, groups nest
+function describe(name, fn)
+    table.insert(scopes, name)
+    fn()
+    table.remove(scopes)
+end
+
+-- Registers a test, run after the whole file was loaded
+function it(name, fn)
+    local parts = {table.unpack(scopes)}
+    table.insert(parts, name)
+    table.insert(tests, {name = table.concat(parts, " "), fn = fn})
+end
+
+-- Fails unless actual equals expected, tables are compared by their contents
+function assert_equal(actual, expected, message)
+    if not deep_equal(actual, expected) then
+        fail(message, "expected " .. describe_value(expected) .. ", got " .. describe_value(actual))
+    end
+end
+
+-- Fails unless the string haystack contains needle, or the table haystack a value equal to it
+function assert_contains(haystack, needle, message)
+    if type(haystack) == "string" then
+        if not string.find(haystack, needle, 1, true) then
+            fail(message, describe_value(haystack) .. " does not contain " .. describe_value(needle))
+        end
+        return
+    end
+    if type(haystack) == "table" then
+        for _, value in pairs(haystack) do
+            if deep_equal(value, needle) then
+                return
+            end
+        end
+        fail(message, "table does not contain " .. describe_value(needle))
+    end
+    fail(message, "expected a string or table to search, got " .. type(haystack))
+end
+
+return tests
+"#;
+
+/// Pass and fail counts of one `syntropy test` run
+#[derive(Debug, Default, PartialEq)]
+pub struct TestSummary {
+    pub files: usize,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Runs the `tests/*.lua` files of every plugin in `plugin_paths`, or only of the plugin
+/// directory named `plugin`, and prints the result of each test.
+///
+/// Every file runs in a fresh Lua VM that can `require` the modules of its plugin. A file
+/// that fails to load counts as one failed test.
+pub fn test_plugins_cli(
+    plugin_paths: &[PathBuf],
+    plugin: Option<&str>,
+    sandbox: bool,
+) -> Result<TestSummary> {
+    let plugin_dirs = find_plugin_dirs(plugin_paths, plugin)?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create test runtime")?;
+
+    let mut summary = TestSummary::default();
+    for plugin_dir in &plugin_dirs {
+        for test_file in find_test_files(plugin_dir)? {
+            let (passed, failed) = runtime.block_on(run_test_file(plugin_dir, &test_file, sandbox));
+            println!("  {} passed, {} failed", passed, failed);
+            println!();
+            summary.files += 1;
+            summary.passed += passed;
+            summary.failed += failed;
+        }
+    }
+
+    if summary.files == 0 {
+        println!("No test files found in {}/*.lua", TEST_DIR);
+    } else {
+        println!(
+            "{} passed, {} failed in {} files",
+            summary.passed, summary.failed, summary.files
+        );
+    }
+
+    Ok(summary)
+}
+
+// Plugin directories below `plugin_paths`, sorted, only the ones named `plugin` when set
+fn find_plugin_dirs(plugin_paths: &[PathBuf], plugin: Option<&str>) -> Result<Vec<PathBuf>> {
+    let mut plugin_dirs = Vec::new();
+    for plugin_path in plugin_paths {
+        if !plugin_path.exists() {
+            continue;
+        }
+
+        let mut entries: Vec<PathBuf> = fs::read_dir(plugin_path)
+            .with_context(|| {
+                format!(
+                    "Failed to read plugins directory at: {}",
+                    plugin_path.display()
+                )
+            })?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read directory entries")?
+            .into_iter()
+            .map(|entry| entry.path())
+            .filter(|path| path.join("plugin.lua").is_file())
+            .filter(|path| plugin.is_none_or(|name| path.file_name() == Some(name.as_ref())))
+            .collect();
+        entries.sort();
+        plugin_dirs.extend(entries);
+    }
+
+    if let Some(name) = plugin {
+        ensure!(
+            !plugin_dirs.is_empty(),
+            "Plugin '{}' not found in the plugin directories",
+            name
+        );
+    }
+
+    Ok(plugin_dirs)
+}
+
+fn find_test_files(plugin_dir: &Path) -> Result<Vec<PathBuf>> {
+    let test_dir = plugin_dir.join(TEST_DIR);
+    if !test_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&test_dir)
+        .with_context(|| format!("Failed to read test directory at: {}", test_dir.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to read directory entries")?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "lua"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+// Loads the file, runs every test it registered and returns the pass and fail counts
+async fn run_test_file(plugin_dir: &Path, test_file: &Path, sandbox: bool) -> (usize, usize) {
+    let label = test_file
+        .strip_prefix(plugin_dir.parent().unwrap_or(plugin_dir))
+        .unwrap_or(test_file);
+    println!("{}", label.display());
+
+    let lua = match create_test_vm(plugin_dir, sandbox) {
+        Ok(lua) => lua,
+        Err(e) => {
+            println!("  ✗ failed to load: {:#}", e);
+            return (0, 1);
+        }
+    };
+    let tests = match load_test_file(&lua, test_file).await {
+        Ok(tests) => tests,
+        Err(e) => {
+            println!("  ✗ failed to load: {}", first_line(&format!("{:#}", e)));
+            return (0, 1);
+        }
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+    for (name, test_fn) in tests {
+        match test_fn.call_async::<()>(()).await {
+            Ok(()) => {
+                passed += 1;
+                println!("  ✓ {}", name);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("  ✗ {}: {}", name, first_line(&e.to_string()));
+            }
+        }
+    }
+    (passed, failed)
+}
+
+async fn load_test_file(lua: &Lua, test_file: &Path) -> Result<Vec<(String, Function)>> {
+    let tests: Table = lua.load(TEST_FRAMEWORK_LUA).eval()?;
+
+    let source = fs::read_to_string(test_file)
+        .with_context(|| format!("Failed to read test file '{}'", test_file.display()))?;
+    lua.load(source)
+        .set_name(format!("@{}", test_file.display()))
+        .exec_async()
+        .await?;
+
+    tests
+        .sequence_values::<Table>()
+        .map(|entry| {
+            let entry = entry?;
+            Ok((entry.get("name")?, entry.get("fn")?))
+        })
+        .collect()
+}
+
+// Fresh VM resolving `require` against the plugin's lua/ directory and, like the loader,
+// the shared modules next to it
+fn create_test_vm(plugin_dir: &Path, sandbox: bool) -> Result<Lua> {
+    let lua = create_lua_vm_with_sandbox(sandbox)?;
+
+    let plugin_dir_str = plugin_dir
+        .to_str()
+        .context("Plugin directory path contains invalid UTF-8")?;
+    let mut path_builder = ModulePathBuilder::default().with_plugin_dir(plugin_dir_str);
+    if let Some(plugins_dir) = plugin_dir.parent()
+        && plugins_dir
+            .file_name()
+            .is_some_and(|name| name == "plugins")
+        && let Some(root) = plugins_dir.parent().and_then(Path::to_str)
+    {
+        path_builder = path_builder.with_shared_modules(root);
+    }
+    path_builder
+        .apply(&lua)
+        .context("Failed to configure Lua module paths")?;
+
+    Ok(lua)
+}
+
+// Lua errors carry a stack traceback after the message
+fn first_line(message: &str) -> &str {
+    message.lines().next().unwrap_or_default()
+}
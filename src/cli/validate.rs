@@ -1,4 +1,6 @@
 use anyhow::{Context, Result, bail, ensure};
+use mlua::Lua;
+use serde::Serialize;
 use std::{
     env,
     path::{Path, PathBuf},
@@ -7,10 +9,12 @@ use std::{
 use crate::{
     configs::{
         expand_path, get_default_config_dir, get_default_data_dir, load_config, validate_config,
+        validate_keybindings,
     },
     lua::create_lua_vm,
     plugins::{
-        ModulePathBuilder, load_plugin, merge_and_validate_plugins, validate_plugin,
+        Mode, ModulePathBuilder, ValidationIssue, load_plugin, merge_and_validate_plugins,
+        plugin_issues, plugin_platform_issues, plugin_warnings, validate_plugin,
         validate_plugin_platform, validate_plugin_with_runtime,
     },
 };
@@ -88,21 +92,17 @@ fn find_merge_candidate(
     }
 }
 
-/// Validates a plugin at the specified path
-///
-/// Accepts either:
-/// - A directory containing plugin.lua
-/// - A direct path to plugin.lua
-///
-/// Performs complete validation including:
-/// - Lua syntax checking
-/// - Structure parsing
-/// - Metadata validation (name, version, icon)
-/// - Task validation (item sources, tags)
-///
-/// If the plugin is in a standard directory and has a merge candidate,
-/// validates the merged result instead of the standalone plugin.
-pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
+/// A plugin file located and set up for validation
+struct PreparedPlugin {
+    lua_runtime: Lua,
+    lua_path: PathBuf,
+    plugin_name: String,
+    location: PluginLocation,
+    merge_candidate: Option<PathBuf>,
+}
+
+/// Resolves a plugin directory or plugin.lua path to an existing plugin.lua
+fn resolve_plugin_file(plugin_path: PathBuf) -> Result<PathBuf> {
     let plugin_path = expand_path(plugin_path).context("Failed to expand plugin path")?;
 
     let lua_path = if plugin_path.is_dir() {
@@ -122,6 +122,13 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
         lua_path.display()
     );
 
+    Ok(lua_path)
+}
+
+/// Resolves the plugin.lua path, detects a merge candidate and configures module paths
+fn prepare_plugin_validation(plugin_path: PathBuf) -> Result<PreparedPlugin> {
+    let lua_path = resolve_plugin_file(plugin_path)?;
+
     // Detect if this plugin is part of a merge scenario
     let location = detect_plugin_location(&lua_path)?;
     let plugin_name = extract_plugin_name(&lua_path)?;
@@ -158,6 +165,38 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
         .apply(&lua_runtime)
         .context("Failed to configure Lua module paths")?;
 
+    Ok(PreparedPlugin {
+        lua_runtime,
+        lua_path,
+        plugin_name,
+        location,
+        merge_candidate,
+    })
+}
+
+/// Validates a plugin at the specified path
+///
+/// Accepts either:
+/// - A directory containing plugin.lua
+/// - A direct path to plugin.lua
+///
+/// Performs complete validation including:
+/// - Lua syntax checking
+/// - Structure parsing
+/// - Metadata validation (name, version, icon)
+/// - Task validation (item sources, tags)
+///
+/// If the plugin is in a standard directory and has a merge candidate,
+/// validates the merged result instead of the standalone plugin.
+pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
+    let PreparedPlugin {
+        lua_runtime,
+        lua_path,
+        plugin_name,
+        location,
+        merge_candidate,
+    } = prepare_plugin_validation(plugin_path)?;
+
     if let Some(candidate_path) = merge_candidate {
         // MERGED VALIDATION
         let (base_path, override_path) = match detect_plugin_location(&lua_path)? {
@@ -179,8 +218,14 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
         println!("  ✓ Found override at {}", override_path.display());
 
         // Validate base plugin first
-        let base_plugin = load_plugin(&lua_runtime, &base_path, DEFAULT_PLUGIN_ICON, None)
-            .with_context(|| format!("Failed to load base plugin from {}", base_path.display()))?;
+        let base_plugin = load_plugin(
+            &lua_runtime,
+            &base_path,
+            DEFAULT_PLUGIN_ICON,
+            &Mode::default(),
+            None,
+        )
+        .with_context(|| format!("Failed to load base plugin from {}", base_path.display()))?;
 
         if let Err(e) = validate_plugin(&base_plugin) {
             bail!(
@@ -196,6 +241,7 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
             &override_path,
             &plugin_name,
             DEFAULT_PLUGIN_ICON,
+            &Mode::default(),
         )
         .context("Failed to merge and validate plugins")?;
 
@@ -208,6 +254,9 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
         validation_runtime
             .block_on(async { validate_plugin_with_runtime(&lua_runtime, &merged_plugin).await })?;
 
+        for warning in plugin_warnings(&merged_plugin) {
+            eprintln!("⚠ {}", warning);
+        }
         println!(
             "✓ Plugin '{}' (v{}) is valid (merged configuration)",
             merged_plugin.metadata.name, merged_plugin.metadata.version
@@ -218,8 +267,14 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
             println!("ℹ Plugin not in standard directory - validating as standalone");
         }
 
-        let plugin = load_plugin(&lua_runtime, &lua_path, DEFAULT_PLUGIN_ICON, None)
-            .context("Failed to load plugin")?;
+        let plugin = load_plugin(
+            &lua_runtime,
+            &lua_path,
+            DEFAULT_PLUGIN_ICON,
+            &Mode::default(),
+            None,
+        )
+        .context("Failed to load plugin")?;
 
         validate_plugin(&plugin)
             .with_context(|| format!("validation failed for plugin {}", plugin.metadata.name))?;
@@ -242,6 +297,9 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
                 )
             })?;
 
+        for warning in plugin_warnings(&plugin) {
+            eprintln!("⚠ {}", warning);
+        }
         println!(
             "✓ Plugin '{}' (v{}) is valid",
             plugin.metadata.name, plugin.metadata.version
@@ -251,6 +309,150 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
     Ok(())
 }
 
+/// Directories `validate --plugin --watch` watches: the plugin's own directory and the
+/// directory of its merge candidate, if any
+pub fn plugin_watch_paths(plugin_path: PathBuf) -> Result<Vec<PathBuf>> {
+    let lua_path = resolve_plugin_file(plugin_path)?;
+    let plugin_name = extract_plugin_name(&lua_path)?;
+    let merge_candidate = find_merge_candidate(&plugin_name, detect_plugin_location(&lua_path)?)?;
+
+    Ok([Some(lua_path), merge_candidate]
+        .into_iter()
+        .flatten()
+        .filter_map(|path| path.parent().map(Path::to_path_buf))
+        .collect())
+}
+
+/// One issue of `validate --format json`
+#[derive(Serialize)]
+struct IssueEntry {
+    code: &'static str,
+    message: String,
+}
+
+impl From<ValidationIssue> for IssueEntry {
+    fn from(issue: ValidationIssue) -> Self {
+        Self {
+            code: issue.code(),
+            message: issue.to_string(),
+        }
+    }
+}
+
+/// Validation result of one plugin for `validate --format json`
+///
+/// Errors keep the plugin from loading, warnings are runtime function type problems and
+/// deprecated values of a plugin that loads fine.
+#[derive(Serialize)]
+struct PluginValidationReport {
+    plugin: String,
+    errors: Vec<IssueEntry>,
+    warnings: Vec<IssueEntry>,
+}
+
+/// Validates a plugin like [`validate_plugin_cli`] and prints the result as a JSON array
+///
+/// Every structural issue is reported instead of only the first one. Returns whether the
+/// plugin is free of errors, warnings don't make it invalid.
+pub fn validate_plugin_json(plugin_path: PathBuf) -> Result<bool> {
+    let PreparedPlugin {
+        lua_runtime,
+        lua_path,
+        plugin_name,
+        location,
+        merge_candidate,
+    } = prepare_plugin_validation(plugin_path)?;
+
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+
+    let plugin = if let Some(candidate_path) = merge_candidate {
+        let (base_path, override_path) = match location {
+            PluginLocation::ConfigDir => (candidate_path, lua_path),
+            _ => (lua_path, candidate_path),
+        };
+        match load_plugin(
+            &lua_runtime,
+            &base_path,
+            DEFAULT_PLUGIN_ICON,
+            &Mode::default(),
+            None,
+        ) {
+            Ok(base_plugin) => errors.extend(plugin_issues(&base_plugin)),
+            Err(e) => errors.push(ValidationIssue::from_error(&e)),
+        }
+        if errors.is_empty() {
+            merge_and_validate_plugins(
+                &lua_runtime,
+                &base_path,
+                &override_path,
+                &plugin_name,
+                DEFAULT_PLUGIN_ICON,
+                &Mode::default(),
+            )
+            .inspect_err(|e| errors.push(ValidationIssue::from_error(e)))
+            .ok()
+        } else {
+            None
+        }
+    } else {
+        match load_plugin(
+            &lua_runtime,
+            &lua_path,
+            DEFAULT_PLUGIN_ICON,
+            &Mode::default(),
+            None,
+        ) {
+            Ok(plugin) => {
+                errors.extend(plugin_issues(&plugin));
+                errors.extend(plugin_platform_issues(&plugin));
+                Some(plugin)
+            }
+            Err(e) => {
+                errors.push(ValidationIssue::from_error(&e));
+                None
+            }
+        }
+    };
+
+    let name = match &plugin {
+        Some(plugin) if !plugin.metadata.name.is_empty() => plugin.metadata.name.clone(),
+        _ => plugin_name,
+    };
+
+    if let Some(plugin) = &plugin {
+        warnings.extend(plugin_warnings(plugin));
+    }
+
+    if let Some(plugin) = plugin
+        && errors.is_empty()
+    {
+        // Runtime function type validation (requires Tokio runtime for async operations)
+        let validation_runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("Failed to create validation runtime")?;
+
+        if let Err(e) = validation_runtime
+            .block_on(async { validate_plugin_with_runtime(&lua_runtime, &plugin).await })
+        {
+            warnings.push(ValidationIssue::InvalidFunctionType(format!("{:#}", e)));
+        }
+    }
+
+    let is_valid = errors.is_empty();
+    let report = PluginValidationReport {
+        plugin: name,
+        errors: errors.into_iter().map(IssueEntry::from).collect(),
+        warnings: warnings.into_iter().map(IssueEntry::from).collect(),
+    };
+    let json =
+        serde_json::to_string_pretty(&[report]).context("Failed to serialize validation report")?;
+    println!("{}", json);
+
+    Ok(is_valid)
+}
+
 /// Validates a config file at the specified path
 ///
 /// Performs complete validation including:
@@ -259,6 +461,7 @@ pub fn validate_plugin_cli(plugin_path: PathBuf) -> Result<()> {
 /// - Style split percentages (must sum to 100)
 /// - Modal size constraints (< 100)
 /// - Default plugin icon width (must be 1 cell)
+/// - Key bindings (no key bound twice), warning about keys the search bar captures
 ///
 /// Note: load_config() already performs validation internally,
 /// so we don't need to call validate_config() separately.
@@ -281,6 +484,12 @@ pub fn validate_config_cli(config_path: PathBuf) -> Result<()> {
 
     validate_config(&config)?;
 
+    if config.search_bar {
+        for warning in validate_keybindings(&config.keybindings)? {
+            eprintln!("⚠ {}", warning);
+        }
+    }
+
     println!("✓ Config file is valid");
 
     Ok(())
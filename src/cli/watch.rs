@@ -0,0 +1,153 @@
+use anyhow::{Context, Result, bail};
+use chrono::Local;
+use crossterm::{
+    cursor::MoveTo,
+    execute,
+    terminal::{Clear, ClearType},
+};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    io::stdout,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Quiet period after the last change before a run is triggered. Editors often write a file
+/// twice (or write a temp file and rename it), this collapses such bursts into one run.
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// How long the loop sleeps when no change is pending
+const IDLE_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// Collapses bursts of file changes into single triggers
+///
+/// Only `.lua` files count as changes. A trigger is ready once `delay` passed without a
+/// new change.
+#[derive(Debug)]
+pub struct Debouncer {
+    delay: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            last_change: None,
+        }
+    }
+
+    /// Records changed paths at `now`, returns whether any of them is a `.lua` file
+    pub fn record<P: AsRef<Path>>(&mut self, paths: &[P], now: Instant) -> bool {
+        let is_lua_change = paths
+            .iter()
+            .any(|path| path.as_ref().extension().is_some_and(|ext| ext == "lua"));
+        if is_lua_change {
+            self.last_change = Some(now);
+        }
+        is_lua_change
+    }
+
+    /// Time left until the pending trigger is ready, `None` when nothing is pending
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.last_change
+            .map(|last_change| self.delay.saturating_sub(now.duration_since(last_change)))
+    }
+
+    /// Returns `true` once per burst, when the quiet period after its last change is over
+    pub fn take_ready(&mut self, now: Instant) -> bool {
+        if self.remaining(now) == Some(Duration::ZERO) {
+            self.last_change = None;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+enum WatchMessage {
+    Changed(Vec<PathBuf>),
+    Interrupted,
+}
+
+/// Runs `run` once, then again after every debounced `.lua` change below `paths`
+///
+/// The terminal is cleared before each rerun and every run ends with a timestamped pass or
+/// fail line. Returns when Ctrl-C is pressed.
+pub fn watch_lua_files(paths: &[PathBuf], mut run: impl FnMut() -> Result<()>) -> Result<()> {
+    let (sender, receiver) = mpsc::channel();
+
+    let change_sender = sender.clone();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        // Access events would fire on every read, including the validation itself
+        if let Ok(event) = event
+            && matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            )
+        {
+            let _ = change_sender.send(WatchMessage::Changed(event.paths));
+        }
+    })
+    .context("Failed to create file watcher")?;
+
+    for path in paths {
+        watcher
+            .watch(path, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", path.display()))?;
+    }
+
+    thread::spawn(move || {
+        let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+        if runtime.block_on(tokio::signal::ctrl_c()).is_ok() {
+            let _ = sender.send(WatchMessage::Interrupted);
+        }
+    });
+
+    let watched: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    let watching = format!(
+        "Watching {} for changes, press Ctrl-C to stop",
+        watched.join(", ")
+    );
+    run_and_report(&mut run, &watching);
+
+    let mut debouncer = Debouncer::new(WATCH_DEBOUNCE);
+    loop {
+        let timeout = debouncer.remaining(Instant::now()).unwrap_or(IDLE_TIMEOUT);
+        match receiver.recv_timeout(timeout) {
+            Ok(WatchMessage::Changed(changed)) => {
+                debouncer.record(&changed, Instant::now());
+            }
+            Ok(WatchMessage::Interrupted) => break,
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => bail!("File watcher stopped unexpectedly"),
+        }
+
+        if debouncer.take_ready(Instant::now()) {
+            let _ = execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0));
+            run_and_report(&mut run, &watching);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_and_report(run: &mut impl FnMut() -> Result<()>, watching: &str) {
+    let result = run();
+    let timestamp = Local::now().format("%H:%M:%S");
+    match result {
+        Ok(()) => println!("[{}] ✓ Passed", timestamp),
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            println!("[{}] ✗ Failed", timestamp);
+        }
+    }
+    println!("{}", watching);
+}
@@ -1,56 +1,336 @@
-use std::{collections::HashMap, fs, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    configs::{KeyBindings, PluginDeclaration, Styles},
-    tui::key_bindings::ParsedKeyBindings,
+    cli::execute::did_you_mean,
+    configs::{KeyBindings, PluginDeclaration, Styles, style::BorderStyle},
+    plugins::Mode,
+    tui::{key_bindings::ParsedKeyBindings, preview_cache::DEFAULT_PREVIEW_CACHE_SIZE},
 };
-use anyhow::{Context, Result, ensure};
+use anyhow::{Context, Result, bail, ensure};
+use tracing::warn;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct Config {
+    /// Config files merged underneath this one, relative to its directory. Resolved by
+    /// [`load_config`], later files override earlier ones.
+    pub include: Vec<PathBuf>,
+    /// Plugin directories searched in addition to the default ones, relative to the config
+    /// file declaring them
+    pub plugin_paths: Vec<PathBuf>,
     pub plugins: HashMap<String, PluginDeclaration>,
+    /// Settings handed to each plugin through `syntropy.config()`, keyed by plugin name
+    pub plugin_config: HashMap<String, toml::Table>,
     pub default_plugin: Option<String>,
     pub default_task: Option<String>,
     pub default_plugin_icon: String,
+    /// Icon shown in the task list for tasks that don't declare one, none when empty
+    pub default_task_icon: String,
+    /// Mode of tasks that don't declare one
+    pub default_task_mode: Mode,
     pub keybindings: KeyBindings,
     pub styles: Styles,
     pub status_bar: bool,
     pub search_bar: bool,
+    /// Keep search queries in the data directory to recall them in later sessions
+    pub search_history: bool,
     pub show_preview_pane: bool,
+    /// Show the first line of each task's description under its name in the task list
+    pub show_task_description: bool,
     pub exit_on_execute: bool,
+    /// Lua file returning a function run before the `pre_run` of every task, relative to
+    /// the config file declaring it
+    pub global_pre_run: Option<PathBuf>,
+    /// Lua file returning a function run after the `post_run` of every task
+    pub global_post_run: Option<PathBuf>,
+    /// Move the list cursor from the last item to the first one and back
+    pub list_wrap: bool,
+    /// Use all configured colors even when `NO_COLOR` or `TERM` ask for fewer
+    pub force_color: bool,
+    /// Previews kept per item list, the least recently viewed ones are dropped first
+    pub preview_cache_size: usize,
+    pub editor: Option<String>,
+    pub editor_fallbacks: Vec<String>,
+    pub editor_overrides_env: bool,
+    /// Print a warning when an exit code outside 0-255 is clamped
+    pub warn_on_exit_clamp: bool,
+    /// Ignore keys the config doesn't know instead of rejecting them
+    pub allow_unknown_config_keys: bool,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            include: Vec::new(),
+            plugin_paths: Vec::new(),
             plugins: HashMap::default(),
+            plugin_config: HashMap::default(),
             default_plugin: None,
             default_task: None,
             default_plugin_icon: String::from("⚒"),
+            default_task_icon: String::new(),
+            default_task_mode: Mode::default(),
             keybindings: KeyBindings::default(),
             styles: Styles::default(),
             status_bar: true,
             search_bar: true,
+            search_history: false,
             show_preview_pane: true,
+            show_task_description: true,
             exit_on_execute: false,
+            global_pre_run: None,
+            global_post_run: None,
+            list_wrap: false,
+            force_color: false,
+            preview_cache_size: DEFAULT_PREVIEW_CACHE_SIZE,
+            editor: None,
+            editor_fallbacks: Vec::new(),
+            editor_overrides_env: false,
+            warn_on_exit_clamp: true,
+            allow_unknown_config_keys: false,
         }
     }
 }
 
 pub fn load_config(config_path: PathBuf) -> Result<Config> {
-    let contents = fs::read_to_string(&config_path)
+    let table = load_config_table(&config_path, &mut Vec::new())?;
+    parse_config_table(table, &config_path)
+}
+
+/// Loads the config at `config_path`, or the defaults when there is none, with the workspace
+/// config at `workspace_path` merged on top of it.
+///
+/// The workspace config may only set the keys in [`WORKSPACE_CONFIG_KEYS`]. Its
+/// `plugin_paths` are added to the ones of the config, everything else overrides it.
+pub fn load_config_with_workspace(
+    config_path: Option<&Path>,
+    workspace_path: &Path,
+) -> Result<Config> {
+    let mut table = match config_path {
+        Some(path) => load_config_table(path, &mut Vec::new())?,
+        None => toml::Table::new(),
+    };
+
+    let mut workspace = load_config_table(workspace_path, &mut Vec::new())?;
+    workspace.remove("include");
+    for key in workspace.keys() {
+        ensure!(
+            WORKSPACE_CONFIG_KEYS.contains(&key.as_str()),
+            "'{}' can't be set in workspace config {:?}, only {} can",
+            key,
+            workspace_path,
+            WORKSPACE_CONFIG_KEYS.join(", ")
+        );
+    }
+
+    if let Some(workspace_plugin_paths) = workspace.remove("plugin_paths") {
+        match (table.get_mut("plugin_paths"), workspace_plugin_paths) {
+            (
+                Some(toml::Value::Array(plugin_paths)),
+                toml::Value::Array(workspace_plugin_paths),
+            ) => {
+                plugin_paths.extend(workspace_plugin_paths);
+            }
+            (_, workspace_plugin_paths) => {
+                table.insert("plugin_paths".to_string(), workspace_plugin_paths);
+            }
+        }
+    }
+    merge_tables(&mut table, workspace);
+
+    parse_config_table(table, workspace_path)
+}
+
+fn parse_config_table(mut table: toml::Table, config_path: &Path) -> Result<Config> {
+    let allow_unknown_keys = matches!(
+        table.get("allow_unknown_config_keys"),
+        Some(toml::Value::Boolean(true))
+    );
+    check_unknown_keys(&mut table, &default_keys(), "", allow_unknown_keys)
+        .with_context(|| format!("Failed to parse {:?}", config_path))?;
+
+    let mut config: Config = toml::Value::Table(table)
+        .try_into()
+        .with_context(|| format!("Failed to parse {:?}", config_path))?;
+
+    config
+        .expand_paths()
+        .with_context(|| format!("Failed to expand paths in {:?}", config_path))?;
+
+    Ok(config)
+}
+
+// Every key of the default config, with nested structs as objects. Unset options are kept
+// as nulls, so they count as known too.
+fn default_keys() -> serde_json::Map<String, serde_json::Value> {
+    match serde_json::to_value(Config::default()) {
+        Ok(serde_json::Value::Object(keys)) => keys,
+        _ => serde_json::Map::new(),
+    }
+}
+
+// Rejects keys of `table` that aren't in `known`, or drops them when `allow_unknown_keys` is
+// set, suggesting the closest known key. Tables are checked recursively where the default is
+// a struct, the keys of maps like `[plugins]` are up to the user.
+fn check_unknown_keys(
+    table: &mut toml::Table,
+    known: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    allow_unknown_keys: bool,
+) -> Result<()> {
+    let unknown: Vec<String> = table
+        .keys()
+        .filter(|key| !known.contains_key(key.as_str()))
+        .cloned()
+        .collect();
+    for key in unknown {
+        if !allow_unknown_keys {
+            bail!(
+                "unknown field `{}{}`. {}Set allow_unknown_config_keys = true to ignore unknown fields",
+                prefix,
+                key,
+                did_you_mean(&key, known.keys().map(String::as_str))
+            );
+        }
+        warn!(key = %format!("{}{}", prefix, key), "Ignoring unknown config key");
+        table.remove(&key);
+    }
+
+    for (key, value) in table.iter_mut() {
+        if let (toml::Value::Table(table), Some(serde_json::Value::Object(known))) =
+            (value, known.get(key))
+            && !known.is_empty()
+        {
+            check_unknown_keys(
+                table,
+                known,
+                &format!("{}{}.", prefix, key),
+                allow_unknown_keys,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// Reads a config file as a raw table with its `include` files merged underneath it.
+// `chain` holds the canonical paths of the files currently being included, to catch cycles.
+fn load_config_table(config_path: &Path, chain: &mut Vec<PathBuf>) -> Result<toml::Table> {
+    let canonical_path = fs::canonicalize(config_path)
         .with_context(|| format!("Failed to read {:?}", config_path))?;
+    if chain.contains(&canonical_path) {
+        let cycle: Vec<String> = chain
+            .iter()
+            .chain([&canonical_path])
+            .map(|path| path.display().to_string())
+            .collect();
+        bail!("Circular config include: {}", cycle.join(" -> "));
+    }
 
-    let config: Config =
+    let contents = fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read {:?}", config_path))?;
+    let mut table: toml::Table =
         toml::from_str(&contents).with_context(|| format!("Failed to parse {:?}", config_path))?;
 
-    Ok(config)
+    let base_dir = config_path.parent().unwrap_or(Path::new(""));
+    for hook_key in ["global_pre_run", "global_post_run"] {
+        if let Some(toml::Value::String(hook_path)) = table.get_mut(hook_key) {
+            let expanded = shellexpand::full(hook_path.as_str())
+                .with_context(|| format!("Failed to expand {} path '{}'", hook_key, hook_path))?;
+            *hook_path = base_dir
+                .join(expanded.as_ref())
+                .to_string_lossy()
+                .into_owned();
+        }
+    }
+    if let Some(toml::Value::Array(plugin_paths)) = table.get_mut("plugin_paths") {
+        for plugin_path in plugin_paths {
+            if let toml::Value::String(plugin_path) = plugin_path {
+                let expanded = shellexpand::full(plugin_path.as_str())
+                    .with_context(|| format!("Failed to expand plugin path '{}'", plugin_path))?;
+                *plugin_path = base_dir
+                    .join(expanded.as_ref())
+                    .to_string_lossy()
+                    .into_owned();
+            }
+        }
+    }
+
+    let includes: Vec<String> = match table.get("include") {
+        Some(include) => include
+            .clone()
+            .try_into()
+            .with_context(|| format!("'include' in {:?} must be an array of paths", config_path))?,
+        None => return Ok(table),
+    };
+
+    let mut merged = toml::Table::new();
+    chain.push(canonical_path);
+    for include in includes {
+        let include_path = base_dir.join(
+            shellexpand::full(&include)
+                .with_context(|| format!("Failed to expand include '{}'", include))?
+                .as_ref(),
+        );
+        let included = load_config_table(&include_path, chain)
+            .with_context(|| format!("Failed to include {:?}", include_path))?;
+        merge_tables(&mut merged, included);
+    }
+    chain.pop();
+
+    merge_tables(&mut merged, table);
+    Ok(merged)
+}
+
+// Deep merges `overlay` into `base`: tables merge key by key, any other value replaces the
+// one in `base`
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
 }
 
+impl Config {
+    // Expands `~`, `$VAR` and `${VAR}` in the fields holding paths, so configs stay portable
+    // across machines. Done once at load time, consumers see plain paths.
+    fn expand_paths(&mut self) -> Result<()> {
+        let expand = |value: &mut String| -> Result<()> {
+            *value = shellexpand::full(value.as_str())
+                .with_context(|| format!("Failed to expand '{}'", value))?
+                .into_owned();
+            Ok(())
+        };
+
+        if let Some(editor) = &mut self.editor {
+            expand(editor)?;
+        }
+        for editor in &mut self.editor_fallbacks {
+            expand(editor)?;
+        }
+        Ok(())
+    }
+}
+
+/// Top-level keys a workspace `.syntropy.toml` may set.
+///
+/// A workspace config comes with whatever repository is checked out, so it can add plugin
+/// directories and restyle the UI, but keybindings, editors and every other setting stay
+/// under the control of the user's own config.
+pub const WORKSPACE_CONFIG_KEYS: &[&str] = &["include", "plugin_paths", "styles"];
+
 pub fn validate_config(config: &Config) -> Result<()> {
     for declaration in config.plugins.values() {
         declaration.validate()?;
@@ -62,6 +342,12 @@ pub fn validate_config(config: &Config) -> Result<()> {
         "Screen scaffold style left and right split must amount to 100"
     );
 
+    let preview_style = &config.styles.preview;
+    ensure!(
+        preview_style.preview_split_ratio > 0.0 && preview_style.preview_split_ratio < 1.0,
+        "Preview split ratio must be between 0 and 1 (exclusive)"
+    );
+
     let status_style = &config.styles.status;
     ensure!(
         status_style.left_split + status_style.right_split == 100,
@@ -74,17 +360,69 @@ pub fn validate_config(config: &Config) -> Result<()> {
         "Modal style vertical_size and horizontal_size must not exceed 100"
     );
 
+    for (section, border_style) in [
+        ("screen_scaffold", &screen_scaffold_style.border_style),
+        ("preview", &preview_style.border_style),
+        ("modal", &modal_style.border_style),
+    ] {
+        if let BorderStyle::Custom(custom) = border_style {
+            for (name, character) in custom.characters() {
+                ensure!(
+                    character.width() == 1,
+                    "Custom border {} '{}' of styles.{} must occupy a single terminal cell",
+                    name,
+                    character,
+                    section
+                );
+            }
+        }
+    }
+
     ensure!(
         config.default_plugin_icon.width() == 1,
         "Default plugin icon '{}' must occupy a single terminal cell",
         config.default_plugin_icon
     );
 
+    ensure!(
+        config.default_task_icon.is_empty() || config.default_task_icon.width() == 1,
+        "Default task icon '{}' must occupy a single terminal cell",
+        config.default_task_icon
+    );
+
+    ensure!(
+        config.preview_cache_size > 0,
+        "preview_cache_size must be at least 1"
+    );
+
     ensure!(
         config.default_task.is_none() || config.default_plugin.is_some(),
         "default_task requires default_plugin to be set"
     );
 
+    for (key, hook_path) in [
+        ("global_pre_run", &config.global_pre_run),
+        ("global_post_run", &config.global_post_run),
+    ] {
+        if let Some(hook_path) = hook_path {
+            ensure!(
+                hook_path.is_file(),
+                "{} file {} does not exist",
+                key,
+                hook_path.display()
+            );
+        }
+    }
+
+    ensure!(
+        config
+            .editor
+            .iter()
+            .chain(&config.editor_fallbacks)
+            .all(|editor| !editor.trim().is_empty()),
+        "editor and editor_fallbacks entries cannot be empty"
+    );
+
     ParsedKeyBindings::from(&config.keybindings).context("Invalid keybinding configuration")?;
 
     Ok(())
@@ -1,29 +1,317 @@
+use std::fmt;
+
+use anyhow::{Context, Result, bail, ensure};
+use crossterm::event::{KeyCode, KeyModifiers};
 use serde::{Deserialize, Serialize};
 
+use crate::tui::key_bindings::KeyBind;
+
+/// Keys triggering one action, written as a single key or a list of aliases in TOML:
+/// `select_next = "<down>"` or `select_next = ["<down>", "j"]`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(from = "OneOrMany", into = "OneOrMany")]
+pub struct KeyBinding(Vec<String>);
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl From<OneOrMany> for KeyBinding {
+    fn from(value: OneOrMany) -> Self {
+        match value {
+            OneOrMany::One(key) => KeyBinding(vec![key]),
+            OneOrMany::Many(keys) => KeyBinding(keys),
+        }
+    }
+}
+
+// Single keys are written back as a plain string, as most configs bind one key per action
+impl From<KeyBinding> for OneOrMany {
+    fn from(binding: KeyBinding) -> Self {
+        match <[String; 1]>::try_from(binding.0) {
+            Ok([key]) => OneOrMany::One(key),
+            Err(keys) => OneOrMany::Many(keys),
+        }
+    }
+}
+
+impl KeyBinding {
+    pub fn keys(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl From<&str> for KeyBinding {
+    fn from(key: &str) -> Self {
+        KeyBinding(vec![key.to_string()])
+    }
+}
+
+impl From<Vec<&str>> for KeyBinding {
+    fn from(keys: Vec<&str>) -> Self {
+        KeyBinding(keys.into_iter().map(str::to_string).collect())
+    }
+}
+
+// Compares single-key bindings, so `binding == "<esc>"` reads like the TOML it came from
+impl PartialEq<&str> for KeyBinding {
+    fn eq(&self, other: &&str) -> bool {
+        self.0.len() == 1 && self.0[0] == *other
+    }
+}
+
+/// Keys separated by ", ", as shown in button labels
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.join(", "))
+    }
+}
+
+/// Key running a task from any screen, a `[[keybindings.quick_actions]]` entry
+///
+/// `items` are matched like `syntropy execute --items`, without them the items are picked by
+/// the task mode.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuickAction {
+    pub key: KeyBinding,
+    pub plugin: String,
+    pub task: String,
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+impl QuickAction {
+    /// `quick action plugin/task`, as named in validation errors
+    pub fn label(&self) -> String {
+        format!("quick action {}/{}", self.plugin, self.task)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct KeyBindings {
-    pub back: String,
-    pub select_previous: String,
-    pub select_next: String,
-    pub scroll_preview_up: String,
-    pub scroll_preview_down: String,
-    pub toggle_preview: String,
-    pub select: String,
-    pub confirm: String,
+    pub back: KeyBinding,
+    pub select_previous: KeyBinding,
+    pub select_next: KeyBinding,
+    pub page_up: KeyBinding,
+    pub page_down: KeyBinding,
+    pub history_previous: KeyBinding,
+    pub history_next: KeyBinding,
+    pub scroll_preview_up: KeyBinding,
+    pub scroll_preview_down: KeyBinding,
+    pub scroll_preview_left: KeyBinding,
+    pub scroll_preview_right: KeyBinding,
+    pub toggle_preview: KeyBinding,
+    pub toggle_preview_wrap: KeyBinding,
+    pub select: KeyBinding,
+    pub confirm: KeyBinding,
+    pub jobs: KeyBinding,
+    pub kill_job: KeyBinding,
+    pub restart_job: KeyBinding,
+    pub sort_items: KeyBinding,
+    pub dump_plan: KeyBinding,
+    pub go_home: KeyBinding,
+    pub toggle_search_mode: KeyBinding,
+    pub toggle_case_sensitive: KeyBinding,
+    pub copy: KeyBinding,
+    pub copy_preview: KeyBinding,
+    pub select_all: KeyBinding,
+    pub select_none: KeyBinding,
+    pub invert_selection: KeyBinding,
+    pub cycle_plugin_filter: KeyBinding,
+    pub quick_actions: Vec<QuickAction>,
 }
 
 impl Default for KeyBindings {
     fn default() -> Self {
         Self {
-            back: "<esc>".to_string(),
-            select_previous: "<up>".to_string(),
-            select_next: "<down>".to_string(),
-            scroll_preview_up: "<C-up>".to_string(),
-            scroll_preview_down: "<C-down>".to_string(),
-            toggle_preview: "<C-p>".to_string(),
-            select: "<tab>".to_string(),
-            confirm: "<enter>".to_string(),
+            back: "<esc>".into(),
+            select_previous: "<up>".into(),
+            select_next: "<down>".into(),
+            page_up: "<pageup>".into(),
+            page_down: "<pagedown>".into(),
+            history_previous: "<A-up>".into(),
+            history_next: "<A-down>".into(),
+            scroll_preview_up: "<C-up>".into(),
+            scroll_preview_down: "<C-down>".into(),
+            scroll_preview_left: "<A-left>".into(),
+            scroll_preview_right: "<A-right>".into(),
+            toggle_preview: "<C-p>".into(),
+            toggle_preview_wrap: "<A-w>".into(),
+            select: "<tab>".into(),
+            confirm: "<enter>".into(),
+            jobs: "<C-j>".into(),
+            kill_job: "<C-k>".into(),
+            restart_job: "<A-r>".into(),
+            sort_items: "<C-s>".into(),
+            dump_plan: "<C-w>".into(),
+            go_home: "<C-home>".into(),
+            toggle_search_mode: "<C-f>".into(),
+            toggle_case_sensitive: "<C-t>".into(),
+            copy: "<C-y>".into(),
+            copy_preview: "<A-y>".into(),
+            select_all: "<C-a>".into(),
+            select_none: "<A-a>".into(),
+            invert_selection: "<A-i>".into(),
+            cycle_plugin_filter: "<A-l>".into(),
+            quick_actions: Vec::new(),
         }
     }
 }
+
+impl KeyBindings {
+    /// Every action name with its configured binding
+    pub fn actions(&self) -> [(&'static str, &KeyBinding); 29] {
+        [
+            ("back", &self.back),
+            ("select_previous", &self.select_previous),
+            ("select_next", &self.select_next),
+            ("page_up", &self.page_up),
+            ("page_down", &self.page_down),
+            ("history_previous", &self.history_previous),
+            ("history_next", &self.history_next),
+            ("scroll_preview_up", &self.scroll_preview_up),
+            ("scroll_preview_down", &self.scroll_preview_down),
+            ("scroll_preview_left", &self.scroll_preview_left),
+            ("scroll_preview_right", &self.scroll_preview_right),
+            ("toggle_preview", &self.toggle_preview),
+            ("toggle_preview_wrap", &self.toggle_preview_wrap),
+            ("confirm", &self.confirm),
+            ("select", &self.select),
+            ("jobs", &self.jobs),
+            ("kill_job", &self.kill_job),
+            ("restart_job", &self.restart_job),
+            ("sort_items", &self.sort_items),
+            ("dump_plan", &self.dump_plan),
+            ("go_home", &self.go_home),
+            ("toggle_search_mode", &self.toggle_search_mode),
+            ("toggle_case_sensitive", &self.toggle_case_sensitive),
+            ("copy", &self.copy),
+            ("copy_preview", &self.copy_preview),
+            ("select_all", &self.select_all),
+            ("select_none", &self.select_none),
+            ("invert_selection", &self.invert_selection),
+            ("cycle_plugin_filter", &self.cycle_plugin_filter),
+        ]
+    }
+}
+
+/// Checks that every action and quick action has a key and that no key is bound to two
+/// of them. Quick actions can't use `<C-c>`, which always quits.
+///
+/// Returns warnings for bindings the search bar captures before they reach their action:
+/// plain or shifted characters and the text editing keys. They only apply with
+/// `search_bar = true`.
+pub fn validate_keybindings(key_bindings: &KeyBindings) -> Result<Vec<String>> {
+    let mut bound: Vec<(KeyBind, &str, Vec<&str>)> = Vec::new();
+    let mut warnings = Vec::new();
+
+    for quick_action in &key_bindings.quick_actions {
+        ensure!(
+            !quick_action.plugin.trim().is_empty() && !quick_action.task.trim().is_empty(),
+            "Quick action bound to '{}' must name a plugin and a task",
+            quick_action.key
+        );
+    }
+
+    let quick_action_labels: Vec<String> = key_bindings
+        .quick_actions
+        .iter()
+        .map(QuickAction::label)
+        .collect();
+    let quick_actions = key_bindings
+        .quick_actions
+        .iter()
+        .zip(&quick_action_labels)
+        .map(|(quick_action, label)| (label.as_str(), &quick_action.key, true));
+    let actions = key_bindings
+        .actions()
+        .into_iter()
+        .map(|(action, binding)| (action, binding, false));
+
+    for (action, binding, is_quick_action) in actions.chain(quick_actions) {
+        ensure!(
+            !binding.keys().is_empty(),
+            "No key bound to '{}' keybinding",
+            action
+        );
+
+        for binding in binding.keys() {
+            let key = KeyBind::parse(binding).with_context(|| {
+                format!("Failed to parse '{}' keybinding '{}'", action, binding)
+            })?;
+
+            ensure!(
+                !(is_quick_action && key == quit_key()),
+                "Key '{}' bound to '{}' is reserved for quitting",
+                binding,
+                action
+            );
+
+            if is_search_bar_key(&key) {
+                warnings.push(format!(
+                    "Key '{}' bound to '{}' is captured by the search bar and won't trigger the action",
+                    binding, action
+                ));
+            }
+
+            match bound.iter_mut().find(|(bound_key, _, _)| *bound_key == key) {
+                Some((_, _, actions)) if actions.contains(&action) => {}
+                Some((_, _, actions)) => actions.push(action),
+                None => bound.push((key, binding, vec![action])),
+            }
+        }
+    }
+
+    let conflicts: Vec<String> = bound
+        .iter()
+        .filter(|(_, _, actions)| actions.len() > 1)
+        .map(|(_, binding, actions)| {
+            format!("Key '{}' is bound to {}", binding, list_actions(actions))
+        })
+        .collect();
+
+    if !conflicts.is_empty() {
+        bail!(
+            "Duplicate key bindings detected:\n  {}",
+            conflicts.join("\n  ")
+        );
+    }
+
+    Ok(warnings)
+}
+
+// Ctrl-C quits the TUI before key bindings are looked up
+fn quit_key() -> KeyBind {
+    KeyBind {
+        code: KeyCode::Char('c'),
+        modifiers: KeyModifiers::CONTROL,
+    }
+}
+
+// Mirrors the keys `SearchBar::handle_event` consumes
+fn is_search_bar_key(key: &KeyBind) -> bool {
+    match key.code {
+        KeyCode::Char(_) => {
+            key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT
+        }
+        KeyCode::Backspace | KeyCode::Delete => true,
+        KeyCode::Left | KeyCode::Right => !key.modifiers.contains(KeyModifiers::ALT),
+        KeyCode::Home | KeyCode::End => !key.modifiers.contains(KeyModifiers::CONTROL),
+        _ => false,
+    }
+}
+
+// "both 'a' and 'b'" or "'a', 'b' and 'c'"
+fn list_actions(actions: &[&str]) -> String {
+    let quoted: Vec<String> = actions.iter().map(|a| format!("'{}'", a)).collect();
+    match quoted.as_slice() {
+        [first, second] => format!("both {} and {}", first, second),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+        [] => String::new(),
+    }
+}
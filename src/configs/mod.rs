@@ -2,13 +2,17 @@ mod config;
 mod key_bindings;
 pub mod paths;
 pub mod plugin_declaration;
+pub mod plugin_watcher;
 pub mod style;
 
-pub use config::{Config, load_config, validate_config};
-pub use key_bindings::KeyBindings;
+pub use config::{
+    Config, WORKSPACE_CONFIG_KEYS, load_config, load_config_with_workspace, validate_config,
+};
+pub use key_bindings::{KeyBinding, KeyBindings, QuickAction, validate_keybindings};
 pub use paths::{
-    expand_path, find_config_file, get_default_config_dir, get_default_data_dir,
-    resolve_plugin_paths,
+    expand_path, find_config_file, find_workspace_config_file, get_default_config_dir,
+    get_default_data_dir, get_system_config_dirs, resolve_plugin_paths,
 };
 pub use plugin_declaration::PluginDeclaration;
+pub use plugin_watcher::PluginWatcher;
 pub use style::Styles;
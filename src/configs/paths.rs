@@ -1,10 +1,12 @@
 use anyhow::{Context, Result, anyhow};
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const SYNTROPY_CONFIG_NAME: &str = "syntropy.toml";
+const WORKSPACE_CONFIG_NAME: &str = ".syntropy.toml";
 const SYNTROPY_APP_NAME: &str = "syntropy";
 const PLUGINS_DIR_NAME: &str = "plugins";
+const DEFAULT_XDG_CONFIG_DIRS: &str = "/etc/xdg";
 
 /// Expands a path with tilde (~) and environment variable substitution
 ///
@@ -77,6 +79,26 @@ pub fn get_default_config_dir() -> Result<PathBuf> {
         .context("Failed to determine home directory")
 }
 
+/// Returns the system-wide config directories, highest priority first
+///
+/// Respects XDG Base Directory Specification:
+/// - Reads the colon-separated `$XDG_CONFIG_DIRS` and appends `syntropy` to each entry
+/// - Falls back to `/etc/xdg/syntropy` if XDG_CONFIG_DIRS is unset or empty
+/// - Skips empty and relative entries (must be absolute per XDG spec)
+pub fn get_system_config_dirs() -> Vec<PathBuf> {
+    let xdg_config_dirs = env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| DEFAULT_XDG_CONFIG_DIRS.to_string());
+
+    xdg_config_dirs
+        .split(':')
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .map(|path| path.join(SYNTROPY_APP_NAME))
+        .collect()
+}
+
 /// Returns the default data directory based on platform conventions
 ///
 /// Respects XDG Base Directory Specification:
@@ -110,10 +132,14 @@ pub fn get_default_data_dir() -> Result<PathBuf> {
 ///
 /// 1. CLI argument path (if provided) - returns error if specified but doesn't exist
 /// 2. XDG config directory: `~/.config/syntropy/syntropy.toml`
-/// 3. Current directory: `./syntropy.toml`
+/// 3. System config directories: `syntropy/syntropy.toml` in each entry of
+///    `$XDG_CONFIG_DIRS` (default `/etc/xdg`), in order
+/// 4. Current directory: `./syntropy.toml`
 ///
 /// Returns `Ok(Some(path))` if config found, `Ok(None)` if no config found via auto-discovery,
 /// or `Err` if CLI path was explicitly specified but doesn't exist.
+///
+/// The workspace config merged on top of this one is found by [`find_workspace_config_file`].
 pub fn find_config_file(cli_path: Option<PathBuf>) -> Result<Option<PathBuf>> {
     // Priority 1: CLI argument - error if explicitly specified but missing
     if let Some(path) = cli_path {
@@ -130,7 +156,16 @@ pub fn find_config_file(cli_path: Option<PathBuf>) -> Result<Option<PathBuf>> {
         return Ok(Some(xdg_config_path));
     }
 
-    // Priority 3: Current directory
+    // Priority 3: System config directories, deployed by administrators
+    if let Some(system_config_path) = get_system_config_dirs()
+        .into_iter()
+        .map(|dir| dir.join(SYNTROPY_CONFIG_NAME))
+        .find(|path| path.exists())
+    {
+        return Ok(Some(system_config_path));
+    }
+
+    // Priority 4: Current directory
     let local_config_path = PathBuf::from(".").join(SYNTROPY_CONFIG_NAME);
     if local_config_path.exists() {
         return Ok(Some(local_config_path));
@@ -140,17 +175,66 @@ pub fn find_config_file(cli_path: Option<PathBuf>) -> Result<Option<PathBuf>> {
     Ok(None)
 }
 
+/// Finds the workspace config `.syntropy.toml` in `start_dir` or the closest parent
+/// directory containing one
+///
+/// Like `.editorconfig`, this lets a project carry its own settings. Returns `None` when no
+/// directory up to the filesystem root has one.
+pub fn find_workspace_config_file(start_dir: &Path) -> Option<PathBuf> {
+    start_dir
+        .ancestors()
+        .map(|dir| dir.join(WORKSPACE_CONFIG_NAME))
+        .find(|path| path.is_file())
+}
+
 /// Resolves plugin directory paths using XDG Base Directory specification
 ///
 /// # Behavior
 ///
-/// Returns both default plugin directories:
+/// Returns the default plugin directories with the configured `plugin_paths` in between:
 /// - `~/.config/syntropy/plugins/` (user-created plugins)
+/// - `syntropy/plugins/` of each `$XDG_CONFIG_DIRS` entry that exists (system-wide plugins)
+/// - each of `plugin_paths`, in order
 /// - `~/.local/share/syntropy/plugins/` (managed plugins installed via `syntropy plugins --install`)
 ///
+/// A `plugin_paths` entry containing `*`, `?` or `[` is a glob pattern replaced by the
+/// directories it matches, in sorted order. Patterns matching nothing add no directory.
+///
 /// Plugins with the same name are merged, with config directory taking precedence.
-pub fn resolve_plugin_paths() -> Result<Vec<PathBuf>> {
+pub fn resolve_plugin_paths(plugin_paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
     let config_plugins = get_default_config_dir()?.join(PLUGINS_DIR_NAME);
+    let system_plugins = get_system_config_dirs()
+        .into_iter()
+        .map(|dir| dir.join(PLUGINS_DIR_NAME))
+        .filter(|dir| dir.is_dir());
     let data_plugins = get_default_data_dir()?.join(PLUGINS_DIR_NAME);
-    Ok(vec![config_plugins, data_plugins])
+    let mut expanded_plugin_paths = Vec::new();
+    for plugin_path in plugin_paths {
+        expanded_plugin_paths.extend(expand_plugin_path_glob(plugin_path)?);
+    }
+    Ok([config_plugins]
+        .into_iter()
+        .chain(system_plugins)
+        .chain(expanded_plugin_paths)
+        .chain([data_plugins])
+        .collect())
+}
+
+// Directories matched by a glob pattern, sorted, or the path itself when it has no glob
+// characters
+fn expand_plugin_path_glob(plugin_path: &Path) -> Result<Vec<PathBuf>> {
+    let Some(pattern) = plugin_path
+        .to_str()
+        .filter(|path| path.contains(['*', '?', '[']))
+    else {
+        return Ok(vec![plugin_path.to_path_buf()]);
+    };
+
+    let mut matches: Vec<PathBuf> = glob::glob(pattern)
+        .with_context(|| format!("Invalid glob pattern in plugin path '{}'", pattern))?
+        .filter_map(Result::ok)
+        .filter(|path| path.is_dir())
+        .collect();
+    matches.sort();
+    Ok(matches)
 }
@@ -9,6 +9,12 @@ pub struct PluginDeclaration {
     pub tag: Option<String>,
     #[serde(default)]
     pub commit: Option<String>,
+    /// Branch to track, pulled by `syntropy plugins sync`
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Seconds after which a tracked branch is pulled again when syntropy starts
+    #[serde(default)]
+    pub sync_interval: Option<u64>,
 }
 
 impl PluginDeclaration {
@@ -21,14 +27,30 @@ impl PluginDeclaration {
             self.git
         );
 
-        match (&self.tag, &self.commit) {
-            (Some(_), Some(_)) => {
+        ensure!(
+            self.sync_interval.is_none() || self.branch.is_some(),
+            "Plugin sync_interval requires a branch to track"
+        );
+
+        match (&self.tag, &self.commit, &self.branch) {
+            (Some(_), Some(_), _) => {
                 bail!("Plugin must not declare both tag and commit - choose one")
             }
-            (None, None) => {
-                bail!("Plugin must specify either tag or commit")
+            (Some(_), _, Some(_)) | (_, Some(_), Some(_)) => {
+                bail!("Plugin must not declare a branch together with a tag or commit")
+            }
+            (None, None, None) => {
+                bail!("Plugin must specify either tag or commit, or a branch to track")
             }
             _ => Ok(()),
         }
     }
+
+    /// The tag, commit or branch checked out after cloning
+    pub fn ref_spec(&self) -> Option<&str> {
+        self.tag
+            .as_deref()
+            .or(self.commit.as_deref())
+            .or(self.branch.as_deref())
+    }
 }
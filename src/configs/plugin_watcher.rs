@@ -0,0 +1,80 @@
+use std::{
+    path::PathBuf,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::watch;
+
+use crate::cli::watch::Debouncer;
+
+/// Quiet period after the last `.lua` change before plugins are reloaded, long enough to
+/// collapse a save touching several files into one reload
+pub const PLUGIN_RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How long the debounce thread sleeps when no change is pending
+const IDLE_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// Watches plugin directories for `.lua` changes while it is alive
+///
+/// Every debounced burst of changes increments the value of the channel returned by
+/// [`PluginWatcher::new`], receivers reload the plugins when it changed.
+pub struct PluginWatcher {
+    // Dropping the watcher stops the debounce thread
+    _watcher: RecommendedWatcher,
+}
+
+impl PluginWatcher {
+    /// Watches the existing directories of `plugin_paths` recursively
+    pub fn new(plugin_paths: &[PathBuf]) -> Result<(Self, watch::Receiver<u64>)> {
+        let (change_sender, change_receiver) = mpsc::channel::<Vec<PathBuf>>();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            // Access events would fire on every plugin load, including the reload itself
+            if let Ok(event) = event
+                && matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                )
+            {
+                let _ = change_sender.send(event.paths);
+            }
+        })
+        .context("Failed to create plugin watcher")?;
+
+        for path in plugin_paths.iter().filter(|path| path.is_dir()) {
+            watcher
+                .watch(path, RecursiveMode::Recursive)
+                .with_context(|| format!("Failed to watch {}", path.display()))?;
+        }
+
+        let (reload_sender, reload_receiver) = watch::channel(0);
+        thread::spawn(move || debounce_changes(change_receiver, reload_sender));
+
+        Ok((Self { _watcher: watcher }, reload_receiver))
+    }
+}
+
+// Forwards changes as reloads once they settled, until the watcher or every receiver is gone
+fn debounce_changes(changes: mpsc::Receiver<Vec<PathBuf>>, reloads: watch::Sender<u64>) {
+    let mut debouncer = Debouncer::new(PLUGIN_RELOAD_DEBOUNCE);
+    loop {
+        let timeout = debouncer.remaining(Instant::now()).unwrap_or(IDLE_TIMEOUT);
+        match changes.recv_timeout(timeout) {
+            Ok(changed) => {
+                debouncer.record(&changed, Instant::now());
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+
+        if debouncer.take_ready(Instant::now()) {
+            reloads.send_modify(|generation| *generation += 1);
+            if reloads.is_closed() {
+                return;
+            }
+        }
+    }
+}
@@ -7,6 +7,10 @@ pub const DEFAULT_COLOR: &str = "terminal";
 pub struct Colors {
     pub highlights_background: String,
     pub highlights_text: String,
+    /// Characters of list entries matched by the search query
+    pub search_highlight: String,
+    /// `[tag]` prefix of the items of multi-source tasks, unless the source sets a `color`
+    pub tag: String,
     pub borders: String,
     pub borders_list: String,
     pub borders_preview: String,
@@ -32,6 +36,8 @@ impl Default for Colors {
         Self {
             highlights_text: DEFAULT_COLOR.to_string(),
             highlights_background: DEFAULT_COLOR.to_string(),
+            search_highlight: DEFAULT_COLOR.to_string(),
+            tag: DEFAULT_COLOR.to_string(),
             borders: DEFAULT_COLOR.to_string(),
             text: DEFAULT_COLOR.to_string(),
             background: DEFAULT_COLOR.to_string(),
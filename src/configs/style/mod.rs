@@ -6,6 +6,7 @@ mod screen_scaffold;
 mod search_bar;
 mod status;
 mod styles;
+pub mod themes;
 
 pub use colors::{Colors, DEFAULT_COLOR};
 pub use list::List;
@@ -14,6 +15,9 @@ pub use preview::Preview;
 pub use screen_scaffold::ScreenScaffold;
 pub use search_bar::SearchBar;
 pub use status::Status;
+pub use styles::BorderStyle;
 pub use styles::Borders;
+pub use styles::CustomBorders;
 pub use styles::FontWeight;
+pub use styles::PreviewMode;
 pub use styles::Styles;
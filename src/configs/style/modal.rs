@@ -1,11 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::configs::style::{Borders, FontWeight};
+use crate::configs::style::{BorderStyle, Borders, FontWeight};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Modal {
     pub borders: Vec<Borders>,
+    pub border_style: BorderStyle,
     pub font_weight: FontWeight,
     pub show_title: bool,
     pub scroll_offset: u16,
@@ -17,6 +18,7 @@ impl Default for Modal {
     fn default() -> Self {
         Self {
             borders: vec![Borders::All],
+            border_style: BorderStyle::default(),
             font_weight: FontWeight::Regular,
             show_title: true,
             scroll_offset: 2,
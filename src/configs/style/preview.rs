@@ -1,23 +1,29 @@
 use serde::{Deserialize, Serialize};
 
-use crate::configs::style::{Borders, FontWeight};
+use crate::configs::style::{BorderStyle, Borders, FontWeight, PreviewMode};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Preview {
     pub borders: Vec<Borders>,
+    pub border_style: BorderStyle,
     pub font_weight: FontWeight,
     pub show_title: bool,
     pub scroll_offset: u16,
+    pub preview_mode: PreviewMode,
+    pub preview_split_ratio: f32,
 }
 
 impl Default for Preview {
     fn default() -> Self {
         Self {
             borders: vec![Borders::All],
+            border_style: BorderStyle::default(),
             font_weight: FontWeight::Regular,
             show_title: true,
             scroll_offset: 2,
+            preview_mode: PreviewMode::Overlay,
+            preview_split_ratio: 0.4,
         }
     }
 }
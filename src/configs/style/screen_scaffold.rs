@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use crate::configs::style::BorderStyle;
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct ScreenScaffold {
     pub left_split: u16,
     pub right_split: u16,
+    /// Borders of the list, the search bar and the status bar
+    pub border_style: BorderStyle,
 }
 
 impl Default for ScreenScaffold {
@@ -12,6 +16,7 @@ impl Default for ScreenScaffold {
         Self {
             left_split: 50,
             right_split: 50,
+            border_style: BorderStyle::default(),
         }
     }
 }
@@ -1,6 +1,10 @@
+use anyhow::{Context, Error, Result, anyhow};
 use serde::{Deserialize, Serialize};
 
-use crate::configs::style::{Colors, List, Modal, Preview, ScreenScaffold, SearchBar, Status};
+use crate::configs::style::{
+    Colors, List, Modal, Preview, ScreenScaffold, SearchBar, Status,
+    themes::{THEMES, theme_names},
+};
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
 #[serde[rename_all = "lowercase"]]
@@ -9,6 +13,14 @@ pub enum FontWeight {
     Regular,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Default)]
+#[serde[rename_all = "lowercase"]]
+pub enum PreviewMode {
+    #[default]
+    Overlay,
+    Split,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
 #[serde[rename_all = "lowercase"]]
 pub enum Borders {
@@ -19,8 +31,70 @@ pub enum Borders {
     All,
 }
 
+/// Characters borders are drawn with
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde[rename_all = "lowercase"]]
+pub enum BorderStyle {
+    /// Single lines with square corners
+    #[default]
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+    /// `+`, `-` and `|`, for terminals without box-drawing characters
+    Ascii,
+    /// Blank borders, the space they take up is kept
+    None,
+    /// `{ custom = { top_left = "*", ... } }`, unset characters are the plain ones
+    Custom(CustomBorders),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomBorders {
+    pub top_left: String,
+    pub top_right: String,
+    pub bottom_left: String,
+    pub bottom_right: String,
+    pub vertical_left: String,
+    pub vertical_right: String,
+    pub horizontal_top: String,
+    pub horizontal_bottom: String,
+}
+
+impl CustomBorders {
+    /// Every character with the name of its field
+    pub fn characters(&self) -> [(&'static str, &str); 8] {
+        [
+            ("top_left", &self.top_left),
+            ("top_right", &self.top_right),
+            ("bottom_left", &self.bottom_left),
+            ("bottom_right", &self.bottom_right),
+            ("vertical_left", &self.vertical_left),
+            ("vertical_right", &self.vertical_right),
+            ("horizontal_top", &self.horizontal_top),
+            ("horizontal_bottom", &self.horizontal_bottom),
+        ]
+    }
+}
+
+impl Default for CustomBorders {
+    fn default() -> Self {
+        Self {
+            top_left: String::from("┌"),
+            top_right: String::from("┐"),
+            bottom_left: String::from("└"),
+            bottom_right: String::from("┘"),
+            vertical_left: String::from("│"),
+            vertical_right: String::from("│"),
+            horizontal_top: String::from("─"),
+            horizontal_bottom: String::from("─"),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
-#[serde[default, deny_unknown_fields]]
+#[serde(try_from = "RawStyles")]
 pub struct Styles {
     pub list: List,
     pub preview: Preview,
@@ -28,5 +102,55 @@ pub struct Styles {
     pub status: Status,
     pub screen_scaffold: ScreenScaffold,
     pub search_bar: SearchBar,
+    /// Built-in color preset, see `themes.rs`
+    pub theme: Option<String>,
+    /// Theme colors with the `[styles.colors]` overrides applied
     pub colors: Colors,
 }
+
+// `[styles]` as written in the config file. Colors are kept as a raw table so that only
+// the fields the user actually set override the theme.
+#[derive(Deserialize, Default)]
+#[serde[default, deny_unknown_fields]]
+struct RawStyles {
+    list: List,
+    preview: Preview,
+    modal: Modal,
+    status: Status,
+    screen_scaffold: ScreenScaffold,
+    search_bar: SearchBar,
+    theme: Option<String>,
+    colors: toml::Table,
+}
+
+impl TryFrom<RawStyles> for Styles {
+    type Error = Error;
+
+    fn try_from(raw: RawStyles) -> Result<Self> {
+        let base_colors = match &raw.theme {
+            Some(theme) => THEMES.get(theme.as_str()).cloned().with_context(|| {
+                format!(
+                    "Unknown theme '{}'. Available themes: {}",
+                    theme,
+                    theme_names().join(", ")
+                )
+            })?,
+            None => Colors::default(),
+        };
+
+        let mut colors = toml::Table::try_from(base_colors)?;
+        colors.extend(raw.colors);
+        let colors = Colors::deserialize(colors).map_err(|e| anyhow!("{}", e.message()))?;
+
+        Ok(Self {
+            list: raw.list,
+            preview: raw.preview,
+            modal: raw.modal,
+            status: raw.status,
+            screen_scaffold: raw.screen_scaffold,
+            search_bar: raw.search_bar,
+            theme: raw.theme,
+            colors,
+        })
+    }
+}
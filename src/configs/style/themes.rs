@@ -0,0 +1,59 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use crate::configs::style::Colors;
+
+/// Built-in color presets selectable with `theme` in `[styles]`
+///
+/// Only the global colors are set, component colors fall back to them unless overridden
+/// in `[styles.colors]`. Search matches use the highlight text color, item tags the border
+/// color.
+pub static THEMES: LazyLock<HashMap<&'static str, Colors>> = LazyLock::new(|| {
+    HashMap::from([
+        (
+            "dark",
+            theme_colors("#3a3a3a", "#ffffff", "#5f5f5f", "#d0d0d0", "#1c1c1c"),
+        ),
+        (
+            "light",
+            theme_colors("#d7d7d7", "#000000", "#a8a8a8", "#303030", "#fafafa"),
+        ),
+        (
+            "gruvbox",
+            theme_colors("#504945", "#fabd2f", "#665c54", "#ebdbb2", "#282828"),
+        ),
+        (
+            "nord",
+            theme_colors("#3b4252", "#88c0d0", "#4c566a", "#d8dee9", "#2e3440"),
+        ),
+        (
+            "solarized-dark",
+            theme_colors("#073642", "#b58900", "#586e75", "#839496", "#002b36"),
+        ),
+    ])
+});
+
+/// Sorted names of the built-in themes, used in error messages
+pub fn theme_names() -> Vec<&'static str> {
+    let mut names: Vec<_> = THEMES.keys().copied().collect();
+    names.sort();
+    names
+}
+
+fn theme_colors(
+    highlights_background: &str,
+    highlights_text: &str,
+    borders: &str,
+    text: &str,
+    background: &str,
+) -> Colors {
+    Colors {
+        highlights_background: highlights_background.to_string(),
+        highlights_text: highlights_text.to_string(),
+        search_highlight: highlights_text.to_string(),
+        tag: borders.to_string(),
+        borders: borders.to_string(),
+        text: text.to_string(),
+        background: background.to_string(),
+        ..Colors::default()
+    }
+}
@@ -0,0 +1,148 @@
+use std::{error::Error, fmt};
+
+use crate::{
+    cli::execute::did_you_mean,
+    execution::{
+        EXIT_ITEM_NOT_FOUND, EXIT_LUA_ERROR, EXIT_PLUGIN_NOT_FOUND, EXIT_PROCESS_ERROR,
+        EXIT_TASK_NOT_FOUND,
+    },
+};
+
+/// Kinds of execution failures, carried inside `anyhow::Error` so callers can tell them
+/// apart with `downcast_ref::<ExecutionError>()` or [`ExecutionError::find`]
+#[derive(Debug)]
+pub enum ExecutionError {
+    /// Calling a plugin function failed, including wrong return types
+    LuaError(mlua::Error),
+    /// A command exited unsuccessfully
+    ProcessError {
+        command: String,
+        exit_code: i32,
+        /// What the command wrote to stderr, trimmed
+        stderr: String,
+    },
+    /// Requested item is none of the items of a task
+    ItemNotFound {
+        task: String,
+        item: String,
+        available: Vec<String>,
+    },
+    /// Unknown plugin
+    PluginNotFound {
+        plugin: String,
+        available: Vec<String>,
+    },
+    /// Unknown task of a plugin, `available` is sorted for the message
+    TaskNotFound {
+        plugin: String,
+        task: String,
+        available: Vec<String>,
+    },
+}
+
+impl ExecutionError {
+    /// Finds the execution error behind `error`
+    ///
+    /// A plugin function failing because a `syntropy.*` call it made failed is reported as
+    /// that call's error, e.g. a `ProcessError` of `syntropy.git_status`, not as a `LuaError`.
+    pub fn find(error: &anyhow::Error) -> Option<&ExecutionError> {
+        let found = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ExecutionError>())?;
+        match found {
+            ExecutionError::LuaError(lua_error) => Some(
+                lua_error
+                    .chain()
+                    .skip(1)
+                    .find_map(|cause| cause.downcast_ref::<ExecutionError>())
+                    .unwrap_or(found),
+            ),
+            _ => Some(found),
+        }
+    }
+
+    /// Exit code of `syntropy execute` failing with this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ExecutionError::LuaError(_) => EXIT_LUA_ERROR,
+            ExecutionError::ProcessError { .. } => EXIT_PROCESS_ERROR,
+            ExecutionError::ItemNotFound { .. } => EXIT_ITEM_NOT_FOUND,
+            ExecutionError::PluginNotFound { .. } => EXIT_PLUGIN_NOT_FOUND,
+            ExecutionError::TaskNotFound { .. } => EXIT_TASK_NOT_FOUND,
+        }
+    }
+
+    /// `kind` of this error in `execute --format json` failures
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExecutionError::LuaError(_) => "lua_error",
+            ExecutionError::ProcessError { .. } => "process_error",
+            ExecutionError::ItemNotFound { .. } => "item_not_found",
+            ExecutionError::PluginNotFound { .. } => "plugin_not_found",
+            ExecutionError::TaskNotFound { .. } => "task_not_found",
+        }
+    }
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::LuaError(e) => write!(f, "{}", e),
+            ExecutionError::ProcessError {
+                command,
+                exit_code,
+                stderr,
+            } => {
+                write!(
+                    f,
+                    "Command '{}' failed with exit code {}",
+                    command, exit_code
+                )?;
+                if !stderr.is_empty() {
+                    write!(f, ": {}", stderr)?;
+                }
+                Ok(())
+            }
+            ExecutionError::ItemNotFound {
+                task,
+                item,
+                available,
+            } => write!(
+                f,
+                "Item '{}' not found in task '{}'. Available items:\n  {}",
+                item,
+                task,
+                available.join("\n  ")
+            ),
+            ExecutionError::PluginNotFound { plugin, available } => write!(
+                f,
+                "Plugin '{}' not found. {}Available plugins: {}",
+                plugin,
+                did_you_mean(plugin, available.iter().map(String::as_str)),
+                available.join(", ")
+            ),
+            ExecutionError::TaskNotFound {
+                plugin,
+                task,
+                available,
+            } => write!(
+                f,
+                "Task '{}' not found in plugin '{}'. {}Available tasks: {}",
+                task,
+                plugin,
+                did_you_mean(task, available.iter().map(String::as_str)),
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ExecutionError {
+    // The Lua error is displayed in place, so the chain continues with its own source
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExecutionError::LuaError(e) => e.source(),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,18 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 /// Standard exit code constants
 pub const EXIT_SUCCESS: i32 = 0;
 pub const EXIT_FAILURE: i32 = 1;
+/// `syntropy execute --fail-on-empty` found no items to execute
+pub const EXIT_NO_ITEMS: i32 = 3;
+/// `syntropy execute` failed with an `ExecutionError`, one code per kind
+pub const EXIT_LUA_ERROR: i32 = 4;
+pub const EXIT_PROCESS_ERROR: i32 = 5;
+pub const EXIT_ITEM_NOT_FOUND: i32 = 6;
+pub const EXIT_PLUGIN_NOT_FOUND: i32 = 7;
+pub const EXIT_TASK_NOT_FOUND: i32 = 8;
 pub const EXIT_SIGINT: i32 = 130;
+pub const EXIT_SIGTERM: i32 = 143;
 
 /// Clamps exit codes to POSIX-compliant range (0-255).
 ///
@@ -33,3 +44,16 @@ pub fn clamp_exit_code(code: i32) -> i32 {
         code => code,
     }
 }
+
+// Global `warn_on_exit_clamp` setting - initialized at startup, used by Lua
+static WARN_ON_EXIT_CLAMP: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether `syntropy.clamp_exit_code` warns about clamped codes, on by default
+pub fn set_warn_on_exit_clamp(warn: bool) {
+    WARN_ON_EXIT_CLAMP.store(warn, Ordering::Relaxed);
+}
+
+/// Whether clamped exit codes are reported, see [`set_warn_on_exit_clamp`]
+pub fn warn_on_exit_clamp() -> bool {
+    WARN_ON_EXIT_CLAMP.load(Ordering::Relaxed)
+}
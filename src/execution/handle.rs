@@ -1,15 +1,17 @@
 use std::{
     mem::replace,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use anyhow::{Result, ensure};
 use tokio::task::JoinHandle;
 
 use crate::{
+    cli::execute::select_items,
     execution::{
-        RuntimeHandle, SharedLua, clamp_exit_code,
-        runner::{run_execute_pipeline, run_items_pipeline, run_preview_pipeline},
+        ExecuteOutput, PreviewOutput, RuntimeHandle, SharedLua, clamp_exit_code,
+        runner::{run_execute_pipeline, run_items_pipeline_streaming, run_preview_pipeline},
     },
     plugins::Task,
 };
@@ -26,6 +28,12 @@ pub enum Operation {
         task: Arc<Task>,
         selected_items: Vec<String>,
     },
+    /// Fetches the items of `task` and executes it on `items`, matched like
+    /// `syntropy execute --items`. Without `items` they are picked by the task mode.
+    Run {
+        task: Arc<Task>,
+        items: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -43,15 +51,46 @@ pub enum ExecutionResult {
         items: Vec<String>,
         preselected_items: Vec<String>,
     },
-    Preview(String),
-    Output(String, i32),
+    Preview(PreviewOutput),
+    /// Execute output, clamped exit code and how long the execute pipeline took
+    Output(String, i32, Duration),
     Error(String),
     None,
 }
 
+impl ExecutionResult {
+    /// `Output` of a finished execute pipeline with any failure summary appended to the
+    /// output, `Error` when it failed
+    pub(crate) fn from_execute_output(output: Result<ExecuteOutput>) -> Self {
+        match output {
+            Ok(execute_output) => {
+                let output = match execute_output.failure_summary() {
+                    Some(summary) => format!("{}\n\n{}", execute_output.output, summary),
+                    None => execute_output.output,
+                };
+                ExecutionResult::Output(
+                    output,
+                    clamp_exit_code(execute_output.exit_code),
+                    execute_output.duration,
+                )
+            }
+            Err(output) => ExecutionResult::Error(format!("{:#}", output)),
+        }
+    }
+}
+
+/// Items an `Operation::Items` fetched so far, and whether any arrived since they were
+/// last consumed
+#[derive(Default)]
+struct PartialItems {
+    items: Vec<String>,
+    changed: bool,
+}
+
 pub struct Handle {
     state: Arc<Mutex<State>>,
     result: Arc<Mutex<ExecutionResult>>,
+    partial_items: Arc<Mutex<PartialItems>>,
     thread_handle: Option<JoinHandle<()>>,
     runtime_handle: RuntimeHandle,
     lua_runtime: SharedLua,
@@ -62,16 +101,27 @@ impl Handle {
         Handle {
             state: Arc::new(Mutex::new(State::None)),
             result: Arc::new(Mutex::new(ExecutionResult::None)),
+            partial_items: Arc::new(Mutex::new(PartialItems::default())),
             thread_handle: None,
             runtime_handle,
             lua_runtime: Arc::clone(lua_runtime),
         }
     }
 
-    async fn dispatch_task(operation: Operation, lua_runtime: SharedLua) -> ExecutionResult {
+    async fn dispatch_task(
+        operation: Operation,
+        lua_runtime: SharedLua,
+        partial_items: Arc<Mutex<PartialItems>>,
+    ) -> ExecutionResult {
         match &operation {
             Operation::Items { task } => {
-                let items = run_items_pipeline(lua_runtime, task).await;
+                let items = run_items_pipeline_streaming(lua_runtime, task, |items| {
+                    if let Ok(mut partial_guard) = partial_items.lock() {
+                        partial_guard.items.extend_from_slice(items);
+                        partial_guard.changed = true;
+                    }
+                })
+                .await;
                 match items {
                     Ok((items, preselected_items)) => ExecutionResult::Items {
                         items,
@@ -92,11 +142,17 @@ impl Handle {
                 selected_items,
             } => {
                 let output = run_execute_pipeline(lua_runtime, task, selected_items, None).await;
-                match output {
-                    Ok((output, exit_code)) => {
-                        ExecutionResult::Output(output, clamp_exit_code(exit_code))
+                ExecutionResult::from_execute_output(output)
+            }
+            Operation::Run { task, items } => {
+                let items_arg: Vec<&str> = items.iter().map(String::as_str).collect();
+                match select_items(&lua_runtime, task, &items_arg, false).await {
+                    Ok((selected_items, _)) => {
+                        let output =
+                            run_execute_pipeline(lua_runtime, task, &selected_items, None).await;
+                        ExecutionResult::from_execute_output(output)
                     }
-                    Err(output) => ExecutionResult::Error(format!("{:#}", output)),
+                    Err(e) => ExecutionResult::Error(format!("{:#}", e)),
                 }
             }
         }
@@ -120,9 +176,17 @@ impl Handle {
 
         let result_clone = Arc::clone(&self.result);
         let lua_runtime = Arc::clone(&self.lua_runtime);
+        let partial_items = Arc::clone(&self.partial_items);
+        self.clear_partial_items();
 
         let handle = self.runtime_handle.spawn(async move {
-            let result = Self::dispatch_task(operation, lua_runtime).await;
+            let result =
+                Self::dispatch_task(operation, lua_runtime, Arc::clone(&partial_items)).await;
+
+            // The result holds every item, partial ones must not be consumed after it
+            if let Ok(mut partial_guard) = partial_items.lock() {
+                *partial_guard = PartialItems::default();
+            }
 
             let state_lock = state_clone.lock();
             let result_lock = result_clone.lock();
@@ -163,6 +227,42 @@ impl Handle {
         }
     }
 
+    /// Items the running `Operation::Items` fetched so far, `None` unless more arrived since
+    /// the last call
+    ///
+    /// Items of `items_command` sources arrive line by line, the list can be shown while the
+    /// command still runs. The final list is returned by [`Handle::consume_result`].
+    #[must_use = "Partial items should be shown until the result arrives"]
+    pub fn consume_partial_items(&mut self) -> Option<Vec<String>> {
+        let mut partial_guard = self.partial_items.lock().ok()?;
+        if !partial_guard.changed {
+            return None;
+        }
+        partial_guard.changed = false;
+        Some(partial_guard.items.clone())
+    }
+
+    fn clear_partial_items(&mut self) {
+        if let Ok(mut partial_guard) = self.partial_items.lock() {
+            *partial_guard = PartialItems::default();
+        }
+    }
+
+    /// Aborts the running operation (if any) and discards its pending result
+    pub fn kill(&mut self) {
+        if let Some(handle) = self.thread_handle.take() {
+            handle.abort();
+        }
+        self.clear_partial_items();
+
+        if let Ok(mut state_guard) = self.state.lock()
+            && let Ok(mut result_guard) = self.result.lock()
+        {
+            *state_guard = State::None;
+            *result_guard = ExecutionResult::None;
+        }
+    }
+
     pub fn is_executing(&self) -> bool {
         self.state
             .lock()
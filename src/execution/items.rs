@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use mlua::{Lua, Table, Value};
+
+/// Value and preview of an item returned as a `{ display, value, preview }` table.
+///
+/// The TUI, search, selection and `--items` all work on the display string, the value only
+/// replaces it when the item reaches `execute` or `preview`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemDetails {
+    /// Passed to `execute` and `preview` instead of the display string
+    pub value: String,
+    /// Shown instead of calling the `preview` function
+    pub preview: Option<String>,
+}
+
+/// Item details of the last `items()` call of every item source, kept as Lua app data so
+/// every caller sharing the runtime resolves items the same way
+#[derive(Default)]
+struct ItemDetailsRegistry(HashMap<(String, String, String), HashMap<String, ItemDetails>>);
+
+/// Reads the items returned by an `items()` function.
+///
+/// Strings (and numbers) are items shown as-is. Tables need a `display` string and may set
+/// `value` and `preview`, their details are returned keyed by the display string.
+pub fn parse_items(
+    table: Table,
+    function_key: &str,
+) -> Result<(Vec<String>, HashMap<String, ItemDetails>)> {
+    let mut items = Vec::new();
+    let mut details = HashMap::new();
+
+    for pair in table.pairs::<usize, Value>() {
+        let (index, value) = pair.with_context(|| {
+            format!(
+                "Error reading table entry for lua function: {}",
+                function_key
+            )
+        })?;
+
+        let Value::Table(item_table) = value else {
+            let item: String = table.get(index).with_context(|| {
+                format!(
+                    "Error reading table entry for lua function: {}",
+                    function_key
+                )
+            })?;
+            items.push(item);
+            continue;
+        };
+
+        let display: String = item_table
+            .get::<Option<String>>("display")
+            .ok()
+            .flatten()
+            .with_context(|| {
+                format!(
+                    "Item table at index {} returned by {} needs a 'display' string",
+                    index, function_key
+                )
+            })?;
+        let value: Option<String> = item_table
+            .get("value")
+            .with_context(|| format!("Item '{}' has a 'value' that is not a string", display))?;
+        let preview: Option<String> = item_table
+            .get("preview")
+            .with_context(|| format!("Item '{}' has a 'preview' that is not a string", display))?;
+
+        if value.is_some() || preview.is_some() {
+            details.insert(
+                display.clone(),
+                ItemDetails {
+                    value: value.unwrap_or_else(|| display.clone()),
+                    preview,
+                },
+            );
+        }
+        items.push(display);
+    }
+
+    Ok((items, details))
+}
+
+/// Replaces the stored item details of an item source
+pub fn store_item_details(
+    lua: &Lua,
+    plugin_name: &str,
+    task_key: &str,
+    source_key: &str,
+    details: HashMap<String, ItemDetails>,
+) {
+    if lua.app_data_ref::<ItemDetailsRegistry>().is_none() {
+        lua.set_app_data(ItemDetailsRegistry::default());
+    }
+    if let Some(mut registry) = lua.app_data_mut::<ItemDetailsRegistry>() {
+        let key = (
+            plugin_name.to_string(),
+            task_key.to_string(),
+            source_key.to_string(),
+        );
+        if details.is_empty() {
+            registry.0.remove(&key);
+        } else {
+            registry.0.insert(key, details);
+        }
+    }
+}
+
+/// Details of an untagged item from the last `items()` call of its source, `None` for plain
+/// string items
+pub fn item_details(
+    lua: &Lua,
+    plugin_name: &str,
+    task_key: &str,
+    source_key: &str,
+    item: &str,
+) -> Option<ItemDetails> {
+    let registry = lua.app_data_ref::<ItemDetailsRegistry>()?;
+    registry
+        .0
+        .get(&(
+            plugin_name.to_string(),
+            task_key.to_string(),
+            source_key.to_string(),
+        ))?
+        .get(item)
+        .cloned()
+}
+
+/// Maps untagged items to the values `execute` receives
+pub fn item_values(
+    lua: &Lua,
+    plugin_name: &str,
+    task_key: &str,
+    source_key: &str,
+    items: &[String],
+) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| {
+            item_details(lua, plugin_name, task_key, source_key, item)
+                .map(|details| details.value)
+                .unwrap_or_else(|| item.clone())
+        })
+        .collect()
+}
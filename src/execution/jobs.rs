@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result, ensure};
+
+use crate::{
+    execution::{ExecutionResult, Handle, Operation, RuntimeHandle, SharedLua, State},
+    plugins::Task,
+};
+
+pub type JobId = usize;
+
+/// A background task execution tracked by the `JobRegistry`
+pub struct Job {
+    pub id: JobId,
+    pub task: Arc<Task>,
+    pub selected_items: Vec<String>,
+    handle: Handle,
+    state: State,
+    output: Option<String>,
+}
+
+impl Job {
+    /// Current job state.
+    ///
+    /// - `Running` while the task executes
+    /// - `Finished` once it exited with code 0
+    /// - `Error` when it exited with a non-zero code or failed
+    /// - `None` when it was killed
+    pub fn state(&self) -> State {
+        if self.handle.is_executing() {
+            State::Running
+        } else {
+            self.state.clone()
+        }
+    }
+
+    /// Output of the last completed run, if any
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.output = None;
+        self.state = State::Running;
+        self.handle.execute(Operation::Execute {
+            task: Arc::clone(&self.task),
+            selected_items: self.selected_items.clone(),
+        })
+    }
+}
+
+/// Tracks background task executions spawned on the tokio runtime.
+///
+/// Each job owns its own `Handle`, so jobs run independently of the screen that started
+/// them. Dropping the registry (or removing a job) aborts the underlying execution.
+pub struct JobRegistry {
+    jobs: Vec<Job>,
+    next_id: JobId,
+    runtime_handle: RuntimeHandle,
+    lua_runtime: SharedLua,
+}
+
+impl JobRegistry {
+    pub fn new(runtime_handle: RuntimeHandle, lua_runtime: &SharedLua) -> Self {
+        Self {
+            jobs: Vec::new(),
+            next_id: 1,
+            runtime_handle,
+            lua_runtime: Arc::clone(lua_runtime),
+        }
+    }
+
+    /// Starts executing `task` in the background and returns the new job id
+    pub fn spawn(&mut self, task: Arc<Task>, selected_items: Vec<String>) -> Result<JobId> {
+        let id = self.next_id;
+        let mut job = Job {
+            id,
+            task,
+            selected_items,
+            handle: Handle::new(self.runtime_handle.clone(), &self.lua_runtime),
+            state: State::None,
+            output: None,
+        };
+        job.start()?;
+
+        self.next_id += 1;
+        self.jobs.push(job);
+        Ok(id)
+    }
+
+    /// Aborts a running job. The job stays in the registry and can be restarted.
+    pub fn kill(&mut self, id: JobId) -> Result<()> {
+        let job = self.get_mut(id)?;
+        job.handle.kill();
+        job.state = State::None;
+        Ok(())
+    }
+
+    /// Runs a job that is not currently running again with the same items
+    pub fn restart(&mut self, id: JobId) -> Result<()> {
+        let job = self.get_mut(id)?;
+        ensure!(!job.handle.is_executing(), "Job #{} is still running", id);
+        job.start()
+    }
+
+    /// Removes a job from the registry, aborting it if it is still running
+    pub fn remove(&mut self, id: JobId) -> Option<Job> {
+        let position = self.jobs.iter().position(|job| job.id == id)?;
+        let mut job = self.jobs.remove(position);
+        job.handle.kill();
+        Some(job)
+    }
+
+    pub fn status(&self, id: JobId) -> Option<State> {
+        self.get(id).map(Job::state)
+    }
+
+    pub fn get(&self, id: JobId) -> Option<&Job> {
+        self.jobs.iter().find(|job| job.id == id)
+    }
+
+    /// Iterates over all jobs in the order they were spawned
+    pub fn jobs(&self) -> impl Iterator<Item = &Job> {
+        self.jobs.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Collects results of jobs that completed since the last poll.
+    /// Returns `true` if any job changed state.
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        for job in &mut self.jobs {
+            match job.handle.consume_result() {
+                ExecutionResult::Output(output, exit_code, _) => {
+                    job.state = if exit_code == 0 {
+                        State::Finished
+                    } else {
+                        State::Error
+                    };
+                    job.output = Some(output);
+                    changed = true;
+                }
+                ExecutionResult::Error(output) => {
+                    job.state = State::Error;
+                    job.output = Some(output);
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        changed
+    }
+
+    fn get_mut(&mut self, id: JobId) -> Result<&mut Job> {
+        self.jobs
+            .iter_mut()
+            .find(|job| job.id == id)
+            .with_context(|| format!("Job #{} not found", id))
+    }
+}
@@ -1,10 +1,17 @@
-use mlua::Table;
+use std::{collections::BTreeMap, time::Duration};
+
+use mlua::{FromLua, Table};
+use serde::Serialize;
 
 use crate::{
-    execution::SharedLua,
+    execution::{
+        ExecutionError, SharedLua,
+        items::{parse_items, store_item_details},
+        vm_lease::LeasedVm,
+    },
     lua::{
-        get_lua_function, get_optional_lua_function, lua_table_to_vec_string,
-        vec_string_to_lua_table,
+        GlobalHook, call_global_hook, get_lua_function, get_optional_lua_function,
+        lua_table_to_vec_string, vec_string_to_lua_table,
     },
     plugins::{ItemSource, Plugin, Task},
 };
@@ -25,6 +32,112 @@ impl Drop for RegistryCleanupGuard<'_> {
     }
 }
 
+/// Return values of a Lua `execute(items)` function
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExecuteOutput {
+    pub output: String,
+    pub exit_code: i32,
+    /// Optional third return value of `execute`, a table mapping each item to its own exit code
+    pub item_exit_codes: Option<BTreeMap<String, i32>>,
+    /// Per-source results, in execution order, filled in by the execute pipeline
+    pub sources: Vec<SourceExecution>,
+    /// Wall time of the whole execute pipeline, set by the execute pipeline
+    pub duration: Duration,
+}
+
+/// Result of running `execute` for the items of one item source
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SourceExecution {
+    /// Item source key, `None` for tasks without item sources
+    pub source: Option<String>,
+    pub items_executed: usize,
+    pub output: String,
+    pub exit_code: i32,
+}
+
+/// Return value of a Lua `preview(item)` function, a string or a `{ content, syntax }` table
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PreviewOutput {
+    pub content: String,
+    /// Syntax the TUI highlights `content` as, a language name or file extension
+    pub syntax: Option<String>,
+}
+
+impl PreviewOutput {
+    /// Preview shown as plain text
+    pub fn plain(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            syntax: None,
+        }
+    }
+}
+
+// Strings (and numbers) are plain previews, tables need a `content` string
+fn parse_preview_output(
+    lua: &mlua::Lua,
+    value: mlua::Value,
+    function: &str,
+) -> Result<PreviewOutput> {
+    let mlua::Value::Table(table) = value else {
+        let content = String::from_lua(value, lua)
+            .map_err(ExecutionError::LuaError)
+            .with_context(|| format!("Error calling {}()", function))?;
+        return Ok(PreviewOutput::plain(content));
+    };
+
+    let content: String = table
+        .get::<Option<String>>("content")
+        .ok()
+        .flatten()
+        .with_context(|| format!("Table returned by {}() needs a 'content' string", function))?;
+    let syntax: Option<String> = table.get("syntax").with_context(|| {
+        format!(
+            "Table returned by {}() has a 'syntax' that is not a string",
+            function
+        )
+    })?;
+    Ok(PreviewOutput { content, syntax })
+}
+
+impl ExecuteOutput {
+    /// Items reported with a non-zero exit code, in item order
+    pub fn failed_items(&self) -> Vec<(&str, i32)> {
+        self.item_exit_codes
+            .iter()
+            .flatten()
+            .filter(|(_, exit_code)| **exit_code != 0)
+            .map(|(item, exit_code)| (item.as_str(), *exit_code))
+            .collect()
+    }
+
+    /// Turns a successful result into a failure carrying the first failing item's exit code
+    pub fn propagate_item_failures(&mut self) {
+        if self.exit_code == 0
+            && let Some((_, exit_code)) = self.failed_items().first()
+        {
+            self.exit_code = *exit_code;
+        }
+    }
+
+    /// One line per failed item, `None` when no item failed
+    pub fn failure_summary(&self) -> Option<String> {
+        let failed_items = self.failed_items();
+        if failed_items.is_empty() {
+            return None;
+        }
+        let lines: Vec<String> = failed_items
+            .iter()
+            .map(|(item, exit_code)| format!("  - {} (exit code {})", item, exit_code))
+            .collect();
+        Some(format!(
+            "{} item(s) failed:\n{}",
+            failed_items.len(),
+            lines.join("\n")
+        ))
+    }
+}
+
 pub async fn has_item_source_execute(lua: &SharedLua, task: &Task, source_key: &str) -> bool {
     let lua_guard = lua.lock().await;
 
@@ -49,7 +162,7 @@ pub async fn call_item_source_items(
     task_key: &str,
     source_key: &str,
 ) -> Result<Vec<String>> {
-    let lua_guard = lua.lock().await;
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -68,9 +181,10 @@ pub async fn call_item_source_items(
 
     let _cleanup_guard = RegistryCleanupGuard { lua: &lua_guard };
 
-    let result: Result<Table> = items_fn
-        .call_async(())
+    let result: Result<Table> = lua_guard
+        .run(items_fn.call_async(()))
         .await
+        .map_err(ExecutionError::LuaError)
         .with_context(|| format!("Error calling {}()", path.join(".")));
 
     // Clear plugin context (belt-and-suspenders with guard)
@@ -78,8 +192,43 @@ pub async fn call_item_source_items(
         .set_named_registry_value("__syntropy_current_plugin__", mlua::Value::Nil)
         .context("Failed to clear current plugin context")?;
 
-    let result = result?;
-    lua_table_to_vec_string(result, ItemSource::LUA_FN_NAME_ITEMS)
+    let (items, details) = parse_items(result?, ItemSource::LUA_FN_NAME_ITEMS)?;
+    store_item_details(&lua_guard, plugin_name, task_key, source_key, details);
+    Ok(items)
+}
+
+/// Calls the optional `enabled()` of an item source, sources without one are enabled
+pub async fn call_item_source_enabled(
+    lua: &SharedLua,
+    plugin_name: &str,
+    task_key: &str,
+    source_key: &str,
+) -> Result<bool> {
+    let lua_guard = LeasedVm::acquire(lua).await;
+
+    let path = &[
+        plugin_name,
+        Plugin::LUA_PROPERTY_TASKS,
+        task_key,
+        Task::LUA_PROPERTY_ITEM_SOURCES,
+        source_key,
+        ItemSource::LUA_FN_NAME_ENABLED,
+    ];
+    let Some(enabled_fn) = get_optional_lua_function(&lua_guard, path)? else {
+        return Ok(true);
+    };
+
+    lua_guard
+        .set_named_registry_value("__syntropy_current_plugin__", plugin_name)
+        .context("Failed to set current plugin context")?;
+
+    let _cleanup_guard = RegistryCleanupGuard { lua: &lua_guard };
+
+    lua_guard
+        .run(enabled_fn.call_async::<bool>(()))
+        .await
+        .map_err(ExecutionError::LuaError)
+        .with_context(|| format!("Error calling {}()", path.join(".")))
 }
 
 pub async fn call_item_source_preselected_items(
@@ -88,7 +237,7 @@ pub async fn call_item_source_preselected_items(
     task_key: &str,
     source_key: &str,
 ) -> Result<Vec<String>> {
-    let lua_guard = lua.lock().await;
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -106,9 +255,10 @@ pub async fn call_item_source_preselected_items(
 
     let result = match get_optional_lua_function(&lua_guard, path)? {
         Some(func) => {
-            let table_result: Result<Table> = func
-                .call_async(())
+            let table_result: Result<Table> = lua_guard
+                .run(func.call_async(()))
                 .await
+                .map_err(ExecutionError::LuaError)
                 .with_context(|| format!("Error calling {}()", path.join(".")));
             match table_result {
                 Ok(table) => {
@@ -132,8 +282,8 @@ pub async fn call_item_source_preview(
     task_key: &str,
     source_key: &str,
     current_item: &str,
-) -> Result<Option<String>> {
-    let lua_guard = lua.lock().await;
+) -> Result<Option<PreviewOutput>> {
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -150,14 +300,12 @@ pub async fn call_item_source_preview(
 
     let result = match get_optional_lua_function(&lua_guard, path)? {
         Some(func) => {
-            let res: Result<String> = func
-                .call_async(current_item)
+            let value: mlua::Value = lua_guard
+                .run(func.call_async(current_item))
                 .await
-                .with_context(|| format!("Error calling {}()", path.join(".")));
-            match res {
-                Ok(s) => Ok(Some(s)),
-                Err(e) => Err(e),
-            }
+                .map_err(ExecutionError::LuaError)
+                .with_context(|| format!("Error calling {}()", path.join(".")))?;
+            parse_preview_output(&lua_guard, value, &path.join(".")).map(Some)
         }
         None => Ok(None),
     };
@@ -171,8 +319,8 @@ pub async fn call_item_source_execute(
     task: &Task,
     source_key: &str,
     selected_items: &[String],
-) -> Result<(String, i32)> {
-    let lua_guard = lua.lock().await;
+) -> Result<ExecuteOutput> {
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         &task.plugin_name,
@@ -191,17 +339,19 @@ pub async fn call_item_source_execute(
     let items_table =
         vec_string_to_lua_table(&lua_guard, selected_items, ItemSource::LUA_FN_NAME_EXECUTE)?;
 
-    let result: Result<(String, i32)> = execute_fn
-        .call_async(items_table)
+    let result: Result<ExecuteOutput> = lua_guard
+        .run(execute_fn.call_async(items_table))
         .await
-        .with_context(|| format!("Error calling {}(),", path.join(".")));
+        .map_err(ExecutionError::LuaError)
+        .with_context(|| format!("Error calling {}(),", path.join(".")))
+        .and_then(|values| to_execute_output(values, path));
 
     lua_guard.set_named_registry_value("__syntropy_current_plugin__", mlua::Value::Nil)?;
     result
 }
 
 pub async fn call_task_pre_run(lua: &SharedLua, plugin_name: &str, task_key: &str) -> Result<()> {
-    let lua_guard = lua.lock().await;
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -214,12 +364,25 @@ pub async fn call_task_pre_run(lua: &SharedLua, plugin_name: &str, task_key: &st
 
     let _cleanup_guard = RegistryCleanupGuard { lua: &lua_guard };
 
-    let result = match get_optional_lua_function(&lua_guard, path)? {
-        Some(func) => func
-            .call_async::<()>(())
-            .await
-            .with_context(|| format!("Error calling {}()", path.join("."))),
-        None => Ok(()),
+    // The global hook runs first, its failure skips the task's own pre_run
+    let result = match lua_guard
+        .run(call_global_hook(
+            &lua_guard,
+            GlobalHook::PreRun,
+            plugin_name,
+            task_key,
+        ))
+        .await
+    {
+        Err(e) => Err(e),
+        Ok(()) => match get_optional_lua_function(&lua_guard, path)? {
+            Some(func) => lua_guard
+                .run(func.call_async::<()>(()))
+                .await
+                .map_err(ExecutionError::LuaError)
+                .with_context(|| format!("Error calling {}()", path.join("."))),
+            None => Ok(()),
+        },
     };
 
     lua_guard.set_named_registry_value("__syntropy_current_plugin__", mlua::Value::Nil)?;
@@ -227,7 +390,7 @@ pub async fn call_task_pre_run(lua: &SharedLua, plugin_name: &str, task_key: &st
 }
 
 pub async fn call_task_post_run(lua: &SharedLua, plugin_name: &str, task_key: &str) -> Result<()> {
-    let lua_guard = lua.lock().await;
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -240,16 +403,26 @@ pub async fn call_task_post_run(lua: &SharedLua, plugin_name: &str, task_key: &s
 
     let _cleanup_guard = RegistryCleanupGuard { lua: &lua_guard };
 
-    let result = match get_optional_lua_function(&lua_guard, path)? {
-        Some(func) => func
-            .call_async::<()>(())
+    let task_result = match get_optional_lua_function(&lua_guard, path)? {
+        Some(func) => lua_guard
+            .run(func.call_async::<()>(()))
             .await
+            .map_err(ExecutionError::LuaError)
             .with_context(|| format!("Error calling {}()", path.join("."))),
         None => Ok(()),
     };
+    // The global hook runs last, even after a failed post_run, like the cleanup it may do
+    let global_result = lua_guard
+        .run(call_global_hook(
+            &lua_guard,
+            GlobalHook::PostRun,
+            plugin_name,
+            task_key,
+        ))
+        .await;
 
     lua_guard.set_named_registry_value("__syntropy_current_plugin__", mlua::Value::Nil)?;
-    result
+    task_result.and(global_result)
 }
 
 pub async fn call_task_preview(
@@ -257,8 +430,8 @@ pub async fn call_task_preview(
     plugin_name: &str,
     task_key: &str,
     current_item: &str,
-) -> Result<Option<String>> {
-    let lua_guard = lua.lock().await;
+) -> Result<Option<PreviewOutput>> {
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         plugin_name,
@@ -273,14 +446,12 @@ pub async fn call_task_preview(
 
     let result = match get_optional_lua_function(&lua_guard, path)? {
         Some(func) => {
-            let res: Result<String> = func
-                .call_async(current_item)
+            let value: mlua::Value = lua_guard
+                .run(func.call_async(current_item))
                 .await
-                .with_context(|| format!("Error calling {}()", path.join(".")));
-            match res {
-                Ok(s) => Ok(Some(s)),
-                Err(e) => Err(e),
-            }
+                .map_err(ExecutionError::LuaError)
+                .with_context(|| format!("Error calling {}()", path.join(".")))?;
+            parse_preview_output(&lua_guard, value, &path.join(".")).map(Some)
         }
         None => Ok(None),
     };
@@ -294,7 +465,17 @@ pub async fn call_task_execute(
     task: &Task,
     selected_items: &[String],
 ) -> Result<(String, i32)> {
-    let lua_guard = lua.lock().await;
+    let execute_output = call_task_execute_output(lua, task, selected_items).await?;
+    Ok((execute_output.output, execute_output.exit_code))
+}
+
+/// Like [`call_task_execute`], but keeps the optional per-item exit codes
+pub async fn call_task_execute_output(
+    lua: &SharedLua,
+    task: &Task,
+    selected_items: &[String],
+) -> Result<ExecuteOutput> {
+    let lua_guard = LeasedVm::acquire(lua).await;
 
     let path = &[
         &task.plugin_name,
@@ -311,11 +492,39 @@ pub async fn call_task_execute(
     let items_table =
         vec_string_to_lua_table(&lua_guard, selected_items, Task::LUA_FN_NAME_EXECUTE)?;
 
-    let result: Result<(String, i32)> = execute_fn
-        .call_async(items_table)
+    let result: Result<ExecuteOutput> = lua_guard
+        .run(execute_fn.call_async(items_table))
         .await
-        .with_context(|| format!("Error calling {}()", path.join(".")));
+        .map_err(ExecutionError::LuaError)
+        .with_context(|| format!("Error calling {}()", path.join(".")))
+        .and_then(|values| to_execute_output(values, path));
 
     lua_guard.set_named_registry_value("__syntropy_current_plugin__", mlua::Value::Nil)?;
     result
 }
+
+fn to_execute_output(
+    (output, exit_code, item_exit_codes): (String, i32, Option<Table>),
+    path: &[&str],
+) -> Result<ExecuteOutput> {
+    let item_exit_codes = item_exit_codes
+        .map(|table| {
+            table
+                .pairs::<String, i32>()
+                .collect::<mlua::Result<BTreeMap<_, _>>>()
+                .with_context(|| {
+                    format!(
+                        "{}() third return value must map items to integer exit codes",
+                        path.join(".")
+                    )
+                })
+        })
+        .transpose()?;
+
+    Ok(ExecuteOutput {
+        output,
+        exit_code,
+        item_exit_codes,
+        ..ExecuteOutput::default()
+    })
+}
@@ -1,19 +1,40 @@
+mod error;
 pub mod exit_code;
 mod handle;
+pub mod items;
+pub mod jobs;
 mod lua;
+pub mod plan;
 pub mod runner;
+mod vm_lease;
 
 use std::sync::Arc;
 
-pub use exit_code::{EXIT_FAILURE, EXIT_SIGINT, EXIT_SUCCESS, clamp_exit_code};
+pub use error::ExecutionError;
+pub use exit_code::{
+    EXIT_FAILURE, EXIT_ITEM_NOT_FOUND, EXIT_LUA_ERROR, EXIT_NO_ITEMS, EXIT_PLUGIN_NOT_FOUND,
+    EXIT_PROCESS_ERROR, EXIT_SIGINT, EXIT_SIGTERM, EXIT_SUCCESS, EXIT_TASK_NOT_FOUND,
+    clamp_exit_code, set_warn_on_exit_clamp, warn_on_exit_clamp,
+};
 pub use handle::{ExecutionResult, Handle, Operation, State};
+pub use items::ItemDetails;
+pub use jobs::{Job, JobId, JobRegistry};
+pub use lua::{
+    ExecuteOutput, PreviewOutput, SourceExecution, call_item_source_items, call_task_execute,
+    call_task_execute_output,
+};
 pub(crate) use lua::{
-    call_item_source_execute, call_item_source_preselected_items, call_item_source_preview,
-    call_task_post_run, call_task_pre_run, call_task_preview, has_item_source_execute,
+    call_item_source_enabled, call_item_source_execute, call_item_source_preselected_items,
+    call_item_source_preview, call_task_post_run, call_task_pre_run, call_task_preview,
+    has_item_source_execute,
 };
-pub use lua::{call_item_source_items, call_task_execute};
 use mlua::Lua;
-pub use runner::{run_execute_pipeline, run_items_pipeline, run_preview_pipeline};
+pub use plan::ExecutionPlan;
+pub use runner::{
+    resolve_item_value, run_execute_pipeline, run_items_pipeline, run_items_pipeline_streaming,
+    run_preview_pipeline, run_preview_pipeline_batch,
+};
+pub(crate) use vm_lease::release_vm_while;
 
 type SharedLua = Arc<tokio::sync::Mutex<Lua>>;
 type RuntimeHandle = tokio::runtime::Handle;
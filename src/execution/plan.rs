@@ -0,0 +1,64 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::configs::get_default_data_dir;
+
+/// A recorded task selection that `syntropy execute --plan <file>` replays.
+///
+/// Items are stored exactly as shown in the TUI, so items of multi-source
+/// tasks keep their `[tag] ` prefix.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExecutionPlan {
+    pub plugin: String,
+    pub task: String,
+    #[serde(default)]
+    pub items: Vec<String>,
+}
+
+impl ExecutionPlan {
+    pub fn new(plugin: impl Into<String>, task: impl Into<String>, items: Vec<String>) -> Self {
+        Self {
+            plugin: plugin.into(),
+            task: task.into(),
+            items,
+        }
+    }
+
+    /// Reads a plan from a JSON file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read plan file {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse plan file {}", path.display()))
+    }
+
+    /// Writes the plan as pretty-printed JSON, creating parent directories as needed
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize plan")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write plan file {}", path.display()))
+    }
+
+    /// Saves the plan to `<data_dir>/plans/<plugin>-<task>-<timestamp>.json` and returns the path
+    pub fn dump(&self) -> Result<PathBuf> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let path = get_default_data_dir()?
+            .join("plans")
+            .join(format!("{}-{}-{}.json", self.plugin, self.task, timestamp));
+        self.save(&path)?;
+        Ok(path)
+    }
+}
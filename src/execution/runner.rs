@@ -1,16 +1,30 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    process::Stdio,
+    sync::Arc,
+    time::Instant,
+};
 
 use anyhow::{Context, Result, bail, ensure};
+use indexmap::IndexMap;
 use mlua::Lua;
-use tokio::sync::Mutex;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, BufReader},
+    process::Command,
+    sync::Mutex,
+};
+use tracing::{debug, info, warn};
 
 use crate::{
     execution::{
-        EXIT_FAILURE, EXIT_SIGINT, call_item_source_execute, call_item_source_items,
-        call_item_source_preselected_items, call_item_source_preview, call_task_execute,
+        EXIT_FAILURE, EXIT_SIGINT, ExecuteOutput, ExecutionError, PreviewOutput, SourceExecution,
+        call_item_source_enabled, call_item_source_execute, call_item_source_items,
+        call_item_source_preselected_items, call_item_source_preview, call_task_execute_output,
         call_task_post_run, call_task_pre_run, call_task_preview, has_item_source_execute,
+        items::{ItemDetails, item_details, item_values},
     },
-    plugins::Task,
+    plugins::{Dedup, DefaultSelect, ItemSource, Plugin, Task},
+    signal::track_child,
 };
 
 /// Executes the items pipeline to fetch and prepare items from all item sources.
@@ -19,8 +33,10 @@ use crate::{
 ///
 /// 1. Executes the task's `pre_run` hook (if defined)
 /// 2. Validates that the task has at least one item source
-/// 3. For each item source, fetches items and preselected items
-/// 4. Applies tag prefixing when multiple item sources exist
+/// 3. For each item source, fetches items, from `items()` or the stdout lines of
+///    `items_command`, and preselected items
+/// 4. Removes duplicate items according to the task's `dedup` strategy
+/// 5. Applies tag prefixing when multiple item sources exist
 ///
 /// # Tag Encoding
 ///
@@ -38,7 +54,8 @@ use crate::{
 ///
 /// Returns a tuple of `(items, preselected_items)` where:
 /// - `items` - Combined list of all items from all sources (with tags if multiple sources)
-/// - `preselected_items` - Combined list of preselected items (with tags if multiple sources)
+/// - `preselected_items` - Combined list of preselected items (with tags if multiple sources),
+///   every item or none at all depending on the task's `default_select`
 ///
 /// # Errors
 ///
@@ -46,6 +63,19 @@ use crate::{
 pub async fn run_items_pipeline(
     lua: Arc<Mutex<Lua>>,
     task: &Task,
+) -> Result<(Vec<String>, Vec<String>)> {
+    run_items_pipeline_streaming(lua, task, |_| {}).await
+}
+
+/// Like [`run_items_pipeline`], but hands items to `on_items` as soon as they are final
+///
+/// Items of an `items()` function arrive in one call per source, lines of an
+/// `items_command` one call per line while the command still runs. The calls add up to the
+/// returned items, in order.
+pub async fn run_items_pipeline_streaming(
+    lua: Arc<Mutex<Lua>>,
+    task: &Task,
+    mut on_items: impl FnMut(&[String]),
 ) -> Result<(Vec<String>, Vec<String>)> {
     let Some(item_sources) = &task.item_sources else {
         bail!("No item_sources for task: {}", task.task_key);
@@ -56,22 +86,112 @@ pub async fn run_items_pipeline(
     let mut joined_items = Vec::new();
     let mut joined_preselected_items = Vec::new();
     let mut source_errors: Vec<(String, anyhow::Error)> = Vec::new();
+    let mut seen_values = HashSet::new();
 
     ensure!(!item_sources.is_empty(), "No items");
 
     for (item_source_key, item_source) in item_sources {
-        let items =
-            match call_item_source_items(&lua, &task.plugin_name, &task.task_key, item_source_key)
+        // A disabled source counts as one without items
+        match call_item_source_enabled(&lua, &task.plugin_name, &task.task_key, item_source_key)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => {
+                source_errors.push((item_source_key.clone(), e));
+                continue;
+            }
+        }
+
+        let tag_item = |item: &String| {
+            if item_sources.len() == 1 {
+                item.clone()
+            } else {
+                format!("[{}] {}", item_source.tag, item)
+            }
+        };
+        if task.dedup == Dedup::PerSource {
+            seen_values.clear();
+        }
+
+        let items = match &item_source.items_command {
+            // Lines are deduplicated and passed on one by one, they have no details so an
+            // item is its own value
+            Some(command) => {
+                let mut items = Vec::new();
+                let streamed = stream_command_lines(command, |line| {
+                    if task.dedup == Dedup::None || seen_values.insert(line.clone()) {
+                        let item = tag_item(&line);
+                        on_items(std::slice::from_ref(&item));
+                        joined_items.push(item);
+                        items.push(line);
+                    }
+                })
                 .await
-            {
-                Ok(items) => items,
-                Err(e) => {
+                .with_context(|| {
+                    let path = [
+                        task.plugin_name.as_str(),
+                        Plugin::LUA_PROPERTY_TASKS,
+                        &task.task_key,
+                        Task::LUA_PROPERTY_ITEM_SOURCES,
+                        item_source_key,
+                        ItemSource::LUA_PROPERTY_ITEMS_COMMAND,
+                    ];
+                    format!("Error running {}", path.join("."))
+                });
+                if let Err(e) = streamed {
                     source_errors.push((item_source_key.clone(), e));
-                    continue; // Skip to next source
+                    if items.is_empty() {
+                        continue;
+                    }
                 }
-            };
+                items
+            }
+            None => {
+                let items = match call_item_source_items(
+                    &lua,
+                    &task.plugin_name,
+                    &task.task_key,
+                    item_source_key,
+                )
+                .await
+                {
+                    Ok(items) => items,
+                    Err(e) => {
+                        source_errors.push((item_source_key.clone(), e));
+                        continue; // Skip to next source
+                    }
+                };
+                let items = if task.dedup == Dedup::None {
+                    items
+                } else {
+                    let values = item_values(
+                        &*lua.lock().await,
+                        &task.plugin_name,
+                        &task.task_key,
+                        item_source_key,
+                        &items,
+                    );
+                    items
+                        .into_iter()
+                        .zip(values)
+                        .filter(|(_, value)| seen_values.insert(value.clone()))
+                        .map(|(item, _)| item)
+                        .collect()
+                };
+                let tagged: Vec<String> = items.iter().map(tag_item).collect();
+                on_items(&tagged);
+                joined_items.extend(tagged);
+                items
+            }
+        };
 
-        let preselected_items = match call_item_source_preselected_items(
+        // Only `default_select = "preselected"` uses what the sources preselect
+        if task.default_select != DefaultSelect::Preselected {
+            continue;
+        }
+
+        let mut preselected_items = match call_item_source_preselected_items(
             &lua,
             &task.plugin_name,
             &task.task_key,
@@ -90,31 +210,21 @@ pub async fn run_items_pipeline(
             }
         };
 
-        if item_sources.len() == 1 {
-            joined_items.extend(items);
-        } else {
-            joined_items.extend(items.iter().map(|s| format!("[{}] {}", item_source.tag, s)));
-        }
-
-        if item_sources.len() == 1 {
-            joined_preselected_items.extend(preselected_items);
-        } else {
-            joined_preselected_items.extend(
-                preselected_items
-                    .iter()
-                    .map(|s| format!("[{}] {}", item_source.tag, s)),
-            );
+        // Preselected items that were dropped as duplicates can't be selected
+        if task.dedup != Dedup::None {
+            let kept: HashSet<&String> = items.iter().collect();
+            preselected_items.retain(|item| kept.contains(item));
         }
+        joined_preselected_items.extend(preselected_items.iter().map(tag_item));
     }
 
     // Fail only if ALL sources failed
     if joined_items.is_empty() && !source_errors.is_empty() {
-        let error_details = source_errors
-            .iter()
-            .map(|(key, e)| format!("  - {}: {:#}", key, e))
-            .collect::<Vec<_>>()
-            .join("\n");
-        bail!("All item sources failed:\n{}", error_details);
+        return Err(all_sources_failed(source_errors));
+    }
+
+    if task.default_select == DefaultSelect::All {
+        joined_preselected_items = joined_items.clone();
     }
 
     Ok((joined_items, joined_preselected_items))
@@ -142,7 +252,8 @@ pub async fn run_items_pipeline(
 ///
 /// # Returns
 ///
-/// Returns the preview text generated by the item source or task preview function.
+/// Returns the preview generated by the item source or task preview function, with the
+/// syntax it asked to be highlighted as.
 ///
 /// # Errors
 ///
@@ -151,52 +262,119 @@ pub async fn run_preview_pipeline(
     lua: Arc<Mutex<Lua>>,
     task: &Task,
     current_item: &str,
-) -> Result<String> {
+) -> Result<PreviewOutput> {
     let Some(item_sources) = &task.item_sources else {
         bail!("No preview available");
     };
-    let item_source = if item_sources.len() == 1 {
-        item_sources.values().next().context(
-            "Plugin declares an item_source, but it's missing. This should never happen.",
-        )?
-    } else {
-        let tag = parse_tag(current_item).0.with_context(|| {
-            format!(
-                "Item '{}' missing expected tag in multi-source task",
-                current_item
-            )
-        })?;
-        item_sources
-            .values()
-            .find(|item_source| item_source.tag == tag)
-            .with_context(|| {
-                format!(
-                    "Item source for tag {} is missing. This should never happen",
-                    tag
-                )
-            })?
-    };
+    let item_source = resolve_item_source(item_sources, current_item)?;
 
     let item = strip_tag(current_item);
 
+    let details = item_details(
+        &*lua.lock().await,
+        &task.plugin_name,
+        &task.task_key,
+        &item_source.item_source_key,
+        item,
+    );
+    let item = match details {
+        Some(ItemDetails {
+            preview: Some(preview),
+            ..
+        }) => return Ok(PreviewOutput::plain(preview)),
+        Some(details) => details.value,
+        None => item.to_string(),
+    };
+
     let preview = call_item_source_preview(
         &lua,
         &task.plugin_name,
         &task.task_key,
         &item_source.item_source_key,
-        item,
+        &item,
     )
     .await?;
 
     let preview = match preview {
         Some(output) => output,
-        None => call_task_preview(&lua, &task.plugin_name, &task.task_key, item)
+        None => call_task_preview(&lua, &task.plugin_name, &task.task_key, &item)
             .await?
-            .unwrap_or_else(|| String::from("No preview")),
+            .unwrap_or_else(|| PreviewOutput::plain("No preview")),
     };
     Ok(preview)
 }
 
+/// Generates the previews of several items of a task, keyed by item.
+///
+/// Every item goes through [`run_preview_pipeline`], so the item source's `preview` takes
+/// precedence over the task's. A failing item is kept as its error and doesn't stop the
+/// remaining items. Duplicate items are previewed once.
+pub async fn run_preview_pipeline_batch(
+    lua: Arc<Mutex<Lua>>,
+    task: &Task,
+    items: &[String],
+) -> HashMap<String, Result<PreviewOutput>> {
+    let mut previews = HashMap::with_capacity(items.len());
+    for item in items {
+        if previews.contains_key(item) {
+            continue;
+        }
+        let preview = run_preview_pipeline(Arc::clone(&lua), task, item).await;
+        previews.insert(item.clone(), preview);
+    }
+    previews
+}
+
+/// Value `execute` receives for an item of the item list, the `value` of a structured item or
+/// the item without its `[tag]` prefix
+pub fn resolve_item_value(lua: &Lua, task: &Task, current_item: &str) -> Result<String> {
+    let item_sources = task
+        .item_sources
+        .as_ref()
+        .context("Task has no item sources")?;
+    let item_source = resolve_item_source(item_sources, current_item)?;
+    let item = strip_tag(current_item);
+
+    Ok(item_details(
+        lua,
+        &task.plugin_name,
+        &task.task_key,
+        &item_source.item_source_key,
+        item,
+    )
+    .map(|details| details.value)
+    .unwrap_or_else(|| item.to_string()))
+}
+
+// Item source an item of the item list came from, found by its tag in multi-source tasks
+fn resolve_item_source<'a>(
+    item_sources: &'a IndexMap<String, ItemSource>,
+    current_item: &str,
+) -> Result<&'a ItemSource> {
+    if item_sources.len() == 1 {
+        item_sources
+            .values()
+            .next()
+            .context("Plugin declares an item_source, but it's missing. This should never happen.")
+    } else {
+        let tag = parse_tag(current_item).0.with_context(|| {
+            format!(
+                "Item '{}' missing expected tag in multi-source task",
+                current_item
+            )
+        })?;
+        item_sources
+            .values()
+            .find(|item_source| item_source.tag == tag)
+            .with_context(|| {
+                format!(
+                    "Item source for tag {} is missing. This should never happen",
+                    tag
+                )
+            })
+    }
+}
+
 /// Executes the task pipeline for a set of user-selected items.
 ///
 /// This function processes selected items through their appropriate execution handlers:
@@ -216,6 +394,13 @@ pub async fn run_preview_pipeline(
 /// Item sources are processed sequentially, but each `execute()` function can implement its
 /// own concurrency model for processing multiple items.
 ///
+/// # Source Errors
+///
+/// By default a source whose `execute()` raises is recorded as failed with exit code 1, the
+/// remaining sources still run and the captured error messages are appended to the output.
+/// Tasks declaring `continue_on_error = false` abort on the first raising source instead,
+/// after running `post_run`.
+///
 /// # Tag Processing
 ///
 /// For tasks with a single item source, items are used as-is. For multi-source tasks, items
@@ -230,22 +415,64 @@ pub async fn run_preview_pipeline(
 ///
 /// # Returns
 ///
-/// Returns a tuple of `(output, exit_code)` where:
+/// Returns an [`ExecuteOutput`] where:
 /// - `output` - Combined output from all item source executions, joined with newlines
-/// - `exit_code` - First non-zero exit code encountered, or 0 if all executions succeeded
+/// - `exit_code` - First non-zero exit code encountered, or 0 if all executions succeeded.
+///   When every source succeeded but an item reported a non-zero code, the first failing
+///   item's code is used instead
+/// - `item_exit_codes` - Per-item exit codes merged across sources, if any `execute` returned them
+/// - `sources` - One entry per executed item source with its own output and exit code
+/// - `duration` - Wall time of the whole pipeline, including `post_run`
 ///
 /// # Errors
 ///
-/// Returns an error if any execution function fails or if the post_run hook fails.
+/// Returns an error if every item source fails, if a source fails in a task with
+/// `continue_on_error = false`, or if the post_run hook fails without any output.
 pub async fn run_execute_pipeline(
     lua: Arc<Mutex<Lua>>,
     task: &Task,
     selected_items: &[String],
     cancellation: Option<&crate::signal::Cancellation>,
-) -> Result<(String, i32)> {
+) -> Result<ExecuteOutput> {
+    let started = Instant::now();
+    debug!(
+        plugin = %task.plugin_name,
+        task = %task.task_key,
+        items = selected_items.len(),
+        "Executing task"
+    );
+    let result = execute_task_sources(lua, task, selected_items, cancellation, started).await;
+    match &result {
+        Ok(execute_output) => info!(
+            plugin = %task.plugin_name,
+            task = %task.task_key,
+            exit_code = execute_output.exit_code,
+            duration_ms = execute_output.duration.as_millis() as u64,
+            "Task finished"
+        ),
+        Err(e) => warn!(
+            plugin = %task.plugin_name,
+            task = %task.task_key,
+            error = %format!("{:#}", e),
+            "Task failed"
+        ),
+    }
+    result
+}
+
+// Body of `run_execute_pipeline`, split out so the outcome is logged on every return path
+async fn execute_task_sources(
+    lua: Arc<Mutex<Lua>>,
+    task: &Task,
+    selected_items: &[String],
+    cancellation: Option<&crate::signal::Cancellation>,
+    started: Instant,
+) -> Result<ExecuteOutput> {
     if let Some(item_sources) = &task.item_sources {
         let mut joined_output: Vec<String> = Vec::new();
         let mut final_exit_code = 0;
+        let mut item_exit_codes: Option<BTreeMap<String, i32>> = None;
+        let mut sources: Vec<SourceExecution> = Vec::new();
         let mut source_errors: Vec<(String, anyhow::Error)> = Vec::new();
         for (item_source_key, item_source) in item_sources {
             let mut tags: HashSet<String> = HashSet::default();
@@ -270,11 +497,25 @@ pub async fn run_execute_pipeline(
                 continue;
             }
 
+            // Disabled sources list no items, so nothing selected from them may run either
+            if !call_item_source_enabled(&lua, &task.plugin_name, &task.task_key, item_source_key)
+                .await
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
             if let Some(cancel) = cancellation
                 && cancel.is_cancelled()
             {
                 let _ = call_task_post_run(&lua, &task.plugin_name, &task.task_key).await;
-                return Ok(("Task cancelled\n".to_string(), EXIT_SIGINT));
+                return Ok(ExecuteOutput {
+                    output: "Task cancelled\n".to_string(),
+                    exit_code: EXIT_SIGINT,
+                    item_exit_codes,
+                    sources,
+                    duration: started.elapsed(),
+                });
             }
 
             ensure!(
@@ -283,6 +524,15 @@ pub async fn run_execute_pipeline(
                 item_source_key
             );
 
+            let items = item_values(
+                &*lua.lock().await,
+                &task.plugin_name,
+                &task.task_key,
+                item_source_key,
+                &items,
+            );
+
+            debug!(source = %item_source_key, items = items.len(), "Executing item source");
             let result = if has_item_source_execute(&lua, task, item_source_key).await {
                 if item_sources.len() > 1
                     && let Some(tag) = tags.into_iter().next()
@@ -295,17 +545,39 @@ pub async fn run_execute_pipeline(
                     continue;
                 }
             } else {
-                call_task_execute(&lua, task, &items).await
+                call_task_execute_output(&lua, task, &items).await
             };
 
             match result {
-                Ok((output, exit_code)) => {
-                    joined_output.push(output);
-                    if final_exit_code == 0 && exit_code != 0 {
-                        final_exit_code = exit_code;
+                Ok(execute_output) => {
+                    sources.push(SourceExecution {
+                        source: Some(item_source_key.clone()),
+                        items_executed: items.len(),
+                        output: execute_output.output.clone(),
+                        exit_code: execute_output.exit_code,
+                    });
+                    joined_output.push(execute_output.output);
+                    if final_exit_code == 0 && execute_output.exit_code != 0 {
+                        final_exit_code = execute_output.exit_code;
                     }
+                    if let Some(source_item_exit_codes) = execute_output.item_exit_codes {
+                        item_exit_codes
+                            .get_or_insert_default()
+                            .extend(source_item_exit_codes);
+                    }
+                }
+                Err(e) if !task.continue_on_error => {
+                    // Still run cleanup before aborting the remaining sources
+                    let _ = call_task_post_run(&lua, &task.plugin_name, &task.task_key).await;
+                    return Err(e.context(format!("Item source '{}' failed", item_source_key)));
                 }
                 Err(e) => {
+                    sources.push(SourceExecution {
+                        source: Some(item_source_key.clone()),
+                        items_executed: items.len(),
+                        output: format!("{:#}", e),
+                        exit_code: EXIT_FAILURE,
+                    });
                     source_errors.push((item_source_key.clone(), e));
                     if final_exit_code == 0 {
                         final_exit_code = EXIT_FAILURE;
@@ -327,18 +599,15 @@ pub async fn run_execute_pipeline(
             }
         }
 
+        // Report the captured errors alongside the output of the sources that succeeded
+        if !joined_output.is_empty() && !source_errors.is_empty() {
+            joined_output.push(format_source_errors(&source_errors));
+        }
+
         // Determine final result
         let output = if joined_output.is_empty() {
             if !source_errors.is_empty() {
-                let error_details = source_errors
-                    .iter()
-                    .map(|(key, e)| format!("  - {}: {:#}", key, e))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-                return Err(anyhow::anyhow!(
-                    "All item sources failed:\n{}",
-                    error_details
-                ));
+                return Err(all_sources_failed(source_errors));
             } else {
                 "No items were executed".to_string()
             }
@@ -346,15 +615,76 @@ pub async fn run_execute_pipeline(
             joined_output.join("\n")
         };
 
-        Ok((output, final_exit_code))
+        let mut execute_output = ExecuteOutput {
+            output,
+            exit_code: final_exit_code,
+            item_exit_codes,
+            sources,
+            duration: started.elapsed(),
+        };
+        execute_output.propagate_item_failures();
+
+        Ok(execute_output)
     } else {
+        debug!("Running pre_run, execute and post_run");
         call_task_pre_run(&lua, &task.plugin_name, &task.task_key).await?;
-        let (output, exit_code) = call_task_execute(&lua, task, &[]).await?;
+        let mut execute_output = call_task_execute_output(&lua, task, &[]).await?;
         call_task_post_run(&lua, &task.plugin_name, &task.task_key).await?;
-        Ok((output, exit_code))
+        execute_output.sources = vec![SourceExecution {
+            source: None,
+            items_executed: 0,
+            output: execute_output.output.clone(),
+            exit_code: execute_output.exit_code,
+        }];
+        execute_output.duration = started.elapsed();
+        execute_output.propagate_item_failures();
+        Ok(execute_output)
+    }
+}
+
+fn format_source_errors(source_errors: &[(String, anyhow::Error)]) -> String {
+    format!(
+        "{} item source(s) failed:\n{}",
+        source_errors.len(),
+        format_source_error_details(source_errors)
+    )
+}
+
+// When the sources failed the same way, the last error stays in the chain, so
+// `ExecutionError::find` still tells what kind of failure it was. The message is the same.
+fn all_sources_failed(mut source_errors: Vec<(String, anyhow::Error)>) -> anyhow::Error {
+    let kind = |error: &anyhow::Error| ExecutionError::find(error).map(ExecutionError::kind);
+    let same_kind = source_errors
+        .windows(2)
+        .all(|pair| kind(&pair[0].1) == kind(&pair[1].1));
+    match source_errors.pop() {
+        Some((key, last)) if same_kind && kind(&last).is_some() => {
+            let mut message = String::from("All item sources failed:\n");
+            if !source_errors.is_empty() {
+                message.push_str(&format_source_error_details(&source_errors));
+                message.push('\n');
+            }
+            message.push_str(&format!("  - {}", key));
+            last.context(message)
+        }
+        last => {
+            source_errors.extend(last);
+            anyhow::anyhow!(
+                "All item sources failed:\n{}",
+                format_source_error_details(&source_errors)
+            )
+        }
     }
 }
 
+fn format_source_error_details(source_errors: &[(String, anyhow::Error)]) -> String {
+    source_errors
+        .iter()
+        .map(|(key, e)| format!("  - {}: {:#}", key, e))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Parses an item string to extract optional tag and content.
 ///
 /// Items can be tagged with `[tag] content` format when multiple item sources are used.
@@ -437,3 +767,53 @@ pub fn parse_tag(item: &str) -> (Option<&str>, &str) {
 pub fn strip_tag(item: &str) -> &str {
     parse_tag(item).1
 }
+
+// Runs `command` with `sh -c` and passes each non-empty stdout line to `on_line` as soon as
+// it is written. Fails when the command exits unsuccessfully, its stderr is logged.
+async fn stream_command_lines(command: &str, mut on_line: impl FnMut(String)) -> Result<()> {
+    debug!(command, "Spawning items command");
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // Leaving the screen aborts the fetch, the command must not outlive it
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("Failed to spawn '{}'", command))?;
+    let mut tracked = track_child(child.id());
+
+    let stdout = child.stdout.take().context("Failed to capture stdout")?;
+    let mut stderr = child.stderr.take().context("Failed to capture stderr")?;
+
+    let read_lines = async {
+        let mut lines = BufReader::new(stdout).lines();
+        while let Some(line) = lines.next_line().await? {
+            if !line.trim().is_empty() {
+                on_line(line);
+            }
+        }
+        Ok::<_, std::io::Error>(())
+    };
+    // stderr is drained alongside, a full stderr pipe would block the command
+    let mut stderr_output = String::new();
+    let (lines_read, _) = tokio::join!(read_lines, stderr.read_to_string(&mut stderr_output));
+    lines_read.with_context(|| format!("Failed to read the output of '{}'", command))?;
+
+    let status = child
+        .wait()
+        .await
+        .with_context(|| format!("Failed to wait for '{}'", command))?;
+    tracked.exited();
+    if !status.success() {
+        return Err(ExecutionError::ProcessError {
+            command: command.to_string(),
+            exit_code: status.code().unwrap_or(EXIT_FAILURE),
+            stderr: stderr_output.trim().to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Lets a plugin call hand the shared Lua VM back while it waits outside of Lua
+//!
+//! Plugin calls hold the [`SharedLua`] mutex while they run. A call made through a
+//! [`LeasedVm`] can unlock it with [`release_vm_while`] while it awaits a timer or a child
+//! process, so other plugin calls and the TUI's own lookups run meanwhile. The call locks the
+//! VM again before it runs Lua code again.
+
+use std::{
+    future::Future,
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+use mlua::Lua;
+use tokio::sync::OwnedMutexGuard;
+
+use crate::execution::SharedLua;
+
+const CURRENT_PLUGIN_KEY: &str = "__syntropy_current_plugin__";
+
+#[derive(Clone)]
+struct VmLease {
+    vm: SharedLua,
+    /// `None` while the call waits with the VM released
+    guard: Arc<Mutex<Option<OwnedMutexGuard<Lua>>>>,
+}
+
+impl VmLease {
+    fn guard(&self) -> std::sync::MutexGuard<'_, Option<OwnedMutexGuard<Lua>>> {
+        // A panic while holding the lock can't leave the slot inconsistent
+        self.guard
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+tokio::task_local! {
+    static CURRENT_LEASE: VmLease;
+}
+
+/// The shared Lua VM, locked for the duration of one plugin call
+pub(crate) struct LeasedVm {
+    lua: Lua,
+    lease: VmLease,
+}
+
+impl LeasedVm {
+    pub(crate) async fn acquire(vm: &SharedLua) -> Self {
+        let guard = vm.clone().lock_owned().await;
+        Self {
+            lua: guard.clone(),
+            lease: VmLease {
+                vm: vm.clone(),
+                guard: Arc::new(Mutex::new(Some(guard))),
+            },
+        }
+    }
+
+    /// Runs a plugin call that [`release_vm_while`] may release the VM from
+    pub(crate) async fn run<F: Future>(&self, call: F) -> F::Output {
+        CURRENT_LEASE.scope(self.lease.clone(), call).await
+    }
+}
+
+impl Deref for LeasedVm {
+    type Target = Lua;
+
+    fn deref(&self) -> &Lua {
+        &self.lua
+    }
+}
+
+/// Awaits `wait` with the shared VM unlocked and locks it again before returning. Outside of a
+/// [`LeasedVm::run`] call, e.g. while plugins load, it just awaits `wait`.
+pub(crate) async fn release_vm_while<F: Future>(lua: &Lua, wait: F) -> F::Output {
+    let Ok(lease) = CURRENT_LEASE.try_with(VmLease::clone) else {
+        return wait.await;
+    };
+    let Some(guard) = lease.guard().take() else {
+        return wait.await;
+    };
+    // Calls running meanwhile set and clear the current plugin for their own plugin
+    let current_plugin: mlua::Value = lua
+        .named_registry_value(CURRENT_PLUGIN_KEY)
+        .unwrap_or(mlua::Value::Nil);
+    drop(guard);
+
+    let output = wait.await;
+
+    let guard = lease.vm.clone().lock_owned().await;
+    let _ = lua.set_named_registry_value(CURRENT_PLUGIN_KEY, current_plugin);
+    *lease.guard() = Some(guard);
+    output
+}
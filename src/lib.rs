@@ -13,5 +13,5 @@ pub use configs::Config;
 pub use execution::{ExecutionResult, Handle, Operation, State};
 
 pub use configs::{find_config_file, load_config, resolve_plugin_paths, validate_config};
-pub use lua::create_lua_vm;
+pub use lua::{create_lua_vm, create_lua_vm_with_sandbox};
 pub use plugins::load_plugins;
@@ -0,0 +1,82 @@
+use std::{env, path::Path, sync::OnceLock};
+
+use crate::Config;
+
+/// Editor used when neither the environment nor the config names an available one
+pub const DEFAULT_EDITOR: &str = "vim";
+
+/// Editor preferences from the config, consulted by `syntropy.invoke_editor`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EditorSettings {
+    pub editor: Option<String>,
+    pub fallbacks: Vec<String>,
+    pub overrides_env: bool,
+}
+
+impl From<&Config> for EditorSettings {
+    fn from(config: &Config) -> Self {
+        Self {
+            editor: config.editor.clone(),
+            fallbacks: config.editor_fallbacks.clone(),
+            overrides_env: config.editor_overrides_env,
+        }
+    }
+}
+
+// Global editor settings - initialized at startup, used by Lua
+static EDITOR_SETTINGS: OnceLock<EditorSettings> = OnceLock::new();
+
+/// Stores the editor settings for the rest of the process, later calls are ignored
+pub fn set_editor_settings(settings: EditorSettings) {
+    let _ = EDITOR_SETTINGS.set(settings);
+}
+
+/// Picks the editor for `syntropy.invoke_editor`
+///
+/// `$EDITOR` and `$VISUAL` are used as-is, config entries (`editor`, then
+/// `editor_fallbacks` in order) only when `is_available` finds them. The environment is
+/// consulted first unless `overrides_env` is set, [`DEFAULT_EDITOR`] comes last.
+pub fn resolve_editor(
+    editor_env: Option<String>,
+    visual_env: Option<String>,
+    settings: &EditorSettings,
+    is_available: impl Fn(&str) -> bool,
+) -> String {
+    let from_env = || editor_env.clone().or_else(|| visual_env.clone());
+    let from_config = || {
+        settings
+            .editor
+            .iter()
+            .chain(&settings.fallbacks)
+            .find(|editor| is_available(editor))
+            .cloned()
+    };
+
+    let editor = if settings.overrides_env {
+        from_config().or_else(from_env)
+    } else {
+        from_env().or_else(from_config)
+    };
+    editor.unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// Resolves the editor from the process environment and the settings set at startup
+pub fn current_editor() -> String {
+    let default_settings = EditorSettings::default();
+    resolve_editor(
+        env::var("EDITOR").ok(),
+        env::var("VISUAL").ok(),
+        EDITOR_SETTINGS.get().unwrap_or(&default_settings),
+        is_on_path,
+    )
+}
+
+// Whether `command` is an existing path or an executable name found in $PATH
+fn is_on_path(command: &str) -> bool {
+    if command.contains(std::path::MAIN_SEPARATOR) {
+        return Path::new(command).is_file();
+    }
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(command).is_file()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,89 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use mlua::{Function, Lua, Value};
+
+use crate::execution::ExecutionError;
+
+const GLOBAL_PRE_RUN_KEY: &str = "__syntropy_global_pre_run__";
+const GLOBAL_POST_RUN_KEY: &str = "__syntropy_global_post_run__";
+
+/// Hook from the config running around the `pre_run` and `post_run` of every task
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlobalHook {
+    PreRun,
+    PostRun,
+}
+
+impl GlobalHook {
+    fn registry_key(self) -> &'static str {
+        match self {
+            GlobalHook::PreRun => GLOBAL_PRE_RUN_KEY,
+            GlobalHook::PostRun => GLOBAL_POST_RUN_KEY,
+        }
+    }
+
+    fn config_key(self) -> &'static str {
+        match self {
+            GlobalHook::PreRun => "global_pre_run",
+            GlobalHook::PostRun => "global_post_run",
+        }
+    }
+}
+
+/// Loads the `global_pre_run` and `global_post_run` files of the config into the VM. Each
+/// file must return a function, called with `{plugin = <name>, task = <key>}`.
+pub fn load_global_hooks(lua: &Lua, pre_run: Option<&Path>, post_run: Option<&Path>) -> Result<()> {
+    for (hook, path) in [
+        (GlobalHook::PreRun, pre_run),
+        (GlobalHook::PostRun, post_run),
+    ] {
+        let Some(path) = path else {
+            continue;
+        };
+        let function = load_hook_file(lua, path)
+            .with_context(|| format!("Failed to load {} hook", hook.config_key()))?;
+        lua.set_named_registry_value(hook.registry_key(), function)?;
+    }
+    Ok(())
+}
+
+fn load_hook_file(lua: &Lua, path: &Path) -> Result<Function> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let value: Value = lua
+        .load(source)
+        .set_name(format!("@{}", path.display()))
+        .eval()
+        .map_err(ExecutionError::LuaError)?;
+
+    match value {
+        Value::Function(function) => Ok(function),
+        other => bail!(
+            "{} must return a function, got {}",
+            path.display(),
+            other.type_name()
+        ),
+    }
+}
+
+/// Runs `hook` for the task when the config declared it
+pub(crate) async fn call_global_hook(
+    lua: &Lua,
+    hook: GlobalHook,
+    plugin_name: &str,
+    task_key: &str,
+) -> Result<()> {
+    let Some(function) = lua.named_registry_value::<Option<Function>>(hook.registry_key())? else {
+        return Ok(());
+    };
+
+    let context = lua.create_table()?;
+    context.set("plugin", plugin_name)?;
+    context.set("task", task_key)?;
+    function
+        .call_async::<()>(context)
+        .await
+        .map_err(ExecutionError::LuaError)
+        .with_context(|| format!("Error calling {}()", hook.config_key()))
+}
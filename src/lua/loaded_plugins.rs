@@ -0,0 +1,64 @@
+use mlua::{Lua, Result as LuaResult, Table as LuaTable};
+
+use crate::plugins::Plugin;
+
+/// Metadata and task keys of a loaded plugin, without any of its Lua functions
+struct PluginSummary {
+    name: String,
+    version: String,
+    icon: String,
+    description: String,
+    platforms: Vec<String>,
+    tasks: Vec<String>,
+}
+
+/// Plugins of the last load, kept as Lua app data for `syntropy.plugins()`
+#[derive(Default)]
+struct LoadedPlugins(Vec<PluginSummary>);
+
+/// Makes `plugins` available to `syntropy.plugins()`, replacing the plugins of the previous
+/// load. Called by the loader once every plugin is loaded, so `plugin.lua` files evaluated
+/// during the load see the plugins of the previous one.
+pub(crate) fn set_loaded_plugins(lua: &Lua, plugins: &[Plugin]) {
+    let summaries = plugins
+        .iter()
+        .map(|plugin| PluginSummary {
+            name: plugin.metadata.name.clone(),
+            version: plugin.metadata.version.clone(),
+            icon: plugin.metadata.icon.clone(),
+            description: plugin.metadata.description.clone(),
+            platforms: plugin.metadata.platforms.clone(),
+            tasks: plugin
+                .tasks_sorted()
+                .map(|(task_key, _)| task_key.to_string())
+                .collect(),
+        })
+        .collect();
+    lua.set_app_data(LoadedPlugins(summaries));
+}
+
+/// Loaded plugins as a fresh sequence of `{name, version, icon, description, platforms,
+/// tasks}` tables, in load order
+pub(crate) fn loaded_plugins_table(lua: &Lua) -> LuaResult<LuaTable> {
+    let loaded = lua.app_data_ref::<LoadedPlugins>();
+    let summaries = loaded.as_ref().map_or(&[][..], |loaded| &loaded.0[..]);
+
+    let table = lua.create_table_with_capacity(summaries.len(), 0)?;
+    for summary in summaries {
+        let plugin = lua.create_table_with_capacity(0, 6)?;
+        plugin.set("name", summary.name.as_str())?;
+        plugin.set("version", summary.version.as_str())?;
+        plugin.set("icon", summary.icon.as_str())?;
+        plugin.set("description", summary.description.as_str())?;
+        plugin.set(
+            "platforms",
+            lua.create_sequence_from(summary.platforms.iter().map(String::as_str))?,
+        )?;
+        plugin.set(
+            "tasks",
+            lua.create_sequence_from(summary.tasks.iter().map(String::as_str))?,
+        )?;
+        table.push(plugin)?;
+    }
+    Ok(table)
+}
@@ -1,8 +1,18 @@
 mod bridge;
+pub mod editor;
+mod global_hooks;
+mod loaded_plugins;
+mod plugin_config;
 mod runtime;
 mod stdlib;
 
 pub(crate) use bridge::{
     get_lua_function, get_optional_lua_function, lua_table_to_vec_string, vec_string_to_lua_table,
 };
-pub use runtime::{MERGE_LUA_FN_KEY, create_lua_vm};
+pub(crate) use global_hooks::call_global_hook;
+pub use global_hooks::{GlobalHook, load_global_hooks};
+pub(crate) use loaded_plugins::set_loaded_plugins;
+pub use plugin_config::set_plugin_configs;
+pub(crate) use runtime::PluginModulePath;
+pub use runtime::{MERGE_LUA_FN_KEY, create_lua_vm, create_lua_vm_with_sandbox};
+pub(crate) use stdlib::set_clipboard_text;
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use mlua::{Lua, Result as LuaResult, Table as LuaTable, Value as LuaValue};
+
+/// `[plugin_config.<name>]` tables of the config, kept as Lua app data for
+/// `syntropy.config()`
+#[derive(Default)]
+struct PluginConfigs(HashMap<String, toml::Table>);
+
+/// Makes the `[plugin_config.<name>]` tables available to `syntropy.config()`, must be
+/// called before plugins are loaded so they can read their config at load time
+pub fn set_plugin_configs(lua: &Lua, configs: HashMap<String, toml::Table>) {
+    lua.set_app_data(PluginConfigs(configs));
+}
+
+/// Config table of `plugin_name` as a fresh Lua table, empty when it has none
+pub(crate) fn plugin_config_table(lua: &Lua, plugin_name: &str) -> LuaResult<LuaTable> {
+    let configs = lua.app_data_ref::<PluginConfigs>();
+    match configs
+        .as_ref()
+        .and_then(|configs| configs.0.get(plugin_name))
+    {
+        Some(table) => toml_table_to_lua(lua, table),
+        None => lua.create_table(),
+    }
+}
+
+fn toml_table_to_lua(lua: &Lua, table: &toml::Table) -> LuaResult<LuaTable> {
+    let lua_table = lua.create_table_with_capacity(0, table.len())?;
+    for (key, value) in table {
+        lua_table.set(key.as_str(), toml_value_to_lua(lua, value)?)?;
+    }
+    Ok(lua_table)
+}
+
+// Datetimes have no Lua counterpart, they are passed as their TOML string
+fn toml_value_to_lua(lua: &Lua, value: &toml::Value) -> LuaResult<LuaValue> {
+    Ok(match value {
+        toml::Value::String(string) => LuaValue::String(lua.create_string(string)?),
+        toml::Value::Integer(integer) => LuaValue::Integer(*integer),
+        toml::Value::Float(float) => LuaValue::Number(*float),
+        toml::Value::Boolean(boolean) => LuaValue::Boolean(*boolean),
+        toml::Value::Datetime(datetime) => {
+            LuaValue::String(lua.create_string(datetime.to_string())?)
+        }
+        toml::Value::Array(array) => {
+            let lua_array = lua.create_table_with_capacity(array.len(), 0)?;
+            for value in array {
+                lua_array.push(toml_value_to_lua(lua, value)?)?;
+            }
+            LuaValue::Table(lua_array)
+        }
+        toml::Value::Table(table) => LuaValue::Table(toml_table_to_lua(lua, table)?),
+    })
+}
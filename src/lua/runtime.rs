@@ -1,5 +1,6 @@
 use anyhow::Result;
-use mlua::{Lua, LuaOptions, StdLib, Table};
+use mlua::{Function, IntoLuaMulti, Lua, LuaOptions, MultiValue, StdLib, Table};
+use std::path::Path;
 
 use crate::lua::stdlib::register_syntropy_stdlib;
 
@@ -63,29 +64,25 @@ end
 return merge
 "#;
 
-pub fn create_lua_vm() -> Result<Lua> {
-    let lua = Lua::new_with(StdLib::ALL_SAFE, LuaOptions::default())?;
+/// `package.path` templates set by [`crate::plugins::ModulePathBuilder::apply`], the only
+/// places the sandboxed `require` loads modules from
+pub(crate) struct PluginModulePath(pub String);
 
-    let os_table: Table = lua.globals().get("os")?;
+pub fn create_lua_vm() -> Result<Lua> {
+    create_lua_vm_with_sandbox(true)
+}
 
-    let blocked_exit = lua.create_function(|_, _: mlua::MultiValue| -> mlua::Result<()> {
-        Err(mlua::Error::external(
-            "os.exit is not available in the plugin sandbox",
-        ))
-    })?;
-    os_table.set("exit", blocked_exit)?;
+/// Creates the plugin VM, with `sandbox` off plugins keep `os.exit`, `os.execute`,
+/// `io.popen` and the default `require` searchers
+pub fn create_lua_vm_with_sandbox(sandbox: bool) -> Result<Lua> {
+    let lua = Lua::new_with(StdLib::ALL_SAFE, LuaOptions::default())?;
 
-    let blocked_execute = lua.create_function(|_, _: mlua::MultiValue| -> mlua::Result<()> {
-        Err(mlua::Error::external(
-            "os.execute is not available in the plugin sandbox",
-        ))
-    })?;
-    os_table.set("execute", blocked_execute)?;
+    if sandbox {
+        apply_sandbox(&lua)?;
+    }
 
     register_syntropy_stdlib(&lua)?;
 
-    lua.globals().set("os", os_table)?;
-
     // Inject merge function for plugin override system
     let merge_fn: mlua::Function = lua.load(MERGE_LUA).eval()?;
     lua.globals().set("merge", merge_fn)?;
@@ -96,3 +93,71 @@ pub fn create_lua_vm() -> Result<Lua> {
 
     Ok(lua)
 }
+
+fn blocked_function(lua: &Lua, name: &'static str) -> mlua::Result<Function> {
+    lua.create_function(move |_, _: MultiValue| -> mlua::Result<()> {
+        Err(mlua::Error::external(format!(
+            "{} is not available in the plugin sandbox",
+            name
+        )))
+    })
+}
+
+// Removes the functions that end the process, spawn commands outside syntropy.shell or load
+// code from outside the plugin directories
+fn apply_sandbox(lua: &Lua) -> Result<()> {
+    let globals = lua.globals();
+
+    let os_table: Table = globals.get("os")?;
+    os_table.set("exit", blocked_function(lua, "os.exit")?)?;
+    os_table.set("execute", blocked_function(lua, "os.execute")?)?;
+
+    let io_table: Table = globals.get("io")?;
+    io_table.set("popen", blocked_function(lua, "io.popen")?)?;
+
+    // Keep package.preload, then only resolve against the plugin module path
+    let package: Table = globals.get("package")?;
+    let searchers: Table = package.get("searchers")?;
+    let preload_searcher: Function = searchers.get(1)?;
+    let sandboxed_searchers = lua.create_sequence_from([
+        preload_searcher,
+        lua.create_function(plugin_module_searcher)?,
+    ])?;
+    package.set("searchers", sandboxed_searchers)?;
+    package.set("cpath", "")?;
+    package.set("loadlib", mlua::Value::Nil)?;
+
+    Ok(())
+}
+
+// Resolves `require(name)` against the templates stored by ModulePathBuilder::apply rather
+// than package.path, so a plugin reassigning package.path can't widen it
+fn plugin_module_searcher(lua: &Lua, name: String) -> mlua::Result<MultiValue> {
+    if name.contains('/') || name.contains('\\') {
+        return format!("\n\tmodule '{}' is not a plugin module name", name).into_lua_multi(lua);
+    }
+
+    let module_path = lua
+        .app_data_ref::<PluginModulePath>()
+        .map(|path| path.0.clone())
+        .unwrap_or_default();
+    let relative = name.replace('.', "/");
+
+    let mut message = String::new();
+    for template in module_path
+        .split(';')
+        .filter(|template| !template.is_empty())
+    {
+        let candidate = template.replace('?', &relative);
+        if Path::new(&candidate).is_file() {
+            let loader = lua.load(Path::new(&candidate)).into_function()?;
+            return (loader, candidate).into_lua_multi(lua);
+        }
+        message.push_str(&format!("\n\tno file '{}'", candidate));
+    }
+    if message.is_empty() {
+        message = "\n\tno plugin module directories".to_string();
+    }
+
+    message.into_lua_multi(lua)
+}
@@ -1,15 +1,29 @@
+use arboard::Clipboard;
 use mlua::{Error as LuaError, Lua, Result as LuaResult, Table as LuaTable};
-use std::{env, process::Stdio};
+use std::{
+    io::{BufRead, IsTerminal, Write},
+    path::Path,
+    process::Stdio,
+    sync::Mutex,
+    time::Duration,
+};
 use tokio::io::AsyncBufReadExt;
+use tracing::{debug, error, info, warn};
 
-use crate::execution::clamp_exit_code;
-use crate::tui::{ExternalTuiRequest, get_tui_sender};
+use crate::execution::{ExecutionError, clamp_exit_code, release_vm_while, warn_on_exit_clamp};
+use crate::lua::editor::current_editor;
+use crate::lua::loaded_plugins::loaded_plugins_table;
+use crate::lua::plugin_config::plugin_config_table;
+use crate::plugins::git_ops::{checkout_branch, git_branches, git_status};
+use crate::signal::{track_child, track_child_group};
+use crate::tui::{ExternalTuiRequest, PluginLogLevel, get_tui_sender};
 
 pub fn register_syntropy_stdlib(lua: &Lua) -> LuaResult<()> {
     let syntropy_table = lua.create_table()?;
 
-    let shell_fn = lua.create_async_function(|_, cmd: String| async move {
-        let (output, exit_code) = execute_shell_async(&cmd)
+    // The Lua VM is free for other plugin calls while the command runs
+    let shell_fn = lua.create_async_function(|lua, cmd: String| async move {
+        let (output, exit_code) = release_vm_while(&lua, execute_shell_async(&cmd))
             .await
             .map_err(LuaError::external)?;
 
@@ -39,35 +53,53 @@ pub fn register_syntropy_stdlib(lua: &Lua) -> LuaResult<()> {
 
     syntropy_table.set("invoke_editor", invoke_editor_fn)?;
 
+    // confirm: Ask the user a yes/no question
+    let confirm_fn = lua.create_async_function(|_, message: String| async move {
+        let answer = confirm(message).await.map_err(LuaError::external)?;
+
+        Ok(answer)
+    })?;
+
+    syntropy_table.set("confirm", confirm_fn)?;
+
+    // prompt: Ask the user for a line of text
+    let prompt_fn = lua.create_async_function(
+        |_, (message, default): (String, Option<String>)| async move {
+            let answer = prompt(message, default.unwrap_or_default())
+                .await
+                .map_err(LuaError::external)?;
+
+            Ok(answer)
+        },
+    )?;
+
+    syntropy_table.set("prompt", prompt_fn)?;
+
+    // sleep: Waits without blocking a thread of the runtime or holding the Lua VM
+    let sleep_fn = lua.create_async_function(|lua, ms: i64| async move {
+        let duration = sleep_duration(ms).map_err(LuaError::external)?;
+        release_vm_while(&lua, tokio::time::sleep(duration)).await;
+
+        Ok(())
+    })?;
+
+    syntropy_table.set("sleep", sleep_fn)?;
+
+    // log: Output that neither corrupts the TUI nor mixes with a task's stdout
+    let log_fn = lua.create_function(|_, (message, level): (String, Option<String>)| {
+        let level = log_level(level.as_deref()).map_err(LuaError::external)?;
+        log(level, message);
+
+        Ok(())
+    })?;
+
+    syntropy_table.set("log", log_fn)?;
+
     let expand_path_fn = lua.create_function(|lua_ctx, path: String| {
         // Handle ./ and ../ as plugin-relative paths
         if path.starts_with("./") || path.starts_with("../") {
-            // Get current plugin name from registry
-            let plugin_name: String = lua_ctx
-                .named_registry_value("__syntropy_current_plugin__")
-                .map_err(|_| {
-                    LuaError::external(
-                        "Cannot resolve relative path: no plugin context (expand_path called outside plugin execution)"
-                    )
-                })?;
-
-            // Get plugin table from globals
-            let plugin_table: mlua::Table = lua_ctx
-                .globals()
-                .get(plugin_name.as_str())
-                .map_err(|e| {
-                    LuaError::external(format!("Failed to get plugin '{}': {}", plugin_name, e))
-                })?;
-
-            // Get plugin directory from plugin table
-            let plugin_dir: String = plugin_table
-                .get("__plugin_dir")
-                .map_err(|_| {
-                    LuaError::external(format!(
-                        "Plugin '{}' missing __plugin_dir (this is a syntropy bug)",
-                        plugin_name
-                    ))
-                })?;
+            let plugin_dir =
+                current_plugin_dir(lua_ctx, "Cannot resolve relative path", "expand_path")?;
 
             // Join relative path with plugin directory
             let resolved = std::path::Path::new(&plugin_dir).join(&path);
@@ -86,10 +118,147 @@ pub fn register_syntropy_stdlib(lua: &Lua) -> LuaResult<()> {
     })?;
 
     syntropy_table.set("expand_path", expand_path_fn)?;
+
+    // plugin_dir: Directory of the plugin that is currently executing
+    let plugin_dir_fn = lua.create_function(|lua_ctx, ()| {
+        current_plugin_dir(lua_ctx, "Cannot get plugin directory", "plugin_dir")
+    })?;
+
+    syntropy_table.set("plugin_dir", plugin_dir_fn)?;
+
+    // plugin_name: Name of the plugin that is currently executing
+    let plugin_name_fn = lua.create_function(|lua_ctx, ()| {
+        current_plugin_name(lua_ctx, "Cannot get plugin name", "plugin_name")
+    })?;
+
+    syntropy_table.set("plugin_name", plugin_name_fn)?;
+
+    // config: `[plugin_config.<name>]` table of the named or currently executing plugin
+    let config_fn = lua.create_function(|lua_ctx, plugin_name: Option<String>| {
+        let plugin_name = match plugin_name {
+            Some(plugin_name) => plugin_name,
+            None => current_plugin_name(lua_ctx, "Cannot get plugin config", "config")?,
+        };
+        plugin_config_table(lua_ctx, &plugin_name)
+    })?;
+
+    syntropy_table.set("config", config_fn)?;
+
+    // plugins: Metadata and task keys of every loaded plugin
+    let plugins_fn = lua.create_function(|lua_ctx, ()| loaded_plugins_table(lua_ctx))?;
+
+    syntropy_table.set("plugins", plugins_fn)?;
+
+    // clamp_exit_code: Exit code clamped to 0-255 like syntropy does with the ones of execute
+    let clamp_exit_code_fn = lua.create_function(|_, (code, quiet): (i64, Option<bool>)| {
+        Ok(clamp_lua_exit_code(code, quiet.unwrap_or(false)))
+    })?;
+
+    syntropy_table.set("clamp_exit_code", clamp_exit_code_fn)?;
+
+    let glob_fn = lua.create_function(|lua_ctx, pattern: String| {
+        let expanded = expand_tilde(&pattern).map_err(LuaError::external)?;
+        let paths = glob_paths(&expanded).map_err(LuaError::external)?;
+        lua_ctx.create_sequence_from(paths)
+    })?;
+
+    syntropy_table.set("glob", glob_fn)?;
+
+    // git_status: Changed and untracked files of the repository containing `path`
+    let git_status_fn = lua.create_function(|lua_ctx, path: String| {
+        let expanded = expand_tilde(&path).map_err(LuaError::external)?;
+        let entries = git_status(Path::new(&expanded)).map_err(git_error)?;
+        let table = lua_ctx.create_table()?;
+        for entry in entries {
+            let entry_table = lua_ctx.create_table()?;
+            entry_table.set("path", entry.path)?;
+            entry_table.set("status", entry.status.as_str())?;
+            table.push(entry_table)?;
+        }
+        Ok(table)
+    })?;
+
+    syntropy_table.set("git_status", git_status_fn)?;
+
+    // git_branches / git_checkout: Local branches of a repository and switching between them
+    let git_branches_fn = lua.create_function(|lua_ctx, path: String| {
+        let expanded = expand_tilde(&path).map_err(LuaError::external)?;
+        let branches = git_branches(Path::new(&expanded)).map_err(git_error)?;
+        let table = lua_ctx.create_table()?;
+        for branch in branches {
+            let branch_table = lua_ctx.create_table()?;
+            branch_table.set("name", branch.name)?;
+            branch_table.set("current", branch.current)?;
+            table.push(branch_table)?;
+        }
+        Ok(table)
+    })?;
+
+    syntropy_table.set("git_branches", git_branches_fn)?;
+
+    let git_checkout_fn = lua.create_function(|_, (path, branch): (String, String)| {
+        let result = expand_tilde(&path)
+            .map_err(anyhow::Error::msg)
+            .and_then(|expanded| checkout_branch(Path::new(&expanded), &branch));
+        Ok(match result {
+            Ok(()) => (true, None),
+            Err(e) => (false, Some(format!("{:#}", e))),
+        })
+    })?;
+
+    syntropy_table.set("git_checkout", git_checkout_fn)?;
+
+    // clipboard_set / clipboard_get: System clipboard, errors when it isn't available
+    let clipboard_set_fn = lua
+        .create_function(|_, text: String| set_clipboard_text(text).map_err(LuaError::external))?;
+
+    syntropy_table.set("clipboard_set", clipboard_set_fn)?;
+
+    let clipboard_get_fn =
+        lua.create_function(|_, ()| get_clipboard_text().map_err(LuaError::external))?;
+
+    syntropy_table.set("clipboard_get", clipboard_get_fn)?;
     lua.globals().set("syntropy", syntropy_table)?;
     Ok(())
 }
 
+// A failed git command stays an ExecutionError::ProcessError, so the plugin function calling it
+// fails with that kind, see ExecutionError::find
+fn git_error(error: anyhow::Error) -> LuaError {
+    match error.downcast::<ExecutionError>() {
+        Ok(error) => LuaError::external(error),
+        Err(error) => LuaError::external(error),
+    }
+}
+
+// Name of the plugin whose function is running, set by the execution layer around every
+// plugin call. `action` and `function_name` describe the failing call outside of one.
+fn current_plugin_name(lua: &Lua, action: &str, function_name: &str) -> LuaResult<String> {
+    lua.named_registry_value("__syntropy_current_plugin__")
+        .map_err(|_| {
+            LuaError::external(format!(
+                "{}: no plugin context ({} called outside plugin execution)",
+                action, function_name
+            ))
+        })
+}
+
+// Directory of the plugin whose function is running, kept as `__plugin_dir` in its table
+fn current_plugin_dir(lua: &Lua, action: &str, function_name: &str) -> LuaResult<String> {
+    let plugin_name = current_plugin_name(lua, action, function_name)?;
+
+    let plugin_table: LuaTable = lua.globals().get(plugin_name.as_str()).map_err(|e| {
+        LuaError::external(format!("Failed to get plugin '{}': {}", plugin_name, e))
+    })?;
+
+    plugin_table.get("__plugin_dir").map_err(|_| {
+        LuaError::external(format!(
+            "Plugin '{}' missing __plugin_dir (this is a syntropy bug)",
+            plugin_name
+        ))
+    })
+}
+
 pub async fn invoke_tui(command: String, args_table: LuaTable) -> Result<i32, String> {
     // Convert Lua table to Vec<String>
     let args: Vec<String> = args_table
@@ -102,7 +271,7 @@ pub async fn invoke_tui(command: String, args_table: LuaTable) -> Result<i32, St
         // TUI mode: send request to main thread and wait for response
         let (response_tx, response_rx) = tokio::sync::oneshot::channel();
 
-        let request = ExternalTuiRequest {
+        let request = ExternalTuiRequest::Command {
             command: command.clone(),
             args,
             response: response_tx,
@@ -134,16 +303,14 @@ pub async fn invoke_tui(command: String, args_table: LuaTable) -> Result<i32, St
 }
 
 pub async fn invoke_editor(path: String) -> Result<i32, String> {
-    let editor = env::var("EDITOR")
-        .or_else(|_| env::var("VISUAL"))
-        .unwrap_or_else(|_| "vim".to_string());
+    let editor = current_editor();
 
     // Check if we're in TUI mode or CLI mode
     if let Some(sender) = get_tui_sender() {
         // TUI mode: send request to main thread and wait for response
         let (response_tx, response_rx) = tokio::sync::oneshot::channel();
 
-        let request = ExternalTuiRequest {
+        let request = ExternalTuiRequest::Command {
             command: editor.clone(),
             args: vec![path.clone()],
             response: response_tx,
@@ -174,18 +341,153 @@ pub async fn invoke_editor(path: String) -> Result<i32, String> {
     }
 }
 
+pub async fn confirm(message: String) -> Result<bool, String> {
+    // Check if we're in TUI mode or CLI mode
+    if let Some(sender) = get_tui_sender() {
+        // TUI mode: main loop renders a confirmation dialog and reports the answer
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+
+        let request = ExternalTuiRequest::Confirm {
+            message,
+            response: response_tx,
+        };
+
+        sender
+            .send(request)
+            .map_err(|_| "Failed to send confirm request to TUI".to_string())?;
+
+        response_rx
+            .await
+            .map_err(|_| "Failed to receive confirm response from TUI".to_string())
+    } else {
+        // CLI mode: ask on the terminal, or fall back to "no" when stdin isn't a TTY
+        let answer = read_line_from_tty(format!("{} [y/N] ", message)).await?;
+
+        Ok(answer
+            .is_some_and(|answer| matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")))
+    }
+}
+
+pub async fn prompt(message: String, default: String) -> Result<String, String> {
+    // Check if we're in TUI mode or CLI mode
+    if let Some(sender) = get_tui_sender() {
+        // TUI mode: main loop renders an input dialog and reports the answer
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+
+        let request = ExternalTuiRequest::Prompt {
+            message,
+            default,
+            response: response_tx,
+        };
+
+        sender
+            .send(request)
+            .map_err(|_| "Failed to send prompt request to TUI".to_string())?;
+
+        response_rx
+            .await
+            .map_err(|_| "Failed to receive prompt response from TUI".to_string())
+    } else {
+        // CLI mode: ask on the terminal, or fall back to the default when stdin isn't a TTY
+        let question = if default.is_empty() {
+            format!("{} ", message)
+        } else {
+            format!("{} [{}] ", message, default)
+        };
+
+        match read_line_from_tty(question).await? {
+            Some(answer) if !answer.trim().is_empty() => Ok(answer.trim().to_string()),
+            _ => Ok(default),
+        }
+    }
+}
+
+/// Prints the question to stderr and reads one line from stdin.
+/// Returns None without prompting when stdin is not a terminal.
+async fn read_line_from_tty(question: String) -> Result<Option<String>, String> {
+    if !std::io::stdin().is_terminal() {
+        return Ok(None);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let mut stderr = std::io::stderr();
+        write!(stderr, "{}", question).map_err(|e| format!("Failed to write prompt: {}", e))?;
+        stderr
+            .flush()
+            .map_err(|e| format!("Failed to write prompt: {}", e))?;
+
+        let mut answer = String::new();
+        std::io::stdin()
+            .lock()
+            .read_line(&mut answer)
+            .map_err(|e| format!("Failed to read answer: {}", e))?;
+
+        Ok(Some(answer))
+    })
+    .await
+    .map_err(|e| format!("Failed to read answer: {}", e))?
+}
+
+pub fn log(level: PluginLogLevel, message: String) {
+    // Check if we're in TUI mode or CLI mode
+    if let Some(sender) = get_tui_sender() {
+        // TUI mode: shown in the status bar, and kept in the log file when logging is enabled
+        match level {
+            PluginLogLevel::Info => info!("Plugin log: {}", message),
+            PluginLogLevel::Warn => warn!("Plugin log: {}", message),
+            PluginLogLevel::Error => error!("Plugin log: {}", message),
+        }
+        let _ = sender.send(ExternalTuiRequest::Log { level, message });
+    } else {
+        // CLI mode: stderr, so the messages stay out of the output of the task
+        eprintln!("[{}] {}", level, message);
+    }
+}
+
+fn log_level(name: Option<&str>) -> Result<PluginLogLevel, String> {
+    let Some(name) = name else {
+        return Ok(PluginLogLevel::default());
+    };
+    PluginLogLevel::from_name(name).ok_or_else(|| {
+        let levels: Vec<String> = PluginLogLevel::ALL.map(|level| level.to_string()).into();
+        format!(
+            "syntropy.log() level must be one of {}, got '{}'",
+            levels.join(", "),
+            name
+        )
+    })
+}
+
 /// Executes a shell command asynchronously using tokio.
 /// Uses `sh -c` to support complex shell syntax (pipes, redirects, etc.).
 /// Returns (output, exit_code) on success. Avoids blocking on background
 /// processes (e.g. `cmd &`) by aborting reader tasks after the shell exits.
 pub async fn execute_shell_async(command: &str) -> Result<(String, i32), String> {
-    let mut child = tokio::process::Command::new("sh")
+    debug!(command, "Spawning shell command");
+    let mut shell = tokio::process::Command::new("sh");
+    shell
         .arg("-c")
         .arg(command)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // In the TUI the command leads its own process group, so killing a job or quitting also
+    // stops what the command started. The CLI keeps it in the terminal's foreground group,
+    // where commands like sudo can still read from the terminal.
+    let own_group = get_tui_sender().is_some();
+    #[cfg(unix)]
+    if own_group {
+        shell.process_group(0);
+    }
+    let mut child = shell
         .spawn()
         .map_err(|e| format!("Failed to spawn command: {}", e))?;
+    // Lets Ctrl+C, SIGTERM, quitting the TUI and aborting the caller terminate the command
+    let mut tracked = if own_group {
+        track_child_group(child.id())
+    } else {
+        track_child(child.id())
+    };
 
     let stdout = child.stdout.take().ok_or("Failed to capture stdout")?;
     let stderr = child.stderr.take().ok_or("Failed to capture stderr")?;
@@ -217,6 +519,7 @@ pub async fn execute_shell_async(command: &str) -> Result<(String, i32), String>
         .wait()
         .await
         .map_err(|e| format!("Failed to wait for command: {}", e))?;
+    tracked.exited();
 
     // Brief window to flush any buffered pipe data from the shell
     tokio::time::sleep(std::time::Duration::from_millis(10)).await;
@@ -233,11 +536,79 @@ pub async fn execute_shell_async(command: &str) -> Result<(String, i32), String>
     }
 
     let exit_code = clamp_exit_code(status.code().unwrap_or(-1));
+    debug!(command, exit_code, "Shell command finished");
     Ok((output.join("\n"), exit_code))
 }
 
+// Warns like `syntropy execute` when the code changed, unless `quiet` or
+// `warn_on_exit_clamp = false`. The TUI owns stderr, there the warning is only logged.
+fn clamp_lua_exit_code(code: i64, quiet: bool) -> i32 {
+    let clamped = clamp_exit_code(code.clamp(i32::MIN.into(), i32::MAX.into()) as i32);
+    if i64::from(clamped) != code && !quiet && warn_on_exit_clamp() {
+        if get_tui_sender().is_some() {
+            warn!(code, clamped, "Exit code clamped");
+        } else {
+            eprintln!("Warning: Exit code {} clamped to {}", code, clamped);
+        }
+    }
+    clamped
+}
+
+/// Longest delay `syntropy.sleep` accepts, one hour
+const MAX_SLEEP_MS: i64 = 60 * 60 * 1000;
+
+fn sleep_duration(ms: i64) -> Result<Duration, String> {
+    if !(0..=MAX_SLEEP_MS).contains(&ms) {
+        return Err(format!(
+            "syntropy.sleep() takes 0 to {} milliseconds, got {}",
+            MAX_SLEEP_MS, ms
+        ));
+    }
+    Ok(Duration::from_millis(ms as u64))
+}
+
 fn expand_tilde(path: &str) -> Result<String, String> {
     shellexpand::full(path)
         .map(|expanded| expanded.to_string())
         .map_err(|e| format!("Failed to expand path: {}", e))
 }
+
+// Matching paths in sorted order, entries that can't be read are skipped
+fn glob_paths(pattern: &str) -> Result<Vec<String>, String> {
+    let entries =
+        glob::glob(pattern).map_err(|e| format!("Invalid glob pattern '{}': {}", pattern, e))?;
+    let mut paths: Vec<String> = entries
+        .filter_map(Result::ok)
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+// Kept for the lifetime of the process, on X11 the clipboard content is served by its owner
+// and would be gone as soon as the clipboard is dropped
+static CLIPBOARD: Mutex<Option<Clipboard>> = Mutex::new(None);
+
+fn with_clipboard<T>(
+    action: impl FnOnce(&mut Clipboard) -> Result<T, arboard::Error>,
+) -> Result<T, String> {
+    let mut clipboard = CLIPBOARD
+        .lock()
+        .map_err(|_| "Clipboard lock is poisoned".to_string())?;
+    let clipboard = match clipboard.as_mut() {
+        Some(clipboard) => clipboard,
+        None => clipboard
+            .insert(Clipboard::new().map_err(|e| format!("Clipboard is not available: {}", e))?),
+    };
+    action(clipboard).map_err(|e| format!("Clipboard is not available: {}", e))
+}
+
+/// Replaces the system clipboard content with `text`
+pub fn set_clipboard_text(text: String) -> Result<(), String> {
+    with_clipboard(|clipboard| clipboard.set_text(text))
+}
+
+/// Text content of the system clipboard
+pub fn get_clipboard_text() -> Result<String, String> {
+    with_clipboard(|clipboard| clipboard.get_text())
+}
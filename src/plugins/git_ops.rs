@@ -1,7 +1,10 @@
 use std::path::Path;
 use std::process::Command;
+use std::time::{Duration, SystemTime};
 
-use anyhow::{Context, Result, ensure};
+use anyhow::{Context, Result, bail, ensure};
+
+use crate::execution::{EXIT_FAILURE, ExecutionError};
 
 /// Clones a git repository and checks out the specified ref
 ///
@@ -92,6 +95,55 @@ pub fn git_fetch(repo_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// What [`clone_or_update_plugin`] did with the plugin directory
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyncOutcome {
+    Cloned,
+    Updated,
+}
+
+/// Clones the repository tracking `branch` to `dest`, or pulls the branch when `dest`
+/// already holds a clone
+///
+/// # Errors
+///
+/// Returns an error if `dest` exists but is not a git repository, or if the clone or pull
+/// fails. A pull only fast-forwards, local changes that diverged are left alone.
+pub fn clone_or_update_plugin(git_url: &str, dest: &Path, branch: &str) -> Result<SyncOutcome> {
+    if !dest.exists() {
+        clone_plugin(git_url, dest, branch)?;
+        return Ok(SyncOutcome::Cloned);
+    }
+
+    ensure!(
+        is_git_repo(dest),
+        "{} is not a git repository",
+        dest.display()
+    );
+    git_in_repo(dest, &["checkout", "--quiet", branch, "--"])?;
+    git_in_repo(dest, &["pull", "--ff-only", "--quiet", "origin", branch])?;
+    Ok(SyncOutcome::Updated)
+}
+
+/// When the repository was last fetched from its remote, or cloned when it never was
+pub fn last_sync_time(repo_path: &Path) -> Option<SystemTime> {
+    let git_dir = repo_path.join(".git");
+    ["FETCH_HEAD", "HEAD"]
+        .iter()
+        .find_map(|file| git_dir.join(file).metadata().ok()?.modified().ok())
+}
+
+/// Whether a plugin last synced at `last_sync` is due to be pulled again at `now`. A plugin
+/// never synced is always due.
+pub fn sync_due(last_sync: Option<SystemTime>, now: SystemTime, interval: Duration) -> bool {
+    match last_sync {
+        Some(last_sync) => now
+            .duration_since(last_sync)
+            .is_ok_and(|elapsed| elapsed >= interval),
+        None => true,
+    }
+}
+
 /// Gets the latest tag sorted by version
 ///
 /// Uses `git tag --sort=-version:refname` to get tags sorted by semantic version
@@ -166,3 +218,183 @@ pub fn get_current_tag(repo_path: &Path) -> Result<Option<String>> {
 pub fn is_git_repo(path: &Path) -> bool {
     path.join(".git").exists()
 }
+
+/// Kind of change `git status` reports for a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GitFileStatus {
+    Modified,
+    Added,
+    Deleted,
+    Untracked,
+}
+
+impl GitFileStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GitFileStatus::Modified => "modified",
+            GitFileStatus::Added => "added",
+            GitFileStatus::Deleted => "deleted",
+            GitFileStatus::Untracked => "untracked",
+        }
+    }
+
+    // Staged changes win over the worktree ones of the porcelain XY code; renames, copies,
+    // type changes and conflicts count as modified
+    fn from_porcelain(code: &[u8]) -> Self {
+        match code {
+            b"??" => GitFileStatus::Untracked,
+            [b'A', _] => GitFileStatus::Added,
+            [b'D', _] | [_, b'D'] => GitFileStatus::Deleted,
+            _ => GitFileStatus::Modified,
+        }
+    }
+}
+
+/// One changed path of [`git_status`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitStatusEntry {
+    /// Path relative to the repository root, the new path of a rename
+    pub path: String,
+    pub status: GitFileStatus,
+}
+
+/// Lists the changed and untracked files of a repository
+///
+/// # Arguments
+///
+/// * `repo_path` - Path to the repository or a directory inside it
+///
+/// # Returns
+///
+/// Returns the entries in the order of `git status`, every untracked file listed on its own
+///
+/// # Errors
+///
+/// Returns an error if `repo_path` is not inside a git repository or git status fails
+pub fn git_status(repo_path: &Path) -> Result<Vec<GitStatusEntry>> {
+    let stdout = git_in_repo(
+        repo_path,
+        &["status", "--porcelain=v1", "-z", "--untracked-files=all"],
+    )?;
+
+    let mut entries = Vec::new();
+    let mut records = stdout.split(|&byte| byte == 0);
+    while let Some(record) = records.next() {
+        if record.len() < 4 {
+            continue;
+        }
+        let code = &record[..2];
+        // -z lists the original path of a rename or copy as the next record
+        if matches!(code[0], b'R' | b'C') {
+            records.next();
+        }
+        entries.push(GitStatusEntry {
+            path: String::from_utf8_lossy(&record[3..]).into_owned(),
+            status: GitFileStatus::from_porcelain(code),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// A local branch of [`git_branches`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitBranch {
+    pub name: String,
+    /// Whether the branch is checked out, false for every branch on a detached HEAD
+    pub current: bool,
+}
+
+/// Lists the local branches of a repository
+///
+/// # Arguments
+///
+/// * `repo_path` - Path to the repository or a directory inside it
+///
+/// # Returns
+///
+/// Returns the branches sorted by name, empty for a repository without commits
+///
+/// # Errors
+///
+/// Returns an error if `repo_path` is not inside a git repository or git fails
+pub fn git_branches(repo_path: &Path) -> Result<Vec<GitBranch>> {
+    let stdout = git_in_repo(
+        repo_path,
+        &[
+            "for-each-ref",
+            "--format=%(HEAD)%(refname:short)",
+            "refs/heads",
+        ],
+    )?;
+
+    Ok(String::from_utf8(stdout)?
+        .lines()
+        .filter_map(|line| {
+            let (head, name) = line.split_at_checked(1)?;
+            Some(GitBranch {
+                name: name.to_string(),
+                current: head == "*",
+            })
+        })
+        .collect())
+}
+
+/// Checks out a local branch
+///
+/// # Arguments
+///
+/// * `repo_path` - Path to the repository or a directory inside it
+/// * `branch` - Name of the local branch, a detached HEAD is left by checking it out
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `repo_path` is not inside a git repository
+/// - The branch does not exist
+/// - git refuses the checkout, e.g. because local changes would be overwritten
+pub fn checkout_branch(repo_path: &Path, branch: &str) -> Result<()> {
+    let exists = git_branches(repo_path)?
+        .iter()
+        .any(|candidate| candidate.name == branch);
+    ensure!(
+        exists,
+        "Branch '{}' does not exist in {}",
+        branch,
+        repo_path.display()
+    );
+
+    git_in_repo(repo_path, &["checkout", "--quiet", branch, "--"])?;
+    Ok(())
+}
+
+// Runs git in `repo_path` and returns its stdout, reporting a directory outside any
+// repository as such instead of with git's own message
+fn git_in_repo(repo_path: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let output = Command::new("git")
+        .current_dir(repo_path)
+        .args(args)
+        .output()
+        .with_context(|| {
+            format!(
+                "Failed to execute git {} in {} (is git installed and the directory present?)",
+                args[0],
+                repo_path.display()
+            )
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("not a git repository") {
+            bail!("{} is not a git repository", repo_path.display());
+        }
+        return Err(ExecutionError::ProcessError {
+            command: format!("git {}", args[0]),
+            exit_code: output.status.code().unwrap_or(EXIT_FAILURE),
+            stderr: stderr.trim().to_string(),
+        }
+        .into());
+    }
+
+    Ok(output.stdout)
+}
@@ -3,24 +3,28 @@ use indexmap::{IndexMap, IndexSet};
 use mlua::{Lua, Table, Value};
 use semver::Version;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fs,
     path::{Path, PathBuf},
     sync::Arc,
+    time::{Duration, Instant},
 };
 
 use crate::{
     configs::Config,
-    lua::MERGE_LUA_FN_KEY,
+    lua::{MERGE_LUA_FN_KEY, set_loaded_plugins},
     plugins::{
-        ItemSource, Metadata, Mode, ModulePathBuilder, Plugin, PluginSource, Task, TaskMap,
-        plugin_candidate::PluginCandidate,
+        Dedup, DefaultSelect, ItemSource, Metadata, Mode, ModulePathBuilder, Plugin, PluginSource,
+        Task, TaskMap,
+        plugin_candidate::{PluginCandidate, PreparedPlugin},
     },
+    tui::views::parse_color,
 };
 use tokio::sync::Mutex;
+use tracing::{debug, warn};
 use unicode_width::UnicodeWidthStr;
 
-const VALID_PLATFORMS: &[&str] = &["macos", "linux", "windows"];
+pub(crate) const VALID_PLATFORMS: &[&str] = &["macos", "linux", "windows"];
 
 fn reset_package_loaded(lua: &Lua, stdlib_keys: &[String]) -> Result<()> {
     let package: Table = lua.globals().get("package")?;
@@ -42,7 +46,8 @@ fn reset_package_loaded(lua: &Lua, stdlib_keys: &[String]) -> Result<()> {
     Ok(())
 }
 
-fn current_platform() -> &'static str {
+/// Platform syntropy runs on, named like in `metadata.platforms`
+pub fn current_platform() -> &'static str {
     #[cfg(target_os = "macos")]
     return "macos";
 
@@ -56,51 +61,71 @@ fn current_platform() -> &'static str {
     return "unknown";
 }
 
+/// Outcome of loading a single plugin
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginLoadStatus {
+    Ok,
+    /// The plugin failed to evaluate, merge or validate
+    Error(String),
+    /// The plugin loaded but does not support the current platform
+    PlatformUnsupported(String),
+}
+
+/// Load result of one plugin, as reported by [`load_plugins_with_report`]
+#[derive(Debug, Clone)]
+pub struct PluginLoadRecord {
+    /// `metadata.name`, or the plugin directory name when the file could not be evaluated
+    pub name: String,
+    /// Every plugin.lua contributing to this plugin, in directory scan order
+    pub paths: Vec<PathBuf>,
+    pub status: PluginLoadStatus,
+    /// Time spent evaluating, merging and validating the plugin
+    pub load_time: Duration,
+}
+
 pub fn load_plugins(
     plugin_paths: &[PathBuf],
     config: &Config,
     lua_runtime: Arc<Mutex<Lua>>,
 ) -> Result<Vec<Plugin>> {
-    let lua_runtime = lua_runtime.blocking_lock();
-
-    // Configure package.path ONCE for ALL plugins before any evaluation
-    // This ensures require() works during peek() and subsequent evaluations
-    let mut path_builder = ModulePathBuilder::default();
+    let (plugins, records) = load_plugins_with_report(plugin_paths, config, lua_runtime)?;
 
-    // STEP 1: Add all plugin lua/ directories
-    for plugin_dir_path in plugin_paths {
-        if !plugin_dir_path.exists() {
-            continue;
+    for record in records {
+        match record.status {
+            PluginLoadStatus::Ok => {}
+            PluginLoadStatus::Error(e) | PluginLoadStatus::PlatformUnsupported(e) => {
+                eprintln!("⚠ Skipping plugin '{}': {}", record.name, e);
+            }
         }
+    }
 
-        let plugin_dir = fs::read_dir(plugin_dir_path).with_context(|| {
-            format!(
-                "Failed to read plugins directory at: {}",
-                plugin_dir_path.display()
-            )
-        })?;
+    Ok(plugins)
+}
 
-        let mut entries: Vec<_> = plugin_dir
-            .collect::<Result<Vec<_>, _>>()
-            .context("Failed to read directory entries")?;
-        entries.sort_by_key(|entry| entry.path());
+/// Loads plugins like [`load_plugins`], but instead of printing skipped plugins returns a
+/// record for every plugin found, including the ones that failed to load.
+pub fn load_plugins_with_report(
+    plugin_paths: &[PathBuf],
+    config: &Config,
+    lua_runtime: Arc<Mutex<Lua>>,
+) -> Result<(Vec<Plugin>, Vec<PluginLoadRecord>)> {
+    let lua_runtime = lua_runtime.blocking_lock();
+    let mut records: Vec<PluginLoadRecord> = Vec::new();
 
-        for dir in entries {
-            let path = dir.path();
-            if !path.is_dir() {
-                continue;
-            }
+    // Configure package.path ONCE for ALL plugins before any evaluation
+    // This ensures require() works during peek() and subsequent evaluations
+    let mut path_builder = ModulePathBuilder::default();
 
-            if !path.join("plugin.lua").exists() {
-                continue;
-            }
+    let plugin_files = discover_plugin_files(plugin_paths)?;
 
-            let plugin_dir_str = path
-                .to_str()
-                .context("Plugin directory path contains invalid UTF-8")?;
+    // STEP 1: Add all plugin lua/ directories
+    for lua_plugin_path in &plugin_files {
+        let plugin_dir_str = lua_plugin_path
+            .parent()
+            .and_then(Path::to_str)
+            .context("Plugin directory path contains invalid UTF-8")?;
 
-            path_builder = path_builder.with_plugin_dir(plugin_dir_str);
-        }
+        path_builder = path_builder.with_plugin_dir(plugin_dir_str);
     }
 
     // STEP 2: Add shared/ directories from each plugin root
@@ -153,71 +178,53 @@ pub fn load_plugins(
     // Use IndexMap to preserve directory order (config dir before data dir)
     let mut plugin_map: IndexMap<String, Vec<PluginCandidate>> = IndexMap::new();
 
-    for plugin_dir_path in plugin_paths {
-        // Skip if directory doesn't exist (allows optional directories)
-        if !plugin_dir_path.exists() {
-            continue;
-        }
-
-        let plugin_dir = fs::read_dir(plugin_dir_path).with_context(|| {
-            format!(
-                "Failed to read plugins directory at: {}",
-                plugin_dir_path.display()
-            )
-        })?;
-
-        // Collect and sort directory entries for deterministic ordering across platforms
-        let mut entries: Vec<_> = plugin_dir
-            .collect::<Result<Vec<_>, _>>()
-            .context("Failed to read directory entries")?;
-        entries.sort_by_key(|entry| entry.path());
-
-        for dir in entries {
-            let path = dir.path();
-            if !path.is_dir() {
-                continue;
-            }
-            let lua_plugin_path = path.join("plugin.lua");
-
-            if !lua_plugin_path.exists() {
+    // Reading and compiling the files doesn't need the shared VM and runs in parallel,
+    // evaluating them stays sequential in directory order
+    for prepared in PreparedPlugin::prepare_all(plugin_files) {
+        let lua_plugin_path = prepared.path.clone();
+        let path = lua_plugin_path.parent().unwrap_or(&lua_plugin_path);
+
+        // Create candidate by peeking (caches name)
+        // Handle peek failures gracefully - skip invalid plugins
+        let peek_started = Instant::now();
+        let prepare_time = prepared.prepare_time;
+        let candidate = match PluginCandidate::peek(&lua_runtime, prepared)
+            .with_context(|| format!("Failed to peek plugin at {:?}", path))
+        {
+            Ok(c) => c,
+            Err(e) => {
+                records.push(PluginLoadRecord {
+                    name: path
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    paths: vec![lua_plugin_path.clone()],
+                    status: PluginLoadStatus::Error(format!("{:#}", e)),
+                    load_time: prepare_time + peek_started.elapsed(),
+                });
+                reset_package_loaded(&lua_runtime, &stdlib_loaded_keys)?;
                 continue;
             }
+        };
+        reset_package_loaded(&lua_runtime, &stdlib_loaded_keys)?;
 
-            // Create candidate by peeking (caches name)
-            // Handle peek failures gracefully - skip invalid plugins
-            let candidate = match PluginCandidate::peek(&lua_runtime, lua_plugin_path.clone())
-                .with_context(|| format!("Failed to peek plugin at {:?}", path))
-            {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!(
-                        "⚠ Skipping plugin '{}': {:#}",
-                        path.file_name()
-                            .and_then(|n| n.to_str())
-                            .unwrap_or("unknown"),
-                        e
-                    );
-                    reset_package_loaded(&lua_runtime, &stdlib_loaded_keys)?;
-                    continue;
-                }
-            };
-            reset_package_loaded(&lua_runtime, &stdlib_loaded_keys)?;
-
-            plugin_map
-                .entry(candidate.name.clone())
-                .or_default()
-                .push(candidate);
-        }
+        plugin_map
+            .entry(candidate.name.clone())
+            .or_default()
+            .push(candidate);
     }
 
     // PASS 2: Load plugins (with merging if multiple sources exist)
     let mut plugins: Vec<Plugin> = Vec::new();
 
     for (plugin_name, candidates) in plugin_map {
+        let paths: Vec<PathBuf> = candidates.iter().map(|c| c.path.clone()).collect();
+        let started = Instant::now();
+        let peek_time: Duration = candidates.iter().map(|c| c.peek_time).sum();
         // Wrap entire plugin loading in graceful error handling
         let plugin_result = (|| -> Result<Plugin> {
-            let paths: Vec<PathBuf> = candidates.iter().map(|c| c.path.clone()).collect();
-            let source = PluginSource::from_paths(paths)?;
+            let source = PluginSource::from_paths(paths.clone())?;
 
             let plugin = if source.needs_merge() {
                 // Evaluate cached contents from candidates
@@ -232,6 +239,7 @@ pub fn load_plugins(
                     &source,
                     &plugin_name,
                     &config.default_plugin_icon,
+                    &config.default_task_mode,
                     tables,
                 )
                 .with_context(|| format!("Failed to merge plugin '{}'", plugin_name))?
@@ -248,6 +256,7 @@ pub fn load_plugins(
                     &lua_runtime,
                     path,
                     &config.default_plugin_icon,
+                    &config.default_task_mode,
                     Some(cached_table),
                 )
                 .with_context(|| {
@@ -266,21 +275,89 @@ pub fn load_plugins(
         let plugin = match plugin_result {
             Ok(p) => p,
             Err(e) => {
-                eprintln!("⚠ Skipping plugin '{}': {:#}", plugin_name, e);
+                records.push(PluginLoadRecord {
+                    name: plugin_name,
+                    paths,
+                    status: PluginLoadStatus::Error(format!("{:#}", e)),
+                    load_time: peek_time + started.elapsed(),
+                });
                 continue;
             }
         };
 
         // Validate platform compatibility (skip gracefully on incompatibility)
         if let Err(e) = validate_plugin_platform(&plugin) {
-            eprintln!("⚠ Skipping plugin '{}': {:#}", plugin_name, e);
+            records.push(PluginLoadRecord {
+                name: plugin_name,
+                paths,
+                status: PluginLoadStatus::PlatformUnsupported(format!("{:#}", e)),
+                load_time: peek_time + started.elapsed(),
+            });
             continue;
         }
 
+        records.push(PluginLoadRecord {
+            name: plugin_name,
+            paths,
+            status: PluginLoadStatus::Ok,
+            load_time: peek_time + started.elapsed(),
+        });
         plugins.push(plugin);
     }
 
-    Ok(plugins)
+    for record in &records {
+        match &record.status {
+            PluginLoadStatus::Ok => {
+                debug!(plugin = %record.name, paths = ?record.paths, "Loaded plugin");
+            }
+            PluginLoadStatus::Error(e) => {
+                warn!(plugin = %record.name, paths = ?record.paths, error = %e, "Failed to load plugin");
+            }
+            PluginLoadStatus::PlatformUnsupported(e) => {
+                warn!(plugin = %record.name, error = %e, "Skipped plugin unsupported on this platform");
+            }
+        }
+    }
+
+    set_loaded_plugins(&lua_runtime, &plugins);
+
+    Ok((plugins, records))
+}
+
+/// Paths of the plugin.lua files in `plugin_paths`, plugin directories of each root sorted
+/// for deterministic ordering across platforms. Roots that don't exist are skipped.
+fn discover_plugin_files(plugin_paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut plugin_files = Vec::new();
+    for plugin_dir_path in plugin_paths {
+        // Skip if directory doesn't exist (allows optional directories)
+        if !plugin_dir_path.exists() {
+            continue;
+        }
+
+        let plugin_dir = fs::read_dir(plugin_dir_path).with_context(|| {
+            format!(
+                "Failed to read plugins directory at: {}",
+                plugin_dir_path.display()
+            )
+        })?;
+
+        let mut entries: Vec<_> = plugin_dir
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read directory entries")?;
+        entries.sort_by_key(|entry| entry.path());
+
+        for dir in entries {
+            let path = dir.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let lua_plugin_path = path.join("plugin.lua");
+            if lua_plugin_path.exists() {
+                plugin_files.push(lua_plugin_path);
+            }
+        }
+    }
+    Ok(plugin_files)
 }
 
 /// Evaluates a plugin.lua file and returns the plugin table
@@ -370,6 +447,7 @@ fn parse_merged_plugin(
     merged_table: &Table,
     plugin_name: &str,
     default_plugin_icon: &str,
+    default_task_mode: &Mode,
 ) -> Result<Plugin> {
     let metadata_table: Table = merged_table
         .get("metadata")
@@ -390,7 +468,7 @@ fn parse_merged_plugin(
         .get("tasks")
         .with_context(|| format!("Merged plugin '{}' missing 'tasks' table", plugin_name))?;
 
-    let tasks = parse_tasks(&tasks_table, &metadata.name)?;
+    let tasks = parse_tasks(&tasks_table, &metadata.name, default_task_mode)?;
 
     Ok(Plugin { metadata, tasks })
 }
@@ -412,6 +490,7 @@ fn load_and_merge_plugin(
     source: &PluginSource,
     plugin_name: &str,
     default_plugin_icon: &str,
+    default_task_mode: &Mode,
     cached_tables: Vec<Table>,
 ) -> Result<Plugin> {
     let (_base, _override_path, ignored) = match source {
@@ -461,7 +540,12 @@ fn load_and_merge_plugin(
     store_plugin_in_globals(lua_runtime, plugin_name, &merged_table)?;
 
     // Step 5: Parse and return Plugin struct
-    parse_merged_plugin(&merged_table, plugin_name, default_plugin_icon)
+    parse_merged_plugin(
+        &merged_table,
+        plugin_name,
+        default_plugin_icon,
+        default_task_mode,
+    )
 }
 
 /// Merges and validates two plugin files (base + override)
@@ -478,6 +562,7 @@ fn load_and_merge_plugin(
 /// * `override_path` - Path to the override plugin file
 /// * `plugin_name` - Expected plugin name (for validation)
 /// * `default_icon` - Default icon if not specified
+/// * `default_task_mode` - Mode of tasks that don't declare one
 ///
 /// Note: Module paths must be configured before calling this function
 pub fn merge_and_validate_plugins(
@@ -486,6 +571,7 @@ pub fn merge_and_validate_plugins(
     override_path: &Path,
     plugin_name: &str,
     default_icon: &str,
+    default_task_mode: &Mode,
 ) -> Result<Plugin> {
     // Load both plugin tables
     let base_table = evaluate_plugin_file(lua_runtime, base_path, None)?;
@@ -508,7 +594,7 @@ pub fn merge_and_validate_plugins(
     store_plugin_in_globals(lua_runtime, plugin_name, &merged_table)?;
 
     // Parse and validate merged plugin
-    let plugin = parse_merged_plugin(&merged_table, plugin_name, default_icon)?;
+    let plugin = parse_merged_plugin(&merged_table, plugin_name, default_icon, default_task_mode)?;
     validate_plugin(&plugin)?;
 
     Ok(plugin)
@@ -518,6 +604,7 @@ pub fn load_plugin(
     lua_runtime: &Lua,
     lua_path: &Path,
     default_plugin_icon: &str,
+    default_task_mode: &Mode,
     cached_table: Option<Table>,
 ) -> Result<Plugin> {
     // Evaluate plugin file to get table (uses cache if provided)
@@ -538,7 +625,7 @@ pub fn load_plugin(
         .set(metadata.name.as_str(), plugin_table)
         .with_context(|| format!("Failed to store plugin '{}' in Lua globals", metadata.name))?;
 
-    let tasks = parse_tasks(&tasks_table, &metadata.name)?;
+    let tasks = parse_tasks(&tasks_table, &metadata.name, default_task_mode)?;
 
     Ok(Plugin { metadata, tasks })
 }
@@ -575,7 +662,11 @@ fn parse_metadata(metadata_table: &Table, default_plugin_icon: &str) -> Result<M
     })
 }
 
-fn parse_tasks(tasks_table: &Table, plugin_name: &str) -> Result<TaskMap> {
+fn parse_tasks(
+    tasks_table: &Table,
+    plugin_name: &str,
+    default_task_mode: &Mode,
+) -> Result<TaskMap> {
     let mut tasks = HashMap::new();
 
     for key_table_pair in tasks_table.pairs::<String, Table>() {
@@ -592,18 +683,35 @@ fn parse_tasks(tasks_table: &Table, plugin_name: &str) -> Result<TaskMap> {
             .get("suppress_success_notification")
             .ok()
             .unwrap_or(false);
+        let continue_on_error: bool = task_table
+            .get::<Option<bool>>("continue_on_error")
+            .ok()
+            .flatten()
+            .unwrap_or(true);
 
         let task = Task {
             task_key: task_key.clone(),
             plugin_name: plugin_name.to_string(),
             name: task_table.get("name").unwrap_or_else(|_| task_key.clone()),
             description,
-            mode: parse_mode(&task_table)?,
+            icon: parse_task_icon(&task_table, &task_key)?,
+            mode: parse_mode(&task_table, default_task_mode)?,
+            deprecated_mode_none: task_table
+                .get::<Option<String>>("mode")
+                .ok()
+                .flatten()
+                .is_some_and(|mode| mode == "none"),
             item_sources: parse_item_sources(&task_table, &task_key)?,
             item_polling_interval,
             preview_polling_interval,
             execution_confirmation_message,
             suppress_success_notification,
+            continue_on_error,
+            dedup: parse_dedup(&task_table)?,
+            default_select: parse_default_select(&task_table)?,
+            order: parse_order(&task_table, &task_key)?,
+            group: parse_group(&task_table, &task_key)?,
+            aliases: parse_aliases(&task_table, &task_key)?,
         };
 
         validate_task(&task_table, &task_key)?;
@@ -614,26 +722,143 @@ fn parse_tasks(tasks_table: &Table, plugin_name: &str) -> Result<TaskMap> {
     Ok(tasks)
 }
 
-fn parse_mode(task_table: &Table) -> Result<Mode> {
-    let mode_str: String = task_table
-        .get("mode")
-        .unwrap_or_else(|_| "none".to_string());
+fn parse_mode(task_table: &Table, default_task_mode: &Mode) -> Result<Mode> {
+    let Some(mode_str) = task_table.get::<Option<String>>("mode").ok().flatten() else {
+        return Ok(default_task_mode.clone());
+    };
 
     match mode_str.as_str() {
         "multi" => Ok(Mode::Multi),
-        "none" => Ok(Mode::None),
-        _ => bail!("Invalid mode '{}' (must be 'multi' or 'none')", mode_str),
+        "single" | "none" => Ok(Mode::Single),
+        "background" => Ok(Mode::Background),
+        _ => bail!(
+            "Invalid mode '{}' (must be 'multi', 'single' or 'background')",
+            mode_str
+        ),
+    }
+}
+
+fn parse_dedup(task_table: &Table) -> Result<Dedup> {
+    let Some(dedup_str) = task_table.get::<Option<String>>("dedup").ok().flatten() else {
+        return Ok(Dedup::None);
+    };
+
+    match dedup_str.as_str() {
+        "none" => Ok(Dedup::None),
+        "global" => Ok(Dedup::Global),
+        "per-source" => Ok(Dedup::PerSource),
+        _ => bail!(
+            "Invalid dedup '{}' (must be 'none', 'global' or 'per-source')",
+            dedup_str
+        ),
     }
 }
 
+fn parse_default_select(task_table: &Table) -> Result<DefaultSelect> {
+    let Some(default_select_str) = task_table
+        .get::<Option<String>>("default_select")
+        .ok()
+        .flatten()
+    else {
+        return Ok(DefaultSelect::Preselected);
+    };
+
+    match default_select_str.as_str() {
+        "preselected" => Ok(DefaultSelect::Preselected),
+        "all" => Ok(DefaultSelect::All),
+        "none" => Ok(DefaultSelect::None),
+        _ => bail!(
+            "Invalid default_select '{}' (must be 'preselected', 'all' or 'none')",
+            default_select_str
+        ),
+    }
+}
+
+fn parse_order(task_table: &Table, task_key: &str) -> Result<Option<i64>> {
+    match task_table.get::<mlua::Value>("order")? {
+        mlua::Value::Nil => Ok(None),
+        mlua::Value::Integer(order) => Ok(Some(order)),
+        value => bail!(
+            "Task '{}' order field must be an integer, got {}",
+            task_key,
+            value.type_name()
+        ),
+    }
+}
+
+fn parse_task_icon(task_table: &Table, task_key: &str) -> Result<String> {
+    match task_table.get::<mlua::Value>("icon")? {
+        mlua::Value::Nil => Ok(String::new()),
+        mlua::Value::String(icon) => Ok(icon.to_str()?.to_string()),
+        value => bail!(
+            "Task '{}' icon field must be a string, got {}",
+            task_key,
+            value.type_name()
+        ),
+    }
+}
+
+fn parse_group(task_table: &Table, task_key: &str) -> Result<Option<String>> {
+    match task_table.get::<mlua::Value>("group")? {
+        mlua::Value::Nil => Ok(None),
+        mlua::Value::String(group) => {
+            let group = group.to_str()?.to_string();
+            ensure!(
+                !group.trim().is_empty(),
+                "Task '{}' group field cannot be empty",
+                task_key
+            );
+            Ok(Some(group))
+        }
+        value => bail!(
+            "Task '{}' group field must be a string, got {}",
+            task_key,
+            value.type_name()
+        ),
+    }
+}
+
+fn parse_aliases(task_table: &Table, task_key: &str) -> Result<Vec<String>> {
+    let aliases_table = match task_table.get::<mlua::Value>("aliases")? {
+        mlua::Value::Nil => return Ok(Vec::new()),
+        mlua::Value::Table(aliases_table) => aliases_table,
+        value => bail!(
+            "Task '{}' aliases field must be a table of strings, got {}",
+            task_key,
+            value.type_name()
+        ),
+    };
+
+    let mut aliases = Vec::new();
+    for alias in aliases_table.sequence_values::<mlua::Value>() {
+        let alias = match alias? {
+            mlua::Value::String(alias) => alias.to_str()?.to_string(),
+            value => bail!(
+                "Task '{}' aliases must be strings, got {}",
+                task_key,
+                value.type_name()
+            ),
+        };
+        ensure!(
+            !alias.trim().is_empty(),
+            "Task '{}' aliases cannot be empty",
+            task_key
+        );
+        if alias != task_key && !aliases.contains(&alias) {
+            aliases.push(alias);
+        }
+    }
+    Ok(aliases)
+}
+
 fn parse_item_sources(
     task_table: &Table,
     task_key: &str,
-) -> Result<Option<HashMap<String, ItemSource>>> {
+) -> Result<Option<IndexMap<String, ItemSource>>> {
     let sources_table = task_table.get::<Table>("item_sources").ok();
 
     if let Some(sources_table) = sources_table {
-        let mut sources = HashMap::new();
+        let mut sources = IndexMap::new();
 
         for key_table_pair in sources_table.pairs() {
             let (item_source_key, source_table): (String, Table) = key_table_pair
@@ -643,11 +868,20 @@ fn parse_item_sources(
                 .get("tag")
                 .with_context(|| format!("Item source {} missing 'tag' field", item_source_key))?;
 
+            let source_owner = format!("Item source '{}' in task '{}'", item_source_key, task_key);
+            let has_items_fn =
+                function_field(&source_table, ItemSource::LUA_FN_NAME_ITEMS, &source_owner)?;
+            let items_command = parse_items_command(&source_table, &source_owner)?;
+            let color = parse_source_color(&source_table, &source_owner)?;
             ensure!(
-                source_table.get::<mlua::Function>("items").is_ok(),
-                "Item source '{}' in task '{}' must define an 'items' function",
-                item_source_key,
-                task_key
+                has_items_fn || items_command.is_some(),
+                "{} must define an 'items' function or an 'items_command'",
+                source_owner
+            );
+            ensure!(
+                !(has_items_fn && items_command.is_some()),
+                "{} cannot define both an 'items' function and an 'items_command'",
+                source_owner
             );
 
             sources.insert(
@@ -655,115 +889,410 @@ fn parse_item_sources(
                 ItemSource {
                     tag,
                     item_source_key,
+                    color,
+                    items_command,
                 },
             );
         }
 
+        // Lua tables keep no declaration order and `pairs` visits string keys in a different
+        // order in every VM, the source key is the only stable order
+        sources.sort_unstable_keys();
         Ok(Some(sources))
     } else {
         Ok(None)
     }
 }
 
+// Optional `items_command` of an item source, a non-empty shell command
+fn parse_items_command(source_table: &Table, source_owner: &str) -> Result<Option<String>> {
+    match source_table.get::<mlua::Value>(ItemSource::LUA_PROPERTY_ITEMS_COMMAND)? {
+        mlua::Value::Nil => Ok(None),
+        mlua::Value::String(command) => {
+            let command = command.to_str()?.trim().to_string();
+            ensure!(
+                !command.is_empty(),
+                "{} 'items_command' cannot be empty",
+                source_owner
+            );
+            Ok(Some(command))
+        }
+        value => bail!(
+            "{} 'items_command' must be a string, got {}",
+            source_owner,
+            value.type_name()
+        ),
+    }
+}
+
+// Optional `color` of an item source, validated like the colors of the config
+fn parse_source_color(source_table: &Table, source_owner: &str) -> Result<Option<String>> {
+    match source_table.get::<mlua::Value>(ItemSource::LUA_PROPERTY_COLOR)? {
+        mlua::Value::Nil => Ok(None),
+        mlua::Value::String(color) => {
+            let color = color.to_str()?.trim().to_string();
+            parse_color(&color)
+                .with_context(|| format!("{} has an invalid 'color'", source_owner))?;
+            Ok((!color.is_empty()).then_some(color))
+        }
+        value => bail!(
+            "{} 'color' must be a string, got {}",
+            source_owner,
+            value.type_name()
+        ),
+    }
+}
+
 /// Validates platform compatibility for a plugin
 /// Returns an error if the plugin declares platforms and the current platform is not supported
-pub fn validate_plugin_platform(plugin: &Plugin) -> Result<()> {
-    if !plugin.metadata.platforms.is_empty() {
-        // Check all declared platforms are valid
-        for platform in &plugin.metadata.platforms {
-            ensure!(
-                VALID_PLATFORMS.contains(&platform.as_str()),
+/// A problem found while validating a plugin, with a stable code for `validate --format json`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    MissingName,
+    MissingVersion {
+        plugin: String,
+    },
+    InvalidVersion {
+        plugin: String,
+        version: String,
+    },
+    InvalidIcon {
+        plugin: String,
+        icon: String,
+    },
+    InvalidTaskIcon {
+        plugin: String,
+        task: String,
+        icon: String,
+    },
+    NoTasks {
+        plugin: String,
+    },
+    /// A mode='multi' task has an item source without a tag
+    MissingMultiModeTag {
+        plugin: String,
+        task: String,
+    },
+    /// A task with several item sources has one without a tag
+    MissingSourceTag {
+        plugin: String,
+        task: String,
+    },
+    DuplicateTag {
+        plugin: String,
+        task: String,
+        tag: String,
+    },
+    /// An alias claimed by several tasks of a plugin
+    DuplicateAlias {
+        plugin: String,
+        alias: String,
+        tasks: Vec<String>,
+    },
+    InvalidPlatform {
+        plugin: String,
+        platform: String,
+    },
+    UnsupportedPlatform {
+        plugin: String,
+        platform: String,
+        supported: Vec<String>,
+    },
+    /// The plugin file could not be evaluated, parsed or merged
+    LoadFailed(String),
+    /// A task function returned a value of the wrong type when called with mock data
+    InvalidFunctionType(String),
+    /// A task declares `mode = "none"`, which still loads as `mode = "single"`
+    DeprecatedModeNone {
+        plugin: String,
+        task: String,
+    },
+}
+
+impl ValidationIssue {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationIssue::MissingName => "MISSING_NAME",
+            ValidationIssue::MissingVersion { .. } => "MISSING_VERSION",
+            ValidationIssue::InvalidVersion { .. } => "INVALID_VERSION",
+            ValidationIssue::InvalidIcon { .. } => "INVALID_ICON",
+            ValidationIssue::InvalidTaskIcon { .. } => "INVALID_TASK_ICON",
+            ValidationIssue::NoTasks { .. } => "NO_TASKS",
+            ValidationIssue::MissingMultiModeTag { .. } => "MISSING_MULTI_MODE_TAG",
+            ValidationIssue::MissingSourceTag { .. } => "MISSING_SOURCE_TAG",
+            ValidationIssue::DuplicateTag { .. } => "DUPLICATE_TAG",
+            ValidationIssue::DuplicateAlias { .. } => "DUPLICATE_ALIAS",
+            ValidationIssue::InvalidPlatform { .. } => "INVALID_PLATFORM",
+            ValidationIssue::UnsupportedPlatform { .. } => "UNSUPPORTED_PLATFORM",
+            ValidationIssue::LoadFailed(_) => "LOAD_FAILED",
+            ValidationIssue::InvalidFunctionType(_) => "INVALID_FUNCTION_TYPE",
+            ValidationIssue::DeprecatedModeNone { .. } => "DEPRECATED_MODE_NONE",
+        }
+    }
+
+    /// The issue carried by `error` or its context chain, otherwise a `LoadFailed`
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ValidationIssue>())
+            .cloned()
+            .unwrap_or_else(|| ValidationIssue::LoadFailed(format!("{:#}", error)))
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationIssue::MissingName => write!(f, "Plugin must have a name"),
+            ValidationIssue::MissingVersion { plugin } => {
+                write!(f, "Plugin ({}) must have a specified version", plugin)
+            }
+            ValidationIssue::InvalidVersion { plugin, version } => write!(
+                f,
+                "Plugin ({}) version '{}' has invalid format - must follow semantic versioning (e.g., '1.0.0', '2.5.1-beta')",
+                plugin, version
+            ),
+            ValidationIssue::InvalidIcon { plugin, icon } => write!(
+                f,
+                "Plugin ({}) icon '{}' must occupy a single terminal cell",
+                plugin, icon
+            ),
+            ValidationIssue::InvalidTaskIcon { plugin, task, icon } => write!(
+                f,
+                "Task ({}) {} icon '{}' must occupy a single terminal cell",
+                plugin, task, icon
+            ),
+            ValidationIssue::NoTasks { plugin } => {
+                write!(f, "Plugin ({}) must define at least one task", plugin)
+            }
+            ValidationIssue::MissingMultiModeTag { plugin, task } => write!(
+                f,
+                "Task ({}) {} uses mode='multi' which requires all item sources to declare a non-empty tag",
+                plugin, task
+            ),
+            ValidationIssue::MissingSourceTag { plugin, task } => write!(
+                f,
+                "Task ({}) {} has multiple item sources so every item source needs to declare a tag",
+                plugin, task
+            ),
+            ValidationIssue::DuplicateTag { plugin, task, tag } => write!(
+                f,
+                "Task ({}) {} has duplicate tag '{}' in item sources - each source must have a unique tag",
+                plugin, task, tag
+            ),
+            ValidationIssue::DuplicateAlias {
+                plugin,
+                alias,
+                tasks,
+            } => write!(
+                f,
+                "Plugin ({}) alias '{}' is used by several tasks: {} - each alias must name a single task",
+                plugin,
+                alias,
+                tasks.join(", ")
+            ),
+            ValidationIssue::InvalidPlatform { plugin, platform } => write!(
+                f,
                 "Plugin ({}) declares invalid platform '{}' - valid platforms are: {}",
-                plugin.metadata.name,
+                plugin,
                 platform,
                 VALID_PLATFORMS.join(", ")
-            );
+            ),
+            ValidationIssue::UnsupportedPlatform {
+                plugin,
+                platform,
+                supported,
+            } => write!(
+                f,
+                "Plugin ({}) does not support current platform '{}' - supported platforms: {}",
+                plugin,
+                platform,
+                supported.join(", ")
+            ),
+            ValidationIssue::LoadFailed(message)
+            | ValidationIssue::InvalidFunctionType(message) => {
+                write!(f, "{}", message)
+            }
+            ValidationIssue::DeprecatedModeNone { plugin, task } => write!(
+                f,
+                "Task ({}) {} uses the deprecated mode='none', use mode='single' instead",
+                plugin, task
+            ),
         }
+    }
+}
 
-        // Check if current platform is supported
-        let current = current_platform();
-        if current != "unknown" {
-            ensure!(
-                plugin.metadata.platforms.iter().any(|p| p == current),
-                "Plugin ({}) does not support current platform '{}' - supported platforms: {}",
-                plugin.metadata.name,
-                current,
-                plugin.metadata.platforms.join(", ")
-            );
+impl std::error::Error for ValidationIssue {}
+
+fn first_issue(issues: Vec<ValidationIssue>) -> Result<()> {
+    match issues.into_iter().next() {
+        Some(issue) => Err(issue.into()),
+        None => Ok(()),
+    }
+}
+
+/// Platform problems of a plugin: declared platforms that don't exist and an unsupported
+/// current platform
+pub fn plugin_platform_issues(plugin: &Plugin) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if plugin.metadata.platforms.is_empty() {
+        return issues;
+    }
+
+    // Check all declared platforms are valid
+    for platform in &plugin.metadata.platforms {
+        if !VALID_PLATFORMS.contains(&platform.as_str()) {
+            issues.push(ValidationIssue::InvalidPlatform {
+                plugin: plugin.metadata.name.clone(),
+                platform: platform.clone(),
+            });
         }
     }
-    Ok(())
+
+    // Check if current platform is supported
+    let current = current_platform();
+    if current != "unknown" && !plugin.metadata.platforms.iter().any(|p| p == current) {
+        issues.push(ValidationIssue::UnsupportedPlatform {
+            plugin: plugin.metadata.name.clone(),
+            platform: current.to_string(),
+            supported: plugin.metadata.platforms.clone(),
+        });
+    }
+    issues
 }
 
-pub fn validate_plugin(plugin: &Plugin) -> Result<()> {
-    ensure!(!plugin.metadata.name.is_empty(), "Plugin must have a name");
-    ensure!(
-        !plugin.metadata.version.is_empty(),
-        "Plugin ({}) must have a specified version",
-        plugin.metadata.name
-    );
+pub fn validate_plugin_platform(plugin: &Plugin) -> Result<()> {
+    first_issue(plugin_platform_issues(plugin))
+}
 
-    Version::parse(&plugin.metadata.version).map_err(|_| {
-        anyhow::anyhow!(
-            "Plugin ({}) version '{}' has invalid format - must follow semantic versioning (e.g., '1.0.0', '2.5.1-beta')",
-            plugin.metadata.name,
-            plugin.metadata.version,
-        )
-    })?;
+/// Every structural problem of a plugin, in the order `validate_plugin` reports them
+pub fn plugin_issues(plugin: &Plugin) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let name = &plugin.metadata.name;
 
-    ensure!(
-        plugin.metadata.icon.width() == 1,
-        "Plugin ({}) icon '{}' must occupy a single terminal cell",
-        plugin.metadata.name,
-        plugin.metadata.icon,
-    );
+    if name.is_empty() {
+        issues.push(ValidationIssue::MissingName);
+    }
+    if plugin.metadata.version.is_empty() {
+        issues.push(ValidationIssue::MissingVersion {
+            plugin: name.clone(),
+        });
+    } else if Version::parse(&plugin.metadata.version).is_err() {
+        issues.push(ValidationIssue::InvalidVersion {
+            plugin: name.clone(),
+            version: plugin.metadata.version.clone(),
+        });
+    }
 
-    ensure!(
-        !plugin.tasks.is_empty(),
-        "Plugin ({}) must define at least one task",
-        plugin.metadata.name
-    );
+    if plugin.metadata.icon.width() != 1 {
+        issues.push(ValidationIssue::InvalidIcon {
+            plugin: name.clone(),
+            icon: plugin.metadata.icon.clone(),
+        });
+    }
 
-    for (task_key, task) in &plugin.tasks {
-        if let Some(item_sources) = &task.item_sources {
-            // Validate that multi-source tasks OR multi-mode tasks have non-empty tags
-            if task.mode == Mode::Multi {
-                // Multi mode requires ALL sources to have non-empty tags (for UI consistency)
-                ensure!(
-                    item_sources.values().all(|s| !s.tag.is_empty()),
-                    "Task ({}) {} uses mode='multi' which requires all item sources to declare a non-empty tag",
-                    plugin.metadata.name,
-                    task_key
-                );
-            } else {
-                // For mode=none, only multi-source tasks need non-empty tags
-                ensure!(
-                    item_sources.is_empty()
-                        || item_sources.len() == 1
-                        || item_sources.values().all(|s| !s.tag.is_empty()),
-                    "Task ({}) {} has multiple item sources so every item source needs to declare a tag",
-                    plugin.metadata.name,
-                    task_key
-                );
+    if plugin.tasks.is_empty() {
+        issues.push(ValidationIssue::NoTasks {
+            plugin: name.clone(),
+        });
+    }
+
+    let mut task_keys: Vec<&String> = plugin.tasks.keys().collect();
+    task_keys.sort();
+    for task_key in &task_keys {
+        let icon = &plugin.tasks[*task_key].icon;
+        if !icon.is_empty() && icon.width() != 1 {
+            issues.push(ValidationIssue::InvalidTaskIcon {
+                plugin: name.clone(),
+                task: task_key.to_string(),
+                icon: icon.clone(),
+            });
+        }
+    }
+    for task_key in task_keys {
+        let Some(item_sources) = &plugin.tasks[task_key].item_sources else {
+            continue;
+        };
+        // Validate that multi-source tasks OR multi-mode tasks have non-empty tags
+        if plugin.tasks[task_key].mode == Mode::Multi {
+            // Multi mode requires ALL sources to have non-empty tags (for UI consistency)
+            if !item_sources.values().all(|s| !s.tag.is_empty()) {
+                issues.push(ValidationIssue::MissingMultiModeTag {
+                    plugin: name.clone(),
+                    task: task_key.clone(),
+                });
             }
+        } else if item_sources.len() > 1 && !item_sources.values().all(|s| !s.tag.is_empty()) {
+            // For mode=single, only multi-source tasks need non-empty tags
+            issues.push(ValidationIssue::MissingSourceTag {
+                plugin: name.clone(),
+                task: task_key.clone(),
+            });
+        }
 
-            // Validate no duplicate tags in item sources
-            if item_sources.len() > 1 {
-                let mut seen_tags = std::collections::HashSet::new();
-                for source in item_sources.values() {
-                    if !source.tag.is_empty() && !seen_tags.insert(&source.tag) {
-                        bail!(
-                            "Task ({}) {} has duplicate tag '{}' in item sources - each source must have a unique tag",
-                            plugin.metadata.name,
-                            task_key,
-                            source.tag
-                        );
-                    }
+        // Validate no duplicate tags in item sources
+        if item_sources.len() > 1 {
+            let mut seen_tags = std::collections::HashSet::new();
+            let mut duplicate_tags = std::collections::BTreeSet::new();
+            for source in item_sources.values() {
+                if !source.tag.is_empty() && !seen_tags.insert(&source.tag) {
+                    duplicate_tags.insert(source.tag.clone());
                 }
             }
+            for tag in duplicate_tags {
+                issues.push(ValidationIssue::DuplicateTag {
+                    plugin: name.clone(),
+                    task: task_key.clone(),
+                    tag,
+                });
+            }
         }
     }
-    Ok(())
+
+    // An alias must name a single task. One equal to another task's key is only shadowed,
+    // `--task` prefers exact keys
+    let mut alias_owners: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (task_key, task) in &plugin.tasks {
+        for alias in &task.aliases {
+            alias_owners
+                .entry(alias.as_str())
+                .or_default()
+                .push(task_key.clone());
+        }
+    }
+    for (alias, mut tasks) in alias_owners {
+        if tasks.len() > 1 {
+            tasks.sort();
+            issues.push(ValidationIssue::DuplicateAlias {
+                plugin: name.clone(),
+                alias: alias.to_string(),
+                tasks,
+            });
+        }
+    }
+    issues
+}
+
+/// Problems of a plugin that still loads, deprecated values it should move away from
+pub fn plugin_warnings(plugin: &Plugin) -> Vec<ValidationIssue> {
+    let mut task_keys: Vec<&String> = plugin.tasks.keys().collect();
+    task_keys.sort();
+    task_keys
+        .into_iter()
+        .filter(|task_key| plugin.tasks[*task_key].deprecated_mode_none)
+        .map(|task_key| ValidationIssue::DeprecatedModeNone {
+            plugin: plugin.metadata.name.clone(),
+            task: task_key.clone(),
+        })
+        .collect()
+}
+
+/// Fails on the first issue of [`plugin_issues`], [`plugin_warnings`] are only logged
+pub fn validate_plugin(plugin: &Plugin) -> Result<()> {
+    for warning in plugin_warnings(plugin) {
+        warn!(plugin = %plugin.metadata.name, "{}", warning);
+    }
+    first_issue(plugin_issues(plugin))
 }
 
 /// Validates a plugin with runtime function type checking
@@ -799,11 +1328,33 @@ pub async fn validate_plugin_with_runtime(lua_runtime: &Lua, plugin: &Plugin) ->
     Ok(())
 }
 
+/// Whether `table` defines the function `field`, erroring when the field holds anything
+/// other than a function or nil
+fn function_field(table: &Table, field: &str, owner: &str) -> Result<bool> {
+    match table.get::<mlua::Value>(field)? {
+        mlua::Value::Nil => Ok(false),
+        mlua::Value::Function(_) => Ok(true),
+        value => bail!(
+            "{} {} field must be a function, got {}",
+            owner,
+            field,
+            value.type_name()
+        ),
+    }
+}
+
 fn validate_task(task_table: &Table, task_key: &str) -> Result<()> {
+    let task_owner = format!("Task '{}'", task_key);
+    let has_execute = function_field(task_table, Task::LUA_FN_NAME_EXECUTE, &task_owner)?;
+    for field in [
+        Task::LUA_FN_NAME_PREVIEW,
+        Task::LUA_FN_NAME_PRE_RUN,
+        Task::LUA_FN_NAME_POST_RUN,
+    ] {
+        function_field(task_table, field, &task_owner)?;
+    }
+
     let has_item_sources_table = task_table.get::<Table>("item_sources").is_ok();
-    let has_execute = task_table
-        .get::<mlua::Function>(Task::LUA_FN_NAME_EXECUTE)
-        .is_ok();
 
     // Check if item_sources table exists AND is non-empty
     let has_valid_item_sources = if has_item_sources_table {
@@ -822,6 +1373,32 @@ fn validate_task(task_table: &Table, task_key: &str) -> Result<()> {
         task_key
     );
 
+    if has_valid_item_sources {
+        let sources_table: Table = task_table.get("item_sources")?;
+        for pair in sources_table.pairs::<String, Table>() {
+            let (source_key, source_table) = pair?;
+            let source_owner = format!("Item source '{}' in task '{}'", source_key, task_key);
+            let has_source_execute = function_field(
+                &source_table,
+                ItemSource::LUA_FN_NAME_EXECUTE,
+                &source_owner,
+            )?;
+            for field in [
+                ItemSource::LUA_FN_NAME_PREVIEW,
+                ItemSource::LUA_FN_NAME_PRESELECTED_ITEMS,
+                ItemSource::LUA_FN_NAME_ENABLED,
+            ] {
+                function_field(&source_table, field, &source_owner)?;
+            }
+            ensure!(
+                has_source_execute || has_execute,
+                "{} has no 'execute' function and task '{}' defines none to fall back to",
+                source_owner,
+                task_key
+            );
+        }
+    }
+
     let description: String = task_table.get("description").unwrap_or_default();
     ensure!(
         !description.is_empty(),
@@ -849,6 +1426,22 @@ async fn validate_preview_return_type(preview_fn: &mlua::Function, context: &str
     }
 }
 
+/// Validates that enabled() returns a boolean
+async fn validate_enabled_return_type(enabled_fn: &mlua::Function, context: &str) -> Result<()> {
+    match enabled_fn.call_async::<mlua::Value>(()).await {
+        Ok(value) => {
+            ensure!(
+                value.is_boolean(),
+                "{} must return a boolean but returned {}",
+                context,
+                value.type_name()
+            );
+            Ok(())
+        }
+        Err(e) => Err(e).with_context(|| format!("{} validation failed", context)),
+    }
+}
+
 /// Validates that items() returns an array (sequential table)
 async fn validate_items_return_type(items_fn: &mlua::Function, context: &str) -> Result<()> {
     match items_fn.call_async::<mlua::Value>(()).await {
@@ -996,6 +1589,16 @@ async fn validate_task_function_types(
         for pair in item_sources_table.pairs::<String, Table>() {
             let (source_key, source_table) = pair?;
 
+            if let Ok(enabled_fn) =
+                source_table.get::<mlua::Function>(ItemSource::LUA_FN_NAME_ENABLED)
+            {
+                validate_enabled_return_type(
+                    &enabled_fn,
+                    &format!("Item source '{}' enabled()", source_key),
+                )
+                .await?;
+            }
+
             // Validate items() function
             if let Ok(items_fn) = source_table.get::<mlua::Function>(ItemSource::LUA_FN_NAME_ITEMS)
             {
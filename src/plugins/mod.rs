@@ -4,15 +4,19 @@ mod module_path_builder;
 mod plugin;
 mod plugin_candidate;
 mod plugin_source;
+mod schema;
 
 use std::{collections::HashMap, sync::Arc};
 
 pub use loader::{
-    load_plugin, load_plugins, merge_and_validate_plugins, validate_plugin,
-    validate_plugin_platform, validate_plugin_with_runtime,
+    PluginLoadRecord, PluginLoadStatus, ValidationIssue, current_platform, load_plugin,
+    load_plugins, load_plugins_with_report, merge_and_validate_plugins, plugin_issues,
+    plugin_platform_issues, plugin_warnings, validate_plugin, validate_plugin_platform,
+    validate_plugin_with_runtime,
 };
 pub use module_path_builder::ModulePathBuilder;
-pub use plugin::{ItemSource, Metadata, Mode, Plugin, Task};
+pub use plugin::{Dedup, DefaultSelect, ItemSource, Metadata, Mode, Plugin, Task, TaskGroup};
 use plugin_source::PluginSource;
+pub use schema::plugin_schema;
 
 type TaskMap = HashMap<String, Arc<Task>>;
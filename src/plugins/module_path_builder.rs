@@ -2,32 +2,8 @@ use anyhow::Result;
 use mlua::{Lua, Table};
 use std::path::Path;
 
-/// Configure Lua package.path for plugin module loading
-///
-/// Sets up resolution order:
-/// 1. Plugin namespaced modules: <plugin_dir>/lua/?.lua and <plugin_dir>/lua/?/init.lua
-/// 2. Shared syntropy modules: <syntropy_root>/plugins/shared/?.lua
-///
-/// # Arguments
-/// * `lua` - Lua VM instance
-/// * `plugin_dir` - Absolute path to plugin directory (containing plugin.lua)
-/// * `syntropy_root` - Absolute path to syntropy repository root
-///
-/// # Example
-/// ```
-/// use mlua::Lua;
-/// use std::path::Path;
-///
-/// # fn example() -> mlua::Result<()> {
-/// let lua = Lua::new();
-/// let plugin_dir = "/path/to/plugins/packages";
-/// let syntropy_root = "/path/to/syntropy";
-///
-/// // This would configure Lua package.path for the plugin
-/// // syntropy::lua::runtime::configure_module_paths(&lua, plugin_dir, syntropy_root)?;
-/// # Ok(())
-/// # }
-/// ```
+use crate::lua::PluginModulePath;
+
 /// Builder for constructing Lua package.path strings
 ///
 /// Consolidates module path configuration logic for plugins.
@@ -77,14 +53,176 @@ impl ModulePathBuilder {
     }
 
     /// Build the final package.path string (semicolon-separated)
+    ///
+    /// `require("name")` replaces every `?` of a template with `name`, its dots turned into
+    /// path separators, and loads the first file that exists. The templates keep the order
+    /// they were added in, so:
+    ///
+    /// 1. Plugin directories come first, in the order of [`Self::with_plugin_dir`] calls.
+    ///    Each contributes `<plugin_dir>/lua/?.lua` followed by `<plugin_dir>/lua/?/init.lua`,
+    ///    so `require("a.b.c")` finds `lua/a/b/c.lua` before `lua/a/b/c/init.lua`.
+    /// 2. Shared modules come after all plugin directories, `<root>/plugins/shared/?.lua`
+    ///    for each [`Self::with_shared_modules`] call, and are only reached when no plugin
+    ///    provides the module.
+    ///
+    /// The loader adds the config directory's plugins before the data directory's, so a
+    /// module present in both resolves to the config copy. Directories without a `lua/` or
+    /// `plugins/shared/` subdirectory add nothing.
     pub fn build(self) -> String {
         self.paths.join(";")
     }
 
     /// Apply the built path to a Lua runtime's package.path
+    ///
+    /// The path is also kept as the only search path of the sandboxed `require`.
     pub fn apply(self, lua: &Lua) -> Result<()> {
+        let path = self.build();
         let package: Table = lua.globals().get("package")?;
-        package.set("path", self.build())?;
+        package.set("path", path.as_str())?;
+        lua.set_app_data(PluginModulePath(path));
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_module(root: &Path, relative: &str, value: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, format!("return \"{}\"", value)).unwrap();
+    }
+
+    fn require(builder: ModulePathBuilder, module: &str) -> mlua::Result<String> {
+        let lua = Lua::new();
+        builder.apply(&lua).unwrap();
+        lua.load(format!("return require(\"{}\")", module)).eval()
+    }
+
+    fn dir(temp: &TempDir, name: &str) -> String {
+        temp.path().join(name).to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_plugin_dir_adds_module_and_init_templates() {
+        let temp = TempDir::new().unwrap();
+        let plugin_dir = dir(&temp, "notes");
+        fs::create_dir_all(format!("{}/lua", plugin_dir)).unwrap();
+
+        let path = ModulePathBuilder::default()
+            .with_plugin_dir(&plugin_dir)
+            .build();
+
+        assert_eq!(
+            path,
+            format!("{0}/lua/?.lua;{0}/lua/?/init.lua", plugin_dir)
+        );
+    }
+
+    #[test]
+    fn test_dirs_without_lua_or_shared_add_nothing() {
+        let temp = TempDir::new().unwrap();
+
+        let path = ModulePathBuilder::default()
+            .with_plugin_dir(&dir(&temp, "notes"))
+            .with_shared_modules(&dir(&temp, "root"))
+            .build();
+
+        assert!(path.is_empty());
+    }
+
+    #[test]
+    fn test_basic_module_resolution() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "notes/lua/notes/utils.lua", "utils");
+
+        let builder = ModulePathBuilder::default().with_plugin_dir(&dir(&temp, "notes"));
+
+        assert_eq!(require(builder, "notes.utils").unwrap(), "utils");
+    }
+
+    #[test]
+    fn test_nested_module_resolution() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "notes/lua/a/b/c.lua", "nested");
+
+        let builder = ModulePathBuilder::default().with_plugin_dir(&dir(&temp, "notes"));
+
+        assert_eq!(require(builder, "a.b.c").unwrap(), "nested");
+    }
+
+    #[test]
+    fn test_directory_module_resolves_to_init() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "notes/lua/notes/init.lua", "init");
+
+        let builder = ModulePathBuilder::default().with_plugin_dir(&dir(&temp, "notes"));
+
+        assert_eq!(require(builder, "notes").unwrap(), "init");
+    }
+
+    #[test]
+    fn test_config_dir_takes_precedence_over_data_dir() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "config/notes/lua/notes/utils.lua", "config");
+        write_module(temp.path(), "data/notes/lua/notes/utils.lua", "data");
+
+        let builder = ModulePathBuilder::default()
+            .with_plugin_dir(&dir(&temp, "config/notes"))
+            .with_plugin_dir(&dir(&temp, "data/notes"));
+
+        assert_eq!(require(builder, "notes.utils").unwrap(), "config");
+    }
+
+    #[test]
+    fn test_data_dir_module_is_found_when_config_lacks_it() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "config/notes/lua/notes/other.lua", "other");
+        write_module(temp.path(), "data/notes/lua/notes/utils.lua", "data");
+
+        let builder = ModulePathBuilder::default()
+            .with_plugin_dir(&dir(&temp, "config/notes"))
+            .with_plugin_dir(&dir(&temp, "data/notes"));
+
+        assert_eq!(require(builder, "notes.utils").unwrap(), "data");
+    }
+
+    #[test]
+    fn test_shared_module_fallback() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("notes/lua")).unwrap();
+        write_module(temp.path(), "root/plugins/shared/strings.lua", "shared");
+
+        let builder = ModulePathBuilder::default()
+            .with_plugin_dir(&dir(&temp, "notes"))
+            .with_shared_modules(&dir(&temp, "root"));
+
+        assert_eq!(require(builder, "strings").unwrap(), "shared");
+    }
+
+    #[test]
+    fn test_plugin_module_shadows_shared_module() {
+        let temp = TempDir::new().unwrap();
+        write_module(temp.path(), "notes/lua/strings.lua", "plugin");
+        write_module(temp.path(), "root/plugins/shared/strings.lua", "shared");
+
+        let builder = ModulePathBuilder::default()
+            .with_plugin_dir(&dir(&temp, "notes"))
+            .with_shared_modules(&dir(&temp, "root"));
+
+        assert_eq!(require(builder, "strings").unwrap(), "plugin");
+    }
+
+    #[test]
+    fn test_missing_module_fails() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("notes/lua")).unwrap();
+
+        let builder = ModulePathBuilder::default().with_plugin_dir(&dir(&temp, "notes"));
+
+        assert!(require(builder, "missing").is_err());
+    }
+}
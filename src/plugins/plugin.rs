@@ -1,24 +1,94 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
 
 use crate::plugins::TaskMap;
 
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Mode {
     Multi,
+    /// A single item is executed, `"none"` is its deprecated name
     #[default]
-    None,
+    #[serde(alias = "none")]
+    Single,
+    Background,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 3] = [Mode::Multi, Mode::Single, Mode::Background];
 }
 
 impl fmt::Display for Mode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Mode::Multi => write!(f, "multi"),
-            Mode::None => write!(f, "none"),
+            Mode::Single => write!(f, "single"),
+            Mode::Background => write!(f, "background"),
+        }
+    }
+}
+
+/// How duplicate items of a task's item sources are removed, by item value
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Dedup {
+    /// Keep every item
+    #[default]
+    None,
+    /// Keep the first occurrence across all sources, with its tag
+    Global,
+    /// Keep the first occurrence within each source
+    PerSource,
+}
+
+impl Dedup {
+    pub const ALL: [Dedup; 3] = [Dedup::None, Dedup::Global, Dedup::PerSource];
+}
+
+impl fmt::Display for Dedup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dedup::None => write!(f, "none"),
+            Dedup::Global => write!(f, "global"),
+            Dedup::PerSource => write!(f, "per-source"),
+        }
+    }
+}
+
+/// Items of a `mode="multi"` task that are marked when its items are first listed
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DefaultSelect {
+    /// The items returned by the `preselected_items` of the item sources
+    #[default]
+    Preselected,
+    /// Every item
+    All,
+    /// No item, even when item sources preselect some
+    None,
+}
+
+impl DefaultSelect {
+    pub const ALL: [DefaultSelect; 3] = [
+        DefaultSelect::Preselected,
+        DefaultSelect::All,
+        DefaultSelect::None,
+    ];
+}
+
+impl fmt::Display for DefaultSelect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefaultSelect::Preselected => write!(f, "preselected"),
+            DefaultSelect::All => write!(f, "all"),
+            DefaultSelect::None => write!(f, "none"),
         }
     }
 }
 
+/// Tasks sharing a `group` (or `None` for ungrouped tasks) as `(task_key, task)` pairs
+pub type TaskGroup<'a> = (Option<&'a str>, Vec<(&'a str, &'a Arc<Task>)>);
+
 #[derive(Debug, Clone)]
 pub struct Plugin {
     pub metadata: Metadata,
@@ -27,6 +97,52 @@ pub struct Plugin {
 
 impl Plugin {
     pub const LUA_PROPERTY_TASKS: &str = "tasks";
+
+    /// Iterates over tasks as `(task_key, task)` sorted by `order` (tasks without one last),
+    /// then by task key, the label of the task list (case-insensitive, ties broken by exact
+    /// key and name) for a stable order.
+    pub fn tasks_sorted(&self) -> impl Iterator<Item = (&str, &Arc<Task>)> {
+        let mut tasks: Vec<(&str, &Arc<Task>)> = self
+            .tasks
+            .iter()
+            .map(|(task_key, task)| (task_key.as_str(), task))
+            .collect();
+        tasks.sort_by(|(a_key, a), (b_key, b)| {
+            a.order
+                .is_none()
+                .cmp(&b.order.is_none())
+                .then(a.order.cmp(&b.order))
+                .then_with(|| a_key.to_lowercase().cmp(&b_key.to_lowercase()))
+                .then_with(|| a_key.cmp(b_key))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        tasks.into_iter()
+    }
+
+    /// Splits `tasks_sorted()` by `Task::group`, keeping the sorted order within each group.
+    ///
+    /// Ungrouped tasks come first under `None`, followed by the named groups in the order
+    /// their first task appears.
+    pub fn tasks_grouped(&self) -> Vec<TaskGroup<'_>> {
+        let mut ungrouped = Vec::new();
+        let mut groups: Vec<TaskGroup> = Vec::new();
+
+        for (task_key, task) in self.tasks_sorted() {
+            let Some(group) = task.group.as_deref() else {
+                ungrouped.push((task_key, task));
+                continue;
+            };
+            match groups.iter_mut().find(|(name, _)| *name == Some(group)) {
+                Some((_, tasks)) => tasks.push((task_key, task)),
+                None => groups.push((Some(group), vec![(task_key, task)])),
+            }
+        }
+
+        if !ungrouped.is_empty() {
+            groups.insert(0, (None, ungrouped));
+        }
+        groups
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -48,10 +164,17 @@ pub struct Task {
 
     pub description: String,
 
-    pub item_sources: Option<HashMap<String, ItemSource>>,
+    /// Shown before the task in the TUI task list, empty when the task declares none
+    pub icon: String,
+
+    /// Item sources in source key order, the order they are listed and executed in
+    pub item_sources: Option<IndexMap<String, ItemSource>>,
 
     pub mode: Mode,
 
+    /// The task declared `mode = "none"`, the deprecated name of [`Mode::Single`]
+    pub deprecated_mode_none: bool,
+
     pub preview_polling_interval: usize,
 
     pub item_polling_interval: usize,
@@ -59,6 +182,20 @@ pub struct Task {
     pub execution_confirmation_message: Option<String>,
 
     pub suppress_success_notification: bool,
+
+    pub continue_on_error: bool,
+
+    pub dedup: Dedup,
+
+    /// Decides the preselected items, over the `preselected_items` of the item sources
+    pub default_select: DefaultSelect,
+
+    pub order: Option<i64>,
+
+    pub group: Option<String>,
+
+    /// Alternative names for `--task`, the first one is shown in the TUI task list
+    pub aliases: Vec<String>,
 }
 
 impl Task {
@@ -74,11 +211,21 @@ pub struct ItemSource {
     pub item_source_key: String,
 
     pub tag: String,
+
+    /// Color of the `[tag]` prefix in the item list, a color name or hex value. Falls back
+    /// to the `tag` color of the theme
+    pub color: Option<String>,
+
+    /// Shell command whose stdout lines are the items, used instead of an `items` function
+    pub items_command: Option<String>,
 }
 
 impl ItemSource {
+    pub const LUA_FN_NAME_ENABLED: &str = "enabled";
     pub const LUA_FN_NAME_EXECUTE: &str = "execute";
     pub const LUA_FN_NAME_ITEMS: &str = "items";
     pub const LUA_FN_NAME_PRESELECTED_ITEMS: &str = "preselected_items";
     pub const LUA_FN_NAME_PREVIEW: &str = "preview";
+    pub const LUA_PROPERTY_COLOR: &str = "color";
+    pub const LUA_PROPERTY_ITEMS_COMMAND: &str = "items_command";
 }
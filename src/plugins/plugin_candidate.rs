@@ -1,11 +1,114 @@
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::{
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
 
-use mlua::{Lua, Table};
+use mlua::{ChunkMode, Lua, LuaOptions, StdLib, Table};
+
+/// A plugin.lua read from disk and compiled, ready to be evaluated
+///
+/// Preparing a file needs no shared state, so [`PreparedPlugin::prepare_all`] prepares all
+/// plugin files in parallel before the shared Lua VM evaluates them one at a time.
+#[derive(Debug)]
+pub struct PreparedPlugin {
+    /// Absolute path to plugin.lua file
+    pub path: PathBuf,
+
+    /// Bytecode of the compiled file, or why it could not be read or compiled
+    bytecode: Result<Vec<u8>>,
+
+    /// Time spent reading and compiling the file
+    pub prepare_time: Duration,
+}
+
+impl PreparedPlugin {
+    /// Reads and compiles every file of `paths` on a few threads, each with a Lua VM of its
+    /// own. The prepared plugins are returned in the order of `paths`.
+    pub fn prepare_all(paths: Vec<PathBuf>) -> Vec<Self> {
+        let workers = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(paths.len());
+        if workers <= 1 {
+            return prepare_batch(paths);
+        }
+
+        let batch_size = paths.len().div_ceil(workers);
+        let mut batches: Vec<Vec<PathBuf>> = Vec::new();
+        let mut paths = paths.into_iter().peekable();
+        while paths.peek().is_some() {
+            batches.push(paths.by_ref().take(batch_size).collect());
+        }
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = batches
+                .into_iter()
+                .map(|batch| scope.spawn(move || prepare_batch(batch)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
+    fn prepare(compiler: &Lua, path: PathBuf) -> Self {
+        let started = Instant::now();
+        let bytecode = compile_plugin_file(compiler, &path);
+        Self {
+            path,
+            bytecode,
+            prepare_time: started.elapsed(),
+        }
+    }
+}
+
+// Compiling only parses the files, a VM without standard libraries is enough
+fn prepare_batch(paths: Vec<PathBuf>) -> Vec<PreparedPlugin> {
+    match Lua::new_with(StdLib::NONE, LuaOptions::default()) {
+        Ok(compiler) => paths
+            .into_iter()
+            .map(|path| PreparedPlugin::prepare(&compiler, path))
+            .collect(),
+        Err(e) => paths
+            .into_iter()
+            .map(|path| PreparedPlugin {
+                path,
+                bytecode: Err(anyhow::Error::new(e.clone()).context("Failed to create Lua VM")),
+                prepare_time: Duration::ZERO,
+            })
+            .collect(),
+    }
+}
+
+fn compile_plugin_file(compiler: &Lua, path: &Path) -> Result<Vec<u8>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read plugin file '{}'", path.display()))?;
+
+    let chunk = compiler
+        .load(&contents)
+        .set_name(chunk_name(path)?)
+        .into_function()
+        .with_context(|| format!("Failed to evaluate plugin '{}'", path.display()))?;
+
+    // Debug info is kept for the line numbers in error messages
+    Ok(chunk.dump(false))
+}
+
+fn chunk_name(path: &Path) -> Result<&str> {
+    path.to_str()
+        .with_context(|| format!("Plugin path contains invalid UTF-8: {}", path.display()))
+}
 
 /// Represents a plugin file discovered during directory scanning
 ///
-/// Caches the plugin name and file contents to enable single-evaluation loading.
+/// Caches the plugin name and compiled file to enable single-evaluation loading.
 #[derive(Debug, Clone)]
 pub struct PluginCandidate {
     /// Absolute path to plugin.lua file
@@ -14,57 +117,62 @@ pub struct PluginCandidate {
     /// Plugin name extracted from metadata.name (cached from peek)
     pub name: String,
 
-    /// Cached file contents from disk read
+    /// Bytecode of the file, compiled while preparing
     ///
-    /// This is read once during peek and reused during load,
-    /// eliminating redundant file I/O.
-    pub cached_contents: String,
+    /// The file is read and compiled once and the bytecode is reused during load,
+    /// eliminating redundant file I/O and parsing.
+    pub bytecode: Vec<u8>,
+
+    /// Time spent preparing the file and evaluating it during peek
+    pub peek_time: Duration,
 }
 
 impl PluginCandidate {
-    /// Peek at a plugin file to extract its name and cache contents
+    /// Peek at a prepared plugin file to extract its name
     ///
-    /// **Important**: The returned candidate contains cached file contents
+    /// **Important**: The returned candidate contains the compiled file
     /// that will be evaluated only once during loading.
-    pub fn peek(lua_runtime: &Lua, path: PathBuf) -> Result<Self> {
-        let cached_contents = std::fs::read_to_string(&path)
-            .with_context(|| format!("Failed to read plugin file '{}'", path.display()))?;
-
-        let plugin_table: Table = lua_runtime
-            .load(&cached_contents)
-            .set_name(path.to_str().with_context(|| {
-                format!("Plugin path contains invalid UTF-8: {}", path.display())
-            })?)
-            .eval()
-            .with_context(|| format!("Failed to evaluate plugin '{}'", path.display()))?;
+    pub fn peek(lua_runtime: &Lua, prepared: PreparedPlugin) -> Result<Self> {
+        let started = Instant::now();
+        let bytecode = prepared.bytecode?;
+        let candidate = Self {
+            path: prepared.path,
+            name: String::new(),
+            bytecode,
+            peek_time: Duration::ZERO,
+        };
+
+        let plugin_table = candidate.evaluate(lua_runtime)?;
 
-        let metadata_table: Table = plugin_table
-            .get("metadata")
-            .with_context(|| format!("Plugin '{}' missing 'metadata' table", path.display()))?;
+        let metadata_table: Table = plugin_table.get("metadata").with_context(|| {
+            format!(
+                "Plugin '{}' missing 'metadata' table",
+                candidate.path.display()
+            )
+        })?;
 
-        let name: String = metadata_table
-            .get("name")
-            .with_context(|| format!("Plugin '{}' missing 'name' in metadata", path.display()))?;
+        let name: String = metadata_table.get("name").with_context(|| {
+            format!(
+                "Plugin '{}' missing 'name' in metadata",
+                candidate.path.display()
+            )
+        })?;
 
         Ok(Self {
-            path,
             name,
-            cached_contents,
+            peek_time: prepared.prepare_time + started.elapsed(),
+            ..candidate
         })
     }
 
-    /// Evaluate the cached contents into a Lua table
+    /// Evaluate the compiled file into a Lua table
     ///
     /// This is the second (and final) evaluation of the plugin file.
     pub fn evaluate(&self, lua: &Lua) -> Result<Table> {
         let plugin_table: Table = lua
-            .load(&self.cached_contents)
-            .set_name(self.path.to_str().with_context(|| {
-                format!(
-                    "Plugin path contains invalid UTF-8: {}",
-                    self.path.display()
-                )
-            })?)
+            .load(&self.bytecode)
+            .set_name(chunk_name(&self.path)?)
+            .set_mode(ChunkMode::Binary)
             .eval()
             .with_context(|| format!("Failed to evaluate plugin '{}'", self.path.display()))?;
 
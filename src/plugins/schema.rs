@@ -0,0 +1,258 @@
+use serde_json::{Map, Value, json};
+
+use crate::plugins::{Dedup, DefaultSelect, ItemSource, Mode, Task, loader::VALID_PLATFORMS};
+
+const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// JSON Schema of the table returned by `plugin.lua`, printed by `syntropy schema`.
+///
+/// Plugins are read from Lua tables rather than deserialized, so the schema is written out
+/// here next to the loader's rules, with the enum values taken from [`Mode`], [`Dedup`],
+/// [`DefaultSelect`] and the valid platforms. Lua functions have no JSON type, their fields only describe what
+/// the function does. Unknown fields are allowed, the loader ignores them.
+pub fn plugin_schema() -> Value {
+    json!({
+        "$schema": JSON_SCHEMA_DRAFT,
+        "title": "Syntropy plugin",
+        "description": "Table returned by a plugin's plugin.lua",
+        "type": "object",
+        "required": ["metadata", "tasks"],
+        "properties": {
+            "metadata": metadata_schema(),
+            "tasks": {
+                "description": "Tasks of the plugin, keyed by task key",
+                "type": "object",
+                "minProperties": 1,
+                "additionalProperties": task_schema(),
+            },
+        },
+    })
+}
+
+fn metadata_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["name", "version"],
+        "properties": {
+            "name": {
+                "description": "Plugin name, matching its directory",
+                "type": "string",
+                "minLength": 1,
+            },
+            "version": {
+                "description": "Semantic version, e.g. 1.0.0",
+                "type": "string",
+                "minLength": 1,
+            },
+            "icon": {
+                "description": "Single terminal cell shown before the plugin name",
+                "type": "string",
+            },
+            "description": {
+                "type": "string",
+            },
+            "platforms": {
+                "description": "Platforms the plugin runs on, all when omitted",
+                "type": "array",
+                "items": { "enum": VALID_PLATFORMS },
+            },
+        },
+    })
+}
+
+fn task_schema() -> Value {
+    let mut properties = Map::new();
+    properties.insert(
+        "name".into(),
+        json!({ "description": "Display name, defaults to the task key", "type": "string" }),
+    );
+    properties.insert(
+        "description".into(),
+        json!({
+            "description": "Shown in the preview pane, its first line under the task name",
+            "type": "string",
+            "minLength": 1,
+        }),
+    );
+    properties.insert(
+        "icon".into(),
+        json!({
+            "description": "Single terminal cell shown before the task in the task list",
+            "type": "string",
+        }),
+    );
+    properties.insert(
+        "mode".into(),
+        json!({
+            "description": "Selection mode, defaults to the config's default_task_mode",
+            "enum": Mode::ALL.map(|mode| mode.to_string()),
+        }),
+    );
+    properties.insert(
+        "execution_confirmation_message".into(),
+        json!({
+            "description": "Asks for confirmation with this message before executing",
+            "type": "string",
+        }),
+    );
+    properties.insert(
+        "suppress_success_notification".into(),
+        json!({ "type": "boolean", "default": false }),
+    );
+    properties.insert(
+        "continue_on_error".into(),
+        json!({
+            "description": "Run the remaining item sources when one fails",
+            "type": "boolean",
+            "default": true,
+        }),
+    );
+    properties.insert(
+        "dedup".into(),
+        json!({
+            "enum": Dedup::ALL.map(|dedup| dedup.to_string()),
+            "default": Dedup::default().to_string(),
+        }),
+    );
+    properties.insert(
+        "default_select".into(),
+        json!({
+            "description": "Items of a multi task marked on start, over preselected_items",
+            "enum": DefaultSelect::ALL.map(|default_select| default_select.to_string()),
+            "default": DefaultSelect::default().to_string(),
+        }),
+    );
+    properties.insert(
+        "order".into(),
+        json!({ "description": "Position in the task list", "type": "integer" }),
+    );
+    properties.insert(
+        "group".into(),
+        json!({
+            "description": "Header the task is listed under",
+            "type": "string",
+            "minLength": 1,
+        }),
+    );
+    properties.insert(
+        "aliases".into(),
+        json!({
+            "description": "Alternative names for execute --task",
+            "type": "array",
+            "items": { "type": "string", "minLength": 1 },
+        }),
+    );
+    properties.insert(
+        "item_polling_interval".into(),
+        json!({
+            "description": "Milliseconds between item refreshes, 0 disables polling",
+            "type": "integer",
+            "minimum": 0,
+            "default": 0,
+        }),
+    );
+    properties.insert(
+        "preview_polling_interval".into(),
+        json!({
+            "description": "Milliseconds between preview refreshes, 0 disables polling",
+            "type": "integer",
+            "minimum": 0,
+            "default": 0,
+        }),
+    );
+    properties.insert(
+        Task::LUA_PROPERTY_ITEM_SOURCES.into(),
+        json!({
+            "description": "Sources of the items, keyed by source key",
+            "type": "object",
+            "minProperties": 1,
+            "additionalProperties": item_source_schema(),
+        }),
+    );
+    for (name, description) in [
+        (
+            Task::LUA_FN_NAME_PRE_RUN,
+            "Runs before the items are fetched",
+        ),
+        (Task::LUA_FN_NAME_POST_RUN, "Runs after the task executed"),
+        (
+            Task::LUA_FN_NAME_EXECUTE,
+            "Executes the task, fallback of item sources without one",
+        ),
+        (
+            Task::LUA_FN_NAME_PREVIEW,
+            "Returns the preview of an item, a string or {content, syntax}",
+        ),
+    ] {
+        properties.insert(name.into(), lua_function(description));
+    }
+
+    json!({
+        "type": "object",
+        "required": ["description"],
+        "anyOf": [
+            { "required": [Task::LUA_FN_NAME_EXECUTE] },
+            { "required": [Task::LUA_PROPERTY_ITEM_SOURCES] },
+        ],
+        "properties": properties,
+    })
+}
+
+fn item_source_schema() -> Value {
+    let mut properties = Map::new();
+    properties.insert(
+        "tag".into(),
+        json!({
+            "description": "Short prefix telling the items of several sources apart",
+            "type": "string",
+        }),
+    );
+    properties.insert(
+        ItemSource::LUA_PROPERTY_COLOR.into(),
+        json!({ "description": "Color of the tag, a color name or #rrggbb", "type": "string" }),
+    );
+    properties.insert(
+        ItemSource::LUA_PROPERTY_ITEMS_COMMAND.into(),
+        json!({
+            "description": "Shell command printing one item per line",
+            "type": "string",
+            "minLength": 1,
+        }),
+    );
+    for (name, description) in [
+        (ItemSource::LUA_FN_NAME_ITEMS, "Returns the items"),
+        (
+            ItemSource::LUA_FN_NAME_ENABLED,
+            "Returns false to hide the source",
+        ),
+        (
+            ItemSource::LUA_FN_NAME_PRESELECTED_ITEMS,
+            "Returns the items marked on start",
+        ),
+        (
+            ItemSource::LUA_FN_NAME_PREVIEW,
+            "Returns the preview of an item, a string or {content, syntax}",
+        ),
+        (
+            ItemSource::LUA_FN_NAME_EXECUTE,
+            "Executes the selected items",
+        ),
+    ] {
+        properties.insert(name.into(), lua_function(description));
+    }
+
+    json!({
+        "type": "object",
+        "required": ["tag"],
+        "oneOf": [
+            { "required": [ItemSource::LUA_FN_NAME_ITEMS] },
+            { "required": [ItemSource::LUA_PROPERTY_ITEMS_COMMAND] },
+        ],
+        "properties": properties,
+    })
+}
+
+// Functions can't be typed in JSON, the description tells editors what is expected
+fn lua_function(description: &str) -> Value {
+    json!({ "description": format!("Lua function. {}", description) })
+}
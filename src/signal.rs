@@ -1,11 +1,31 @@
-//! Signal handling for graceful shutdown on SIGINT (Ctrl+C)
+//! Signal handling for graceful shutdown on SIGINT (Ctrl+C) and SIGTERM
 //!
 //! This module provides a shared cancellation state that allows the signal handler
 //! to communicate with the execution pipeline. The atomic state ensures thread-safe
 //! cancellation signaling.
+//!
+//! Shell commands spawned by plugins are tracked as well, so they can be terminated
+//! instead of being left running as orphans when syntropy is interrupted or quits.
 
-use std::sync::Arc;
 use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Time tracked child processes get to exit after SIGTERM before they are killed
+pub const CHILD_TERMINATION_GRACE: Duration = Duration::from_secs(2);
+
+/// How often `terminate_children` checks whether the children exited
+const CHILD_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The running tracked children
+static CHILD_PROCESSES: Mutex<Vec<ChildProcess>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChildProcess {
+    pid: u32,
+    /// Whether the child leads its own process group, signals then reach the whole group
+    group: bool,
+}
 
 /// Cancellation state shared across signal handler and execution
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -62,6 +82,141 @@ impl Default for Cancellation {
     }
 }
 
+/// Registration of a running child process, unregistered when dropped
+///
+/// Keep it alive until the child has been waited for, otherwise its pid could be
+/// reused by an unrelated process before it is unregistered. Dropping it before
+/// `exited` was called, e.g. because the task waiting for the child was aborted,
+/// terminates the child.
+#[derive(Debug)]
+pub struct TrackedChild {
+    child: Option<ChildProcess>,
+    exited: bool,
+}
+
+impl TrackedChild {
+    /// Marks the child as waited for, dropping the registration then only unregisters it
+    pub fn exited(&mut self) {
+        self.exited = true;
+    }
+}
+
+impl Drop for TrackedChild {
+    fn drop(&mut self) {
+        if let Some(child) = self.child {
+            let mut children = child_processes();
+            if let Some(index) = children.iter().position(|&tracked| tracked == child) {
+                children.swap_remove(index);
+            }
+            drop(children);
+            if !self.exited {
+                send_signal(child, ChildSignal::Terminate);
+            }
+        }
+    }
+}
+
+/// Registers the child process `pid` so `terminate_children` can reach it
+///
+/// `pid` is `None` for children that already exited, as returned by
+/// `tokio::process::Child::id`.
+pub fn track_child(pid: Option<u32>) -> TrackedChild {
+    track(pid, false)
+}
+
+/// Like `track_child`, for a child spawned as the leader of its own process group
+///
+/// Signals reach the whole group, so processes the child started are terminated with it.
+pub fn track_child_group(pid: Option<u32>) -> TrackedChild {
+    track(pid, true)
+}
+
+fn track(pid: Option<u32>, group: bool) -> TrackedChild {
+    let child = pid.map(|pid| ChildProcess { pid, group });
+    if let Some(child) = child {
+        child_processes().push(child);
+    }
+    TrackedChild {
+        child,
+        exited: false,
+    }
+}
+
+/// Number of tracked children that are still running
+pub fn running_children() -> usize {
+    child_processes().len()
+}
+
+/// Sends SIGTERM to every tracked child, then SIGKILL to those still running after `grace`
+///
+/// Returns once every child exited and was unregistered, or shortly after the SIGKILL.
+pub async fn terminate_children(grace: Duration) {
+    let children = child_processes().clone();
+    if children.is_empty() {
+        return;
+    }
+    for &child in &children {
+        send_signal(child, ChildSignal::Terminate);
+    }
+
+    let deadline = Instant::now() + grace;
+    while Instant::now() < deadline {
+        if !children
+            .iter()
+            .any(|child| child_processes().contains(child))
+        {
+            return;
+        }
+        tokio::time::sleep(CHILD_POLL_INTERVAL).await;
+    }
+
+    // Only children that were running before the grace period are killed
+    let remaining: Vec<ChildProcess> = child_processes()
+        .iter()
+        .copied()
+        .filter(|child| children.contains(child))
+        .collect();
+    for child in remaining {
+        send_signal(child, ChildSignal::Kill);
+    }
+    // Gives the owners a moment to reap the killed children
+    tokio::time::sleep(CHILD_POLL_INTERVAL).await;
+}
+
+fn child_processes() -> std::sync::MutexGuard<'static, Vec<ChildProcess>> {
+    // A panic while holding the lock can't leave the list inconsistent
+    CHILD_PROCESSES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[derive(Clone, Copy)]
+enum ChildSignal {
+    Terminate,
+    Kill,
+}
+
+#[cfg(unix)]
+fn send_signal(child: ChildProcess, child_signal: ChildSignal) {
+    use nix::sys::signal::{Signal, kill, killpg};
+    use nix::unistd::Pid;
+
+    let signal = match child_signal {
+        ChildSignal::Terminate => Signal::SIGTERM,
+        ChildSignal::Kill => Signal::SIGKILL,
+    };
+    let pid = Pid::from_raw(child.pid as i32);
+    // The child may have exited in the meantime, which is what we want anyway
+    if child.group {
+        let _ = killpg(pid, signal);
+    } else {
+        let _ = kill(pid, signal);
+    }
+}
+
+#[cfg(not(unix))]
+fn send_signal(_child: ChildProcess, _child_signal: ChildSignal) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +245,61 @@ mod tests {
         cancel1.request_cancel();
         assert!(cancel2.is_cancelled());
     }
+
+    #[test]
+    fn test_tracked_child_unregisters_on_drop() {
+        let pid = u32::MAX;
+        let is_tracked = || child_processes().iter().any(|child| child.pid == pid);
+        let mut tracked = track_child(Some(pid));
+        assert!(is_tracked());
+
+        tracked.exited();
+        drop(tracked);
+        assert!(!is_tracked());
+    }
+
+    #[test]
+    fn test_exited_child_is_not_tracked() {
+        let before = running_children();
+        let _tracked = track_child(None);
+        assert_eq!(running_children(), before);
+    }
+
+    #[cfg(target_os = "linux")]
+    fn is_running(pid: u32) -> bool {
+        // Zombies wait for their parent to reap them, they no longer run
+        std::fs::read_to_string(format!("/proc/{}/stat", pid))
+            .is_ok_and(|stat| !stat.rsplit(')').next().unwrap_or("").starts_with(" Z"))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_dropping_tracked_group_before_exit_terminates_the_group() {
+        use std::io::{BufRead, BufReader};
+        use std::os::unix::process::CommandExt;
+        use std::process::{Command, Stdio};
+
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg("sleep 30 & echo $!; wait")
+            .stdout(Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let grandchild: u32 = line.trim().parse().unwrap();
+        assert!(is_running(grandchild));
+
+        drop(track_child_group(Some(child.id())));
+        child.wait().unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while is_running(grandchild) && Instant::now() < deadline {
+            std::thread::sleep(CHILD_POLL_INTERVAL);
+        }
+        assert!(!is_running(grandchild));
+    }
 }
@@ -0,0 +1,58 @@
+use std::env;
+
+use ratatui::style::Color;
+
+/// Terminals (`$TERM`) limited to the 16 named colors, RGB colors are dropped on them
+const BASIC_TERMS: [&str; 5] = ["ansi", "linux", "vt100", "vt102", "vt220"];
+
+/// Colors the terminal can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Named and RGB colors
+    TrueColor,
+    /// Named colors only
+    Basic,
+    /// No colors at all, the terminal's own foreground and background
+    Monochrome,
+}
+
+impl ColorMode {
+    /// `color` as this mode renders it, colors it can't show fall back to the terminal default
+    pub fn apply(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorMode::TrueColor, color) => color,
+            (ColorMode::Basic, Color::Rgb(..) | Color::Indexed(_)) => Color::Reset,
+            (ColorMode::Basic, color) => color,
+            (ColorMode::Monochrome, _) => Color::Reset,
+        }
+    }
+}
+
+/// Decides the color mode from the values of `$NO_COLOR` and `$TERM`
+///
+/// A non-empty `NO_COLOR` (see no-color.org) and `TERM=dumb` turn colors off, the terminals
+/// in [`BASIC_TERMS`] get named colors only. `force_color` overrides the detection.
+pub fn detect_color_mode(
+    no_color: Option<&str>,
+    term: Option<&str>,
+    force_color: bool,
+) -> ColorMode {
+    if force_color {
+        return ColorMode::TrueColor;
+    }
+    if no_color.is_some_and(|value| !value.is_empty()) {
+        return ColorMode::Monochrome;
+    }
+    match term {
+        Some("dumb") => ColorMode::Monochrome,
+        Some(term) if BASIC_TERMS.contains(&term) => ColorMode::Basic,
+        _ => ColorMode::TrueColor,
+    }
+}
+
+/// [`detect_color_mode`] for the environment of this process
+pub fn color_mode_from_env(force_color: bool) -> ColorMode {
+    let no_color = env::var("NO_COLOR").ok();
+    let term = env::var("TERM").ok();
+    detect_color_mode(no_color.as_deref(), term.as_deref(), force_color)
+}
@@ -1,11 +1,21 @@
+use crossterm::event::MouseEvent;
+use mlua::Lua;
 use ratatui::{Frame, layout::Rect};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+use tokio::{runtime::Handle as RuntimeHandle, sync::Mutex};
 
 use crate::{
     app::App,
+    execution::{EXIT_FAILURE, ExecutionResult, Handle, JobRegistry, Operation},
+    plugins::{Mode, Task},
     tui::{
         events::InputEvent,
+        fuzzy_searcher::SearchOptions,
         navigation::{Intent, Route},
-        screens::{ItemListScreen, PluginListScreen, Screen, Status, TaskListScreen},
+        screens::{
+            ItemListScreen, JobListScreen, PluginListScreen, Screen, Status, TaskListScreen,
+        },
+        strings::{JobStrings, QuickActionStrings},
         views::Styles,
     },
 };
@@ -14,14 +24,123 @@ pub struct ScreenDispatcher {
     pub plugin_screen: PluginListScreen,
     pub task_screen: TaskListScreen,
     pub item_screen: ItemListScreen,
+    pub job_screen: JobListScreen,
+    pub quick_actions: QuickActionRunner,
+}
+
+/// Runs the tasks of `keybindings.quick_actions`, whatever screen is shown
+pub struct QuickActionRunner {
+    execution_handle: Handle,
+    job_registry: Rc<RefCell<JobRegistry>>,
+    running_task: Option<Arc<Task>>,
+}
+
+impl QuickActionRunner {
+    pub fn new(
+        runtime_handle: RuntimeHandle,
+        lua_runtime: &Arc<Mutex<Lua>>,
+        job_registry: &Rc<RefCell<JobRegistry>>,
+    ) -> Self {
+        Self {
+            execution_handle: Handle::new(runtime_handle, lua_runtime),
+            job_registry: Rc::clone(job_registry),
+            running_task: None,
+        }
+    }
+
+    // Background tasks become jobs on the given items, others fetch their items first
+    fn run(&mut self, task: &Arc<Task>, items: &[String]) -> Intent {
+        if task.mode == Mode::Background {
+            return match self
+                .job_registry
+                .borrow_mut()
+                .spawn(Arc::clone(task), items.to_vec())
+            {
+                Ok(_) if task.suppress_success_notification => Intent::None,
+                Ok(job_id) => Intent::ShowToast {
+                    message: format!("{} #{}", JobStrings::STARTED, job_id),
+                    exit_code: None,
+                },
+                Err(e) => Intent::ShowToast {
+                    message: format!("{:#}", e),
+                    exit_code: Some(EXIT_FAILURE),
+                },
+            };
+        }
+
+        if self.execution_handle.is_executing() {
+            return Intent::ShowToast {
+                message: QuickActionStrings::ALREADY_RUNNING.to_string(),
+                exit_code: None,
+            };
+        }
+        match self.execution_handle.execute(Operation::Run {
+            task: Arc::clone(task),
+            items: items.to_vec(),
+        }) {
+            Ok(()) => {
+                self.running_task = Some(Arc::clone(task));
+                Intent::ShowToast {
+                    message: format!("{} {}", QuickActionStrings::RUNNING, task.name),
+                    exit_code: None,
+                }
+            }
+            Err(e) => Intent::ShowToast {
+                message: format!("{:#}", e),
+                exit_code: Some(EXIT_FAILURE),
+            },
+        }
+    }
+
+    fn on_update(&mut self, app: &App) -> Intent {
+        let result = self.execution_handle.consume_result();
+        if result == ExecutionResult::None {
+            return Intent::None;
+        }
+        let task = self.running_task.take();
+
+        match result {
+            _ if app.config.exit_on_execute => Intent::Quit,
+            ExecutionResult::Output(_, 0, _)
+                if task.is_some_and(|task| task.suppress_success_notification) =>
+            {
+                Intent::None
+            }
+            ExecutionResult::Output(output, exit_code, _) => Intent::ShowToast {
+                message: output,
+                exit_code: Some(exit_code),
+            },
+            ExecutionResult::Error(output) => Intent::ShowToast {
+                message: output,
+                exit_code: Some(EXIT_FAILURE),
+            },
+            _ => Intent::None,
+        }
+    }
 }
 
 impl ScreenDispatcher {
+    /// Runs the quick action at `index` of `keybindings.quick_actions`, a plugin or task that
+    /// doesn't exist is reported in a toast
+    pub fn run_quick_action(&mut self, index: usize, app: &App) -> Intent {
+        let Some(quick_action) = app.config.keybindings.quick_actions.get(index) else {
+            return Intent::None;
+        };
+        match app.find_task(&quick_action.plugin, &quick_action.task) {
+            Ok((_, _, task)) => self.quick_actions.run(task, &quick_action.items),
+            Err(e) => Intent::ShowToast {
+                message: format!("{:#}", e),
+                exit_code: Some(EXIT_FAILURE),
+            },
+        }
+    }
+
     pub fn on_enter(&mut self, route: &Route, app: &App) {
         match route {
             Route::Plugin { payload } => self.plugin_screen.on_enter(app, payload),
             Route::Task { payload } => self.task_screen.on_enter(app, payload),
             Route::Item { payload } => self.item_screen.on_enter(app, payload),
+            Route::Jobs { payload } => self.job_screen.on_enter(app, payload),
         }
     }
 
@@ -30,6 +149,7 @@ impl ScreenDispatcher {
             Route::Plugin { .. } => self.plugin_screen.on_exit(),
             Route::Task { .. } => self.task_screen.on_exit(),
             Route::Item { .. } => self.item_screen.on_exit(),
+            Route::Jobs { .. } => self.job_screen.on_exit(),
         }
     }
 
@@ -38,6 +158,16 @@ impl ScreenDispatcher {
             Route::Plugin { payload } => self.plugin_screen.handle_event(event, app, payload),
             Route::Task { payload } => self.task_screen.handle_event(event, app, payload),
             Route::Item { payload } => self.item_screen.handle_event(event, app, payload),
+            Route::Jobs { payload } => self.job_screen.handle_event(event, app, payload),
+        }
+    }
+
+    pub fn handle_mouse(&mut self, route: &Route, event: MouseEvent, app: &App) -> Intent {
+        match route {
+            Route::Plugin { payload } => self.plugin_screen.handle_mouse(event, app, payload),
+            Route::Task { payload } => self.task_screen.handle_mouse(event, app, payload),
+            Route::Item { payload } => self.item_screen.handle_mouse(event, app, payload),
+            Route::Jobs { payload } => self.job_screen.handle_mouse(event, app, payload),
         }
     }
 
@@ -46,14 +176,21 @@ impl ScreenDispatcher {
             Route::Plugin { .. } => self.plugin_screen.render(frame, rect, styles),
             Route::Task { .. } => self.task_screen.render(frame, rect, styles),
             Route::Item { .. } => self.item_screen.render(frame, rect, styles),
+            Route::Jobs { .. } => self.job_screen.render(frame, rect, styles),
         }
     }
 
     pub fn update(&mut self, route: &Route, app: &App) -> Intent {
-        match route {
+        let intent = match route {
             Route::Plugin { payload } => self.plugin_screen.on_update(app, payload),
             Route::Task { payload } => self.task_screen.on_update(app, payload),
             Route::Item { payload } => self.item_screen.on_update(app, payload),
+            Route::Jobs { payload } => self.job_screen.on_update(app, payload),
+        };
+        // A quick action result waits for the next update when the screen has one
+        match intent {
+            Intent::None => self.quick_actions.on_update(app),
+            intent => intent,
         }
     }
 
@@ -62,14 +199,34 @@ impl ScreenDispatcher {
             Route::Plugin { .. } => self.plugin_screen.get_status(),
             Route::Task { .. } => self.task_screen.get_status(),
             Route::Item { .. } => self.item_screen.get_status(),
+            Route::Jobs { .. } => self.job_screen.get_status(),
+        }
+    }
+
+    pub fn get_status_indicator(&self, route: &Route) -> Option<&str> {
+        match route {
+            Route::Plugin { .. } => self.plugin_screen.get_status_indicator(),
+            Route::Task { .. } => self.task_screen.get_status_indicator(),
+            Route::Item { .. } => self.item_screen.get_status_indicator(),
+            Route::Jobs { .. } => self.job_screen.get_status_indicator(),
+        }
+    }
+
+    pub fn on_search(&mut self, route: &Route, query: &str, options: SearchOptions) {
+        match route {
+            Route::Plugin { .. } => self.plugin_screen.on_search(query, options),
+            Route::Task { .. } => self.task_screen.on_search(query, options),
+            Route::Item { .. } => self.item_screen.on_search(query, options),
+            Route::Jobs { .. } => self.job_screen.on_search(query, options),
         }
     }
 
-    pub fn on_search(&mut self, route: &Route, query: &str) {
+    pub fn confirmation_shown(&self, route: &Route) -> bool {
         match route {
-            Route::Plugin { .. } => self.plugin_screen.on_search(query),
-            Route::Task { .. } => self.task_screen.on_search(query),
-            Route::Item { .. } => self.item_screen.on_search(query),
+            Route::Plugin { .. } => self.plugin_screen.confirmation_shown(),
+            Route::Task { .. } => self.task_screen.confirmation_shown(),
+            Route::Item { .. } => self.item_screen.confirmation_shown(),
+            Route::Jobs { .. } => self.job_screen.confirmation_shown(),
         }
     }
 
@@ -78,6 +235,7 @@ impl ScreenDispatcher {
             Route::Plugin { .. } => self.plugin_screen.consumed_event(event),
             Route::Task { .. } => self.task_screen.consumed_event(event),
             Route::Item { .. } => self.item_screen.consumed_event(event),
+            Route::Jobs { .. } => self.job_screen.consumed_event(event),
         }
     }
 }
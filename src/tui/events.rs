@@ -1,4 +1,4 @@
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::tui::key_bindings::ParsedKeyBindings;
 
@@ -7,23 +7,59 @@ pub enum InputEvent {
     Back,
     PreviousItem,
     NextItem,
+    /// Moves the cursor up or down by the height of the visible list
+    PageUp,
+    PageDown,
+    /// Recalls the previous or next query of the search history into the search bar
+    HistoryPrevious,
+    HistoryNext,
     ScrollPreviewUp,
     ScrollPreviewDown,
+    /// Pans the preview while it doesn't wrap lines
+    ScrollPreviewLeft,
+    ScrollPreviewRight,
     TogglePreview,
+    /// Switches the preview between wrapping long lines and panning them
+    TogglePreviewWrap,
     Confirm,
     Select,
+    ShowJobs,
+    KillJob,
+    RestartJob,
+    SortItems,
+    DumpPlan,
+    GoHome,
+    ToggleSearchMode,
+    ToggleCaseSensitive,
+    Copy,
+    CopyPreview,
+    SelectAll,
+    SelectNone,
+    InvertSelection,
+    /// Switches the plugin list between all plugins and the ones for the current platform
+    CyclePluginFilter,
+    /// Runs the quick action at this index of `keybindings.quick_actions`
+    QuickAction(usize),
 }
 
+/// Answers a yes/no dialog with yes, next to the confirm keybinding
+pub const KEY_YES: char = 'y';
+/// Answers a yes/no dialog with no, next to the back keybinding
+pub const KEY_NO: char = 'n';
+
 pub fn handle_key(key: &KeyEvent, bindings: &ParsedKeyBindings) -> Option<InputEvent> {
-    match () {
-        _ if bindings.back.matches(key) => Some(InputEvent::Back),
-        _ if bindings.select_previous.matches(key) => Some(InputEvent::PreviousItem),
-        _ if bindings.select_next.matches(key) => Some(InputEvent::NextItem),
-        _ if bindings.scroll_preview_up.matches(key) => Some(InputEvent::ScrollPreviewUp),
-        _ if bindings.scroll_preview_down.matches(key) => Some(InputEvent::ScrollPreviewDown),
-        _ if bindings.toggle_preview.matches(key) => Some(InputEvent::TogglePreview),
-        _ if bindings.confirm.matches(key) => Some(InputEvent::Confirm),
-        _ if bindings.select.matches(key) => Some(InputEvent::Select),
+    bindings.action(key)
+}
+
+/// [`InputEvent::Confirm`] for [`KEY_YES`] and [`InputEvent::Back`] for [`KEY_NO`], in either
+/// case and without modifiers. Only meant for keys pressed while a yes/no dialog is open.
+pub fn handle_yes_no_key(key: &KeyEvent) -> Option<InputEvent> {
+    if !(key.modifiers - KeyModifiers::SHIFT).is_empty() {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char(c) if c.eq_ignore_ascii_case(&KEY_YES) => Some(InputEvent::Confirm),
+        KeyCode::Char(c) if c.eq_ignore_ascii_case(&KEY_NO) => Some(InputEvent::Back),
         _ => None,
     }
 }
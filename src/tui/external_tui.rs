@@ -1,16 +1,70 @@
 use anyhow::Result;
+use std::fmt;
 use std::process::Stdio;
 use std::sync::OnceLock;
 use tokio::sync::oneshot;
 
 use crate::execution::clamp_exit_code;
 
-/// Request to run an external TUI application with full terminal control
+/// Request from Lua that needs the TUI main loop to take over the terminal
 #[derive(Debug)]
-pub struct ExternalTuiRequest {
-    pub command: String,
-    pub args: Vec<String>,
-    pub response: oneshot::Sender<i32>,
+pub enum ExternalTuiRequest {
+    /// Run an external TUI application with full terminal control
+    Command {
+        command: String,
+        args: Vec<String>,
+        response: oneshot::Sender<i32>,
+    },
+    /// Ask the user a yes/no question in a modal dialog
+    Confirm {
+        message: String,
+        response: oneshot::Sender<bool>,
+    },
+    /// Ask the user for a line of text in a modal dialog
+    Prompt {
+        message: String,
+        default: String,
+        response: oneshot::Sender<String>,
+    },
+    /// Show a message of `syntropy.log()` in the status bar
+    Log {
+        level: PluginLogLevel,
+        message: String,
+    },
+}
+
+/// Level of a message logged by a plugin with `syntropy.log()`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum PluginLogLevel {
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl PluginLogLevel {
+    pub const ALL: [PluginLogLevel; 3] = [
+        PluginLogLevel::Info,
+        PluginLogLevel::Warn,
+        PluginLogLevel::Error,
+    ];
+
+    /// The level named `name`, as printed by its `Display`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.to_string() == name)
+    }
+}
+
+impl fmt::Display for PluginLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginLogLevel::Info => write!(f, "info"),
+            PluginLogLevel::Warn => write!(f, "warn"),
+            PluginLogLevel::Error => write!(f, "error"),
+        }
+    }
 }
 
 pub type TuiRequestSender = tokio::sync::mpsc::UnboundedSender<ExternalTuiRequest>;
@@ -1,11 +1,87 @@
 use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+use regex::{Regex, RegexBuilder};
+
+/// How the search query is matched against items, cycled with `toggle_search_mode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMode {
+    /// Ranked fuzzy matching
+    #[default]
+    Fuzzy,
+    /// Items starting with the query, smart case, in their original order
+    Prefix,
+    /// Items matching the query as a regular expression, in their original order
+    Regex,
+}
+
+impl SearchMode {
+    pub fn next(self) -> Self {
+        match self {
+            SearchMode::Fuzzy => SearchMode::Prefix,
+            SearchMode::Prefix => SearchMode::Regex,
+            SearchMode::Regex => SearchMode::Fuzzy,
+        }
+    }
+
+    /// Marker shown in front of the query in the search bar
+    pub fn indicator(self) -> &'static str {
+        match self {
+            SearchMode::Fuzzy => "[~]",
+            SearchMode::Prefix => "[^]",
+            SearchMode::Regex => "[/]",
+        }
+    }
+
+    /// Whether `query` can be searched with, only regex queries can be invalid
+    pub fn is_valid_query(self, query: &str) -> bool {
+        self != SearchMode::Regex || Regex::new(query).is_ok()
+    }
+}
+
+/// Search bar state a screen searches with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchOptions {
+    pub mode: SearchMode,
+    /// Match case exactly. Otherwise fuzzy and prefix queries only match case when they
+    /// contain uppercase letters, and regex queries ignore case.
+    pub case_sensitive: bool,
+}
 
 #[derive(Default)]
 pub struct FuzzySearcher {
     matcher: SkimMatcherV2,
+    mode: SearchMode,
+    case_sensitive: bool,
 }
 
 impl FuzzySearcher {
+    pub fn mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: SearchMode) {
+        self.mode = mode;
+    }
+
+    pub fn set_case_sensitive(&mut self, case_sensitive: bool) {
+        if self.case_sensitive != case_sensitive {
+            self.case_sensitive = case_sensitive;
+            self.matcher = if case_sensitive {
+                SkimMatcherV2::default().respect_case()
+            } else {
+                SkimMatcherV2::default()
+            };
+        }
+    }
+
+    pub fn set_options(&mut self, options: SearchOptions) {
+        self.set_mode(options.mode);
+        self.set_case_sensitive(options.case_sensitive);
+    }
+
+    /// Indices of the items matching `query`, every item for an empty query.
+    ///
+    /// Fuzzy results are ranked by score, prefix and regex results keep the item order. An
+    /// invalid regex matches nothing.
     pub fn search<T>(&self, items: &[T], query: &str) -> Vec<usize>
     where
         T: std::ops::Deref,
@@ -15,6 +91,31 @@ impl FuzzySearcher {
             return (0..items.len()).collect();
         }
 
+        match self.mode {
+            SearchMode::Fuzzy => {}
+            SearchMode::Prefix => {
+                return items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| {
+                        prefix_len(item.deref().as_ref(), query, self.case_sensitive).is_some()
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect();
+            }
+            SearchMode::Regex => {
+                let Ok(regex) = self.regex(query) else {
+                    return Vec::new();
+                };
+                return items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| regex.is_match(item.deref().as_ref()))
+                    .map(|(idx, _)| idx)
+                    .collect();
+            }
+        }
+
         let mut matches: Vec<_> = items
             .iter()
             .enumerate()
@@ -30,4 +131,76 @@ impl FuzzySearcher {
 
         matches.into_iter().map(|(idx, _)| idx).collect()
     }
+
+    /// Character positions of `item` matched by `query`, empty for an empty query or no match
+    pub fn match_positions(&self, item: &str, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        match self.mode {
+            SearchMode::Fuzzy => self
+                .matcher
+                .fuzzy_indices(item, query)
+                .map(|(_score, positions)| positions)
+                .unwrap_or_default(),
+            SearchMode::Prefix => prefix_len(item, query, self.case_sensitive)
+                .map(|len| (0..len).collect())
+                .unwrap_or_default(),
+            SearchMode::Regex => self
+                .regex(query)
+                .ok()
+                .and_then(|regex| regex.find(item))
+                .map(|found| {
+                    let start = item[..found.start()].chars().count();
+                    (start..start + found.as_str().chars().count()).collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn regex(&self, query: &str) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(query)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+    }
+
+    /// [`match_positions`](Self::match_positions) of every item, in the same order
+    pub fn highlights<T>(&self, items: &[T], query: &str) -> Vec<Vec<usize>>
+    where
+        T: std::ops::Deref,
+        T::Target: AsRef<str>,
+    {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        items
+            .iter()
+            .map(|item| self.match_positions(item.deref().as_ref(), query))
+            .collect()
+    }
+}
+
+/// Length in characters of the prefix of `item` matching `query`. Unless `case_sensitive`,
+/// case is only matched when the query contains uppercase letters.
+fn prefix_len(item: &str, query: &str, case_sensitive: bool) -> Option<usize> {
+    let case_sensitive = case_sensitive || query.chars().any(char::is_uppercase);
+    let mut item_chars = item.chars();
+    let mut len = 0;
+
+    for query_char in query.chars() {
+        let item_char = item_chars.next()?;
+        let matches = if case_sensitive {
+            item_char == query_char
+        } else {
+            item_char.to_lowercase().eq(query_char.to_lowercase())
+        };
+        if !matches {
+            return None;
+        }
+        len += 1;
+    }
+
+    Some(len)
 }
@@ -0,0 +1,72 @@
+use chrono::NaiveTime;
+use std::{collections::HashSet, ops::Deref};
+
+use crate::tui::strings::ItemRefreshStrings;
+
+/// Reconciles the marked items with a refreshed item list.
+///
+/// Items are identified by their string. Marks of items that no longer exist are dropped,
+/// marks of surviving items are kept, and preselected items are only marked when they
+/// first appear, so a refresh never re-marks an item the user unmarked.
+pub fn reconcile_marked<T>(
+    previous: &[T],
+    current: &[T],
+    marked: &HashSet<String>,
+    preselected: &[String],
+) -> HashSet<String>
+where
+    T: Deref,
+    T::Target: AsRef<str>,
+{
+    let previous: HashSet<&str> = previous.iter().map(|item| (**item).as_ref()).collect();
+    let current: HashSet<&str> = current.iter().map(|item| (**item).as_ref()).collect();
+
+    let kept = marked
+        .iter()
+        .filter(|item| current.contains(item.as_str()))
+        .cloned();
+    let new_preselected = preselected
+        .iter()
+        .filter(|item| current.contains(item.as_str()) && !previous.contains(item.as_str()))
+        .cloned();
+
+    kept.chain(new_preselected).collect()
+}
+
+/// Cursor position in `current` for the item at `selected` in `previous`.
+///
+/// Follows the item if it still exists, otherwise stays at the same position clamped to
+/// the new list. Returns `None` when nothing was selected or `current` is empty.
+pub fn reconcile_cursor<T>(previous: &[T], current: &[T], selected: Option<usize>) -> Option<usize>
+where
+    T: Deref,
+    T::Target: AsRef<str>,
+{
+    let selected = selected?;
+    if current.is_empty() {
+        return None;
+    }
+
+    previous
+        .get(selected)
+        .and_then(|item| {
+            current
+                .iter()
+                .position(|candidate| (**candidate).as_ref() == (**item).as_ref())
+        })
+        .or(Some(selected.min(current.len() - 1)))
+}
+
+/// Status bar indicator of the item list: the sort order, followed by the time of the last
+/// item refresh once a polling task refreshed its items, e.g. `A-Z ⟳ 14:02:11`
+pub fn status_indicator(sort: &str, last_refresh: Option<NaiveTime>) -> String {
+    match last_refresh {
+        Some(time) => format!(
+            "{} {} {}",
+            sort,
+            ItemRefreshStrings::REFRESHED,
+            time.format("%H:%M:%S")
+        ),
+        None => sort.to_string(),
+    }
+}
@@ -0,0 +1,28 @@
+use std::{collections::HashSet, hash::Hash};
+
+/// Marks every visible item, keeping the marks of items hidden by the search
+pub fn select_all<T: Clone + Eq + Hash>(marked: &HashSet<T>, visible: &[T]) -> HashSet<T> {
+    marked.iter().chain(visible).cloned().collect()
+}
+
+/// Unmarks every visible item, keeping the marks of items hidden by the search
+pub fn select_none<T: Clone + Eq + Hash>(marked: &HashSet<T>, visible: &[T]) -> HashSet<T> {
+    let visible: HashSet<&T> = visible.iter().collect();
+    marked
+        .iter()
+        .filter(|item| !visible.contains(item))
+        .cloned()
+        .collect()
+}
+
+/// Flips the mark of every visible item, keeping the marks of items hidden by the search
+pub fn invert_selection<T: Clone + Eq + Hash>(marked: &HashSet<T>, visible: &[T]) -> HashSet<T> {
+    let mut inverted = select_none(marked, visible);
+    inverted.extend(
+        visible
+            .iter()
+            .filter(|item| !marked.contains(*item))
+            .cloned(),
+    );
+    inverted
+}
@@ -0,0 +1,60 @@
+use std::{cmp::Ordering, fmt::Display, ops::Deref};
+
+use crate::tui::strings::SortStrings;
+
+/// Display order of the items in the item list, cycled with the `sort_items` keybinding
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ItemSortMode {
+    /// Order returned by the task's `items()` (or by search score while searching)
+    #[default]
+    SourceOrder,
+    Alphabetical,
+    ReverseAlphabetical,
+}
+
+impl ItemSortMode {
+    pub fn next(self) -> Self {
+        match self {
+            ItemSortMode::SourceOrder => ItemSortMode::Alphabetical,
+            ItemSortMode::Alphabetical => ItemSortMode::ReverseAlphabetical,
+            ItemSortMode::ReverseAlphabetical => ItemSortMode::SourceOrder,
+        }
+    }
+
+    /// Sorts `items` in place. Comparison is case-insensitive with ties broken by the exact
+    /// string, and `SourceOrder` leaves the slice untouched.
+    pub fn apply<T>(self, items: &mut [T])
+    where
+        T: Deref,
+        T::Target: AsRef<str>,
+    {
+        match self {
+            ItemSortMode::SourceOrder => {}
+            ItemSortMode::Alphabetical => items.sort_by(|lhs, rhs| compare(lhs, rhs)),
+            ItemSortMode::ReverseAlphabetical => items.sort_by(|lhs, rhs| compare(rhs, lhs)),
+        }
+    }
+}
+
+impl Display for ItemSortMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemSortMode::SourceOrder => write!(f, "{}", SortStrings::SOURCE_ORDER),
+            ItemSortMode::Alphabetical => write!(f, "{}", SortStrings::ALPHABETICAL),
+            ItemSortMode::ReverseAlphabetical => {
+                write!(f, "{}", SortStrings::REVERSE_ALPHABETICAL)
+            }
+        }
+    }
+}
+
+fn compare<T>(lhs: &T, rhs: &T) -> Ordering
+where
+    T: Deref,
+    T::Target: AsRef<str>,
+{
+    let (lhs, rhs) = (lhs.deref().as_ref(), rhs.deref().as_ref());
+    lhs.to_lowercase()
+        .cmp(&rhs.to_lowercase())
+        .then_with(|| lhs.cmp(rhs))
+}
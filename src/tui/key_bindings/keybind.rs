@@ -6,7 +6,7 @@ use std::{
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// A parsed key binding that can match against key events
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyBind {
     pub code: KeyCode,
     pub modifiers: KeyModifiers,
@@ -170,21 +170,28 @@ impl KeyBind {
         }
     }
 
-    /// Check if this binding matches a KeyEvent
-    pub fn matches(&self, event: &KeyEvent) -> bool {
+    /// The binding a KeyEvent matches
+    pub fn from_event(event: &KeyEvent) -> Self {
         // Normalize event: if it has uppercase char + SHIFT, convert to lowercase + SHIFT
         // This makes "K" equivalent to "<S-k>" and handles terminal behavior
-        let (normalized_code, normalized_mods) = if let KeyCode::Char(ch) = event.code {
-            if ch.is_ascii_uppercase() && event.modifiers.contains(KeyModifiers::SHIFT) {
-                (KeyCode::Char(ch.to_ascii_lowercase()), event.modifiers)
-            } else {
-                (event.code, event.modifiers)
+        let code = match event.code {
+            KeyCode::Char(ch)
+                if ch.is_ascii_uppercase() && event.modifiers.contains(KeyModifiers::SHIFT) =>
+            {
+                KeyCode::Char(ch.to_ascii_lowercase())
             }
-        } else {
-            (event.code, event.modifiers)
+            code => code,
         };
 
-        self.code == normalized_code && self.modifiers == normalized_mods
+        KeyBind {
+            code,
+            modifiers: event.modifiers,
+        }
+    }
+
+    /// Check if this binding matches a KeyEvent
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        *self == Self::from_event(event)
     }
 }
 
@@ -1,139 +1,169 @@
-use anyhow::{Context, Result, bail};
-use crossterm::event::{KeyCode, KeyModifiers};
 use std::collections::HashMap;
 
-use crate::{configs::KeyBindings, tui::key_bindings::KeyBind};
+use anyhow::{Context, Result};
+use crossterm::event::KeyEvent;
 
+use crate::{
+    configs::{KeyBindings, validate_keybindings},
+    tui::{events::InputEvent, key_bindings::KeyBind},
+};
+
+/// Every bound key mapped to the action it triggers
 #[derive(Debug, Clone)]
 pub struct ParsedKeyBindings {
-    pub back: KeyBind,
-    pub select_previous: KeyBind,
-    pub select_next: KeyBind,
-    pub scroll_preview_up: KeyBind,
-    pub scroll_preview_down: KeyBind,
-    pub toggle_preview: KeyBind,
-    pub select: KeyBind,
-    pub confirm: KeyBind,
+    actions: HashMap<KeyBind, InputEvent>,
 }
 
 impl ParsedKeyBindings {
     pub fn from(key_bindings: &KeyBindings) -> Result<ParsedKeyBindings> {
-        let parsed_keybindings = ParsedKeyBindings {
-            back: KeyBind::parse(&key_bindings.back).with_context(|| {
-                format!("Failed to parse 'back' keybinding '{}'", key_bindings.back)
-            })?,
-            select_previous: KeyBind::parse(&key_bindings.select_previous).with_context(|| {
-                format!(
-                    "Failed to parse 'select_previous' keybinding '{}'",
-                    key_bindings.select_previous
-                )
-            })?,
-            select_next: KeyBind::parse(&key_bindings.select_next).with_context(|| {
-                format!(
-                    "Failed to parse 'select_next' keybinding '{}'",
-                    key_bindings.select_next
-                )
-            })?,
-            scroll_preview_up: KeyBind::parse(&key_bindings.scroll_preview_up).with_context(
-                || {
-                    format!(
-                        "Failed to parse 'scroll_preview_up' keybinding '{}'",
-                        key_bindings.scroll_preview_up
-                    )
-                },
-            )?,
-            scroll_preview_down: KeyBind::parse(&key_bindings.scroll_preview_down).with_context(
-                || {
-                    format!(
-                        "Failed to parse 'scroll_preview_down' keybinding '{}'",
-                        key_bindings.scroll_preview_down
-                    )
-                },
-            )?,
-            toggle_preview: KeyBind::parse(&key_bindings.toggle_preview).with_context(|| {
-                format!(
-                    "Failed to parse 'toggle_preview' keybinding '{}'",
-                    key_bindings.toggle_preview
-                )
-            })?,
-            select: KeyBind::parse(&key_bindings.select).with_context(|| {
-                format!(
-                    "Failed to parse 'select' keybinding '{}'",
-                    key_bindings.select
-                )
-            })?,
-            confirm: KeyBind::parse(&key_bindings.confirm).with_context(|| {
-                format!(
-                    "Failed to parse 'confirm' keybinding '{}'",
-                    key_bindings.confirm
-                )
-            })?,
-        };
-
         // Check for duplicate key bindings
-        check_for_duplicates(&parsed_keybindings)?;
+        validate_keybindings(key_bindings)?;
 
-        Ok(parsed_keybindings)
-    }
-}
+        let bindings = [
+            ("back", &key_bindings.back, InputEvent::Back),
+            (
+                "select_previous",
+                &key_bindings.select_previous,
+                InputEvent::PreviousItem,
+            ),
+            (
+                "select_next",
+                &key_bindings.select_next,
+                InputEvent::NextItem,
+            ),
+            ("page_up", &key_bindings.page_up, InputEvent::PageUp),
+            ("page_down", &key_bindings.page_down, InputEvent::PageDown),
+            (
+                "history_previous",
+                &key_bindings.history_previous,
+                InputEvent::HistoryPrevious,
+            ),
+            (
+                "history_next",
+                &key_bindings.history_next,
+                InputEvent::HistoryNext,
+            ),
+            (
+                "scroll_preview_up",
+                &key_bindings.scroll_preview_up,
+                InputEvent::ScrollPreviewUp,
+            ),
+            (
+                "scroll_preview_down",
+                &key_bindings.scroll_preview_down,
+                InputEvent::ScrollPreviewDown,
+            ),
+            (
+                "scroll_preview_left",
+                &key_bindings.scroll_preview_left,
+                InputEvent::ScrollPreviewLeft,
+            ),
+            (
+                "scroll_preview_right",
+                &key_bindings.scroll_preview_right,
+                InputEvent::ScrollPreviewRight,
+            ),
+            (
+                "toggle_preview",
+                &key_bindings.toggle_preview,
+                InputEvent::TogglePreview,
+            ),
+            (
+                "toggle_preview_wrap",
+                &key_bindings.toggle_preview_wrap,
+                InputEvent::TogglePreviewWrap,
+            ),
+            ("confirm", &key_bindings.confirm, InputEvent::Confirm),
+            ("select", &key_bindings.select, InputEvent::Select),
+            ("jobs", &key_bindings.jobs, InputEvent::ShowJobs),
+            ("kill_job", &key_bindings.kill_job, InputEvent::KillJob),
+            (
+                "restart_job",
+                &key_bindings.restart_job,
+                InputEvent::RestartJob,
+            ),
+            (
+                "sort_items",
+                &key_bindings.sort_items,
+                InputEvent::SortItems,
+            ),
+            ("dump_plan", &key_bindings.dump_plan, InputEvent::DumpPlan),
+            ("go_home", &key_bindings.go_home, InputEvent::GoHome),
+            (
+                "toggle_search_mode",
+                &key_bindings.toggle_search_mode,
+                InputEvent::ToggleSearchMode,
+            ),
+            (
+                "toggle_case_sensitive",
+                &key_bindings.toggle_case_sensitive,
+                InputEvent::ToggleCaseSensitive,
+            ),
+            ("copy", &key_bindings.copy, InputEvent::Copy),
+            (
+                "copy_preview",
+                &key_bindings.copy_preview,
+                InputEvent::CopyPreview,
+            ),
+            (
+                "select_all",
+                &key_bindings.select_all,
+                InputEvent::SelectAll,
+            ),
+            (
+                "select_none",
+                &key_bindings.select_none,
+                InputEvent::SelectNone,
+            ),
+            (
+                "invert_selection",
+                &key_bindings.invert_selection,
+                InputEvent::InvertSelection,
+            ),
+            (
+                "cycle_plugin_filter",
+                &key_bindings.cycle_plugin_filter,
+                InputEvent::CyclePluginFilter,
+            ),
+        ];
 
-fn check_for_duplicates(parsed: &ParsedKeyBindings) -> Result<()> {
-    let mut binding_map: HashMap<(KeyCode, KeyModifiers), Vec<&str>> = HashMap::new();
+        let quick_actions =
+            key_bindings
+                .quick_actions
+                .iter()
+                .enumerate()
+                .map(|(index, quick_action)| {
+                    (
+                        quick_action.label(),
+                        &quick_action.key,
+                        InputEvent::QuickAction(index),
+                    )
+                });
 
-    binding_map
-        .entry((parsed.back.code, parsed.back.modifiers))
-        .or_default()
-        .push("back");
-    binding_map
-        .entry((
-            parsed.select_previous.code,
-            parsed.select_previous.modifiers,
-        ))
-        .or_default()
-        .push("select_previous");
-    binding_map
-        .entry((parsed.select_next.code, parsed.select_next.modifiers))
-        .or_default()
-        .push("select_next");
-    binding_map
-        .entry((
-            parsed.scroll_preview_up.code,
-            parsed.scroll_preview_up.modifiers,
-        ))
-        .or_default()
-        .push("scroll_preview_up");
-    binding_map
-        .entry((
-            parsed.scroll_preview_down.code,
-            parsed.scroll_preview_down.modifiers,
-        ))
-        .or_default()
-        .push("scroll_preview_down");
-    binding_map
-        .entry((parsed.toggle_preview.code, parsed.toggle_preview.modifiers))
-        .or_default()
-        .push("toggle_preview");
-    binding_map
-        .entry((parsed.select.code, parsed.select.modifiers))
-        .or_default()
-        .push("select");
-    binding_map
-        .entry((parsed.confirm.code, parsed.confirm.modifiers))
-        .or_default()
-        .push("confirm");
+        let mut actions = HashMap::new();
+        for (action, binding, event) in bindings
+            .into_iter()
+            .map(|(action, binding, event)| (action.to_string(), binding, event))
+            .chain(quick_actions)
+        {
+            for key in binding.keys() {
+                let key_bind = KeyBind::parse(key).with_context(|| {
+                    format!("Failed to parse '{}' keybinding '{}'", action, key)
+                })?;
+                actions.insert(key_bind, event.clone());
+            }
+        }
 
-    let conflicts: Vec<String> = binding_map
-        .iter()
-        .filter(|(_, actions)| actions.len() > 1)
-        .map(|(key, actions)| format!("{:?} is bound to: {}", key, actions.join(", ")))
-        .collect();
+        Ok(ParsedKeyBindings { actions })
+    }
 
-    if !conflicts.is_empty() {
-        bail!(
-            "Duplicate key bindings detected:\n  {}",
-            conflicts.join("\n  ")
-        );
+    /// The action bound to the key of `event`
+    pub fn action(&self, event: &KeyEvent) -> Option<InputEvent> {
+        self.actions.get(&KeyBind::from_event(event)).cloned()
     }
 
-    Ok(())
+    /// Whether the key of `event` triggers `action`
+    pub fn triggers(&self, event: &KeyEvent, action: InputEvent) -> bool {
+        self.action(event) == Some(action)
+    }
 }
@@ -1,16 +1,25 @@
+pub mod color_mode;
 mod dispatcher;
 pub mod events;
 pub mod external_tui;
 pub mod fuzzy_searcher;
+pub mod item_refresh;
+pub mod item_selection;
+pub mod item_sort;
 pub mod key_bindings;
 pub mod navigation;
+pub mod panic_guard;
+pub mod plugin_filter;
+pub mod preview_cache;
+pub mod preview_poller;
 mod screens;
+pub mod search_history;
 mod strings;
 mod tui_app;
 pub mod views;
 
 pub use external_tui::{
-    ExternalTuiRequest, TuiRequestReceiver, TuiRequestSender, create_tui_channel, get_tui_sender,
-    run_tui_command_blocking, set_tui_sender,
+    ExternalTuiRequest, PluginLogLevel, TuiRequestReceiver, TuiRequestSender, create_tui_channel,
+    get_tui_sender, run_tui_command_blocking, set_tui_sender,
 };
 pub use tui_app::TuiApp;
@@ -1,7 +1,18 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum Intent {
-    SelectPlugin { plugin_idx: usize },
-    SelectTask { plugin_idx: usize, task_key: String },
+    SelectPlugin {
+        plugin_idx: usize,
+    },
+    SelectTask {
+        plugin_idx: usize,
+        task_key: String,
+    },
+    ShowToast {
+        message: String,
+        exit_code: Option<i32>,
+    },
+    /// Return to the first screen of the navigation stack
+    GoHome,
     Quit,
     None,
 }
@@ -5,5 +5,5 @@ pub mod routes;
 
 pub use intent::Intent;
 pub use navigator::{Navigator, StackEntry};
-pub use payload::{ItemPayload, PluginPayload, TaskPayload};
+pub use payload::{ItemPayload, JobsPayload, PluginPayload, TaskPayload};
 pub use routes::Route;
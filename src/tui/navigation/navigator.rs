@@ -55,6 +55,20 @@ impl Navigator {
         }
     }
 
+    /// Pops every entry above the root, returning them from the most recent one down
+    pub fn back_to_root(&mut self) -> Vec<StackEntry> {
+        let popped: Vec<StackEntry> = self.stack.drain(1..).rev().collect();
+        if !popped.is_empty() {
+            self.update_breadcrumbs();
+        }
+        popped
+    }
+
+    /// Number of entries on the stack, the root included
+    pub fn history_len(&self) -> usize {
+        self.stack.len()
+    }
+
     pub fn resolve_intent(&mut self, event: Intent) -> Option<Route> {
         match event {
             Intent::SelectPlugin { plugin_idx } => Some(Route::Task {
@@ -69,7 +83,7 @@ impl Navigator {
                     task_key,
                 },
             }),
-            Intent::Quit | Intent::None => None,
+            Intent::ShowToast { .. } | Intent::GoHome | Intent::Quit | Intent::None => None,
         }
     }
 
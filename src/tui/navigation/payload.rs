@@ -6,6 +6,9 @@ pub struct TaskPayload {
     pub plugin_idx: usize,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobsPayload;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ItemPayload {
     pub plugin_idx: usize,
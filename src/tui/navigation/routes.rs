@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use crate::tui::{
-    navigation::{ItemPayload, PluginPayload, TaskPayload},
+    navigation::{ItemPayload, JobsPayload, PluginPayload, TaskPayload},
     strings::RouteStrings,
 };
 
@@ -10,6 +10,7 @@ pub enum Route {
     Plugin { payload: PluginPayload },
     Task { payload: TaskPayload },
     Item { payload: ItemPayload },
+    Jobs { payload: JobsPayload },
 }
 
 impl Display for Route {
@@ -18,6 +19,7 @@ impl Display for Route {
             Route::Plugin { .. } => write!(f, "{}", RouteStrings::PLUGIN),
             Route::Task { .. } => write!(f, "{}", RouteStrings::TASK),
             Route::Item { .. } => write!(f, "{}", RouteStrings::ITEM),
+            Route::Jobs { .. } => write!(f, "{}", RouteStrings::JOBS),
         }
     }
 }
@@ -0,0 +1,69 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Runs `f`, turning a panic on this thread into `Err` with its message and location.
+///
+/// The message is not printed when the panic happens, the TUI would draw it over the
+/// alternate screen. The caller restores the terminal and reports it instead. Panics on other
+/// threads still go to the previous panic hook, which is reinstated afterwards.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    let guarded_thread = thread::current().id();
+    let caught = Arc::new(Mutex::new(None));
+    let previous_hook: Arc<PanicHook> = Arc::new(panic::take_hook());
+
+    {
+        let caught = Arc::clone(&caught);
+        let previous_hook = Arc::clone(&previous_hook);
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().id() == guarded_thread {
+                if let Ok(mut caught) = caught.lock() {
+                    *caught = Some(describe_panic(info));
+                }
+            } else {
+                previous_hook(info);
+            }
+        }));
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+    // Dropping the guarding hook releases its reference to the previous one
+    drop(panic::take_hook());
+    match Arc::try_unwrap(previous_hook) {
+        Ok(previous_hook) => panic::set_hook(previous_hook),
+        Err(previous_hook) => panic::set_hook(Box::new(move |info| previous_hook(info))),
+    }
+
+    result.map_err(|payload| {
+        caught
+            .lock()
+            .ok()
+            .and_then(|mut caught| caught.take())
+            .unwrap_or_else(|| panic_message(payload.as_ref()))
+    })
+}
+
+/// Message of a panic payload, the `&str` or `String` passed to `panic!`
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn describe_panic(info: &PanicHookInfo<'_>) -> String {
+    let message = panic_message(info.payload());
+    match info.location() {
+        Some(location) => format!("{} at {}:{}", message, location.file(), location.line()),
+        None => message,
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use crate::{plugins::Metadata, tui::strings::FilterStrings};
+
+/// Plugins shown in the plugin list, cycled with the `cycle_plugin_filter` keybinding
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum PluginFilter {
+    #[default]
+    All,
+    /// Plugins declaring the current platform in `metadata.platforms`. Plugins unsupported
+    /// on it are never loaded, so this hides the ones written for every platform.
+    CurrentPlatform,
+}
+
+impl PluginFilter {
+    pub fn next(self) -> Self {
+        match self {
+            PluginFilter::All => PluginFilter::CurrentPlatform,
+            PluginFilter::CurrentPlatform => PluginFilter::All,
+        }
+    }
+
+    /// Whether the plugin is shown on `platform`, named like in `metadata.platforms`
+    pub fn matches(self, metadata: &Metadata, platform: &str) -> bool {
+        match self {
+            PluginFilter::All => true,
+            PluginFilter::CurrentPlatform => metadata.platforms.iter().any(|p| p == platform),
+        }
+    }
+}
+
+impl Display for PluginFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluginFilter::All => write!(f, "{}", FilterStrings::ALL),
+            PluginFilter::CurrentPlatform => write!(f, "{}", FilterStrings::CURRENT_PLATFORM),
+        }
+    }
+}
+
+/// Whether `query` is part of the plugin name or description. Ignores case unless
+/// `case_sensitive`, an empty query matches every plugin.
+pub fn matches_text(metadata: &Metadata, query: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        return metadata.name.contains(query) || metadata.description.contains(query);
+    }
+    let query = query.to_lowercase();
+    metadata.name.to_lowercase().contains(&query)
+        || metadata.description.to_lowercase().contains(&query)
+}
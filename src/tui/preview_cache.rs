@@ -0,0 +1,94 @@
+use indexmap::IndexMap;
+
+/// Previews kept per item list when the config doesn't set `preview_cache_size`
+pub const DEFAULT_PREVIEW_CACHE_SIZE: usize = 50;
+
+/// Previews of an item list, evicting the least recently used one beyond `capacity`.
+///
+/// Entries are kept in recency order, the front is the least recently used. Looking an
+/// entry up with [`PreviewCache::get`] counts a hit or a miss. Previews are strings unless
+/// the screen keeps more about them.
+#[derive(Debug)]
+pub struct PreviewCache<P = String> {
+    capacity: usize,
+    entries: IndexMap<String, P>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<P> Default for PreviewCache<P> {
+    fn default() -> Self {
+        Self::new(DEFAULT_PREVIEW_CACHE_SIZE)
+    }
+}
+
+impl<P> PreviewCache<P> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: IndexMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Preview of `item`, marking it as the most recently used and counting a hit or miss
+    pub fn get(&mut self, item: &str) -> Option<&P> {
+        match self.entries.get_index_of(item) {
+            Some(idx) => {
+                self.hits += 1;
+                let last = self.entries.len() - 1;
+                self.entries.move_index(idx, last);
+                self.entries.get_index(last).map(|(_, preview)| preview)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Preview of `item` without touching its recency or the stats
+    pub fn peek(&self, item: &str) -> Option<&P> {
+        self.entries.get(item)
+    }
+
+    pub fn contains(&self, item: &str) -> bool {
+        self.entries.contains_key(item)
+    }
+
+    /// Stores the preview of `item` as the most recently used, evicting the least recently
+    /// used preview when full
+    pub fn insert(&mut self, item: String, preview: P) {
+        self.entries.shift_remove(&item);
+        if self.entries.len() >= self.capacity {
+            self.entries.shift_remove_index(0);
+        }
+        self.entries.insert(item, preview);
+    }
+
+    /// Drops every preview except the one of `keep`
+    pub fn retain_only(&mut self, keep: &str) {
+        self.entries.retain(|item, _| item == keep);
+    }
+
+    /// Drops every preview and resets the stats
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Lookups by [`PreviewCache::get`] that found a preview and that didn't
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits, self.misses)
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+/// Schedules the periodic preview refresh of a task's `preview_polling_interval`.
+///
+/// The timer follows the focused item: moving the focus restarts it, so a freshly focused
+/// item is refreshed one full interval after its first preview. Ticks that come due while a
+/// preview call is still running are coalesced into a single refresh once it finished.
+#[derive(Debug, Default)]
+pub struct PreviewPoller {
+    interval: Duration,
+    focused: Option<String>,
+    last_refresh: Option<Instant>,
+}
+
+impl PreviewPoller {
+    /// Starts polling every `interval`, a zero interval disables polling
+    pub fn start(&mut self, interval: Duration) {
+        self.reset();
+        self.interval = interval;
+    }
+
+    /// Stops polling and forgets the focused item
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether the preview of `focused` is due a refresh at `now`.
+    ///
+    /// `in_flight` tells whether a preview call is still running, due ticks are held back
+    /// until it finished.
+    pub fn poll(&mut self, focused: &str, now: Instant, in_flight: bool) -> bool {
+        if self.interval.is_zero() {
+            return false;
+        }
+
+        if self.focused.as_deref() != Some(focused) {
+            self.focused = Some(focused.to_string());
+            self.last_refresh = Some(now);
+            return false;
+        }
+
+        if in_flight {
+            return false;
+        }
+
+        match self.last_refresh {
+            Some(last_refresh) if now.duration_since(last_refresh) < self.interval => false,
+            _ => {
+                self.last_refresh = Some(now);
+                true
+            }
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{
     app::App,
-    tui::{events::InputEvent, navigation::Intent, screens::core::status::Status, views::Styles},
+    tui::{
+        events::InputEvent, fuzzy_searcher::SearchOptions, navigation::Intent,
+        screens::core::status::Status, views::Styles,
+    },
 };
+use crossterm::event::MouseEvent;
 use ratatui::{Frame, layout::Rect};
 
 /// Screen trait providing a unified interface for all screen implementations.
@@ -82,6 +86,12 @@ pub trait Screen<T> {
     /// Mutable reference to the screen's `Status` for the status bar
     fn get_status(&mut self) -> &mut Status;
 
+    /// Returns a short screen-specific indicator shown next to the status in the status bar.
+    /// Optional with default implementation showing nothing.
+    fn get_status_indicator(&self) -> Option<&str> {
+        None
+    }
+
     /// Called when the screen becomes inactive in the navigation stack.
     ///
     /// This lifecycle hook is invoked when navigating away from the screen,
@@ -104,6 +114,18 @@ pub trait Screen<T> {
     /// `Intent` enum indicating navigation action (e.g., `Intent::None`, `Intent::Back`, etc.)
     fn handle_event(&mut self, event: InputEvent, app: &App, payload: &T) -> Intent;
 
+    /// Handles mouse events (clicks and scroll wheel) and returns navigation intent.
+    /// Optional with default implementation ignoring the mouse.
+    ///
+    /// # Parameters
+    ///
+    /// * `event` - The mouse event, with the terminal cell it occurred at
+    /// * `app` - Immutable reference to the application context
+    /// * `payload` - Type-safe payload containing screen-specific data
+    fn handle_mouse(&mut self, _event: MouseEvent, _app: &App, _payload: &T) -> Intent {
+        Intent::None
+    }
+
     /// Renders the screen to the terminal frame.
     ///
     /// Each screen owns its layout logic, including how it divides the available area
@@ -124,7 +146,8 @@ pub trait Screen<T> {
     /// #Parameters
     ///
     /// * `query` - Search query to perform filtering with
-    fn on_search(&mut self, query: &str);
+    /// * `options` - How the query is matched against the screen's entries
+    fn on_search(&mut self, query: &str, options: SearchOptions);
 
     /// Called before app processes input events.
     ///
@@ -137,4 +160,10 @@ pub trait Screen<T> {
     fn consumed_event(&mut self, _event: &InputEvent) -> bool {
         false
     }
+
+    /// Whether the screen asks a yes/no question, which plain `y` and `n` answer before the
+    /// search bar sees them
+    fn confirmation_shown(&self) -> bool {
+        false
+    }
 }
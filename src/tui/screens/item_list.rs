@@ -1,4 +1,5 @@
 use std::{
+    cell::RefCell,
     collections::{HashMap, HashSet, hash_map::DefaultHasher},
     hash::{Hash, Hasher},
     rc::Rc,
@@ -8,20 +9,38 @@ use std::{
 
 use crate::{
     app::App,
-    execution::{ExecutionResult, Handle, Operation, State},
+    execution::{
+        ExecutionPlan, ExecutionResult, Handle, JobRegistry, Operation, PreviewOutput, State,
+        resolve_item_value, runner::strip_tag,
+    },
+    lua::set_clipboard_text,
     plugins::{Mode, Task},
     tui::{
-        events::InputEvent,
-        fuzzy_searcher::FuzzySearcher,
+        events::{InputEvent, KEY_NO, KEY_YES},
+        fuzzy_searcher::{FuzzySearcher, SearchOptions},
+        item_refresh::{reconcile_cursor, reconcile_marked, status_indicator},
+        item_selection::{invert_selection, select_all, select_none},
+        item_sort::ItemSortMode,
         navigation::{Intent, ItemPayload},
+        preview_cache::PreviewCache,
+        preview_poller::PreviewPoller,
         screens::{Screen, Status},
-        strings::{ModalStrings, PreviewStrings},
-        views::{Modal, ModalDialog, Preview, SelectableList, Styles, render_screen_scaffold},
+        strings::{ClipboardStrings, ItemListStrings, JobStrings, ModalStrings, PreviewStrings},
+        views::{
+            ContextMenu, ContextMenuAction, ListMovement, Modal, ModalDialog, Preview, PreviewWrap,
+            SelectableList, Styles, parse_color, render_screen_scaffold,
+        },
     },
 };
+use chrono::{Local, NaiveTime};
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use mlua::Lua;
-use ratatui::{Frame, layout::Rect};
+use ratatui::{Frame, layout::Rect, style::Color};
 use tokio::{runtime::Handle as RuntimeHandle, sync::Mutex};
+use tracing::debug;
+
+// Columns the preview pans by while it doesn't wrap lines
+const PREVIEW_PAN_COLUMNS: u16 = 8;
 
 #[derive(Default, PartialEq)]
 struct ExecutionStates {
@@ -31,29 +50,39 @@ struct ExecutionStates {
 
 #[derive(Default)]
 struct Cache {
-    previews: HashMap<String, String>,
+    // The screen serves one task at a time and is cleared on exit, so previews are keyed by
+    // the item alone, its tag telling the sources of a multi-source task apart
+    previews: PreviewCache<PreviewOutput>,
+    preview_lookup: String,
+    preview_refresh_pending: bool,
     status: Status,
     execution_states: ExecutionStates,
     instant_since_last_item_poll: Option<Instant>,
-    instant_since_last_preview_poll: Option<Instant>,
     search_query: String,
     display_marked: HashSet<usize>,
     display_marked_dirty: bool,
+    highlights: Vec<Vec<usize>>,
     items_hash: u64,
+    // Whether a complete item list arrived since entering the screen, until then partial
+    // lists of streaming sources are shown
+    items_loaded: bool,
     pending_execution_items: String,
 }
 
 impl Cache {
     fn clear(&mut self) {
         self.previews.clear();
+        self.preview_lookup.clear();
+        self.preview_refresh_pending = false;
         self.status = Status::default();
         self.execution_states = ExecutionStates::default();
         self.instant_since_last_item_poll = None;
-        self.instant_since_last_preview_poll = None;
         self.search_query.clear();
         self.display_marked.clear();
         self.display_marked_dirty = false;
+        self.highlights.clear();
         self.items_hash = 0;
+        self.items_loaded = false;
         self.pending_execution_items.clear();
     }
 }
@@ -61,7 +90,6 @@ impl Cache {
 pub struct ItemListScreen {
     items: Vec<Rc<String>>,
     search_results: Vec<Rc<String>>,
-    search_results_map: HashMap<Rc<String>, usize>,
     marked_items: HashSet<String>,
     selected_item: Rc<String>,
     pending_preview_item: Option<Rc<String>>,
@@ -73,22 +101,30 @@ pub struct ItemListScreen {
     show_preview: bool,
     execution_handle: Handle,
     preview_handle: Handle,
+    job_registry: Rc<RefCell<JobRegistry>>,
     cache: Cache,
     modal_content: Option<String>,
     modal_dialog_shown: bool,
     pending_execution_items: Vec<String>,
+    sort_mode: ItemSortMode,
+    last_item_refresh: Option<NaiveTime>,
+    status_indicator: String,
+    context_menu: ContextMenu,
+    preview_poller: PreviewPoller,
+    area: Rect,
 }
 
 impl ItemListScreen {
     pub fn new(
         runtime_handle: RuntimeHandle,
         lua_runtime: &Arc<Mutex<Lua>>,
+        job_registry: &Rc<RefCell<JobRegistry>>,
         show_preview_pane: bool,
+        preview_cache_size: usize,
     ) -> Self {
         Self {
             items: Vec::new(),
             search_results: Vec::new(),
-            search_results_map: HashMap::new(),
             marked_items: HashSet::new(),
             selected_item: Rc::new(String::new()),
             fuzzy_searcher: FuzzySearcher::default(),
@@ -99,11 +135,21 @@ impl ItemListScreen {
             modal_dialog: ModalDialog::default(),
             execution_handle: Handle::new(runtime_handle.clone(), lua_runtime),
             preview_handle: Handle::new(runtime_handle.clone(), lua_runtime),
+            job_registry: Rc::clone(job_registry),
             pending_preview_item: None,
             pending_execution_items: Vec::new(),
-            cache: Cache::default(),
+            cache: Cache {
+                previews: PreviewCache::new(preview_cache_size),
+                ..Cache::default()
+            },
             modal_content: None,
             modal_dialog_shown: false,
+            sort_mode: ItemSortMode::default(),
+            last_item_refresh: None,
+            status_indicator: format!("{} · {}", ItemSortMode::default(), PreviewWrap::default()),
+            context_menu: ContextMenu::default(),
+            preview_poller: PreviewPoller::default(),
+            area: Rect::default(),
         }
     }
 
@@ -122,6 +168,11 @@ impl ItemListScreen {
         }
     }
 
+    fn update_status_indicator(&mut self) {
+        let modes = format!("{} · {}", self.sort_mode, self.preview.wrap());
+        self.status_indicator = status_indicator(&modes, self.last_item_refresh);
+    }
+
     fn update_preview(&mut self, task: &Arc<Task>) {
         let pending_cache = if let Some(pending_preview) = &self.pending_preview_item {
             pending_preview == &self.selected_item
@@ -129,20 +180,26 @@ impl ItemListScreen {
             false
         };
 
-        let cache_valid = self.cache.previews.contains_key(&**self.selected_item)
+        // Count a hit or miss once per focused item, not on every frame
+        if *self.selected_item != self.cache.preview_lookup {
+            let hit = self.cache.previews.get(&self.selected_item).is_some();
+            let (hits, misses) = self.cache.previews.stats();
+            debug!(item = %self.selected_item, hit, hits, misses, "Preview cache lookup");
+            self.cache.preview_lookup = (*self.selected_item).clone();
+        }
+
+        let cache_valid = (self.cache.previews.contains(&self.selected_item)
+            && !self.cache.preview_refresh_pending)
             || pending_cache
             || self.preview_handle.is_executing();
 
-        let should_invalidate_cache = task.preview_polling_interval > 0
-            && self
-                .cache
-                .instant_since_last_preview_poll
-                .is_some_and(|last_preview_poll| {
-                    last_preview_poll.elapsed()
-                        >= Duration::from_millis(task.preview_polling_interval as u64)
-                });
+        let refresh_due = self.preview_poller.poll(
+            &self.selected_item,
+            Instant::now(),
+            self.preview_handle.is_executing(),
+        );
 
-        if cache_valid && !should_invalidate_cache {
+        if cache_valid && !refresh_due {
             return;
         }
 
@@ -155,8 +212,19 @@ impl ItemListScreen {
             .is_ok()
         {
             self.pending_preview_item = Some(Rc::clone(&self.selected_item));
+            self.cache.preview_refresh_pending = false;
         }
-        self.cache.instant_since_last_preview_poll = Some(Instant::now());
+    }
+
+    fn move_cursor(&mut self, movement: ListMovement, app: &App, task: &Arc<Task>) {
+        self.selectable_list.move_selection(
+            movement,
+            self.search_results.len(),
+            app.config.list_wrap,
+        );
+        self.sync_selected_item();
+        self.preview.reset_scroll();
+        self.update_preview(task);
     }
 
     fn sync_selected_item(&mut self) {
@@ -171,11 +239,9 @@ impl ItemListScreen {
     }
 
     fn search(&mut self) {
-        let previously_selected = if !self.selected_item.is_empty() {
-            Some(Rc::clone(&self.selected_item))
-        } else {
-            None
-        };
+        let previous_results = std::mem::take(&mut self.search_results);
+        let previously_selected =
+            (!self.selected_item.is_empty()).then(|| self.selectable_list.selected());
 
         let search_indexes = self
             .fuzzy_searcher
@@ -185,38 +251,158 @@ impl ItemListScreen {
             .iter()
             .map(|index| self.items[*index].clone())
             .collect();
+        self.sort_mode.apply(&mut self.search_results);
+        self.cache.highlights = self
+            .fuzzy_searcher
+            .highlights(&self.search_results, self.cache.search_query.as_str());
 
-        self.search_results_map = self
-            .search_results
-            .iter()
-            .enumerate()
-            .map(|(idx, item)| (Rc::clone(item), idx))
-            .collect();
+        self.cache.display_marked_dirty = true;
 
+        match reconcile_cursor(&previous_results, &self.search_results, previously_selected) {
+            Some(idx) => self.selectable_list.select(idx),
+            None if !self.search_results.is_empty() => self.selectable_list.select_first(),
+            None => {}
+        }
+        self.sync_selected_item();
+    }
+
+    // Replaces the items when they changed. Preselected items are marked when they are new to
+    // the list, every item is new until the first complete list arrived.
+    fn set_items(&mut self, items: Vec<String>, preselected_items: &[String]) {
+        self.selectable_list.set_placeholder(None);
+        let mut hasher = DefaultHasher::new();
+        for item in &items {
+            item.hash(&mut hasher);
+        }
+        let new_hash = hasher.finish();
+
+        if new_hash != self.cache.items_hash || !self.cache.items_loaded {
+            let items: Vec<Rc<String>> = items.into_iter().map(Rc::new).collect();
+            let previous: &[Rc<String>] = if self.cache.items_loaded {
+                &self.items
+            } else {
+                &[]
+            };
+            self.marked_items =
+                reconcile_marked(previous, &items, &self.marked_items, preselected_items);
+            self.items = items;
+            self.cache.items_hash = new_hash;
+            self.search();
+        }
+    }
+
+    fn select_index(&mut self, idx: usize, task: &Arc<Task>) {
+        self.selectable_list.select(idx);
+        self.sync_selected_item();
+        self.preview.reset_scroll();
+        self.update_preview(task);
+    }
+
+    fn toggle_marked(&mut self) {
+        let selected_item = &self.selected_item;
+        if self.marked_items.contains(&**selected_item) {
+            self.marked_items.remove(&**selected_item);
+        } else {
+            self.marked_items.insert((**selected_item).clone());
+        }
         self.cache.display_marked_dirty = true;
+    }
 
-        if !self.search_results.is_empty() {
-            if let Some(prev_item) = previously_selected {
-                if let Some(&new_idx) = self.search_results_map.get(&prev_item) {
-                    self.selectable_list.select(new_idx);
+    fn run_context_menu_action(&mut self, action: ContextMenuAction, task: &Arc<Task>) {
+        self.context_menu.close();
+        match action {
+            ContextMenuAction::Preview => {
+                self.show_preview = true;
+                self.preview.reset_scroll();
+                self.update_preview(task);
+            }
+            ContextMenuAction::Select => {
+                if matches!(task.mode, Mode::Multi) {
+                    self.toggle_marked();
+                }
+            }
+        }
+    }
+
+    /// Items the task would run on: the marked items in multi mode, otherwise the highlighted one
+    fn selection(&self, task: &Task) -> Vec<String> {
+        match task.mode {
+            Mode::Multi => self.marked_items.iter().cloned().collect(),
+            Mode::Single | Mode::Background => {
+                if self.selected_item.is_empty() {
+                    vec![]
                 } else {
-                    self.selectable_list.select_first();
+                    vec![(*self.selected_item).clone()]
                 }
-            } else {
-                self.selectable_list.select_first();
             }
         }
-        self.sync_selected_item();
     }
 
-    fn execute(&mut self, task: &Arc<Task>) {
+    /// Copies the value of the focused item, or its loaded preview, to the clipboard
+    fn copy_to_clipboard(&self, app: &App, task: &Task, preview: bool) -> Intent {
+        if self.selected_item.is_empty() {
+            return Intent::ShowToast {
+                message: ClipboardStrings::NO_ITEM.to_string(),
+                exit_code: None,
+            };
+        }
+
+        let (text, copied) = if preview {
+            let Some(preview) = self.cache.previews.peek(&self.selected_item) else {
+                return Intent::ShowToast {
+                    message: ClipboardStrings::NO_PREVIEW.to_string(),
+                    exit_code: None,
+                };
+            };
+            (preview.content.clone(), ClipboardStrings::COPIED_PREVIEW)
+        } else {
+            // The runtime is busy while a plugin function runs, the display string is the
+            // best guess for the value then
+            let value = app
+                .lua_runtime
+                .try_lock()
+                .ok()
+                .and_then(|lua| resolve_item_value(&lua, task, &self.selected_item).ok())
+                .unwrap_or_else(|| strip_tag(&self.selected_item).to_string());
+            (value, ClipboardStrings::COPIED_ITEM)
+        };
+
+        let message = match set_clipboard_text(text) {
+            Ok(()) => copied.to_string(),
+            Err(e) => e,
+        };
+        Intent::ShowToast {
+            message,
+            exit_code: None,
+        }
+    }
+
+    fn execute(&mut self, task: &Arc<Task>) -> Intent {
         self.cache.pending_execution_items.clear();
         let execution_items = self.pending_execution_items.clone();
         self.pending_execution_items.clear();
+        if task.mode == Mode::Background {
+            return match self
+                .job_registry
+                .borrow_mut()
+                .spawn(Arc::clone(task), execution_items)
+            {
+                Ok(_) if task.suppress_success_notification => Intent::None,
+                Ok(job_id) => Intent::ShowToast {
+                    message: format!("{} #{}", JobStrings::STARTED, job_id),
+                    exit_code: None,
+                },
+                Err(e) => {
+                    self.modal_content = Some(format!("{:#}", e));
+                    Intent::None
+                }
+            };
+        }
         let _ = self.execution_handle.execute(Operation::Execute {
             task: Arc::clone(task),
             selected_items: execution_items,
         });
+        Intent::None
     }
 }
 
@@ -228,18 +414,25 @@ impl Screen<ItemPayload> for ItemListScreen {
         if let Some(confirmation_message) = &task.execution_confirmation_message {
             self.modal_dialog.configure(
                 confirmation_message.clone(),
-                app.config.keybindings.confirm.clone(),
-                app.config.keybindings.back.clone(),
+                format!("{}/{}", KEY_YES, app.config.keybindings.confirm),
+                format!("{}/{}", KEY_NO, app.config.keybindings.back),
             );
         };
-        self.modal.configure(app.config.keybindings.confirm.clone());
+        self.modal
+            .configure(app.config.keybindings.confirm.to_string());
+        // The screen shows right away, the items fill in once their sources returned
+        self.selectable_list
+            .set_placeholder(Some(ItemListStrings::LOADING));
         let _ = self.execution_handle.execute(Operation::Items {
             task: Arc::clone(task),
         });
         self.cache.instant_since_last_item_poll = Some(Instant::now());
+        self.preview_poller
+            .start(Duration::from_millis(task.preview_polling_interval as u64));
 
         self.selectable_list
             .set_multiselect_enable(matches!(task.mode, Mode::Multi));
+        self.selectable_list.set_tag_colors(tag_colors(task));
 
         self.selectable_list.select(0);
     }
@@ -248,62 +441,71 @@ impl Screen<ItemPayload> for ItemListScreen {
         self.cache.clear();
         self.items.clear();
         self.search_results.clear();
-        self.search_results_map.clear();
         self.marked_items.clear();
         self.selected_item = Rc::new(String::new());
         self.selectable_list.reset_selected();
+        self.selectable_list.set_placeholder(None);
         self.pending_preview_item = None;
         self.pending_execution_items.clear();
         self.modal_content = None;
         self.modal_dialog_shown = false;
+        self.context_menu.close();
+        self.preview_poller.reset();
+        self.last_item_refresh = None;
+        self.update_status_indicator();
     }
 
     fn on_update(&mut self, app: &App, payload: &ItemPayload) -> Intent {
+        let mut intent = Intent::None;
         self.poll_items(app, payload);
+        // Refreshes keep showing the previous items until the new ones are complete
+        if let Some(items) = self.execution_handle.consume_partial_items()
+            && !self.cache.items_loaded
+        {
+            self.set_items(items, &[]);
+        }
         match self.execution_handle.consume_result() {
             ExecutionResult::Items {
                 items,
                 preselected_items,
             } => {
-                let mut hasher = DefaultHasher::new();
-                for item in &items {
-                    item.hash(&mut hasher);
-                }
-                let new_hash = hasher.finish();
-
-                if new_hash != self.cache.items_hash {
-                    self.items = items.into_iter().map(Rc::new).collect();
-                    self.cache.items_hash = new_hash;
-                    self.search();
+                self.set_items(items, &preselected_items);
+                self.cache.items_loaded = true;
+
+                // Polling tasks show when their items were last fetched, changed or not
+                if app
+                    .get_task(payload.plugin_idx, &payload.task_key)
+                    .is_some_and(|task| task.item_polling_interval > 0)
+                {
+                    // The previews may be outdated along with the items, the focused one stays
+                    // on screen until its refresh arrives
+                    self.cache.previews.retain_only(&self.selected_item);
+                    self.cache.preview_refresh_pending = true;
+                    self.last_item_refresh = Some(Local::now().time());
+                    self.update_status_indicator();
                 }
-
-                preselected_items.iter().for_each(|preselected| {
-                    self.marked_items.insert(preselected.clone());
-                });
-                self.cache.display_marked_dirty = true;
             }
-            ExecutionResult::Output(output, exit_code) => {
+            ExecutionResult::Output(output, exit_code, _) => {
                 if app.config.exit_on_execute {
                     return Intent::Quit;
-                } else {
-                    let should_show_modal =
-                        if let Some(task) = app.get_task(payload.plugin_idx, &payload.task_key) {
-                            !task.suppress_success_notification || exit_code > 0
-                        } else {
-                            exit_code > 0
-                        };
-
-                    if should_show_modal {
+                } else if let Some(task) = app.get_task(payload.plugin_idx, &payload.task_key) {
+                    let _ = self.execution_handle.execute(Operation::Items {
+                        task: Arc::clone(task),
+                    });
+                    if exit_code > 0 {
                         self.modal_content = Some(output);
+                    } else if !task.suppress_success_notification {
+                        intent = Intent::ShowToast {
+                            message: output,
+                            exit_code: Some(exit_code),
+                        };
                     }
-                    if let Some(task) = app.get_task(payload.plugin_idx, &payload.task_key) {
-                        let _ = self.execution_handle.execute(Operation::Items {
-                            task: Arc::clone(task),
-                        });
-                    }
+                } else if exit_code > 0 {
+                    self.modal_content = Some(output);
                 }
             }
             ExecutionResult::Error(output) => {
+                self.selectable_list.set_placeholder(None);
                 if app.config.exit_on_execute {
                     return Intent::Quit;
                 } else {
@@ -318,11 +520,15 @@ impl Screen<ItemPayload> for ItemListScreen {
             _ => {}
         }
 
-        if let ExecutionResult::Preview(output) | ExecutionResult::Error(output) =
-            self.preview_handle.consume_result()
+        let preview = match self.preview_handle.consume_result() {
+            ExecutionResult::Preview(preview) => Some(preview),
+            ExecutionResult::Error(error) => Some(PreviewOutput::plain(error)),
+            _ => None,
+        };
+        if let Some(preview) = preview
             && let Some(idx) = self.pending_preview_item.clone()
         {
-            self.cache.previews.insert((*idx).clone(), output);
+            self.cache.previews.insert((*idx).clone(), preview);
             self.pending_preview_item = None;
         }
 
@@ -330,7 +536,7 @@ impl Screen<ItemPayload> for ItemListScreen {
             self.update_preview(task);
         }
 
-        Intent::None
+        intent
     }
 
     fn handle_event(&mut self, event: InputEvent, app: &App, payload: &ItemPayload) -> Intent {
@@ -359,7 +565,7 @@ impl Screen<ItemPayload> for ItemListScreen {
                 InputEvent::Confirm => {
                     self.modal_dialog.reset_scroll();
                     self.modal_dialog_shown = false;
-                    self.execute(task);
+                    return self.execute(task);
                 }
                 InputEvent::ScrollPreviewUp => {
                     self.modal_dialog
@@ -376,18 +582,35 @@ impl Screen<ItemPayload> for ItemListScreen {
             };
             return Intent::None;
         }
+        if self.context_menu.is_open() {
+            match event {
+                InputEvent::NextItem => self.context_menu.select_next(),
+                InputEvent::PreviousItem => self.context_menu.select_previous(),
+                InputEvent::Confirm => {
+                    let action = self.context_menu.selected_action();
+                    self.run_context_menu_action(action, task);
+                }
+                InputEvent::Back => self.context_menu.close(),
+                _ => {}
+            };
+            return Intent::None;
+        }
         match event {
-            InputEvent::NextItem => {
-                self.selectable_list.select_next();
-                self.sync_selected_item();
-                self.preview.reset_scroll();
-                self.update_preview(task);
+            InputEvent::NextItem => self.move_cursor(ListMovement::Down(1), app, task),
+            InputEvent::PreviousItem => self.move_cursor(ListMovement::Up(1), app, task),
+            InputEvent::PageDown => {
+                self.move_cursor(
+                    ListMovement::Down(self.selectable_list.page_height()),
+                    app,
+                    task,
+                );
             }
-            InputEvent::PreviousItem => {
-                self.selectable_list.select_previous();
-                self.sync_selected_item();
-                self.preview.reset_scroll();
-                self.update_preview(task);
+            InputEvent::PageUp => {
+                self.move_cursor(
+                    ListMovement::Up(self.selectable_list.page_height()),
+                    app,
+                    task,
+                );
             }
             InputEvent::ScrollPreviewUp => {
                 self.preview
@@ -402,38 +625,68 @@ impl Screen<ItemPayload> for ItemListScreen {
                         .scroll_down(app.config.styles.preview.scroll_offset);
                 }
             }
+            InputEvent::ScrollPreviewLeft => self.preview.scroll_left(PREVIEW_PAN_COLUMNS),
+            InputEvent::ScrollPreviewRight => self.preview.scroll_right(PREVIEW_PAN_COLUMNS),
             InputEvent::TogglePreview => {
                 self.show_preview = !self.show_preview;
             }
+            InputEvent::TogglePreviewWrap => {
+                self.preview.toggle_wrap();
+                self.update_status_indicator();
+            }
+            InputEvent::SortItems => {
+                self.sort_mode = self.sort_mode.next();
+                self.update_status_indicator();
+                self.search();
+            }
             InputEvent::Select => {
                 if matches!(task.mode, Mode::Multi) {
-                    let selected_item = &self.selected_item;
-                    if self.marked_items.contains(&**selected_item) {
-                        self.marked_items.remove(&**selected_item);
-                    } else {
-                        self.marked_items.insert((**selected_item).clone());
-                    }
-                    self.cache.display_marked_dirty = true;
-                    self.selectable_list.select_next();
+                    self.toggle_marked();
+                    self.selectable_list.move_selection(
+                        ListMovement::Down(1),
+                        self.search_results.len(),
+                        false,
+                    );
                     self.sync_selected_item();
                 }
             }
             InputEvent::Confirm => {
-                self.pending_execution_items = match task.mode {
-                    Mode::Multi => self.marked_items.iter().cloned().collect(),
-                    Mode::None => {
-                        if self.selected_item.is_empty() {
-                            vec![]
-                        } else {
-                            vec![(*self.selected_item).clone()]
-                        }
-                    }
-                };
+                self.pending_execution_items = self.selection(task);
                 self.cache.pending_execution_items = self.pending_execution_items.join(", ");
                 if task.execution_confirmation_message.is_some() {
                     self.modal_dialog_shown = true;
                 } else {
-                    self.execute(task);
+                    return self.execute(task);
+                }
+            }
+            InputEvent::SelectAll | InputEvent::SelectNone | InputEvent::InvertSelection => {
+                if matches!(task.mode, Mode::Multi) {
+                    let visible: Vec<String> = self
+                        .search_results
+                        .iter()
+                        .map(|item| (**item).clone())
+                        .collect();
+                    self.marked_items = match event {
+                        InputEvent::SelectAll => select_all(&self.marked_items, &visible),
+                        InputEvent::SelectNone => select_none(&self.marked_items, &visible),
+                        _ => invert_selection(&self.marked_items, &visible),
+                    };
+                    self.cache.display_marked_dirty = true;
+                }
+            }
+            InputEvent::Copy => return self.copy_to_clipboard(app, task, false),
+            InputEvent::CopyPreview => return self.copy_to_clipboard(app, task, true),
+            InputEvent::DumpPlan => {
+                let plan =
+                    ExecutionPlan::new(&task.plugin_name, &task.task_key, self.selection(task));
+                match plan.dump() {
+                    Ok(path) => {
+                        return Intent::ShowToast {
+                            message: format!("Plan saved to {}", path.display()),
+                            exit_code: None,
+                        };
+                    }
+                    Err(e) => self.modal_content = Some(format!("{:#}", e)),
                 }
             }
             _ => {}
@@ -441,6 +694,54 @@ impl Screen<ItemPayload> for ItemListScreen {
         Intent::None
     }
 
+    fn handle_mouse(&mut self, event: MouseEvent, app: &App, payload: &ItemPayload) -> Intent {
+        if self.modal_content.is_some() || self.modal_dialog_shown {
+            return Intent::None;
+        }
+        let Some(task) = app.get_task(payload.plugin_idx, &payload.task_key) else {
+            return Intent::None;
+        };
+        let (column, row) = (event.column, event.row);
+
+        if self.context_menu.is_open() {
+            if let MouseEventKind::Down(button) = event.kind {
+                match self.context_menu.action_at(column, row) {
+                    Some(action) if button == MouseButton::Left => {
+                        self.run_context_menu_action(action, task);
+                    }
+                    Some(_) => {}
+                    None => self.context_menu.close(),
+                }
+            }
+            return Intent::None;
+        }
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(idx) = self.selectable_list.item_at(column, row) {
+                    self.select_index(idx, task);
+                    if matches!(task.mode, Mode::Multi) {
+                        self.toggle_marked();
+                    }
+                }
+            }
+            MouseEventKind::Down(MouseButton::Right) => {
+                if let Some(idx) = self.selectable_list.item_at(column, row) {
+                    self.select_index(idx, task);
+                    self.context_menu.open(column, row, self.area);
+                }
+            }
+            MouseEventKind::ScrollDown => {
+                return self.handle_event(InputEvent::NextItem, app, payload);
+            }
+            MouseEventKind::ScrollUp => {
+                return self.handle_event(InputEvent::PreviousItem, app, payload);
+            }
+            _ => {}
+        }
+        Intent::None
+    }
+
     fn get_status(&mut self) -> &mut Status {
         let current_state = ExecutionStates {
             execution: self.execution_handle.read_state(),
@@ -453,7 +754,12 @@ impl Screen<ItemPayload> for ItemListScreen {
         &mut self.cache.status
     }
 
+    fn get_status_indicator(&self) -> Option<&str> {
+        Some(&self.status_indicator)
+    }
+
     fn render(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        self.area = area;
         let display_items: Vec<&String> =
             self.search_results.iter().map(|rc| rc.as_ref()).collect();
 
@@ -476,23 +782,25 @@ impl Screen<ItemPayload> for ItemListScreen {
         let display_marked = &self.cache.display_marked;
 
         if self.show_preview {
-            let preview = if !self.selected_item.is_empty()
-                && let Some(cached) = self.cache.previews.get(&**self.selected_item)
+            let (preview, syntax) = if !self.selected_item.is_empty()
+                && let Some(cached) = self.cache.previews.peek(&self.selected_item)
             {
-                cached.as_str()
+                (cached.content.as_str(), cached.syntax.as_deref())
             } else {
-                PreviewStrings::LOADING
+                (PreviewStrings::LOADING, None)
             };
 
             render_screen_scaffold(
                 frame,
                 area,
                 &styles.screen_scaffold_style,
+                &styles.preview,
                 |frame, left, right| -> () {
                     self.selectable_list.render(
                         frame,
                         left,
                         &display_items,
+                        &self.cache.highlights,
                         &styles.list,
                         &styles.colors,
                         Some(display_marked),
@@ -501,6 +809,7 @@ impl Screen<ItemPayload> for ItemListScreen {
                         frame,
                         right,
                         preview,
+                        syntax,
                         self.selected_item.as_str(),
                         &styles.preview,
                         &styles.colors,
@@ -512,12 +821,16 @@ impl Screen<ItemPayload> for ItemListScreen {
                 frame,
                 area,
                 &display_items,
+                &self.cache.highlights,
                 &styles.list,
                 &styles.colors,
                 Some(display_marked),
             );
         }
 
+        self.context_menu
+            .render(frame, &styles.modal, &styles.colors);
+
         if let Some(content) = &self.modal_content {
             self.modal.render(
                 frame,
@@ -541,14 +854,20 @@ impl Screen<ItemPayload> for ItemListScreen {
         }
     }
 
-    fn on_search(&mut self, query: &str) {
+    fn on_search(&mut self, query: &str, options: SearchOptions) {
+        self.fuzzy_searcher.set_options(options);
         self.cache.search_query = query.to_string();
         self.selected_item = Rc::new(String::new());
         self.search();
     }
 
     fn consumed_event(&mut self, event: &InputEvent) -> bool {
-        matches!(event, InputEvent::Back) && self.modal_dialog_shown
+        matches!(event, InputEvent::Back)
+            && (self.modal_dialog_shown || self.context_menu.is_open())
+    }
+
+    fn confirmation_shown(&self) -> bool {
+        self.modal_dialog_shown
     }
 }
 
@@ -564,3 +883,21 @@ fn resolve_status(state: &ExecutionStates) -> Status {
         (State::None, _) => Status::Idle,
     }
 }
+
+// Colors of the `[tag]` prefixes, only the items of multi-source tasks are tagged. The
+// colors were validated when the plugin loaded.
+fn tag_colors(task: &Task) -> HashMap<String, Option<Color>> {
+    match &task.item_sources {
+        Some(sources) if sources.len() > 1 => sources
+            .values()
+            .map(|source| {
+                let color = source
+                    .color
+                    .as_deref()
+                    .and_then(|color| parse_color(color).ok().flatten());
+                (source.tag.clone(), color)
+            })
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
@@ -0,0 +1,272 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    app::App,
+    execution::{Job, JobId, JobRegistry, State},
+    tui::{
+        events::InputEvent,
+        fuzzy_searcher::{FuzzySearcher, SearchOptions},
+        navigation::{Intent, JobsPayload},
+        screens::{Screen, Status},
+        strings::{JobStrings, PreviewStrings},
+        views::{ListMovement, Preview, SelectableList, Styles, render_screen_scaffold},
+    },
+};
+use ratatui::{Frame, layout::Rect};
+
+#[derive(Default)]
+struct Cache {
+    status: Status,
+    job_ids: Vec<JobId>,
+    job_names: Vec<String>,
+    preview: String,
+    title: String,
+    search_query: String,
+}
+
+pub struct JobListScreen {
+    job_registry: Rc<RefCell<JobRegistry>>,
+    selectable_list: SelectableList,
+    preview: Preview,
+    show_preview: bool,
+    cache: Cache,
+    fuzzy_searcher: FuzzySearcher,
+    items_indices: Vec<usize>,
+    highlights: Vec<Vec<usize>>,
+}
+
+impl JobListScreen {
+    pub fn new(job_registry: &Rc<RefCell<JobRegistry>>, show_preview_pane: bool) -> Self {
+        Self {
+            job_registry: Rc::clone(job_registry),
+            selectable_list: SelectableList::new(false),
+            preview: Preview::default(),
+            show_preview: show_preview_pane,
+            cache: Cache::default(),
+            fuzzy_searcher: FuzzySearcher::default(),
+            items_indices: Vec::new(),
+            highlights: Vec::new(),
+        }
+    }
+
+    fn selected_job_id(&self) -> Option<JobId> {
+        self.items_indices
+            .get(self.selectable_list.selected())
+            .and_then(|&idx| self.cache.job_ids.get(idx))
+            .copied()
+    }
+
+    fn refresh(&mut self) {
+        let job_names: Vec<String> = self.job_registry.borrow().jobs().map(job_name).collect();
+
+        if job_names != self.cache.job_names {
+            self.cache.job_ids = self
+                .job_registry
+                .borrow()
+                .jobs()
+                .map(|job| job.id)
+                .collect();
+            self.cache.job_names = job_names;
+            self.search();
+        }
+
+        self.update_preview();
+    }
+
+    fn search(&mut self) {
+        let selected_job_id = self.selected_job_id();
+        self.items_indices = self
+            .fuzzy_searcher
+            .search(&self.cache.job_names, &self.cache.search_query);
+        let names: Vec<&String> = self
+            .items_indices
+            .iter()
+            .map(|&idx| &self.cache.job_names[idx])
+            .collect();
+        self.highlights = self
+            .fuzzy_searcher
+            .highlights(&names, &self.cache.search_query);
+
+        let selected = selected_job_id
+            .and_then(|id| {
+                self.items_indices
+                    .iter()
+                    .position(|&idx| self.cache.job_ids.get(idx) == Some(&id))
+            })
+            .unwrap_or(0);
+        self.selectable_list.select(selected);
+    }
+
+    fn move_cursor(&mut self, movement: ListMovement, app: &App) {
+        self.selectable_list.move_selection(
+            movement,
+            self.items_indices.len(),
+            app.config.list_wrap,
+        );
+        self.preview.reset_scroll();
+        self.update_preview();
+    }
+
+    fn update_preview(&mut self) {
+        let registry = self.job_registry.borrow();
+        let Some(job) = self.selected_job_id().and_then(|id| registry.get(id)) else {
+            self.cache.title.clear();
+            self.cache.preview = PreviewStrings::NO_JOBS.to_string();
+            return;
+        };
+
+        self.cache.title = job.task.name.clone();
+        self.cache.preview = format!(
+            "{}: {}\n{}: {}\n{}: {}\n\n{}:\n{}",
+            PreviewStrings::TASK,
+            job.task.task_key,
+            PreviewStrings::STATE,
+            state_label(&job.state()),
+            PreviewStrings::ITEMS,
+            job.selected_items.join(", "),
+            PreviewStrings::OUTPUT,
+            job.output().unwrap_or_default(),
+        );
+    }
+}
+
+impl Screen<JobsPayload> for JobListScreen {
+    fn on_enter(&mut self, _app: &App, _payload: &JobsPayload) {
+        self.cache.job_names.clear();
+        self.selectable_list.select(0);
+        self.refresh();
+    }
+
+    fn on_exit(&mut self) {
+        self.cache = Cache::default();
+        self.items_indices.clear();
+        self.selectable_list.reset_selected();
+    }
+
+    fn on_update(&mut self, _app: &App, _payload: &JobsPayload) -> Intent {
+        self.refresh();
+        Intent::None
+    }
+
+    fn handle_event(&mut self, event: InputEvent, app: &App, _payload: &JobsPayload) -> Intent {
+        match event {
+            InputEvent::NextItem => self.move_cursor(ListMovement::Down(1), app),
+            InputEvent::PreviousItem => self.move_cursor(ListMovement::Up(1), app),
+            InputEvent::PageDown => {
+                self.move_cursor(ListMovement::Down(self.selectable_list.page_height()), app);
+            }
+            InputEvent::PageUp => {
+                self.move_cursor(ListMovement::Up(self.selectable_list.page_height()), app);
+            }
+            InputEvent::ScrollPreviewUp => {
+                self.preview
+                    .scroll_up(app.config.styles.preview.scroll_offset);
+            }
+            InputEvent::ScrollPreviewDown => {
+                self.preview
+                    .scroll_down(app.config.styles.preview.scroll_offset);
+            }
+            InputEvent::TogglePreview => {
+                self.show_preview = !self.show_preview;
+            }
+            InputEvent::KillJob => {
+                if let Some(id) = self.selected_job_id() {
+                    let _ = self.job_registry.borrow_mut().kill(id);
+                    self.refresh();
+                }
+            }
+            InputEvent::RestartJob => {
+                if let Some(id) = self.selected_job_id() {
+                    let _ = self.job_registry.borrow_mut().restart(id);
+                    self.refresh();
+                }
+            }
+            _ => {}
+        }
+        Intent::None
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
+        let items: Vec<&String> = self
+            .items_indices
+            .iter()
+            .map(|&idx| &self.cache.job_names[idx])
+            .collect();
+
+        if self.show_preview {
+            render_screen_scaffold(
+                frame,
+                area,
+                &styles.screen_scaffold_style,
+                &styles.preview,
+                |frame, left, right| -> () {
+                    self.selectable_list.render(
+                        frame,
+                        left,
+                        &items,
+                        &self.highlights,
+                        &styles.list,
+                        &styles.colors,
+                        None,
+                    );
+                    self.preview.render(
+                        frame,
+                        right,
+                        &self.cache.preview,
+                        None,
+                        &self.cache.title,
+                        &styles.preview,
+                        &styles.colors,
+                    );
+                },
+            );
+        } else {
+            self.selectable_list.render(
+                frame,
+                area,
+                &items,
+                &self.highlights,
+                &styles.list,
+                &styles.colors,
+                None,
+            );
+        }
+    }
+
+    fn get_status(&mut self) -> &mut Status {
+        let registry = self.job_registry.borrow();
+        self.cache.status = match self.selected_job_id().and_then(|id| registry.status(id)) {
+            Some(State::Running) => Status::Running,
+            Some(State::Finished) => Status::Complete,
+            Some(State::Error) => Status::Error,
+            Some(State::None) | None => Status::Idle,
+        };
+        &mut self.cache.status
+    }
+
+    fn on_search(&mut self, query: &str, options: SearchOptions) {
+        self.fuzzy_searcher.set_options(options);
+        self.cache.search_query = query.to_string();
+        self.search();
+        self.update_preview();
+    }
+}
+
+fn job_name(job: &Job) -> String {
+    format!(
+        "#{} [{}] {}: {}",
+        job.id,
+        state_label(&job.state()),
+        job.task.plugin_name,
+        job.task.name
+    )
+}
+
+fn state_label(state: &State) -> &'static str {
+    match state {
+        State::Running => JobStrings::RUNNING,
+        State::Finished => JobStrings::FINISHED,
+        State::Error => JobStrings::FAILED,
+        State::None => JobStrings::KILLED,
+    }
+}
@@ -1,10 +1,12 @@
 pub mod core;
 pub mod item_list;
+pub mod job_list;
 pub mod plugin_list;
 pub mod task_list;
 
 pub use core::screen::Screen;
 pub use core::status::Status;
 pub use item_list::ItemListScreen;
+pub use job_list::JobListScreen;
 pub use plugin_list::PluginListScreen;
 pub use task_list::TaskListScreen;
@@ -1,12 +1,15 @@
 use crate::{
     app::App,
+    plugins::{Metadata, current_platform},
     tui::{
         events::InputEvent,
-        fuzzy_searcher::FuzzySearcher,
+        fuzzy_searcher::SearchMode,
+        fuzzy_searcher::{FuzzySearcher, SearchOptions},
         navigation::{Intent, PluginPayload},
+        plugin_filter::{PluginFilter, matches_text},
         screens::{Screen, Status},
         strings::PreviewStrings,
-        views::{Preview, SelectableList, Styles, render_screen_scaffold},
+        views::{ListMovement, Preview, SelectableList, Styles, render_screen_scaffold},
     },
 };
 use core::str;
@@ -28,6 +31,13 @@ pub struct PluginListScreen {
     cache: Cache,
     fuzzy_searcher: FuzzySearcher,
     item_indices: Vec<usize>,
+    highlights: Vec<Vec<usize>>,
+    filter: PluginFilter,
+    filter_indicator: Option<String>,
+    // Plugin metadata by original index, for filtering outside of `handle_event`
+    plugins: Vec<Metadata>,
+    query: String,
+    search_options: SearchOptions,
 }
 
 impl PluginListScreen {
@@ -39,6 +49,12 @@ impl PluginListScreen {
             cache: Cache::default(),
             fuzzy_searcher: FuzzySearcher::default(),
             item_indices: Vec::new(),
+            highlights: Vec::new(),
+            filter: PluginFilter::default(),
+            filter_indicator: None,
+            plugins: Vec::new(),
+            query: String::new(),
+            search_options: SearchOptions::default(),
         };
 
         plugin_list_screen.selectable_list.select(0);
@@ -52,6 +68,56 @@ impl PluginListScreen {
             .copied()
     }
 
+    /// Applies the query and the plugin filter to the plugin list
+    fn search(&mut self) {
+        let platform = current_platform();
+        let shown = |idx: &usize| self.filter.matches(&self.plugins[*idx], platform);
+
+        let mut indices: Vec<usize> = self
+            .fuzzy_searcher
+            .search(&self.cache.plugin_names, &self.query)
+            .into_iter()
+            .filter(shown)
+            .collect();
+        // Fuzzy matching the name misses plugins found by what they do
+        if self.search_options.mode == SearchMode::Fuzzy {
+            let description_matches: Vec<usize> = (0..self.plugins.len())
+                .filter(shown)
+                .filter(|idx| !indices.contains(idx))
+                .filter(|&idx| {
+                    matches_text(
+                        &self.plugins[idx],
+                        &self.query,
+                        self.search_options.case_sensitive,
+                    )
+                })
+                .collect();
+            indices.extend(description_matches);
+        }
+        self.item_indices = indices;
+
+        let names: Vec<&String> = self
+            .item_indices
+            .iter()
+            .map(|&idx| &self.cache.plugin_names[idx])
+            .collect();
+        self.highlights = self.fuzzy_searcher.highlights(&names, &self.query);
+
+        if !self.item_indices.is_empty() {
+            self.selectable_list.select_first();
+        }
+    }
+
+    fn move_cursor(&mut self, movement: ListMovement, app: &App) {
+        self.selectable_list.move_selection(
+            movement,
+            self.item_indices.len(),
+            app.config.list_wrap,
+        );
+        self.preview.reset_scroll();
+        self.update_preview(app);
+    }
+
     fn update_preview(&mut self, app: &App) {
         let Some(original_idx) = self.original_index() else {
             return;
@@ -99,28 +165,30 @@ impl Screen<PluginPayload> for PluginListScreen {
             .iter()
             .map(|p| format!("{} {}", p.metadata.icon, p.metadata.name))
             .collect();
-        self.item_indices = (0..self.cache.plugin_names.len()).collect();
+        self.plugins = app.plugins.iter().map(|p| p.metadata.clone()).collect();
+        self.search();
         self.selectable_list.select(0);
         self.update_preview(app);
     }
 
     fn on_exit(&mut self) {
         self.cache.previews.clear();
+        self.plugins.clear();
+        self.query.clear();
         self.item_indices.clear();
+        self.highlights.clear();
         self.selectable_list.reset_selected();
     }
 
     fn handle_event(&mut self, event: InputEvent, app: &App, _payload: &PluginPayload) -> Intent {
         match event {
-            InputEvent::NextItem => {
-                self.selectable_list.select_next();
-                self.preview.reset_scroll();
-                self.update_preview(app);
+            InputEvent::NextItem => self.move_cursor(ListMovement::Down(1), app),
+            InputEvent::PreviousItem => self.move_cursor(ListMovement::Up(1), app),
+            InputEvent::PageDown => {
+                self.move_cursor(ListMovement::Down(self.selectable_list.page_height()), app);
             }
-            InputEvent::PreviousItem => {
-                self.selectable_list.select_previous();
-                self.preview.reset_scroll();
-                self.update_preview(app);
+            InputEvent::PageUp => {
+                self.move_cursor(ListMovement::Up(self.selectable_list.page_height()), app);
             }
             InputEvent::ScrollPreviewUp => {
                 self.preview
@@ -133,6 +201,14 @@ impl Screen<PluginPayload> for PluginListScreen {
             InputEvent::TogglePreview => {
                 self.show_preview = !self.show_preview;
             }
+            InputEvent::CyclePluginFilter => {
+                self.filter = self.filter.next();
+                self.filter_indicator =
+                    (self.filter != PluginFilter::All).then(|| self.filter.to_string());
+                self.search();
+                self.preview.reset_scroll();
+                self.update_preview(app);
+            }
             InputEvent::Confirm => {
                 if let Some(original_idx) = self.original_index()
                     && app.get_plugin(original_idx).is_some()
@@ -165,11 +241,13 @@ impl Screen<PluginPayload> for PluginListScreen {
                 frame,
                 area,
                 &styles.screen_scaffold_style,
+                &styles.preview,
                 |frame, left, right| -> () {
                     self.selectable_list.render(
                         frame,
                         left,
                         &items,
+                        &self.highlights,
                         &styles.list,
                         &styles.colors,
                         None,
@@ -178,6 +256,7 @@ impl Screen<PluginPayload> for PluginListScreen {
                         frame,
                         right,
                         preview,
+                        None,
                         &self.cache.title,
                         &styles.preview,
                         &styles.colors,
@@ -185,8 +264,15 @@ impl Screen<PluginPayload> for PluginListScreen {
                 },
             );
         } else {
-            self.selectable_list
-                .render(frame, area, &items, &styles.list, &styles.colors, None);
+            self.selectable_list.render(
+                frame,
+                area,
+                &items,
+                &self.highlights,
+                &styles.list,
+                &styles.colors,
+                None,
+            );
         }
     }
 
@@ -194,11 +280,14 @@ impl Screen<PluginPayload> for PluginListScreen {
         &mut self.cache.status
     }
 
-    fn on_search(&mut self, query: &str) {
-        self.item_indices = self.fuzzy_searcher.search(&self.cache.plugin_names, query);
+    fn get_status_indicator(&self) -> Option<&str> {
+        self.filter_indicator.as_deref()
+    }
 
-        if !self.item_indices.is_empty() {
-            self.selectable_list.select_first();
-        }
+    fn on_search(&mut self, query: &str, options: SearchOptions) {
+        self.fuzzy_searcher.set_options(options);
+        self.search_options = options;
+        self.query = query.to_string();
+        self.search();
     }
 }
@@ -1,23 +1,33 @@
 use mlua::Lua;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::{cell::RefCell, rc::Rc, sync::Arc};
 use tokio::{runtime::Handle as RuntimeHandle, sync::Mutex};
 
 use crate::{
     app::App,
-    execution::{ExecutionResult, Handle, Operation, State},
-    plugins::Task,
+    execution::{ExecutionResult, Handle, JobRegistry, Operation, State},
+    plugins::{Mode, Task},
     tui::{
-        events::InputEvent,
-        fuzzy_searcher::FuzzySearcher,
+        events::{InputEvent, KEY_NO, KEY_YES},
+        fuzzy_searcher::{FuzzySearcher, SearchOptions},
         navigation::{Intent, TaskPayload},
         screens::{Screen, Status},
-        strings::ModalStrings,
-        views::{Modal, ModalDialog, Preview, SelectableList, Styles, render_screen_scaffold},
+        strings::{JobStrings, ModalStrings},
+        views::{
+            ListMovement, Modal, ModalDialog, Preview, SelectableList, Styles,
+            render_screen_scaffold,
+        },
     },
 };
 use ratatui::{Frame, layout::Rect};
 
+/// A row of the task list: either a non-selectable group header or an index into `task_keys`
+#[derive(Clone)]
+enum TaskRow {
+    Header(String),
+    Task(usize),
+}
+
 #[derive(Default)]
 struct Cache {
     status: Status,
@@ -31,12 +41,19 @@ pub struct TaskListScreen {
     preview: Preview,
     show_preview: bool,
     task_keys: Vec<String>,
+    /// Shown and searched instead of `task_keys`, the key with its first alias
+    task_labels: Vec<String>,
+    /// First lines of the task descriptions, empty when `show_task_description` is off
+    task_descriptions: Vec<String>,
     cache: Cache,
     fuzzy_searcher: FuzzySearcher,
-    items_indices: Vec<usize>,
+    grouped_rows: Vec<TaskRow>,
+    rows: Vec<TaskRow>,
+    highlights: Vec<Vec<usize>>,
     modal: Modal,
     modal_content: Option<String>,
     execution_handle: Handle,
+    job_registry: Rc<RefCell<JobRegistry>>,
     modal_dialog: ModalDialog,
     modal_dialog_shown: bool,
 }
@@ -45,6 +62,7 @@ impl TaskListScreen {
     pub fn new(
         runtime_handle: RuntimeHandle,
         lua_runtime: &Arc<Mutex<Lua>>,
+        job_registry: &Rc<RefCell<JobRegistry>>,
         show_preview_pane: bool,
     ) -> Self {
         Self {
@@ -52,21 +70,68 @@ impl TaskListScreen {
             preview: Preview::default(),
             show_preview: show_preview_pane,
             task_keys: Vec::new(),
+            task_labels: Vec::new(),
+            task_descriptions: Vec::new(),
             cache: Cache::default(),
             fuzzy_searcher: FuzzySearcher::default(),
-            items_indices: Vec::new(),
+            grouped_rows: Vec::new(),
+            rows: Vec::new(),
+            highlights: Vec::new(),
             modal: Modal::default(),
             modal_content: None,
             execution_handle: Handle::new(runtime_handle.clone(), lua_runtime),
+            job_registry: Rc::clone(job_registry),
             modal_dialog: ModalDialog::default(),
             modal_dialog_shown: false,
         }
     }
 
     fn original_index(&self) -> Option<usize> {
-        self.items_indices
-            .get(self.selectable_list.selected())
-            .copied()
+        match self.rows.get(self.selectable_list.selected()) {
+            Some(TaskRow::Task(idx)) => Some(*idx),
+            _ => None,
+        }
+    }
+
+    // Shows the descriptions of the tasks in `rows` under their names, headers get none
+    fn sync_subtitles(&mut self) {
+        if self.task_descriptions.is_empty() {
+            self.selectable_list.set_subtitles(Vec::new());
+            return;
+        }
+        let subtitles = self
+            .rows
+            .iter()
+            .map(|row| match row {
+                TaskRow::Header(_) => String::new(),
+                TaskRow::Task(idx) => self.task_descriptions[*idx].clone(),
+            })
+            .collect();
+        self.selectable_list.set_subtitles(subtitles);
+    }
+
+    fn move_cursor(&mut self, movement: ListMovement, app: &App, payload: &TaskPayload) {
+        let wrap = app.config.list_wrap;
+        self.selectable_list
+            .move_selection(movement, self.rows.len(), wrap);
+        self.skip_header(matches!(movement, ListMovement::Down(_)), wrap);
+        self.preview.reset_scroll();
+        self.update_preview(app, payload);
+    }
+
+    /// Moves the selection off a group header, in the direction of travel when possible. With
+    /// `wrap`, moving up onto the leading header continues at the last row
+    fn skip_header(&mut self, forward: bool, wrap: bool) {
+        let selected = self.selectable_list.selected();
+        if matches!(self.rows.get(selected), Some(TaskRow::Header(_))) {
+            if !forward && selected == 0 && wrap {
+                self.selectable_list.select(self.rows.len() - 1);
+            } else if forward || selected == 0 {
+                self.selectable_list.select(selected + 1);
+            } else {
+                self.selectable_list.select(selected - 1);
+            }
+        }
     }
 
     fn update_preview(&mut self, app: &App, payload: &TaskPayload) {
@@ -88,22 +153,71 @@ impl TaskListScreen {
         }
     }
 
-    fn execute(&mut self, task: &Arc<Task>) {
+    fn execute(&mut self, task: &Arc<Task>) -> Intent {
+        if task.mode == Mode::Background {
+            return match self
+                .job_registry
+                .borrow_mut()
+                .spawn(Arc::clone(task), vec![])
+            {
+                Ok(_) if task.suppress_success_notification => Intent::None,
+                Ok(job_id) => Intent::ShowToast {
+                    message: format!("{} #{}", JobStrings::STARTED, job_id),
+                    exit_code: None,
+                },
+                Err(e) => {
+                    self.modal_content = Some(format!("{:#}", e));
+                    Intent::None
+                }
+            };
+        }
         let _ = self.execution_handle.execute(Operation::Execute {
             task: Arc::clone(task),
             selected_items: vec![],
         });
+        Intent::None
     }
 }
 
 impl Screen<TaskPayload> for TaskListScreen {
     fn on_enter(&mut self, app: &App, payload: &TaskPayload) {
         if let Some(plugin) = app.get_plugin(payload.plugin_idx) {
-            self.task_keys = plugin.tasks.keys().cloned().collect();
-            // Sort task keys alphabetically (case-insensitive) for consistent display order
-            self.task_keys.sort_by_key(|a| a.to_lowercase());
-            self.items_indices = (0..self.task_keys.len()).collect();
+            self.task_keys.clear();
+            self.task_labels.clear();
+            self.task_descriptions.clear();
+            self.grouped_rows.clear();
+            for (group, tasks) in plugin.tasks_grouped() {
+                if let Some(group) = group {
+                    self.grouped_rows
+                        .push(TaskRow::Header(format!("── {} ──", group)));
+                }
+                for (task_key, task) in tasks {
+                    self.grouped_rows.push(TaskRow::Task(self.task_keys.len()));
+                    self.task_keys.push(task_key.to_string());
+                    let label = match task.aliases.first() {
+                        Some(alias) => format!("{} ({})", task_key, alias),
+                        None => task_key.to_string(),
+                    };
+                    let icon = if task.icon.is_empty() {
+                        &app.config.default_task_icon
+                    } else {
+                        &task.icon
+                    };
+                    self.task_labels.push(if icon.is_empty() {
+                        label
+                    } else {
+                        format!("{} {}", icon, label)
+                    });
+                    if app.config.show_task_description {
+                        self.task_descriptions
+                            .push(task.description.lines().next().unwrap_or("").to_string());
+                    }
+                }
+            }
+            self.rows = self.grouped_rows.clone();
+            self.sync_subtitles();
             self.selectable_list.select(0);
+            self.skip_header(true, false);
             self.update_preview(app, payload);
         }
         if let Some(original_idx) = self.original_index()
@@ -113,16 +227,22 @@ impl Screen<TaskPayload> for TaskListScreen {
         {
             self.modal_dialog.configure(
                 confirmation_message.clone(),
-                app.config.keybindings.confirm.clone(),
-                app.config.keybindings.back.clone(),
+                format!("{}/{}", KEY_YES, app.config.keybindings.confirm),
+                format!("{}/{}", KEY_NO, app.config.keybindings.back),
             );
         };
-        self.modal.configure(app.config.keybindings.confirm.clone());
+        self.modal
+            .configure(app.config.keybindings.confirm.to_string());
     }
 
     fn on_exit(&mut self) {
         self.cache.previews.clear();
         self.task_keys.clear();
+        self.task_labels.clear();
+        self.task_descriptions.clear();
+        self.grouped_rows.clear();
+        self.rows.clear();
+        self.sync_subtitles();
         self.selectable_list.reset_selected();
         self.modal_content = None;
         self.modal_dialog_shown = false;
@@ -130,20 +250,25 @@ impl Screen<TaskPayload> for TaskListScreen {
 
     fn on_update(&mut self, app: &App, payload: &TaskPayload) -> Intent {
         match self.execution_handle.consume_result() {
-            ExecutionResult::Output(output, exit_code) => {
+            ExecutionResult::Output(output, exit_code, _) => {
                 if app.config.exit_on_execute {
                     return Intent::Quit;
+                } else if exit_code > 0 {
+                    self.modal_content = Some(output);
                 } else {
-                    let should_show_modal = if let Some(original_idx) = self.original_index()
+                    let suppress_notification = if let Some(original_idx) = self.original_index()
                         && let Some(selected_task_key) = self.task_keys.get(original_idx)
                         && let Some(task) = app.get_task(payload.plugin_idx, selected_task_key)
                     {
-                        !task.suppress_success_notification || exit_code > 0
+                        task.suppress_success_notification
                     } else {
-                        exit_code > 0
+                        false
                     };
-                    if should_show_modal {
-                        self.modal_content = Some(output);
+                    if !suppress_notification {
+                        return Intent::ShowToast {
+                            message: output,
+                            exit_code: Some(exit_code),
+                        };
                     }
                 }
             }
@@ -188,7 +313,7 @@ impl Screen<TaskPayload> for TaskListScreen {
                     {
                         self.modal_dialog.reset_scroll();
                         self.modal_dialog_shown = false;
-                        self.execute(task);
+                        return self.execute(task);
                     }
                 }
                 InputEvent::ScrollPreviewUp => {
@@ -207,15 +332,21 @@ impl Screen<TaskPayload> for TaskListScreen {
             return Intent::None;
         }
         match event {
-            InputEvent::NextItem => {
-                self.selectable_list.select_next();
-                self.preview.reset_scroll();
-                self.update_preview(app, payload);
+            InputEvent::NextItem => self.move_cursor(ListMovement::Down(1), app, payload),
+            InputEvent::PreviousItem => self.move_cursor(ListMovement::Up(1), app, payload),
+            InputEvent::PageDown => {
+                self.move_cursor(
+                    ListMovement::Down(self.selectable_list.page_height()),
+                    app,
+                    payload,
+                );
             }
-            InputEvent::PreviousItem => {
-                self.selectable_list.select_previous();
-                self.preview.reset_scroll();
-                self.update_preview(app, payload);
+            InputEvent::PageUp => {
+                self.move_cursor(
+                    ListMovement::Up(self.selectable_list.page_height()),
+                    app,
+                    payload,
+                );
             }
             InputEvent::ScrollPreviewUp => {
                 self.preview
@@ -237,7 +368,7 @@ impl Screen<TaskPayload> for TaskListScreen {
                     if task.execution_confirmation_message.is_some() {
                         self.modal_dialog_shown = true;
                     } else {
-                        self.execute(task);
+                        return self.execute(task);
                     }
                 } else if let Some(original_idx) = self.original_index()
                     && let Some(selected_task_key) = self.task_keys.get(original_idx)
@@ -255,9 +386,12 @@ impl Screen<TaskPayload> for TaskListScreen {
 
     fn render(&mut self, frame: &mut Frame, area: Rect, styles: &Styles) {
         let items: Vec<&String> = self
-            .items_indices
+            .rows
             .iter()
-            .map(|&idx| &self.task_keys[idx])
+            .map(|row| match row {
+                TaskRow::Header(label) => label,
+                TaskRow::Task(idx) => &self.task_labels[*idx],
+            })
             .collect();
 
         if self.show_preview {
@@ -272,11 +406,13 @@ impl Screen<TaskPayload> for TaskListScreen {
                 frame,
                 area,
                 &styles.screen_scaffold_style,
+                &styles.preview,
                 |frame, left, right| -> () {
                     self.selectable_list.render(
                         frame,
                         left,
                         &items,
+                        &self.highlights,
                         &styles.list,
                         &styles.colors,
                         None,
@@ -285,6 +421,7 @@ impl Screen<TaskPayload> for TaskListScreen {
                         frame,
                         right,
                         preview,
+                        None,
                         &self.cache.title,
                         &styles.preview,
                         &styles.colors,
@@ -292,8 +429,15 @@ impl Screen<TaskPayload> for TaskListScreen {
                 },
             );
         } else {
-            self.selectable_list
-                .render(frame, area, &items, &styles.list, &styles.colors, None);
+            self.selectable_list.render(
+                frame,
+                area,
+                &items,
+                &self.highlights,
+                &styles.list,
+                &styles.colors,
+                None,
+            );
         }
 
         if let Some(content) = &self.modal_content {
@@ -333,13 +477,38 @@ impl Screen<TaskPayload> for TaskListScreen {
         &mut self.cache.status
     }
 
-    fn on_search(&mut self, query: &str) {
-        self.items_indices = self.fuzzy_searcher.search(&self.task_keys, query);
-        if !self.items_indices.is_empty() {
+    fn on_search(&mut self, query: &str, options: SearchOptions) {
+        self.fuzzy_searcher.set_options(options);
+        // Group headers only make sense in the unfiltered list; search results are ranked
+        self.rows = if query.is_empty() {
+            self.grouped_rows.clone()
+        } else {
+            self.fuzzy_searcher
+                .search(&self.task_labels, query)
+                .into_iter()
+                .map(TaskRow::Task)
+                .collect()
+        };
+        self.sync_subtitles();
+        let task_labels: Vec<&String> = self
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                TaskRow::Task(idx) => Some(&self.task_labels[*idx]),
+                TaskRow::Header(_) => None,
+            })
+            .collect();
+        self.highlights = self.fuzzy_searcher.highlights(&task_labels, query);
+        if !self.rows.is_empty() {
             self.selectable_list.select_first();
+            self.skip_header(true, false);
         }
     }
     fn consumed_event(&mut self, event: &InputEvent) -> bool {
         matches!(event, InputEvent::Back) && self.modal_dialog_shown
     }
+
+    fn confirmation_shown(&self) -> bool {
+        self.modal_dialog_shown
+    }
 }
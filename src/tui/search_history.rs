@@ -0,0 +1,133 @@
+use std::{collections::VecDeque, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Queries kept when the config doesn't limit the history
+pub const DEFAULT_SEARCH_HISTORY_SIZE: usize = 100;
+
+/// Recent search queries, oldest first, dropping the oldest one beyond `capacity`.
+///
+/// [`SearchHistory::older`] and [`SearchHistory::newer`] walk through the queries like a
+/// shell history. The query typed before walking is kept as the draft and returned when
+/// walking past the newest entry.
+#[derive(Debug)]
+pub struct SearchHistory {
+    capacity: usize,
+    entries: VecDeque<String>,
+    // Index of the recalled entry, None while not walking the history
+    cursor: Option<usize>,
+    draft: String,
+}
+
+impl Default for SearchHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEARCH_HISTORY_SIZE)
+    }
+}
+
+impl SearchHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: VecDeque::new(),
+            cursor: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Reads the history written by [`SearchHistory::save`], one query per line. A missing
+    /// file is an empty history.
+    pub fn load(path: &Path, capacity: usize) -> Result<Self> {
+        let mut history = Self::new(capacity);
+        if !path.exists() {
+            return Ok(history);
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read search history at {}", path.display()))?;
+        for line in content.lines() {
+            history.push(line);
+        }
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+
+        let mut content = String::new();
+        for entry in &self.entries {
+            content.push_str(entry);
+            content.push('\n');
+        }
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write search history to {}", path.display()))
+    }
+
+    /// Adds `query` as the newest entry and stops walking the history. Blank queries and
+    /// repeats of the newest entry are not added.
+    ///
+    /// Returns whether the history changed.
+    pub fn push(&mut self, query: &str) -> bool {
+        self.reset();
+
+        let query = query.trim();
+        if query.is_empty() || self.entries.back().is_some_and(|last| last == query) {
+            return false;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(query.to_string());
+        true
+    }
+
+    /// Recalls the entry before the current one, starting with the newest. `current` is kept
+    /// as the draft when the walk starts. Stays on the oldest entry.
+    pub fn older(&mut self, current: &str) -> Option<&str> {
+        let index = match self.cursor {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Recalls the entry after the current one, or the draft when walking past the newest
+    /// entry. None while not walking the history.
+    pub fn newer(&mut self) -> Option<&str> {
+        let index = self.cursor?;
+        if index + 1 < self.entries.len() {
+            self.cursor = Some(index + 1);
+            self.entries.get(index + 1).map(String::as_str)
+        } else {
+            self.cursor = None;
+            Some(self.draft.as_str())
+        }
+    }
+
+    /// Stops walking the history, the next [`SearchHistory::older`] starts at the newest
+    /// entry again
+    pub fn reset(&mut self) {
+        self.cursor = None;
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
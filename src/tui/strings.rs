@@ -7,12 +7,34 @@ impl StatusStrings {
     pub const COMPLETE: &str = "Complete";
 }
 
+pub struct SortStrings;
+
+impl SortStrings {
+    pub const SOURCE_ORDER: &str = "Source order";
+    pub const ALPHABETICAL: &str = "A-Z";
+    pub const REVERSE_ALPHABETICAL: &str = "Z-A";
+}
+
+pub struct FilterStrings;
+
+impl FilterStrings {
+    pub const ALL: &str = "All plugins";
+    pub const CURRENT_PLATFORM: &str = "This platform";
+}
+
 pub struct RouteStrings;
 
 impl RouteStrings {
     pub const PLUGIN: &str = "Plugin";
     pub const TASK: &str = "Task";
     pub const ITEM: &str = "Item";
+    pub const JOBS: &str = "Jobs";
+}
+
+pub struct ItemListStrings;
+
+impl ItemListStrings {
+    pub const LOADING: &str = "Loading items...";
 }
 
 pub struct PreviewStrings;
@@ -24,6 +46,13 @@ impl PreviewStrings {
     pub const PLATFORMS: &str = "Platforms";
     pub const DESCRIPTION: &str = "Description";
     pub const TASKS: &str = "Tasks";
+    pub const TASK: &str = "Task";
+    pub const STATE: &str = "State";
+    pub const ITEMS: &str = "Items";
+    pub const OUTPUT: &str = "Output";
+    pub const NO_JOBS: &str = "No background jobs";
+    pub const WRAP: &str = "wrap";
+    pub const SCROLL: &str = "scroll";
 }
 
 pub struct ModalStrings;
@@ -31,7 +60,52 @@ pub struct ModalStrings;
 impl ModalStrings {
     pub const TITLE_MODAL_RESULT: &str = "Task result";
     pub const TITLE_MODAL_DIALOG_CONFIRM: &str = "Confirm execution";
+    pub const TITLE_MODAL_DIALOG_QUESTION: &str = "Question";
+    pub const TITLE_MODAL_DIALOG_INPUT: &str = "Input";
+    pub const TITLE_TOAST: &str = "Notification";
+    pub const LABEL_EXIT_CODE: &str = "exit code";
     pub const LABEL_BUTTON_CONFIRM: &str = "Confirm";
     pub const LABEL_BUTTON_DISMISS: &str = "Dismiss";
     pub const LABEL_BUTTON_CANCEL: &str = "Cancel";
+    pub const PLUGINS_RELOADED: &str = "Plugins reloaded";
+    pub const PLUGIN_RELOAD_FAILED: &str = "Failed to reload plugins";
+}
+
+pub struct ContextMenuStrings;
+
+impl ContextMenuStrings {
+    pub const PREVIEW: &str = "Preview";
+    pub const SELECT: &str = "Select";
+}
+
+pub struct JobStrings;
+
+impl JobStrings {
+    pub const RUNNING: &str = "running";
+    pub const FINISHED: &str = "finished";
+    pub const FAILED: &str = "failed";
+    pub const KILLED: &str = "killed";
+    pub const STARTED: &str = "Started background job";
+}
+
+pub struct QuickActionStrings;
+
+impl QuickActionStrings {
+    pub const RUNNING: &str = "Running";
+    pub const ALREADY_RUNNING: &str = "A quick action is already running";
+}
+
+pub struct ClipboardStrings;
+
+impl ClipboardStrings {
+    pub const COPIED_ITEM: &str = "Copied item to clipboard";
+    pub const COPIED_PREVIEW: &str = "Copied preview to clipboard";
+    pub const NO_ITEM: &str = "Nothing to copy";
+    pub const NO_PREVIEW: &str = "Preview is not loaded yet";
+}
+
+pub struct ItemRefreshStrings;
+
+impl ItemRefreshStrings {
+    pub const REFRESHED: &str = "⟳";
 }
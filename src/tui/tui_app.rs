@@ -1,19 +1,31 @@
 use crate::{
     app::App,
-    execution::clamp_exit_code,
+    configs::{PluginWatcher, get_default_data_dir},
+    execution::{EXIT_FAILURE, JobRegistry, clamp_exit_code},
+    plugins::{PluginLoadStatus, load_plugins_with_report},
     tui::{
-        ExternalTuiRequest, TuiRequestReceiver, create_tui_channel,
-        dispatcher::ScreenDispatcher,
-        events::{InputEvent, handle_key},
+        ExternalTuiRequest, TuiRequestReceiver,
+        color_mode::color_mode_from_env,
+        create_tui_channel,
+        dispatcher::{QuickActionRunner, ScreenDispatcher},
+        events::{InputEvent, KEY_NO, KEY_YES, handle_key, handle_yes_no_key},
         key_bindings::ParsedKeyBindings,
-        navigation::{Intent, ItemPayload, Navigator, PluginPayload, Route, TaskPayload},
+        navigation::{
+            Intent, ItemPayload, JobsPayload, Navigator, PluginPayload, Route, TaskPayload,
+        },
+        panic_guard::catch_panic,
         run_tui_command_blocking,
-        screens::{ItemListScreen, PluginListScreen, TaskListScreen},
+        screens::{ItemListScreen, JobListScreen, PluginListScreen, TaskListScreen},
+        search_history::{DEFAULT_SEARCH_HISTORY_SIZE, SearchHistory},
         set_tui_sender,
-        views::{SearchBar, StatusBar, Styles},
+        strings::ModalStrings,
+        views::{
+            ModalDialog, SearchBar, StatusBar, Styles, ToastNotification, ToastQueue,
+            syntax_highlight::preload_syntaxes,
+        },
     },
 };
-use anyhow::{Context, Result, ensure};
+use anyhow::{Context, Result, bail, ensure};
 use crossterm::{
     cursor::Show,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
@@ -28,16 +40,45 @@ use ratatui::{
     widgets::Block,
 };
 use std::{
+    cell::RefCell,
     io,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
-use tokio::runtime::Handle as RuntimeHandle;
+use tokio::{
+    runtime::Handle as RuntimeHandle,
+    sync::{oneshot, watch},
+};
+use tracing::warn;
+use tui_input::{Input, backend::crossterm::EventHandler};
 
 const SECOND_IN_MILLIS: u64 = 1000;
 const RENDER_FPS: u64 = 30;
 const ANIMATION_KEY_FRAMES_PER_SECOND: u64 = 10;
 const MILLIS_PER_KEYFRAME: u64 = SECOND_IN_MILLIS / ANIMATION_KEY_FRAMES_PER_SECOND;
 const BAR_HEIGHT: u16 = 3;
+const SEARCH_HISTORY_FILE: &str = "search_history";
+
+/// Lua request waiting for the user to answer an input dialog
+enum PendingInput {
+    Confirm {
+        response: oneshot::Sender<bool>,
+    },
+    Prompt {
+        input: Input,
+        default: String,
+        response: oneshot::Sender<String>,
+    },
+}
+
+/// Plugin directories reloaded whenever the watcher reports a `.lua` change
+struct PluginReload {
+    plugin_paths: Vec<PathBuf>,
+    changes: watch::Receiver<u64>,
+    _watcher: PluginWatcher,
+}
 
 pub struct TuiApp {
     app: App,
@@ -49,37 +90,63 @@ pub struct TuiApp {
     status_bar: StatusBar,
     search_bar: SearchBar,
     tui_rx: TuiRequestReceiver,
+    job_registry: Rc<RefCell<JobRegistry>>,
+    input_dialog: ModalDialog,
+    pending_input: Option<PendingInput>,
+    toasts: ToastQueue,
+    plugin_reload: Option<PluginReload>,
 }
 
 impl TuiApp {
     pub fn new(app: App, runtime_handle: RuntimeHandle) -> Result<Self> {
         let keybindings = ParsedKeyBindings::from(&app.config.keybindings)?;
 
-        let initial_route = Self::resolve_initial_route(&app)?;
-        let route_name = Self::get_route_name(&initial_route, &app);
+        let mut initial_routes = Self::resolve_initial_routes(&app)?.into_iter();
+        let root_route = initial_routes
+            .next()
+            .context("Initial navigation stack is empty")?;
+        let root_name = Self::get_route_name(&root_route, &app);
 
-        let navigator = Navigator::new(
-            initial_route,
-            route_name,
+        let mut navigator = Navigator::new(
+            root_route,
+            root_name,
             app.config.styles.status.breadcrumbs_separator.clone(),
         );
-        let styles = Styles::try_from(&app.config.styles)?;
+        for route in initial_routes {
+            let route_name = Self::get_route_name(&route, &app);
+            navigator.push(route, route_name);
+        }
+        let styles = Styles::try_from(&app.config.styles)?
+            .with_color_mode(color_mode_from_env(app.config.force_color));
+        let job_registry = Rc::new(RefCell::new(JobRegistry::new(
+            runtime_handle.clone(),
+            &app.lua_runtime,
+        )));
         let screen_dispatcher = ScreenDispatcher {
             plugin_screen: PluginListScreen::new(app.config.show_preview_pane),
             task_screen: TaskListScreen::new(
                 runtime_handle.clone(),
                 &app.lua_runtime,
+                &job_registry,
                 app.config.show_preview_pane,
             ),
             item_screen: ItemListScreen::new(
                 runtime_handle.clone(),
                 &app.lua_runtime,
+                &job_registry,
                 app.config.show_preview_pane,
+                app.config.preview_cache_size,
+            ),
+            job_screen: JobListScreen::new(&job_registry, app.config.show_preview_pane),
+            quick_actions: QuickActionRunner::new(
+                runtime_handle.clone(),
+                &app.lua_runtime,
+                &job_registry,
             ),
         };
 
         let status_bar = StatusBar::default();
-        let search_bar = SearchBar::default();
+        let search_bar = Self::create_search_bar(&app);
 
         // Create TUI command channel for external TUI applications (editors, file managers, etc.)
         let (tui_tx, tui_rx) = create_tui_channel();
@@ -97,16 +164,49 @@ impl TuiApp {
             status_bar,
             search_bar,
             tui_rx,
+            job_registry,
+            input_dialog: ModalDialog::default(),
+            pending_input: None,
+            toasts: ToastQueue::default(),
+            plugin_reload: None,
         })
     }
 
+    /// Reloads the plugins from `plugin_paths` whenever `watcher` reports a change
+    pub fn with_plugin_reload(
+        mut self,
+        watcher: PluginWatcher,
+        changes: watch::Receiver<u64>,
+        plugin_paths: Vec<PathBuf>,
+    ) -> Self {
+        self.plugin_reload = Some(PluginReload {
+            plugin_paths,
+            changes,
+            _watcher: watcher,
+        });
+        self
+    }
+
     pub fn run(&mut self) -> Result<()> {
+        preload_syntaxes();
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
+        // A panic while rendering or handling events would leave the terminal in raw mode,
+        // it is restored before the panic is reported
+        match catch_panic(|| self.run_loop(&mut terminal)) {
+            Ok(result) => result,
+            Err(message) => {
+                restore_terminal();
+                bail!("Syntropy crashed: {}", message)
+            }
+        }
+    }
+
+    fn run_loop(&mut self, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
         self.screen_dispatcher
             .on_enter(self.navigator.current(), &self.app);
 
@@ -122,6 +222,7 @@ impl TuiApp {
             }
             let screen_chunk = if self.app.config.search_bar { 1 } else { 0 };
             let status_bar_chunk = if self.app.config.search_bar { 2 } else { 1 };
+            let input_dialog_content = self.input_dialog_content();
 
             terminal.draw(|frame| {
                 let background_block =
@@ -146,11 +247,25 @@ impl TuiApp {
                     frame,
                     &self.styles,
                 );
+                if let Some(toast) = self.toasts.current() {
+                    ToastNotification::render(
+                        frame,
+                        chunks[screen_chunk],
+                        toast,
+                        &self.styles.modal,
+                        &self.styles.colors,
+                    );
+                }
+                let status_indicator = self
+                    .screen_dispatcher
+                    .get_status_indicator(self.navigator.current())
+                    .map(str::to_string);
                 let status = self.screen_dispatcher.get_status(self.navigator.current());
                 if self.app.config.status_bar {
                     self.status_bar.render(
                         frame,
                         status,
+                        status_indicator.as_deref(),
                         breadcrumbs,
                         get_key_frame(),
                         chunks[status_bar_chunk],
@@ -158,12 +273,59 @@ impl TuiApp {
                         &self.styles.colors,
                     );
                 }
+                if let Some((title, item)) = &input_dialog_content {
+                    self.input_dialog.render(
+                        frame,
+                        frame.area(),
+                        title,
+                        item,
+                        &self.styles.modal,
+                        &self.styles.colors,
+                    );
+                }
             })?;
             self.update_screens();
+            self.reload_changed_plugins();
 
             // Check for external TUI requests (imperative: handle immediately)
-            if let Ok(request) = self.tui_rx.try_recv() {
-                self.suspend_and_run_tui(request, &mut terminal)?;
+            // Input dialogs are answered one at a time, so hold further requests until then
+            if self.pending_input.is_none()
+                && let Ok(request) = self.tui_rx.try_recv()
+            {
+                match request {
+                    ExternalTuiRequest::Command {
+                        command,
+                        args,
+                        response,
+                    } => {
+                        self.suspend_and_run_tui(&command, &args, response, terminal)?;
+                    }
+                    ExternalTuiRequest::Confirm { message, response } => {
+                        self.show_input_dialog(message, PendingInput::Confirm { response });
+                    }
+                    ExternalTuiRequest::Log { level, message } => {
+                        // Without a status bar the message shows as a toast instead
+                        if self.app.config.status_bar {
+                            self.status_bar.show_log(level, message);
+                        } else {
+                            self.toasts.push(format!("[{}] {}", level, message), None);
+                        }
+                    }
+                    ExternalTuiRequest::Prompt {
+                        message,
+                        default,
+                        response,
+                    } => {
+                        self.show_input_dialog(
+                            message,
+                            PendingInput::Prompt {
+                                input: Input::default(),
+                                default,
+                                response,
+                            },
+                        );
+                    }
+                }
                 continue; // Skip poll_events, go straight to next render
             }
 
@@ -182,33 +344,166 @@ impl TuiApp {
             SECOND_IN_MILLIS.div_euclid(RENDER_FPS),
         ))? {
             let event = event::read()?;
-            if self.app.config.search_bar && self.search_bar.handle_event(&event) {
-                self.screen_dispatcher
-                    .on_search(self.navigator.current(), self.search_bar.value());
+            if self.pending_input.is_some() {
+                self.handle_input_dialog_event(&event);
                 return Ok(());
             }
 
-            if let Event::Key(key) = event {
+            if let Event::Key(key) = event
+                && !self.toasts.is_empty()
+            {
                 if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
                     self.should_quit = true;
-                    return Ok(());
+                } else {
+                    self.toasts.dismiss();
                 }
+                return Ok(());
+            }
 
-                if let Some(input_event) = handle_key(&key, &self.keybindings) {
-                    self.handle_event(input_event);
+            // Plain y and n answer a confirmation instead of being typed into the search bar
+            if let Event::Key(key) = event
+                && self
+                    .screen_dispatcher
+                    .confirmation_shown(self.navigator.current())
+                && let Some(answer) = handle_yes_no_key(&key)
+            {
+                self.handle_event(answer);
+                return Ok(());
+            }
+
+            if self.app.config.search_bar && self.search_bar.handle_event(&event) {
+                self.screen_dispatcher.on_search(
+                    self.navigator.current(),
+                    self.search_bar.value(),
+                    self.search_bar.options(),
+                );
+                return Ok(());
+            }
+
+            match event {
+                Event::Key(key) => {
+                    if key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)
+                    {
+                        self.should_quit = true;
+                        return Ok(());
+                    }
+
+                    if let Some(input_event) = handle_key(&key, &self.keybindings) {
+                        self.handle_event(input_event);
+                    }
+                }
+                Event::Mouse(mouse) => {
+                    let intent = self.screen_dispatcher.handle_mouse(
+                        self.navigator.current(),
+                        mouse,
+                        &self.app,
+                    );
+                    self.apply_intent(intent);
                 }
+                _ => {}
             }
         }
         Ok(())
     }
 
+    fn show_input_dialog(&mut self, message: String, pending_input: PendingInput) {
+        let keybindings = &self.app.config.keybindings;
+        let (confirm_key_binding, cancel_key_binding) = match pending_input {
+            PendingInput::Confirm { .. } => (
+                format!("{}/{}", KEY_YES, keybindings.confirm),
+                format!("{}/{}", KEY_NO, keybindings.back),
+            ),
+            PendingInput::Prompt { .. } => (
+                keybindings.confirm.to_string(),
+                keybindings.back.to_string(),
+            ),
+        };
+        self.input_dialog.reset_scroll();
+        self.input_dialog
+            .configure(message, confirm_key_binding, cancel_key_binding);
+        self.pending_input = Some(pending_input);
+    }
+
+    fn input_dialog_content(&self) -> Option<(&'static str, String)> {
+        match self.pending_input.as_ref()? {
+            PendingInput::Confirm { .. } => {
+                Some((ModalStrings::TITLE_MODAL_DIALOG_QUESTION, String::new()))
+            }
+            PendingInput::Prompt { input, default, .. } => {
+                let value = if input.value().is_empty() {
+                    default.as_str()
+                } else {
+                    input.value()
+                };
+                Some((
+                    ModalStrings::TITLE_MODAL_DIALOG_INPUT,
+                    format!("\n> {}", value),
+                ))
+            }
+        }
+    }
+
+    fn handle_input_dialog_event(&mut self, event: &Event) {
+        let Event::Key(key) = event else {
+            return;
+        };
+
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.should_quit = true;
+            return;
+        }
+
+        // A prompt takes y and n as input, only a confirmation is answered by them
+        let answer = match self.pending_input {
+            Some(PendingInput::Confirm { .. }) => handle_yes_no_key(key),
+            _ => None,
+        };
+        let confirmed = self.keybindings.triggers(key, InputEvent::Confirm)
+            || answer == Some(InputEvent::Confirm);
+        let cancelled =
+            self.keybindings.triggers(key, InputEvent::Back) || answer == Some(InputEvent::Back);
+
+        if !confirmed && !cancelled {
+            if let Some(PendingInput::Prompt { input, .. }) = &mut self.pending_input {
+                input.handle_event(event);
+            }
+            return;
+        }
+
+        match self.pending_input.take() {
+            Some(PendingInput::Confirm { response }) => {
+                let _ = response.send(confirmed);
+            }
+            Some(PendingInput::Prompt {
+                input,
+                default,
+                response,
+            }) => {
+                let answer = if confirmed && !input.value().is_empty() {
+                    input.value().to_string()
+                } else {
+                    default
+                };
+                let _ = response.send(answer);
+            }
+            None => {}
+        }
+    }
+
     fn update_screens(&mut self) {
+        self.job_registry.borrow_mut().poll();
+        self.toasts.expire(Instant::now());
+        self.status_bar.expire_log(Instant::now());
+
         let intent = self
             .screen_dispatcher
             .update(self.navigator.current(), &self.app);
 
-        if matches!(intent, Intent::Quit) {
-            self.should_quit = true;
+        match intent {
+            Intent::Quit => self.should_quit = true,
+            Intent::ShowToast { message, exit_code } => self.toasts.push(message, exit_code),
+            _ => {}
         }
     }
 
@@ -233,24 +528,116 @@ impl TuiApp {
                     self.should_quit = true;
                 }
             }
-            _ => {
-                let intent =
-                    self.screen_dispatcher
-                        .handle_event(self.navigator.current(), event, &self.app);
-
-                if let Some(new_route) = self.navigator.resolve_intent(intent) {
+            InputEvent::ShowJobs => {
+                if !matches!(self.navigator.current(), Route::Jobs { .. }) {
                     self.search_bar.clear();
                     self.screen_dispatcher.on_exit(self.navigator.current());
-                    let route_name = Self::get_route_name(&new_route, &self.app);
-                    self.navigator.push(new_route, route_name);
+                    let route = Route::Jobs {
+                        payload: JobsPayload,
+                    };
+                    let route_name = Self::get_route_name(&route, &self.app);
+                    self.navigator.push(route, route_name);
                     self.screen_dispatcher
                         .on_enter(self.navigator.current(), &self.app);
                 }
             }
+            InputEvent::GoHome => self.apply_intent(Intent::GoHome),
+            InputEvent::QuickAction(index) => {
+                let intent = self.screen_dispatcher.run_quick_action(index, &self.app);
+                self.apply_intent(intent);
+            }
+            InputEvent::HistoryPrevious | InputEvent::HistoryNext if self.app.config.search_bar => {
+                let changed = if event == InputEvent::HistoryPrevious {
+                    self.search_bar.recall_previous()
+                } else {
+                    self.search_bar.recall_next()
+                };
+                if changed {
+                    self.screen_dispatcher.on_search(
+                        self.navigator.current(),
+                        self.search_bar.value(),
+                        self.search_bar.options(),
+                    );
+                }
+            }
+            InputEvent::ToggleSearchMode | InputEvent::ToggleCaseSensitive
+                if self.app.config.search_bar =>
+            {
+                if event == InputEvent::ToggleSearchMode {
+                    self.search_bar.cycle_mode();
+                } else {
+                    self.search_bar.toggle_case_sensitive();
+                }
+                self.screen_dispatcher.on_search(
+                    self.navigator.current(),
+                    self.search_bar.value(),
+                    self.search_bar.options(),
+                );
+            }
+            _ => {
+                let intent =
+                    self.screen_dispatcher
+                        .handle_event(self.navigator.current(), event, &self.app);
+                self.apply_intent(intent);
+            }
+        }
+    }
+
+    // Shows toasts and navigates to the route resolved from a screen's intent
+    fn apply_intent(&mut self, intent: Intent) {
+        if let Intent::ShowToast { message, exit_code } = intent {
+            self.toasts.push(message, exit_code);
+        } else if intent == Intent::GoHome {
+            let popped = self.navigator.back_to_root();
+            if let Some(top_stack_entry) = popped.first() {
+                self.search_bar.clear();
+                self.screen_dispatcher.on_exit(&top_stack_entry.route);
+                self.screen_dispatcher
+                    .on_enter(self.navigator.current(), &self.app);
+            }
+        } else if let Some(new_route) = self.navigator.resolve_intent(intent) {
+            self.search_bar.clear();
+            self.screen_dispatcher.on_exit(self.navigator.current());
+            let route_name = Self::get_route_name(&new_route, &self.app);
+            self.navigator.push(new_route, route_name);
+            self.screen_dispatcher
+                .on_enter(self.navigator.current(), &self.app);
         }
     }
 
-    fn resolve_initial_route(app: &App) -> Result<Route> {
+    // Search bar with the history of earlier sessions when `search_history` is on, an
+    // unreadable history file starts an empty one
+    fn create_search_bar(app: &App) -> SearchBar {
+        if !app.config.search_history {
+            return SearchBar::default();
+        }
+
+        let history_path = match get_default_data_dir() {
+            Ok(data_dir) => data_dir.join(SEARCH_HISTORY_FILE),
+            Err(e) => {
+                warn!("Search history disabled: {:#}", e);
+                return SearchBar::default();
+            }
+        };
+        let history = SearchHistory::load(&history_path, DEFAULT_SEARCH_HISTORY_SIZE)
+            .unwrap_or_else(|e| {
+                warn!("{:#}", e);
+                SearchHistory::default()
+            });
+        SearchBar::with_history(history, Some(history_path))
+    }
+
+    /// Navigation stack the TUI opens with, the last route being the screen shown first.
+    ///
+    /// Opens the plugin list, or deep-links to `default_plugin` (`--plugin`) and
+    /// `default_task` (`--task`) with the screens above them on the stack, so `back` walks up
+    /// as if the user navigated there. Fails when the plugin or task doesn't exist, before the
+    /// terminal is taken over.
+    pub fn resolve_initial_routes(app: &App) -> Result<Vec<Route>> {
+        let plugin_list = Route::Plugin {
+            payload: PluginPayload {},
+        };
+
         if let Some(default_plugin_name) = &app.config.default_plugin {
             let plugin_idx = app
                 .plugins
@@ -284,27 +671,105 @@ impl TuiApp {
                         .join(", ")
                 );
 
-                Ok(Route::Item {
-                    payload: ItemPayload {
-                        plugin_idx,
-                        task_key: default_task_key.clone(),
+                Ok(vec![
+                    plugin_list,
+                    Route::Task {
+                        payload: TaskPayload { plugin_idx },
                     },
-                })
+                    Route::Item {
+                        payload: ItemPayload {
+                            plugin_idx,
+                            task_key: default_task_key.clone(),
+                        },
+                    },
+                ])
             } else {
-                Ok(Route::Task {
-                    payload: TaskPayload { plugin_idx },
-                })
+                Ok(vec![
+                    plugin_list,
+                    Route::Task {
+                        payload: TaskPayload { plugin_idx },
+                    },
+                ])
             }
         } else {
-            Ok(Route::Plugin {
-                payload: PluginPayload {},
+            Ok(vec![plugin_list])
+        }
+    }
+
+    // Loads the plugins again after the watcher saw a change and returns to the plugin list,
+    // the routes above it may point at plugins and tasks that are gone
+    fn reload_changed_plugins(&mut self) {
+        let Some(reload) = &mut self.plugin_reload else {
+            return;
+        };
+        // A running task holds the VM, the reload waits for it instead of blocking the UI
+        if !reload.changes.has_changed().unwrap_or(false)
+            || self.app.lua_runtime.try_lock().is_err()
+        {
+            return;
+        }
+        reload.changes.mark_unchanged();
+
+        let result = load_plugins_with_report(
+            &reload.plugin_paths,
+            &self.app.config,
+            Arc::clone(&self.app.lua_runtime),
+        );
+        let (plugins, records) = match result {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                warn!(error = %format!("{:#}", e), "Failed to reload plugins");
+                self.toasts.push(
+                    format!("{}: {:#}", ModalStrings::PLUGIN_RELOAD_FAILED, e),
+                    Some(EXIT_FAILURE),
+                );
+                return;
+            }
+        };
+
+        let failures: Vec<String> = records
+            .iter()
+            .filter_map(|record| match &record.status {
+                PluginLoadStatus::Error(e) => Some(format!("{}: {}", record.name, e)),
+                _ => None,
             })
+            .collect();
+        self.app.plugins = plugins;
+
+        let popped = self.navigator.back_to_root();
+        let exited = popped
+            .first()
+            .map_or(self.navigator.current(), |entry| &entry.route)
+            .clone();
+        self.search_bar.clear();
+        self.screen_dispatcher.on_exit(&exited);
+        self.screen_dispatcher
+            .on_enter(self.navigator.current(), &self.app);
+
+        if failures.is_empty() {
+            self.toasts.push(
+                format!(
+                    "{} ({})",
+                    ModalStrings::PLUGINS_RELOADED,
+                    self.app.plugins.len()
+                ),
+                None,
+            );
+        } else {
+            self.toasts.push(
+                format!(
+                    "{}\n{}",
+                    ModalStrings::PLUGIN_RELOAD_FAILED,
+                    failures.join("\n")
+                ),
+                Some(EXIT_FAILURE),
+            );
         }
     }
 
     fn get_route_name(route: &Route, app: &App) -> String {
         match route {
-            Route::Plugin { .. } => route.to_string(),
+            Route::Plugin { .. } | Route::Jobs { .. } => route.to_string(),
             Route::Task { payload } => app
                 .plugins
                 .get(payload.plugin_idx)
@@ -321,7 +786,9 @@ impl TuiApp {
 
     fn suspend_and_run_tui(
         &mut self,
-        request: ExternalTuiRequest,
+        command: &str,
+        args: &[String],
+        response: oneshot::Sender<i32>,
         terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     ) -> Result<()> {
         // Suspend TUI: disable raw mode and leave alternate screen
@@ -329,8 +796,8 @@ impl TuiApp {
         execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
 
         // Run external TUI command in blocking mode (gives it full terminal control)
-        let exit_code = run_tui_command_blocking(&request.command, &request.args)
-            .unwrap_or_else(|_| clamp_exit_code(-1));
+        let exit_code =
+            run_tui_command_blocking(command, args).unwrap_or_else(|_| clamp_exit_code(-1));
 
         // Restore TUI: re-enter alternate screen and enable raw mode
         execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
@@ -340,7 +807,7 @@ impl TuiApp {
         terminal.clear()?;
 
         // Send response back to waiting Lua function
-        let _ = request.response.send(exit_code);
+        let _ = response.send(exit_code);
 
         Ok(())
     }
@@ -348,16 +815,21 @@ impl TuiApp {
 
 impl Drop for TuiApp {
     fn drop(&mut self) {
-        let _ = disable_raw_mode();
-        let _ = execute!(
-            io::stdout(),
-            LeaveAlternateScreen,
-            DisableMouseCapture,
-            Show
-        );
+        restore_terminal();
     }
 }
 
+// Leaves raw mode and the alternate screen, safe to call when they were already left
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        Show
+    );
+}
+
 fn get_key_frame() -> u64 {
     let system_time_in_millis = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -0,0 +1,120 @@
+use ratatui::{
+    Frame,
+    layout::{Position, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, Clear, List, ListItem, ListState},
+};
+
+use crate::tui::{
+    strings::ContextMenuStrings,
+    views::{ColorStyle, style::ModalStyle},
+};
+
+const CONTEXT_MENU_WIDTH: u16 = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContextMenuAction {
+    Preview,
+    Select,
+}
+
+impl ContextMenuAction {
+    pub const ALL: [ContextMenuAction; 2] = [ContextMenuAction::Preview, ContextMenuAction::Select];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContextMenuAction::Preview => ContextMenuStrings::PREVIEW,
+            ContextMenuAction::Select => ContextMenuStrings::SELECT,
+        }
+    }
+}
+
+/// Popup menu opened with a right click on a list item
+#[derive(Default)]
+pub struct ContextMenu {
+    area: Option<Rect>,
+    selected: usize,
+}
+
+impl ContextMenu {
+    /// Opens the menu with its top left corner at the clicked cell, shifted as needed to fit
+    /// inside `bounds`
+    pub fn open(&mut self, column: u16, row: u16, bounds: Rect) {
+        let width = CONTEXT_MENU_WIDTH.min(bounds.width);
+        let height = (ContextMenuAction::ALL.len() as u16 + 2).min(bounds.height);
+        self.area = Some(Rect {
+            x: column.clamp(bounds.x, bounds.right().saturating_sub(width)),
+            y: row.clamp(bounds.y, bounds.bottom().saturating_sub(height)),
+            width,
+            height,
+        });
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.area = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.area.is_some()
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % ContextMenuAction::ALL.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected =
+            (self.selected + ContextMenuAction::ALL.len() - 1) % ContextMenuAction::ALL.len();
+    }
+
+    pub fn selected_action(&self) -> ContextMenuAction {
+        ContextMenuAction::ALL[self.selected]
+    }
+
+    /// Returns the action drawn at the given cell, `None` for cells outside the entries
+    pub fn action_at(&self, column: u16, row: u16) -> Option<ContextMenuAction> {
+        let area = self.area?;
+        let entries = Block::bordered().inner(area);
+        if !entries.contains(Position::new(column, row)) {
+            return None;
+        }
+        ContextMenuAction::ALL
+            .get((row - entries.y) as usize)
+            .copied()
+    }
+
+    pub fn render(&self, frame: &mut Frame, modal_style: &ModalStyle, color_style: &ColorStyle) {
+        let Some(area) = self.area else {
+            return;
+        };
+
+        frame.render_widget(Clear, area);
+
+        let mut block = Block::bordered()
+            .border_set(modal_style.border_set.as_set())
+            .style(Style::default().bg(color_style.background_modal))
+            .border_style(Style::default().fg(color_style.borders_modal));
+
+        if let Some(font_weight) = modal_style.font_weight {
+            block = block.add_modifier(font_weight);
+        }
+
+        let items: Vec<ListItem> = ContextMenuAction::ALL
+            .iter()
+            .map(|action| ListItem::new(action.label()))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .style(Style::default().fg(color_style.text_modal))
+            .highlight_style(
+                Style::default()
+                    .bg(color_style.highlights_background)
+                    .fg(color_style.highlights_text),
+            );
+
+        let mut list_state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
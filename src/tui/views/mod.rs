@@ -1,3 +1,4 @@
+mod context_menu;
 mod modal;
 mod modal_dialog;
 mod preview;
@@ -6,12 +7,16 @@ mod search_bar;
 mod selectable_list;
 mod status_bar;
 pub mod style;
+pub mod syntax_highlight;
+mod toast;
 
+pub use context_menu::{ContextMenu, ContextMenuAction};
 pub use modal::Modal;
 pub use modal_dialog::ModalDialog;
-pub use preview::Preview;
+pub use preview::{Preview, PreviewWrap, clamp_horizontal_offset, wrap_lines, wrapped_line_count};
 pub use screen_scaffold::render_screen_scaffold;
 pub use search_bar::SearchBar;
-pub use selectable_list::SelectableList;
-pub use status_bar::StatusBar;
+pub use selectable_list::{ListMovement, SelectableList, next_index};
+pub use status_bar::{STATUS_LOG_DURATION, StatusBar, StatusLog};
 pub use style::{ColorStyle, Styles, parse_color};
+pub use toast::{TOAST_DURATION, Toast, ToastNotification, ToastQueue};
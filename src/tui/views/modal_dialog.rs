@@ -67,7 +67,9 @@ impl ModalDialog {
         let mut outer_block = Block::default();
 
         if let Some(borders) = modal_style.borders {
-            outer_block = outer_block.borders(borders);
+            outer_block = outer_block
+                .borders(borders)
+                .border_set(modal_style.border_set.as_set());
         }
 
         if modal_style.show_title {
@@ -111,6 +113,7 @@ impl ModalDialog {
 
         let mut cancel_block = Block::default()
             .borders(ratatui::widgets::Borders::ALL)
+            .border_set(modal_style.border_set.as_set())
             .border_style(Style::default().fg(color_style.borders_modal))
             .style(Style::default().bg(color_style.background_modal));
 
@@ -127,6 +130,7 @@ impl ModalDialog {
 
         let mut confirm_block = Block::default()
             .borders(ratatui::widgets::Borders::ALL)
+            .border_set(modal_style.border_set.as_set())
             .border_style(Style::default().fg(color_style.borders_modal))
             .style(Style::default().bg(color_style.background_modal));
 
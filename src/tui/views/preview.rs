@@ -1,15 +1,60 @@
+use std::{fmt, ops::Range};
+
 use ratatui::{
     Frame,
     layout::Rect,
     style::{Style, Stylize},
+    text::{Line, Span, Text},
     widgets::{Block, Paragraph},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::tui::{
+    strings::PreviewStrings,
+    views::{ColorStyle, style::PreviewStyle, syntax_highlight::highlight},
+};
+
+/// How the preview shows lines wider than the pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreviewWrap {
+    /// Lines are cut at the pane's edge and the preview pans left and right
+    #[default]
+    Scroll,
+    /// Lines continue on the next row, broken between words
+    Wrap,
+}
+
+impl PreviewWrap {
+    pub fn toggle(self) -> Self {
+        match self {
+            PreviewWrap::Scroll => PreviewWrap::Wrap,
+            PreviewWrap::Wrap => PreviewWrap::Scroll,
+        }
+    }
+}
 
-use crate::tui::views::{ColorStyle, style::PreviewStyle};
+impl fmt::Display for PreviewWrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreviewWrap::Scroll => write!(f, "{}", PreviewStrings::SCROLL),
+            PreviewWrap::Wrap => write!(f, "{}", PreviewStrings::WRAP),
+        }
+    }
+}
 
 #[derive(Default)]
 pub struct Preview {
     scroll_offset: u16,
+    horizontal_offset: u16,
+    wrap: PreviewWrap,
+    highlighted: Option<Highlighted>,
+}
+
+// Highlighting runs once per preview rather than on every frame
+struct Highlighted {
+    content: String,
+    syntax: String,
+    lines: Option<Vec<Line<'static>>>,
 }
 impl Preview {
     pub fn scroll_up(&mut self, offset: u16) {
@@ -20,15 +65,40 @@ impl Preview {
         self.scroll_offset = self.scroll_offset.saturating_add(offset);
     }
 
+    pub fn scroll_left(&mut self, offset: u16) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(offset);
+    }
+
+    /// Pans right, only in [`PreviewWrap::Scroll`] mode. Clamped to the widest line on render
+    pub fn scroll_right(&mut self, offset: u16) {
+        if self.wrap == PreviewWrap::Scroll {
+            self.horizontal_offset = self.horizontal_offset.saturating_add(offset);
+        }
+    }
+
     pub fn reset_scroll(&mut self) {
         self.scroll_offset = 0;
+        self.horizontal_offset = 0;
+    }
+
+    pub fn wrap(&self) -> PreviewWrap {
+        self.wrap
     }
 
+    /// Switches between wrapping and panning, the preview starts over at the top
+    pub fn toggle_wrap(&mut self) {
+        self.wrap = self.wrap.toggle();
+        self.reset_scroll();
+    }
+
+    /// Draws `preview`, highlighted as `syntax` when it names a known syntax and as plain
+    /// text otherwise
     pub fn render(
-        &self,
+        &mut self,
         frame: &mut Frame,
         area: Rect,
         preview: &str,
+        syntax: Option<&str>,
         title: &str,
         preview_style: &PreviewStyle,
         color_style: &ColorStyle,
@@ -36,7 +106,9 @@ impl Preview {
         let mut block = Block::default();
 
         if let Some(borders) = preview_style.borders {
-            block = block.borders(borders);
+            block = block
+                .borders(borders)
+                .border_set(preview_style.border_set.as_set());
         }
 
         if preview_style.show_title {
@@ -49,14 +121,174 @@ impl Preview {
 
         block = block.border_style(Style::default().fg(color_style.borders_preview));
 
-        let paragraph = Paragraph::new(preview)
+        let inner = block.inner(area);
+        let lines = match syntax.and_then(|syntax| self.highlight(preview, syntax, color_style)) {
+            Some(lines) => lines.to_vec(),
+            None => preview.lines().map(Line::raw).collect(),
+        };
+        let text = match self.wrap {
+            PreviewWrap::Scroll => {
+                self.horizontal_offset =
+                    clamp_horizontal_offset(self.horizontal_offset, preview, inner.width);
+                Text::from(lines)
+            }
+            PreviewWrap::Wrap => Text::from(wrap_styled_lines(&lines, inner.width)),
+        };
+        let max_scroll = text.lines.len().saturating_sub(inner.height as usize);
+        self.scroll_offset = self.scroll_offset.min(clamp_to_u16(max_scroll));
+
+        let paragraph = Paragraph::new(text)
             .block(block)
             .style(
                 Style::default()
                     .fg(color_style.text_preview)
                     .bg(color_style.background_preview),
             )
-            .scroll((self.scroll_offset, 0));
+            .scroll((self.scroll_offset, self.horizontal_offset));
         frame.render_widget(paragraph, area);
     }
+
+    // Lines of the preview highlighted as `syntax`, None when it isn't a known syntax
+    fn highlight(
+        &mut self,
+        content: &str,
+        syntax: &str,
+        color_style: &ColorStyle,
+    ) -> Option<&[Line<'static>]> {
+        let cached = self.highlighted.as_ref().is_some_and(|highlighted| {
+            highlighted.content == content && highlighted.syntax == syntax
+        });
+        if !cached {
+            self.highlighted = Some(Highlighted {
+                content: content.to_string(),
+                syntax: syntax.to_string(),
+                lines: highlight(content, syntax, color_style.mode),
+            });
+        }
+        self.highlighted.as_ref()?.lines.as_deref()
+    }
+}
+
+/// Breaks every line of `content` into rows of at most `width` columns, between words where
+/// possible. Words wider than `width` are split, the spaces a row is broken at are dropped.
+pub fn wrap_lines(content: &str, width: u16) -> Vec<String> {
+    content
+        .lines()
+        .flat_map(|line| {
+            wrap_ranges(line, width)
+                .into_iter()
+                .map(|range| line[range].to_string())
+        })
+        .collect()
+}
+
+// `lines` broken into rows like `wrap_lines`, every part of a row keeping its style
+fn wrap_styled_lines<'a>(lines: &[Line<'a>], width: u16) -> Vec<Line<'a>> {
+    let mut rows = Vec::new();
+    for line in lines {
+        let plain: String = line
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        for range in wrap_ranges(&plain, width) {
+            let mut row = Vec::new();
+            let mut span_start = 0;
+            for span in &line.spans {
+                let span_end = span_start + span.content.len();
+                let start = range.start.max(span_start);
+                let end = range.end.min(span_end);
+                if start < end {
+                    let content = span.content[start - span_start..end - span_start].to_string();
+                    row.push(Span::styled(content, span.style));
+                }
+                span_start = span_end;
+            }
+            rows.push(Line::from(row).style(line.style));
+        }
+    }
+    rows
+}
+
+// Byte ranges of the rows `line` wraps into, see `wrap_lines`
+fn wrap_ranges(line: &str, width: u16) -> Vec<Range<usize>> {
+    let width = (width as usize).max(1);
+    let mut rows = Vec::new();
+    let mut row = 0..0;
+    let mut row_width = 0;
+    let mut offset = 0;
+
+    for segment in split_words(line) {
+        let segment_start = offset;
+        offset += segment.len();
+        let segment_width = segment.width();
+        if row_width + segment_width <= width {
+            row.end = offset;
+            row_width += segment_width;
+            continue;
+        }
+
+        if segment.starts_with(char::is_whitespace) {
+            if row_width > 0 {
+                rows.push(row);
+                row_width = 0;
+            }
+            row = offset..offset;
+            continue;
+        }
+
+        if segment_width <= width && row_width > 0 {
+            let trimmed = line[row.clone()].trim_end().len();
+            rows.push(row.start..row.start + trimmed);
+            row = segment_start..segment_start;
+            row_width = 0;
+        }
+        for (idx, c) in segment.char_indices() {
+            let char_width = c.width().unwrap_or(0);
+            if row_width + char_width > width && row_width > 0 {
+                rows.push(row.clone());
+                row = row.end..row.end;
+                row_width = 0;
+            }
+            row.end = segment_start + idx + c.len_utf8();
+            row_width += char_width;
+        }
+    }
+    rows.push(row);
+
+    rows
+}
+
+/// Rows `content` takes up when wrapped at `width` columns, see [`wrap_lines`]
+pub fn wrapped_line_count(content: &str, width: u16) -> usize {
+    wrap_lines(content, width).len()
+}
+
+/// `offset` limited so the widest line of `content` still ends within `width` columns
+pub fn clamp_horizontal_offset(offset: u16, content: &str, width: u16) -> u16 {
+    let widest = content
+        .lines()
+        .map(UnicodeWidthStr::width)
+        .max()
+        .unwrap_or(0);
+    offset.min(clamp_to_u16(widest.saturating_sub(width as usize)))
+}
+
+fn clamp_to_u16(value: usize) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
+
+// Runs of whitespace and of other characters, in order
+fn split_words(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| c.is_whitespace() != first.is_whitespace())
+            .map_or(rest.len(), |(idx, _)| idx);
+        let (segment, tail) = rest.split_at(end);
+        rest = tail;
+        Some(segment)
+    })
 }
@@ -3,22 +3,41 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
 };
 
-use crate::tui::views::style::ScreenScaffoldStyle;
+use crate::{
+    configs::style::PreviewMode,
+    tui::views::style::{PreviewStyle, ScreenScaffoldStyle},
+};
 
+/// Lays out the list (left) and preview (right) areas of a screen.
+///
+/// In `Overlay` mode the areas follow the `screen_scaffold` splits. In `Split` mode the
+/// preview takes `preview_split_ratio` of the width and the list takes the rest.
 pub fn render_screen_scaffold<F>(
     frame: &mut Frame,
     area: Rect,
     screen_scaffold_style: &ScreenScaffoldStyle,
+    preview_style: &PreviewStyle,
     callback: F,
 ) where
     F: FnOnce(&mut Frame<'_>, Rect, Rect),
 {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
+    let constraints = match preview_style.mode {
+        PreviewMode::Overlay => [
             Constraint::Percentage(screen_scaffold_style.left_split),
             Constraint::Percentage(screen_scaffold_style.right_split),
-        ])
+        ],
+        PreviewMode::Split => {
+            let preview_split = (preview_style.split_ratio.clamp(0.0, 1.0) * 100.0).round() as u16;
+            [
+                Constraint::Percentage(100 - preview_split),
+                Constraint::Percentage(preview_split),
+            ]
+        }
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
         .split(area);
 
     callback(frame, chunks[0], chunks[1])
@@ -5,33 +5,66 @@ use ratatui::{
     style::Style,
     widgets::{Block, Paragraph},
 };
+use std::path::PathBuf;
+use tracing::warn;
 use tui_input::{Input, backend::crossterm::EventHandler};
 
-use crate::tui::views::{ColorStyle, style::SearchBarStyle};
+use crate::tui::{
+    fuzzy_searcher::{SearchMode, SearchOptions},
+    search_history::SearchHistory,
+    views::{ColorStyle, style::SearchBarStyle},
+};
 
 #[derive(Default)]
 pub struct SearchBar {
     input: Input,
+    mode: SearchMode,
+    case_sensitive: bool,
+    history: SearchHistory,
+    // File the history is written to on every new query, None keeps it in memory
+    history_path: Option<PathBuf>,
 }
 
 impl SearchBar {
+    /// Search bar recalling queries from `history`, saved to `history_path` when set
+    pub fn with_history(history: SearchHistory, history_path: Option<PathBuf>) -> Self {
+        Self {
+            history,
+            history_path,
+            ..Self::default()
+        }
+    }
+
     pub fn handle_event(&mut self, event: &Event) -> bool {
+        let handled = self.handle_input_event(event);
+        if handled {
+            // Editing a recalled query makes it the draft of the next walk
+            self.history.reset();
+        }
+        handled
+    }
+
+    fn handle_input_event(&mut self, event: &Event) -> bool {
         if let Event::Key(key) = event {
             match key.code {
-                KeyCode::Char(_) => {
-                    if key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT {
-                        self.input.handle_event(event);
-                        true
-                    } else {
-                        false
-                    }
+                KeyCode::Char(_)
+                    if key.modifiers == KeyModifiers::NONE
+                        || key.modifiers == KeyModifiers::SHIFT =>
+                {
+                    self.input.handle_event(event);
+                    true
                 }
-                KeyCode::Backspace
-                | KeyCode::Delete
-                | KeyCode::Left
-                | KeyCode::Right
-                | KeyCode::Home
-                | KeyCode::End => {
+                KeyCode::Backspace | KeyCode::Delete => {
+                    self.input.handle_event(event);
+                    true
+                }
+                // <A-left> and <A-right> are left to keybindings such as scroll_preview_left
+                KeyCode::Left | KeyCode::Right if !key.modifiers.contains(KeyModifiers::ALT) => {
+                    self.input.handle_event(event);
+                    true
+                }
+                // <C-home> and <C-end> are left to keybindings such as go_home
+                KeyCode::Home | KeyCode::End if !key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.input.handle_event(event);
                     true
                 }
@@ -50,8 +83,59 @@ impl SearchBar {
         self.input.value().is_empty()
     }
 
+    /// Clears the query and turns case sensitivity off, the search mode is kept. The query is
+    /// added to the history.
     pub fn clear(&mut self) {
+        if self.history.push(self.input.value())
+            && let Some(path) = &self.history_path
+            && let Err(e) = self.history.save(path)
+        {
+            warn!("{:#}", e);
+        }
         self.input = Input::default();
+        self.case_sensitive = false;
+    }
+
+    /// Replaces the query with the previous history entry, returns whether it changed
+    pub fn recall_previous(&mut self) -> bool {
+        let recalled = self.history.older(self.input.value()).map(str::to_string);
+        self.set_query(recalled)
+    }
+
+    /// Replaces the query with the next history entry or the query typed before recalling,
+    /// returns whether it changed
+    pub fn recall_next(&mut self) -> bool {
+        let recalled = self.history.newer().map(str::to_string);
+        self.set_query(recalled)
+    }
+
+    pub fn history(&self) -> &SearchHistory {
+        &self.history
+    }
+
+    fn set_query(&mut self, query: Option<String>) -> bool {
+        match query {
+            Some(query) if query != self.input.value() => {
+                self.input = Input::new(query);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn options(&self) -> SearchOptions {
+        SearchOptions {
+            mode: self.mode,
+            case_sensitive: self.case_sensitive,
+        }
+    }
+
+    pub fn cycle_mode(&mut self) {
+        self.mode = self.mode.next();
+    }
+
+    pub fn toggle_case_sensitive(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
     }
 
     pub fn render(
@@ -61,25 +145,39 @@ impl SearchBar {
         search_bar_style: &SearchBarStyle,
         color_style: &ColorStyle,
     ) {
-        let text = if self.is_empty() {
-            search_bar_style.search_hint.to_string()
+        let query = if self.is_empty() {
+            search_bar_style.search_hint.as_str()
         } else {
-            self.input.value().to_string()
+            self.input.value()
         };
+        let case_indicator = if self.case_sensitive { "[Aa]" } else { "[aa]" };
+        let text = format!("{}{} {}", self.mode.indicator(), case_indicator, query);
+        // An invalid regex is shown in red instead of failing the search
+        let invalid = !self.mode.is_valid_query(self.value());
 
         let mut paragraph_block = Block::default();
 
         if let Some(borders) = search_bar_style.borders {
-            paragraph_block = paragraph_block.borders(borders);
+            paragraph_block = paragraph_block
+                .borders(borders)
+                .border_set(search_bar_style.border_set.as_set());
         }
 
-        paragraph_block =
-            paragraph_block.border_style(Style::default().fg(color_style.borders_search));
+        let border_color = if invalid {
+            color_style.error
+        } else {
+            color_style.borders_search
+        };
+        paragraph_block = paragraph_block.border_style(Style::default().fg(border_color));
 
         let mut paragraph = Paragraph::new(text).block(paragraph_block);
 
         let mut style = Style::default()
-            .fg(color_style.text_search)
+            .fg(if invalid {
+                color_style.error
+            } else {
+                color_style.text_search
+            })
             .bg(color_style.background_search);
 
         if let Some(font_weight) = search_bar_style.font_weight {
@@ -1,13 +1,45 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, List, ListItem, ListState, Paragraph},
 };
+use unicode_width::UnicodeWidthStr;
 
-use crate::tui::views::{ColorStyle, style::ListStyle};
+use crate::{
+    execution::runner::parse_tag,
+    tui::views::{ColorStyle, style::ListStyle},
+};
+
+/// Cursor move of a list, by one item or a page of items
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListMovement {
+    Down(usize),
+    Up(usize),
+}
+
+/// Index the cursor lands on after `movement` in a list of `len` items
+///
+/// Moves stop at the first and last item. With `wrap`, a move from the last item down
+/// continues at the first one and a move from the first item up at the last one, so a page
+/// move first stops at the end and only wraps on the next move.
+pub fn next_index(current: usize, len: usize, movement: ListMovement, wrap: bool) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    let last = len - 1;
+    let current = current.min(last);
+
+    match movement {
+        ListMovement::Down(_) if wrap && current == last => 0,
+        ListMovement::Down(step) => current.saturating_add(step).min(last),
+        ListMovement::Up(_) if wrap && current == 0 => last,
+        ListMovement::Up(step) => current.saturating_sub(step),
+    }
+}
 
 #[derive(Default)]
 pub struct SelectionCountCache {
@@ -35,6 +67,14 @@ pub struct SelectableList {
     list_state: ListState,
     multiselect: bool,
     selection_count_cache: SelectionCountCache,
+    // Area and length of the list at the last render, used to map mouse clicks to items
+    list_area: Rect,
+    item_count: usize,
+    placeholder: Option<&'static str>,
+    // Tags of the item sources with their own color, see `set_tag_colors`
+    tag_colors: HashMap<String, Option<Color>>,
+    // Second lines of the items, see `set_subtitles`
+    subtitles: Vec<String>,
 }
 
 impl SelectableList {
@@ -43,6 +83,11 @@ impl SelectableList {
             list_state: ListState::default(),
             multiselect,
             selection_count_cache: SelectionCountCache::default(),
+            list_area: Rect::default(),
+            item_count: 0,
+            placeholder: None,
+            tag_colors: HashMap::new(),
+            subtitles: Vec::new(),
         }
     }
 
@@ -62,29 +107,76 @@ impl SelectableList {
         self.list_state.select(Some(index));
     }
 
-    pub fn select_next(&mut self) {
-        self.list_state.select_next();
+    /// Moves the cursor within a list of `len` items, see [`next_index`]. Without a selection
+    /// a move down selects the first item and a move up the last one
+    pub fn move_selection(&mut self, movement: ListMovement, len: usize, wrap: bool) {
+        let next = match (self.list_state.selected(), movement) {
+            (None, ListMovement::Down(_)) => 0,
+            (None, ListMovement::Up(_)) => len.saturating_sub(1),
+            (Some(current), _) => next_index(current, len, movement, wrap),
+        };
+        self.list_state.select(Some(next));
+    }
+
+    /// Items visible at the last render, the distance of a page move
+    pub fn page_height(&self) -> usize {
+        (self.list_area.height as usize / self.item_height()).max(1)
+    }
+
+    /// Text shown instead of an empty list, e.g. while the items are still loading
+    pub fn set_placeholder(&mut self, placeholder: Option<&'static str>) {
+        self.placeholder = placeholder;
     }
 
-    pub fn select_previous(&mut self) {
-        self.list_state.select_previous();
+    /// Tags whose `[tag]` prefix is colored, with the color of their source. Tags without
+    /// a color use the `tag` color of the theme, an empty map leaves items uncolored
+    pub fn set_tag_colors(&mut self, tag_colors: HashMap<String, Option<Color>>) {
+        self.tag_colors = tag_colors;
+    }
+
+    /// Dimmed second lines of the items, by index. Every item takes two lines while there are
+    /// subtitles, items without one get an empty second line. Empty keeps items on one line
+    pub fn set_subtitles(&mut self, subtitles: Vec<String>) {
+        self.subtitles = subtitles;
+    }
+
+    // Lines each item takes up
+    fn item_height(&self) -> usize {
+        if self.subtitles.is_empty() { 1 } else { 2 }
     }
 
     pub fn reset_selected(&mut self) {
         self.list_state.select(None);
     }
 
+    /// Returns the index of the item rendered at the given terminal cell, taking the current
+    /// scroll offset into account
+    pub fn item_at(&self, column: u16, row: u16) -> Option<usize> {
+        if !self.list_area.contains(Position::new(column, row)) {
+            return None;
+        }
+        let idx = self.list_state.offset() + (row - self.list_area.y) as usize / self.item_height();
+        (idx < self.item_count).then_some(idx)
+    }
+
+    /// Renders `items`, underlining the characters at `highlights[idx]` of each item. Pass
+    /// an empty slice when no search is active
     pub fn render(
         &mut self,
         frame: &mut Frame,
         area: Rect,
         items: &[&String],
+        highlights: &[Vec<usize>],
         list_style: &ListStyle,
         color_style: &ColorStyle,
         external_marks: Option<&HashSet<usize>>,
     ) {
         let empty_marks = HashSet::new();
         let marks = external_marks.unwrap_or(&empty_marks);
+        let mut highlight_style = Style::default().add_modifier(Modifier::UNDERLINED);
+        if color_style.search_highlight != Color::Reset {
+            highlight_style = highlight_style.fg(color_style.search_highlight);
+        }
         let render_items: Vec<ListItem> = items
             .iter()
             .enumerate()
@@ -96,7 +188,28 @@ impl SelectableList {
                 } else {
                     &list_style.icon_unmarked
                 };
-                ListItem::new(format!("{} {}", icon, item))
+                let positions = highlights.get(idx).map_or(&[][..], Vec::as_slice);
+                let tag = self.tag_prefix(item, color_style);
+                let line = if positions.is_empty() && tag.is_none() {
+                    Line::raw(format!("{} {}", icon, item))
+                } else {
+                    let mut spans = vec![Span::raw(format!("{} ", icon))];
+                    spans.extend(item_spans(item, positions, highlight_style, tag));
+                    Line::from(spans)
+                };
+                if self.subtitles.is_empty() {
+                    return ListItem::new(line);
+                }
+                // Aligned with the text of the item, after the marking icon
+                let subtitle = Line::styled(
+                    format!(
+                        "{} {}",
+                        " ".repeat(icon.width()),
+                        self.subtitles.get(idx).map_or("", String::as_str)
+                    ),
+                    Style::default().add_modifier(Modifier::DIM),
+                );
+                ListItem::new(vec![line, subtitle])
             })
             .collect();
 
@@ -124,11 +237,14 @@ impl SelectableList {
         let mut outer_block = Block::default();
 
         if let Some(borders) = list_style.borders {
-            outer_block = outer_block.borders(borders).border_style(
-                Style::default()
-                    .fg(color_style.borders_list)
-                    .bg(color_style.background_list),
-            );
+            outer_block = outer_block
+                .borders(borders)
+                .border_set(list_style.border_set.as_set())
+                .border_style(
+                    Style::default()
+                        .fg(color_style.borders_list)
+                        .bg(color_style.background_list),
+                );
         }
 
         let inner_area = outer_block.inner(area);
@@ -158,9 +274,72 @@ impl SelectableList {
                 .style(style);
 
             frame.render_widget(selection_count, vertical_chunks[1]);
-            frame.render_stateful_widget(list, vertical_chunks[0], &mut self.list_state);
+            self.list_area = vertical_chunks[0];
         } else {
-            frame.render_stateful_widget(list, inner_area, &mut self.list_state);
+            self.list_area = inner_area;
         }
+        self.item_count = item_count;
+
+        if item_count == 0
+            && let Some(placeholder) = self.placeholder
+        {
+            let placeholder = Paragraph::new(placeholder).style(apply_font_weight(
+                Style::default()
+                    .fg(color_style.text_list)
+                    .bg(color_style.background_list)
+                    .add_modifier(Modifier::DIM),
+            ));
+            frame.render_widget(placeholder, self.list_area);
+            return;
+        }
+
+        frame.render_stateful_widget(list, self.list_area, &mut self.list_state);
+    }
+
+    // Character length and style of the `[tag]` prefix of `item` when its tag is colored
+    fn tag_prefix(&self, item: &str, color_style: &ColorStyle) -> Option<(usize, Style)> {
+        let (Some(tag), _) = parse_tag(item) else {
+            return None;
+        };
+        let color = self
+            .tag_colors
+            .get(tag)?
+            .map_or(color_style.tag, |color| color_style.mode.apply(color));
+        (color != Color::Reset).then(|| (tag.chars().count() + 2, Style::default().fg(color)))
+    }
+}
+
+// Splits `item` into runs of equally styled characters: the `tag` prefix of the given
+// character length and the characters matched at `positions`, sorted character indices
+fn item_spans(
+    item: &str,
+    positions: &[usize],
+    highlight_style: Style,
+    tag: Option<(usize, Style)>,
+) -> Vec<Span<'static>> {
+    let (tag_len, tag_style) = tag.unwrap_or_default();
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_style = Style::default();
+    let mut positions = positions.iter().peekable();
+
+    for (char_idx, c) in item.chars().enumerate() {
+        let mut style = if char_idx < tag_len {
+            tag_style
+        } else {
+            Style::default()
+        };
+        if positions.next_if_eq(&&char_idx).is_some() {
+            style = style.patch(highlight_style);
+        }
+        if style != run_style && !run.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_style = style;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, run_style));
     }
+    spans
 }
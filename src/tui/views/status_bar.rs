@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -6,17 +8,68 @@ use ratatui::{
 };
 
 use crate::tui::{
+    PluginLogLevel,
     screens::Status,
     views::{ColorStyle, style::StatusStyle},
 };
 
+/// How long a message of `syntropy.log()` replaces the breadcrumbs
+pub const STATUS_LOG_DURATION: Duration = Duration::from_secs(5);
+
+/// Latest message a plugin logged, shown on the left of the status bar
+pub struct StatusLog {
+    pub level: PluginLogLevel,
+    pub message: String,
+    created_at: Instant,
+}
+
+impl StatusLog {
+    /// The message as shown in the status bar, on a single line
+    pub fn line(&self) -> String {
+        format!(
+            "[{}] {}",
+            self.level,
+            self.message
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
 #[derive(Default)]
 pub struct StatusBar {
     pub last_keyframe: u64,
     pub cached_status_line: String,
+    log: Option<StatusLog>,
 }
 
 impl StatusBar {
+    /// Shows `message` instead of the breadcrumbs until [`STATUS_LOG_DURATION`] passed or
+    /// the next message arrives
+    pub fn show_log(&mut self, level: PluginLogLevel, message: String) {
+        self.log = Some(StatusLog {
+            level,
+            message,
+            created_at: Instant::now(),
+        });
+    }
+
+    pub fn log(&self) -> Option<&StatusLog> {
+        self.log.as_ref()
+    }
+
+    /// Drops the message once it was shown for [`STATUS_LOG_DURATION`]
+    pub fn expire_log(&mut self, now: Instant) {
+        if self
+            .log
+            .as_ref()
+            .is_some_and(|log| now.duration_since(log.created_at) >= STATUS_LOG_DURATION)
+        {
+            self.log = None;
+        }
+    }
+
     pub fn get_status_line(
         &mut self,
         status: &Status,
@@ -57,6 +110,7 @@ impl StatusBar {
         &mut self,
         frame: &mut Frame<'_>,
         status: &mut Status,
+        status_indicator: Option<&str>,
         breadcrumbs: &str,
         keyframe: u64,
         area: Rect,
@@ -66,7 +120,9 @@ impl StatusBar {
         let mut outer_block = Block::default();
 
         if let Some(borders) = status_style.borders {
-            outer_block = outer_block.borders(borders);
+            outer_block = outer_block
+                .borders(borders)
+                .border_set(status_style.border_set.as_set());
         }
 
         outer_block = outer_block.border_style(
@@ -96,11 +152,19 @@ impl StatusBar {
             ])
             .split(vertical_chunks[1]);
 
-        let mut left_status = Paragraph::new(breadcrumbs).alignment(Alignment::Left);
+        let mut left_status = match &self.log {
+            Some(log) => Paragraph::new(log.line()),
+            None => Paragraph::new(breadcrumbs),
+        }
+        .alignment(Alignment::Left);
 
         let status_line = self.get_status_line(status, keyframe, status_style);
+        let status_line = match status_indicator {
+            Some(indicator) => format!("[{}] {}", indicator, status_line),
+            None => status_line.clone(),
+        };
 
-        let mut right_status = Paragraph::new(&status_line[..]).alignment(Alignment::Right);
+        let mut right_status = Paragraph::new(status_line).alignment(Alignment::Right);
 
         let mut text_style = Style::default()
             .fg(color_style.text_status)
@@ -110,7 +174,12 @@ impl StatusBar {
             text_style = text_style.patch(font_weight);
         }
 
-        left_status = left_status.style(text_style);
+        let left_style = match self.log.as_ref().map(|log| log.level) {
+            Some(PluginLogLevel::Warn) => text_style.fg(color_style.warning),
+            Some(PluginLogLevel::Error) => text_style.fg(color_style.error),
+            Some(PluginLogLevel::Info) | None => text_style,
+        };
+        left_status = left_status.style(left_style);
         right_status = right_status.style(text_style);
 
         frame.render_widget(left_status, status_chunks[0]);
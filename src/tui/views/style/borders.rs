@@ -1,5 +1,8 @@
 use crate::configs::style;
-use ratatui::widgets::Borders;
+use ratatui::{
+    symbols::border,
+    widgets::{BorderType, Borders},
+};
 
 impl From<&style::Borders> for Borders {
     fn from(config_borders: &style::Borders) -> Self {
@@ -19,3 +22,81 @@ pub fn parse(config_borders: &[style::Borders]) -> Option<Borders> {
         .map(Borders::from)
         .reduce(|acc, b| acc | b)
 }
+
+/// Characters of a border style, owned so custom characters from the config fit in
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderSet {
+    top_left: String,
+    top_right: String,
+    bottom_left: String,
+    bottom_right: String,
+    vertical_left: String,
+    vertical_right: String,
+    horizontal_top: String,
+    horizontal_bottom: String,
+}
+
+impl BorderSet {
+    /// The set to hand to [`ratatui::widgets::Block::border_set`]
+    pub fn as_set(&self) -> border::Set<'_> {
+        border::Set {
+            top_left: &self.top_left,
+            top_right: &self.top_right,
+            bottom_left: &self.bottom_left,
+            bottom_right: &self.bottom_right,
+            vertical_left: &self.vertical_left,
+            vertical_right: &self.vertical_right,
+            horizontal_top: &self.horizontal_top,
+            horizontal_bottom: &self.horizontal_bottom,
+        }
+    }
+}
+
+impl From<border::Set<'_>> for BorderSet {
+    fn from(set: border::Set<'_>) -> Self {
+        Self {
+            top_left: set.top_left.to_string(),
+            top_right: set.top_right.to_string(),
+            bottom_left: set.bottom_left.to_string(),
+            bottom_right: set.bottom_right.to_string(),
+            vertical_left: set.vertical_left.to_string(),
+            vertical_right: set.vertical_right.to_string(),
+            horizontal_top: set.horizontal_top.to_string(),
+            horizontal_bottom: set.horizontal_bottom.to_string(),
+        }
+    }
+}
+
+impl From<&style::BorderStyle> for BorderSet {
+    fn from(border_style: &style::BorderStyle) -> Self {
+        match border_style {
+            style::BorderStyle::Plain => BorderType::Plain.to_border_set().into(),
+            style::BorderStyle::Rounded => BorderType::Rounded.to_border_set().into(),
+            style::BorderStyle::Double => BorderType::Double.to_border_set().into(),
+            style::BorderStyle::Thick => BorderType::Thick.to_border_set().into(),
+            style::BorderStyle::Ascii => ASCII.into(),
+            style::BorderStyle::None => border::EMPTY.into(),
+            style::BorderStyle::Custom(custom) => Self {
+                top_left: custom.top_left.clone(),
+                top_right: custom.top_right.clone(),
+                bottom_left: custom.bottom_left.clone(),
+                bottom_right: custom.bottom_right.clone(),
+                vertical_left: custom.vertical_left.clone(),
+                vertical_right: custom.vertical_right.clone(),
+                horizontal_top: custom.horizontal_top.clone(),
+                horizontal_bottom: custom.horizontal_bottom.clone(),
+            },
+        }
+    }
+}
+
+const ASCII: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
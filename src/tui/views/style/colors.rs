@@ -1,13 +1,18 @@
 use anyhow::{Context, Error, Result, ensure};
 use ratatui::style::Color;
 
-use crate::configs::style::{Colors, DEFAULT_COLOR};
+use crate::{
+    configs::style::{Colors, DEFAULT_COLOR},
+    tui::color_mode::ColorMode,
+};
 
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct ColorStyle {
     pub highlights_text: Color,
     pub highlights_background: Color,
+    pub search_highlight: Color,
+    pub tag: Color,
     pub borders: Color,
     pub borders_list: Color,
     pub borders_preview: Color,
@@ -26,6 +31,12 @@ pub struct ColorStyle {
     pub background_search: Color,
     pub background_status: Color,
     pub background_modal: Color,
+    /// Invalid input, like a search query that isn't a valid regex
+    pub error: Color,
+    /// Warnings, like the ones plugins log with `syntropy.log()`
+    pub warning: Color,
+    /// Mode the colors were converted for, colors set by plugins are converted on use
+    pub mode: ColorMode,
 }
 
 impl TryFrom<&Colors> for ColorStyle {
@@ -40,6 +51,8 @@ impl TryFrom<&Colors> for ColorStyle {
             highlights_text: parse_color(&colors.highlights_text)?.unwrap_or(Color::Reset),
             highlights_background: parse_color(&colors.highlights_background)?
                 .unwrap_or(Color::Reset),
+            search_highlight: parse_color(&colors.search_highlight)?.unwrap_or(Color::Reset),
+            tag: parse_color(&colors.tag)?.unwrap_or(Color::Reset),
             borders,
             borders_list: parse_color(&colors.borders_list)?.unwrap_or(borders),
             borders_preview: parse_color(&colors.borders_preview)?.unwrap_or(borders),
@@ -58,12 +71,48 @@ impl TryFrom<&Colors> for ColorStyle {
             background_search: parse_color(&colors.background_search)?.unwrap_or(background),
             background_status: parse_color(&colors.background_status)?.unwrap_or(background),
             background_modal: parse_color(&colors.background_modal)?.unwrap_or(background),
+            error: Color::Red,
+            warning: Color::Yellow,
+            mode: ColorMode::TrueColor,
         };
 
         Ok(color_style)
     }
 }
 
+impl ColorStyle {
+    /// Every color as `mode` renders it
+    pub fn with_color_mode(self, mode: ColorMode) -> Self {
+        Self {
+            highlights_text: mode.apply(self.highlights_text),
+            highlights_background: mode.apply(self.highlights_background),
+            search_highlight: mode.apply(self.search_highlight),
+            tag: mode.apply(self.tag),
+            borders: mode.apply(self.borders),
+            borders_list: mode.apply(self.borders_list),
+            borders_preview: mode.apply(self.borders_preview),
+            borders_search: mode.apply(self.borders_search),
+            borders_status: mode.apply(self.borders_status),
+            borders_modal: mode.apply(self.borders_modal),
+            text: mode.apply(self.text),
+            text_list: mode.apply(self.text_list),
+            text_preview: mode.apply(self.text_preview),
+            text_search: mode.apply(self.text_search),
+            text_status: mode.apply(self.text_status),
+            text_modal: mode.apply(self.text_modal),
+            background: mode.apply(self.background),
+            background_list: mode.apply(self.background_list),
+            background_preview: mode.apply(self.background_preview),
+            background_search: mode.apply(self.background_search),
+            background_status: mode.apply(self.background_status),
+            background_modal: mode.apply(self.background_modal),
+            error: mode.apply(self.error),
+            warning: mode.apply(self.warning),
+            mode,
+        }
+    }
+}
+
 pub fn parse_color(color: &str) -> Result<Option<Color>> {
     let normalized_color = color.trim();
 
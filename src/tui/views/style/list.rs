@@ -1,22 +1,28 @@
 use ratatui::{style::Modifier, widgets::Borders};
 
-use crate::{configs::style::List, tui::views::style::borders::parse};
+use crate::{
+    configs::style::{BorderStyle, List},
+    tui::views::style::borders::{BorderSet, parse},
+};
 
 pub struct ListStyle {
     pub highlight_symbol: String,
     pub icon_marked: String,
     pub icon_unmarked: String,
     pub borders: Option<Borders>,
+    pub border_set: BorderSet,
     pub font_weight: Option<Modifier>,
 }
 
-impl From<&List> for ListStyle {
-    fn from(list_style: &List) -> Self {
+impl ListStyle {
+    /// Drawn with the `border_style` of the screen scaffold
+    pub fn new(list_style: &List, border_style: &BorderStyle) -> Self {
         Self {
             highlight_symbol: list_style.highlight_symbol.clone(),
             icon_marked: list_style.icon_marked.clone(),
             icon_unmarked: list_style.icon_unmarked.clone(),
             borders: parse(&list_style.borders),
+            border_set: BorderSet::from(border_style),
             font_weight: (&list_style.font_weight).into(),
         }
     }
@@ -9,6 +9,7 @@ mod search_bar;
 mod status;
 mod styles;
 
+pub use borders::BorderSet;
 pub use colors::{ColorStyle, parse_color};
 pub use list::ListStyle;
 pub use modal::ModalStyle;
@@ -1,9 +1,13 @@
 use ratatui::widgets::Borders;
 
-use crate::{configs::style::Modal, tui::views::style::borders::parse};
+use crate::{
+    configs::style::Modal,
+    tui::views::style::borders::{BorderSet, parse},
+};
 
 pub struct ModalStyle {
     pub borders: Option<Borders>,
+    pub border_set: BorderSet,
     pub font_weight: Option<ratatui::style::Modifier>,
     pub show_title: bool,
     pub vertical_size: u16,
@@ -14,6 +18,7 @@ impl From<&Modal> for ModalStyle {
     fn from(modal_style: &Modal) -> Self {
         Self {
             borders: parse(&modal_style.borders),
+            border_set: BorderSet::from(&modal_style.border_style),
             font_weight: (&modal_style.font_weight).into(),
             show_title: modal_style.show_title,
             vertical_size: modal_style.vertical_size,
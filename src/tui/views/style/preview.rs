@@ -1,19 +1,28 @@
 use ratatui::widgets::Borders;
 
-use crate::{configs::style::Preview, tui::views::style::borders::parse};
+use crate::{
+    configs::style::{Preview, PreviewMode},
+    tui::views::style::borders::{BorderSet, parse},
+};
 
 pub struct PreviewStyle {
     pub borders: Option<Borders>,
+    pub border_set: BorderSet,
     pub font_weight: Option<ratatui::style::Modifier>,
     pub show_title: bool,
+    pub mode: PreviewMode,
+    pub split_ratio: f32,
 }
 
 impl From<&Preview> for PreviewStyle {
     fn from(preview_style: &Preview) -> Self {
         Self {
             borders: parse(&preview_style.borders),
+            border_set: BorderSet::from(&preview_style.border_style),
             font_weight: (&preview_style.font_weight).into(),
             show_title: preview_style.show_title,
+            mode: preview_style.preview_mode,
+            split_ratio: preview_style.preview_split_ratio,
         }
     }
 }
@@ -1,18 +1,21 @@
 use ratatui::{style::Modifier, widgets::Borders};
 
-use crate::configs::style::SearchBar;
-use crate::tui::views::style::borders::parse;
+use crate::configs::style::{BorderStyle, SearchBar};
+use crate::tui::views::style::borders::{BorderSet, parse};
 
 pub struct SearchBarStyle {
     pub borders: Option<Borders>,
+    pub border_set: BorderSet,
     pub font_weight: Option<Modifier>,
     pub search_hint: String,
 }
 
-impl From<&SearchBar> for SearchBarStyle {
-    fn from(search_bar_style: &SearchBar) -> Self {
+impl SearchBarStyle {
+    /// Drawn with the `border_style` of the screen scaffold
+    pub fn new(search_bar_style: &SearchBar, border_style: &BorderStyle) -> Self {
         Self {
             borders: parse(&search_bar_style.borders),
+            border_set: BorderSet::from(border_style),
             font_weight: (&search_bar_style.font_weight).into(),
             search_hint: (search_bar_style.search_hint.clone()),
         }
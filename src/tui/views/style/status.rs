@@ -1,11 +1,15 @@
 use ratatui::{style::Modifier, widgets::Borders};
 
-use crate::{configs::style::Status, tui::views::style::borders::parse};
+use crate::{
+    configs::style::{BorderStyle, Status},
+    tui::views::style::borders::{BorderSet, parse},
+};
 
 pub struct StatusStyle {
     pub left_split: u16,
     pub right_split: u16,
     pub borders: Option<Borders>,
+    pub border_set: BorderSet,
     pub font_weight: Option<Modifier>,
     pub idle_icons: Vec<String>,
     pub error_icons: Vec<String>,
@@ -13,12 +17,14 @@ pub struct StatusStyle {
     pub running_icons: Vec<String>,
 }
 
-impl From<&Status> for StatusStyle {
-    fn from(status_style: &Status) -> Self {
+impl StatusStyle {
+    /// Drawn with the `border_style` of the screen scaffold
+    pub fn new(status_style: &Status, border_style: &BorderStyle) -> Self {
         Self {
             left_split: status_style.left_split,
             right_split: status_style.right_split,
             borders: parse(&status_style.borders),
+            border_set: BorderSet::from(border_style),
             font_weight: (&status_style.font_weight).into(),
             idle_icons: status_style.idle_icons.clone(),
             error_icons: status_style.error_icons.clone(),
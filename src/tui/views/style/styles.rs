@@ -2,9 +2,12 @@ use anyhow::{Error, Result};
 
 use crate::{
     configs::{self},
-    tui::views::style::{
-        ColorStyle, ListStyle, ModalStyle, PreviewStyle, ScreenScaffoldStyle, SearchBarStyle,
-        StatusStyle,
+    tui::{
+        color_mode::ColorMode,
+        views::style::{
+            ColorStyle, ListStyle, ModalStyle, PreviewStyle, ScreenScaffoldStyle, SearchBarStyle,
+            StatusStyle,
+        },
     },
 };
 
@@ -19,17 +22,28 @@ pub struct Styles {
     pub screen_scaffold_style: ScreenScaffoldStyle,
 }
 
+impl Styles {
+    /// Degrades the colors to what a terminal in `mode` can render
+    pub fn with_color_mode(mut self, mode: ColorMode) -> Self {
+        self.colors = self.colors.with_color_mode(mode);
+        self
+    }
+}
+
 impl TryFrom<&configs::Styles> for Styles {
     type Error = Error;
 
     fn try_from(styles: &configs::Styles) -> Result<Styles> {
         let styles = Self {
-            list: ListStyle::from(&styles.list),
+            list: ListStyle::new(&styles.list, &styles.screen_scaffold.border_style),
             colors: ColorStyle::try_from(&styles.colors)?,
             preview: PreviewStyle::from(&styles.preview),
             modal: ModalStyle::from(&styles.modal),
-            status: StatusStyle::from(&styles.status),
-            search_bar_style: SearchBarStyle::from(&styles.search_bar),
+            status: StatusStyle::new(&styles.status, &styles.screen_scaffold.border_style),
+            search_bar_style: SearchBarStyle::new(
+                &styles.search_bar,
+                &styles.screen_scaffold.border_style,
+            ),
             screen_scaffold_style: ScreenScaffoldStyle::from(&styles.screen_scaffold),
         };
         Ok(styles)
@@ -0,0 +1,85 @@
+use std::{sync::LazyLock, thread};
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use syntect::{
+    easy::HighlightLines,
+    highlighting::{self, FontStyle, Theme, ThemeSet},
+    parsing::SyntaxSet,
+    util::LinesWithEndings,
+};
+
+use crate::tui::color_mode::ColorMode;
+
+/// Theme of highlighted previews, only its foreground colors are used
+const THEME: &str = "base16-ocean.dark";
+
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+static HIGHLIGHT_THEME: LazyLock<Theme> = LazyLock::new(|| {
+    ThemeSet::load_defaults()
+        .themes
+        .remove(THEME)
+        .unwrap_or_default()
+});
+
+/// Compiles the syntax definitions and the theme in the background, so the first
+/// highlighted preview doesn't wait for them
+pub fn preload_syntaxes() {
+    thread::spawn(|| {
+        LazyLock::force(&SYNTAXES);
+        LazyLock::force(&HIGHLIGHT_THEME);
+    });
+}
+
+/// Whether `syntax` names a known syntax, by name ("Rust") or file extension ("rs"),
+/// ignoring case
+pub fn is_known_syntax(syntax: &str) -> bool {
+    SYNTAXES.find_syntax_by_token(syntax).is_some()
+}
+
+/// Lines of `content` colored as `syntax`, see [`is_known_syntax`]. Colors go through
+/// `color_mode`, the background is left to the preview pane.
+///
+/// Returns `None` when the syntax is unknown or the content can't be parsed as it.
+pub fn highlight(content: &str, syntax: &str, color_mode: ColorMode) -> Option<Vec<Line<'static>>> {
+    let syntax = SYNTAXES.find_syntax_by_token(syntax)?;
+    let mut highlighter = HighlightLines::new(syntax, &HIGHLIGHT_THEME);
+
+    let mut lines = Vec::new();
+    for line in LinesWithEndings::from(content) {
+        let spans = highlighter
+            .highlight_line(line, &SYNTAXES)
+            .ok()?
+            .into_iter()
+            .map(|(style, text)| {
+                Span::styled(
+                    text.trim_end_matches(['\n', '\r']).to_string(),
+                    span_style(style, color_mode),
+                )
+            })
+            .filter(|span| !span.content.is_empty())
+            .collect::<Vec<_>>();
+        lines.push(Line::from(spans));
+    }
+    Some(lines)
+}
+
+fn span_style(style: highlighting::Style, color_mode: ColorMode) -> Style {
+    let foreground = style.foreground;
+    let mut span_style =
+        Style::default().fg(color_mode.apply(Color::Rgb(foreground.r, foreground.g, foreground.b)));
+
+    for (font_style, modifier) in [
+        (FontStyle::BOLD, Modifier::BOLD),
+        (FontStyle::ITALIC, Modifier::ITALIC),
+        (FontStyle::UNDERLINE, Modifier::UNDERLINED),
+    ] {
+        if style.font_style.contains(font_style) {
+            span_style = span_style.add_modifier(modifier);
+        }
+    }
+    span_style
+}
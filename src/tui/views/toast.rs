@@ -0,0 +1,140 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Style, Stylize},
+    widgets::{Block, Clear, Paragraph, Wrap},
+};
+
+use crate::tui::{
+    strings::ModalStrings,
+    views::{ColorStyle, style::ModalStyle},
+};
+
+pub const TOAST_DURATION: Duration = Duration::from_secs(3);
+const TOAST_MIN_WIDTH: u16 = 30;
+const TOAST_MAX_LINES: usize = 6;
+const TOAST_MARGIN: u16 = 1;
+
+pub struct Toast {
+    pub message: String,
+    pub exit_code: Option<i32>,
+    created_at: Instant,
+}
+
+impl Toast {
+    pub fn title(&self) -> String {
+        match self.exit_code {
+            Some(exit_code) => format!(
+                "{} ({} {})",
+                ModalStrings::TITLE_MODAL_RESULT,
+                ModalStrings::LABEL_EXIT_CODE,
+                exit_code
+            ),
+            None => ModalStrings::TITLE_TOAST.to_string(),
+        }
+    }
+}
+
+/// Pending toasts, newest last. Only the most recent toast is displayed.
+#[derive(Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    pub fn push(&mut self, message: String, exit_code: Option<i32>) {
+        self.toasts.push_back(Toast {
+            message,
+            exit_code,
+            created_at: Instant::now(),
+        });
+    }
+
+    /// The toast that should currently be displayed
+    pub fn current(&self) -> Option<&Toast> {
+        self.toasts.back()
+    }
+
+    /// Dismisses the displayed toast. Returns `false` if there was nothing to dismiss.
+    pub fn dismiss(&mut self) -> bool {
+        self.toasts.pop_back().is_some()
+    }
+
+    /// Drops toasts that have been pending for longer than `TOAST_DURATION`
+    pub fn expire(&mut self, now: Instant) {
+        self.toasts
+            .retain(|toast| now.duration_since(toast.created_at) < TOAST_DURATION);
+    }
+
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// Floating notification box rendered in the bottom right corner of the screen
+pub struct ToastNotification;
+
+impl ToastNotification {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        toast: &Toast,
+        modal_style: &ModalStyle,
+        color_style: &ColorStyle,
+    ) {
+        let toast_area = corner_rect(&toast.message, area);
+
+        frame.render_widget(Clear, toast_area);
+
+        let mut block = Block::default();
+
+        if let Some(borders) = modal_style.borders {
+            block = block
+                .borders(borders)
+                .border_set(modal_style.border_set.as_set());
+        }
+
+        if modal_style.show_title {
+            block = block.title(toast.title());
+        }
+
+        if let Some(font_weight) = modal_style.font_weight {
+            block = block.add_modifier(font_weight);
+        }
+
+        block = block
+            .style(Style::default().bg(color_style.background_modal))
+            .border_style(Style::default().fg(color_style.borders_modal));
+
+        let paragraph = Paragraph::new(toast.message.trim_end())
+            .block(block)
+            .style(Style::default().fg(color_style.text_modal))
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, toast_area);
+    }
+}
+
+fn corner_rect(message: &str, area: Rect) -> Rect {
+    let width = (area.width / 3)
+        .max(TOAST_MIN_WIDTH)
+        .min(area.width.saturating_sub(TOAST_MARGIN * 2));
+    let lines = message.trim_end().lines().count().clamp(1, TOAST_MAX_LINES) as u16;
+    let height = (lines + 2).min(area.height.saturating_sub(TOAST_MARGIN * 2));
+
+    Rect {
+        x: area.right().saturating_sub(width + TOAST_MARGIN),
+        y: area.bottom().saturating_sub(height + TOAST_MARGIN),
+        width,
+        height,
+    }
+}
@@ -0,0 +1,276 @@
+//! Integration tests for `App::run_task`
+//!
+//! Tests running tasks through the library API, without the CLI, and the
+//! `ExecutionError` kinds of its failures.
+
+use std::sync::Arc;
+
+use syntropy::{
+    App, Config, ExecutionResult, create_lua_vm, execution::ExecutionError, load_plugins,
+};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const RUNNER_PLUGIN: &str = r#"
+return {
+    metadata = {name = "runner", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        greet = {
+            description = "Greet the selected items",
+            mode = "multi",
+            item_sources = {
+                names = {
+                    tag = "n",
+                    items = function() return {"alice", "bob"} end,
+                    preselected_items = function() return {"bob"} end,
+                    execute = function(items) return "hello " .. table.concat(items, ","), 0 end,
+                },
+            },
+        },
+        pick = {
+            description = "Single item task",
+            item_sources = {
+                names = {
+                    tag = "n",
+                    items = function() return {"alice", "bob"} end,
+                    execute = function(items) return "picked " .. table.concat(items, ","), 0 end,
+                },
+            },
+        },
+        standalone = {
+            description = "Execute-only task",
+            execute = function() return "standalone ran", 0 end,
+        },
+        fail = {
+            description = "Task exiting with a failure",
+            execute = function() return "something broke", 3 end,
+        },
+        explode = {
+            description = "Task raising an error",
+            execute = function() error("boom") end,
+        },
+    },
+}
+"#;
+
+fn app(fixture: &TestFixture) -> App {
+    fixture.create_plugin("runner", RUNNER_PLUGIN);
+    let config = Config::default();
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &config,
+        lua.clone(),
+    )
+    .unwrap();
+    App::new(config, plugins, lua)
+}
+
+fn run_task(app: &App, plugin: &str, task: &str, items: Option<Vec<String>>) -> ExecutionResult {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    runtime.block_on(app.run_task(plugin, task, items))
+}
+
+fn expect_output(result: ExecutionResult) -> (String, i32) {
+    match result {
+        ExecutionResult::Output(output, exit_code, _) => (output, exit_code),
+        other => panic!("Expected output, got {:?}", other),
+    }
+}
+
+fn expect_error(result: ExecutionResult) -> String {
+    match result {
+        ExecutionResult::Error(error) => error,
+        other => panic!("Expected an error, got {:?}", other),
+    }
+}
+
+// ============================================================================
+// Successful Runs
+// ============================================================================
+
+#[test]
+fn run_task_with_explicit_items() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let result = run_task(&app, "runner", "greet", Some(vec!["alice".to_string()]));
+
+    assert_eq!(expect_output(result), ("hello alice".to_string(), 0));
+}
+
+#[test]
+fn run_task_without_items_uses_preselection() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let result = run_task(&app, "runner", "greet", None);
+
+    assert_eq!(expect_output(result), ("hello bob".to_string(), 0));
+}
+
+#[test]
+fn run_task_execute_only() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let result = run_task(&app, "runner", "standalone", None);
+
+    assert_eq!(expect_output(result), ("standalone ran".to_string(), 0));
+}
+
+#[test]
+fn run_task_matches_names_case_insensitively() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let result = run_task(&app, "Runner", "STANDALONE", None);
+
+    assert_eq!(expect_output(result), ("standalone ran".to_string(), 0));
+}
+
+// ============================================================================
+// Failing Runs
+// ============================================================================
+
+#[test]
+fn run_task_reports_failing_exit_code() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let (output, exit_code) = expect_output(run_task(&app, "runner", "fail", None));
+
+    assert_eq!(exit_code, 3);
+    assert!(output.contains("something broke"), "output: {}", output);
+}
+
+#[test]
+fn run_task_reports_lua_error() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = expect_error(run_task(&app, "runner", "explode", None));
+
+    assert!(error.contains("boom"), "error: {}", error);
+}
+
+#[test]
+fn run_task_unknown_plugin() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = expect_error(run_task(&app, "runer", "greet", None));
+
+    assert!(
+        error.contains("Plugin 'runer' not found"),
+        "error: {}",
+        error
+    );
+    assert!(error.contains("Did you mean 'runner'?"), "error: {}", error);
+}
+
+#[test]
+fn run_task_unknown_item() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = expect_error(run_task(
+        &app,
+        "runner",
+        "greet",
+        Some(vec!["carol".to_string()]),
+    ));
+
+    assert!(error.contains("carol"), "error: {}", error);
+}
+
+#[test]
+fn run_task_single_mode_requires_items_when_several_exist() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = expect_error(run_task(&app, "runner", "pick", None));
+
+    assert!(
+        error.contains("requires single-item selection"),
+        "error: {}",
+        error
+    );
+}
+
+#[test]
+fn run_task_items_for_execute_only_task() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = expect_error(run_task(
+        &app,
+        "runner",
+        "standalone",
+        Some(vec!["alice".to_string()]),
+    ));
+
+    assert!(error.contains("has no item sources"), "error: {}", error);
+}
+
+// ============================================================================
+// Error Kinds
+// ============================================================================
+
+#[test]
+fn find_task_unknown_plugin_is_plugin_not_found() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = app.find_task("missing", "greet").unwrap_err();
+
+    match error.downcast_ref::<ExecutionError>() {
+        Some(ExecutionError::PluginNotFound { plugin, available }) => {
+            assert_eq!(plugin, "missing");
+            assert_eq!(available, &["runner".to_string()]);
+        }
+        other => panic!("Expected PluginNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn find_task_unknown_task_is_task_not_found() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+
+    let error = app.find_task("runner", "missing").unwrap_err();
+
+    match error.downcast_ref::<ExecutionError>() {
+        Some(ExecutionError::TaskNotFound { plugin, task, .. }) => {
+            assert_eq!(plugin, "runner");
+            assert_eq!(task, "missing");
+        }
+        other => panic!("Expected TaskNotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn lua_failure_is_lua_error() {
+    let fixture = TestFixture::new();
+    let app = app(&fixture);
+    let (_, _, task) = app.find_task("runner", "explode").unwrap();
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let error = runtime
+        .block_on(syntropy::execution::call_task_execute(
+            &app.lua_runtime,
+            task,
+            &[],
+        ))
+        .unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ExecutionError>(),
+        Some(ExecutionError::LuaError(_))
+    ));
+}
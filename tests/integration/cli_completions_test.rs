@@ -0,0 +1,173 @@
+//! Integration tests for CLI completions subcommand
+//!
+//! Tests the dynamic completion scripts that query plugin and task names at completion time.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::{env, path::Path};
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const COMPLETION_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "completion-plugin",
+        version = "1.0.0",
+        description = "A plugin for completion testing",
+        platforms = {"macos", "linux"},
+    },
+    tasks = {
+        deploy = {
+            description = "Deploy",
+            execute = function() return "ok", 0 end,
+        },
+        build = {
+            description = "Build",
+            execute = function() return "ok", 0 end,
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn completions(shell: &str, dynamic: bool) -> String {
+    let mut command = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    command.args(["completions", shell]);
+    if dynamic {
+        command.arg("--dynamic");
+    }
+    let output = command.output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Sources the dynamic bash script and completes `words` with `syntropy` on PATH
+fn complete_in_bash(fixture: &TestFixture, words: &[&str]) -> Vec<String> {
+    let binary = Path::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    let path = format!(
+        "{}:{}",
+        binary.parent().unwrap().display(),
+        env::var("PATH").unwrap_or_default()
+    );
+    let script = format!(
+        "{}\nCOMP_WORDS=({})\nCOMP_CWORD={}\n_syntropy_dynamic\nprintf '%s\\n' \"${{COMPREPLY[@]}}\"",
+        completions("bash", true),
+        words.join(" "),
+        words.len() - 1
+    );
+
+    let output = std::process::Command::new("bash")
+        .args(["-c", &script])
+        .env("PATH", path)
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .output()
+        .unwrap();
+    let mut candidates: Vec<String> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect();
+    candidates.sort();
+    candidates
+}
+
+// ============================================================================
+// Script Content Tests
+// ============================================================================
+
+#[test]
+fn test_dynamic_zsh_queries_plugin_and_task_names() {
+    let script = completions("zsh", true);
+
+    assert!(script.contains("syntropy list --plugins"));
+    assert!(script.contains("syntropy list --tasks $plugin"));
+    assert!(script.contains("'--plugin=[Plugin name]:NAME:_syntropy_plugin_names' \\"));
+    assert!(script.contains("'--task=[Task name]:NAME:_syntropy_task_names' \\"));
+}
+
+#[test]
+fn test_dynamic_zsh_keeps_file_completion_of_validate_plugin() {
+    let script = completions("zsh", true);
+
+    assert!(script.contains("'(--config)--plugin=[Validate a plugin file]:PATH:_files' \\"));
+}
+
+#[test]
+fn test_dynamic_zsh_defines_helpers_before_dispatch() {
+    let script = completions("zsh", true);
+
+    let helper = script.find("_syntropy_plugin_names() {").unwrap();
+    let dispatch = script
+        .find("if [ \"$funcstack[1]\" = \"_syntropy\" ]")
+        .unwrap();
+    assert!(helper < dispatch);
+}
+
+#[test]
+fn test_static_zsh_does_not_query_names() {
+    let script = completions("zsh", false);
+
+    assert!(!script.contains("_syntropy_plugin_names"));
+}
+
+#[test]
+fn test_dynamic_fish_completes_plugin_and_task() {
+    let script = completions("fish", true);
+
+    assert!(script.contains("-l plugin -r -f -a \"(__fish_syntropy_plugin_names)\""));
+    assert!(script.contains("-l task -r -f -a \"(__fish_syntropy_task_names)\""));
+}
+
+#[test]
+fn test_dynamic_completions_reject_other_shells() {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args(["completions", "powershell", "--dynamic"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "only available for bash, zsh and fish",
+        ));
+}
+
+// ============================================================================
+// Bash Completion Tests
+// ============================================================================
+
+#[test]
+fn test_bash_completes_plugin_names() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("completion-plugin", COMPLETION_PLUGIN);
+
+    let candidates = complete_in_bash(&fixture, &["syntropy", "execute", "--plugin", "comp"]);
+
+    assert_eq!(candidates, vec!["completion-plugin"]);
+}
+
+#[test]
+fn test_bash_completes_task_names_of_chosen_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("completion-plugin", COMPLETION_PLUGIN);
+
+    let candidates = complete_in_bash(
+        &fixture,
+        &[
+            "syntropy",
+            "execute",
+            "--plugin",
+            "completion-plugin",
+            "--task",
+            "''",
+        ],
+    );
+
+    assert_eq!(candidates, vec!["build", "deploy"]);
+}
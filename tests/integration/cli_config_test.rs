@@ -0,0 +1,108 @@
+//! Integration tests for CLI config subcommand
+//!
+//! Tests `config init`: default location, --output, and overwrite protection.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+use syntropy::cli::config::DEFAULT_CONFIG_TEMPLATE;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// config init
+// ============================================================================
+
+#[test]
+fn test_config_init_writes_default_location() {
+    let fixture = TestFixture::new();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["config", "init"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Config file written to"));
+
+    let written =
+        fs::read_to_string(fixture.config_path().join("syntropy").join("syntropy.toml")).unwrap();
+    assert_eq!(written, DEFAULT_CONFIG_TEMPLATE);
+}
+
+#[test]
+fn test_config_init_writes_to_output() {
+    let fixture = TestFixture::new();
+    let output = fixture.data_path().join("nested").join("custom.toml");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["config", "init", "--output"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    assert!(output.is_file());
+    assert!(
+        !fixture
+            .config_path()
+            .join("syntropy")
+            .join("syntropy.toml")
+            .exists()
+    );
+}
+
+#[test]
+fn test_config_init_refuses_to_overwrite() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "status_bar = false\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["config", "init"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"))
+        .stderr(predicate::str::contains("--force"));
+
+    let content =
+        fs::read_to_string(fixture.config_path().join("syntropy").join("syntropy.toml")).unwrap();
+    assert_eq!(content, "status_bar = false\n");
+}
+
+#[test]
+fn test_config_init_force_overwrites() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "status_bar = false\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["config", "init", "--force"])
+        .assert()
+        .success();
+
+    let content =
+        fs::read_to_string(fixture.config_path().join("syntropy").join("syntropy.toml")).unwrap();
+    assert_eq!(content, DEFAULT_CONFIG_TEMPLATE);
+}
+
+#[test]
+fn test_config_init_output_passes_validate() {
+    let fixture = TestFixture::new();
+    let output = fixture.data_path().join("syntropy.toml");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["config", "init", "--output"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--config"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Config file is valid"));
+}
@@ -0,0 +1,86 @@
+//! Integration tests for --tui deep links
+//!
+//! Tests that invalid deep links fail before the terminal is taken over.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+const PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "test-plugin",
+        version = "1.0.0",
+        description = "Plugin for deep link tests",
+    },
+    tasks = {
+        test_task = {
+            description = "Echo the selection",
+            mode = "none",
+            item_sources = {
+                src = {
+                    tag = "t",
+                    items = function() return {"item1", "item2"} end,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn syntropy(fixture: &TestFixture) -> Command {
+    let mut command = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    command
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path());
+    command
+}
+
+// ============================================================================
+// Deep links
+// ============================================================================
+
+#[test]
+fn test_tui_with_subcommand_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--tui", "list", "--plugins"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--tui cannot be combined with a subcommand",
+        ));
+}
+
+#[test]
+fn test_tui_deep_link_to_unknown_plugin_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--tui", "--plugin", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "default_plugin 'missing' not found",
+        ));
+}
+
+#[test]
+fn test_tui_deep_link_to_unknown_task_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--tui", "--plugin", "test-plugin", "--task", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "default_task 'missing' not found in plugin 'test-plugin'",
+        ))
+        .stderr(predicate::str::contains("test_task"));
+}
@@ -0,0 +1,249 @@
+//! Integration tests for CLI doctor subcommand
+//!
+//! Tests that plugin load diagnostics report loaded, broken, duplicated and
+//! platform-filtered plugins.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Config and Plugin Constants
+// ============================================================================
+
+const MINIMAL_CONFIG: &str = r#"
+default_plugin_icon = "⚒"
+"#;
+
+const VALID_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "healthy",
+        version = "1.0.0",
+        icon = "H",
+        description = "Valid plugin",
+        platforms = {"macos", "linux"},
+    },
+    tasks = {
+        test = {
+            description = "Test task",
+            execute = function() return "ok", 0 end,
+        },
+    },
+}
+"#;
+
+const OVERRIDE_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "healthy",
+        icon = "O",
+    },
+}
+"#;
+
+/// Task has neither item_sources nor execute
+const BROKEN_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "broken",
+        version = "1.0.0",
+        icon = "B",
+        description = "Broken plugin",
+        platforms = {"macos", "linux"},
+    },
+    tasks = {
+        test = {
+            description = "Task without execute",
+        },
+    },
+}
+"#;
+
+const SYNTAX_ERROR_PLUGIN: &str = r#"
+return {
+    metadata = {name = "bad" version = "1.0.0"}
+}
+"#;
+
+const FOREIGN_PLATFORM_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "foreign",
+        version = "1.0.0",
+        icon = "F",
+        description = "Plugin for another platform",
+        platforms = {"nonexistent-os"},
+    },
+    tasks = {
+        test = {
+            description = "Test task",
+            execute = function() return "ok", 0 end,
+        },
+    },
+}
+"#;
+
+fn doctor(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .arg("doctor");
+    cmd
+}
+
+// ============================================================================
+// syntropy doctor — Human-readable Report
+// ============================================================================
+
+#[test]
+fn test_doctor_reports_config_file_and_plugin_directories() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Config file:"))
+        .stdout(predicate::str::contains("syntropy.toml"))
+        .stdout(predicate::str::contains("Plugin directories:"))
+        .stdout(predicate::str::contains("healthy/plugin.lua"));
+}
+
+#[test]
+fn test_doctor_without_config_file_uses_defaults() {
+    let fixture = TestFixture::new();
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Config file: none found, using defaults",
+        ))
+        .stdout(predicate::str::contains("No plugins found."));
+}
+
+#[test]
+fn test_doctor_lists_loaded_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✓ healthy"));
+}
+
+#[test]
+fn test_doctor_flags_broken_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+    fixture.create_plugin("broken", BROKEN_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✓ healthy"))
+        .stdout(predicate::str::contains("✗ broken (error)"))
+        // Diagnostics go to the report, not to the loader's stderr warnings
+        .stderr(predicate::str::contains("Skipping plugin").not());
+}
+
+#[test]
+fn test_doctor_flags_plugin_with_syntax_error_by_directory_name() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("syntax-error", SYNTAX_ERROR_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✗ syntax-error (error)"));
+}
+
+#[test]
+fn test_doctor_warns_on_duplicate_plugin_names() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+    fixture.create_plugin_override("healthy", OVERRIDE_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Warnings:"))
+        .stdout(predicate::str::contains(
+            "Plugin 'healthy' found in multiple directories",
+        ));
+}
+
+#[test]
+fn test_doctor_warns_on_platform_filtered_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("foreign", FOREIGN_PLATFORM_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("✗ foreign"))
+        .stdout(predicate::str::contains(
+            "Plugin 'foreign' skipped on this platform",
+        ));
+}
+
+#[test]
+fn test_doctor_reports_invalid_config_and_still_lists_plugins() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "unknown_field = true\n");
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+
+    doctor(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unknown field `unknown_field`"))
+        .stdout(predicate::str::contains("✓ healthy"));
+}
+
+// ============================================================================
+// syntropy doctor --json
+// ============================================================================
+
+#[test]
+fn test_doctor_json_reports_plugin_statuses() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("healthy", VALID_PLUGIN);
+    fixture.create_plugin("broken", BROKEN_PLUGIN);
+
+    let output = doctor(&fixture).arg("--json").output().unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let plugins = report["plugins"].as_array().unwrap();
+    let status_of = |name: &str| {
+        plugins
+            .iter()
+            .find(|p| p["name"] == name)
+            .map(|p| p["status"].as_str().unwrap().to_string())
+    };
+
+    assert_eq!(status_of("healthy").as_deref(), Some("ok"));
+    assert_eq!(status_of("broken").as_deref(), Some("error"));
+    assert!(
+        plugins.iter().find(|p| p["name"] == "broken").unwrap()["error"]
+            .as_str()
+            .is_some()
+    );
+    assert!(
+        report["config_file"]
+            .as_str()
+            .unwrap()
+            .ends_with("syntropy.toml")
+    );
+    assert_eq!(report["plugin_directories"].as_array().unwrap().len(), 2);
+}
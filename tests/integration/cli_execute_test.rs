@@ -6,6 +6,10 @@ use assert_cmd::Command;
 use predicates::prelude::*;
 
 use crate::common::TestFixture;
+use syntropy::execution::{
+    EXIT_FAILURE, EXIT_ITEM_NOT_FOUND, EXIT_LUA_ERROR, EXIT_PLUGIN_NOT_FOUND, EXIT_PROCESS_ERROR,
+    EXIT_TASK_NOT_FOUND, ExecutionPlan,
+};
 
 // ============================================================================
 // Mock Plugin Templates and Config
@@ -184,7 +188,7 @@ fn execute_with_nonexistent_plugin() {
         .arg("--task")
         .arg("foo")
         .assert()
-        .failure()
+        .code(EXIT_PLUGIN_NOT_FOUND)
         .stderr(predicate::str::contains("Plugin 'nonexistent' not found"))
         .stderr(predicate::str::contains("Available plugins:"));
 }
@@ -204,13 +208,93 @@ fn execute_with_nonexistent_task() {
         .arg("--task")
         .arg("nonexistent_task")
         .assert()
-        .failure()
+        .code(EXIT_TASK_NOT_FOUND)
         .stderr(predicate::str::contains(
             "Task 'nonexistent_task' not found",
         ))
         .stderr(predicate::str::contains("Available tasks:"));
 }
 
+#[test]
+fn execute_suggests_plugin_for_one_character_typo() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test-plugin", SIMPLE_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test-plugn", "--task", "test_task"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Plugin 'test-plugn' not found. Did you mean 'test-plugin'? Available plugins:",
+        ));
+}
+
+#[test]
+fn execute_suggests_task_for_one_character_typo() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test-plugin", SIMPLE_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test-plugin", "--task", "test_tsk"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'test_task'?"))
+        .stderr(predicate::str::contains("Available tasks:"));
+}
+
+#[test]
+fn execute_matches_plugin_and_task_case_insensitively() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test-plugin", SIMPLE_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "Test-Plugin", "--task", "TEST_TASK"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Executed 3 items"))
+        .stderr(predicate::str::contains(
+            "Info: Matched plugin 'test-plugin' case-insensitively for 'Test-Plugin'",
+        ))
+        .stderr(predicate::str::contains(
+            "Info: Matched task 'test_task' case-insensitively for 'TEST_TASK'",
+        ));
+}
+
+#[test]
+fn execute_with_ambiguous_case_insensitive_task_errors() {
+    const CASE_COLLISION_PLUGIN: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        Deploy = {description = "Test task", execute = function() return "upper", 0 end},
+        DEPLOY = {description = "Test task", execute = function() return "caps", 0 end},
+    },
+}
+"#;
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", CASE_COLLISION_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", "deploy"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Ambiguous task 'deploy', it matches case-insensitively: DEPLOY, Deploy",
+        ));
+}
+
 // ============================================================================
 // Test 6-9: Success Paths
 // ============================================================================
@@ -486,6 +570,34 @@ return {
     assert!(stdout.contains("Negative exit code"));
 }
 
+#[test]
+fn execute_with_negative_exit_code_and_warning_disabled() {
+    const NEGATIVE_EXIT_CODE: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        negative = {
+            description = "Test task",
+            name = "Negative Exit Code Task",
+            execute = function() return "Negative exit code", -1 end,
+        },
+    },
+}
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "warn_on_exit_clamp = false\n");
+    fixture.create_plugin("test", NEGATIVE_EXIT_CODE);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", "negative"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("clamped").not());
+}
+
 #[test]
 fn execute_with_exit_code_greater_than_255() {
     // Validates that exit codes >255 are clamped to 255 with warning
@@ -604,11 +716,7 @@ confirm = "<enter>"
 // Test 17-21: Additional Edge Cases (5 tests)
 // ============================================================================
 
-#[test]
-fn execute_with_empty_items_array() {
-    // Verifies that when items() returns an empty array, execute is NOT called
-    // This is the correct behavior - empty items should skip execution entirely
-    const EMPTY_ITEMS: &str = r#"
+const EMPTY_ITEMS: &str = r#"
 return {
     metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
     tasks = {
@@ -628,6 +736,10 @@ return {
 }
 "#;
 
+#[test]
+fn execute_with_empty_items_array() {
+    // Verifies that when items() returns an empty array, execute is NOT called
+    // This is the correct behavior - empty items should skip execution entirely
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("test", EMPTY_ITEMS);
@@ -641,12 +753,57 @@ return {
         .arg("--task")
         .arg("empty")
         .assert()
-        .success()
+        .code(0)
         // When items are empty, execute is NOT called, but we show a message indicating no items were executed
         .stdout(predicate::str::contains("No items were executed"))
         .stderr(predicate::str::contains("all 0 item"));
 }
 
+#[test]
+fn execute_fail_on_empty_exits_3_without_items() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", EMPTY_ITEMS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plugin")
+        .arg("test")
+        .arg("--task")
+        .arg("empty")
+        .arg("--fail-on-empty")
+        .assert()
+        .code(3)
+        .stdout(predicate::str::contains("No items were executed"));
+}
+
+#[test]
+fn execute_fail_on_empty_exits_0_with_items() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin(
+        "test",
+        &EMPTY_ITEMS.replace("return {} end", "return {\"a\", \"b\"} end"),
+    );
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plugin")
+        .arg("test")
+        .arg("--task")
+        .arg("empty")
+        .arg("--items")
+        .arg("a")
+        .arg("--fail-on-empty")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("Processed 1 items"));
+}
+
 #[test]
 fn execute_with_items_function_error() {
     const ITEMS_ERROR: &str = r#"
@@ -883,7 +1040,7 @@ return {
         single_select = {
             description = "Test task",
             name = "Single Select Task",
-            mode = "none",
+            mode = "single",
             item_sources = {
                 src = {
                     tag = "s",
@@ -903,7 +1060,7 @@ return {
         only_one = {
             description = "Test task",
             name = "Single Item Task",
-            mode = "none",
+            mode = "single",
             item_sources = {
                 src = {
                     tag = "s",
@@ -966,8 +1123,8 @@ return {
 // ----------------------------------------------------------------------------
 
 #[test]
-fn item_flag_with_mode_none_succeeds() {
-    // Tests that mode="none" tasks work with --items flag
+fn item_flag_with_mode_single_succeeds() {
+    // Tests that mode="single" tasks work with --items flag
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("test", PLUGIN_MODE_NONE_MULTIPLE_ITEMS);
@@ -1013,8 +1170,8 @@ fn item_flag_with_mode_multi_overrides_preselection() {
 }
 
 #[test]
-fn mode_none_without_item_flag_multiple_items_errors() {
-    // Tests that mode="none" with multiple items requires --items flag
+fn mode_single_without_item_flag_multiple_items_errors() {
+    // Tests that mode="single" with multiple items requires --items flag
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("test", PLUGIN_MODE_NONE_MULTIPLE_ITEMS);
@@ -1029,14 +1186,14 @@ fn mode_none_without_item_flag_multiple_items_errors() {
         .arg("single_select")
         .assert()
         .failure()
-        .stderr(predicate::str::contains("mode='none'"))
+        .stderr(predicate::str::contains("mode='single'"))
         .stderr(predicate::str::contains("requires single-item selection"))
         .stderr(predicate::str::contains("Available items:"));
 }
 
 #[test]
-fn mode_none_without_item_flag_single_item_succeeds() {
-    // Tests that mode="none" with single item works without --items
+fn mode_single_without_item_flag_single_item_succeeds() {
+    // Tests that mode="single" with single item works without --items
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("test", PLUGIN_MODE_NONE_SINGLE_ITEM);
@@ -1072,7 +1229,7 @@ fn item_not_found_shows_available_items() {
         .arg("--items")
         .arg("nonexistent")
         .assert()
-        .failure()
+        .code(EXIT_ITEM_NOT_FOUND)
         .stderr(predicate::str::contains("Item 'nonexistent' not found"))
         .stderr(predicate::str::contains("Available items:"))
         .stderr(predicate::str::contains("item1"));
@@ -3101,7 +3258,7 @@ fn produce_items_lua_error() {
         .arg("failing_items")
         .arg("--produce-items")
         .assert()
-        .failure()
+        .code(EXIT_LUA_ERROR)
         .stderr(predicate::str::contains("Failed to fetch items"));
 }
 
@@ -3422,3 +3579,702 @@ fn produce_items_with_special_chars() {
         .stdout(predicate::str::contains("item<>?"))
         .stdout(predicate::str::contains("item|&"));
 }
+
+// ============================================================================
+// --sort Tests for --produce-* Output
+// ============================================================================
+
+fn produce_items_sorted(sort_args: &[&str]) -> String {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plugin")
+        .arg("test")
+        .arg("--task")
+        .arg("multi_source")
+        .arg("--produce-items")
+        .args(sort_args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn produce_items_sort_alpha_ignores_tags() {
+    assert_eq!(
+        produce_items_sorted(&["--sort", "alpha"]),
+        "[cask] Docker\n[pkg] git\n[cask] iTerm2\n[pkg] node\n[pkg] npm\n"
+    );
+}
+
+#[test]
+fn produce_items_sort_alpha_desc() {
+    assert_eq!(
+        produce_items_sorted(&["--sort", "alpha-desc"]),
+        "[pkg] npm\n[pkg] node\n[cask] iTerm2\n[pkg] git\n[cask] Docker\n"
+    );
+}
+
+#[test]
+fn produce_items_sort_by_tagged_string() {
+    assert_eq!(
+        produce_items_sorted(&["--sort", "alpha", "--sort-by", "tagged"]),
+        "[cask] Docker\n[cask] iTerm2\n[pkg] git\n[pkg] node\n[pkg] npm\n"
+    );
+}
+
+#[test]
+fn sort_by_requires_sort() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plugin")
+        .arg("test")
+        .arg("--task")
+        .arg("multi_source")
+        .arg("--produce-items")
+        .arg("--sort-by")
+        .arg("tagged")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--sort <SORT>"));
+}
+
+#[test]
+fn sort_requires_a_produce_flag() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plugin")
+        .arg("test")
+        .arg("--task")
+        .arg("multi_source")
+        .arg("--sort")
+        .arg("alpha")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--produce-items|"));
+}
+
+// ============================================================================
+// --format json
+// ============================================================================
+
+fn execute_json(plugin: &str, args: &[&str]) -> std::process::Output {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", plugin);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn format_json_reports_sources_and_exit_code() {
+    let output = execute_json(
+        PLUGIN_WITH_PRESELECTION,
+        &["--plugin", "test", "--task", "selective"],
+    );
+
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["plugin"], "test");
+    assert_eq!(report["task"], "selective");
+    assert_eq!(report["total_exit_code"], 0);
+    assert!(report["duration_ms"].is_u64());
+    assert_eq!(
+        report["sources"],
+        serde_json::json!([{
+            "source": "src",
+            "items_executed": 2,
+            "output": "Processed: b,d",
+            "exit_code": 0,
+        }])
+    );
+}
+
+#[test]
+fn format_json_reports_each_source_of_multi_source_task() {
+    let output = execute_json(
+        PLUGIN_MULTISOURCE_WITH_TAGS,
+        &[
+            "--plugin",
+            "test",
+            "--task",
+            "multi_source",
+            "--items",
+            "[pkg] git,[cask] Docker",
+        ],
+    );
+
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut sources: Vec<_> = report["sources"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|source| {
+            (
+                source["source"].as_str().unwrap().to_string(),
+                source["output"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    sources.sort();
+    assert_eq!(
+        sources,
+        vec![
+            ("cask".to_string(), "Cask: Docker".to_string()),
+            ("packages".to_string(), "Packages: git".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn format_json_standalone_task_has_single_unnamed_source() {
+    let output = execute_json(FAILING_TASK, &["--plugin", "test", "--task", "failing"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["total_exit_code"], 1);
+    assert_eq!(
+        report["sources"],
+        serde_json::json!([{
+            "source": null,
+            "items_executed": 0,
+            "output": "Task failed",
+            "exit_code": 1,
+        }])
+    );
+}
+
+#[test]
+fn format_json_unknown_plugin_prints_json_error() {
+    let output = execute_json(STANDALONE_TASK, &["--plugin", "missing", "--task", "x"]);
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(output.status.code(), Some(EXIT_PLUGIN_NOT_FOUND));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "NOT_FOUND");
+    assert_eq!(error["kind"], "plugin_not_found");
+    assert!(
+        error["error"]
+            .as_str()
+            .unwrap()
+            .contains("Plugin 'missing' not found")
+    );
+}
+
+#[test]
+fn format_json_unknown_task_prints_json_error() {
+    let output = execute_json(STANDALONE_TASK, &["--plugin", "test", "--task", "missing"]);
+
+    assert_eq!(output.status.code(), Some(EXIT_TASK_NOT_FOUND));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "NOT_FOUND");
+    assert_eq!(error["kind"], "task_not_found");
+}
+
+#[test]
+fn format_json_execution_error_prints_json_error() {
+    const RAISING_TASK: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        raising = {
+            description = "Test task",
+            execute = function() error("boom") end,
+        },
+    },
+}
+"#;
+    let output = execute_json(RAISING_TASK, &["--plugin", "test", "--task", "raising"]);
+
+    assert_eq!(output.status.code(), Some(EXIT_LUA_ERROR));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "EXECUTION_FAILED");
+    assert_eq!(error["kind"], "lua_error");
+    assert!(error["error"].as_str().unwrap().contains("boom"));
+}
+
+#[test]
+fn format_json_unknown_item_prints_json_error() {
+    let output = execute_json(
+        SIMPLE_PLUGIN,
+        &[
+            "--plugin",
+            "test-plugin",
+            "--task",
+            "test_task",
+            "--items",
+            "nope",
+        ],
+    );
+
+    assert_eq!(output.status.code(), Some(EXIT_ITEM_NOT_FOUND));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "EXECUTION_FAILED");
+    assert_eq!(error["kind"], "item_not_found");
+}
+
+#[test]
+fn format_json_failing_items_command_prints_json_error() {
+    const COMMAND_TASK: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        listing = {
+            description = "Test task",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items_command = "echo denied >&2; exit 2",
+                    execute = function(items) return "done", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+    let output = execute_json(COMMAND_TASK, &["--plugin", "test", "--task", "listing"]);
+
+    assert_eq!(output.status.code(), Some(EXIT_PROCESS_ERROR));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "EXECUTION_FAILED");
+    assert_eq!(error["kind"], "process_error");
+    assert!(error["error"].as_str().unwrap().contains("denied"));
+}
+
+#[test]
+fn format_json_other_failure_prints_execution_failed_kind() {
+    let output = execute_json(
+        STANDALONE_TASK,
+        &["--plugin", "test", "--task", "standalone", "--items", "x"],
+    );
+
+    assert_eq!(output.status.code(), Some(EXIT_FAILURE));
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["code"], "EXECUTION_FAILED");
+    assert_eq!(error["kind"], "execution_failed");
+}
+
+#[test]
+fn format_json_conflicts_with_produce_items() {
+    let output = execute_json(
+        SIMPLE_PLUGIN,
+        &[
+            "--plugin",
+            "test-plugin",
+            "--task",
+            "test_task",
+            "--produce-items",
+        ],
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+// ============================================================================
+// --source Flag
+// ============================================================================
+
+fn execute_multi_source(args: &[&str]) -> assert_cmd::assert::Assert {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", "multi_source"])
+        .args(args)
+        .assert()
+}
+
+#[test]
+fn source_limits_execution_to_one_item_source() {
+    execute_multi_source(&["--source", "pkg"])
+        .success()
+        .stdout(predicate::str::contains("Packages: git,node,npm"))
+        .stdout(predicate::str::contains("Cask").not());
+}
+
+#[test]
+fn source_composes_with_items() {
+    execute_multi_source(&["--source", "cask", "--items", "Docker"])
+        .success()
+        .stdout("Cask: Docker\n");
+}
+
+#[test]
+fn source_limits_produce_items_to_untagged_source_items() {
+    execute_multi_source(&["--source", "cask", "--produce-items"])
+        .success()
+        .stdout("iTerm2\nDocker\n");
+}
+
+#[test]
+fn source_unknown_tag_lists_available_tags() {
+    execute_multi_source(&["--source", "brew"])
+        .failure()
+        .stderr(predicate::str::contains(
+            "Source tag 'brew' not found in task 'multi_source'. Available tags: cask, pkg",
+        ));
+}
+
+#[test]
+fn source_requires_task_with_item_sources() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", STANDALONE_TASK);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "test",
+            "--task",
+            "standalone",
+            "--source",
+            "pkg",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "The --source flag requires a task with item sources",
+        ));
+}
+
+// ============================================================================
+// --env and --env-file Flags
+// ============================================================================
+
+const ENV_PLUGIN: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        show_env = {
+            description = "Test task",
+            execute = function()
+                local shell_output = syntropy.shell("echo shell:$SYNTROPY_TEST_STAGE")
+                return "lua:" .. tostring(os.getenv("SYNTROPY_TEST_STAGE")) .. "\n" .. shell_output, 0
+            end,
+        },
+    },
+}
+"#;
+
+fn execute_env(fixture: &TestFixture, args: &[&str]) -> assert_cmd::assert::Assert {
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", ENV_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env_remove("SYNTROPY_TEST_STAGE")
+        .args(["execute", "--plugin", "test", "--task", "show_env"])
+        .args(args)
+        .assert()
+}
+
+#[test]
+fn env_flag_is_visible_to_lua_and_shell() {
+    let fixture = TestFixture::new();
+
+    execute_env(&fixture, &["--env", "SYNTROPY_TEST_STAGE=prod"])
+        .success()
+        .stdout(predicate::str::contains("lua:prod"))
+        .stdout(predicate::str::contains("shell:prod"));
+}
+
+#[test]
+fn env_flag_requires_single_equals() {
+    let fixture = TestFixture::new();
+
+    execute_env(&fixture, &["--env", "SYNTROPY_TEST_STAGE"])
+        .failure()
+        .stderr(predicate::str::contains("must contain exactly one '='"));
+}
+
+#[test]
+fn env_file_is_loaded_and_env_flag_takes_precedence() {
+    let fixture = TestFixture::new();
+    let env_file = fixture.config_path().join("stage.env");
+    std::fs::create_dir_all(fixture.config_path()).unwrap();
+    std::fs::write(&env_file, "# staging\nSYNTROPY_TEST_STAGE=staging\n").unwrap();
+    let env_file = env_file.to_string_lossy().into_owned();
+
+    execute_env(&fixture, &["--env-file", &env_file])
+        .success()
+        .stdout(predicate::str::contains("lua:staging"));
+
+    execute_env(
+        &fixture,
+        &["--env-file", &env_file, "--env", "SYNTROPY_TEST_STAGE=prod"],
+    )
+    .success()
+    .stdout(predicate::str::contains("shell:prod"));
+}
+
+#[test]
+fn env_flag_warns_when_overriding_existing_variable() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", ENV_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("SYNTROPY_TEST_STAGE", "dev")
+        .args(["execute", "--plugin", "test", "--task", "show_env"])
+        .args(["--env", "SYNTROPY_TEST_STAGE=prod"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("lua:prod"))
+        .stderr(predicate::str::contains(
+            "Warning: Overriding existing environment variable 'SYNTROPY_TEST_STAGE'",
+        ));
+}
+
+// ============================================================================
+// --plan Flag
+// ============================================================================
+
+fn execute_plan(fixture: &TestFixture, plan: &ExecutionPlan) -> assert_cmd::assert::Assert {
+    let plan_path = fixture.data_path().join("plan.json");
+    plan.save(&plan_path).unwrap();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("execute")
+        .arg("--plan")
+        .arg(&plan_path)
+        .assert()
+}
+
+#[test]
+fn plan_runs_recorded_tagged_items() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+    let plan = ExecutionPlan::new("test", "multi_source", vec!["[cask] Docker".to_string()]);
+
+    execute_plan(&fixture, &plan)
+        .success()
+        .stdout("Cask: Docker\n");
+}
+
+#[test]
+fn plan_runs_standalone_task() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", STANDALONE_TASK);
+    let plan = ExecutionPlan::new("test", "standalone", vec![]);
+
+    execute_plan(&fixture, &plan)
+        .success()
+        .stdout(predicate::str::contains("Task completed"));
+}
+
+#[test]
+fn plan_with_unknown_item_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN_MULTISOURCE_WITH_TAGS);
+    let plan = ExecutionPlan::new("test", "multi_source", vec!["[pkg] brew".to_string()]);
+
+    execute_plan(&fixture, &plan)
+        .failure()
+        .stderr(predicate::str::contains("Item '[pkg] brew' not found"));
+}
+
+#[test]
+fn plan_conflicts_with_plugin_and_task() {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args(["execute", "--plan", "plan.json", "--plugin", "test"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// ============================================================================
+// Execution Confirmation Tests
+// ============================================================================
+
+const CONFIRMATION_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "confirm",
+        version = "1.0.0",
+    },
+    tasks = {
+        wipe = {
+            description = "Wipes the items",
+            mode = "multi",
+            execution_confirmation_message = "Wipe these?",
+            item_sources = {
+                src = {
+                    tag = "w",
+                    items = function() return {"a", "b"} end,
+                    execute = function(items) return "Wiped " .. #items .. " items", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+#[test]
+fn execute_ignores_execution_confirmation_message() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("confirm", CONFIRMATION_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "confirm", "--task", "wipe"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wiped 2 items"))
+        .stderr(predicate::str::contains("Wipe these?").not());
+}
+
+#[test]
+fn execute_produce_items_does_not_ask_for_confirmation() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("confirm", CONFIRMATION_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "confirm",
+            "--task",
+            "wipe",
+            "--produce-items",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("a\nb"));
+}
+
+// ============================================================================
+// Background Task Tests
+// ============================================================================
+
+const BACKGROUND_PLUGIN: &str = r#"
+return {
+    metadata = {name = "bg", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        serve = {
+            description = "Serves one of the items",
+            mode = "background",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = function() return {"api", "web"} end,
+                    execute = function(items) return "Serving " .. items[1], 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn jobs_dir(fixture: &TestFixture) -> std::path::PathBuf {
+    fixture.data_path().join("syntropy").join("jobs")
+}
+
+fn execute_background(fixture: &TestFixture, items: Option<&str>) -> assert_cmd::assert::Assert {
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("bg", BACKGROUND_PLUGIN);
+
+    let mut command = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    command
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "bg", "--task", "serve"]);
+    if let Some(items) = items {
+        command.args(["--items", items]);
+    }
+    command.assert()
+}
+
+#[test]
+fn background_task_detaches_and_prints_job_id() {
+    let fixture = TestFixture::new();
+
+    let output = execute_background(&fixture, Some("web")).success();
+    let job_id = String::from_utf8_lossy(&output.get_output().stdout)
+        .trim()
+        .to_string();
+    assert!(job_id.parse::<u32>().is_ok(), "job id: {:?}", job_id);
+
+    let log_path = jobs_dir(&fixture).join(format!("{}.log", job_id));
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while !std::fs::read_to_string(&log_path)
+        .unwrap_or_default()
+        .contains("Serving web")
+    {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "job log never showed the output"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn background_task_with_unknown_item_fails_without_detaching() {
+    let fixture = TestFixture::new();
+
+    execute_background(&fixture, Some("db"))
+        .code(EXIT_ITEM_NOT_FOUND)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Item 'db' not found"));
+    assert!(!jobs_dir(&fixture).exists());
+}
+
+#[test]
+fn background_task_with_several_items_requires_items_flag() {
+    let fixture = TestFixture::new();
+
+    execute_background(&fixture, None)
+        .code(EXIT_FAILURE)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("requires single-item selection"));
+    assert!(!jobs_dir(&fixture).exists());
+}
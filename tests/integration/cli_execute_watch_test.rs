@@ -0,0 +1,210 @@
+//! Integration tests for `execute --watch`
+//!
+//! The items are polled after the first run and the task is executed again, after a `---`
+//! separator, whenever they changed. With `--format json` the reports are printed one per
+//! line instead. Ctrl-C stops watching with exit code 130.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(unix)]
+use nix::sys::signal::{self, Signal};
+#[cfg(unix)]
+use nix::unistd::Pid;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+// Items are the lines of the file named by $WATCH_ITEMS_FILE
+const FILE_ITEMS_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "watcher",
+        version = "1.0.0",
+        description = "Items from a file",
+    },
+    tasks = {
+        apply = {
+            description = "Apply items",
+            mode = "multi",
+            item_sources = {
+                lines = {
+                    tag = "l",
+                    items = function()
+                        local items = {}
+                        for line in io.lines(os.getenv("WATCH_ITEMS_FILE")) do
+                            table.insert(items, line)
+                        end
+                        return items
+                    end,
+                    execute = function(items)
+                        return "Executed: " .. table.concat(items, ","), 0
+                    end,
+                },
+            },
+        },
+        standalone = {
+            description = "No items",
+            execute = function()
+                return "done", 0
+            end,
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn spawn_watch(fixture: &TestFixture, items_file: &Path, args: &[&str]) -> std::process::Child {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("WATCH_ITEMS_FILE", items_file)
+        .args([
+            "execute", "--plugin", "watcher", "--task", "apply", "--watch", "100",
+        ])
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn syntropy process")
+}
+
+#[cfg(unix)]
+fn interrupt(child: std::process::Child) -> Output {
+    signal::kill(Pid::from_raw(child.id() as i32), Signal::SIGINT).expect("Failed to send SIGINT");
+    child
+        .wait_with_output()
+        .expect("Failed to wait for syntropy process")
+}
+
+// ============================================================================
+// Watching
+// ============================================================================
+
+#[test]
+#[cfg(unix)]
+fn test_watch_executes_again_when_items_change() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("watcher", FILE_ITEMS_PLUGIN);
+    let items_file = fixture.temp_dir.path().join("items.txt");
+    fs::write(&items_file, "a\n").unwrap();
+
+    let child = spawn_watch(&fixture, &items_file, &[]);
+    thread::sleep(Duration::from_millis(800));
+    fs::write(&items_file, "a\nb\n").unwrap();
+    thread::sleep(Duration::from_millis(800));
+    let output = interrupt(child);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        ["Executed: a", "---", "Executed: a,b"],
+        "Unexpected output: {}",
+        stdout
+    );
+    assert_eq!(output.status.code(), Some(130));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_watch_skips_execution_while_items_are_unchanged() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("watcher", FILE_ITEMS_PLUGIN);
+    let items_file = fixture.temp_dir.path().join("items.txt");
+    fs::write(&items_file, "a\n").unwrap();
+
+    let child = spawn_watch(&fixture, &items_file, &[]);
+    thread::sleep(Duration::from_millis(800));
+    let output = interrupt(child);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "Executed: a");
+    assert_eq!(output.status.code(), Some(130));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_watch_json_prints_one_report_per_line() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("watcher", FILE_ITEMS_PLUGIN);
+    let items_file = fixture.temp_dir.path().join("items.txt");
+    fs::write(&items_file, "a\n").unwrap();
+
+    let child = spawn_watch(&fixture, &items_file, &["--format", "json"]);
+    thread::sleep(Duration::from_millis(800));
+    fs::write(&items_file, "a\nb\n").unwrap();
+    thread::sleep(Duration::from_millis(800));
+    let output = interrupt(child);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let reports: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Every line should be a JSON report"))
+        .collect();
+    assert_eq!(reports.len(), 2, "Unexpected output: {}", stdout);
+    assert_eq!(reports[1]["sources"][0]["output"], "Executed: a,b");
+}
+
+// ============================================================================
+// Argument Validation
+// ============================================================================
+
+#[test]
+fn test_watch_requires_item_sources() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("watcher", FILE_ITEMS_PLUGIN);
+
+    assert_cmd::Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "watcher",
+            "--task",
+            "standalone",
+            "--watch",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "The --watch flag requires a task with item sources",
+        ));
+}
+
+#[test]
+fn test_watch_conflicts_with_produce_items() {
+    assert_cmd::Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args([
+            "execute",
+            "--plugin",
+            "watcher",
+            "--task",
+            "apply",
+            "--watch",
+            "--produce-items",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_watch_rejects_zero_interval() {
+    assert_cmd::Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args([
+            "execute", "--plugin", "watcher", "--task", "apply", "--watch", "0",
+        ])
+        .assert()
+        .failure();
+}
@@ -330,6 +330,32 @@ fn test_list_plugin_not_found_shows_available_plugins() {
         .stderr(predicate::str::contains("list-test-plugin"));
 }
 
+#[test]
+fn test_list_plugin_tasks_follow_group_and_order() {
+    const ORDERED_PLUGIN: &str = r#"
+return {
+    metadata = {name = "ordered", version = "1.0.0", icon = "O", platforms = {"macos", "linux"}},
+    tasks = {
+        alpha = {description = "Alpha", group = "dev", order = 2, execute = function() return "", 0 end},
+        beta = {description = "Beta", group = "dev", order = 1, execute = function() return "", 0 end},
+        gamma = {description = "Gamma", execute = function() return "", 0 end},
+    },
+}
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("ordered", ORDERED_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugin", "ordered"])
+        .assert()
+        .success()
+        .stdout("gamma - Gamma\nbeta - Beta\nalpha - Alpha\n");
+}
+
 // ============================================================================
 // syntropy list --plugin NAME --task KEY — Task Detail
 // ============================================================================
@@ -400,7 +426,7 @@ fn test_list_task_shows_mode_multi() {
 }
 
 #[test]
-fn test_list_task_shows_mode_none_for_standalone() {
+fn test_list_task_shows_mode_single_for_standalone() {
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
@@ -417,7 +443,7 @@ fn test_list_task_shows_mode_none_for_standalone() {
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("none"));
+        .stdout(predicate::str::contains("single"));
 }
 
 #[test]
@@ -531,7 +557,7 @@ fn test_list_task_detail_includes_key() {
 }
 
 // ============================================================================
-// S4: Mode field uses exact Display strings "multi" and "none"
+// S4: Mode field uses exact Display strings "multi" and "single"
 // ============================================================================
 
 #[test]
@@ -556,7 +582,7 @@ fn test_list_task_mode_multi_exact_label() {
 }
 
 #[test]
-fn test_list_task_mode_none_exact_label() {
+fn test_list_task_mode_single_exact_label() {
     let fixture = TestFixture::new();
     fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
     fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
@@ -573,7 +599,7 @@ fn test_list_task_mode_none_exact_label() {
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("mode: none"));
+        .stdout(predicate::str::contains("mode: single"));
 }
 
 // ============================================================================
@@ -741,6 +767,212 @@ fn test_list_task_with_two_sources_shows_count() {
         .stdout(predicate::str::contains("item_sources: 2"));
 }
 
+// ============================================================================
+// Script-friendly Listings (--plugins, --tasks, --sources, --format)
+// ============================================================================
+
+#[test]
+fn test_list_plugins_flag_prints_tab_separated_rows() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
+    fixture.create_plugin("second-plugin", SECOND_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugins"])
+        .assert()
+        .success()
+        .stdout(
+            "list-test-plugin\tA plugin for list testing\n\
+             second-plugin\tThe second plugin\n",
+        );
+}
+
+#[test]
+fn test_list_tasks_flag_prints_key_description_and_mode() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--tasks", "list-test-plugin"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "multi_task\tA task with item sources and multi mode\tmulti\n",
+        ))
+        .stdout(predicate::str::contains(
+            "standalone\tAn execute-only task with no item sources\tsingle\n",
+        ));
+}
+
+#[test]
+fn test_list_tasks_flag_unknown_plugin_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--tasks", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Plugin 'missing' not found"));
+}
+
+#[test]
+fn test_list_sources_flag_prints_tag_and_item_count() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("multisource-plugin", PLUGIN_WITH_TWO_SOURCES);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--sources", "multisource-plugin", "dual_task"])
+        .assert()
+        .success()
+        .stdout("one\t2\ntwo\t2\n");
+}
+
+#[test]
+fn test_list_sources_flag_unknown_task_fails() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("multisource-plugin", PLUGIN_WITH_TWO_SOURCES);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--sources", "multisource-plugin", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 'missing' not found in plugin 'multisource-plugin'",
+        ));
+}
+
+#[test]
+fn test_list_plugins_json_format() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("list-test-plugin", RICH_PLUGIN);
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugins", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let listing: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        listing,
+        serde_json::json!([
+            {"name": "list-test-plugin", "description": "A plugin for list testing"}
+        ])
+    );
+}
+
+#[test]
+fn test_list_tasks_json_format_includes_mode() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("second-plugin", SECOND_PLUGIN);
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--tasks", "second-plugin", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let listing: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        listing,
+        serde_json::json!([
+            {"key": "only_task", "description": "The only task in this plugin", "mode": "single"}
+        ])
+    );
+}
+
+#[test]
+fn test_list_sources_json_format() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("multisource-plugin", PLUGIN_WITH_TWO_SOURCES);
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "list",
+            "--sources",
+            "multisource-plugin",
+            "dual_task",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let listing: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        listing,
+        serde_json::json!([
+            {"key": "source_one", "tag": "one", "item_count": 2},
+            {"key": "source_two", "tag": "two", "item_count": 2}
+        ])
+    );
+}
+
+#[test]
+fn test_list_format_requires_listing_flag() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--format", "json"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_list_listing_flags_conflict_with_plugin_flag() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugins", "--plugin", "some-plugin"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_list_listing_flags_are_mutually_exclusive() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugins", "--tasks", "some-plugin"])
+        .assert()
+        .failure();
+}
+
 // ============================================================================
 // Error Cases
 // ============================================================================
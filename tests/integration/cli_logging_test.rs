@@ -0,0 +1,123 @@
+//! Integration tests for --log-level and --log-file
+//!
+//! Tests that log events reach the chosen file and stay off stderr unless requested.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+use crate::common::TestFixture;
+
+const PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "test-plugin",
+        version = "1.0.0",
+        description = "Plugin for logging tests",
+    },
+    tasks = {
+        test_task = {
+            description = "Echo the selection",
+            mode = "none",
+            item_sources = {
+                src = {
+                    tag = "t",
+                    items = function() return {"item1", "item2"} end,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Log file
+// ============================================================================
+
+#[test]
+fn test_debug_level_writes_plugin_load_to_log_file() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+    let log_file = fixture.data_path().join("logs").join("syntropy.log");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["--log-level", "debug", "--log-file"])
+        .arg(&log_file)
+        .args(["list", "--plugins"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Loaded plugin").not());
+
+    let log = fs::read_to_string(&log_file).unwrap();
+    assert!(log.contains("DEBUG"), "log: {}", log);
+    assert!(log.contains("Loaded plugin"), "log: {}", log);
+    assert!(log.contains("test-plugin"), "log: {}", log);
+}
+
+#[test]
+fn test_log_file_without_level_logs_info() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+    let log_file = fixture.data_path().join("syntropy.log");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("--log-file")
+        .arg(&log_file)
+        .args([
+            "execute",
+            "--plugin",
+            "test-plugin",
+            "--task",
+            "test_task",
+            "--items",
+            "item1",
+        ])
+        .assert()
+        .success();
+
+    let log = fs::read_to_string(&log_file).unwrap();
+    assert!(log.contains("Task finished"), "log: {}", log);
+    assert!(
+        !log.contains("Loaded plugin"),
+        "debug events are filtered: {}",
+        log
+    );
+}
+
+// ============================================================================
+// Defaults
+// ============================================================================
+
+#[test]
+fn test_logging_is_off_by_default() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["list", "--plugins"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_log_level_without_file_logs_commands_to_stderr() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test-plugin", PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["--log-level", "debug", "list", "--plugins"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Loaded plugin").not())
+        .stderr(predicate::str::contains("Loaded plugin"));
+}
@@ -0,0 +1,192 @@
+//! Integration tests for --plugin-dir, --plugin-dir-append and glob plugin_paths
+//!
+//! Tests that plugins load from directories given on the command line, replacing or
+//! extending the configured plugin directories for that invocation, and from every
+//! directory a glob in plugin_paths matches.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::{fs, path::PathBuf};
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+const DEV_PLUGIN: &str = r#"
+return {
+    metadata = {name = "dev-plugin", version = "1.0.0", icon = "D"},
+    tasks = {
+        hello = {
+            description = "Development task",
+            execute = function() return "hello from the plugin dir", 0 end,
+        },
+    },
+}
+"#;
+
+const INSTALLED_PLUGIN: &str = r#"
+return {
+    metadata = {name = "installed-plugin", version = "1.0.0", icon = "I"},
+    tasks = {
+        hello = {
+            description = "Installed task",
+            execute = function() return "hello from the data dir", 0 end,
+        },
+    },
+}
+"#;
+
+// Creates `<temp>/dev/dev-plugin/plugin.lua` outside the XDG directories and returns
+// `<temp>/dev`
+fn create_plugin_dir(fixture: &TestFixture) -> PathBuf {
+    let plugin_dir = fixture.temp_dir.path().join("dev");
+    fs::create_dir_all(plugin_dir.join("dev-plugin")).unwrap();
+    fs::write(plugin_dir.join("dev-plugin").join("plugin.lua"), DEV_PLUGIN).unwrap();
+    plugin_dir
+}
+
+fn syntropy(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path());
+    cmd
+}
+
+// ============================================================================
+// --plugin-dir
+// ============================================================================
+
+#[test]
+fn plugin_dir_executes_plugin_outside_xdg_paths() {
+    let fixture = TestFixture::new();
+    let plugin_dir = create_plugin_dir(&fixture);
+
+    syntropy(&fixture)
+        .arg("--plugin-dir")
+        .arg(&plugin_dir)
+        .args(["execute", "--plugin", "dev-plugin", "--task", "hello"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello from the plugin dir"));
+}
+
+#[test]
+fn plugin_dir_replaces_configured_plugin_directories() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("installed-plugin", INSTALLED_PLUGIN);
+    let plugin_dir = create_plugin_dir(&fixture);
+
+    syntropy(&fixture)
+        .arg("--plugin-dir")
+        .arg(&plugin_dir)
+        .args(["execute", "--plugin", "installed-plugin", "--task", "hello"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn plugin_dir_append_keeps_configured_plugin_directories() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("installed-plugin", INSTALLED_PLUGIN);
+    let plugin_dir = create_plugin_dir(&fixture);
+
+    syntropy(&fixture)
+        .arg("--plugin-dir")
+        .arg(&plugin_dir)
+        .args(["--plugin-dir-append", "list", "--plugins"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dev-plugin"))
+        .stdout(predicate::str::contains("installed-plugin"));
+}
+
+#[test]
+fn plugin_dir_is_repeatable() {
+    let fixture = TestFixture::new();
+    let plugin_dir = create_plugin_dir(&fixture);
+    let other_dir = fixture.temp_dir.path().join("other");
+    fs::create_dir_all(other_dir.join("installed-plugin")).unwrap();
+    fs::write(
+        other_dir.join("installed-plugin").join("plugin.lua"),
+        INSTALLED_PLUGIN,
+    )
+    .unwrap();
+
+    syntropy(&fixture)
+        .arg("--plugin-dir")
+        .arg(&plugin_dir)
+        .arg("--plugin-dir")
+        .arg(&other_dir)
+        .args(["list", "--plugins"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dev-plugin"))
+        .stdout(predicate::str::contains("installed-plugin"));
+}
+
+#[test]
+fn plugin_dir_missing_directory_fails() {
+    let fixture = TestFixture::new();
+    let missing = fixture.temp_dir.path().join("missing");
+
+    syntropy(&fixture)
+        .arg("--plugin-dir")
+        .arg(&missing)
+        .args(["list", "--plugins"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Plugin directory"))
+        .stderr(predicate::str::contains("does not exist"));
+}
+
+#[test]
+fn plugin_dir_append_requires_plugin_dir() {
+    let fixture = TestFixture::new();
+
+    syntropy(&fixture)
+        .args(["--plugin-dir-append", "list", "--plugins"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--plugin-dir"));
+}
+
+// ============================================================================
+// Glob plugin_paths
+// ============================================================================
+
+#[test]
+fn glob_plugin_path_loads_plugins_from_every_matching_directory() {
+    let fixture = TestFixture::new();
+    let groups = fixture.temp_dir.path().join("groups");
+    for (group, name, greeting) in [
+        ("work", "work-plugin", "hello from work"),
+        ("personal", "personal-plugin", "hello from home"),
+    ] {
+        let plugin_dir = groups.join(group).join(name);
+        fs::create_dir_all(&plugin_dir).unwrap();
+        fs::write(
+            plugin_dir.join("plugin.lua"),
+            DEV_PLUGIN
+                .replace("dev-plugin", name)
+                .replace("hello from the plugin dir", greeting),
+        )
+        .unwrap();
+    }
+    fixture.create_config(
+        "syntropy.toml",
+        &format!("plugin_paths = [\"{}/*\"]\n", groups.display()),
+    );
+
+    for (name, greeting) in [
+        ("work-plugin", "hello from work"),
+        ("personal-plugin", "hello from home"),
+    ] {
+        syntropy(&fixture)
+            .args(["execute", "--plugin", name, "--task", "hello"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(greeting));
+    }
+}
@@ -0,0 +1,134 @@
+//! Integration tests for `syntropy plugins --check`
+//!
+//! Covers the per-plugin status lines and the exit codes: 0 when every plugin loads, 1 when
+//! one fails to load and 2 when one is only skipped on this platform.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+const VALID_PLUGIN: &str = r#"
+return {
+    metadata = {name = "notes", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        run = {
+            name = "Run",
+            description = "Loads fine",
+            execute = function()
+                return "ran", 0
+            end,
+        },
+    },
+}
+"#;
+
+const BROKEN_PLUGIN: &str = r#"
+return {
+    metadata = {name = "broken", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {},
+}
+"#;
+
+const WINDOWS_PLUGIN: &str = r#"
+return {
+    metadata = {name = "windows-only", version = "1.0.0", platforms = {"windows"}},
+    tasks = {
+        run = {
+            name = "Run",
+            description = "Only runs on windows",
+            execute = function()
+                return "ran", 0
+            end,
+        },
+    },
+}
+"#;
+
+fn check(fixture: &TestFixture) -> assert_cmd::assert::Assert {
+    fixture.create_config("syntropy.toml", "");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["plugins", "--check"])
+        .assert()
+}
+
+// ============================================================================
+// Exit codes
+// ============================================================================
+
+#[test]
+fn test_check_succeeds_when_all_plugins_load() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", VALID_PLUGIN);
+
+    check(&fixture)
+        .code(0)
+        .stdout(predicate::str::is_match(r"✓ notes  ok\s+\d+\.\dms").unwrap())
+        .stdout(predicate::str::contains(
+            "1 plugins checked: 1 ok, 0 with errors, 0 with warnings",
+        ));
+}
+
+#[test]
+fn test_check_exits_1_when_a_plugin_fails_to_load() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", VALID_PLUGIN);
+    fixture.create_plugin("broken", BROKEN_PLUGIN);
+
+    check(&fixture)
+        .code(1)
+        .stdout(predicate::str::contains("✗ broken"))
+        .stdout(predicate::str::contains("error"))
+        .stdout(predicate::str::contains("✓ notes"));
+}
+
+#[test]
+fn test_check_exits_2_when_a_plugin_only_has_warnings() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("windows-only", WINDOWS_PLUGIN);
+
+    check(&fixture)
+        .code(2)
+        .stdout(predicate::str::contains("⚠ windows-only  warning"));
+}
+
+#[test]
+fn test_check_errors_take_precedence_over_warnings() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("windows-only", WINDOWS_PLUGIN);
+    fixture.create_plugin("broken", BROKEN_PLUGIN);
+
+    check(&fixture).code(1).stdout(predicate::str::contains(
+        "2 plugins checked: 0 ok, 1 with errors, 1 with warnings",
+    ));
+}
+
+#[test]
+fn test_check_without_plugins() {
+    let fixture = TestFixture::new();
+
+    check(&fixture)
+        .code(0)
+        .stdout(predicate::str::contains("No plugins found."));
+}
+
+#[test]
+fn test_check_is_exclusive_with_other_operations() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["plugins", "--check", "--list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Exactly one operation flag"));
+}
@@ -0,0 +1,172 @@
+//! Integration tests for `syntropy plugins scaffold`
+//!
+//! Covers the generated files, that the skeleton validates, runs and passes its own tests,
+//! the `--minimal` variant and refusing to overwrite or use invalid names.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn syntropy(fixture: &TestFixture, args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(args)
+        .assert()
+}
+
+fn plugin_dir(fixture: &TestFixture, name: &str) -> std::path::PathBuf {
+    fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join(name)
+}
+
+// ============================================================================
+// Generated files
+// ============================================================================
+
+#[test]
+fn test_scaffold_creates_plugin_module_and_test_file() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "scaffold", "my-plugin"])
+        .success()
+        .stdout(predicate::str::contains("Created plugin 'my-plugin'"));
+
+    let dir = plugin_dir(&fixture, "my-plugin");
+    let plugin_lua = fs::read_to_string(dir.join("plugin.lua")).unwrap();
+    assert!(plugin_lua.contains(r#"name = "my-plugin""#));
+    for field in [
+        "items = ",
+        "preview = ",
+        "execute = ",
+        "pre_run = ",
+        "post_run = ",
+    ] {
+        assert!(plugin_lua.contains(field), "missing {}", field);
+    }
+    assert!(dir.join("lua/my-plugin/util.lua").is_file());
+    assert!(dir.join("tests/test_plugin.lua").is_file());
+}
+
+#[test]
+fn test_scaffold_uses_default_plugin_icon_from_config() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", r#"default_plugin_icon = "P""#);
+
+    syntropy(&fixture, &["plugins", "scaffold", "iconic"]).success();
+
+    let plugin_lua = fs::read_to_string(plugin_dir(&fixture, "iconic").join("plugin.lua")).unwrap();
+    assert!(plugin_lua.contains(r#"icon = "P""#));
+}
+
+#[test]
+fn test_minimal_scaffold_skips_optional_fields() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "scaffold", "tiny", "--minimal"]).success();
+
+    let plugin_lua = fs::read_to_string(plugin_dir(&fixture, "tiny").join("plugin.lua")).unwrap();
+    assert!(plugin_lua.contains(r#"name = "tiny""#));
+    assert!(!plugin_lua.contains("icon"));
+    assert!(!plugin_lua.contains("item_sources"));
+    assert!(!plugin_lua.contains("pre_run"));
+}
+
+// ============================================================================
+// Generated plugin works
+// ============================================================================
+
+#[test]
+fn test_scaffolded_plugin_executes_and_passes_its_tests() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "scaffold", "my-plugin"]).success();
+
+    syntropy(
+        &fixture,
+        &[
+            "execute",
+            "--plugin",
+            "my-plugin",
+            "--task",
+            "example",
+            "--items",
+            "first,third",
+        ],
+    )
+    .success()
+    .stdout(predicate::str::contains("Selected: first, third"));
+
+    syntropy(&fixture, &["test", "--plugin", "my-plugin"])
+        .code(0)
+        .stdout(predicate::str::contains("2 passed, 0 failed in 1 files"));
+}
+
+#[test]
+fn test_minimal_scaffolded_plugin_validates_and_executes() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "scaffold", "tiny", "--minimal"]).success();
+
+    let plugin_lua = plugin_dir(&fixture, "tiny").join("plugin.lua");
+    syntropy(
+        &fixture,
+        &["validate", "--plugin", plugin_lua.to_str().unwrap()],
+    )
+    .success();
+    syntropy(
+        &fixture,
+        &["execute", "--plugin", "tiny", "--task", "example"],
+    )
+    .success()
+    .stdout(predicate::str::contains("Hello from tiny"));
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[test]
+fn test_scaffold_refuses_existing_plugin_directory() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+    fixture.create_plugin("taken", "-- existing");
+
+    syntropy(&fixture, &["plugins", "scaffold", "taken"])
+        .failure()
+        .stderr(predicate::str::contains("Plugin directory already exists"));
+
+    let plugin_lua = fs::read_to_string(plugin_dir(&fixture, "taken").join("plugin.lua")).unwrap();
+    assert_eq!(plugin_lua, "-- existing");
+}
+
+#[test]
+fn test_scaffold_rejects_invalid_name() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "scaffold", "../escape"])
+        .failure()
+        .stderr(predicate::str::contains("Invalid plugin name '../escape'"));
+}
+
+#[test]
+fn test_scaffold_conflicts_with_operation_flags() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    syntropy(&fixture, &["plugins", "--list", "scaffold", "x"]).failure();
+}
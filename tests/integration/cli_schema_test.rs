@@ -0,0 +1,106 @@
+//! Integration tests for CLI schema subcommand
+//!
+//! Tests that the printed JSON Schema describes the required fields and allowed values of
+//! the table returned by plugin.lua.
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+use crate::common::TestFixture;
+
+fn schema() -> Value {
+    let fixture = TestFixture::new();
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("schema")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("schema is valid JSON")
+}
+
+fn task(schema: &Value) -> &Value {
+    &schema["properties"]["tasks"]["additionalProperties"]
+}
+
+// ============================================================================
+// Schema Tests
+// ============================================================================
+
+#[test]
+fn schema_requires_metadata_name_and_version() {
+    let schema = schema();
+
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(schema["required"], serde_json::json!(["metadata", "tasks"]));
+
+    let metadata = &schema["properties"]["metadata"];
+    assert_eq!(metadata["required"], serde_json::json!(["name", "version"]));
+    assert_eq!(metadata["properties"]["name"]["type"], "string");
+    assert_eq!(
+        metadata["properties"]["platforms"]["items"]["enum"],
+        serde_json::json!(["macos", "linux", "windows"])
+    );
+}
+
+#[test]
+fn schema_lists_task_modes_and_fields() {
+    let schema = schema();
+    let task = task(&schema);
+
+    assert_eq!(task["required"], serde_json::json!(["description"]));
+    assert_eq!(
+        task["properties"]["mode"]["enum"],
+        serde_json::json!(["multi", "single", "background"])
+    );
+    assert_eq!(
+        task["properties"]["dedup"]["enum"],
+        serde_json::json!(["none", "global", "per-source"])
+    );
+    assert_eq!(
+        task["properties"]["item_polling_interval"]["type"],
+        "integer"
+    );
+    assert_eq!(task["properties"]["preview_polling_interval"]["minimum"], 0);
+    assert_eq!(
+        task["properties"]["execution_confirmation_message"]["type"],
+        "string"
+    );
+    assert!(
+        task["properties"]["execute"]["description"]
+            .as_str()
+            .unwrap()
+            .starts_with("Lua function")
+    );
+}
+
+#[test]
+fn schema_describes_item_sources() {
+    let schema = schema();
+    let source = &task(&schema)["properties"]["item_sources"]["additionalProperties"];
+
+    assert_eq!(source["required"], serde_json::json!(["tag"]));
+    assert_eq!(
+        source["oneOf"],
+        serde_json::json!([{"required": ["items"]}, {"required": ["items_command"]}])
+    );
+    for field in [
+        "color",
+        "items_command",
+        "enabled",
+        "preselected_items",
+        "preview",
+        "execute",
+    ] {
+        assert!(
+            source["properties"].get(field).is_some(),
+            "item source field {} is described",
+            field
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Integration tests for `syntropy test`
+//!
+//! Covers running the `tests/*.lua` files of plugins, the `describe`/`it` and assertion
+//! helpers, `--plugin` filtering and the exit code: 0 when every test passes, 1 otherwise.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn plugin_source(name: &str) -> String {
+    format!(
+        r#"
+return {{
+    metadata = {{name = "{}", version = "1.0.0"}},
+    tasks = {{
+        run = {{
+            description = "Runs",
+            execute = function()
+                return "ran", 0
+            end,
+        }},
+    }},
+}}
+"#,
+        name
+    )
+}
+
+fn create_test_file(fixture: &TestFixture, plugin: &str, file: &str, content: &str) {
+    let path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join(plugin)
+        .join("tests")
+        .join(file);
+    fs::create_dir_all(path.parent().unwrap()).expect("Failed to create tests dir");
+    fs::write(path, content).expect("Failed to write test file");
+}
+
+fn run_tests(fixture: &TestFixture, args: &[&str]) -> assert_cmd::assert::Assert {
+    fixture.create_config("syntropy.toml", "");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("test")
+        .args(args)
+        .assert()
+}
+
+// ============================================================================
+// Passing and failing tests
+// ============================================================================
+
+#[test]
+fn test_passing_tests_exit_0() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    create_test_file(
+        &fixture,
+        "notes",
+        "basic_test.lua",
+        r#"
+it("adds", function()
+    assert_equal(1 + 1, 2)
+end)
+
+it("compares tables by contents", function()
+    assert_equal({a = 1, b = {2, 3}}, {a = 1, b = {2, 3}})
+end)
+"#,
+    );
+
+    run_tests(&fixture, &[])
+        .code(0)
+        .stdout(predicate::str::contains("notes/tests/basic_test.lua"))
+        .stdout(predicate::str::contains("✓ adds"))
+        .stdout(predicate::str::contains("✓ compares tables by contents"))
+        .stdout(predicate::str::contains("2 passed, 0 failed in 1 files"));
+}
+
+#[test]
+fn test_failing_assertion_exits_1_with_message() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    create_test_file(
+        &fixture,
+        "notes",
+        "basic_test.lua",
+        r#"
+it("passes", function()
+    assert_equal("a", "a")
+end)
+
+it("fails", function()
+    assert_equal(1 + 1, 3, "sum")
+end)
+"#,
+    );
+
+    run_tests(&fixture, &[])
+        .code(1)
+        .stdout(predicate::str::contains("✓ passes"))
+        .stdout(predicate::str::contains("✗ fails:"))
+        .stdout(predicate::str::contains(
+            "basic_test.lua:7: sum: expected 3, got 2",
+        ))
+        .stdout(predicate::str::contains("  1 passed, 1 failed"))
+        .stdout(predicate::str::contains("1 passed, 1 failed in 1 files"));
+}
+
+#[test]
+fn test_describe_prefixes_nested_test_names() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    create_test_file(
+        &fixture,
+        "notes",
+        "nested_test.lua",
+        r#"
+describe("parser", function()
+    describe("split", function()
+        it("handles commas", function() end)
+    end)
+    it("trims", function() end)
+end)
+it("top level", function() end)
+"#,
+    );
+
+    run_tests(&fixture, &[])
+        .code(0)
+        .stdout(predicate::str::contains("✓ parser split handles commas"))
+        .stdout(predicate::str::contains("✓ parser trims"))
+        .stdout(predicate::str::contains("✓ top level"));
+}
+
+#[test]
+fn test_assert_contains_searches_strings_and_tables() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    create_test_file(
+        &fixture,
+        "notes",
+        "contains_test.lua",
+        r#"
+it("finds substrings literally", function()
+    assert_contains("a.b (c)", ".b (")
+end)
+
+it("finds table values", function()
+    assert_contains({"x", {y = 1}}, {y = 1})
+end)
+
+it("reports a missing substring", function()
+    assert_contains("hello", "world")
+end)
+"#,
+    );
+
+    run_tests(&fixture, &[])
+        .code(1)
+        .stdout(predicate::str::contains("✓ finds substrings literally"))
+        .stdout(predicate::str::contains("✓ finds table values"))
+        .stdout(predicate::str::contains(
+            r#"✗ reports a missing substring: "#,
+        ))
+        .stdout(predicate::str::contains(
+            r#""hello" does not contain "world""#,
+        ));
+}
+
+#[test]
+fn test_file_that_fails_to_load_counts_as_failure() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    create_test_file(&fixture, "notes", "broken_test.lua", "error('boom')");
+
+    run_tests(&fixture, &[])
+        .code(1)
+        .stdout(predicate::str::contains("✗ failed to load:"))
+        .stdout(predicate::str::contains("boom"))
+        .stdout(predicate::str::contains("0 passed, 1 failed in 1 files"));
+}
+
+// ============================================================================
+// Plugin modules and selection
+// ============================================================================
+
+#[test]
+fn test_tests_can_require_plugin_modules() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    fixture.create_lib_module(
+        "notes",
+        "util",
+        "return { double = function(n) return n * 2 end }",
+    );
+    create_test_file(
+        &fixture,
+        "notes",
+        "util_test.lua",
+        r#"
+local util = require("notes.util")
+
+it("doubles", function()
+    assert_equal(util.double(21), 42)
+end)
+"#,
+    );
+
+    run_tests(&fixture, &[])
+        .code(0)
+        .stdout(predicate::str::contains("✓ doubles"));
+}
+
+#[test]
+fn test_plugin_flag_runs_only_that_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+    fixture.create_plugin("other", &plugin_source("other"));
+    create_test_file(
+        &fixture,
+        "notes",
+        "a_test.lua",
+        r#"it("notes test", function() end)"#,
+    );
+    create_test_file(
+        &fixture,
+        "other",
+        "a_test.lua",
+        r#"it("other test", function() error("should not run") end)"#,
+    );
+
+    run_tests(&fixture, &["--plugin", "notes"])
+        .code(0)
+        .stdout(predicate::str::contains("✓ notes test"))
+        .stdout(predicate::str::contains("other test").not());
+}
+
+#[test]
+fn test_unknown_plugin_is_an_error() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+
+    run_tests(&fixture, &["--plugin", "missing"])
+        .failure()
+        .stderr(predicate::str::contains(
+            "Plugin 'missing' not found in the plugin directories",
+        ));
+}
+
+#[test]
+fn test_no_test_files_exits_0() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("notes", &plugin_source("notes"));
+
+    run_tests(&fixture, &[])
+        .code(0)
+        .stdout(predicate::str::contains(
+            "No test files found in tests/*.lua",
+        ));
+}
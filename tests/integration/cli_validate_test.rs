@@ -0,0 +1,212 @@
+//! Integration tests for `validate --plugin --format json`
+//!
+//! Tests the structured validation report used by CI pipelines and pre-commit hooks.
+
+use assert_cmd::Command;
+use serde_json::Value;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn validate_json(plugin_content: &str) -> (bool, Value) {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("test", plugin_content);
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("test")
+        .join("plugin.lua");
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--format", "json", "--plugin"])
+        .arg(&plugin_path)
+        .output()
+        .unwrap();
+
+    let report = serde_json::from_slice(&output.stdout).unwrap();
+    (output.status.success(), report)
+}
+
+fn codes(issues: &Value) -> Vec<&str> {
+    issues
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| issue["code"].as_str().unwrap())
+        .collect()
+}
+
+// ============================================================================
+// Report Tests
+// ============================================================================
+
+#[test]
+fn valid_plugin_reports_no_issues() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "valid", version = "1.0.0"},
+    tasks = {t = {description = "Test task", execute = function() return "", 0 end}}
+}
+"#,
+    );
+
+    assert!(success);
+    assert_eq!(report[0]["plugin"], "valid");
+    assert_eq!(report[0]["errors"], serde_json::json!([]));
+    assert_eq!(report[0]["warnings"], serde_json::json!([]));
+}
+
+#[test]
+fn structural_errors_are_all_reported_with_codes() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "broken", version = "one", icon = "AB"},
+    tasks = {t = {description = "Test task", execute = function() return "", 0 end}}
+}
+"#,
+    );
+
+    assert!(!success);
+    assert_eq!(report[0]["plugin"], "broken");
+    assert_eq!(
+        codes(&report[0]["errors"]),
+        ["INVALID_VERSION", "INVALID_ICON"]
+    );
+    assert!(
+        report[0]["errors"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("version 'one' has invalid format")
+    );
+}
+
+#[test]
+fn wide_task_icon_is_reported() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "icons", version = "1.0.0"},
+    tasks = {t = {description = "Test task", icon = "AB", execute = function() return "", 0 end}}
+}
+"#,
+    );
+
+    assert!(!success);
+    assert_eq!(codes(&report[0]["errors"]), ["INVALID_TASK_ICON"]);
+    assert!(
+        report[0]["errors"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Task (icons) t icon 'AB' must occupy a single terminal cell")
+    );
+}
+
+#[test]
+fn multi_source_tag_errors_are_reported() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "tags", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "Test task",
+            item_sources = {
+                a = {tag = "x", items = function() return {} end, execute = function() return "", 0 end},
+                b = {tag = "x", items = function() return {} end, execute = function() return "", 0 end},
+            },
+        },
+    },
+}
+"#,
+    );
+
+    assert!(!success);
+    assert_eq!(codes(&report[0]["errors"]), ["DUPLICATE_TAG"]);
+}
+
+#[test]
+fn deprecated_mode_none_is_a_warning() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "legacy", version = "1.0.0"},
+    tasks = {t = {description = "Test task", mode = "none", execute = function() return "", 0 end}}
+}
+"#,
+    );
+
+    assert!(success);
+    assert_eq!(report[0]["errors"], serde_json::json!([]));
+    assert_eq!(codes(&report[0]["warnings"]), ["DEPRECATED_MODE_NONE"]);
+    assert!(
+        report[0]["warnings"][0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("use mode='single' instead")
+    );
+}
+
+#[test]
+fn unloadable_plugin_reports_load_failed() {
+    let (success, report) = validate_json("return {");
+
+    assert!(!success);
+    assert_eq!(report[0]["plugin"], "test");
+    assert_eq!(codes(&report[0]["errors"]), ["LOAD_FAILED"]);
+}
+
+#[test]
+fn function_type_problems_are_warnings() {
+    let (success, report) = validate_json(
+        r#"
+return {
+    metadata = {name = "warned", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "Test task",
+            item_sources = {
+                src = {tag = "s", items = function() return 123 end, execute = function() return "", 0 end},
+            },
+        },
+    },
+}
+"#,
+    );
+
+    assert!(success);
+    assert_eq!(report[0]["errors"], serde_json::json!([]));
+    assert_eq!(codes(&report[0]["warnings"]), ["INVALID_FUNCTION_TYPE"]);
+}
+
+#[test]
+fn format_requires_plugin() {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args(["validate", "--config", "--format", "json"])
+        .assert()
+        .failure();
+}
+
+// ============================================================================
+// Watch Mode
+// ============================================================================
+
+#[test]
+fn watch_requires_plugin() {
+    let fixture = TestFixture::new();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--watch"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--plugin"));
+}
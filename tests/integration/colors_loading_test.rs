@@ -3,7 +3,9 @@
 //! Tests the TOML → Colors → ColorStyle pipeline end-to-end.
 
 use ratatui::style::Color;
+use syntropy::Config;
 use syntropy::configs::style::colors::Colors;
+use syntropy::configs::style::themes::THEMES;
 use syntropy::tui::views::style::colors::ColorStyle;
 
 // ============================================================================
@@ -426,6 +428,161 @@ background_list = ""
     );
 }
 
+// ============================================================================
+// Themes
+// ============================================================================
+
+#[test]
+fn test_theme_provides_default_colors() {
+    let config_toml = r##"
+[styles]
+theme = "gruvbox"
+"##;
+
+    let config: Config = toml::from_str(config_toml).expect("Failed to parse TOML");
+    let style = ColorStyle::try_from(&config.styles.colors).expect("Theme colors should parse");
+
+    assert_eq!(config.styles.colors, THEMES["gruvbox"]);
+    assert_eq!(style.text, Color::Rgb(0xeb, 0xdb, 0xb2));
+    assert_eq!(style.background, Color::Rgb(0x28, 0x28, 0x28));
+    assert_eq!(
+        style.text_list, style.text,
+        "Component colors should cascade to theme globals"
+    );
+}
+
+#[test]
+fn test_search_highlight_defaults_to_theme_highlight_text() {
+    let themed: Config = toml::from_str("[styles]\ntheme = \"nord\"\n").unwrap();
+    let overridden: Config = toml::from_str(
+        r##"
+[styles]
+theme = "nord"
+
+[styles.colors]
+search_highlight = "yellow"
+"##,
+    )
+    .unwrap();
+
+    let themed_style = ColorStyle::try_from(&themed.styles.colors).unwrap();
+    let overridden_style = ColorStyle::try_from(&overridden.styles.colors).unwrap();
+    let default_style = ColorStyle::try_from(&Config::default().styles.colors).unwrap();
+
+    assert_eq!(themed_style.search_highlight, themed_style.highlights_text);
+    assert_eq!(overridden_style.search_highlight, Color::Yellow);
+    assert_eq!(default_style.search_highlight, Color::Reset);
+}
+
+#[test]
+fn test_tag_defaults_to_theme_borders() {
+    let themed: Config = toml::from_str("[styles]\ntheme = \"nord\"\n").unwrap();
+    let overridden: Config = toml::from_str(
+        r##"
+[styles]
+theme = "nord"
+
+[styles.colors]
+tag = "cyan"
+"##,
+    )
+    .unwrap();
+
+    let themed_style = ColorStyle::try_from(&themed.styles.colors).unwrap();
+    let overridden_style = ColorStyle::try_from(&overridden.styles.colors).unwrap();
+    let default_style = ColorStyle::try_from(&Config::default().styles.colors).unwrap();
+
+    assert_eq!(themed_style.tag, themed_style.borders);
+    assert_eq!(overridden_style.tag, Color::Cyan);
+    assert_eq!(default_style.tag, Color::Reset);
+}
+
+#[test]
+fn test_explicit_colors_override_theme() {
+    let config_toml = r##"
+[styles]
+theme = "dark"
+
+[styles.colors]
+text = "red"
+borders_list = "#00ff00"
+"##;
+
+    let config: Config = toml::from_str(config_toml).expect("Failed to parse TOML");
+    let style = ColorStyle::try_from(&config.styles.colors).expect("Colors should parse");
+
+    assert_eq!(style.text, Color::Red);
+    assert_eq!(style.borders_list, Color::Rgb(0, 255, 0));
+    assert_eq!(config.styles.colors.background, THEMES["dark"].background);
+}
+
+#[test]
+fn test_explicit_terminal_color_overrides_theme() {
+    let config_toml = r##"
+[styles]
+theme = "light"
+
+[styles.colors]
+background = "terminal"
+"##;
+
+    let config: Config = toml::from_str(config_toml).expect("Failed to parse TOML");
+    let style = ColorStyle::try_from(&config.styles.colors).expect("Colors should parse");
+
+    assert_eq!(style.background, Color::Reset);
+    assert_ne!(style.text, Color::Reset);
+}
+
+#[test]
+fn test_all_builtin_themes_parse() {
+    for (name, colors) in THEMES.iter() {
+        assert!(
+            ColorStyle::try_from(colors).is_ok(),
+            "Theme '{}' should contain valid colors",
+            name
+        );
+    }
+    for name in ["dark", "light", "gruvbox"] {
+        assert!(THEMES.contains_key(name), "Missing theme '{}'", name);
+    }
+}
+
+#[test]
+fn test_unknown_theme_is_rejected() {
+    let config_toml = r##"
+[styles]
+theme = "neon"
+"##;
+
+    let err = toml::from_str::<Config>(config_toml).unwrap_err();
+
+    assert!(err.to_string().contains("Unknown theme 'neon'"));
+    assert!(err.to_string().contains("gruvbox"));
+}
+
+#[test]
+fn test_unknown_color_field_is_rejected_with_theme() {
+    let config_toml = r##"
+[styles]
+theme = "dark"
+
+[styles.colors]
+not_a_color = "red"
+"##;
+
+    let err = toml::from_str::<Config>(config_toml).unwrap_err();
+
+    assert!(err.to_string().contains("unknown field `not_a_color`"));
+}
+
+#[test]
+fn test_no_theme_keeps_terminal_defaults() {
+    let config: Config = toml::from_str("").expect("Failed to parse TOML");
+
+    assert_eq!(config.styles.theme, None);
+    assert_eq!(config.styles.colors, Colors::default());
+}
+
 // ============================================================================
 // Integration with TestFixture
 // ============================================================================
@@ -6,6 +6,7 @@ use assert_cmd::Command;
 use predicates::prelude::*;
 
 use crate::common::TestFixture;
+use syntropy::configs::load_config;
 
 // ============================================================================
 // Mock Config Templates
@@ -36,6 +37,10 @@ confirm = "<enter>"
 left_split = 40
 right_split = 60
 
+[styles.preview]
+preview_mode = "split"
+preview_split_ratio = 0.3
+
 [styles.status]
 left_split = 30
 right_split = 70
@@ -89,6 +94,53 @@ fn test_complete_valid_config() {
         .stdout(predicate::str::contains("Config file is valid"));
 }
 
+#[test]
+fn test_config_with_quick_actions_is_valid() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+[[keybindings.quick_actions]]
+key = "<F5>"
+plugin = "packages"
+task = "upgrade"
+items = ["git", "curl"]
+"#,
+    );
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Config file is valid"));
+}
+
+#[test]
+fn test_quick_action_on_built_in_key_is_reported() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+[[keybindings.quick_actions]]
+key = "<C-j>"
+plugin = "packages"
+task = "upgrade"
+"#,
+    );
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Key '<C-j>' is bound to both 'jobs' and 'quick action packages/upgrade'",
+        ));
+}
+
 #[test]
 fn test_valid_config_with_custom_keybindings() {
     let fixture = TestFixture::new();
@@ -163,8 +215,101 @@ unknown_option = 42
         .stderr(predicate::str::contains("unknown field"));
 }
 
+// Writes `contents` as the config and loads it
+fn load(contents: &str) -> anyhow::Result<syntropy::Config> {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", contents);
+    load_config(fixture.config_path().join("syntropy/syntropy.toml"))
+}
+
+#[test]
+fn test_unknown_top_level_key_suggests_known_key() {
+    let error = load("[keybinding]\nback = \"q\"\n").unwrap_err();
+    let message = format!("{:#}", error);
+
+    assert!(
+        message.contains("unknown field `keybinding`. Did you mean 'keybindings'?"),
+        "error: {}",
+        message
+    );
+    assert!(
+        message.contains("allow_unknown_config_keys = true"),
+        "error: {}",
+        message
+    );
+}
+
+#[test]
+fn test_unknown_keybinding_reports_full_key() {
+    let error = load("[keybindings]\nselct_next = \"j\"\n").unwrap_err();
+    let message = format!("{:#}", error);
+
+    assert!(
+        message.contains("unknown field `keybindings.selct_next`. Did you mean 'select_next'?"),
+        "error: {}",
+        message
+    );
+}
+
+#[test]
+fn test_unknown_style_key_rejected() {
+    let error = load("[styles.preview]\nscroll_ofset = 3\n").unwrap_err();
+    let message = format!("{:#}", error);
+
+    assert!(
+        message.contains("unknown field `styles.preview.scroll_ofset`"),
+        "error: {}",
+        message
+    );
+}
+
+#[test]
+fn test_unknown_key_without_close_match_has_no_suggestion() {
+    let error = load("completely_unrelated = 1\n").unwrap_err();
+    let message = format!("{:#}", error);
+
+    assert!(
+        message.contains("unknown field `completely_unrelated`. Set"),
+        "error: {}",
+        message
+    );
+}
+
+#[test]
+fn test_known_keys_pass_strict_check() {
+    let config = load(
+        "default_plugin = \"notes\"\neditor = \"vim\"\n\n[keybindings]\nselect_next = \"j\"\n\n[styles]\ntheme = \"nord\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(config.default_plugin.as_deref(), Some("notes"));
+    assert_eq!(config.editor.as_deref(), Some("vim"));
+}
+
+#[test]
+fn test_plugin_tables_keep_their_own_keys() {
+    let config = load(
+        "[plugin_config.notes]\nanything = \"goes\"\n\n[plugins.notes]\ngit = \"https://example.com/notes\"\n",
+    )
+    .unwrap();
+
+    assert!(config.plugin_config.contains_key("notes"));
+    assert!(config.plugins.contains_key("notes"));
+}
+
+#[test]
+fn test_allow_unknown_config_keys_ignores_them() {
+    let config = load(
+        "allow_unknown_config_keys = true\nkeybinding = 1\nsearch_bar = false\n\n[keybindings]\nselct_next = \"j\"\n",
+    )
+    .unwrap();
+
+    assert!(config.allow_unknown_config_keys);
+    assert!(!config.search_bar);
+}
+
 // ============================================================================
-// Category 3: Invalid Semantic Rules (5 tests)
+// Category 3: Invalid Semantic Rules (7 tests)
 // ============================================================================
 
 #[test]
@@ -189,6 +334,68 @@ right_split = 50
         ));
 }
 
+#[test]
+fn test_wide_custom_border_character_rejected() {
+    const WIDE_BORDER: &str = r#"
+[styles.preview]
+border_style = { custom = { top_left = "🔍" } }
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", WIDE_BORDER);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Custom border top_left '🔍' of styles.preview must occupy a single terminal cell",
+        ));
+}
+
+#[test]
+fn test_preview_split_ratio_out_of_range() {
+    const INVALID_RATIO: &str = r#"
+[styles.preview]
+preview_mode = "split"
+preview_split_ratio = 1.5
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", INVALID_RATIO);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Preview split ratio must be between 0 and 1 (exclusive)",
+        ));
+}
+
+#[test]
+fn test_invalid_preview_mode() {
+    const INVALID_MODE: &str = r#"
+[styles.preview]
+preview_mode = "floating"
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", INVALID_MODE);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown variant"));
+}
+
 #[test]
 fn test_status_splits_not_sum_to_100() {
     const INVALID_STATUS_SPLITS: &str = r#"
@@ -273,6 +480,88 @@ default_task = "export"
         ));
 }
 
+#[test]
+fn test_empty_editor_fallback_rejected() {
+    const EMPTY_FALLBACK: &str = r#"
+editor = "hx"
+editor_fallbacks = ["nano", " "]
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", EMPTY_FALLBACK);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "editor and editor_fallbacks entries cannot be empty",
+        ));
+}
+
+#[test]
+fn test_editor_settings_accepted() {
+    const EDITOR_SETTINGS: &str = r#"
+editor = "hx"
+editor_fallbacks = ["nvim", "nano"]
+editor_overrides_env = true
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", EDITOR_SETTINGS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_env_variables_in_paths_expanded_at_load() {
+    const ENV_PATHS: &str = r#"
+editor = "${HOME}/.local/share/syntropy/bin/hx"
+editor_fallbacks = ["~/bin/nvim", "nano"]
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", ENV_PATHS);
+    let home = std::env::var("HOME").unwrap();
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert_eq!(
+        config.editor,
+        Some(format!("{}/.local/share/syntropy/bin/hx", home))
+    );
+    assert_eq!(
+        config.editor_fallbacks,
+        [format!("{}/bin/nvim", home), "nano".to_string()]
+    );
+}
+
+#[test]
+fn test_undefined_env_variable_in_path_rejected() {
+    const UNDEFINED_VARIABLE: &str = r#"
+editor = "${SYNTROPY_TEST_UNDEFINED_VARIABLE}/hx"
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", UNDEFINED_VARIABLE);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to expand paths"))
+        .stderr(predicate::str::contains("SYNTROPY_TEST_UNDEFINED_VARIABLE"));
+}
+
 // ============================================================================
 // Category 4: Invalid Key Bindings (4 tests - ALL WILL FAIL)
 // ============================================================================
@@ -357,6 +646,70 @@ scroll_preview_up = "k"
         .stderr(predicate::str::contains("Duplicate").or(predicate::str::contains("conflict")));
 }
 
+#[test]
+fn test_duplicate_key_binding_names_actions() {
+    const DUPLICATE_BINDINGS: &str = r#"
+[keybindings]
+back = "<C-c>"
+confirm = "<C-c>"
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", DUPLICATE_BINDINGS);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Key '<C-c>' is bound to both 'back' and 'confirm'",
+        ));
+}
+
+#[test]
+fn test_search_bar_shadowed_key_binding_warns() {
+    const SHADOWED_BINDING: &str = r#"
+[keybindings]
+back = "q"
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", SHADOWED_BINDING);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "⚠ Key 'q' bound to 'back' is captured by the search bar",
+        ));
+}
+
+#[test]
+fn test_shadowed_key_binding_without_search_bar_does_not_warn() {
+    const NO_SEARCH_BAR: &str = r#"
+search_bar = false
+
+[keybindings]
+back = "q"
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", NO_SEARCH_BAR);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("search bar").not());
+}
+
 // ============================================================================
 // Category 5: Edge Cases (3 tests)
 // ============================================================================
@@ -412,3 +765,153 @@ back = ""
         .failure()
         .stderr(predicate::str::contains("Empty").or(predicate::str::contains("invalid")));
 }
+
+// ============================================================================
+// Category 6: Config Includes (5 tests)
+// ============================================================================
+
+#[test]
+fn test_include_merges_base_under_config() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        "base.toml",
+        r#"
+status_bar = false
+search_bar = false
+
+[keybindings]
+back = "<C-q>"
+
+[styles.list]
+highlight_symbol = "*"
+icon_marked = "x"
+"#,
+    );
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+include = ["base.toml"]
+status_bar = true
+
+[styles.list]
+highlight_symbol = ">"
+"#,
+    );
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert!(config.status_bar, "top-level value wins");
+    assert!(!config.search_bar, "included value applies");
+    assert_eq!(config.keybindings.back, "<C-q>");
+    assert_eq!(config.styles.list.highlight_symbol, ">");
+    assert_eq!(
+        config.styles.list.icon_marked, "x",
+        "tables merge key by key"
+    );
+}
+
+#[test]
+fn test_include_paths_relative_to_including_file() {
+    let fixture = TestFixture::new();
+    fixture.create_config("shared/keys.toml", "[keybindings]\nback = \"<C-q>\"\n");
+    fixture.create_config("shared/base.toml", "include = [\"keys.toml\"]\n");
+    fixture.create_config("syntropy.toml", "include = [\"shared/base.toml\"]\n");
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert_eq!(config.keybindings.back, "<C-q>");
+}
+
+#[test]
+fn test_later_include_wins() {
+    let fixture = TestFixture::new();
+    fixture.create_config("first.toml", "default_plugin_icon = \"a\"\n");
+    fixture.create_config("second.toml", "default_plugin_icon = \"b\"\n");
+    fixture.create_config(
+        "syntropy.toml",
+        "include = [\"first.toml\", \"second.toml\"]\n",
+    );
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert_eq!(config.default_plugin_icon, "b");
+}
+
+#[test]
+fn test_circular_include_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_config("a.toml", "include = [\"syntropy.toml\"]\n");
+    fixture.create_config("syntropy.toml", "include = [\"a.toml\"]\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Circular config include"));
+}
+
+#[test]
+fn test_missing_include_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "include = [\"missing.toml\"]\n");
+
+    let error = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap_err();
+
+    assert!(
+        format!("{:#}", error).contains("missing.toml"),
+        "error: {:#}",
+        error
+    );
+}
+
+#[test]
+fn test_default_task_mode_parsed() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "default_task_mode = \"multi\"\n");
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert_eq!(config.default_task_mode, syntropy::plugins::Mode::Multi);
+}
+
+#[test]
+fn test_default_task_mode_none_parsed_as_single() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "default_task_mode = \"none\"\n");
+
+    let config = load_config(fixture.config_path().join("syntropy/syntropy.toml")).unwrap();
+
+    assert_eq!(config.default_task_mode, syntropy::plugins::Mode::Single);
+}
+
+#[test]
+fn test_wide_default_task_icon_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "default_task_icon = \"AB\"\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Default task icon 'AB' must occupy a single terminal cell",
+        ));
+}
+
+#[test]
+fn test_invalid_default_task_mode_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "default_task_mode = \"parallel\"\n");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .arg("validate")
+        .arg("--config")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("default_task_mode"));
+}
@@ -0,0 +1,191 @@
+//! Integration tests for the task `dedup` field
+//!
+//! Sources of the test plugin overlap on purpose, `[a]` and `[b]` both list "shared" and
+//! `[a]` lists "twice" twice.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn dedup_plugin(dedup: Option<&str>) -> String {
+    let dedup = dedup
+        .map(|dedup| format!("dedup = \"{}\",", dedup))
+        .unwrap_or_default();
+    format!(
+        r#"
+return {{
+    metadata = {{name = "dedup", version = "1.0.0", icon = "D", platforms = {{"macos", "linux"}}}},
+    tasks = {{
+        overlap = {{
+            description = "Overlapping sources",
+            mode = "multi",
+            {dedup}
+            item_sources = {{
+                first = {{
+                    tag = "a",
+                    items = function() return {{"shared", "twice", "twice", "only-a"}} end,
+                    preselected_items = function() return {{"shared"}} end,
+                    execute = function(items) return "A: " .. table.concat(items, ","), 0 end,
+                }},
+                second = {{
+                    tag = "b",
+                    items = function() return {{"shared", "only-b"}} end,
+                    preselected_items = function() return {{"shared"}} end,
+                    execute = function(items) return "B: " .. table.concat(items, ","), 0 end,
+                }},
+            }},
+        }},
+    }},
+}}
+"#
+    )
+}
+
+fn produce(fixture: &TestFixture, flag: &str) -> Vec<String> {
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "dedup", "--task", "overlap", flag])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    let mut lines: Vec<String> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    lines.sort();
+    lines
+}
+
+fn fixture_with(dedup: Option<&str>) -> TestFixture {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("dedup", &dedup_plugin(dedup));
+    fixture
+}
+
+// ============================================================================
+// Strategies
+// ============================================================================
+
+#[test]
+fn dedup_none_keeps_every_item() {
+    let fixture = fixture_with(None);
+
+    assert_eq!(
+        produce(&fixture, "--produce-items"),
+        vec![
+            "[a] only-a",
+            "[a] shared",
+            "[a] twice",
+            "[a] twice",
+            "[b] only-b",
+            "[b] shared",
+        ]
+    );
+}
+
+#[test]
+fn dedup_per_source_removes_duplicates_within_a_source() {
+    let fixture = fixture_with(Some("per-source"));
+
+    assert_eq!(
+        produce(&fixture, "--produce-items"),
+        vec![
+            "[a] only-a",
+            "[a] shared",
+            "[a] twice",
+            "[b] only-b",
+            "[b] shared",
+        ]
+    );
+}
+
+#[test]
+fn dedup_global_keeps_one_item_per_value() {
+    let fixture = fixture_with(Some("global"));
+
+    let items = produce(&fixture, "--produce-items");
+
+    assert_eq!(items.len(), 4);
+    assert!(items.contains(&"[a] only-a".to_string()));
+    assert!(items.contains(&"[a] twice".to_string()));
+    assert!(items.contains(&"[b] only-b".to_string()));
+    assert_eq!(
+        items
+            .iter()
+            .filter(|item| item.ends_with("] shared"))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn dedup_global_drops_preselection_of_removed_items() {
+    let fixture = fixture_with(Some("global"));
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "dedup", "--task", "overlap"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("shared").count(), 1, "{}", stdout);
+}
+
+#[test]
+fn dedup_per_source_keeps_preselection_of_every_source() {
+    let fixture = fixture_with(Some("per-source"));
+
+    assert_eq!(
+        produce(&fixture, "--produce-preselected-items"),
+        vec!["[a] shared", "[b] shared"]
+    );
+}
+
+#[test]
+fn dedup_global_makes_untagged_item_unambiguous() {
+    let fixture = fixture_with(Some("global"));
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "dedup", "--task", "overlap"])
+        .args(["--items", "shared"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shared"));
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn invalid_dedup_fails_to_load() {
+    let fixture = fixture_with(Some("everywhere"));
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "dedup",
+            "--task",
+            "overlap",
+            "--produce-items",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid dedup 'everywhere'"));
+}
@@ -0,0 +1,210 @@
+//! Integration tests for the task `default_select` field
+//!
+//! The `[a]` source of the test plugin preselects "apple", the `[b]` source preselects
+//! nothing. Each mode decides which items are marked on start and which ones `execute`
+//! runs on without `--items`.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{
+    configs::Config,
+    execution::run_items_pipeline,
+    lua::create_lua_vm,
+    plugins::{DefaultSelect, load_plugins},
+};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn default_select_plugin(default_select: Option<&str>) -> String {
+    let default_select = default_select
+        .map(|default_select| format!("default_select = \"{}\",", default_select))
+        .unwrap_or_default();
+    format!(
+        r#"
+return {{
+    metadata = {{name = "select", version = "1.0.0"}},
+    tasks = {{
+        apply = {{
+            description = "Apply to items",
+            mode = "multi",
+            {default_select}
+            item_sources = {{
+                first = {{
+                    tag = "a",
+                    items = function() return {{"apple", "apricot"}} end,
+                    preselected_items = function() return {{"apple"}} end,
+                    execute = function(items) return "A: " .. table.concat(items, ","), 0 end,
+                }},
+                second = {{
+                    tag = "b",
+                    items = function() return {{"banana"}} end,
+                    execute = function(items) return "B: " .. table.concat(items, ","), 0 end,
+                }},
+            }},
+        }},
+    }},
+}}
+"#
+    )
+}
+
+fn fixture_with(default_select: Option<&str>) -> TestFixture {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("select", &default_select_plugin(default_select));
+    fixture
+}
+
+// Items and preselected items of the task, as the TUI receives them on start
+fn fetch_items(fixture: &TestFixture) -> (Vec<String>, Vec<String>) {
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    let task = plugins[0].tasks.get("apply").unwrap();
+
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(run_items_pipeline(lua, task))
+        .unwrap()
+}
+
+fn execute(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "select", "--task", "apply"]);
+    cmd
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+#[test]
+fn default_select_defaults_to_preselected() {
+    let fixture = fixture_with(None);
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua,
+    )
+    .unwrap();
+
+    assert_eq!(
+        plugins[0].tasks["apply"].default_select,
+        DefaultSelect::Preselected
+    );
+}
+
+#[test]
+fn invalid_default_select_fails_validation() {
+    let fixture = fixture_with(Some("some"));
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--plugin"])
+        .arg(
+            fixture
+                .data_path()
+                .join("syntropy/plugins/select/plugin.lua"),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid default_select 'some' (must be 'preselected', 'all' or 'none')",
+        ));
+}
+
+// ============================================================================
+// Initial Selection
+// ============================================================================
+
+#[test]
+fn default_select_preselected_marks_source_preselection() {
+    let fixture = fixture_with(Some("preselected"));
+
+    let (_, preselected) = fetch_items(&fixture);
+
+    assert_eq!(preselected, vec!["[a] apple"]);
+}
+
+#[test]
+fn default_select_all_marks_every_item() {
+    let fixture = fixture_with(Some("all"));
+
+    let (items, preselected) = fetch_items(&fixture);
+
+    assert_eq!(preselected, items);
+    assert_eq!(preselected, vec!["[a] apple", "[a] apricot", "[b] banana"]);
+}
+
+#[test]
+fn default_select_none_marks_nothing_despite_preselection() {
+    let fixture = fixture_with(Some("none"));
+
+    let (items, preselected) = fetch_items(&fixture);
+
+    assert_eq!(items.len(), 3);
+    assert!(preselected.is_empty());
+}
+
+// ============================================================================
+// CLI Execution
+// ============================================================================
+
+#[test]
+fn execute_with_default_select_all_runs_on_every_item() {
+    let fixture = fixture_with(Some("all"));
+
+    execute(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("A: apple,apricot"))
+        .stdout(predicate::str::contains("B: banana"));
+}
+
+#[test]
+fn execute_with_default_select_none_requires_items() {
+    let fixture = fixture_with(Some("none"));
+
+    execute(&fixture)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("default_select='none'"))
+        .stderr(predicate::str::contains("--items"));
+}
+
+#[test]
+fn execute_with_default_select_none_runs_on_given_items() {
+    let fixture = fixture_with(Some("none"));
+
+    execute(&fixture)
+        .args(["--items", "[b] banana"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("B: banana"));
+}
+
+#[test]
+fn produce_preselected_items_follows_default_select() {
+    let fixture = fixture_with(Some("all"));
+
+    execute(&fixture)
+        .arg("--produce-preselected-items")
+        .assert()
+        .success()
+        .stdout("[a] apple\n[a] apricot\n[b] banana\n");
+}
@@ -210,3 +210,109 @@ return {
             "Warning: Exit code -5 clamped to 1",
         ));
 }
+
+// ============================================================================
+// Per-item Exit Codes
+// ============================================================================
+
+const PER_ITEM_EXIT_CODES: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", description = "Test plugin", platforms = {"macos", "linux"}},
+    tasks = {
+        per_item = {
+            description = "Reports an exit code per item",
+            mode = "multi",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = function() return {"a", "b", "c"} end,
+                    execute = function(items) return "ran " .. #items .. " items", 0, {a = 0, b = 1} end
+                }
+            }
+        },
+        all_ok = {
+            description = "Every item succeeds",
+            mode = "multi",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = function() return {"a", "b"} end,
+                    execute = function(items) return "ok", 0, {a = 0, b = 0} end
+                }
+            }
+        },
+        standalone = {
+            description = "Execute-only task reporting item codes",
+            execute = function() return "done", 0, {x = 3} end
+        },
+        bad_codes = {
+            description = "Third return value is not an exit code map",
+            execute = function() return "done", 0, {x = "oops"} end
+        }
+    }
+}
+"#;
+
+fn execute_per_item(fixture: &TestFixture, task: &str, items: Option<&str>) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["execute", "--plugin", "test", "--task", task]);
+    if let Some(items) = items {
+        cmd.args(["--items", items]);
+    }
+    cmd
+}
+
+#[test]
+fn test_per_item_exit_codes_report_failed_items() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PER_ITEM_EXIT_CODES);
+
+    execute_per_item(&fixture, "per_item", Some("a,b"))
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("ran 2 items"))
+        .stderr(predicate::str::contains("1 item(s) failed"))
+        .stderr(predicate::str::contains("- b (exit code 1)"))
+        .stderr(predicate::str::contains("- a (exit code").not());
+}
+
+#[test]
+fn test_per_item_exit_codes_all_zero_succeeds() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PER_ITEM_EXIT_CODES);
+
+    execute_per_item(&fixture, "all_ok", Some("a,b"))
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("failed").not());
+}
+
+#[test]
+fn test_per_item_exit_codes_on_execute_only_task() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PER_ITEM_EXIT_CODES);
+
+    execute_per_item(&fixture, "standalone", None)
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("- x (exit code 3)"));
+}
+
+#[test]
+fn test_per_item_exit_codes_invalid_table_is_error() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PER_ITEM_EXIT_CODES);
+
+    execute_per_item(&fixture, "bad_codes", None)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "third return value must map items to integer exit codes",
+        ));
+}
@@ -0,0 +1,207 @@
+//! Integration tests for the global_pre_run and global_post_run config hooks
+//!
+//! Tests that the hooks run around every task in the order global_pre_run, pre_run,
+//! execute, post_run, global_post_run, receive the plugin and task, and that a failing or
+//! invalid hook aborts.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+const COUNTER_PLUGIN: &str = r#"
+return {
+    metadata = {name = "hooks", version = "1.0.0", icon = "H"},
+    tasks = {
+        count = {
+            description = "Reports how often the global hook ran",
+            execute = function()
+                return "count=" .. tostring(hook_counter), 0
+            end,
+        },
+        ordered = {
+            description = "Records the hook order",
+            pre_run = function()
+                table.insert(hook_log, "task_pre")
+            end,
+            execute = function()
+                table.insert(hook_log, "execute")
+                return "log=" .. table.concat(hook_log, ","), 0
+            end,
+            post_run = function()
+                table.insert(hook_log, "task_post")
+            end,
+        },
+        context = {
+            description = "Reports the context passed to the hook",
+            execute = function()
+                return "context=" .. hook_context, 0
+            end,
+        },
+    },
+}
+"#;
+
+// Writes a hook file next to the config and returns its path relative to the config
+fn create_hook(fixture: &TestFixture, name: &str, content: &str) -> String {
+    let relative = format!("hooks/{}", name);
+    let path = fixture.config_path().join("syntropy").join(&relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+    relative
+}
+
+fn execute(fixture: &TestFixture, task: &str) -> assert_cmd::assert::Assert {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "hooks", "--task", task])
+        .assert()
+}
+
+// ============================================================================
+// Running the hooks
+// ============================================================================
+
+#[test]
+fn global_pre_run_increments_counter_seen_by_task() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    let pre_run = create_hook(
+        &fixture,
+        "pre_run.lua",
+        "return function() hook_counter = (hook_counter or 0) + 1 end",
+    );
+    fixture.create_config(
+        "syntropy.toml",
+        &format!("global_pre_run = \"{}\"\n", pre_run),
+    );
+
+    execute(&fixture, "count")
+        .success()
+        .stdout(predicate::str::contains("count=1"));
+}
+
+#[test]
+fn hooks_run_around_task_hooks_in_order() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    let log_path = fixture.temp_dir.path().join("hook_log.txt");
+    let pre_run = create_hook(
+        &fixture,
+        "pre_run.lua",
+        r#"return function()
+    hook_log = {"global_pre"}
+end"#,
+    );
+    let post_run = create_hook(
+        &fixture,
+        "post_run.lua",
+        &format!(
+            r#"return function()
+    table.insert(hook_log, "global_post")
+    local file = io.open("{}", "w")
+    file:write(table.concat(hook_log, ","))
+    file:close()
+end"#,
+            log_path.display()
+        ),
+    );
+    fixture.create_config(
+        "syntropy.toml",
+        &format!(
+            "global_pre_run = \"{}\"\nglobal_post_run = \"{}\"\n",
+            pre_run, post_run
+        ),
+    );
+
+    execute(&fixture, "ordered")
+        .success()
+        .stdout(predicate::str::contains("log=global_pre,task_pre,execute"));
+
+    assert_eq!(
+        fs::read_to_string(&log_path).unwrap(),
+        "global_pre,task_pre,execute,task_post,global_post"
+    );
+}
+
+#[test]
+fn hook_receives_plugin_and_task() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    let pre_run = create_hook(
+        &fixture,
+        "pre_run.lua",
+        "return function(context) hook_context = context.plugin .. '/' .. context.task end",
+    );
+    fixture.create_config(
+        "syntropy.toml",
+        &format!("global_pre_run = \"{}\"\n", pre_run),
+    );
+
+    execute(&fixture, "context")
+        .success()
+        .stdout(predicate::str::contains("context=hooks/context"));
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[test]
+fn failing_global_pre_run_aborts_task() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    let pre_run = create_hook(
+        &fixture,
+        "pre_run.lua",
+        "return function() error('auth expired') end",
+    );
+    fixture.create_config(
+        "syntropy.toml",
+        &format!("global_pre_run = \"{}\"\n", pre_run),
+    );
+
+    execute(&fixture, "count")
+        .failure()
+        .stdout(predicate::str::contains("count=").not())
+        .stderr(predicate::str::contains("global_pre_run"))
+        .stderr(predicate::str::contains("auth expired"));
+}
+
+#[test]
+fn hook_file_not_returning_function_fails_at_startup() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    let post_run = create_hook(&fixture, "post_run.lua", "return 42");
+    fixture.create_config(
+        "syntropy.toml",
+        &format!("global_post_run = \"{}\"\n", post_run),
+    );
+
+    execute(&fixture, "count")
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to load global_post_run hook",
+        ))
+        .stderr(predicate::str::contains(
+            "must return a function, got integer",
+        ));
+}
+
+#[test]
+fn missing_hook_file_fails_config_validation() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("hooks", COUNTER_PLUGIN);
+    fixture.create_config("syntropy.toml", "global_pre_run = \"hooks/missing.lua\"\n");
+
+    execute(&fixture, "count")
+        .failure()
+        .stderr(predicate::str::contains("global_pre_run file"))
+        .stderr(predicate::str::contains("does not exist"));
+}
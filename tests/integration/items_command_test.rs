@@ -0,0 +1,356 @@
+//! Integration tests for item sources with an `items_command`
+//!
+//! Tests that the stdout lines of the command become the items, that they are handed out
+//! while the command still runs, and how the loader validates the field.
+
+use std::{
+    io::{BufRead, BufReader},
+    process::Stdio,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{
+    Config, Handle, Operation, State, create_lua_vm,
+    execution::{EXIT_PROCESS_ERROR, run_items_pipeline_streaming},
+    load_plugins,
+    plugins::{Plugin, Task},
+};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+fn command_plugin(items_command: &str) -> String {
+    format!(
+        r#"
+return {{
+    metadata = {{name = "stream", version = "1.0.0", platforms = {{"macos", "linux"}}}},
+    tasks = {{
+        files = {{
+            description = "Items of a command",
+            mode = "multi",
+            item_sources = {{
+                lines = {{
+                    tag = "l",
+                    items_command = "{}",
+                    preselected_items = function() return {{"beta"}} end,
+                    execute = function(items) return "RAN:" .. table.concat(items, "|"), 0 end,
+                }},
+            }},
+        }},
+    }},
+}}
+"#,
+        items_command
+    )
+}
+
+const MIXED_SOURCES: &str = r#"
+return {
+    metadata = {name = "stream", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        files = {
+            description = "Command and function sources",
+            dedup = "global",
+            item_sources = {
+                a_lua = {
+                    tag = "lua",
+                    items = function() return {"shared", "from-lua"} end,
+                    execute = function(items) return table.concat(items, "|"), 0 end,
+                },
+                b_cmd = {
+                    tag = "cmd",
+                    items_command = "printf 'shared\\nfrom-cmd\\n'",
+                    execute = function(items) return table.concat(items, "|"), 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const BOTH_ITEMS: &str = r#"
+return {
+    metadata = {name = "stream", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        files = {
+            description = "Both kinds of items",
+            item_sources = {
+                lines = {
+                    tag = "l",
+                    items = function() return {"a"} end,
+                    items_command = "echo a",
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const COMMAND_NOT_STRING: &str = r#"
+return {
+    metadata = {name = "stream", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        files = {
+            description = "Command of the wrong type",
+            item_sources = {
+                lines = {
+                    tag = "l",
+                    items_command = 42,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn load(fixture: &TestFixture) -> (Arc<Mutex<mlua::Lua>>, Vec<Plugin>) {
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    (lua, plugins)
+}
+
+fn files_task(plugins: &[Plugin]) -> Arc<Task> {
+    Arc::clone(plugins[0].tasks.get("files").unwrap())
+}
+
+fn produce_items(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "stream",
+            "--task",
+            "files",
+            "--produce-items",
+        ]);
+    cmd
+}
+
+// ============================================================================
+// Streaming API
+// ============================================================================
+
+#[test]
+fn streaming_pipeline_hands_out_lines_while_the_command_runs() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(
+        "stream",
+        &command_plugin("echo first; sleep 1; echo second"),
+    );
+    let (lua, plugins) = load(&fixture);
+    let task = files_task(&plugins);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let started = Instant::now();
+    let mut arrivals = Vec::new();
+    let (items, _) = runtime
+        .block_on(run_items_pipeline_streaming(lua, &task, |items| {
+            arrivals.push((items.to_vec(), started.elapsed()));
+        }))
+        .unwrap();
+
+    assert_eq!(items, vec!["first", "second"]);
+    assert_eq!(arrivals.len(), 2);
+    assert_eq!(arrivals[0].0, vec!["first"]);
+    assert_eq!(arrivals[1].0, vec!["second"]);
+    assert!(
+        arrivals[1].1 - arrivals[0].1 >= Duration::from_millis(500),
+        "lines arrived together: {:?}",
+        arrivals
+    );
+}
+
+#[test]
+fn handle_exposes_partial_items_before_the_result() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(
+        "stream",
+        &command_plugin("echo first; sleep 1; echo second"),
+    );
+    let (lua, plugins) = load(&fixture);
+    let task = files_task(&plugins);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut handle = Handle::new(runtime.handle().clone(), &lua);
+    handle.execute(Operation::Items { task }).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let partial = loop {
+        if let Some(items) = handle.consume_partial_items() {
+            break items;
+        }
+        assert!(Instant::now() < deadline, "no partial items arrived");
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    assert_eq!(partial, vec!["first"]);
+    assert_eq!(handle.read_state(), State::Running);
+}
+
+#[test]
+fn streaming_pipeline_deduplicates_across_sources() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", MIXED_SOURCES);
+    let (lua, plugins) = load(&fixture);
+    let task = files_task(&plugins);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut streamed = Vec::new();
+    let (items, _) = runtime
+        .block_on(run_items_pipeline_streaming(lua, &task, |items| {
+            streamed.extend_from_slice(items)
+        }))
+        .unwrap();
+
+    assert_eq!(streamed, items);
+    assert_eq!(
+        items.iter().filter(|item| item.ends_with("shared")).count(),
+        1
+    );
+    assert!(items.contains(&"[cmd] from-cmd".to_string()));
+    assert!(items.contains(&"[lua] from-lua".to_string()));
+}
+
+// ============================================================================
+// CLI
+// ============================================================================
+
+#[test]
+fn produce_items_prints_command_lines() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", &command_plugin("printf 'alpha\\n\\nbeta\\n'"));
+
+    produce_items(&fixture)
+        .assert()
+        .success()
+        .stdout("alpha\nbeta\n");
+}
+
+#[test]
+fn produce_items_streams_before_the_command_exits() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(
+        "stream",
+        &command_plugin("echo first; sleep 2; echo second"),
+    );
+
+    let mut child = std::process::Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "stream",
+            "--task",
+            "files",
+            "--produce-items",
+        ])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut first = String::new();
+    stdout.read_line(&mut first).unwrap();
+    let still_running = child.try_wait().unwrap().is_none();
+    let _ = child.kill();
+    let _ = child.wait();
+
+    assert_eq!(first, "first\n");
+    assert!(
+        still_running,
+        "first line only arrived after the command exited"
+    );
+}
+
+#[test]
+fn preselected_items_of_command_source() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", &command_plugin("printf 'alpha\\nbeta\\n'"));
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "stream", "--task", "files"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("RAN:beta"));
+}
+
+#[test]
+fn failing_command_is_an_error() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", &command_plugin("echo oops >&2; exit 3"));
+
+    produce_items(&fixture)
+        .assert()
+        .code(EXIT_PROCESS_ERROR)
+        .stderr(predicate::str::contains(
+            "stream.tasks.files.item_sources.lines.items_command",
+        ))
+        .stderr(predicate::str::contains("failed with exit code 3: oops"));
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn items_function_and_command_are_exclusive() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", BOTH_ITEMS);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--plugin"])
+        .arg(
+            fixture
+                .data_path()
+                .join("syntropy/plugins/stream/plugin.lua"),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cannot define both an 'items' function and an 'items_command'",
+        ));
+}
+
+#[test]
+fn items_command_must_be_a_string() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("stream", COMMAND_NOT_STRING);
+
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--plugin"])
+        .arg(
+            fixture
+                .data_path()
+                .join("syntropy/plugins/stream/plugin.lua"),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'items_command' must be a string, got integer",
+        ));
+}
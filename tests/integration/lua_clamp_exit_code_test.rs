@@ -0,0 +1,125 @@
+//! Integration tests for syntropy.clamp_exit_code() Lua function
+//!
+//! Covers clamping of out of range codes, passing valid codes through and the warning
+//! `syntropy execute` prints for clamped codes.
+
+use assert_cmd::Command;
+use mlua::Lua;
+use predicates::prelude::*;
+use syntropy::create_lua_vm;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn clamp(lua: &Lua, code: i64) -> mlua::Result<i32> {
+    let syntropy: mlua::Table = lua.globals().get("syntropy")?;
+    let clamp_exit_code: mlua::Function = syntropy.get("clamp_exit_code")?;
+    clamp_exit_code.call::<i32>((code, true))
+}
+
+// Plugin whose execute returns syntropy.clamp_exit_code(300) without the quiet flag
+const CLAMPING_PLUGIN: &str = r#"
+return {
+    metadata = {name = "clamp", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        run = {
+            name = "Run",
+            description = "Clamps its own exit code",
+            execute = function()
+                return "clamped", syntropy.clamp_exit_code(300)
+            end,
+        },
+    },
+}
+"#;
+
+fn execute_clamping_plugin(config: &str) -> assert_cmd::assert::Assert {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", config);
+    fixture.create_plugin("clamp", CLAMPING_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "clamp", "--task", "run"])
+        .assert()
+}
+
+// ============================================================================
+// Clamping
+// ============================================================================
+
+#[test]
+fn test_negative_code_clamps_to_one() {
+    let lua = create_lua_vm().unwrap();
+
+    assert_eq!(clamp(&lua, -1).unwrap(), 1);
+}
+
+#[test]
+fn test_code_above_range_clamps_to_255() {
+    let lua = create_lua_vm().unwrap();
+
+    assert_eq!(clamp(&lua, 300).unwrap(), 255);
+}
+
+#[test]
+fn test_codes_in_range_are_unchanged() {
+    let lua = create_lua_vm().unwrap();
+
+    for code in [0, 1, 42, 255] {
+        assert_eq!(clamp(&lua, code).unwrap(), code as i32);
+    }
+}
+
+#[test]
+fn test_codes_beyond_i32_are_clamped() {
+    let lua = create_lua_vm().unwrap();
+
+    assert_eq!(clamp(&lua, i64::MAX).unwrap(), 255);
+    assert_eq!(clamp(&lua, i64::MIN).unwrap(), 1);
+}
+
+#[test]
+fn test_quiet_flag_is_optional() {
+    let lua = create_lua_vm().unwrap();
+
+    let code: i32 = lua
+        .load("return syntropy.clamp_exit_code(7)")
+        .eval()
+        .unwrap();
+
+    assert_eq!(code, 7);
+}
+
+#[test]
+fn test_non_numeric_code_raises_error() {
+    let lua = create_lua_vm().unwrap();
+
+    let result: mlua::Result<i32> = lua.load("return syntropy.clamp_exit_code('x')").eval();
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Warning
+// ============================================================================
+
+#[test]
+fn test_clamped_code_warns_by_default() {
+    execute_clamping_plugin("")
+        .code(255)
+        .stderr(predicate::str::contains(
+            "Warning: Exit code 300 clamped to 255",
+        ));
+}
+
+#[test]
+fn test_warn_on_exit_clamp_false_silences_warning() {
+    execute_clamping_plugin("warn_on_exit_clamp = false\n")
+        .code(255)
+        .stderr(predicate::str::contains("clamped").not());
+}
@@ -0,0 +1,90 @@
+//! Integration tests for syntropy.clipboard_set() and syntropy.clipboard_get()
+//!
+//! Headless environments (CI, containers) have no clipboard, the functions must fail there
+//! with a readable Lua error instead of panicking.
+
+use mlua::Lua;
+use serial_test::serial;
+use syntropy::create_lua_vm;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn syntropy_function(lua: &Lua, name: &str) -> mlua::Function {
+    let syntropy: mlua::Table = lua.globals().get("syntropy").unwrap();
+    syntropy.get(name).unwrap()
+}
+
+fn assert_unavailable(error: &mlua::Error) {
+    assert!(
+        error.to_string().contains("Clipboard is not available"),
+        "Unexpected clipboard error: {}",
+        error
+    );
+}
+
+// ============================================================================
+// Clipboard
+// ============================================================================
+
+#[test]
+fn test_clipboard_functions_are_registered() {
+    let lua = create_lua_vm().unwrap();
+
+    let kinds: (String, String) = lua
+        .load("return type(syntropy.clipboard_set), type(syntropy.clipboard_get)")
+        .eval()
+        .unwrap();
+
+    assert_eq!(kinds, ("function".to_string(), "function".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_clipboard_round_trip_or_unavailable() {
+    let lua = create_lua_vm().unwrap();
+    let text = "syntropy clipboard test ✓";
+
+    match syntropy_function(&lua, "clipboard_set").call::<()>(text) {
+        Ok(()) => {
+            let copied: String = syntropy_function(&lua, "clipboard_get").call(()).unwrap();
+            assert_eq!(copied, text);
+        }
+        Err(e) => assert_unavailable(&e),
+    }
+}
+
+#[test]
+#[serial]
+fn test_clipboard_error_is_catchable_from_lua() {
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, result): (bool, String) = lua
+        .load(
+            r#"
+            local ok, result = pcall(syntropy.clipboard_set, "from lua")
+            if ok then
+                return true, syntropy.clipboard_get()
+            end
+            return false, tostring(result)
+            "#,
+        )
+        .eval()
+        .unwrap();
+
+    if ok {
+        assert_eq!(result, "from lua");
+    } else {
+        assert!(result.contains("Clipboard is not available"), "{}", result);
+    }
+}
+
+#[test]
+fn test_clipboard_set_requires_text() {
+    let lua = create_lua_vm().unwrap();
+
+    let result = lua.load("syntropy.clipboard_set()").exec();
+
+    assert!(result.is_err());
+}
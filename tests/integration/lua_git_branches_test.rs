@@ -0,0 +1,184 @@
+//! Integration tests for syntropy.git_branches() and syntropy.git_checkout() Lua functions
+//!
+//! Covers listing branches with the current one flagged, switching branches, detached HEAD
+//! and the failures of missing branches, conflicting changes and paths outside a repository.
+
+use mlua::Lua;
+use std::{fs, path::Path, process::Command};
+use syntropy::create_lua_vm;
+use tempfile::TempDir;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn call_git_branches(lua: &Lua, path: &Path) -> mlua::Result<Vec<(String, bool)>> {
+    let syntropy: mlua::Table = lua.globals().get("syntropy")?;
+    let git_branches: mlua::Function = syntropy.get("git_branches")?;
+    let branches: Vec<mlua::Table> = git_branches.call(path.to_str().unwrap())?;
+    branches
+        .into_iter()
+        .map(|branch| Ok((branch.get("name")?, branch.get("current")?)))
+        .collect()
+}
+
+fn call_git_checkout(lua: &Lua, path: &Path, branch: &str) -> (bool, Option<String>) {
+    let syntropy: mlua::Table = lua.globals().get("syntropy").unwrap();
+    let git_checkout: mlua::Function = syntropy.get("git_checkout").unwrap();
+    git_checkout.call((path.to_str().unwrap(), branch)).unwrap()
+}
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Repository on branch `main` with a `feature` branch whose `file.txt` differs
+fn create_repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    fs::write(dir.path().join("file.txt"), "main").unwrap();
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "--quiet", "-m", "main"]);
+    git(dir.path(), &["checkout", "--quiet", "-b", "feature"]);
+    fs::write(dir.path().join("file.txt"), "feature").unwrap();
+    git(dir.path(), &["commit", "--quiet", "-am", "feature"]);
+    git(dir.path(), &["checkout", "--quiet", "main"]);
+    dir
+}
+
+fn branch(name: &str, current: bool) -> (String, bool) {
+    (name.to_string(), current)
+}
+
+// ============================================================================
+// git_branches
+// ============================================================================
+
+#[test]
+fn test_lists_branches_with_current_flagged() {
+    let repo = create_repo();
+    let lua = create_lua_vm().unwrap();
+
+    let branches = call_git_branches(&lua, repo.path()).unwrap();
+
+    assert_eq!(
+        branches,
+        vec![branch("feature", false), branch("main", true)]
+    );
+}
+
+#[test]
+fn test_detached_head_flags_no_branch() {
+    let repo = create_repo();
+    git(repo.path(), &["checkout", "--quiet", "--detach", "feature"]);
+    let lua = create_lua_vm().unwrap();
+
+    let branches = call_git_branches(&lua, repo.path()).unwrap();
+
+    assert_eq!(
+        branches,
+        vec![branch("feature", false), branch("main", false)]
+    );
+}
+
+#[test]
+fn test_branches_outside_repository_errors() {
+    let dir = TempDir::new().unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let error = call_git_branches(&lua, dir.path()).unwrap_err();
+
+    assert!(
+        error.to_string().contains("is not a git repository"),
+        "{}",
+        error
+    );
+}
+
+// ============================================================================
+// git_checkout
+// ============================================================================
+
+#[test]
+fn test_checkout_switches_branch() {
+    let repo = create_repo();
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, message) = call_git_checkout(&lua, repo.path(), "feature");
+
+    assert!(ok, "{:?}", message);
+    assert_eq!(message, None);
+    assert_eq!(
+        fs::read_to_string(repo.path().join("file.txt")).unwrap(),
+        "feature"
+    );
+    assert_eq!(
+        call_git_branches(&lua, repo.path()).unwrap(),
+        vec![branch("feature", true), branch("main", false)]
+    );
+}
+
+#[test]
+fn test_checkout_leaves_detached_head() {
+    let repo = create_repo();
+    git(repo.path(), &["checkout", "--quiet", "--detach", "feature"]);
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, _) = call_git_checkout(&lua, repo.path(), "main");
+
+    assert!(ok);
+    assert!(call_git_branches(&lua, repo.path()).unwrap()[1].1);
+}
+
+#[test]
+fn test_checkout_missing_branch_fails() {
+    let repo = create_repo();
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, message) = call_git_checkout(&lua, repo.path(), "missing");
+
+    assert!(!ok);
+    assert!(
+        message
+            .unwrap()
+            .contains("Branch 'missing' does not exist in"),
+    );
+}
+
+#[test]
+fn test_checkout_refused_by_git_fails() {
+    let repo = create_repo();
+    fs::write(repo.path().join("file.txt"), "uncommitted").unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, message) = call_git_checkout(&lua, repo.path(), "feature");
+
+    assert!(!ok);
+    assert!(
+        message
+            .unwrap()
+            .contains("Command 'git checkout' failed with exit code 1: ")
+    );
+    assert_eq!(
+        fs::read_to_string(repo.path().join("file.txt")).unwrap(),
+        "uncommitted"
+    );
+}
+
+#[test]
+fn test_checkout_outside_repository_fails() {
+    let dir = TempDir::new().unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let (ok, message) = call_git_checkout(&lua, dir.path(), "main");
+
+    assert!(!ok);
+    assert!(message.unwrap().contains("is not a git repository"));
+}
@@ -0,0 +1,195 @@
+//! Integration tests for syntropy.git_status() Lua function
+//!
+//! Covers the status of modified, added, deleted and untracked files, renames, clean
+//! repositories, environment variable expansion, paths outside a repository and git failing.
+
+use mlua::Lua;
+use serial_test::serial;
+use std::{env, fs, path::Path, process::Command};
+use syntropy::{create_lua_vm, execution::ExecutionError};
+use tempfile::TempDir;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn call_git_status(lua: &Lua, path: &str) -> mlua::Result<Vec<(String, String)>> {
+    let syntropy: mlua::Table = lua.globals().get("syntropy")?;
+    let git_status: mlua::Function = syntropy.get("git_status")?;
+    let entries: Vec<mlua::Table> = git_status.call(path.to_string())?;
+    entries
+        .into_iter()
+        .map(|entry| Ok((entry.get("path")?, entry.get("status")?)))
+        .collect()
+}
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Repository with `kept.txt`, `edited.txt` and `removed.txt` committed
+fn create_repo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet"]);
+    for file in ["kept.txt", "edited.txt", "removed.txt"] {
+        fs::write(dir.path().join(file), file).unwrap();
+    }
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "--quiet", "-m", "initial"]);
+    dir
+}
+
+fn sorted(mut entries: Vec<(String, String)>) -> Vec<(String, String)> {
+    entries.sort();
+    entries
+}
+
+fn entry(path: &str, status: &str) -> (String, String) {
+    (path.to_string(), status.to_string())
+}
+
+// ============================================================================
+// Statuses
+// ============================================================================
+
+#[test]
+fn test_reports_each_kind_of_change() {
+    let repo = create_repo();
+    fs::write(repo.path().join("edited.txt"), "changed").unwrap();
+    fs::remove_file(repo.path().join("removed.txt")).unwrap();
+    fs::write(repo.path().join("staged.txt"), "new").unwrap();
+    git(repo.path(), &["add", "staged.txt"]);
+    fs::create_dir(repo.path().join("notes")).unwrap();
+    fs::write(repo.path().join("notes/untracked.md"), "").unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let entries = call_git_status(&lua, repo.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(
+        sorted(entries),
+        vec![
+            entry("edited.txt", "modified"),
+            entry("notes/untracked.md", "untracked"),
+            entry("removed.txt", "deleted"),
+            entry("staged.txt", "added"),
+        ]
+    );
+}
+
+#[test]
+fn test_rename_reports_new_path_once() {
+    let repo = create_repo();
+    git(repo.path(), &["mv", "kept.txt", "moved.txt"]);
+    let lua = create_lua_vm().unwrap();
+
+    let entries = call_git_status(&lua, repo.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(entries, vec![entry("moved.txt", "modified")]);
+}
+
+#[test]
+fn test_clean_repository_returns_empty_table() {
+    let repo = create_repo();
+    let lua = create_lua_vm().unwrap();
+
+    let entries = call_git_status(&lua, repo.path().to_str().unwrap()).unwrap();
+
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn test_paths_are_relative_to_repository_root() {
+    let repo = create_repo();
+    fs::create_dir(repo.path().join("sub")).unwrap();
+    fs::write(repo.path().join("sub/new.txt"), "").unwrap();
+    fs::write(repo.path().join("edited.txt"), "changed").unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let entries = call_git_status(&lua, repo.path().join("sub").to_str().unwrap()).unwrap();
+
+    assert_eq!(
+        sorted(entries),
+        vec![
+            entry("edited.txt", "modified"),
+            entry("sub/new.txt", "untracked")
+        ]
+    );
+}
+
+#[test]
+#[serial]
+fn test_expands_environment_variables() {
+    let repo = create_repo();
+    fs::write(repo.path().join("edited.txt"), "changed").unwrap();
+    unsafe { env::set_var("SYNTROPY_GIT_STATUS_REPO", repo.path()) };
+    let lua = create_lua_vm().unwrap();
+
+    let entries = call_git_status(&lua, "$SYNTROPY_GIT_STATUS_REPO").unwrap();
+    unsafe { env::remove_var("SYNTROPY_GIT_STATUS_REPO") };
+
+    assert_eq!(entries, vec![entry("edited.txt", "modified")]);
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[test]
+fn test_directory_outside_repository_errors() {
+    let dir = TempDir::new().unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let error = call_git_status(&lua, dir.path().to_str().unwrap()).unwrap_err();
+
+    assert!(
+        error.to_string().contains("is not a git repository"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_missing_directory_errors() {
+    let dir = TempDir::new().unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let error = call_git_status(&lua, dir.path().join("missing").to_str().unwrap()).unwrap_err();
+
+    assert!(
+        error.to_string().contains("Failed to execute git status"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_failing_git_command_is_process_error() {
+    let repo = create_repo();
+    fs::write(repo.path().join(".git").join("index"), "corrupt").unwrap();
+    let lua = create_lua_vm().unwrap();
+
+    let error = call_git_status(&lua, repo.path().to_str().unwrap()).unwrap_err();
+
+    match error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ExecutionError>())
+    {
+        Some(ExecutionError::ProcessError {
+            command,
+            exit_code,
+            stderr,
+        }) => {
+            assert_eq!(command, "git status");
+            assert_ne!(*exit_code, 0);
+            assert!(stderr.contains("index"), "{}", stderr);
+        }
+        other => panic!("Expected ProcessError, got {:?}", other),
+    }
+}
@@ -0,0 +1,135 @@
+//! Integration tests for syntropy.glob() Lua function
+//!
+//! Covers flat and recursive patterns, sorted output, empty results,
+//! invalid patterns and environment variable expansion.
+
+use mlua::Lua;
+use serial_test::serial;
+use std::{env, fs, path::Path};
+use syntropy::create_lua_vm;
+use tempfile::TempDir;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn call_glob(lua: &Lua, pattern: &str) -> mlua::Result<Vec<String>> {
+    let syntropy: mlua::Table = lua.globals().get("syntropy")?;
+    let glob: mlua::Function = syntropy.get("glob")?;
+    glob.call::<Vec<String>>(pattern.to_string())
+}
+
+/// Creates `a.txt`, `b.txt`, `c.log`, `nested/d.txt` and `nested/deeper/e.txt`
+fn create_tree() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for file in [
+        "b.txt",
+        "a.txt",
+        "c.log",
+        "nested/d.txt",
+        "nested/deeper/e.txt",
+    ] {
+        let path = dir.path().join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+    dir
+}
+
+fn path_in(dir: &Path, file: &str) -> String {
+    dir.join(file).to_string_lossy().into_owned()
+}
+
+// ============================================================================
+// Matching
+// ============================================================================
+
+#[test]
+fn test_glob_flat_pattern_returns_sorted_matches() {
+    let dir = create_tree();
+    let lua = create_lua_vm().unwrap();
+
+    let paths = call_glob(&lua, &path_in(dir.path(), "*.txt")).unwrap();
+
+    assert_eq!(
+        paths,
+        vec![path_in(dir.path(), "a.txt"), path_in(dir.path(), "b.txt")]
+    );
+}
+
+#[test]
+fn test_glob_recursive_pattern_matches_nested_files() {
+    let dir = create_tree();
+    let lua = create_lua_vm().unwrap();
+
+    let paths = call_glob(&lua, &path_in(dir.path(), "**/*.txt")).unwrap();
+
+    assert_eq!(
+        paths,
+        vec![
+            path_in(dir.path(), "a.txt"),
+            path_in(dir.path(), "b.txt"),
+            path_in(dir.path(), "nested/d.txt"),
+            path_in(dir.path(), "nested/deeper/e.txt"),
+        ]
+    );
+}
+
+#[test]
+fn test_glob_no_matches_returns_empty_table() {
+    let dir = create_tree();
+    let lua = create_lua_vm().unwrap();
+
+    let paths = call_glob(&lua, &path_in(dir.path(), "*.md")).unwrap();
+
+    assert!(paths.is_empty());
+}
+
+#[test]
+fn test_glob_returns_sequence_table() {
+    let dir = create_tree();
+    let lua = create_lua_vm().unwrap();
+    lua.globals()
+        .set("pattern", path_in(dir.path(), "*.log"))
+        .unwrap();
+
+    let count: i64 = lua.load("return #syntropy.glob(pattern)").eval().unwrap();
+
+    assert_eq!(count, 1);
+}
+
+// ============================================================================
+// Expansion and Errors
+// ============================================================================
+
+#[test]
+#[serial]
+fn test_glob_expands_environment_variables() {
+    let dir = create_tree();
+    let lua = create_lua_vm().unwrap();
+    unsafe {
+        env::set_var("SYNTROPY_GLOB_TEST_DIR", dir.path());
+    }
+
+    let paths = call_glob(&lua, "$SYNTROPY_GLOB_TEST_DIR/*.log");
+
+    unsafe {
+        env::remove_var("SYNTROPY_GLOB_TEST_DIR");
+    }
+    assert_eq!(paths.unwrap(), vec![path_in(dir.path(), "c.log")]);
+}
+
+#[test]
+fn test_glob_invalid_pattern_raises_error() {
+    let lua = create_lua_vm().unwrap();
+
+    let error = call_glob(&lua, "/tmp/***").unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("Invalid glob pattern '/tmp/***'"),
+        "Unexpected error: {}",
+        error
+    );
+}
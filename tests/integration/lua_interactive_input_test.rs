@@ -0,0 +1,106 @@
+//! Integration tests for syntropy.confirm() and syntropy.prompt()
+//!
+//! In CLI mode both functions read from the terminal. When stdin is not a TTY
+//! (pipes, CI, scripts) they must not block and instead return their defaults:
+//! `confirm` answers false and `prompt` answers the provided default.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+const MINIMAL_CONFIG: &str = r#"
+default_plugin_icon = "⚒"
+
+[keybindings]
+back = "<esc>"
+select_previous = "<up>"
+select_next = "<down>"
+scroll_preview_up = "["
+scroll_preview_down = "]"
+toggle_preview = "<C-p>"
+select = "<tab>"
+confirm = "<enter>"
+"#;
+
+const PLUGIN: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        ask_confirm = {
+            description = "Asks a yes/no question",
+            execute = function()
+                local answer = syntropy.confirm("Proceed?")
+                return "confirm=" .. tostring(answer), 0
+            end,
+        },
+        ask_prompt = {
+            description = "Asks for text with a default",
+            execute = function()
+                local answer = syntropy.prompt("Name?", "fallback")
+                return "prompt=" .. answer, 0
+            end,
+        },
+        ask_prompt_no_default = {
+            description = "Asks for text without a default",
+            execute = function()
+                local answer = syntropy.prompt("Name?")
+                return "prompt=[" .. answer .. "]", 0
+            end,
+        },
+    },
+}
+"#;
+
+fn execute_task(fixture: &TestFixture, task: &str) -> std::process::Output {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", task])
+        .write_stdin("yes\n")
+        .output()
+        .unwrap()
+}
+
+fn setup() -> TestFixture {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("test", PLUGIN);
+    fixture
+}
+
+// === Non-TTY defaults ===
+
+#[test]
+fn test_confirm_returns_false_when_stdin_is_not_a_tty() {
+    let fixture = setup();
+
+    let output = execute_task(&fixture, "ask_confirm");
+
+    assert!(output.status.success());
+    assert!(
+        predicate::str::contains("confirm=false").eval(&String::from_utf8_lossy(&output.stdout))
+    );
+}
+
+#[test]
+fn test_prompt_returns_default_when_stdin_is_not_a_tty() {
+    let fixture = setup();
+
+    let output = execute_task(&fixture, "ask_prompt");
+
+    assert!(output.status.success());
+    assert!(
+        predicate::str::contains("prompt=fallback").eval(&String::from_utf8_lossy(&output.stdout))
+    );
+}
+
+#[test]
+fn test_prompt_without_default_returns_empty_string_when_stdin_is_not_a_tty() {
+    let fixture = setup();
+
+    let output = execute_task(&fixture, "ask_prompt_no_default");
+
+    assert!(output.status.success());
+    assert!(predicate::str::contains("prompt=[]").eval(&String::from_utf8_lossy(&output.stdout)));
+}
@@ -0,0 +1,93 @@
+//! Integration tests for syntropy.log() Lua function
+//!
+//! Covers the CLI path, where messages go to stderr with a level prefix, and the
+//! rejection of unknown levels.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+use syntropy::create_lua_vm;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const LOGGING_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "logger",
+        version = "1.0.0",
+    },
+    tasks = {
+        sync = {
+            description = "Logs while executing",
+            execute = function()
+                syntropy.log("starting sync")
+                syntropy.log("remote is slow", "warn")
+                syntropy.log("one file failed", "error")
+                return "Synced", 0
+            end,
+        },
+    },
+}
+"#;
+
+fn execute_sync(fixture: &TestFixture) -> assert_cmd::assert::Assert {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "logger", "--task", "sync"])
+        .assert()
+}
+
+// ============================================================================
+// CLI Output
+// ============================================================================
+
+#[test]
+fn log_writes_level_prefixed_lines_to_stderr() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("logger", LOGGING_PLUGIN);
+
+    execute_sync(&fixture)
+        .success()
+        .stderr(predicate::str::contains(
+            "[info] starting sync\n[warn] remote is slow\n[error] one file failed\n",
+        ));
+}
+
+#[test]
+fn log_stays_out_of_stdout() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("logger", LOGGING_PLUGIN);
+
+    execute_sync(&fixture)
+        .success()
+        .stdout(predicate::str::contains("Synced"))
+        .stdout(predicate::str::contains("starting sync").not());
+}
+
+// ============================================================================
+// Levels
+// ============================================================================
+
+#[test]
+fn log_rejects_unknown_level() {
+    let lua = create_lua_vm().unwrap();
+
+    let error = lua
+        .load(r#"syntropy.log("hello", "debug")"#)
+        .exec()
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("must be one of info, warn, error, got 'debug'"));
+}
+
+#[test]
+fn log_requires_a_message() {
+    let lua = create_lua_vm().unwrap();
+
+    assert!(lua.load("syntropy.log()").exec().is_err());
+}
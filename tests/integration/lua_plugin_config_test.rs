@@ -0,0 +1,155 @@
+//! Integration tests for syntropy.config()
+//!
+//! `[plugin_config.<name>]` tables of the config are handed to plugins as Lua tables.
+
+use std::collections::HashMap;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{create_lua_vm, lua::set_plugin_configs};
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates and Config
+// ============================================================================
+
+const CONFIG_WITH_PLUGIN_CONFIG: &str = r#"
+[plugin_config.greeter]
+greeting = "Hello"
+"#;
+
+const GREETER_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "greeter",
+        version = "1.0.0",
+        description = "Greets items",
+    },
+    tasks = {
+        greet = {
+            description = "Greet",
+            execute = function()
+                return (syntropy.config().greeting or "No greeting") .. ", world", 0
+            end,
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn lua_with_configs(configs: &str) -> mlua::Lua {
+    let configs: HashMap<String, toml::Table> = toml::from_str(configs).unwrap();
+    let lua = create_lua_vm().unwrap();
+    set_plugin_configs(&lua, configs);
+    lua
+}
+
+// ============================================================================
+// TOML Conversion
+// ============================================================================
+
+#[test]
+fn test_config_converts_toml_values() {
+    let lua = lua_with_configs(
+        r#"
+        [greeter]
+        name = "syntropy"
+        count = 3
+        ratio = 0.5
+        enabled = true
+        released = 2024-01-15
+        tags = ["a", "b"]
+        nested = { depth = 2 }
+        "#,
+    );
+
+    let values: (String, i64, f64, bool, String, String, i64) = lua
+        .load(
+            r#"
+            local config = syntropy.config("greeter")
+            return config.name, config.count, config.ratio, config.enabled,
+                config.released, table.concat(config.tags, ","), config.nested.depth
+            "#,
+        )
+        .eval()
+        .unwrap();
+
+    assert_eq!(
+        values,
+        (
+            "syntropy".to_string(),
+            3,
+            0.5,
+            true,
+            "2024-01-15".to_string(),
+            "a,b".to_string(),
+            2
+        )
+    );
+}
+
+#[test]
+fn test_config_of_unconfigured_plugin_is_empty_table() {
+    let lua = lua_with_configs("[greeter]\nname = \"syntropy\"");
+
+    let is_empty: bool = lua
+        .load(r#"return next(syntropy.config("other")) == nil"#)
+        .eval()
+        .unwrap();
+
+    assert!(is_empty);
+}
+
+#[test]
+fn test_config_without_name_outside_plugin_fails() {
+    let lua = create_lua_vm().unwrap();
+
+    let error = lua
+        .load("return syntropy.config()")
+        .eval::<mlua::Table>()
+        .unwrap_err();
+
+    assert!(
+        error.to_string().contains("Cannot get plugin config"),
+        "Unexpected error: {}",
+        error
+    );
+}
+
+// ============================================================================
+// Plugin Execution
+// ============================================================================
+
+#[test]
+fn test_execute_reads_plugin_config() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", CONFIG_WITH_PLUGIN_CONFIG);
+    fixture.create_plugin("greeter", GREETER_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "greeter", "--task", "greet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hello, world"));
+}
+
+#[test]
+fn test_execute_without_plugin_config_gets_empty_table() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+    fixture.create_plugin("greeter", GREETER_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "greeter", "--task", "greet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No greeting, world"));
+}
@@ -0,0 +1,116 @@
+//! Integration tests for syntropy.plugin_dir() and syntropy.plugin_name()
+//!
+//! Both read the plugin context the execution layer sets around every plugin call.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::create_lua_vm;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugins
+// ============================================================================
+
+const CONTEXT_PLUGIN: &str = r#"
+return {
+    metadata = {name = "context-plugin", version = "1.0.0", icon = "C", platforms = {"macos", "linux"}},
+    tasks = {
+        show = {
+            description = "Show plugin context",
+            execute = function()
+                return "dir=" .. syntropy.plugin_dir() .. "\nname=" .. syntropy.plugin_name(), 0
+            end,
+        },
+        assets = {
+            description = "Build asset paths",
+            item_sources = {
+                src = {
+                    tag = "a",
+                    items = function()
+                        return {syntropy.plugin_dir() .. "/assets/icon.png"}
+                    end,
+                    execute = function(items) return table.concat(items, ","), 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn execute(fixture: &TestFixture, task: &str) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "context-plugin", "--task", task]);
+    cmd
+}
+
+// ============================================================================
+// Inside Plugin Execution
+// ============================================================================
+
+#[test]
+fn plugin_dir_and_name_inside_execute() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("context-plugin", CONTEXT_PLUGIN);
+    let plugin_dir = fixture.data_path().join("syntropy/plugins/context-plugin");
+
+    execute(&fixture, "show")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "dir={}\n",
+            plugin_dir.display()
+        )))
+        .stdout(predicate::str::contains("name=context-plugin"));
+}
+
+#[test]
+fn plugin_dir_inside_items() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("context-plugin", CONTEXT_PLUGIN);
+    let icon = fixture
+        .data_path()
+        .join("syntropy/plugins/context-plugin/assets/icon.png");
+
+    execute(&fixture, "assets")
+        .arg("--produce-items")
+        .assert()
+        .success()
+        .stdout(format!("{}\n", icon.display()));
+}
+
+// ============================================================================
+// Outside Plugin Execution
+// ============================================================================
+
+#[test]
+fn plugin_dir_outside_plugin_execution_fails() {
+    let lua = create_lua_vm().unwrap();
+
+    let error = lua
+        .load("return syntropy.plugin_dir()")
+        .eval::<String>()
+        .unwrap_err();
+
+    assert!(
+        error.to_string().contains(
+            "Cannot get plugin directory: no plugin context (plugin_dir called outside plugin execution)"
+        ),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn plugin_name_outside_plugin_execution_fails() {
+    let lua = create_lua_vm().unwrap();
+
+    let error = lua
+        .load("return syntropy.plugin_name()")
+        .eval::<String>()
+        .unwrap_err();
+
+    assert!(error.to_string().contains("no plugin context"), "{}", error);
+}
@@ -0,0 +1,149 @@
+//! Integration tests for syntropy.plugins()
+//!
+//! The loader keeps the metadata and task keys of the loaded plugins for plugins listing
+//! the other installed plugins.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{Config, create_lua_vm, load_plugins};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const DASHBOARD_PLUGIN: &str = r#"
+return {
+    metadata = {name = "dashboard", version = "1.0.0"},
+    tasks = {
+        count = {
+            description = "Counts the loaded plugins",
+            execute = function()
+                return tostring(#syntropy.plugins()), 0
+            end,
+        },
+        describe = {
+            description = "Describes the notes plugin",
+            execute = function()
+                for _, plugin in ipairs(syntropy.plugins()) do
+                    if plugin.name == "notes" then
+                        return string.format(
+                            "%s %s %s %s [%s] [%s] %s",
+                            plugin.name,
+                            plugin.version,
+                            plugin.icon,
+                            plugin.description,
+                            table.concat(plugin.platforms, ","),
+                            table.concat(plugin.tasks, ","),
+                            type(plugin.tasks[1])
+                        ), 0
+                    end
+                end
+                return "notes not found", 1
+            end,
+        },
+    },
+}
+"#;
+
+const NOTES_PLUGIN: &str = r#"
+return {
+    metadata = {
+        name = "notes",
+        version = "2.1.0",
+        icon = "N",
+        description = "Manages notes",
+        platforms = {"macos", "linux"},
+    },
+    tasks = {
+        open = {description = "Open a note", order = 1, execute = function() return "", 0 end},
+        archive = {description = "Archive notes", execute = function() return "", 0 end},
+    },
+}
+"#;
+
+const TODO_PLUGIN: &str = r#"
+return {
+    metadata = {name = "todo", version = "0.1.0"},
+    tasks = {list = {description = "List todos", execute = function() return "", 0 end}},
+}
+"#;
+
+fn fixture_with_plugins() -> TestFixture {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("dashboard", DASHBOARD_PLUGIN);
+    fixture.create_plugin("notes", NOTES_PLUGIN);
+    fixture.create_plugin("todo", TODO_PLUGIN);
+    fixture
+}
+
+// ============================================================================
+// Loaded Plugins
+// ============================================================================
+
+#[test]
+fn plugins_count_matches_loaded_plugins() {
+    let fixture = fixture_with_plugins();
+    let lua_runtime = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        Arc::clone(&lua_runtime),
+    )
+    .unwrap();
+
+    let count: usize = lua_runtime
+        .blocking_lock()
+        .load("return #syntropy.plugins()")
+        .eval()
+        .unwrap();
+    assert_eq!(count, plugins.len());
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn plugins_is_empty_before_loading() {
+    let lua = create_lua_vm().unwrap();
+
+    let count: usize = lua.load("return #syntropy.plugins()").eval().unwrap();
+
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn plugins_leaves_out_plugins_that_failed_to_load() {
+    let fixture = fixture_with_plugins();
+    fixture.create_plugin("broken", "return {");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "dashboard", "--task", "count"])
+        .assert()
+        .success()
+        .stdout(predicate::eq("3\n"));
+}
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+#[test]
+fn plugins_exposes_metadata_and_sorted_task_keys() {
+    let fixture = fixture_with_plugins();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "dashboard", "--task", "describe"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "notes 2.1.0 N Manages notes [macos,linux] [open,archive] string",
+        ));
+}
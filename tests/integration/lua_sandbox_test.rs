@@ -0,0 +1,211 @@
+//! Integration tests for the plugin Lua sandbox
+//!
+//! Covers the blocked standard library functions, the plugin-relative `require` and
+//! `--sandbox off` lifting both.
+
+use assert_cmd::Command;
+use mlua::Lua;
+use predicates::prelude::*;
+use std::fs;
+use syntropy::{create_lua_vm, create_lua_vm_with_sandbox, plugins::ModulePathBuilder};
+use tempfile::TempDir;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn eval_error(lua: &Lua, code: &str) -> String {
+    lua.load(code).exec().unwrap_err().to_string()
+}
+
+// Plugin whose execute returns the first line io.popen reads from `echo popen`
+const POPEN_PLUGIN: &str = r#"
+return {
+    metadata = {name = "popen", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        run = {
+            name = "Run",
+            description = "Reads a command through io.popen",
+            execute = function()
+                local handle = io.popen("echo popen")
+                local line = handle:read("*l")
+                handle:close()
+                return line, 0
+            end,
+        },
+    },
+}
+"#;
+
+fn execute_popen_plugin(extra_args: &[&str]) -> assert_cmd::assert::Assert {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+    fixture.create_plugin("popen", POPEN_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(extra_args)
+        .args(["execute", "--plugin", "popen", "--task", "run"])
+        .assert()
+}
+
+// ============================================================================
+// Blocked functions
+// ============================================================================
+
+#[test]
+fn test_blocked_functions_raise_sandbox_error() {
+    let lua = create_lua_vm().unwrap();
+
+    for function in ["os.exit", "os.execute", "io.popen"] {
+        let error = eval_error(&lua, &format!("{}('true')", function));
+        assert!(
+            error.contains(&format!(
+                "{} is not available in the plugin sandbox",
+                function
+            )),
+            "unexpected error for {}: {}",
+            function,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_debug_library_is_absent() {
+    let lua = create_lua_vm().unwrap();
+
+    let is_nil: bool = lua.load("return debug == nil").eval().unwrap();
+
+    assert!(is_nil);
+}
+
+#[test]
+fn test_harmless_os_functions_remain() {
+    let lua = create_lua_vm().unwrap();
+
+    let ok: bool = lua
+        .load(
+            "return type(os.time()) == 'number' and type(os.clock()) == 'number' \
+             and type(os.date('%Y')) == 'string' and os.difftime(2, 1) == 1",
+        )
+        .eval()
+        .unwrap();
+
+    assert!(ok);
+}
+
+#[test]
+fn test_c_modules_are_unavailable() {
+    let lua = create_lua_vm().unwrap();
+
+    let ok: bool = lua
+        .load("return package.loadlib == nil and package.cpath == ''")
+        .eval()
+        .unwrap();
+
+    assert!(ok);
+}
+
+// ============================================================================
+// require
+// ============================================================================
+
+#[test]
+fn test_require_loads_plugin_modules() {
+    let temp = TempDir::new().unwrap();
+    let plugin_dir = temp.path().join("notes");
+    fs::create_dir_all(plugin_dir.join("lua/notes")).unwrap();
+    fs::write(plugin_dir.join("lua/notes/utils.lua"), "return 'utils'").unwrap();
+    let lua = create_lua_vm().unwrap();
+    ModulePathBuilder::default()
+        .with_plugin_dir(plugin_dir.to_str().unwrap())
+        .apply(&lua)
+        .unwrap();
+
+    let value: String = lua.load("return require('notes.utils')").eval().unwrap();
+
+    assert_eq!(value, "utils");
+}
+
+#[test]
+fn test_require_ignores_reassigned_package_path() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("outside.lua"), "return 'outside'").unwrap();
+    let lua = create_lua_vm().unwrap();
+    ModulePathBuilder::default().apply(&lua).unwrap();
+
+    let error = eval_error(
+        &lua,
+        &format!(
+            "package.path = '{}/?.lua'; require('outside')",
+            temp.path().display()
+        ),
+    );
+
+    assert!(error.contains("module 'outside' not found"), "{}", error);
+}
+
+#[test]
+fn test_require_rejects_path_separators() {
+    let lua = create_lua_vm().unwrap();
+    ModulePathBuilder::default().apply(&lua).unwrap();
+
+    let error = eval_error(&lua, "require('/etc/passwd')");
+
+    assert!(error.contains("is not a plugin module name"), "{}", error);
+}
+
+#[test]
+fn test_require_still_reads_package_preload() {
+    let lua = create_lua_vm().unwrap();
+
+    let value: String = lua
+        .load(
+            "package.preload.inline = function() return 'preloaded' end; return require('inline')",
+        )
+        .eval()
+        .unwrap();
+
+    assert_eq!(value, "preloaded");
+}
+
+#[test]
+fn test_unsandboxed_require_uses_package_path() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("outside.lua"), "return 'outside'").unwrap();
+    let lua = create_lua_vm_with_sandbox(false).unwrap();
+
+    let value: String = lua
+        .load(format!(
+            "package.path = '{}/?.lua'; return require('outside')",
+            temp.path().display()
+        ))
+        .eval()
+        .unwrap();
+
+    assert_eq!(value, "outside");
+}
+
+// ============================================================================
+// --sandbox
+// ============================================================================
+
+#[test]
+fn test_execute_fails_on_io_popen_by_default() {
+    execute_popen_plugin(&[])
+        .failure()
+        .stderr(predicate::str::contains(
+            "io.popen is not available in the plugin sandbox",
+        ));
+}
+
+#[test]
+fn test_sandbox_off_allows_io_popen() {
+    execute_popen_plugin(&["--sandbox", "off"])
+        .success()
+        .stdout(predicate::str::contains("popen"));
+}
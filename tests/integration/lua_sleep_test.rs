@@ -0,0 +1,261 @@
+//! Integration tests for syntropy.sleep() Lua function
+//!
+//! Covers the delay itself, that the wait doesn't block other async work on the same
+//! thread, that a sleeping task leaves the shared Lua VM to other tasks and the rejection
+//! of out of range values.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use mlua::{Function, Lua};
+use syntropy::{
+    configs::Config, create_lua_vm, execution::call_task_execute, plugins::load_plugins,
+};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn sleep_fn(lua: &Lua) -> Function {
+    let syntropy: mlua::Table = lua.globals().get("syntropy").unwrap();
+    syntropy.get("sleep").unwrap()
+}
+
+// Single-threaded, so a sleep that blocked the thread would stall every other task
+fn current_thread_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap()
+}
+
+// ============================================================================
+// Delay
+// ============================================================================
+
+#[test]
+fn sleep_waits_at_least_the_given_time() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    let started = Instant::now();
+    current_thread_runtime()
+        .block_on(sleep.call_async::<()>(50))
+        .unwrap();
+
+    assert!(started.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn sleep_of_zero_returns() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    current_thread_runtime()
+        .block_on(sleep.call_async::<()>(0))
+        .unwrap();
+}
+
+#[test]
+fn sleep_does_not_block_concurrent_tasks() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    let (slept_until, ticked_at) = current_thread_runtime().block_on(async {
+        let lua_sleep = async {
+            sleep.call_async::<()>(100).await.unwrap();
+            Instant::now()
+        };
+        let tick = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Instant::now()
+        };
+        tokio::join!(lua_sleep, tick)
+    });
+
+    assert!(
+        ticked_at < slept_until,
+        "The concurrent task only ran after syntropy.sleep() returned"
+    );
+}
+
+#[test]
+fn sleep_from_plugin_code() {
+    let lua = create_lua_vm().unwrap();
+    let function: Function = lua
+        .load(
+            r#"
+            return function()
+                syntropy.sleep(20)
+                return "slept"
+            end
+            "#,
+        )
+        .eval()
+        .unwrap();
+
+    let started = Instant::now();
+    let result = current_thread_runtime()
+        .block_on(function.call_async::<String>(()))
+        .unwrap();
+
+    assert_eq!(result, "slept");
+    assert!(started.elapsed() >= Duration::from_millis(20));
+}
+
+// ============================================================================
+// Shared Lua VM
+// ============================================================================
+
+const SLEEPY_PLUGIN: &str = r#"
+return {
+    metadata = {name = "sleepy", version = "1.0.0"},
+    tasks = {
+        slow = {
+            description = "Sleeps before returning the plugin it runs in",
+            execute = function()
+                syntropy.sleep(300)
+                return syntropy.plugin_name(), 0
+            end,
+        },
+        fast = {
+            description = "Returns right away",
+            execute = function() return "fast", 0 end,
+        },
+    },
+}
+"#;
+
+const OTHER_PLUGIN: &str = r#"
+return {
+    metadata = {name = "other", version = "1.0.0"},
+    tasks = {
+        run = {
+            description = "Returns the plugin it runs in",
+            execute = function() return syntropy.plugin_name(), 0 end,
+        },
+    },
+}
+"#;
+
+#[test]
+fn sleeping_task_does_not_block_other_tasks() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("sleepy", SLEEPY_PLUGIN);
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    let slow = plugins[0].tasks.get("slow").unwrap();
+    let fast = plugins[0].tasks.get("fast").unwrap();
+
+    let (slow_done_at, fast_done_at) = current_thread_runtime().block_on(async {
+        let slow_run = async {
+            call_task_execute(&lua, slow, &[]).await.unwrap();
+            Instant::now()
+        };
+        let fast_run = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let (output, _) = call_task_execute(&lua, fast, &[]).await.unwrap();
+            assert_eq!(output, "fast");
+            Instant::now()
+        };
+        tokio::join!(slow_run, fast_run)
+    });
+
+    assert!(
+        fast_done_at < slow_done_at,
+        "The other task only ran after the sleeping task returned"
+    );
+}
+
+#[test]
+fn sleeping_task_keeps_its_plugin_context() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("sleepy", SLEEPY_PLUGIN);
+    fixture.create_plugin("other", OTHER_PLUGIN);
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    let task = |plugin: &str, task: &str| {
+        plugins
+            .iter()
+            .find(|p| p.metadata.name == plugin)
+            .unwrap()
+            .tasks
+            .get(task)
+            .unwrap()
+            .clone()
+    };
+    let (slow, other) = (task("sleepy", "slow"), task("other", "run"));
+
+    let ((slow_output, _), (other_output, _)) = current_thread_runtime().block_on(async {
+        let slow_run = async { call_task_execute(&lua, &slow, &[]).await.unwrap() };
+        let other_run = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            call_task_execute(&lua, &other, &[]).await.unwrap()
+        };
+        tokio::join!(slow_run, other_run)
+    });
+
+    assert_eq!(slow_output, "sleepy");
+    assert_eq!(other_output, "other");
+}
+
+// ============================================================================
+// Invalid Values
+// ============================================================================
+
+#[test]
+fn negative_sleep_is_rejected() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    let error = current_thread_runtime()
+        .block_on(sleep.call_async::<()>(-5))
+        .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("syntropy.sleep() takes 0 to 3600000 milliseconds, got -5"),
+        "Unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn sleep_longer_than_an_hour_is_rejected() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    let started = Instant::now();
+    let error = current_thread_runtime()
+        .block_on(sleep.call_async::<()>(3_600_001))
+        .unwrap_err();
+
+    assert!(error.to_string().contains("got 3600001"), "{}", error);
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn non_numeric_sleep_is_rejected() {
+    let lua = create_lua_vm().unwrap();
+    let sleep = sleep_fn(&lua);
+
+    let result = current_thread_runtime().block_on(sleep.call_async::<()>("soon"));
+
+    assert!(result.is_err());
+}
@@ -2,22 +2,52 @@
 //!
 //! These tests verify behavior from an external user's perspective.
 
+mod app_run_task_test;
 mod case_sensitivity_test;
 mod circular_dependency_test;
+mod cli_completions_test;
+mod cli_config_test;
+mod cli_deep_link_test;
+mod cli_doctor_test;
 mod cli_execute_test;
+mod cli_execute_watch_test;
 mod cli_init_test;
 mod cli_list_test;
+mod cli_logging_test;
+mod cli_plugin_dir_test;
+mod cli_plugins_check_test;
+mod cli_plugins_scaffold_test;
+mod cli_schema_test;
+mod cli_test_command_test;
+mod cli_validate_test;
 mod colors_loading_test;
 mod config_validation_test;
+mod dedup_items_test;
+mod default_select_test;
 mod exit_code_integration_test;
+mod global_hooks_test;
+mod items_command_test;
+mod lua_clamp_exit_code_test;
+mod lua_clipboard_test;
 mod lua_expand_path_test;
+mod lua_git_branches_test;
+mod lua_git_status_test;
+mod lua_glob_test;
+mod lua_interactive_input_test;
+mod lua_log_test;
+mod lua_plugin_config_test;
+mod lua_plugin_context_test;
+mod lua_plugins_test;
 mod lua_registry_cleanup_test;
 mod lua_runtime_error_test;
+mod lua_sandbox_test;
+mod lua_sleep_test;
 mod malformed_module_test;
 mod module_edge_cases_test;
 mod module_nesting_and_merge_test;
 mod multisource_execute_routing_test;
 mod multisource_items_partial_failure_test;
+mod multisource_order_test;
 mod multisource_partial_failure_test;
 mod path_expansion_test;
 mod plugin_function_type_validation_test;
@@ -28,8 +58,18 @@ mod plugin_loading_edge_cases_test;
 mod plugin_loading_graceful_degradation_test;
 mod plugin_loading_test;
 mod plugin_manager_test;
+mod plugin_sync_test;
 mod plugin_validation_merge_test;
 mod plugin_validation_test;
+mod plugin_watcher_test;
+mod preview_batch_test;
+mod preview_syntax_test;
 mod shared_modules_test;
 mod signal_handling_test;
+mod source_color_test;
+mod source_enabled_test;
+mod structured_items_test;
 mod tag_stripping_execute_test;
+mod task_aliases_test;
+mod workspace_config_test;
+mod xdg_config_dirs_test;
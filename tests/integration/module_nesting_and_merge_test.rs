@@ -4,6 +4,7 @@
 // Consolidates tests for:
 // - Deep nesting (5+ levels)
 // - Multiple init.lua at different levels
+// - module.lua versus module/init.lua resolution, also across config overrides
 // - 3+ directory merge scenarios
 // - Module precedence across multiple plugin directories
 
@@ -377,3 +378,141 @@ return {
     assert_eq!(code, 0);
     assert_eq!(result, "vendored + namespaced");
 }
+
+// Writes `content` to `<plugins_dir>/<relative>`, creating parent directories
+fn write_file(plugins_dir: &std::path::Path, relative: &str, content: &str) {
+    let path = plugins_dir.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+const INIT_BASE_PLUGIN: &str = r#"
+local module = require("init_merge.module")
+return {
+    metadata = {name = "init_merge", version = "1.0.0"},
+    tasks = {
+        test = {
+            description = "Reports which module file was loaded",
+            execute = function()
+                return module.source, 0
+            end
+        }
+    }
+}
+"#;
+
+// Loads init_merge from the config and data plugin directories and runs its test task
+fn run_init_merge_task(fixture: &TestFixture) -> String {
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[
+            fixture.config_path().join("syntropy").join("plugins"),
+            fixture.data_path().join("syntropy").join("plugins"),
+        ],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    assert_eq!(plugins.len(), 1);
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let task = plugins[0].tasks.get("test").unwrap();
+    let (result, code) = rt
+        .block_on(async { call_task_execute(&lua, task, &[]).await })
+        .unwrap();
+    assert_eq!(code, 0);
+    result
+}
+
+#[test]
+fn test_module_file_takes_precedence_over_init_lua() {
+    // TEST 6: module.lua before module/init.lua
+    // require("plugin.module") tries lua/plugin/module.lua before lua/plugin/module/init.lua
+
+    let fixture = TestFixture::new();
+    let data_plugins = fixture.data_path().join("syntropy/plugins");
+    write_file(
+        &data_plugins,
+        "init_merge/lua/init_merge/module.lua",
+        r#"return { source = "module.lua" }"#,
+    );
+    write_file(
+        &data_plugins,
+        "init_merge/lua/init_merge/module/init.lua",
+        r#"return { source = "init.lua" }"#,
+    );
+    fixture.create_plugin("init_merge", INIT_BASE_PLUGIN);
+
+    assert_eq!(run_init_merge_task(&fixture), "module.lua");
+}
+
+#[test]
+fn test_init_lua_resolves_submodule_directory() {
+    // TEST 7: module/init.lua fallback
+    // Without lua/plugin/module.lua, require("plugin.module") loads lua/plugin/module/init.lua,
+    // which can require its own sub-modules
+
+    let fixture = TestFixture::new();
+    let data_plugins = fixture.data_path().join("syntropy/plugins");
+    write_file(
+        &data_plugins,
+        "init_merge/lua/init_merge/module/init.lua",
+        r#"
+local helper = require("init_merge.module.helper")
+return { source = "init.lua + " .. helper.name }
+"#,
+    );
+    write_file(
+        &data_plugins,
+        "init_merge/lua/init_merge/module/helper.lua",
+        r#"return { name = "helper" }"#,
+    );
+    fixture.create_plugin("init_merge", INIT_BASE_PLUGIN);
+
+    assert_eq!(run_init_merge_task(&fixture), "init.lua + helper");
+}
+
+#[test]
+fn test_config_override_init_lua_shadows_data_module_file() {
+    // TEST 8: init.lua in a config override
+    // Config directory templates come first, so the override's module/init.lua wins over
+    // the data plugin's module.lua
+
+    let fixture = TestFixture::new();
+    write_file(
+        &fixture.data_path().join("syntropy/plugins"),
+        "init_merge/lua/init_merge/module.lua",
+        r#"return { source = "data module.lua" }"#,
+    );
+    fixture.create_plugin("init_merge", INIT_BASE_PLUGIN);
+    write_file(
+        &fixture.config_path().join("syntropy/plugins"),
+        "init_merge/lua/init_merge/module/init.lua",
+        r#"return { source = "config init.lua" }"#,
+    );
+    fixture.create_plugin_override("init_merge", "return {}");
+
+    assert_eq!(run_init_merge_task(&fixture), "config init.lua");
+}
+
+#[test]
+fn test_data_init_lua_used_when_config_override_lacks_module() {
+    // TEST 9: init.lua fallback across directories
+    // An override without the module still resolves it from the data plugin's init.lua
+
+    let fixture = TestFixture::new();
+    write_file(
+        &fixture.data_path().join("syntropy/plugins"),
+        "init_merge/lua/init_merge/module/init.lua",
+        r#"return { source = "data init.lua" }"#,
+    );
+    fixture.create_plugin("init_merge", INIT_BASE_PLUGIN);
+    write_file(
+        &fixture.config_path().join("syntropy/plugins"),
+        "init_merge/lua/init_merge/other.lua",
+        r#"return {}"#,
+    );
+    fixture.create_plugin_override("init_merge", "return {}");
+
+    assert_eq!(run_init_merge_task(&fixture), "data init.lua");
+}
@@ -15,6 +15,7 @@
 use assert_cmd::Command;
 
 use crate::common::TestFixture;
+use syntropy::execution::EXIT_LUA_ERROR;
 
 const MINIMAL_CONFIG: &str = r#"
 default_plugin_icon = "⚒"
@@ -505,11 +506,16 @@ return {
         stderr
     );
 
-    // Operation should fail with non-zero exit code
-    assert!(
-        !output.status.success(),
-        "Should exit with error code when all sources fail"
+    // Every source raised a Lua error, so the task fails with that kind
+    assert_eq!(
+        output.status.code(),
+        Some(EXIT_LUA_ERROR),
+        "Should exit with the Lua error code when all sources fail. Got stderr: {}",
+        stderr
     );
+    for source in ["A", "B", "C"] {
+        assert!(stderr.contains(&format!("Source {} failed", source)));
+    }
 }
 
 // ============================================================================
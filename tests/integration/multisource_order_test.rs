@@ -0,0 +1,139 @@
+//! Integration tests for the order of item sources
+//!
+//! Lua tables don't keep the order their keys were declared in, so the loader orders item
+//! sources by source key. Tests that the order is the same on every load and that listing
+//! items, joining execute output and picking the exit code follow it.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use syntropy::{Config, create_lua_vm, load_plugins};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+// Declared out of key order, every source fails with its own exit code
+const MANY_SOURCES_PLUGIN: &str = r#"
+return {
+    metadata = {name = "sources", version = "1.0.0"},
+    tasks = {
+        run = {
+            description = "Run every source",
+            mode = "multi",
+            item_sources = {
+                delta = {
+                    tag = "d",
+                    items = function() return {"four"} end,
+                    execute = function(items) return "delta ran", 4 end,
+                },
+                alpha = {
+                    tag = "a",
+                    items = function() return {"one"} end,
+                    execute = function(items) return "alpha ran", 1 end,
+                },
+                echo = {
+                    tag = "e",
+                    items = function() return {"five"} end,
+                    execute = function(items) return "echo ran", 5 end,
+                },
+                charlie = {
+                    tag = "c",
+                    items = function() return {"three"} end,
+                    execute = function(items) return "charlie ran", 3 end,
+                },
+                bravo = {
+                    tag = "b",
+                    items = function() return {"two"} end,
+                    execute = function(items) return "bravo ran", 2 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const SOURCE_KEYS: [&str; 5] = ["alpha", "bravo", "charlie", "delta", "echo"];
+
+fn syntropy(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "sources", "--task", "run"]);
+    cmd
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+#[test]
+fn sources_are_ordered_by_key_on_every_load() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("sources", MANY_SOURCES_PLUGIN);
+
+    // Every VM seeds its string hashes differently, fresh VMs would reorder a hash map
+    for _ in 0..10 {
+        let plugins = load_plugins(
+            &[fixture.data_path().join("syntropy").join("plugins")],
+            &Config::default(),
+            Arc::new(Mutex::new(create_lua_vm().unwrap())),
+        )
+        .unwrap();
+
+        let sources = plugins[0].tasks["run"].item_sources.as_ref().unwrap();
+        let keys: Vec<&str> = sources.keys().map(String::as_str).collect();
+        assert_eq!(keys, SOURCE_KEYS);
+    }
+}
+
+// ============================================================================
+// Execution
+// ============================================================================
+
+#[test]
+fn produced_items_follow_source_order() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("sources", MANY_SOURCES_PLUGIN);
+
+    let output = syntropy(&fixture).arg("--produce-items").output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .collect::<Vec<_>>(),
+        ["[a] one", "[b] two", "[c] three", "[d] four", "[e] five"]
+    );
+}
+
+#[test]
+fn output_and_exit_code_follow_source_order() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("sources", MANY_SOURCES_PLUGIN);
+
+    for _ in 0..3 {
+        let output = syntropy(&fixture)
+            .args(["--items", "[e] five,[c] three,[a] one,[d] four,[b] two"])
+            .output()
+            .unwrap();
+
+        // The first source to fail in key order decides the exit code
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .collect::<Vec<_>>(),
+            [
+                "alpha ran",
+                "bravo ran",
+                "charlie ran",
+                "delta ran",
+                "echo ran"
+            ]
+        );
+    }
+}
@@ -618,3 +618,100 @@ return {
         exit_code
     );
 }
+
+// ============================================================================
+// Test Category 5: continue_on_error
+// ============================================================================
+
+const CONTINUE_ON_ERROR_PLUGIN: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0", icon = "T", platforms = {"macos", "linux"}},
+    tasks = {
+        one_fails = {
+            description = "One source raises, the other succeeds",
+            mode = "multi",
+            continue_on_error = CONTINUE_ON_ERROR,
+            item_sources = {
+                failing = {
+                    tag = "f",
+                    items = function() return {"f1"} end,
+                    preselected_items = function() return {"f1"} end,
+                    execute = function(items)
+                        error("FAILING_SOURCE_RAISED")
+                    end,
+                },
+                working = {
+                    tag = "w",
+                    items = function() return {"w1"} end,
+                    preselected_items = function() return {"w1"} end,
+                    execute = function(items)
+                        return "WORKING_SOURCE_RAN", 0
+                    end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+#[test]
+fn test_execute_continue_on_error_runs_remaining_sources_and_captures_error() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin(
+        "test",
+        &CONTINUE_ON_ERROR_PLUGIN.replace("CONTINUE_ON_ERROR", "true"),
+    );
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", "one_fails"])
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("WORKING_SOURCE_RAN"),
+        "Working source should run despite the failing one. Got: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("1 item source(s) failed:") && stdout.contains("FAILING_SOURCE_RAISED"),
+        "Failing source error should be captured in the output. Got: {}",
+        stdout
+    );
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_execute_continue_on_error_false_aborts_on_raising_source() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin(
+        "test",
+        &CONTINUE_ON_ERROR_PLUGIN.replace("CONTINUE_ON_ERROR", "false"),
+    );
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "test", "--task", "one_fails"])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Item source 'failing' failed") && stderr.contains("FAILING_SOURCE_RAISED"),
+        "Abort error should name the failing source. Got: {}",
+        stderr
+    );
+    assert!(
+        !stderr.contains("All item sources failed"),
+        "Abort should not be reported as every source failing. Got: {}",
+        stderr
+    );
+}
@@ -3,7 +3,10 @@
 //! Tests the plugin loader, merge system, and validation logic.
 
 use std::sync::Arc;
-use syntropy::{Config, create_lua_vm, load_plugins};
+use syntropy::{
+    Config, create_lua_vm, load_plugins,
+    plugins::{Mode, PluginLoadStatus, ValidationIssue, load_plugins_with_report, plugin_warnings},
+};
 use tokio::sync::Mutex;
 
 use crate::common::TestFixture;
@@ -56,7 +59,7 @@ return {
             description = "Test task",
             name = "Single Selection Task",
             description = "Test task with none mode",
-            mode = "none",
+            mode = "single",
             item_sources = {
                 single = {
                     tag = "ss",
@@ -181,6 +184,43 @@ fn test_load_multiple_plugins() {
     assert!(names.contains(&"plugin3"));
 }
 
+#[test]
+fn test_load_many_plugins_keeps_directory_order() {
+    // More plugins than threads preparing them, so several batches are compiled at once
+    let fixture = TestFixture::new();
+    let names: Vec<String> = (0..24).map(|i| format!("plugin{:02}", i)).collect();
+    for name in &names {
+        fixture.create_plugin(name, &MINIMAL_PLUGIN.replace("minimal", name));
+    }
+    fixture.create_plugin("plugin07", "return {\n    metadata = {name = \"broken\",\n");
+
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let (plugins, records) = load_plugins_with_report(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua,
+    )
+    .unwrap();
+
+    let loaded: Vec<&str> = plugins.iter().map(|p| p.metadata.name.as_str()).collect();
+    let expected: Vec<&str> = names
+        .iter()
+        .map(String::as_str)
+        .filter(|name| *name != "plugin07")
+        .collect();
+    assert_eq!(loaded, expected);
+
+    let broken = records.iter().find(|r| r.name == "plugin07").unwrap();
+    let PluginLoadStatus::Error(error) = &broken.status else {
+        panic!("Expected plugin07 to fail, got {:?}", broken.status);
+    };
+    assert!(
+        error.contains("Failed to evaluate plugin") && error.contains("plugin.lua"),
+        "{}",
+        error
+    );
+}
+
 #[test]
 fn test_load_plugin_single_char_icons() {
     // Icons must occupy a single terminal cell (width == 1)
@@ -366,7 +406,7 @@ return {
         task1 = {
             description = "Test task",
             name = "Original Name",
-            mode = "none",
+            mode = "single",
             item_sources = {
                 src = {
                     tag = "s",
@@ -414,7 +454,7 @@ return {
 
     // Verify override fields changed
     assert_eq!(task.name, "Custom Name"); // Overridden
-    assert_eq!(task.mode, syntropy::plugins::Mode::Multi); // Overridden from "none" to "multi"
+    assert_eq!(task.mode, syntropy::plugins::Mode::Multi); // Overridden from "single" to "multi"
 
     // Verify item_sources preserved from base
     assert!(task.item_sources.is_some());
@@ -781,7 +821,7 @@ return {
             description = "Test task",
             name = "Invalid Task",
             description = "Invalid task without execute or item_sources",
-            mode = "none",
+            mode = "single",
         }
     }
 }
@@ -1261,7 +1301,7 @@ return {
         },
         task2 = {
             description = "Test task 2",
-            mode = "none",
+            mode = "single",
             execution_confirmation_message = "This will delete files. Continue?",
             execute = function() return "done", 0 end
         }
@@ -1823,3 +1863,160 @@ return {
     );
     assert_eq!(plugins[0].metadata.name, "valid");
 }
+
+// ============================================================================
+// Default Task Mode Tests
+// ============================================================================
+
+const MODE_PLUGIN: &str = r#"
+return {
+    metadata = { name = "modes", version = "1.0.0" },
+    tasks = {
+        implicit = {
+            description = "No mode",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = function() return {"a"} end,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+        explicit = {
+            description = "Explicit mode",
+            mode = "single",
+            execute = function() return "ok", 0 end,
+        },
+    },
+}
+"#;
+
+fn load_with_default_task_mode(mode: Option<Mode>) -> Vec<syntropy::plugins::Plugin> {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("modes", MODE_PLUGIN);
+    let mut config = Config::default();
+    if let Some(mode) = mode {
+        config.default_task_mode = mode;
+    }
+
+    load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &config,
+        Arc::new(Mutex::new(create_lua_vm().unwrap())),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_task_without_mode_defaults_to_single() {
+    let plugins = load_with_default_task_mode(None);
+
+    assert_eq!(plugins[0].tasks["implicit"].mode, Mode::Single);
+}
+
+#[test]
+fn test_task_without_mode_uses_config_default_task_mode() {
+    let plugins = load_with_default_task_mode(Some(Mode::Multi));
+
+    assert_eq!(plugins[0].tasks["implicit"].mode, Mode::Multi);
+    assert_eq!(
+        plugins[0].tasks["explicit"].mode,
+        Mode::Single,
+        "explicit mode wins over default_task_mode"
+    );
+}
+
+#[test]
+fn test_deprecated_mode_none_loads_as_single_with_warning() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(
+        "legacy",
+        r#"
+return {
+    metadata = {name = "legacy", version = "1.0.0"},
+    tasks = {
+        legacy = {description = "Legacy mode", mode = "none", execute = function() return "ok", 0 end},
+        current = {description = "Current mode", mode = "single", execute = function() return "ok", 0 end},
+    },
+}
+"#,
+    );
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        Arc::new(Mutex::new(create_lua_vm().unwrap())),
+    )
+    .unwrap();
+
+    let tasks = &plugins[0].tasks;
+    assert_eq!(tasks["legacy"].mode, Mode::Single);
+    assert_eq!(tasks["current"].mode, Mode::Single);
+    assert!(tasks["legacy"].deprecated_mode_none);
+    assert!(!tasks["current"].deprecated_mode_none);
+    assert_eq!(
+        plugin_warnings(&plugins[0]),
+        [ValidationIssue::DeprecatedModeNone {
+            plugin: "legacy".to_string(),
+            task: "legacy".to_string(),
+        }]
+    );
+}
+
+// ============================================================================
+// Task Icon Tests
+// ============================================================================
+
+const TASK_ICON_PLUGIN: &str = r#"
+return {
+    metadata = {name = "icons", version = "1.0.0"},
+    tasks = {
+        search = {
+            description = "Search files",
+            icon = "⌕",
+            execute = function() return "ok", 0 end,
+        },
+        plain = {
+            description = "No icon",
+            execute = function() return "ok", 0 end,
+        },
+    },
+}
+"#;
+
+#[test]
+fn test_task_icon_is_parsed() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("icons", TASK_ICON_PLUGIN);
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        Arc::new(Mutex::new(create_lua_vm().unwrap())),
+    )
+    .unwrap();
+
+    assert_eq!(plugins[0].tasks["search"].icon, "⌕");
+    assert_eq!(plugins[0].tasks["plain"].icon, "");
+}
+
+#[test]
+fn test_task_icon_must_be_a_string() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(
+        "icons",
+        &TASK_ICON_PLUGIN.replace(r#"icon = "⌕""#, "icon = 42"),
+    );
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        Arc::new(Mutex::new(create_lua_vm().unwrap())),
+    )
+    .unwrap();
+
+    assert!(
+        plugins.is_empty(),
+        "plugin with invalid task icon is skipped"
+    );
+}
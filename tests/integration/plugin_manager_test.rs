@@ -225,6 +225,95 @@ fn test_list_plugins_mixed_with_non_plugin_directories() {
         .stdout(predicate::str::contains("not-managed-plugin").not());
 }
 
+#[test]
+fn test_list_plugins_piped_output_is_plain_aligned_table() {
+    let fixture = TestFixture::new();
+
+    fixture.create_plugin_override(
+        "table-plugin",
+        r#"
+return {
+    metadata = {
+        name = "table-plugin",
+        version = "2.3.4",
+        icon = "T",
+        description = "Plugin shown in the table",
+        platforms = {"linux", "macos"},
+    },
+    tasks = {
+        one = {description = "One", execute = function() return "", 0 end},
+        two = {description = "Two", execute = function() return "", 0 end},
+    },
+}
+"#,
+    );
+    fixture.create_plugin(
+        "broken-plugin",
+        r#"return {metadata = {name = "broken-plugin"}}"#,
+    );
+
+    let output = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["plugins", "--list"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains('\x1b'), "stdout: {}", stdout);
+
+    let header = stdout
+        .lines()
+        .find(|line| line.contains("NAME"))
+        .expect("table header");
+    for column in [
+        "NAME",
+        "VERSION",
+        "PLATFORMS",
+        "TASKS",
+        "STATUS",
+        "DESCRIPTION",
+    ] {
+        assert!(header.contains(column), "header: {}", header);
+    }
+
+    let row = stdout
+        .lines()
+        .find(|line| line.contains("table-plugin"))
+        .expect("plugin row");
+    let columns: Vec<&str> = row.split_whitespace().collect();
+    assert_eq!(
+        columns[..5],
+        ["T", "table-plugin", "2.3.4", "linux,macos", "2"]
+    );
+    assert!(row.ends_with("Plugin shown in the table"), "row: {}", row);
+    assert_eq!(
+        row.find("2.3.4"),
+        header.find("VERSION"),
+        "columns are aligned with the header"
+    );
+
+    let broken_row = stdout
+        .lines()
+        .find(|line| line.contains("broken-plugin"))
+        .expect("broken plugin row");
+    assert!(broken_row.contains("failed to load"), "row: {}", broken_row);
+}
+
+#[test]
+fn test_plugins_no_color_requires_list() {
+    let fixture = TestFixture::new();
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["plugins", "--install", "--no-color"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--list"));
+}
+
 #[test]
 fn test_plugins_command_requires_exactly_one_flag() {
     let fixture = TestFixture::new();
@@ -0,0 +1,203 @@
+//! Integration tests for syncing plugins that track a git branch
+//!
+//! Covers cloning and pulling with clone_or_update_plugin() against a local repository,
+//! the sync_due() interval check, the `syntropy plugins sync` command and the startup sync
+//! being skipped outside the TUI.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::{
+    fs,
+    path::Path,
+    process::Command as ProcessCommand,
+    time::{Duration, SystemTime},
+};
+use syntropy::plugins::git_ops::{SyncOutcome, clone_or_update_plugin, last_sync_time, sync_due};
+use tempfile::TempDir;
+
+use crate::common::{TestFixture, sample_plugin};
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = ProcessCommand::new("git")
+        .current_dir(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+/// Repository on branch `main` with a committed plugin.lua
+fn create_origin() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    git(dir.path(), &["init", "--quiet", "--initial-branch=main"]);
+    fs::write(dir.path().join("plugin.lua"), sample_plugin()).unwrap();
+    git(dir.path(), &["add", "."]);
+    git(dir.path(), &["commit", "--quiet", "-m", "initial"]);
+    dir
+}
+
+// ============================================================================
+// clone_or_update_plugin
+// ============================================================================
+
+#[test]
+fn test_clone_or_update_clones_missing_plugin() {
+    let origin = create_origin();
+    let plugins = TempDir::new().unwrap();
+    let dest = plugins.path().join("tracked");
+
+    let outcome = clone_or_update_plugin(origin.path().to_str().unwrap(), &dest, "main").unwrap();
+
+    assert_eq!(outcome, SyncOutcome::Cloned);
+    assert!(dest.join("plugin.lua").is_file());
+    assert!(last_sync_time(&dest).is_some());
+}
+
+#[test]
+fn test_clone_or_update_pulls_new_commits() {
+    let origin = create_origin();
+    let plugins = TempDir::new().unwrap();
+    let dest = plugins.path().join("tracked");
+    let url = origin.path().to_str().unwrap();
+    clone_or_update_plugin(url, &dest, "main").unwrap();
+
+    fs::write(origin.path().join("README.md"), "new").unwrap();
+    git(origin.path(), &["add", "."]);
+    git(origin.path(), &["commit", "--quiet", "-m", "second"]);
+
+    let outcome = clone_or_update_plugin(url, &dest, "main").unwrap();
+
+    assert_eq!(outcome, SyncOutcome::Updated);
+    assert!(dest.join("README.md").is_file());
+}
+
+#[test]
+fn test_clone_or_update_rejects_directory_that_is_not_a_repository() {
+    let origin = create_origin();
+    let plugins = TempDir::new().unwrap();
+    let dest = plugins.path().join("tracked");
+    fs::create_dir_all(&dest).unwrap();
+
+    let error = clone_or_update_plugin(origin.path().to_str().unwrap(), &dest, "main")
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("is not a git repository"), "{}", error);
+}
+
+// ============================================================================
+// sync_due
+// ============================================================================
+
+#[test]
+fn test_sync_due_after_interval_elapsed() {
+    let now = SystemTime::now();
+    let interval = Duration::from_secs(3600);
+
+    assert!(sync_due(
+        Some(now - Duration::from_secs(7200)),
+        now,
+        interval
+    ));
+    assert!(sync_due(Some(now - interval), now, interval));
+    assert!(!sync_due(
+        Some(now - Duration::from_secs(60)),
+        now,
+        interval
+    ));
+}
+
+#[test]
+fn test_sync_due_when_never_synced() {
+    assert!(sync_due(None, SystemTime::now(), Duration::from_secs(3600)));
+}
+
+#[test]
+fn test_sync_not_due_for_last_sync_in_the_future() {
+    let now = SystemTime::now();
+
+    assert!(!sync_due(
+        Some(now + Duration::from_secs(60)),
+        now,
+        Duration::from_secs(3600)
+    ));
+}
+
+// ============================================================================
+// syntropy plugins sync
+// ============================================================================
+
+#[test]
+fn test_plugins_sync_skips_pinned_plugins() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+[plugins.pinned]
+git = "https://github.com/user/pinned"
+tag = "v1.0.0"
+"#,
+    );
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["plugins", "sync"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "pinned - pinned to v1.0.0, skipping",
+        ));
+}
+
+#[test]
+fn test_plugins_sync_fails_for_undeclared_plugin() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["plugins", "sync", "--plugin", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Plugin 'missing' not declared in config",
+        ));
+}
+
+#[test]
+fn test_list_does_not_pull_due_plugins() {
+    let origin = create_origin();
+    let fixture = TestFixture::new();
+    let dest = fixture.data_path().join("syntropy/plugins/test-plugin");
+    clone_or_update_plugin(origin.path().to_str().unwrap(), &dest, "main").unwrap();
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+[plugins.test-plugin]
+git = "https://github.com/user/test-plugin"
+branch = "main"
+sync_interval = 0
+"#,
+    );
+
+    fs::write(origin.path().join("README.md"), "new").unwrap();
+    git(origin.path(), &["add", "."]);
+    git(origin.path(), &["commit", "--quiet", "-m", "second"]);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .args(["list"])
+        .assert()
+        .success();
+
+    assert!(!dest.join("README.md").exists());
+}
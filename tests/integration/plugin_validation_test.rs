@@ -116,6 +116,30 @@ fn test_complete_valid_plugin() {
         .stdout(predicate::str::contains("is valid"));
 }
 
+#[test]
+fn test_deprecated_mode_none_warns_but_is_valid() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("complete", COMPLETE_VALID_PLUGIN);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("complete")
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"))
+        .stderr(predicate::str::contains(
+            "⚠ Task (complete) none_task uses the deprecated mode='none', use mode='single' instead",
+        ));
+}
+
 #[test]
 fn test_validate_plugin_directory_path() {
     let fixture = TestFixture::new();
@@ -1284,3 +1308,243 @@ return {
         .success() // Unicode emoji should be accepted
         .stdout(predicate::str::contains("is valid"));
 }
+
+// ============================================================================
+// Task order and group Fields
+// ============================================================================
+
+#[test]
+fn test_order_wrong_type() {
+    const ORDER_STRING: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {t = {description = "d", order = "first", execute = function() return "", 0 end}}
+}
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_plugin("order-string", ORDER_STRING);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("order-string")
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 't' order field must be an integer, got string",
+        ));
+}
+
+#[test]
+fn test_group_wrong_type() {
+    const GROUP_NUMBER: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {t = {description = "d", group = 3, execute = function() return "", 0 end}}
+}
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_plugin("group-number", GROUP_NUMBER);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("group-number")
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 't' group field must be a string, got integer",
+        ));
+}
+
+#[test]
+fn test_order_and_group_accepted() {
+    const ORDERED: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {t = {description = "d", order = 1, group = "dev", execute = function() return "", 0 end}}
+}
+"#;
+
+    let fixture = TestFixture::new();
+    fixture.create_plugin("ordered", ORDERED);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("ordered")
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+        .success();
+}
+
+// ============================================================================
+// Function Fields
+// ============================================================================
+
+fn validate_plugin_cli(plugin_name: &str, content: &str) -> assert_cmd::assert::Assert {
+    let fixture = TestFixture::new();
+    fixture.create_plugin(plugin_name, content);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join(plugin_name)
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+}
+
+#[test]
+fn test_task_preview_not_a_function_rejected() {
+    const PREVIEW_NUMBER: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {t = {description = "d", preview = 5, execute = function() return "", 0 end}}
+}
+"#;
+
+    validate_plugin_cli("preview-number", PREVIEW_NUMBER)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 't' preview field must be a function, got integer",
+        ));
+}
+
+#[test]
+fn test_task_hooks_not_functions_rejected() {
+    const POST_RUN_STRING: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {t = {description = "d", post_run = "cleanup", execute = function() return "", 0 end}}
+}
+"#;
+
+    validate_plugin_cli("post-run-string", POST_RUN_STRING)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 't' post_run field must be a function, got string",
+        ));
+}
+
+#[test]
+fn test_item_source_fields_not_functions_rejected() {
+    const SOURCE_FIELDS: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "d",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = {"a", "b"},
+                    execute = function(items) return "", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+    const SOURCE_PREVIEW: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "d",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    items = function() return {"a"} end,
+                    preview = 5,
+                    execute = function(items) return "", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+    validate_plugin_cli("source-items", SOURCE_FIELDS)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'src' in task 't' items field must be a function, got table",
+        ));
+    validate_plugin_cli("source-preview", SOURCE_PREVIEW)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'src' in task 't' preview field must be a function, got integer",
+        ));
+}
+
+#[test]
+fn test_item_source_without_any_execute_rejected() {
+    const NO_EXECUTE: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "d",
+            item_sources = {
+                a = {tag = "a", items = function() return {"x"} end, execute = function(items) return "", 0 end},
+                b = {tag = "b", items = function() return {"y"} end},
+            },
+        },
+    },
+}
+"#;
+
+    validate_plugin_cli("no-execute", NO_EXECUTE)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'b' in task 't' has no 'execute' function and task 't' defines none to fall back to",
+        ));
+}
+
+#[test]
+fn test_item_source_falls_back_to_task_execute_accepted() {
+    const TASK_EXECUTE: &str = r#"
+return {
+    metadata = {name = "test", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "d",
+            execute = function(items) return "", 0 end,
+            item_sources = {
+                a = {tag = "a", items = function() return {"x"} end},
+                b = {tag = "b", items = function() return {"y"} end},
+            },
+        },
+    },
+}
+"#;
+
+    validate_plugin_cli("task-execute", TASK_EXECUTE).success();
+}
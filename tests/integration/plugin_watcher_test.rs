@@ -0,0 +1,87 @@
+//! Integration tests for the plugin directory watcher behind TUI plugin reloading
+//!
+//! Covers reload signals for `.lua` changes, coalescing a burst of changes into a single
+//! reload and ignoring other files.
+
+use std::{
+    fs, thread,
+    time::{Duration, Instant},
+};
+use syntropy::configs::{PluginWatcher, plugin_watcher::PLUGIN_RELOAD_DEBOUNCE};
+use tempfile::TempDir;
+use tokio::sync::watch;
+
+// Long enough for the file system events and the debounce on a busy machine
+const RELOAD_TIMEOUT: Duration = Duration::from_secs(5);
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn watch_dir() -> (TempDir, PluginWatcher, watch::Receiver<u64>) {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("notes")).unwrap();
+    let (watcher, changes) = PluginWatcher::new(&[dir.path().to_path_buf()]).unwrap();
+    // Let the watcher settle before the first change
+    thread::sleep(Duration::from_millis(100));
+    (dir, watcher, changes)
+}
+
+fn wait_for_reload(changes: &watch::Receiver<u64>) -> bool {
+    let started = Instant::now();
+    while started.elapsed() < RELOAD_TIMEOUT {
+        if changes.has_changed().unwrap() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+// ============================================================================
+// Reload Signals
+// ============================================================================
+
+#[test]
+fn test_lua_change_signals_reload() {
+    let (dir, _watcher, changes) = watch_dir();
+
+    fs::write(dir.path().join("notes").join("plugin.lua"), "return {}").unwrap();
+
+    assert!(wait_for_reload(&changes));
+    assert_eq!(*changes.borrow(), 1);
+}
+
+#[test]
+fn test_burst_of_changes_signals_one_reload() {
+    let (dir, _watcher, mut changes) = watch_dir();
+    let plugin = dir.path().join("notes").join("plugin.lua");
+
+    for i in 0..5 {
+        fs::write(&plugin, format!("return {{ version = {} }}", i)).unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    assert!(wait_for_reload(&changes));
+    changes.mark_unchanged();
+    thread::sleep(PLUGIN_RELOAD_DEBOUNCE * 2);
+    assert_eq!(*changes.borrow(), 1);
+    assert!(!changes.has_changed().unwrap());
+}
+
+#[test]
+fn test_non_lua_change_does_not_signal_reload() {
+    let (dir, _watcher, changes) = watch_dir();
+
+    fs::write(dir.path().join("notes").join("README.md"), "notes").unwrap();
+    thread::sleep(PLUGIN_RELOAD_DEBOUNCE * 3);
+
+    assert!(!changes.has_changed().unwrap());
+}
+
+#[test]
+fn test_missing_plugin_directory_is_skipped() {
+    let dir = TempDir::new().unwrap();
+
+    assert!(PluginWatcher::new(&[dir.path().join("missing")]).is_ok());
+}
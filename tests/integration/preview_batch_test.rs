@@ -0,0 +1,187 @@
+//! Integration tests for run_preview_pipeline_batch and execute --produce-previews
+//!
+//! Previews of several items are generated in one call. Item source previews take
+//! precedence over the task preview, and a failing item doesn't abort the batch.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{
+    configs::Config,
+    execution::{run_items_pipeline, run_preview_pipeline_batch},
+    lua::create_lua_vm,
+    plugins::{Plugin, load_plugins},
+};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+// "fruits" previews its own items, "veggies" falls back to the task preview, "broken"
+// fails for every item
+const PREVIEW_PLUGIN: &str = r#"
+return {
+    metadata = {name = "previews", version = "1.0.0"},
+    tasks = {
+        browse = {
+            description = "Browse",
+            mode = "multi",
+            preview = function(item) return "task preview of " .. item end,
+            item_sources = {
+                fruits = {
+                    tag = "f",
+                    items = function() return {"apple", "pear"} end,
+                    preview = function(item) return "fruit " .. item end,
+                    execute = function() return "", 0 end,
+                },
+                veggies = {
+                    tag = "v",
+                    items = function() return {"leek"} end,
+                    execute = function() return "", 0 end,
+                },
+                broken = {
+                    tag = "b",
+                    items = function() return {"bad"} end,
+                    preview = function(item) error("cannot preview " .. item) end,
+                    execute = function() return "", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn load_preview_plugin(fixture: &TestFixture) -> (Arc<Mutex<mlua::Lua>>, Plugin) {
+    fixture.create_plugin("previews", PREVIEW_PLUGIN);
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let mut plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    (lua, plugins.remove(0))
+}
+
+// ============================================================================
+// Batch API
+// ============================================================================
+
+#[test]
+fn test_batch_honors_item_source_over_task_precedence() {
+    let fixture = TestFixture::new();
+    let (lua, plugin) = load_preview_plugin(&fixture);
+    let task = plugin.tasks.get("browse").unwrap();
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let previews = rt.block_on(async {
+        let (items, _) = run_items_pipeline(lua.clone(), task).await.unwrap();
+        run_preview_pipeline_batch(lua.clone(), task, &items).await
+    });
+
+    assert_eq!(
+        previews["[f] apple"].as_ref().unwrap().content,
+        "fruit apple"
+    );
+    assert_eq!(previews["[f] pear"].as_ref().unwrap().content, "fruit pear");
+    assert_eq!(
+        previews["[v] leek"].as_ref().unwrap().content,
+        "task preview of leek"
+    );
+}
+
+#[test]
+fn test_batch_keeps_going_after_failing_item() {
+    let fixture = TestFixture::new();
+    let (lua, plugin) = load_preview_plugin(&fixture);
+    let task = plugin.tasks.get("browse").unwrap();
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let items: Vec<String> = ["[b] bad", "[f] apple", "[x] unknown"]
+        .map(String::from)
+        .to_vec();
+    let previews = rt.block_on(run_preview_pipeline_batch(lua.clone(), task, &items));
+
+    assert_eq!(previews.len(), 3);
+    let error = previews["[b] bad"].as_ref().unwrap_err();
+    assert!(
+        format!("{:#}", error).contains("cannot preview bad"),
+        "Unexpected error: {:#}",
+        error
+    );
+    assert!(previews["[x] unknown"].is_err());
+    assert_eq!(
+        previews["[f] apple"].as_ref().unwrap().content,
+        "fruit apple"
+    );
+}
+
+#[test]
+fn test_batch_of_no_items_is_empty() {
+    let fixture = TestFixture::new();
+    let (lua, plugin) = load_preview_plugin(&fixture);
+    let task = plugin.tasks.get("browse").unwrap();
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    let previews = rt.block_on(run_preview_pipeline_batch(lua, task, &[]));
+
+    assert!(previews.is_empty());
+}
+
+// ============================================================================
+// CLI
+// ============================================================================
+
+#[test]
+fn test_produce_previews_prints_every_preview_and_reports_failures() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("previews", PREVIEW_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "execute",
+            "--plugin",
+            "previews",
+            "--task",
+            "browse",
+            "--produce-previews",
+            "--sort",
+            "alpha",
+        ])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "==> [f] apple <==\nfruit apple\n==> [v] leek <==\ntask preview of leek\n==> [f] pear <==\nfruit pear\n",
+        ))
+        .stderr(predicate::str::contains(
+            "Failed to generate preview for '[b] bad'",
+        ));
+}
+
+#[test]
+fn test_produce_previews_conflicts_with_items() {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args([
+            "execute",
+            "--plugin",
+            "previews",
+            "--task",
+            "browse",
+            "--produce-previews",
+            "--items",
+            "apple",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -0,0 +1,140 @@
+//! Integration tests for previews returned as `{ content, syntax }` tables
+//!
+//! Tests that the preview pipeline keeps the syntax hint of item source and task previews,
+//! that plain strings have none and that malformed tables fail the preview.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use syntropy::{Config, create_lua_vm, execution::run_preview_pipeline, load_plugins};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const SYNTAX_PLUGIN: &str = r#"
+return {
+    metadata = {name = "code", version = "1.0.0"},
+    tasks = {
+        files = {
+            description = "Browse files",
+            mode = "multi",
+            preview = function(item) return {content = "-- " .. item, syntax = "lua"} end,
+            execute = function() return "", 0 end,
+            item_sources = {
+                rust = {
+                    tag = "rs",
+                    items = function() return {"main.rs"} end,
+                    preview = function(item) return {content = "fn main() {}", syntax = "rust"} end,
+                },
+                lua = {
+                    tag = "lua",
+                    items = function() return {"init.lua"} end,
+                },
+                text = {
+                    tag = "txt",
+                    items = function() return {"notes"} end,
+                    preview = function(item) return "plain " .. item end,
+                },
+                broken = {
+                    tag = "bad",
+                    items = function() return {"nothing", "numeric"} end,
+                    preview = function(item)
+                        if item == "nothing" then return {syntax = "rust"} end
+                        return {content = "x", syntax = {"rust"}}
+                    end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn preview(item: &str) -> anyhow::Result<syntropy::execution::PreviewOutput> {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("code", SYNTAX_PLUGIN);
+    let lua = Arc::new(Mutex::new(create_lua_vm().unwrap()));
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        lua.clone(),
+    )
+    .unwrap();
+    let task = plugins[0].tasks["files"].clone();
+
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(run_preview_pipeline(lua, &task, item))
+}
+
+// ============================================================================
+// Pipeline
+// ============================================================================
+
+#[test]
+fn item_source_preview_table_keeps_syntax() {
+    let output = preview("[rs] main.rs").unwrap();
+
+    assert_eq!(output.content, "fn main() {}");
+    assert_eq!(output.syntax.as_deref(), Some("rust"));
+}
+
+#[test]
+fn task_preview_table_keeps_syntax() {
+    let output = preview("[lua] init.lua").unwrap();
+
+    assert_eq!(output.content, "-- init.lua");
+    assert_eq!(output.syntax.as_deref(), Some("lua"));
+}
+
+#[test]
+fn string_preview_has_no_syntax() {
+    let output = preview("[txt] notes").unwrap();
+
+    assert_eq!(output.content, "plain notes");
+    assert_eq!(output.syntax, None);
+}
+
+#[test]
+fn preview_table_without_content_fails() {
+    let error = preview("[bad] nothing").unwrap_err();
+
+    assert!(
+        format!("{:#}", error).contains("needs a 'content' string"),
+        "Unexpected error: {:#}",
+        error
+    );
+}
+
+#[test]
+fn preview_table_with_non_string_syntax_fails() {
+    let error = preview("[bad] numeric").unwrap_err();
+
+    assert!(
+        format!("{:#}", error).contains("has a 'syntax' that is not a string"),
+        "Unexpected error: {:#}",
+        error
+    );
+}
+
+// ============================================================================
+// CLI
+// ============================================================================
+
+#[test]
+fn cli_preview_prints_content_only() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("code", SYNTAX_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "code", "--task", "files"])
+        .args(["--preview", "[rs] main.rs"])
+        .assert()
+        .success()
+        .stdout("fn main() {}\n");
+}
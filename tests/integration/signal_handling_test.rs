@@ -426,3 +426,151 @@ return {
         stderr
     );
 }
+
+// ============================================================================
+// Test Category 5: Child Process Termination
+// ============================================================================
+
+// Plugin whose execute runs `command` through syntropy.shell
+fn shell_plugin(command: &str) -> String {
+    format!(
+        r#"
+return {{
+    metadata = {{name = "children", version = "1.0.0", platforms = {{"macos", "linux"}}}},
+    tasks = {{
+        run = {{
+            name = "Run",
+            description = "Runs a shell command",
+            mode = "none",
+            execute = function()
+                syntropy.shell([[{}]])
+                return "done", 0
+            end,
+        }},
+    }},
+}}
+"#,
+        command
+    )
+}
+
+// Starts `syntropy execute` of a plugin that runs `command`, `command` must write its
+// pid to the returned path
+fn spawn_shell_task(fixture: &TestFixture, command: &str) -> std::process::Child {
+    fixture.create_config("syntropy.toml", MINIMAL_CONFIG);
+    fixture.create_plugin("children", &shell_plugin(command));
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "children", "--task", "run"])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .expect("Failed to spawn syntropy process")
+}
+
+fn wait_for_pid(pid_file: &Path) -> i32 {
+    for _ in 0..100 {
+        if let Ok(pid) = std::fs::read_to_string(pid_file)
+            && let Ok(pid) = pid.trim().parse()
+        {
+            return pid;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    panic!("Command never wrote its pid to {}", pid_file.display());
+}
+
+// Zombies count as exited, they only wait for their new parent to reap them
+fn is_running(pid: i32) -> bool {
+    let output = Command::new("ps")
+        .args(["-o", "stat=", "-p", &pid.to_string()])
+        .output()
+        .expect("Failed to run ps");
+    let state = String::from_utf8_lossy(&output.stdout);
+    !state.trim().is_empty() && !state.trim().starts_with('Z')
+}
+
+fn wait_until_exited(pid: i32) -> bool {
+    for _ in 0..40 {
+        if !is_running(pid) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+#[test]
+fn test_ctrl_c_terminates_running_shell_command() {
+    let fixture = TestFixture::new();
+    let pid_file = fixture.temp_dir.path().join("command.pid");
+    let mut child = spawn_shell_task(
+        &fixture,
+        &format!("echo $$ > {}; exec sleep 30", pid_file.display()),
+    );
+    let command_pid = wait_for_pid(&pid_file);
+
+    let started = std::time::Instant::now();
+    signal::kill(Pid::from_raw(child.id() as i32), Signal::SIGINT).expect("Failed to send SIGINT");
+    let status = child.wait().expect("Failed to wait for process");
+
+    assert_eq!(status.code(), Some(130));
+    assert!(
+        started.elapsed() < Duration::from_secs(10),
+        "syntropy should not wait for the command to finish"
+    );
+    assert!(
+        wait_until_exited(command_pid),
+        "Command should be terminated"
+    );
+}
+
+#[test]
+fn test_command_ignoring_sigterm_is_killed_after_grace_period() {
+    let fixture = TestFixture::new();
+    let pid_file = fixture.temp_dir.path().join("command.pid");
+    let mut child = spawn_shell_task(
+        &fixture,
+        &format!(
+            "trap '' TERM; echo $$ > {}; exec sleep 30",
+            pid_file.display()
+        ),
+    );
+    let command_pid = wait_for_pid(&pid_file);
+
+    let started = std::time::Instant::now();
+    signal::kill(Pid::from_raw(child.id() as i32), Signal::SIGINT).expect("Failed to send SIGINT");
+    let status = child.wait().expect("Failed to wait for process");
+
+    assert_eq!(status.code(), Some(130));
+    let elapsed = started.elapsed();
+    assert!(
+        elapsed >= Duration::from_secs(2) && elapsed < Duration::from_secs(10),
+        "Command should be killed after the 2s grace period, took {:?}",
+        elapsed
+    );
+    assert!(wait_until_exited(command_pid), "Command should be killed");
+}
+
+#[test]
+fn test_sigterm_terminates_shell_command_and_exits_143() {
+    let fixture = TestFixture::new();
+    let pid_file = fixture.temp_dir.path().join("command.pid");
+    let mut child = spawn_shell_task(
+        &fixture,
+        &format!("echo $$ > {}; exec sleep 30", pid_file.display()),
+    );
+    let command_pid = wait_for_pid(&pid_file);
+
+    signal::kill(Pid::from_raw(child.id() as i32), Signal::SIGTERM)
+        .expect("Failed to send SIGTERM");
+    let status = child.wait().expect("Failed to wait for process");
+
+    assert_eq!(status.code(), Some(143));
+    assert!(
+        wait_until_exited(command_pid),
+        "Command should be terminated"
+    );
+}
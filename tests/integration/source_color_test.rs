@@ -0,0 +1,113 @@
+//! Integration tests for the `color` of item sources
+//!
+//! Tests that the loader keeps the color of each source and rejects colors that aren't
+//! strings or aren't valid color names or hex values.
+
+use std::sync::Arc;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use syntropy::{Config, create_lua_vm, load_plugins};
+use tokio::sync::Mutex;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+fn colored_plugin(color: &str) -> String {
+    format!(
+        r#"
+return {{
+    metadata = {{name = "brew", version = "1.0.0"}},
+    tasks = {{
+        install = {{
+            description = "Install packages",
+            mode = "multi",
+            item_sources = {{
+                formulae = {{
+                    tag = "pkg",
+                    color = {},
+                    items = function() return {{"git"}} end,
+                    execute = function(items) return "", 0 end,
+                }},
+                casks = {{
+                    tag = "cask",
+                    items = function() return {{"firefox"}} end,
+                    execute = function(items) return "", 0 end,
+                }},
+            }},
+        }},
+    }},
+}}
+"#,
+        color
+    )
+}
+
+fn validate(fixture: &TestFixture) -> assert_cmd::assert::Assert {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["validate", "--plugin"])
+        .arg(fixture.data_path().join("syntropy/plugins/brew/plugin.lua"))
+        .assert()
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+#[test]
+fn source_color_is_loaded() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("brew", &colored_plugin(r#""cyan""#));
+
+    let plugins = load_plugins(
+        &[fixture.data_path().join("syntropy").join("plugins")],
+        &Config::default(),
+        Arc::new(Mutex::new(create_lua_vm().unwrap())),
+    )
+    .unwrap();
+
+    let sources = plugins[0].tasks["install"].item_sources.as_ref().unwrap();
+    assert_eq!(sources["formulae"].color.as_deref(), Some("cyan"));
+    assert_eq!(sources["casks"].color, None);
+}
+
+#[test]
+fn hex_source_color_is_valid() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("brew", &colored_plugin(r##""#ff8800""##));
+
+    validate(&fixture).success();
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn invalid_source_color_fails_validation() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("brew", &colored_plugin(r#""not-a-color""#));
+
+    validate(&fixture)
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'formulae' in task 'install' has an invalid 'color'",
+        ));
+}
+
+#[test]
+fn source_color_must_be_a_string() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("brew", &colored_plugin("42"));
+
+    validate(&fixture)
+        .failure()
+        .stderr(predicate::str::contains(
+            "'color' must be a string, got integer",
+        ));
+}
@@ -0,0 +1,226 @@
+//! Integration tests for the item source `enabled()` predicate
+//!
+//! Tests that disabled sources contribute no items and never execute, and that
+//! `enabled` is validated like the other item source functions.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const TWO_SOURCES: &str = r#"
+return {
+    metadata = {name = "toggled", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        browse = {
+            description = "Browse two sources",
+            mode = "multi",
+            item_sources = {
+                on = {
+                    tag = "on",
+                    enabled = function() return true end,
+                    items = function() return {"visible1", "visible2"} end,
+                    execute = function(items) return "ON:" .. table.concat(items, "|"), 0 end,
+                },
+                off = {
+                    tag = "off",
+                    enabled = function() return false end,
+                    items = function() return {"hidden"} end,
+                    preselected_items = function() return {"hidden"} end,
+                    execute = function(items) return "OFF:" .. table.concat(items, "|"), 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const ALL_DISABLED: &str = r#"
+return {
+    metadata = {name = "toggled", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        browse = {
+            description = "Browse a disabled source",
+            item_sources = {
+                off = {
+                    tag = "off",
+                    enabled = function() return false end,
+                    items = function() return {"hidden"} end,
+                    execute = function(items) return "OFF", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const ENABLED_NOT_FUNCTION: &str = r#"
+return {
+    metadata = {name = "toggled", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        browse = {
+            description = "Browse",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    enabled = false,
+                    items = function() return {"a"} end,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const ENABLED_NOT_BOOLEAN: &str = r#"
+return {
+    metadata = {name = "toggled", version = "1.0.0", platforms = {"macos", "linux"}},
+    tasks = {
+        browse = {
+            description = "Browse",
+            item_sources = {
+                src = {
+                    tag = "s",
+                    enabled = function() return "yes" end,
+                    items = function() return {"a"} end,
+                    execute = function(items) return "ok", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn syntropy(fixture: &TestFixture) -> Command {
+    let mut command = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    command
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path());
+    command
+}
+
+// ============================================================================
+// Items Tests
+// ============================================================================
+
+#[test]
+fn test_disabled_source_produces_no_items() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", TWO_SOURCES);
+
+    syntropy(&fixture)
+        .args(["execute", "--plugin", "toggled", "--task", "browse"])
+        .arg("--produce-items")
+        .assert()
+        .success()
+        .stdout("[on] visible1\n[on] visible2\n");
+}
+
+#[test]
+fn test_disabled_source_preselects_nothing() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", TWO_SOURCES);
+
+    syntropy(&fixture)
+        .args(["execute", "--plugin", "toggled", "--task", "browse"])
+        .arg("--produce-preselected-items")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn test_all_sources_disabled_is_an_empty_list() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", ALL_DISABLED);
+
+    syntropy(&fixture)
+        .args(["execute", "--plugin", "toggled", "--task", "browse"])
+        .arg("--produce-items")
+        .assert()
+        .success()
+        .stdout("");
+}
+
+// ============================================================================
+// Execute Tests
+// ============================================================================
+
+#[test]
+fn test_enabled_source_executes() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", TWO_SOURCES);
+
+    syntropy(&fixture)
+        .args(["execute", "--plugin", "toggled", "--task", "browse"])
+        .args(["--items", "[on] visible1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ON:visible1"))
+        .stdout(predicate::str::contains("OFF").not());
+}
+
+#[test]
+fn test_items_of_disabled_source_cannot_be_selected() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", TWO_SOURCES);
+
+    syntropy(&fixture)
+        .args(["execute", "--plugin", "toggled", "--task", "browse"])
+        .args(["--items", "[off] hidden"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("OFF").not())
+        .stderr(predicate::str::contains("Item '[off] hidden' not found"));
+}
+
+// ============================================================================
+// Validation Tests
+// ============================================================================
+
+#[test]
+fn test_enabled_must_be_a_function() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", ENABLED_NOT_FUNCTION);
+
+    syntropy(&fixture)
+        .args(["validate", "--plugin"])
+        .arg(
+            fixture
+                .data_path()
+                .join("syntropy/plugins/toggled/plugin.lua"),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'src' in task 'browse' enabled field must be a function, got boolean",
+        ));
+}
+
+#[test]
+fn test_enabled_must_return_a_boolean() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("toggled", ENABLED_NOT_BOOLEAN);
+
+    syntropy(&fixture)
+        .args(["validate", "--plugin"])
+        .arg(
+            fixture
+                .data_path()
+                .join("syntropy/plugins/toggled/plugin.lua"),
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item source 'src' enabled() must return a boolean but returned string",
+        ));
+}
@@ -0,0 +1,162 @@
+//! Integration tests for items returned as `{ display, value, preview }` tables
+//!
+//! The display string is what is listed, searched and passed to `--items`, while `execute`
+//! and `preview` receive the value.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugins
+// ============================================================================
+
+const STRUCTURED_ITEMS_PLUGIN: &str = r#"
+return {
+    metadata = {name = "structured", version = "1.0.0", icon = "S", platforms = {"macos", "linux"}},
+    tasks = {
+        files = {
+            description = "Structured items",
+            mode = "multi",
+            item_sources = {
+                src = {
+                    tag = "f",
+                    items = function()
+                        return {
+                            {display = "Readme", value = "/tmp/README.md"},
+                            {display = "License", value = "/tmp/LICENSE", preview = "MIT license"},
+                            "plain",
+                        }
+                    end,
+                    preview = function(item) return "Preview of " .. item end,
+                    execute = function(items) return "Executed: " .. table.concat(items, ","), 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+const MISSING_DISPLAY_PLUGIN: &str = r#"
+return {
+    metadata = {name = "structured", version = "1.0.0", icon = "S", platforms = {"macos", "linux"}},
+    tasks = {
+        files = {
+            description = "Structured items",
+            item_sources = {
+                src = {
+                    tag = "f",
+                    items = function() return {{value = "/tmp/README.md"}} end,
+                    execute = function(items) return "Executed", 0 end,
+                },
+            },
+        },
+    },
+}
+"#;
+
+fn syntropy(fixture: &TestFixture) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "structured", "--task", "files"]);
+    cmd
+}
+
+fn fixture_with(plugin: &str) -> TestFixture {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("structured", plugin);
+    fixture
+}
+
+// ============================================================================
+// Display vs Value
+// ============================================================================
+
+#[test]
+fn produce_items_lists_display_strings() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .arg("--produce-items")
+        .assert()
+        .success()
+        .stdout("Readme\nLicense\nplain\n");
+}
+
+#[test]
+fn execute_receives_values_of_selected_displays() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--items", "Readme,plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Executed: /tmp/README.md,plain"));
+}
+
+#[test]
+fn execute_without_items_receives_all_values() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Executed: /tmp/README.md,/tmp/LICENSE,plain",
+        ));
+}
+
+#[test]
+fn items_are_not_selectable_by_value() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--items", "/tmp/README.md"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
+
+// ============================================================================
+// Previews
+// ============================================================================
+
+#[test]
+fn preview_function_receives_value() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--preview", "Readme"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Preview of /tmp/README.md"));
+}
+
+#[test]
+fn item_preview_replaces_preview_function() {
+    let fixture = fixture_with(STRUCTURED_ITEMS_PLUGIN);
+
+    syntropy(&fixture)
+        .args(["--preview", "License"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("MIT license"))
+        .stdout(predicate::str::contains("Preview of").not());
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[test]
+fn item_table_without_display_fails() {
+    let fixture = fixture_with(MISSING_DISPLAY_PLUGIN);
+
+    syntropy(&fixture)
+        .arg("--produce-items")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("needs a 'display' string"));
+}
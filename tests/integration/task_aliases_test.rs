@@ -0,0 +1,190 @@
+//! Integration tests for task aliases
+//!
+//! `aliases = {...}` lets `execute --task` take short names. Exact task keys win over
+//! aliases, and an alias shared by several tasks is rejected at validation.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Mock Plugin Templates
+// ============================================================================
+
+const ALIASED_PLUGIN: &str = r#"
+return {
+    metadata = {name = "packages", version = "1.0.0"},
+    tasks = {
+        export_package_list = {
+            description = "Export",
+            aliases = {"export", "exp"},
+            execute = function() return "exported", 0 end,
+        },
+        export = {
+            description = "Key shadowing an alias",
+            execute = function() return "export task", 0 end,
+        },
+    },
+}
+"#;
+
+const COLLIDING_ALIASES_PLUGIN: &str = r#"
+return {
+    metadata = {name = "colliding", version = "1.0.0"},
+    tasks = {
+        install = {
+            description = "Install",
+            aliases = {"i"},
+            execute = function() return "", 0 end,
+        },
+        info = {
+            description = "Info",
+            aliases = {"i"},
+            execute = function() return "", 0 end,
+        },
+    },
+}
+"#;
+
+const INVALID_ALIASES_PLUGIN: &str = r#"
+return {
+    metadata = {name = "invalid", version = "1.0.0"},
+    tasks = {
+        t = {
+            description = "Test task",
+            aliases = "t2",
+            execute = function() return "", 0 end,
+        },
+    },
+}
+"#;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn execute_task(fixture: &TestFixture, task: &str) -> assert_cmd::assert::Assert {
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args(["execute", "--plugin", "packages", "--task", task])
+        .assert()
+}
+
+fn validate_plugin(fixture: &TestFixture, name: &str) -> assert_cmd::assert::Assert {
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join(name)
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .arg("validate")
+        .arg("--plugin")
+        .arg(&plugin_path)
+        .assert()
+}
+
+// ============================================================================
+// Resolution
+// ============================================================================
+
+#[test]
+fn test_execute_resolves_task_by_alias() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("packages", ALIASED_PLUGIN);
+
+    execute_task(&fixture, "exp")
+        .success()
+        .stdout(predicate::str::contains("exported"));
+}
+
+#[test]
+fn test_execute_prefers_exact_key_over_alias() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("packages", ALIASED_PLUGIN);
+
+    execute_task(&fixture, "export")
+        .success()
+        .stdout(predicate::str::contains("export task"));
+}
+
+#[test]
+fn test_execute_still_resolves_canonical_key() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("packages", ALIASED_PLUGIN);
+
+    execute_task(&fixture, "export_package_list")
+        .success()
+        .stdout(predicate::str::contains("exported"));
+}
+
+#[test]
+fn test_list_task_detail_shows_aliases() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("packages", ALIASED_PLUGIN);
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .args([
+            "list",
+            "--plugin",
+            "packages",
+            "--task",
+            "export_package_list",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("aliases: export, exp"));
+}
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+#[test]
+fn test_alias_collision_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("colliding", COLLIDING_ALIASES_PLUGIN);
+
+    validate_plugin(&fixture, "colliding")
+        .failure()
+        .stderr(predicate::str::contains(
+            "alias 'i' is used by several tasks: info, install",
+        ));
+}
+
+#[test]
+fn test_alias_collision_reported_as_duplicate_alias() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("colliding", COLLIDING_ALIASES_PLUGIN);
+
+    let plugin_path = fixture
+        .data_path()
+        .join("syntropy")
+        .join("plugins")
+        .join("colliding")
+        .join("plugin.lua");
+
+    Command::new(assert_cmd::cargo::cargo_bin!("syntropy"))
+        .args(["validate", "--format", "json", "--plugin"])
+        .arg(&plugin_path)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("DUPLICATE_ALIAS"));
+}
+
+#[test]
+fn test_non_table_aliases_rejected() {
+    let fixture = TestFixture::new();
+    fixture.create_plugin("invalid", INVALID_ALIASES_PLUGIN);
+
+    validate_plugin(&fixture, "invalid")
+        .failure()
+        .stderr(predicate::str::contains(
+            "aliases field must be a table of strings, got string",
+        ));
+}
@@ -0,0 +1,185 @@
+//! Integration tests for workspace `.syntropy.toml` configs
+//!
+//! A workspace config is found in the current or a parent directory and merged on top of
+//! the user's config, limited to plugin paths and styles.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::{fs, path::PathBuf};
+
+use crate::common::TestFixture;
+use syntropy::configs::{find_workspace_config_file, load_config_with_workspace};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+const WORKSPACE_PLUGIN: &str = r#"
+return {
+    metadata = {name = "workspace-plugin", version = "1.0.0", icon = "W", platforms = {"macos", "linux"}},
+    tasks = {
+        hello = {
+            description = "Workspace task",
+            execute = function() return "hello from the workspace", 0 end,
+        },
+    },
+}
+"#;
+
+// Creates `<temp>/workspace/.syntropy.toml` with a `plugins/workspace-plugin` next to it and
+// returns the workspace directory
+fn create_workspace(fixture: &TestFixture, workspace_config: &str) -> PathBuf {
+    let workspace = fixture.temp_dir.path().join("workspace");
+    let plugin_dir = workspace.join("plugins").join("workspace-plugin");
+    fs::create_dir_all(&plugin_dir).unwrap();
+    fs::create_dir_all(workspace.join("nested").join("deeper")).unwrap();
+    fs::write(plugin_dir.join("plugin.lua"), WORKSPACE_PLUGIN).unwrap();
+    fs::write(workspace.join(".syntropy.toml"), workspace_config).unwrap();
+    workspace
+}
+
+fn syntropy(fixture: &TestFixture, current_dir: PathBuf) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .current_dir(current_dir);
+    cmd
+}
+
+// ============================================================================
+// Discovery
+// ============================================================================
+
+#[test]
+fn workspace_config_found_in_parent_directory() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "");
+
+    assert_eq!(
+        find_workspace_config_file(&workspace.join("nested").join("deeper")),
+        Some(workspace.join(".syntropy.toml"))
+    );
+}
+
+#[test]
+fn workspace_plugin_paths_load_plugins_from_subdirectory() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "plugin_paths = [\"plugins\"]\n");
+
+    syntropy(&fixture, workspace.join("nested").join("deeper"))
+        .args(["execute", "--plugin", "workspace-plugin", "--task", "hello"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello from the workspace"));
+}
+
+#[test]
+fn no_workspace_config_flag_skips_workspace_config() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "plugin_paths = [\"plugins\"]\n");
+
+    syntropy(&fixture, workspace)
+        .args(["--no-workspace-config", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("workspace-plugin").not());
+}
+
+// ============================================================================
+// Merging
+// ============================================================================
+
+#[test]
+fn workspace_config_overrides_styles_and_keeps_keybindings() {
+    let fixture = TestFixture::new();
+    fixture.create_config(
+        "syntropy.toml",
+        r#"
+[keybindings]
+back = "<C-q>"
+
+[styles.list]
+highlight_symbol = "*"
+icon_marked = "x"
+"#,
+    );
+    let workspace = create_workspace(
+        &fixture,
+        r#"
+[styles.list]
+highlight_symbol = ">"
+"#,
+    );
+
+    let config = load_config_with_workspace(
+        Some(&fixture.config_path().join("syntropy/syntropy.toml")),
+        &workspace.join(".syntropy.toml"),
+    )
+    .unwrap();
+
+    assert_eq!(config.styles.list.highlight_symbol, ">");
+    assert_eq!(config.styles.list.icon_marked, "x");
+    assert_eq!(config.keybindings.back, "<C-q>");
+}
+
+#[test]
+fn workspace_plugin_paths_are_added_to_global_ones() {
+    let fixture = TestFixture::new();
+    fixture.create_config("syntropy.toml", "plugin_paths = [\"/opt/plugins\"]\n");
+    let workspace = create_workspace(&fixture, "plugin_paths = [\"plugins\"]\n");
+
+    let config = load_config_with_workspace(
+        Some(&fixture.config_path().join("syntropy/syntropy.toml")),
+        &workspace.join(".syntropy.toml"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.plugin_paths,
+        vec![PathBuf::from("/opt/plugins"), workspace.join("plugins")]
+    );
+}
+
+#[test]
+fn workspace_config_without_user_config_uses_defaults() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "[styles.search_bar]\nsearch_hint = \"?\"\n");
+
+    let config = load_config_with_workspace(None, &workspace.join(".syntropy.toml")).unwrap();
+
+    assert_eq!(config.styles.search_bar.search_hint, "?");
+    assert!(config.status_bar);
+}
+
+// ============================================================================
+// Restricted Keys
+// ============================================================================
+
+#[test]
+fn workspace_config_cannot_change_keybindings() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "[keybindings]\nconfirm = \"q\"\n");
+
+    syntropy(&fixture, workspace)
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'keybindings' can't be set in workspace config",
+        ));
+}
+
+#[test]
+fn workspace_include_cannot_change_restricted_keys() {
+    let fixture = TestFixture::new();
+    let workspace = create_workspace(&fixture, "include = [\"editor.toml\"]\n");
+    fs::write(workspace.join("editor.toml"), "editor = \"evil\"\n").unwrap();
+
+    let error = load_config_with_workspace(None, &workspace.join(".syntropy.toml")).unwrap_err();
+
+    assert!(
+        format!("{:#}", error).contains("'editor' can't be set"),
+        "error: {:#}",
+        error
+    );
+}
@@ -0,0 +1,102 @@
+//! Integration tests for the system config directories in XDG_CONFIG_DIRS
+//!
+//! Tests that a config and plugins deployed to a system directory are found when the user
+//! has none, and that the user's config directory takes precedence.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::{fs, path::PathBuf};
+
+use crate::common::TestFixture;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+const SYSTEM_PLUGIN: &str = r#"
+return {
+    metadata = {name = "site", version = "1.0.0", icon = "S"},
+    tasks = {
+        greet = {
+            description = "Greets with the configured greeting",
+            execute = function()
+                return syntropy.config().greeting or "no greeting", 0
+            end,
+        },
+    },
+}
+"#;
+
+// Creates `<temp>/xdg/syntropy` with the site plugin and a config setting its greeting,
+// returns `<temp>/xdg`
+fn create_system_dir(fixture: &TestFixture, greeting: &str) -> PathBuf {
+    let system_dir = fixture.temp_dir.path().join("xdg");
+    let syntropy_dir = system_dir.join("syntropy");
+    fs::create_dir_all(syntropy_dir.join("plugins").join("site")).unwrap();
+    fs::write(
+        syntropy_dir.join("plugins").join("site").join("plugin.lua"),
+        SYSTEM_PLUGIN,
+    )
+    .unwrap();
+    fs::write(
+        syntropy_dir.join("syntropy.toml"),
+        format!("[plugin_config.site]\ngreeting = \"{}\"\n", greeting),
+    )
+    .unwrap();
+    system_dir
+}
+
+fn syntropy(fixture: &TestFixture, system_dirs: &str) -> Command {
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("syntropy"));
+    cmd.env("XDG_DATA_HOME", fixture.data_path())
+        .env("XDG_CONFIG_HOME", fixture.config_path())
+        .env("XDG_CONFIG_DIRS", system_dirs)
+        .current_dir(fixture.temp_dir.path());
+    cmd
+}
+
+// ============================================================================
+// System config and plugins
+// ============================================================================
+
+#[test]
+fn system_config_and_plugins_are_used_without_user_config() {
+    let fixture = TestFixture::new();
+    let system_dir = create_system_dir(&fixture, "hello from the system");
+
+    syntropy(&fixture, system_dir.to_str().unwrap())
+        .args(["execute", "--plugin", "site", "--task", "greet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello from the system"));
+}
+
+#[test]
+fn earlier_system_dir_takes_precedence() {
+    let fixture = TestFixture::new();
+    let system_dir = create_system_dir(&fixture, "hello from the system");
+    let missing = fixture.temp_dir.path().join("missing");
+    let dirs = format!("{}:{}", missing.display(), system_dir.display());
+
+    syntropy(&fixture, &dirs)
+        .args(["execute", "--plugin", "site", "--task", "greet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello from the system"));
+}
+
+#[test]
+fn user_config_takes_precedence_over_system_config() {
+    let fixture = TestFixture::new();
+    let system_dir = create_system_dir(&fixture, "hello from the system");
+    fixture.create_config(
+        "syntropy.toml",
+        "[plugin_config.site]\ngreeting = \"hello from the user\"\n",
+    );
+
+    syntropy(&fixture, system_dir.to_str().unwrap())
+        .args(["execute", "--plugin", "site", "--task", "greet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hello from the user"));
+}
@@ -0,0 +1,263 @@
+//! Unit tests for App and Plugin traversal helpers
+//!
+//! Tests plugins_iter(), tasks_iter(), Plugin::tasks_sorted() and Plugin::tasks_grouped()
+//! ordering and plugin/task associations.
+
+use std::{collections::HashMap, sync::Arc};
+
+use mlua::Lua;
+use syntropy::{
+    App, Config,
+    plugins::{Dedup, DefaultSelect, Metadata, Mode, Plugin, Task},
+};
+use tokio::sync::Mutex;
+
+fn task(plugin_name: &str, task_key: &str) -> Arc<Task> {
+    ordered_task(plugin_name, task_key, task_key, None, None)
+}
+
+fn ordered_task(
+    plugin_name: &str,
+    task_key: &str,
+    name: &str,
+    order: Option<i64>,
+    group: Option<&str>,
+) -> Arc<Task> {
+    Arc::new(Task {
+        plugin_name: plugin_name.to_string(),
+        task_key: task_key.to_string(),
+        name: name.to_string(),
+        description: String::new(),
+        icon: String::new(),
+        item_sources: None,
+        mode: Mode::Single,
+        deprecated_mode_none: false,
+        preview_polling_interval: 0,
+        item_polling_interval: 0,
+        execution_confirmation_message: None,
+        suppress_success_notification: false,
+        continue_on_error: true,
+        dedup: Dedup::None,
+        default_select: DefaultSelect::Preselected,
+        order,
+        group: group.map(str::to_string),
+        aliases: Vec::new(),
+    })
+}
+
+fn plugin(name: &str, task_keys: &[&str]) -> Plugin {
+    Plugin {
+        metadata: Metadata {
+            name: name.to_string(),
+            ..Default::default()
+        },
+        tasks: task_keys
+            .iter()
+            .map(|key| (key.to_string(), task(name, key)))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn two_plugin_app() -> App {
+    App::new(
+        Config::default(),
+        vec![
+            plugin("zeta", &["deploy", "Build", "clean"]),
+            plugin("alpha", &["test", "lint"]),
+        ],
+        Arc::new(Mutex::new(Lua::new())),
+    )
+}
+
+// ============================================================================
+// plugins_iter() Tests
+// ============================================================================
+
+#[test]
+fn test_plugins_iter_yields_plugins_in_load_order() {
+    let app = two_plugin_app();
+
+    let names: Vec<&str> = app
+        .plugins_iter()
+        .map(|p| p.metadata.name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["zeta", "alpha"]);
+}
+
+#[test]
+fn test_plugins_iter_empty_app() {
+    let app = App::new(Config::default(), vec![], Arc::new(Mutex::new(Lua::new())));
+
+    assert_eq!(app.plugins_iter().count(), 0);
+    assert_eq!(app.tasks_iter().count(), 0);
+}
+
+// ============================================================================
+// Plugin::tasks_sorted() Tests
+// ============================================================================
+
+#[test]
+fn test_tasks_sorted_is_case_insensitive() {
+    let plugin = plugin("zeta", &["deploy", "Build", "clean"]);
+
+    let keys: Vec<&str> = plugin.tasks_sorted().map(|(key, _)| key).collect();
+
+    assert_eq!(keys, vec!["Build", "clean", "deploy"]);
+}
+
+#[test]
+fn test_tasks_sorted_breaks_case_ties_deterministically() {
+    let plugin = plugin("p", &["run", "Run", "RUN"]);
+
+    let keys: Vec<&str> = plugin.tasks_sorted().map(|(key, _)| key).collect();
+
+    assert_eq!(keys, vec!["RUN", "Run", "run"]);
+}
+
+/// Fixed task set: `(task_key, name, order, group)`
+fn ordered_plugin() -> Plugin {
+    let tasks = [
+        ("zz_build", "Build", Some(2), Some("dev")),
+        ("deploy", "Deploy", Some(1), Some("ops")),
+        ("lint", "lint", None, Some("dev")),
+        ("check", "Check", Some(1), Some("dev")),
+        ("help", "Help", None, None),
+        ("about", "About", Some(5), None),
+    ];
+    Plugin {
+        metadata: Metadata {
+            name: "ordered".to_string(),
+            ..Default::default()
+        },
+        tasks: tasks
+            .iter()
+            .map(|(key, name, order, group)| {
+                (
+                    key.to_string(),
+                    ordered_task("ordered", key, name, *order, *group),
+                )
+            })
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+#[test]
+fn test_tasks_sorted_by_order_then_key() {
+    let plugin = ordered_plugin();
+
+    let keys: Vec<&str> = plugin.tasks_sorted().map(|(key, _)| key).collect();
+
+    assert_eq!(
+        keys,
+        vec!["check", "deploy", "zz_build", "about", "help", "lint"]
+    );
+}
+
+#[test]
+fn test_tasks_sorted_uses_displayed_key_not_name() {
+    let plugin = Plugin {
+        metadata: Metadata::default(),
+        tasks: [
+            ("a_key", ordered_task("p", "a_key", "Zulu", None, None)),
+            ("z_key", ordered_task("p", "z_key", "Alpha", None, None)),
+        ]
+        .into_iter()
+        .map(|(key, task)| (key.to_string(), task))
+        .collect(),
+    };
+
+    let keys: Vec<&str> = plugin.tasks_sorted().map(|(key, _)| key).collect();
+
+    assert_eq!(keys, vec!["a_key", "z_key"]);
+}
+
+// ============================================================================
+// Plugin::tasks_grouped() Tests
+// ============================================================================
+
+#[test]
+fn test_tasks_grouped_puts_ungrouped_first() {
+    let plugin = ordered_plugin();
+
+    let groups: Vec<(Option<&str>, Vec<&str>)> = plugin
+        .tasks_grouped()
+        .into_iter()
+        .map(|(group, tasks)| (group, tasks.into_iter().map(|(key, _)| key).collect()))
+        .collect();
+
+    assert_eq!(
+        groups,
+        vec![
+            (None, vec!["about", "help"]),
+            (Some("dev"), vec!["check", "zz_build", "lint"]),
+            (Some("ops"), vec!["deploy"]),
+        ]
+    );
+}
+
+#[test]
+fn test_tasks_grouped_without_groups_is_single_unnamed_group() {
+    let plugin = plugin("zeta", &["deploy", "Build", "clean"]);
+
+    let groups = plugin.tasks_grouped();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].0, None);
+    assert_eq!(
+        groups[0].1.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+        vec!["Build", "clean", "deploy"]
+    );
+}
+
+#[test]
+fn test_tasks_grouped_empty_plugin() {
+    let plugin = plugin("empty", &[]);
+
+    assert!(plugin.tasks_grouped().is_empty());
+}
+
+// ============================================================================
+// tasks_iter() Tests
+// ============================================================================
+
+#[test]
+fn test_tasks_iter_yields_all_tasks_in_deterministic_order() {
+    let app = two_plugin_app();
+
+    let entries: Vec<(&str, &str)> = app
+        .tasks_iter()
+        .map(|(plugin_name, task_key, _)| (plugin_name, task_key))
+        .collect();
+
+    assert_eq!(
+        entries,
+        vec![
+            ("zeta", "Build"),
+            ("zeta", "clean"),
+            ("zeta", "deploy"),
+            ("alpha", "lint"),
+            ("alpha", "test"),
+        ]
+    );
+}
+
+#[test]
+fn test_tasks_iter_associates_tasks_with_their_plugin() {
+    let app = two_plugin_app();
+
+    for (plugin_name, task_key, task) in app.tasks_iter() {
+        assert_eq!(task.plugin_name, plugin_name);
+        assert_eq!(task.task_key, task_key);
+    }
+}
+
+#[test]
+fn test_tasks_iter_is_stable_across_calls() {
+    let app = two_plugin_app();
+
+    let first: Vec<(&str, &str)> = app.tasks_iter().map(|(p, t, _)| (p, t)).collect();
+    let second: Vec<(&str, &str)> = app.tasks_iter().map(|(p, t, _)| (p, t)).collect();
+
+    assert_eq!(first, second);
+}
@@ -0,0 +1,106 @@
+//! Unit tests for the `border_style` of the screen scaffold, the preview and modals
+//!
+//! Tests that each style maps to the expected border characters and that the scaffold's
+//! style reaches the list, search bar and status bar.
+
+use syntropy::{configs::Styles, tui::views::style};
+
+fn styles(toml: &str) -> style::Styles {
+    let styles: Styles = toml::from_str(toml).unwrap();
+    style::Styles::try_from(&styles).unwrap()
+}
+
+fn corners(border_set: &style::BorderSet) -> [&str; 4] {
+    let set = border_set.as_set();
+    [
+        set.top_left,
+        set.top_right,
+        set.bottom_left,
+        set.bottom_right,
+    ]
+}
+
+// ============================================================================
+// Named Styles
+// ============================================================================
+
+#[test]
+fn test_default_border_style_is_plain() {
+    let styles = styles("");
+
+    assert_eq!(corners(&styles.preview.border_set), ["┌", "┐", "└", "┘"]);
+    assert_eq!(corners(&styles.modal.border_set), ["┌", "┐", "└", "┘"]);
+    assert_eq!(corners(&styles.list.border_set), ["┌", "┐", "└", "┘"]);
+}
+
+#[test]
+fn test_named_border_styles() {
+    for (name, expected) in [
+        ("rounded", ["╭", "╮", "╰", "╯"]),
+        ("double", ["╔", "╗", "╚", "╝"]),
+        ("thick", ["┏", "┓", "┗", "┛"]),
+        ("ascii", ["+", "+", "+", "+"]),
+        ("none", [" ", " ", " ", " "]),
+    ] {
+        let styles = styles(&format!("[preview]\nborder_style = \"{}\"", name));
+        assert_eq!(corners(&styles.preview.border_set), expected, "{}", name);
+    }
+}
+
+#[test]
+fn test_ascii_border_lines() {
+    let styles = styles("[modal]\nborder_style = \"ascii\"");
+    let set = styles.modal.border_set.as_set();
+
+    assert_eq!(set.horizontal_top, "-");
+    assert_eq!(set.horizontal_bottom, "-");
+    assert_eq!(set.vertical_left, "|");
+    assert_eq!(set.vertical_right, "|");
+}
+
+#[test]
+fn test_unknown_border_style_is_rejected() {
+    assert!(toml::from_str::<Styles>("[modal]\nborder_style = \"dotted\"").is_err());
+}
+
+// ============================================================================
+// Custom Style
+// ============================================================================
+
+#[test]
+fn test_custom_border_style_falls_back_to_plain() {
+    let styles = styles(
+        r#"
+[modal]
+border_style = { custom = { top_left = "*", bottom_right = "*" } }
+"#,
+    );
+
+    assert_eq!(corners(&styles.modal.border_set), ["*", "┐", "└", "*"]);
+    assert_eq!(styles.modal.border_set.as_set().horizontal_top, "─");
+}
+
+#[test]
+fn test_custom_border_style_rejects_unknown_characters() {
+    assert!(
+        toml::from_str::<Styles>("[modal]\nborder_style = { custom = { middle = \"*\" } }")
+            .is_err()
+    );
+}
+
+// ============================================================================
+// Screen Scaffold
+// ============================================================================
+
+#[test]
+fn test_screen_scaffold_style_applies_to_main_panes() {
+    let styles = styles("[screen_scaffold]\nborder_style = \"rounded\"");
+
+    assert_eq!(corners(&styles.list.border_set), ["╭", "╮", "╰", "╯"]);
+    assert_eq!(
+        corners(&styles.search_bar_style.border_set),
+        ["╭", "╮", "╰", "╯"]
+    );
+    assert_eq!(corners(&styles.status.border_set), ["╭", "╮", "╰", "╯"]);
+    assert_eq!(corners(&styles.preview.border_set), ["┌", "┐", "└", "┘"]);
+}
@@ -0,0 +1,99 @@
+//! Unit tests for terminal color support detection
+//!
+//! Tests how `NO_COLOR`, `TERM` and `force_color` pick the color mode, and how each mode
+//! degrades the configured colors.
+
+use ratatui::style::Color;
+use syntropy::configs::style::colors::Colors;
+use syntropy::tui::color_mode::{ColorMode, detect_color_mode};
+use syntropy::tui::views::style::colors::ColorStyle;
+
+fn color_style() -> ColorStyle {
+    let colors = Colors {
+        borders: "cyan".to_string(),
+        text: "#ff8800".to_string(),
+        ..Default::default()
+    };
+    ColorStyle::try_from(&colors).unwrap()
+}
+
+// ============================================================================
+// Detection Tests
+// ============================================================================
+
+#[test]
+fn test_no_color_disables_colors() {
+    let mode = detect_color_mode(Some("1"), Some("xterm-256color"), false);
+
+    assert_eq!(mode, ColorMode::Monochrome);
+}
+
+#[test]
+fn test_empty_no_color_is_ignored() {
+    let mode = detect_color_mode(Some(""), Some("xterm-256color"), false);
+
+    assert_eq!(mode, ColorMode::TrueColor);
+}
+
+#[test]
+fn test_dumb_terminal_disables_colors() {
+    let mode = detect_color_mode(None, Some("dumb"), false);
+
+    assert_eq!(mode, ColorMode::Monochrome);
+}
+
+#[test]
+fn test_linux_console_gets_basic_colors() {
+    let mode = detect_color_mode(None, Some("linux"), false);
+
+    assert_eq!(mode, ColorMode::Basic);
+}
+
+#[test]
+fn test_normal_terminal_gets_all_colors() {
+    assert_eq!(
+        detect_color_mode(None, Some("xterm-256color"), false),
+        ColorMode::TrueColor
+    );
+    assert_eq!(detect_color_mode(None, None, false), ColorMode::TrueColor);
+}
+
+#[test]
+fn test_force_color_overrides_detection() {
+    assert_eq!(
+        detect_color_mode(Some("1"), Some("dumb"), true),
+        ColorMode::TrueColor
+    );
+}
+
+// ============================================================================
+// Degradation Tests
+// ============================================================================
+
+#[test]
+fn test_true_color_keeps_colors() {
+    let style = color_style().with_color_mode(ColorMode::TrueColor);
+
+    assert_eq!(style.borders, Color::Cyan);
+    assert_eq!(style.text, Color::Rgb(0xff, 0x88, 0x00));
+    assert_eq!(style.error, Color::Red);
+}
+
+#[test]
+fn test_basic_drops_rgb_colors() {
+    let style = color_style().with_color_mode(ColorMode::Basic);
+
+    assert_eq!(style.borders, Color::Cyan);
+    assert_eq!(style.text, Color::Reset);
+    assert_eq!(style.text_list, Color::Reset);
+}
+
+#[test]
+fn test_monochrome_drops_all_colors() {
+    let style = color_style().with_color_mode(ColorMode::Monochrome);
+
+    assert_eq!(style.borders, Color::Reset);
+    assert_eq!(style.borders_list, Color::Reset);
+    assert_eq!(style.text, Color::Reset);
+    assert_eq!(style.error, Color::Reset);
+}
@@ -665,6 +665,8 @@ fn test_all_fields_terminal_keyword() {
     let colors = Colors {
         highlights_text: "terminal".to_string(),
         highlights_background: "terminal".to_string(),
+        search_highlight: "terminal".to_string(),
+        tag: "terminal".to_string(),
         borders: "terminal".to_string(),
         borders_list: "terminal".to_string(),
         borders_preview: "terminal".to_string(),
@@ -690,6 +692,8 @@ fn test_all_fields_terminal_keyword() {
     // Everything should be Color::Reset
     assert_eq!(style.highlights_text, Color::Reset);
     assert_eq!(style.highlights_background, Color::Reset);
+    assert_eq!(style.search_highlight, Color::Reset);
+    assert_eq!(style.tag, Color::Reset);
     assert_eq!(style.borders, Color::Reset);
     assert_eq!(style.borders_list, Color::Reset);
     assert_eq!(style.borders_preview, Color::Reset);
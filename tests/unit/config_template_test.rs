@@ -0,0 +1,41 @@
+//! Unit tests for the config template written by `syntropy config init`
+//!
+//! The template must stay valid and in sync with the built-in defaults.
+
+use syntropy::cli::config::DEFAULT_CONFIG_TEMPLATE;
+use syntropy::configs::{Config, validate_config};
+
+// ============================================================================
+// Template Tests
+// ============================================================================
+
+#[test]
+fn test_template_parses_and_validates() {
+    let config: Config = toml::from_str(DEFAULT_CONFIG_TEMPLATE).unwrap();
+
+    validate_config(&config).unwrap();
+}
+
+#[test]
+fn test_template_matches_defaults() {
+    let config: Config = toml::from_str(DEFAULT_CONFIG_TEMPLATE).unwrap();
+
+    assert_eq!(
+        toml::Value::try_from(&config).unwrap(),
+        toml::Value::try_from(Config::default()).unwrap(),
+        "template values drifted from Config::default()"
+    );
+}
+
+#[test]
+fn test_template_lists_every_keybinding() {
+    let config: Config = toml::from_str(DEFAULT_CONFIG_TEMPLATE).unwrap();
+
+    for (action, _) in config.keybindings.actions() {
+        assert!(
+            DEFAULT_CONFIG_TEMPLATE.contains(&format!("\n{} = ", action)),
+            "template is missing keybinding '{}'",
+            action
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Unit tests for the TUI item context menu
+//!
+//! Tests placement, hit testing and keyboard navigation of the right click menu.
+
+use ratatui::layout::Rect;
+
+use syntropy::tui::views::{ContextMenu, ContextMenuAction};
+
+const BOUNDS: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 80,
+    height: 24,
+};
+
+// ============================================================================
+// Open / Close Tests
+// ============================================================================
+
+#[test]
+fn test_context_menu_closed_by_default() {
+    let menu = ContextMenu::default();
+
+    assert!(!menu.is_open());
+    assert_eq!(menu.action_at(0, 0), None);
+}
+
+#[test]
+fn test_context_menu_open_and_close() {
+    let mut menu = ContextMenu::default();
+
+    menu.open(10, 5, BOUNDS);
+    assert!(menu.is_open());
+
+    menu.close();
+    assert!(!menu.is_open());
+}
+
+// ============================================================================
+// Hit Testing Tests
+// ============================================================================
+
+#[test]
+fn test_action_at_maps_rows_to_entries() {
+    let mut menu = ContextMenu::default();
+    menu.open(10, 5, BOUNDS);
+
+    // Border occupies the clicked row and column, entries start one cell inside
+    assert_eq!(menu.action_at(11, 6), Some(ContextMenuAction::Preview));
+    assert_eq!(menu.action_at(11, 7), Some(ContextMenuAction::Select));
+}
+
+#[test]
+fn test_action_at_outside_entries_is_none() {
+    let mut menu = ContextMenu::default();
+    menu.open(10, 5, BOUNDS);
+
+    assert_eq!(menu.action_at(10, 5), None, "border cell");
+    assert_eq!(menu.action_at(11, 8), None, "bottom border");
+    assert_eq!(menu.action_at(50, 20), None, "outside the menu");
+}
+
+#[test]
+fn test_menu_is_shifted_to_fit_inside_bounds() {
+    let mut menu = ContextMenu::default();
+    menu.open(79, 23, BOUNDS);
+
+    // Opened in the bottom right corner, the menu moves up and left so entries stay visible
+    assert_eq!(menu.action_at(78, 21), Some(ContextMenuAction::Preview));
+    assert_eq!(menu.action_at(78, 22), Some(ContextMenuAction::Select));
+    assert_eq!(menu.action_at(79, 22), None, "right border");
+}
+
+// ============================================================================
+// Keyboard Navigation Tests
+// ============================================================================
+
+#[test]
+fn test_open_selects_first_action() {
+    let mut menu = ContextMenu::default();
+    menu.open(10, 5, BOUNDS);
+    menu.select_next();
+
+    menu.open(10, 5, BOUNDS);
+
+    assert_eq!(menu.selected_action(), ContextMenuAction::Preview);
+}
+
+#[test]
+fn test_selection_wraps_around() {
+    let mut menu = ContextMenu::default();
+    menu.open(10, 5, BOUNDS);
+
+    menu.select_previous();
+    assert_eq!(menu.selected_action(), ContextMenuAction::Select);
+
+    menu.select_next();
+    assert_eq!(menu.selected_action(), ContextMenuAction::Preview);
+}
@@ -0,0 +1,115 @@
+use syntropy::lua::editor::{DEFAULT_EDITOR, EditorSettings, resolve_editor};
+
+fn settings(editor: Option<&str>, fallbacks: &[&str], overrides_env: bool) -> EditorSettings {
+    EditorSettings {
+        editor: editor.map(String::from),
+        fallbacks: fallbacks.iter().map(|f| f.to_string()).collect(),
+        overrides_env,
+    }
+}
+
+fn env(value: &str) -> Option<String> {
+    Some(value.to_string())
+}
+
+fn all_available(_: &str) -> bool {
+    true
+}
+
+// ============================================================================
+// Environment first (default)
+// ============================================================================
+
+#[test]
+fn test_editor_env_wins_over_config() {
+    let settings = settings(Some("hx"), &["nano"], false);
+
+    let editor = resolve_editor(env("nvim"), env("code"), &settings, all_available);
+
+    assert_eq!(editor, "nvim");
+}
+
+#[test]
+fn test_visual_env_used_when_editor_unset() {
+    let settings = settings(Some("hx"), &[], false);
+
+    let editor = resolve_editor(None, env("code"), &settings, all_available);
+
+    assert_eq!(editor, "code");
+}
+
+#[test]
+fn test_config_editor_used_when_env_unset() {
+    let settings = settings(Some("hx"), &["nano"], false);
+
+    let editor = resolve_editor(None, None, &settings, all_available);
+
+    assert_eq!(editor, "hx");
+}
+
+#[test]
+fn test_unavailable_config_editor_falls_back_in_order() {
+    let settings = settings(Some("hx"), &["kak", "nano", "micro"], false);
+
+    let editor = resolve_editor(None, None, &settings, |editor| {
+        editor == "nano" || editor == "micro"
+    });
+
+    assert_eq!(editor, "nano");
+}
+
+#[test]
+fn test_fallbacks_used_without_config_editor() {
+    let settings = settings(None, &["nano"], false);
+
+    let editor = resolve_editor(None, None, &settings, all_available);
+
+    assert_eq!(editor, "nano");
+}
+
+#[test]
+fn test_default_editor_when_nothing_available() {
+    let settings = settings(Some("hx"), &["nano"], false);
+
+    let editor = resolve_editor(None, None, &settings, |_| false);
+
+    assert_eq!(editor, DEFAULT_EDITOR);
+}
+
+#[test]
+fn test_default_editor_with_default_settings() {
+    let editor = resolve_editor(None, None, &EditorSettings::default(), all_available);
+
+    assert_eq!(editor, "vim");
+}
+
+// ============================================================================
+// editor_overrides_env
+// ============================================================================
+
+#[test]
+fn test_overrides_env_prefers_config_editor() {
+    let settings = settings(Some("hx"), &[], true);
+
+    let editor = resolve_editor(env("nvim"), env("code"), &settings, all_available);
+
+    assert_eq!(editor, "hx");
+}
+
+#[test]
+fn test_overrides_env_prefers_available_fallback() {
+    let settings = settings(Some("hx"), &["nano"], true);
+
+    let editor = resolve_editor(env("nvim"), None, &settings, |editor| editor == "nano");
+
+    assert_eq!(editor, "nano");
+}
+
+#[test]
+fn test_overrides_env_falls_back_to_env_when_config_unavailable() {
+    let settings = settings(Some("hx"), &["nano"], true);
+
+    let editor = resolve_editor(None, env("code"), &settings, |_| false);
+
+    assert_eq!(editor, "code");
+}
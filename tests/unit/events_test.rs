@@ -3,23 +3,51 @@
 //! Tests the pure function that maps crossterm KeyEvents to InputEvents.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use syntropy::tui::events::{InputEvent, handle_key};
-use syntropy::tui::key_bindings::{KeyBind, ParsedKeyBindings};
+use syntropy::configs::KeyBindings;
+use syntropy::tui::events::{InputEvent, handle_key, handle_yes_no_key};
+use syntropy::tui::key_bindings::ParsedKeyBindings;
 
 // Helper to create test bindings with default configuration
-fn create_test_bindings() -> ParsedKeyBindings {
-    ParsedKeyBindings {
-        back: KeyBind::parse("<esc>").unwrap(),
-        select_previous: KeyBind::parse("<up>").unwrap(),
-        select_next: KeyBind::parse("<down>").unwrap(),
-        scroll_preview_up: KeyBind::parse("<C-u>").unwrap(),
-        scroll_preview_down: KeyBind::parse("<C-d>").unwrap(),
-        toggle_preview: KeyBind::parse("p").unwrap(),
-        confirm: KeyBind::parse("<enter>").unwrap(),
-        select: KeyBind::parse("<tab>").unwrap(),
+fn create_test_bindings() -> KeyBindings {
+    KeyBindings {
+        back: "<esc>".into(),
+        select_previous: "<up>".into(),
+        select_next: "<down>".into(),
+        page_up: "<pageup>".into(),
+        page_down: "<pagedown>".into(),
+        history_previous: "<A-up>".into(),
+        history_next: "<A-down>".into(),
+        scroll_preview_up: "<C-u>".into(),
+        scroll_preview_down: "<C-d>".into(),
+        scroll_preview_left: "<A-left>".into(),
+        scroll_preview_right: "<A-right>".into(),
+        toggle_preview: "p".into(),
+        toggle_preview_wrap: "<A-w>".into(),
+        confirm: "<enter>".into(),
+        select: "<tab>".into(),
+        jobs: "<C-j>".into(),
+        kill_job: "<C-k>".into(),
+        restart_job: "<A-r>".into(),
+        sort_items: "<C-s>".into(),
+        dump_plan: "<C-w>".into(),
+        go_home: "<C-home>".into(),
+        toggle_search_mode: "<C-f>".into(),
+        toggle_case_sensitive: "<C-t>".into(),
+        copy: "<C-y>".into(),
+        copy_preview: "<A-y>".into(),
+        select_all: "<C-a>".into(),
+        select_none: "<A-a>".into(),
+        invert_selection: "<A-i>".into(),
+        cycle_plugin_filter: "<A-l>".into(),
+        quick_actions: vec![],
     }
 }
 
+// Parses the config bindings and maps the key event like the TUI does
+fn handle(event: &KeyEvent, bindings: &KeyBindings) -> Option<InputEvent> {
+    handle_key(event, &ParsedKeyBindings::from(bindings).unwrap())
+}
+
 // ============================================================================
 // Basic Input Event Mapping Tests
 // ============================================================================
@@ -28,34 +56,28 @@ fn create_test_bindings() -> ParsedKeyBindings {
 fn test_handle_key_back() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Back));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Back));
 }
 
 #[test]
 fn test_handle_key_previous_item() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Up, KeyModifiers::empty());
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::PreviousItem)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::PreviousItem));
 }
 
 #[test]
 fn test_handle_key_next_item() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Down, KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::NextItem));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::NextItem));
 }
 
 #[test]
 fn test_handle_key_scroll_preview_up() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL);
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::ScrollPreviewUp)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::ScrollPreviewUp));
 }
 
 #[test]
@@ -63,7 +85,7 @@ fn test_handle_key_scroll_preview_down() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
     assert_eq!(
-        handle_key(&event, &bindings),
+        handle(&event, &bindings),
         Some(InputEvent::ScrollPreviewDown)
     );
 }
@@ -72,24 +94,21 @@ fn test_handle_key_scroll_preview_down() {
 fn test_handle_key_toggle_preview() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::empty());
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::TogglePreview)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::TogglePreview));
 }
 
 #[test]
 fn test_handle_key_confirm() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Confirm));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Confirm));
 }
 
 #[test]
 fn test_handle_key_select() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Tab, KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Select));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Select));
 }
 
 // ============================================================================
@@ -100,7 +119,7 @@ fn test_handle_key_select() {
 fn test_handle_key_unknown_returns_none() {
     let bindings = create_test_bindings();
     let event = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), None);
+    assert_eq!(handle(&event, &bindings), None);
 }
 
 #[test]
@@ -108,7 +127,7 @@ fn test_handle_key_modifier_mismatch() {
     let bindings = create_test_bindings();
     // Binding is 'p' (no modifier), event has Ctrl
     let event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
-    assert_eq!(handle_key(&event, &bindings), None);
+    assert_eq!(handle(&event, &bindings), None);
 }
 
 // ============================================================================
@@ -119,23 +138,23 @@ fn test_handle_key_modifier_mismatch() {
 fn test_handle_key_with_custom_bindings() {
     let mut bindings = create_test_bindings();
     // Override back to use 'q' instead of Esc
-    bindings.back = KeyBind::parse("q").unwrap();
+    bindings.back = "q".into();
 
     let event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Back));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Back));
 
     // Esc should no longer trigger Back
     let esc_event = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
-    assert_eq!(handle_key(&esc_event, &bindings), None);
+    assert_eq!(handle(&esc_event, &bindings), None);
 }
 
 #[test]
 fn test_handle_key_with_ctrl_modifier() {
     let mut bindings = create_test_bindings();
-    bindings.confirm = KeyBind::parse("<C-enter>").unwrap();
+    bindings.confirm = "<C-enter>".into();
 
     let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL);
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Confirm));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Confirm));
 }
 
 // ============================================================================
@@ -144,74 +163,249 @@ fn test_handle_key_with_ctrl_modifier() {
 
 #[test]
 fn test_all_input_event_variants_mappable() {
-    // Ensure all 8 InputEvent variants can be returned
-    let bindings = ParsedKeyBindings {
-        back: KeyBind::parse("1").unwrap(),
-        select_previous: KeyBind::parse("2").unwrap(),
-        select_next: KeyBind::parse("3").unwrap(),
-        scroll_preview_up: KeyBind::parse("4").unwrap(),
-        scroll_preview_down: KeyBind::parse("5").unwrap(),
-        toggle_preview: KeyBind::parse("6").unwrap(),
-        select: KeyBind::parse("7").unwrap(),
-        confirm: KeyBind::parse("8").unwrap(),
+    // Ensure all 18 InputEvent variants can be returned
+    let bindings = KeyBindings {
+        back: "1".into(),
+        select_previous: "2".into(),
+        select_next: "3".into(),
+        page_up: "u".into(),
+        page_down: "d".into(),
+        history_previous: "[".into(),
+        history_next: "]".into(),
+        scroll_preview_up: "4".into(),
+        scroll_preview_down: "5".into(),
+        scroll_preview_left: ",".into(),
+        scroll_preview_right: ".".into(),
+        toggle_preview: "6".into(),
+        toggle_preview_wrap: "x".into(),
+        select: "7".into(),
+        confirm: "8".into(),
+        jobs: "9".into(),
+        kill_job: "0".into(),
+        restart_job: "r".into(),
+        sort_items: "s".into(),
+        dump_plan: "w".into(),
+        go_home: "h".into(),
+        toggle_search_mode: "f".into(),
+        toggle_case_sensitive: "c".into(),
+        copy: "y".into(),
+        copy_preview: "p".into(),
+        select_all: "a".into(),
+        select_none: "n".into(),
+        invert_selection: "i".into(),
+        cycle_plugin_filter: "l".into(),
+        quick_actions: vec![],
     };
 
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('1'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::Back)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('2'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::PreviousItem)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('3'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::NextItem)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('4'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::ScrollPreviewUp)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('5'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::ScrollPreviewDown)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('6'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::TogglePreview)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('7'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::Select)
     );
     assert_eq!(
-        handle_key(
+        handle(
             &KeyEvent::new(KeyCode::Char('8'), KeyModifiers::empty()),
             &bindings
         ),
         Some(InputEvent::Confirm)
     );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('9'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::ShowJobs)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('0'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::KillJob)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::RestartJob)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::SortItems)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('w'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::DumpPlan)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('h'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::GoHome)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::ToggleSearchMode)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('c'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::ToggleCaseSensitive)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::Copy)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('p'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::CopyPreview)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::SelectAll)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::SelectNone)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('i'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::InvertSelection)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('l'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::CyclePluginFilter)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('u'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::PageUp)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::PageDown)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('['), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::HistoryPrevious)
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char(']'), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::HistoryNext)
+    );
+}
+
+#[test]
+fn test_default_history_keys_are_alt_arrows() {
+    let bindings = KeyBindings::default();
+
+    assert_eq!(
+        handle(&KeyEvent::new(KeyCode::Up, KeyModifiers::ALT), &bindings),
+        Some(InputEvent::HistoryPrevious)
+    );
+    assert_eq!(
+        handle(&KeyEvent::new(KeyCode::Down, KeyModifiers::ALT), &bindings),
+        Some(InputEvent::HistoryNext)
+    );
+}
+
+#[test]
+fn test_default_go_home_is_ctrl_home() {
+    let bindings = KeyBindings::default();
+
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Home, KeyModifiers::CONTROL),
+            &bindings
+        ),
+        Some(InputEvent::GoHome)
+    );
 }
 
 // ============================================================================
@@ -221,47 +415,54 @@ fn test_all_input_event_variants_mappable() {
 #[test]
 fn test_handle_key_with_function_keys() {
     let mut bindings = create_test_bindings();
-    bindings.back = KeyBind::parse("<F1>").unwrap();
-    bindings.confirm = KeyBind::parse("<F2>").unwrap();
+    bindings.back = "<F1>".into();
+    bindings.confirm = "<F2>".into();
 
     let event = KeyEvent::new(KeyCode::F(1), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Back));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Back));
 
     let event = KeyEvent::new(KeyCode::F(2), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Confirm));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Confirm));
 }
 
 #[test]
 fn test_handle_key_with_home_end_keys() {
     let mut bindings = create_test_bindings();
-    bindings.select_previous = KeyBind::parse("<home>").unwrap();
-    bindings.select_next = KeyBind::parse("<end>").unwrap();
+    bindings.select_previous = "<home>".into();
+    bindings.select_next = "<end>".into();
 
     let event = KeyEvent::new(KeyCode::Home, KeyModifiers::empty());
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::PreviousItem)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::PreviousItem));
 
     let event = KeyEvent::new(KeyCode::End, KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::NextItem));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::NextItem));
 }
 
 #[test]
 fn test_handle_key_with_page_up_down() {
+    let bindings = create_test_bindings();
+
+    let event = KeyEvent::new(KeyCode::PageUp, KeyModifiers::empty());
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::PageUp));
+
+    let event = KeyEvent::new(KeyCode::PageDown, KeyModifiers::empty());
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::PageDown));
+}
+
+#[test]
+fn test_handle_key_with_page_keys_rebound() {
     let mut bindings = create_test_bindings();
-    bindings.scroll_preview_up = KeyBind::parse("<pageup>").unwrap();
-    bindings.scroll_preview_down = KeyBind::parse("<pagedown>").unwrap();
+    bindings.page_up = "<C-b>".into();
+    bindings.page_down = "<C-n>".into();
+    bindings.scroll_preview_up = "<pageup>".into();
+    bindings.scroll_preview_down = "<pagedown>".into();
 
     let event = KeyEvent::new(KeyCode::PageUp, KeyModifiers::empty());
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::ScrollPreviewUp)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::ScrollPreviewUp));
 
     let event = KeyEvent::new(KeyCode::PageDown, KeyModifiers::empty());
     assert_eq!(
-        handle_key(&event, &bindings),
+        handle(&event, &bindings),
         Some(InputEvent::ScrollPreviewDown)
     );
 }
@@ -273,54 +474,81 @@ fn test_handle_key_with_page_up_down() {
 #[test]
 fn test_handle_key_with_shift_modifier() {
     let mut bindings = create_test_bindings();
-    bindings.select_next = KeyBind::parse("<S-j>").unwrap();
+    bindings.select_next = "<S-j>".into();
 
     let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::SHIFT);
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::NextItem));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::NextItem));
 }
 
 #[test]
 fn test_handle_key_with_alt_modifier() {
     let mut bindings = create_test_bindings();
-    bindings.toggle_preview = KeyBind::parse("<A-p>").unwrap();
+    bindings.toggle_preview = "<A-p>".into();
 
     let event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::ALT);
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::TogglePreview)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::TogglePreview));
 }
 
 #[test]
 fn test_handle_key_with_multiple_modifiers() {
     let mut bindings = create_test_bindings();
-    bindings.confirm = KeyBind::parse("<C-S-enter>").unwrap();
+    bindings.confirm = "<C-S-enter>".into();
 
     let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Confirm));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Confirm));
 }
 
 // ============================================================================
-// Binding Priority Tests (First Match Wins)
+// Aliases and Conflicts
 // ============================================================================
 
 #[test]
-fn test_handle_key_first_match_wins() {
-    // If two bindings map to same key, first check wins
-    let bindings = ParsedKeyBindings {
-        back: KeyBind::parse("q").unwrap(),
-        select_previous: KeyBind::parse("<up>").unwrap(),
-        select_next: KeyBind::parse("<down>").unwrap(),
-        scroll_preview_up: KeyBind::parse("<C-u>").unwrap(),
-        scroll_preview_down: KeyBind::parse("<C-d>").unwrap(),
-        toggle_preview: KeyBind::parse("p").unwrap(),
-        confirm: KeyBind::parse("q").unwrap(), // Duplicate of back!
-        select: KeyBind::parse("<tab>").unwrap(),
+fn test_duplicate_key_is_rejected() {
+    // A key maps to a single action, so binding it twice is a config error
+    let bindings = KeyBindings {
+        back: "q".into(),
+        confirm: "q".into(), // Duplicate of back!
+        ..create_test_bindings()
     };
 
-    // 'q' should map to Back (checked first), not Confirm
-    let event = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Back));
+    assert!(ParsedKeyBindings::from(&bindings).is_err());
+}
+
+#[test]
+fn test_handle_key_aliases_trigger_same_action() {
+    let bindings = KeyBindings {
+        select_previous: vec!["<up>", "k"].into(),
+        select_next: vec!["<down>", "j"].into(),
+        ..create_test_bindings()
+    };
+
+    for (code, expected) in [
+        (KeyCode::Up, InputEvent::PreviousItem),
+        (KeyCode::Char('k'), InputEvent::PreviousItem),
+        (KeyCode::Down, InputEvent::NextItem),
+        (KeyCode::Char('j'), InputEvent::NextItem),
+    ] {
+        let event = KeyEvent::new(code, KeyModifiers::empty());
+        assert_eq!(handle(&event, &bindings), Some(expected));
+    }
+}
+
+#[test]
+fn test_alias_conflicting_with_other_action_is_rejected() {
+    let bindings = KeyBindings {
+        select_next: vec!["<down>", "<tab>"].into(),
+        ..create_test_bindings()
+    };
+
+    let error = ParsedKeyBindings::from(&bindings).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("Key '<tab>' is bound to both 'select_next' and 'select'"),
+        "{}",
+        error
+    );
 }
 
 // ============================================================================
@@ -330,41 +558,38 @@ fn test_handle_key_first_match_wins() {
 #[test]
 fn test_handle_key_case_sensitive() {
     let mut bindings = create_test_bindings();
-    bindings.back = KeyBind::parse("q").unwrap();
-    bindings.confirm = KeyBind::parse("Q").unwrap();
+    bindings.back = "q".into();
+    bindings.confirm = "Q".into();
 
     // Lowercase 'q' should map to back
     let event_lower = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event_lower, &bindings), Some(InputEvent::Back));
+    assert_eq!(handle(&event_lower, &bindings), Some(InputEvent::Back));
 
     // Uppercase 'Q' (char 'q' with SHIFT) should map to confirm
     let event_upper = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::SHIFT);
-    assert_eq!(
-        handle_key(&event_upper, &bindings),
-        Some(InputEvent::Confirm)
-    );
+    assert_eq!(handle(&event_upper, &bindings), Some(InputEvent::Confirm));
 }
 
 #[test]
 fn test_handle_key_space_character() {
     let mut bindings = create_test_bindings();
-    bindings.select = KeyBind::parse("<space>").unwrap();
+    bindings.select = "<space>".into();
 
     let event = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Select));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Select));
 }
 
 #[test]
 fn test_handle_key_symbols() {
     let mut bindings = create_test_bindings();
-    bindings.back = KeyBind::parse("?").unwrap();
-    bindings.confirm = KeyBind::parse("/").unwrap();
+    bindings.back = "?".into();
+    bindings.confirm = "/".into();
 
     let event = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Back));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Back));
 
     let event = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::Confirm));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::Confirm));
 }
 
 // ============================================================================
@@ -373,37 +598,145 @@ fn test_handle_key_symbols() {
 
 #[test]
 fn test_handle_key_vim_navigation() {
-    let bindings = ParsedKeyBindings {
-        back: KeyBind::parse("<esc>").unwrap(),
-        select_previous: KeyBind::parse("k").unwrap(),
-        select_next: KeyBind::parse("j").unwrap(),
-        scroll_preview_up: KeyBind::parse("<C-u>").unwrap(),
-        scroll_preview_down: KeyBind::parse("<C-d>").unwrap(),
-        toggle_preview: KeyBind::parse("p").unwrap(),
-        confirm: KeyBind::parse("<enter>").unwrap(),
-        select: KeyBind::parse("<space>").unwrap(),
+    let bindings = KeyBindings {
+        back: "<esc>".into(),
+        select_previous: "k".into(),
+        select_next: "j".into(),
+        page_up: "<pageup>".into(),
+        page_down: "<pagedown>".into(),
+        history_previous: "<A-up>".into(),
+        history_next: "<A-down>".into(),
+        scroll_preview_up: "<C-u>".into(),
+        scroll_preview_down: "<C-d>".into(),
+        scroll_preview_left: "<A-left>".into(),
+        scroll_preview_right: "<A-right>".into(),
+        toggle_preview: "p".into(),
+        toggle_preview_wrap: "<A-w>".into(),
+        confirm: "<enter>".into(),
+        select: "<space>".into(),
+        jobs: "<C-j>".into(),
+        kill_job: "<C-k>".into(),
+        restart_job: "<A-r>".into(),
+        sort_items: "<C-s>".into(),
+        dump_plan: "<C-w>".into(),
+        go_home: "<C-home>".into(),
+        toggle_search_mode: "<C-f>".into(),
+        toggle_case_sensitive: "<C-t>".into(),
+        copy: "<C-y>".into(),
+        copy_preview: "<A-y>".into(),
+        select_all: "<C-a>".into(),
+        select_none: "<A-a>".into(),
+        invert_selection: "<A-i>".into(),
+        cycle_plugin_filter: "<A-l>".into(),
+        quick_actions: vec![],
     };
 
     // Test j/k navigation
     let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty());
-    assert_eq!(handle_key(&event, &bindings), Some(InputEvent::NextItem));
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::NextItem));
 
     let event = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::empty());
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::PreviousItem)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::PreviousItem));
 
     // Test Ctrl-u/Ctrl-d for scrolling
     let event = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL);
-    assert_eq!(
-        handle_key(&event, &bindings),
-        Some(InputEvent::ScrollPreviewUp)
-    );
+    assert_eq!(handle(&event, &bindings), Some(InputEvent::ScrollPreviewUp));
 
     let event = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
     assert_eq!(
-        handle_key(&event, &bindings),
+        handle(&event, &bindings),
         Some(InputEvent::ScrollPreviewDown)
     );
 }
+
+// ============================================================================
+// Quick Action Tests
+// ============================================================================
+
+#[test]
+fn test_quick_action_key_maps_to_its_index() {
+    let bindings: KeyBindings = toml::from_str(
+        r#"
+[[quick_actions]]
+key = "<C-u>"
+plugin = "packages"
+task = "upgrade"
+
+[[quick_actions]]
+key = ["<A-n>", "<F5>"]
+plugin = "notes"
+task = "new"
+items = ["inbox"]
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(bindings.quick_actions[1].plugin, "notes");
+    assert_eq!(bindings.quick_actions[1].items, ["inbox"]);
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+            &bindings
+        ),
+        Some(InputEvent::QuickAction(0))
+    );
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::F(5), KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::QuickAction(1))
+    );
+}
+
+#[test]
+fn test_quick_action_does_not_replace_built_in_bindings() {
+    let bindings: KeyBindings = toml::from_str(
+        r#"
+[[quick_actions]]
+key = "<F2>"
+plugin = "packages"
+task = "upgrade"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        handle(
+            &KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+            &bindings
+        ),
+        Some(InputEvent::Back)
+    );
+}
+
+// ============================================================================
+// Yes/No Dialog Tests
+// ============================================================================
+
+#[test]
+fn test_yes_no_key_answers_in_either_case() {
+    for (code, modifiers, expected) in [
+        (KeyCode::Char('y'), KeyModifiers::NONE, InputEvent::Confirm),
+        (KeyCode::Char('Y'), KeyModifiers::SHIFT, InputEvent::Confirm),
+        (KeyCode::Char('n'), KeyModifiers::NONE, InputEvent::Back),
+        (KeyCode::Char('N'), KeyModifiers::SHIFT, InputEvent::Back),
+    ] {
+        assert_eq!(
+            handle_yes_no_key(&KeyEvent::new(code, modifiers)),
+            Some(expected)
+        );
+    }
+}
+
+#[test]
+fn test_yes_no_key_ignores_other_keys_and_modifiers() {
+    for (code, modifiers) in [
+        (KeyCode::Char('y'), KeyModifiers::CONTROL),
+        (KeyCode::Char('n'), KeyModifiers::ALT),
+        (KeyCode::Char('x'), KeyModifiers::NONE),
+        (KeyCode::Enter, KeyModifiers::NONE),
+    ] {
+        assert_eq!(handle_yes_no_key(&KeyEvent::new(code, modifiers)), None);
+    }
+}
@@ -1,9 +1,15 @@
 //! Unit tests for execute module functionality
 //!
-//! Tests the parse_comma_separated_with_escapes function and ItemMatcher
-//! used for CLI task execution with item selection.
+//! Tests the parse_comma_separated_with_escapes function, ItemMatcher, match_name, did_you_mean,
+//! sort_items and the --env/--env-file parsers used for CLI task execution.
 
-use syntropy::cli::execute::{ItemMatcher, parse_comma_separated_with_escapes};
+use syntropy::cli::{
+    ItemSort, SortKey,
+    execute::{
+        ItemMatcher, did_you_mean, match_name, parse_comma_separated_with_escapes,
+        parse_env_assignment, parse_env_file, sort_items,
+    },
+};
 
 // ============================================================================
 // parse_comma_separated_with_escapes Tests - Basic Functionality
@@ -256,6 +262,64 @@ fn test_parse_unrecognized_escape() {
     assert_eq!(result, vec!["item\\x", "other"]);
 }
 
+// ============================================================================
+// parse_comma_separated_with_escapes Tests - Quoted Segments
+// ============================================================================
+
+#[test]
+fn test_parse_quoted_comma() {
+    let result = parse_comma_separated_with_escapes(r#""backup,2024",other"#);
+    assert_eq!(result, vec!["backup,2024", "other"]);
+}
+
+#[test]
+fn test_parse_quoted_part_of_item() {
+    let result = parse_comma_separated_with_escapes(r#"backup-"2024,01",other"#);
+    assert_eq!(result, vec!["backup-2024,01", "other"]);
+}
+
+#[test]
+fn test_parse_quoted_and_escaped_items_in_one_list() {
+    let result = parse_comma_separated_with_escapes(r#""a,b",c\,d,"e\"f",g"#);
+    assert_eq!(result, vec!["a,b", "c,d", "e\"f", "g"]);
+}
+
+#[test]
+fn test_parse_escaped_comma_inside_quotes() {
+    let result = parse_comma_separated_with_escapes(r#""a\,b",c"#);
+    assert_eq!(result, vec!["a,b", "c"]);
+}
+
+#[test]
+fn test_parse_escaped_quote_outside_quotes() {
+    let result = parse_comma_separated_with_escapes(r#"say \"hi\",other"#);
+    assert_eq!(result, vec![r#"say "hi""#, "other"]);
+}
+
+#[test]
+fn test_parse_escaped_backslash_before_closing_quote() {
+    let result = parse_comma_separated_with_escapes(r#""dir\\",other"#);
+    assert_eq!(result, vec!["dir\\", "other"]);
+}
+
+#[test]
+fn test_parse_quoted_whitespace_is_kept() {
+    let result = parse_comma_separated_with_escapes(r#"  " padded " , b"#);
+    assert_eq!(result, vec![" padded ", "b"]);
+}
+
+#[test]
+fn test_parse_unterminated_quote_runs_to_end() {
+    let result = parse_comma_separated_with_escapes(r#"a,"b,c"#);
+    assert_eq!(result, vec!["a", "b,c"]);
+}
+
+#[test]
+fn test_parse_empty_quotes_are_skipped() {
+    let result = parse_comma_separated_with_escapes(r#"a,"",b"#);
+    assert_eq!(result, vec!["a", "b"]);
+}
+
 // ============================================================================
 // parse_comma_separated_with_escapes Tests - Real-World Scenarios
 // ============================================================================
@@ -497,6 +561,15 @@ fn test_case_insensitive_match_mixed_case() {
     assert_eq!(result, Some("git".to_string()));
 }
 
+#[test]
+fn test_case_sensitive_matcher_skips_case_insensitive_fallback() {
+    let items = create_test_items();
+    let matcher = ItemMatcher::new(&items, false, "test_task").with_case_sensitive(true);
+
+    assert_eq!(matcher.match_item("Node").unwrap(), "Node");
+    assert!(matcher.match_item("GIT").is_err());
+}
+
 // ============================================================================
 // ItemMatcher Tests - match_item Strategy Precedence
 // ============================================================================
@@ -778,3 +851,203 @@ fn test_matcher_case_insensitive_unicode() {
     let result = matcher.match_item("café").unwrap();
     assert_eq!(result, "Café");
 }
+
+// ============================================================================
+// match_name Tests
+// ============================================================================
+
+#[test]
+fn test_match_name_exact_match() {
+    let names = [("deploy", 1), ("build", 2)];
+
+    let matched = match_name("task", "build", names).unwrap();
+
+    assert_eq!(matched, Some(("build", 2)));
+}
+
+#[test]
+fn test_match_name_exact_match_wins_over_case_insensitive() {
+    let names = [("Build", 1), ("build", 2)];
+
+    let matched = match_name("task", "build", names).unwrap();
+
+    assert_eq!(matched, Some(("build", 2)));
+}
+
+#[test]
+fn test_match_name_unique_case_insensitive_match() {
+    let names = [("test-plugin", 1), ("other", 2)];
+
+    let matched = match_name("plugin", "Test-Plugin", names).unwrap();
+
+    assert_eq!(matched, Some(("test-plugin", 1)));
+}
+
+#[test]
+fn test_match_name_ambiguous_case_insensitive_match_errors() {
+    let names = [("Build", 1), ("BUILD", 2)];
+
+    let error = match_name("task", "build", names).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Ambiguous task 'build', it matches case-insensitively: BUILD, Build"
+    );
+}
+
+#[test]
+fn test_match_name_no_match() {
+    let names = [("deploy", 1)];
+
+    assert_eq!(match_name("task", "build", names).unwrap(), None);
+}
+
+// ============================================================================
+// did_you_mean Tests
+// ============================================================================
+
+#[test]
+fn test_did_you_mean_suggests_one_character_typo() {
+    assert_eq!(
+        did_you_mean("pakages", ["packages", "notes"]),
+        "Did you mean 'packages'? "
+    );
+}
+
+#[test]
+fn test_did_you_mean_prefers_closest_name() {
+    assert_eq!(
+        did_you_mean("build", ["builds", "buildx2", "guild"]),
+        "Did you mean 'builds'? "
+    );
+}
+
+#[test]
+fn test_did_you_mean_ignores_distant_names() {
+    assert_eq!(did_you_mean("deploy", ["packages", "notes"]), "");
+}
+
+// ============================================================================
+// sort_items Tests
+// ============================================================================
+
+fn tagged_items() -> Vec<String> {
+    vec![
+        "[pkg] node".to_string(),
+        "[cask] Docker".to_string(),
+        "[pkg] git".to_string(),
+        "[cask] iTerm2".to_string(),
+    ]
+}
+
+#[test]
+fn test_sort_none_keeps_order() {
+    let mut items = tagged_items();
+    sort_items(&mut items, ItemSort::None, SortKey::Item);
+    assert_eq!(items, tagged_items());
+}
+
+#[test]
+fn test_sort_alpha_by_stripped_item() {
+    let mut items = tagged_items();
+    sort_items(&mut items, ItemSort::Alpha, SortKey::Item);
+    assert_eq!(
+        items,
+        vec!["[cask] Docker", "[pkg] git", "[cask] iTerm2", "[pkg] node"]
+    );
+}
+
+#[test]
+fn test_sort_alpha_by_tagged_string() {
+    let mut items = tagged_items();
+    sort_items(&mut items, ItemSort::Alpha, SortKey::Tagged);
+    assert_eq!(
+        items,
+        vec!["[cask] Docker", "[cask] iTerm2", "[pkg] git", "[pkg] node"]
+    );
+}
+
+#[test]
+fn test_sort_alpha_desc_reverses_alpha() {
+    let mut items = tagged_items();
+    sort_items(&mut items, ItemSort::AlphaDesc, SortKey::Item);
+    assert_eq!(
+        items,
+        vec!["[pkg] node", "[cask] iTerm2", "[pkg] git", "[cask] Docker"]
+    );
+}
+
+#[test]
+fn test_sort_alpha_breaks_case_ties_deterministically() {
+    let mut items = vec!["b".to_string(), "B".to_string(), "a".to_string()];
+    sort_items(&mut items, ItemSort::Alpha, SortKey::Item);
+    assert_eq!(items, vec!["a", "B", "b"]);
+}
+
+// ============================================================================
+// parse_env_assignment / parse_env_file Tests
+// ============================================================================
+
+#[test]
+fn test_parse_env_assignment_splits_key_and_value() {
+    let (key, value) = parse_env_assignment("STAGE=prod").unwrap();
+    assert_eq!(key, "STAGE");
+    assert_eq!(value, "prod");
+}
+
+#[test]
+fn test_parse_env_assignment_allows_empty_value() {
+    let (key, value) = parse_env_assignment("STAGE=").unwrap();
+    assert_eq!(key, "STAGE");
+    assert_eq!(value, "");
+}
+
+#[test]
+fn test_parse_env_assignment_requires_exactly_one_equals() {
+    assert!(parse_env_assignment("STAGE").is_err());
+    let error = parse_env_assignment("TOKEN=a=b").unwrap_err();
+    assert!(error.to_string().contains("exactly one '='"));
+}
+
+#[test]
+fn test_parse_env_assignment_rejects_empty_key() {
+    let error = parse_env_assignment("=prod").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Invalid environment variable name")
+    );
+}
+
+#[test]
+fn test_parse_env_file_skips_comments_and_blank_lines() {
+    let variables =
+        parse_env_file("# settings\n\nSTAGE=prod\n  # indented comment\nREGION=eu\n").unwrap();
+    assert_eq!(
+        variables,
+        vec![
+            ("STAGE".to_string(), "prod".to_string()),
+            ("REGION".to_string(), "eu".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_env_file_strips_export_and_quotes() {
+    let variables =
+        parse_env_file("export NAME=\"hello world\"\nSINGLE='quoted'\nTOKEN=a=b==\n").unwrap();
+    assert_eq!(
+        variables,
+        vec![
+            ("NAME".to_string(), "hello world".to_string()),
+            ("SINGLE".to_string(), "quoted".to_string()),
+            ("TOKEN".to_string(), "a=b==".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_env_file_reports_line_of_invalid_entry() {
+    let error = parse_env_file("STAGE=prod\nnot an assignment\n").unwrap_err();
+    assert!(error.to_string().contains("Line 2"));
+}
@@ -0,0 +1,273 @@
+//! Unit tests for ExecutionError
+//!
+//! Tests the messages of every variant, the exit code and JSON kind of each, and that the
+//! variants are found through `anyhow::Error` context and failed Lua calls.
+
+use std::error::Error;
+
+use anyhow::Context;
+use syntropy::{
+    cli::execute::ItemMatcher,
+    execution::{
+        EXIT_FAILURE, EXIT_ITEM_NOT_FOUND, EXIT_LUA_ERROR, EXIT_NO_ITEMS, EXIT_PLUGIN_NOT_FOUND,
+        EXIT_PROCESS_ERROR, EXIT_SUCCESS, EXIT_TASK_NOT_FOUND, ExecutionError,
+    },
+};
+
+// ============================================================================
+// Display
+// ============================================================================
+
+#[test]
+fn test_display_lua_error_shows_lua_message() {
+    let error = ExecutionError::LuaError(mlua::Error::runtime("boom"));
+    assert_eq!(error.to_string(), "runtime error: boom");
+}
+
+#[test]
+fn test_display_process_error() {
+    let error = ExecutionError::ProcessError {
+        command: "git fetch".to_string(),
+        exit_code: 128,
+        stderr: String::new(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Command 'git fetch' failed with exit code 128"
+    );
+}
+
+#[test]
+fn test_display_process_error_appends_stderr() {
+    let error = ExecutionError::ProcessError {
+        command: "git fetch".to_string(),
+        exit_code: 128,
+        stderr: "fatal: no remote".to_string(),
+    };
+    assert_eq!(
+        error.to_string(),
+        "Command 'git fetch' failed with exit code 128: fatal: no remote"
+    );
+}
+
+#[test]
+fn test_display_item_not_found_lists_available_items() {
+    let error = ExecutionError::ItemNotFound {
+        task: "install".to_string(),
+        item: "vim".to_string(),
+        available: vec!["git".to_string(), "node".to_string()],
+    };
+    assert_eq!(
+        error.to_string(),
+        "Item 'vim' not found in task 'install'. Available items:\n  git\n  node"
+    );
+}
+
+#[test]
+fn test_display_plugin_not_found_suggests_close_name() {
+    let error = ExecutionError::PluginNotFound {
+        plugin: "pakages".to_string(),
+        available: vec!["notes".to_string(), "packages".to_string()],
+    };
+    assert_eq!(
+        error.to_string(),
+        "Plugin 'pakages' not found. Did you mean 'packages'? Available plugins: notes, packages"
+    );
+}
+
+#[test]
+fn test_display_task_not_found_names_plugin() {
+    let error = ExecutionError::TaskNotFound {
+        plugin: "packages".to_string(),
+        task: "zzz".to_string(),
+        available: vec!["install".to_string(), "remove".to_string()],
+    };
+    assert_eq!(
+        error.to_string(),
+        "Task 'zzz' not found in plugin 'packages'. Available tasks: install, remove"
+    );
+}
+
+// ============================================================================
+// Exit Codes and Kinds
+// ============================================================================
+
+fn every_kind() -> Vec<ExecutionError> {
+    vec![
+        ExecutionError::LuaError(mlua::Error::runtime("boom")),
+        ExecutionError::ProcessError {
+            command: "false".to_string(),
+            exit_code: 1,
+            stderr: String::new(),
+        },
+        ExecutionError::ItemNotFound {
+            task: "install".to_string(),
+            item: "vim".to_string(),
+            available: Vec::new(),
+        },
+        ExecutionError::PluginNotFound {
+            plugin: "x".to_string(),
+            available: Vec::new(),
+        },
+        ExecutionError::TaskNotFound {
+            plugin: "x".to_string(),
+            task: "y".to_string(),
+            available: Vec::new(),
+        },
+    ]
+}
+
+#[test]
+fn test_exit_code_per_kind() {
+    let codes: Vec<i32> = every_kind().iter().map(ExecutionError::exit_code).collect();
+    assert_eq!(
+        codes,
+        vec![
+            EXIT_LUA_ERROR,
+            EXIT_PROCESS_ERROR,
+            EXIT_ITEM_NOT_FOUND,
+            EXIT_PLUGIN_NOT_FOUND,
+            EXIT_TASK_NOT_FOUND,
+        ]
+    );
+}
+
+#[test]
+fn test_exit_codes_are_distinct_from_other_failures() {
+    let mut codes: Vec<i32> = every_kind().iter().map(ExecutionError::exit_code).collect();
+    codes.extend([EXIT_SUCCESS, EXIT_FAILURE, EXIT_NO_ITEMS]);
+    let count = codes.len();
+    codes.sort();
+    codes.dedup();
+    assert_eq!(codes.len(), count);
+}
+
+#[test]
+fn test_kind_per_kind() {
+    let kinds: Vec<&str> = every_kind().iter().map(ExecutionError::kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "lua_error",
+            "process_error",
+            "item_not_found",
+            "plugin_not_found",
+            "task_not_found",
+        ]
+    );
+}
+
+// ============================================================================
+// Error Chain
+// ============================================================================
+
+#[test]
+fn test_lua_error_source_skips_displayed_error() {
+    let error = ExecutionError::LuaError(mlua::Error::runtime("boom"));
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_downcast_through_context() {
+    let result: anyhow::Result<()> = Err(ExecutionError::ProcessError {
+        command: "ls".to_string(),
+        exit_code: 2,
+        stderr: String::new(),
+    })
+    .context("Failed to fetch items");
+    let error = result.unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<ExecutionError>(),
+        Some(ExecutionError::ProcessError { exit_code: 2, .. })
+    ));
+    assert_eq!(
+        format!("{:#}", error),
+        "Failed to fetch items: Command 'ls' failed with exit code 2"
+    );
+}
+
+#[test]
+fn test_find_through_context() {
+    let result: anyhow::Result<()> = Err(ExecutionError::TaskNotFound {
+        plugin: "x".to_string(),
+        task: "y".to_string(),
+        available: Vec::new(),
+    })
+    .context("Failed to run task");
+    let error = result.unwrap_err();
+
+    assert!(matches!(
+        ExecutionError::find(&error),
+        Some(ExecutionError::TaskNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_find_without_execution_error() {
+    let error = anyhow::anyhow!("Failed to load config");
+    assert!(ExecutionError::find(&error).is_none());
+}
+
+#[test]
+fn test_find_reports_failed_syntropy_call_of_lua_function() {
+    let lua = mlua::Lua::new();
+    let failing = lua
+        .create_function(|_, ()| -> mlua::Result<()> {
+            Err(mlua::Error::external(ExecutionError::ProcessError {
+                command: "git status".to_string(),
+                exit_code: 128,
+                stderr: "fatal: bad object".to_string(),
+            }))
+        })
+        .unwrap();
+    lua.globals().set("failing", failing).unwrap();
+    let lua_error = lua
+        .load("local function items() failing() end items()")
+        .exec()
+        .unwrap_err();
+    let error = anyhow::Error::from(ExecutionError::LuaError(lua_error));
+
+    match ExecutionError::find(&error) {
+        Some(ExecutionError::ProcessError {
+            command, stderr, ..
+        }) => {
+            assert_eq!(command, "git status");
+            assert_eq!(stderr, "fatal: bad object");
+        }
+        other => panic!("Expected ProcessError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_find_keeps_plain_lua_error() {
+    let lua = mlua::Lua::new();
+    let lua_error = lua.load("error('boom')").exec().unwrap_err();
+    let error = anyhow::Error::from(ExecutionError::LuaError(lua_error));
+
+    assert!(matches!(
+        ExecutionError::find(&error),
+        Some(ExecutionError::LuaError(_))
+    ));
+}
+
+#[test]
+fn test_item_matcher_reports_item_not_found() {
+    let items = vec!["git".to_string(), "node".to_string()];
+    let matcher = ItemMatcher::new(&items, false, "install");
+
+    let error = matcher.match_item("vim").unwrap_err();
+
+    match error.downcast_ref::<ExecutionError>() {
+        Some(ExecutionError::ItemNotFound {
+            task,
+            item,
+            available,
+        }) => {
+            assert_eq!(task, "install");
+            assert_eq!(item, "vim");
+            assert_eq!(available, &items);
+        }
+        other => panic!("Expected ItemNotFound, got {:?}", other),
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Tests the FuzzySearcher implementation for filtering and ranking items.
 
-use syntropy::tui::fuzzy_searcher::FuzzySearcher;
+use syntropy::tui::fuzzy_searcher::{FuzzySearcher, SearchMode, SearchOptions};
 
 // ============================================================================
 // Empty Query Tests
@@ -412,3 +412,141 @@ fn test_shorter_match_ranks_higher() {
     assert!(!result.is_empty());
     assert_eq!(result[0], 0);
 }
+
+// ============================================================================
+// Match Position Tests
+// ============================================================================
+
+#[test]
+fn test_match_positions_of_fuzzy_match() {
+    let searcher = FuzzySearcher::default();
+
+    assert_eq!(searcher.match_positions("foo-bar", "fb"), vec![0, 4]);
+}
+
+#[test]
+fn test_match_positions_count_characters_not_bytes() {
+    let searcher = FuzzySearcher::default();
+
+    assert_eq!(searcher.match_positions("⚒ build", "bd"), vec![2, 6]);
+}
+
+#[test]
+fn test_match_positions_empty_without_query_or_match() {
+    let searcher = FuzzySearcher::default();
+
+    assert!(searcher.match_positions("apple", "").is_empty());
+    assert!(searcher.match_positions("apple", "xyz").is_empty());
+}
+
+#[test]
+fn test_highlights_follow_result_order() {
+    let searcher = FuzzySearcher::default();
+    let items = vec!["f-o-o".to_string(), "foo".to_string(), "apple".to_string()];
+
+    let results: Vec<&String> = searcher
+        .search(&items, "foo")
+        .into_iter()
+        .map(|idx| &items[idx])
+        .collect();
+    let highlights = searcher.highlights(&results, "foo");
+
+    assert_eq!(results, vec!["foo", "f-o-o"]);
+    assert_eq!(highlights, vec![vec![0, 1, 2], vec![0, 2, 4]]);
+    assert!(searcher.highlights(&results, "").is_empty());
+}
+
+// ============================================================================
+// Search Modes
+// ============================================================================
+
+fn searcher_with_mode(mode: SearchMode) -> FuzzySearcher {
+    let mut searcher = FuzzySearcher::default();
+    searcher.set_mode(mode);
+    searcher
+}
+
+#[test]
+fn test_search_mode_cycles_fuzzy_prefix_regex() {
+    assert_eq!(SearchMode::default(), SearchMode::Fuzzy);
+    assert_eq!(SearchMode::Fuzzy.next(), SearchMode::Prefix);
+    assert_eq!(SearchMode::Prefix.next(), SearchMode::Regex);
+    assert_eq!(SearchMode::Regex.next(), SearchMode::Fuzzy);
+    assert_eq!(SearchMode::Fuzzy.indicator(), "[~]");
+    assert_eq!(SearchMode::Prefix.indicator(), "[^]");
+    assert_eq!(SearchMode::Regex.indicator(), "[/]");
+}
+
+#[test]
+fn test_prefix_mode_keeps_item_order() {
+    let searcher = searcher_with_mode(SearchMode::Prefix);
+    let items = vec![
+        "cargo".to_string(),
+        "npm".to_string(),
+        "Cargo-edit".to_string(),
+        "rustup-cargo".to_string(),
+    ];
+
+    assert_eq!(searcher.search(&items, "car"), vec![0, 2]);
+    assert_eq!(searcher.search(&items, "Car"), vec![2]);
+    assert_eq!(searcher.match_positions("Cargo-edit", "car"), vec![0, 1, 2]);
+    assert!(searcher.match_positions("rustup-cargo", "car").is_empty());
+}
+
+#[test]
+fn test_regex_mode_filters_by_pattern() {
+    let searcher = searcher_with_mode(SearchMode::Regex);
+    let items = vec![
+        "libssl-dev".to_string(),
+        "openssl".to_string(),
+        "libz-dev".to_string(),
+    ];
+
+    assert_eq!(searcher.search(&items, "^lib.*-dev$"), vec![0, 2]);
+    assert_eq!(searcher.search(&items, "ssl"), vec![0, 1]);
+    assert_eq!(searcher.match_positions("openssl", "s+l"), vec![4, 5, 6]);
+}
+
+#[test]
+fn test_regex_mode_positions_count_characters() {
+    let searcher = searcher_with_mode(SearchMode::Regex);
+
+    assert_eq!(searcher.match_positions("café au lait", "au"), vec![5, 6]);
+}
+
+#[test]
+fn test_invalid_regex_matches_nothing() {
+    let searcher = searcher_with_mode(SearchMode::Regex);
+    let items = vec!["(group".to_string(), "other".to_string()];
+
+    assert!(searcher.search(&items, "(gro").is_empty());
+    assert!(searcher.match_positions("(group", "(gro").is_empty());
+    assert!(!SearchMode::Regex.is_valid_query("(gro"));
+    assert!(SearchMode::Regex.is_valid_query("\\(gro"));
+    assert!(SearchMode::Fuzzy.is_valid_query("(gro"));
+    assert!(SearchMode::Prefix.is_valid_query("(gro"));
+}
+
+#[test]
+fn test_case_sensitive_option_applies_to_every_mode() {
+    let items = vec!["Git".to_string(), "git".to_string(), "GIT".to_string()];
+    let mut searcher = FuzzySearcher::default();
+
+    // Lowercase queries ignore case unless case-sensitive
+    assert_eq!(searcher.search(&items, "git").len(), 3);
+
+    for mode in [SearchMode::Fuzzy, SearchMode::Prefix, SearchMode::Regex] {
+        searcher.set_options(SearchOptions {
+            mode,
+            case_sensitive: true,
+        });
+        assert_eq!(searcher.search(&items, "git"), vec![1], "{:?}", mode);
+        assert_eq!(searcher.search(&items, "Git"), vec![0], "{:?}", mode);
+    }
+
+    searcher.set_options(SearchOptions {
+        mode: SearchMode::Regex,
+        case_sensitive: false,
+    });
+    assert_eq!(searcher.search(&items, "^git$"), vec![0, 1, 2]);
+}
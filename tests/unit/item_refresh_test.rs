@@ -0,0 +1,112 @@
+//! Unit tests for item list refresh reconciliation
+//!
+//! Tests how marks and the cursor carry over when polled items replace the current list,
+//! and the status bar indicator showing the last refresh.
+
+use std::collections::HashSet;
+
+use chrono::NaiveTime;
+use syntropy::tui::item_refresh::{reconcile_cursor, reconcile_marked, status_indicator};
+
+fn set(items: &[&str]) -> HashSet<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+// ============================================================================
+// Marked Item Tests
+// ============================================================================
+
+#[test]
+fn test_marks_of_surviving_items_are_kept() {
+    let marked = reconcile_marked(&["a", "b", "c"], &["c", "a", "d"], &set(&["a", "c"]), &[]);
+
+    assert_eq!(marked, set(&["a", "c"]));
+}
+
+#[test]
+fn test_marks_of_removed_items_are_dropped() {
+    let marked = reconcile_marked(&["a", "b", "c"], &["a", "c"], &set(&["a", "b"]), &[]);
+
+    assert_eq!(marked, set(&["a"]));
+}
+
+#[test]
+fn test_preselection_applies_on_first_load() {
+    let preselected = vec!["b".to_string()];
+
+    let marked = reconcile_marked(&[], &["a", "b"], &HashSet::new(), &preselected);
+
+    assert_eq!(marked, set(&["b"]));
+}
+
+#[test]
+fn test_refresh_does_not_remark_unmarked_preselected_item() {
+    let preselected = vec!["b".to_string(), "d".to_string()];
+
+    let marked = reconcile_marked(&["a", "b"], &["a", "b", "d"], &HashSet::new(), &preselected);
+
+    assert_eq!(
+        marked,
+        set(&["d"]),
+        "only the new preselected item is marked"
+    );
+}
+
+#[test]
+fn test_preselected_item_missing_from_items_is_ignored() {
+    let preselected = vec!["z".to_string()];
+
+    let marked = reconcile_marked(&[], &["a"], &HashSet::new(), &preselected);
+
+    assert!(marked.is_empty());
+}
+
+// ============================================================================
+// Cursor Tests
+// ============================================================================
+
+#[test]
+fn test_cursor_follows_item_to_new_position() {
+    let cursor = reconcile_cursor(&["a", "b", "c"], &["new", "a", "b", "c"], Some(1));
+
+    assert_eq!(cursor, Some(2));
+}
+
+#[test]
+fn test_cursor_stays_in_place_when_item_is_removed() {
+    let cursor = reconcile_cursor(&["a", "b", "c"], &["a", "c"], Some(1));
+
+    assert_eq!(cursor, Some(1));
+}
+
+#[test]
+fn test_cursor_clamps_to_shorter_list() {
+    let cursor = reconcile_cursor(&["a", "b", "c"], &["a"], Some(2));
+
+    assert_eq!(cursor, Some(0));
+}
+
+#[test]
+fn test_cursor_is_none_for_empty_list_or_no_selection() {
+    assert_eq!(reconcile_cursor(&["a"], &[], Some(0)), None);
+    assert_eq!(reconcile_cursor(&["a"], &["a"], None), None);
+}
+
+// ============================================================================
+// Status Indicator Tests
+// ============================================================================
+
+#[test]
+fn test_status_indicator_without_refresh_shows_sort_only() {
+    assert_eq!(status_indicator("A-Z", None), "A-Z");
+}
+
+#[test]
+fn test_status_indicator_shows_last_refresh_time() {
+    let refreshed = NaiveTime::from_hms_opt(9, 5, 3).unwrap();
+
+    assert_eq!(
+        status_indicator("Source order", Some(refreshed)),
+        "Source order ⟳ 09:05:03"
+    );
+}
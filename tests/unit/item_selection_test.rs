@@ -0,0 +1,95 @@
+//! Unit tests for the select all / none / invert actions of multi-select item lists
+//!
+//! The actions only touch the items visible under the current search, marks of hidden
+//! items are kept.
+
+use std::collections::HashSet;
+
+use syntropy::tui::item_selection::{invert_selection, select_all, select_none};
+
+fn set(indices: &[usize]) -> HashSet<usize> {
+    indices.iter().copied().collect()
+}
+
+// ============================================================================
+// Select All Tests
+// ============================================================================
+
+#[test]
+fn test_select_all_marks_every_item_without_filter() {
+    let marked = select_all(&set(&[1]), &[0, 1, 2, 3]);
+
+    assert_eq!(marked, set(&[0, 1, 2, 3]));
+}
+
+#[test]
+fn test_select_all_within_filter_keeps_hidden_marks() {
+    // Items 0..6, the search shows 1, 3 and 5, item 4 was marked earlier
+    let marked = select_all(&set(&[4]), &[1, 3, 5]);
+
+    assert_eq!(marked, set(&[1, 3, 4, 5]));
+}
+
+#[test]
+fn test_select_all_with_nothing_visible_changes_nothing() {
+    let marked = select_all(&set(&[2]), &[]);
+
+    assert_eq!(marked, set(&[2]));
+}
+
+// ============================================================================
+// Select None Tests
+// ============================================================================
+
+#[test]
+fn test_select_none_within_filter_keeps_hidden_marks() {
+    let marked = select_none(&set(&[1, 3, 4]), &[1, 3, 5]);
+
+    assert_eq!(marked, set(&[4]));
+}
+
+#[test]
+fn test_select_none_without_filter_clears_marks() {
+    let marked = select_none(&set(&[0, 2]), &[0, 1, 2]);
+
+    assert!(marked.is_empty());
+}
+
+// ============================================================================
+// Invert Tests
+// ============================================================================
+
+#[test]
+fn test_invert_flips_visible_marks() {
+    let marked = invert_selection(&set(&[0, 2]), &[0, 1, 2, 3]);
+
+    assert_eq!(marked, set(&[1, 3]));
+}
+
+#[test]
+fn test_invert_against_filtered_view_keeps_hidden_marks() {
+    // Items 0..6, the search shows 1, 2 and 5, items 2 and 4 are marked
+    let marked = invert_selection(&set(&[2, 4]), &[1, 2, 5]);
+
+    assert_eq!(marked, set(&[1, 4, 5]));
+}
+
+#[test]
+fn test_invert_twice_restores_marks() {
+    let original = set(&[0, 3]);
+    let visible = [0, 1, 2];
+
+    let marked = invert_selection(&invert_selection(&original, &visible), &visible);
+
+    assert_eq!(marked, original);
+}
+
+#[test]
+fn test_actions_work_on_item_strings() {
+    let marked: HashSet<String> = ["[a] one".to_string()].into();
+    let visible = vec!["[a] one".to_string(), "[b] two".to_string()];
+
+    let inverted = invert_selection(&marked, &visible);
+
+    assert_eq!(inverted, ["[b] two".to_string()].into());
+}
@@ -0,0 +1,81 @@
+//! Unit tests for item list sort modes
+//!
+//! Tests the sort mode cycle and the ordering applied to displayed items.
+
+use std::rc::Rc;
+
+use syntropy::tui::item_sort::ItemSortMode;
+
+fn items() -> Vec<Rc<String>> {
+    ["node", "Docker", "git", "docker", "iTerm2"]
+        .iter()
+        .map(|item| Rc::new(item.to_string()))
+        .collect()
+}
+
+fn as_strs(items: &[Rc<String>]) -> Vec<&str> {
+    items.iter().map(|item| item.as_str()).collect()
+}
+
+// ============================================================================
+// Cycle Tests
+// ============================================================================
+
+#[test]
+fn test_default_sort_mode_is_source_order() {
+    assert_eq!(ItemSortMode::default(), ItemSortMode::SourceOrder);
+}
+
+#[test]
+fn test_sort_mode_cycles_through_all_modes() {
+    let mode = ItemSortMode::SourceOrder;
+
+    assert_eq!(mode.next(), ItemSortMode::Alphabetical);
+    assert_eq!(mode.next().next(), ItemSortMode::ReverseAlphabetical);
+    assert_eq!(mode.next().next().next(), ItemSortMode::SourceOrder);
+}
+
+#[test]
+fn test_sort_mode_labels() {
+    assert_eq!(ItemSortMode::SourceOrder.to_string(), "Source order");
+    assert_eq!(ItemSortMode::Alphabetical.to_string(), "A-Z");
+    assert_eq!(ItemSortMode::ReverseAlphabetical.to_string(), "Z-A");
+}
+
+// ============================================================================
+// Ordering Tests
+// ============================================================================
+
+#[test]
+fn test_source_order_keeps_items_untouched() {
+    let mut sorted = items();
+    ItemSortMode::SourceOrder.apply(&mut sorted);
+    assert_eq!(sorted, items());
+}
+
+#[test]
+fn test_alphabetical_is_case_insensitive() {
+    let mut sorted = items();
+    ItemSortMode::Alphabetical.apply(&mut sorted);
+    assert_eq!(
+        as_strs(&sorted),
+        vec!["Docker", "docker", "git", "iTerm2", "node"]
+    );
+}
+
+#[test]
+fn test_reverse_alphabetical_is_exact_reverse() {
+    let mut sorted = items();
+    ItemSortMode::ReverseAlphabetical.apply(&mut sorted);
+    assert_eq!(
+        as_strs(&sorted),
+        vec!["node", "iTerm2", "git", "docker", "Docker"]
+    );
+}
+
+#[test]
+fn test_apply_accepts_plain_strings() {
+    let mut sorted = vec!["b".to_string(), "a".to_string()];
+    ItemSortMode::Alphabetical.apply(&mut sorted);
+    assert_eq!(sorted, vec!["a", "b"]);
+}
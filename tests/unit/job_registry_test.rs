@@ -0,0 +1,300 @@
+//! Unit tests for the background job registry
+//!
+//! Tests spawn/kill/restart/remove and state transitions of background jobs
+//! independently of the TUI.
+
+use std::{
+    fs,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use syntropy::{
+    create_lua_vm,
+    execution::{JobRegistry, State},
+    plugins::{Dedup, DefaultSelect, Mode, Task},
+};
+use tempfile::TempDir;
+use tokio::{runtime::Runtime, sync::Mutex};
+
+const PLUGIN: &str = r#"
+test = {
+    tasks = {
+        quick = {
+            execute = function() return "done", 0 end,
+        },
+        failing = {
+            execute = function() return "broken", 3 end,
+        },
+        slow = {
+            execute = function()
+                syntropy.shell("sleep 5")
+                return "slept", 0
+            end,
+        },
+        pid_file = {
+            execute = function()
+                syntropy.shell("echo $$ > " .. pid_file .. "; sleep 30; true")
+                return "slept", 0
+            end,
+        },
+    },
+}
+"#;
+
+fn task(task_key: &str) -> Arc<Task> {
+    Arc::new(Task {
+        plugin_name: "test".to_string(),
+        task_key: task_key.to_string(),
+        name: task_key.to_string(),
+        description: String::new(),
+        icon: String::new(),
+        item_sources: None,
+        mode: Mode::Background,
+        deprecated_mode_none: false,
+        preview_polling_interval: 0,
+        item_polling_interval: 0,
+        execution_confirmation_message: None,
+        suppress_success_notification: false,
+        continue_on_error: true,
+        dedup: Dedup::None,
+        default_select: DefaultSelect::Preselected,
+        order: None,
+        group: None,
+        aliases: Vec::new(),
+    })
+}
+
+fn create_registry(runtime: &Runtime) -> JobRegistry {
+    let lua = create_lua_vm().unwrap();
+    lua.load(PLUGIN).exec().unwrap();
+    JobRegistry::new(runtime.handle().clone(), &Arc::new(Mutex::new(lua)))
+}
+
+// `pid_file` is where the `pid_file` task writes the pid of its shell command
+fn create_registry_with_pid_file(runtime: &Runtime, pid_file: &Path) -> JobRegistry {
+    let lua = create_lua_vm().unwrap();
+    lua.load(PLUGIN).exec().unwrap();
+    lua.globals()
+        .set("pid_file", pid_file.to_string_lossy().into_owned())
+        .unwrap();
+    JobRegistry::new(runtime.handle().clone(), &Arc::new(Mutex::new(lua)))
+}
+
+fn wait_for_completion(registry: &mut JobRegistry, id: usize) -> State {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        registry.poll();
+        let state = registry.status(id).unwrap();
+        if state != State::Running {
+            return state;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("job #{} did not complete in time", id);
+}
+
+fn wait_for_pid(pid_file: &Path) -> u32 {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if let Ok(pid) = fs::read_to_string(pid_file)
+            .unwrap_or_default()
+            .trim()
+            .parse()
+        {
+            return pid;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("{} was not written in time", pid_file.display());
+}
+
+// Zombies wait for their parent to reap them, they no longer run
+fn is_running(pid: u32) -> bool {
+    fs::read_to_string(format!("/proc/{}/stat", pid))
+        .is_ok_and(|stat| !stat.rsplit(')').next().unwrap_or("").starts_with(" Z"))
+}
+
+// ============================================================================
+// Spawn Tests
+// ============================================================================
+
+#[test]
+fn test_spawn_assigns_incrementing_ids() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let first = registry.spawn(task("quick"), vec![]).unwrap();
+    let second = registry.spawn(task("quick"), vec![]).unwrap();
+
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn test_spawned_job_is_running() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("slow"), vec![]).unwrap();
+
+    assert_eq!(registry.status(id), Some(State::Running));
+}
+
+// ============================================================================
+// State Transition Tests
+// ============================================================================
+
+#[test]
+fn test_successful_job_transitions_to_finished() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("quick"), vec![]).unwrap();
+
+    assert_eq!(wait_for_completion(&mut registry, id), State::Finished);
+    assert_eq!(registry.get(id).unwrap().output(), Some("done"));
+}
+
+#[test]
+fn test_non_zero_exit_transitions_to_error() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("failing"), vec![]).unwrap();
+
+    assert_eq!(wait_for_completion(&mut registry, id), State::Error);
+    assert_eq!(registry.get(id).unwrap().output(), Some("broken"));
+}
+
+#[test]
+fn test_running_shell_command_does_not_block_other_jobs() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let slow = registry.spawn(task("slow"), vec![]).unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+    let started = Instant::now();
+    let quick = registry.spawn(task("quick"), vec![]).unwrap();
+
+    assert_eq!(wait_for_completion(&mut registry, quick), State::Finished);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(registry.status(slow), Some(State::Running));
+}
+
+#[test]
+fn test_missing_lua_function_transitions_to_error() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("does_not_exist"), vec![]).unwrap();
+
+    assert_eq!(wait_for_completion(&mut registry, id), State::Error);
+}
+
+#[test]
+fn test_kill_stops_running_job() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("slow"), vec![]).unwrap();
+    registry.kill(id).unwrap();
+
+    assert_eq!(registry.status(id), Some(State::None));
+    assert!(registry.get(id).unwrap().output().is_none());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_kill_terminates_shell_command_of_job() {
+    let runtime = Runtime::new().unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let pid_file = temp_dir.path().join("pid");
+    let mut registry = create_registry_with_pid_file(&runtime, &pid_file);
+
+    let id = registry.spawn(task("pid_file"), vec![]).unwrap();
+    let pid = wait_for_pid(&pid_file);
+    registry.kill(id).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while is_running(pid) && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(
+        !is_running(pid),
+        "The job's shell command outlived the kill"
+    );
+}
+
+#[test]
+fn test_restart_runs_finished_job_again() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("quick"), vec![]).unwrap();
+    wait_for_completion(&mut registry, id);
+
+    registry.restart(id).unwrap();
+
+    assert_eq!(registry.len(), 1);
+    assert_eq!(wait_for_completion(&mut registry, id), State::Finished);
+}
+
+#[test]
+fn test_restart_running_job_fails() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("slow"), vec![]).unwrap();
+
+    assert!(registry.restart(id).is_err());
+    assert_eq!(registry.status(id), Some(State::Running));
+}
+
+#[test]
+fn test_restart_killed_job() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let id = registry.spawn(task("slow"), vec![]).unwrap();
+    registry.kill(id).unwrap();
+    registry.restart(id).unwrap();
+
+    assert_eq!(registry.status(id), Some(State::Running));
+}
+
+// ============================================================================
+// Remove Tests
+// ============================================================================
+
+#[test]
+fn test_remove_drops_job_from_registry() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    let first = registry.spawn(task("slow"), vec![]).unwrap();
+    let second = registry.spawn(task("quick"), vec![]).unwrap();
+
+    let removed = registry.remove(first).unwrap();
+
+    assert_eq!(removed.id, first);
+    assert_eq!(registry.status(first), None);
+    assert_eq!(
+        registry.jobs().map(|job| job.id).collect::<Vec<_>>(),
+        vec![second]
+    );
+}
+
+#[test]
+fn test_unknown_job_ids() {
+    let runtime = Runtime::new().unwrap();
+    let mut registry = create_registry(&runtime);
+
+    assert!(registry.is_empty());
+    assert_eq!(registry.status(42), None);
+    assert!(registry.kill(42).is_err());
+    assert!(registry.restart(42).is_err());
+    assert!(registry.remove(42).is_none());
+}
@@ -0,0 +1,286 @@
+//! Unit tests for key binding validation
+//!
+//! Tests conflict detection and search bar shadowing warnings of validate_keybindings.
+
+use syntropy::configs::{KeyBindings, QuickAction, validate_keybindings};
+
+// ============================================================================
+// Conflict Tests
+// ============================================================================
+
+#[test]
+fn test_default_keybindings_are_valid_without_warnings() {
+    let warnings = validate_keybindings(&KeyBindings::default()).unwrap();
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_conflict_names_both_actions() {
+    let key_bindings = KeyBindings {
+        back: "<C-c>".into(),
+        confirm: "<C-c>".into(),
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Duplicate key bindings detected:\n  Key '<C-c>' is bound to both 'back' and 'confirm'"
+    );
+}
+
+#[test]
+fn test_conflict_lists_every_action_of_a_key() {
+    let key_bindings = KeyBindings {
+        jobs: "<C-x>".into(),
+        kill_job: "<C-x>".into(),
+        restart_job: "<C-x>".into(),
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("Key '<C-x>' is bound to 'jobs', 'kill_job' and 'restart_job'")
+    );
+}
+
+#[test]
+fn test_conflict_detected_across_notations() {
+    let key_bindings = KeyBindings {
+        select_next: "K".into(),
+        select_previous: "<S-k>".into(),
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("is bound to both 'select_previous' and 'select_next'")
+    );
+}
+
+// ============================================================================
+// Search Bar Shadowing Tests
+// ============================================================================
+
+#[test]
+fn test_plain_character_warns_about_search_bar() {
+    let key_bindings = KeyBindings {
+        back: "q".into(),
+        ..KeyBindings::default()
+    };
+
+    let warnings = validate_keybindings(&key_bindings).unwrap();
+
+    assert_eq!(
+        warnings,
+        ["Key 'q' bound to 'back' is captured by the search bar and won't trigger the action"]
+    );
+}
+
+#[test]
+fn test_modified_character_does_not_warn() {
+    let key_bindings = KeyBindings {
+        back: "<C-q>".into(),
+        toggle_preview: "<A-p>".into(),
+        ..KeyBindings::default()
+    };
+
+    assert!(validate_keybindings(&key_bindings).unwrap().is_empty());
+}
+
+#[test]
+fn test_home_warns_unless_ctrl_modified() {
+    let key_bindings = KeyBindings {
+        go_home: "<home>".into(),
+        ..KeyBindings::default()
+    };
+
+    let warnings = validate_keybindings(&key_bindings).unwrap();
+
+    assert_eq!(
+        warnings,
+        [
+            "Key '<home>' bound to 'go_home' is captured by the search bar and won't trigger the action"
+        ]
+    );
+    assert!(
+        validate_keybindings(&KeyBindings::default())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_arrows_warn_unless_alt_modified() {
+    let key_bindings = KeyBindings {
+        scroll_preview_left: "<C-left>".into(),
+        scroll_preview_right: "<A-right>".into(),
+        ..KeyBindings::default()
+    };
+
+    let warnings = validate_keybindings(&key_bindings).unwrap();
+
+    assert_eq!(
+        warnings,
+        [
+            "Key '<C-left>' bound to 'scroll_preview_left' is captured by the search bar and won't trigger the action"
+        ]
+    );
+}
+
+// ============================================================================
+// Alias Tests
+// ============================================================================
+
+#[test]
+fn test_keybinding_accepts_single_key_or_list() {
+    let key_bindings: KeyBindings = toml::from_str(
+        r#"
+back = "q"
+select_next = ["<down>", "j"]
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(key_bindings.back, "q");
+    assert_eq!(key_bindings.select_next.keys(), ["<down>", "j"]);
+}
+
+#[test]
+fn test_empty_alias_list_is_rejected() {
+    let key_bindings = KeyBindings {
+        confirm: Vec::new().into(),
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert_eq!(error.to_string(), "No key bound to 'confirm' keybinding");
+}
+
+#[test]
+fn test_repeated_alias_of_one_action_is_not_a_conflict() {
+    let key_bindings = KeyBindings {
+        confirm: vec!["<enter>", "<enter>"].into(),
+        ..KeyBindings::default()
+    };
+
+    assert!(validate_keybindings(&key_bindings).is_ok());
+}
+
+#[test]
+fn test_alias_warns_about_search_bar() {
+    let key_bindings = KeyBindings {
+        select_next: vec!["<down>", "j"].into(),
+        ..KeyBindings::default()
+    };
+
+    let warnings = validate_keybindings(&key_bindings).unwrap();
+
+    assert_eq!(
+        warnings,
+        [
+            "Key 'j' bound to 'select_next' is captured by the search bar and won't trigger the action"
+        ]
+    );
+}
+
+// ============================================================================
+// Quick Action Tests
+// ============================================================================
+
+fn quick_action(key: &str) -> QuickAction {
+    QuickAction {
+        key: key.into(),
+        plugin: "packages".to_string(),
+        task: "upgrade".to_string(),
+        items: Vec::new(),
+    }
+}
+
+#[test]
+fn test_quick_action_with_free_key_is_valid() {
+    let key_bindings = KeyBindings {
+        quick_actions: vec![quick_action("<C-u>")],
+        ..KeyBindings::default()
+    };
+
+    assert!(validate_keybindings(&key_bindings).unwrap().is_empty());
+}
+
+#[test]
+fn test_quick_action_conflicting_with_built_in_binding_is_rejected() {
+    let key_bindings = KeyBindings {
+        quick_actions: vec![quick_action("<C-y>")],
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Duplicate key bindings detected:\n  Key '<C-y>' is bound to both 'copy' and 'quick action packages/upgrade'"
+    );
+}
+
+#[test]
+fn test_quick_actions_sharing_a_key_are_rejected() {
+    let key_bindings = KeyBindings {
+        quick_actions: vec![
+            quick_action("<F5>"),
+            QuickAction {
+                plugin: "notes".to_string(),
+                task: "new".to_string(),
+                ..quick_action("<F5>")
+            },
+        ],
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert!(error.to_string().contains(
+        "Key '<F5>' is bound to both 'quick action packages/upgrade' and 'quick action notes/new'"
+    ));
+}
+
+#[test]
+fn test_quick_action_on_quit_key_is_rejected() {
+    let key_bindings = KeyBindings {
+        quick_actions: vec![quick_action("<C-c>")],
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Key '<C-c>' bound to 'quick action packages/upgrade' is reserved for quitting"
+    );
+}
+
+#[test]
+fn test_quick_action_without_task_is_rejected() {
+    let key_bindings = KeyBindings {
+        quick_actions: vec![QuickAction {
+            task: String::new(),
+            ..quick_action("<F5>")
+        }],
+        ..KeyBindings::default()
+    };
+
+    let error = validate_keybindings(&key_bindings).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Quick action bound to '<F5>' must name a plugin and a task"
+    );
+}
@@ -1,11 +1,35 @@
+mod app_iter_test;
+mod border_style_test;
+mod color_mode_test;
 mod colors_fallback_test;
 mod colors_parsing_test;
+mod config_template_test;
+mod context_menu_test;
+mod editor_test;
 mod events_test;
 mod execute_test;
+mod execution_error_test;
 mod exit_code_test;
 mod fuzzy_searcher_test;
+mod item_refresh_test;
+mod item_selection_test;
+mod item_sort_test;
+mod job_registry_test;
+mod key_bindings_test;
 mod keybind_test;
 mod navigator_test;
+mod panic_guard_test;
 mod paths_test;
+mod plan_test;
 mod plugin_declaration_test;
+mod plugin_filter_test;
+mod preview_cache_test;
+mod preview_poller_test;
+mod preview_test;
+mod search_history_test;
+mod selectable_list_test;
+mod status_bar_test;
+mod syntax_highlight_test;
 mod tag_parsing_test;
+mod toast_test;
+mod watch_test;
@@ -2,9 +2,20 @@
 //!
 //! Tests the Navigator's stack management, breadcrumb generation, and intent resolution.
 
-use syntropy::tui::navigation::{
-    Intent, ItemPayload, Navigator, PluginPayload, Route, StackEntry, TaskPayload,
+use std::{collections::HashMap, sync::Arc};
+
+use mlua::Lua;
+use syntropy::{
+    App, Config,
+    plugins::{Dedup, DefaultSelect, Metadata, Mode, Plugin, Task},
+    tui::{
+        TuiApp,
+        navigation::{
+            Intent, ItemPayload, Navigator, PluginPayload, Route, StackEntry, TaskPayload,
+        },
+    },
 };
+use tokio::sync::Mutex;
 
 // ============================================================================
 // Navigator Initialization Tests
@@ -194,6 +205,50 @@ fn test_pop_at_root_returns_none() {
     assert_eq!(nav.current(), &route);
 }
 
+#[test]
+fn test_back_to_root_pops_every_entry_above_root() {
+    let route = Route::Plugin {
+        payload: PluginPayload,
+    };
+    let mut nav = Navigator::new(route.clone(), "Plugins".to_string(), " > ".to_string());
+    nav.push(
+        Route::Task {
+            payload: TaskPayload { plugin_idx: 0 },
+        },
+        "Tasks".to_string(),
+    );
+    nav.push(
+        Route::Item {
+            payload: ItemPayload {
+                plugin_idx: 0,
+                task_key: "test".to_string(),
+            },
+        },
+        "Items".to_string(),
+    );
+    assert_eq!(nav.history_len(), 3);
+
+    let popped = nav.back_to_root();
+
+    let names: Vec<&str> = popped.iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, vec!["Items", "Tasks"]);
+    assert_eq!(nav.current(), &route);
+    assert_eq!(nav.history_len(), 1);
+    assert_eq!(nav.get_breadcrumbs(), "Plugins");
+}
+
+#[test]
+fn test_back_to_root_at_root_pops_nothing() {
+    let route = Route::Plugin {
+        payload: PluginPayload,
+    };
+    let mut nav = Navigator::new(route.clone(), "Plugins".to_string(), " > ".to_string());
+
+    assert!(nav.back_to_root().is_empty());
+    assert_eq!(nav.current(), &route);
+    assert_eq!(nav.history_len(), 1);
+}
+
 // ============================================================================
 // Intent Resolution Tests
 // ============================================================================
@@ -251,6 +306,36 @@ fn test_resolve_intent_quit_returns_none() {
     assert_eq!(resolved, None);
 }
 
+#[test]
+fn test_resolve_intent_show_toast_returns_none() {
+    let route = Route::Plugin {
+        payload: PluginPayload,
+    };
+    let mut nav = Navigator::new(route.clone(), "Plugins".to_string(), " > ".to_string());
+
+    let intent = Intent::ShowToast {
+        message: "done".to_string(),
+        exit_code: Some(0),
+    };
+    let resolved = nav.resolve_intent(intent);
+
+    assert_eq!(resolved, None);
+    assert_eq!(nav.current(), &route);
+}
+
+#[test]
+fn test_resolve_intent_go_home_returns_none() {
+    let mut nav = Navigator::new(
+        Route::Plugin {
+            payload: PluginPayload,
+        },
+        "Plugins".to_string(),
+        " > ".to_string(),
+    );
+
+    assert_eq!(nav.resolve_intent(Intent::GoHome), None);
+}
+
 #[test]
 fn test_resolve_intent_none_returns_none() {
     let route = Route::Plugin {
@@ -858,3 +943,115 @@ fn test_intent_select_task_with_different_keys() {
     assert_eq!(i1, i2);
     assert_ne!(i1, i3);
 }
+
+// ============================================================================
+// Initial Route Tests (Deep Links)
+// ============================================================================
+
+fn deep_link_app(default_plugin: Option<&str>, default_task: Option<&str>) -> App {
+    let task = Arc::new(Task {
+        plugin_name: "packages".to_string(),
+        task_key: "install".to_string(),
+        name: "Install".to_string(),
+        description: String::new(),
+        icon: String::new(),
+        item_sources: None,
+        mode: Mode::Single,
+        deprecated_mode_none: false,
+        preview_polling_interval: 0,
+        item_polling_interval: 0,
+        execution_confirmation_message: None,
+        suppress_success_notification: false,
+        continue_on_error: true,
+        dedup: Dedup::None,
+        default_select: DefaultSelect::Preselected,
+        order: None,
+        group: None,
+        aliases: Vec::new(),
+    });
+    let plugins = ["notes", "packages"]
+        .into_iter()
+        .map(|name| Plugin {
+            metadata: Metadata {
+                name: name.to_string(),
+                ..Default::default()
+            },
+            tasks: HashMap::from([("install".to_string(), Arc::clone(&task))]),
+        })
+        .collect();
+    let config = Config {
+        default_plugin: default_plugin.map(str::to_string),
+        default_task: default_task.map(str::to_string),
+        ..Config::default()
+    };
+
+    App::new(config, plugins, Arc::new(Mutex::new(Lua::new())))
+}
+
+#[test]
+fn test_initial_routes_default_to_plugin_list() {
+    let routes = TuiApp::resolve_initial_routes(&deep_link_app(None, None)).unwrap();
+
+    assert_eq!(
+        routes,
+        vec![Route::Plugin {
+            payload: PluginPayload
+        }]
+    );
+}
+
+#[test]
+fn test_initial_routes_deep_link_to_task_keeps_parent_screens() {
+    let app = deep_link_app(Some("packages"), Some("install"));
+
+    let routes = TuiApp::resolve_initial_routes(&app).unwrap();
+
+    assert_eq!(
+        routes,
+        vec![
+            Route::Plugin {
+                payload: PluginPayload
+            },
+            Route::Task {
+                payload: TaskPayload { plugin_idx: 1 }
+            },
+            Route::Item {
+                payload: ItemPayload {
+                    plugin_idx: 1,
+                    task_key: "install".to_string(),
+                }
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_initial_routes_deep_link_to_plugin() {
+    let app = deep_link_app(Some("packages"), None);
+
+    let routes = TuiApp::resolve_initial_routes(&app).unwrap();
+
+    assert_eq!(
+        routes.last(),
+        Some(&Route::Task {
+            payload: TaskPayload { plugin_idx: 1 }
+        })
+    );
+    assert_eq!(routes.len(), 2);
+}
+
+#[test]
+fn test_initial_routes_unknown_plugin_or_task_fails() {
+    let unknown_plugin = deep_link_app(Some("missing"), None);
+    let unknown_task = deep_link_app(Some("packages"), Some("missing"));
+
+    let plugin_error = TuiApp::resolve_initial_routes(&unknown_plugin).unwrap_err();
+    let task_error = TuiApp::resolve_initial_routes(&unknown_task).unwrap_err();
+
+    assert!(plugin_error.to_string().contains("'missing' not found"));
+    assert!(
+        task_error
+            .to_string()
+            .contains("default_task 'missing' not found in plugin 'packages'")
+    );
+}
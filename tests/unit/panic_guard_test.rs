@@ -0,0 +1,96 @@
+//! Unit tests for the TUI panic guard
+//!
+//! Tests that panics are turned into errors carrying their message and location, and that
+//! the previous panic hook is reinstated afterwards.
+
+use std::{
+    panic,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+use syntropy::tui::panic_guard::{catch_panic, panic_message};
+
+// The panic hook is global, tests replacing it must not interleave
+static PANIC_HOOK: Mutex<()> = Mutex::new(());
+
+fn lock_panic_hook() -> MutexGuard<'static, ()> {
+    PANIC_HOOK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ============================================================================
+// catch_panic
+// ============================================================================
+
+#[test]
+fn test_catch_panic_returns_value_without_panic() {
+    let _hook = lock_panic_hook();
+    assert_eq!(catch_panic(|| 42), Ok(42));
+}
+
+#[test]
+fn test_catch_panic_reports_message_and_location() {
+    let _hook = lock_panic_hook();
+    let error = catch_panic(|| panic!("render failed")).unwrap_err();
+
+    assert!(error.starts_with("render failed at "), "error: {}", error);
+    assert!(error.contains("panic_guard_test.rs:"), "error: {}", error);
+}
+
+#[test]
+fn test_catch_panic_reports_formatted_message() {
+    let _hook = lock_panic_hook();
+    let error = catch_panic(|| panic!("index {} out of range", 7)).unwrap_err();
+
+    assert!(
+        error.starts_with("index 7 out of range at "),
+        "error: {}",
+        error
+    );
+}
+
+#[test]
+fn test_catch_panic_reinstates_previous_hook() {
+    let _hook = lock_panic_hook();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let previous = panic::take_hook();
+    {
+        let calls = Arc::clone(&calls);
+        panic::set_hook(Box::new(move |_| {
+            calls.fetch_add(1, Ordering::SeqCst);
+        }));
+    }
+
+    let _ = catch_panic(|| panic!("guarded"));
+    let other_thread = thread::spawn(|| panic!("after the guard")).join();
+
+    panic::set_hook(previous);
+    assert!(other_thread.is_err());
+    // Only the panic outside of the guard reached the test hook
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+// ============================================================================
+// panic_message
+// ============================================================================
+
+#[test]
+fn test_panic_message_of_str_payload() {
+    let payload: Box<dyn std::any::Any + Send> = Box::new("static message");
+    assert_eq!(panic_message(payload.as_ref()), "static message");
+}
+
+#[test]
+fn test_panic_message_of_string_payload() {
+    let payload: Box<dyn std::any::Any + Send> = Box::new("owned message".to_string());
+    assert_eq!(panic_message(payload.as_ref()), "owned message");
+}
+
+#[test]
+fn test_panic_message_of_other_payload() {
+    let payload: Box<dyn std::any::Any + Send> = Box::new(7_u32);
+    assert_eq!(panic_message(payload.as_ref()), "unknown panic");
+}
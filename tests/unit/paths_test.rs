@@ -4,8 +4,9 @@ use std::fs;
 use std::path::PathBuf;
 use syntropy::configs::paths::{
     expand_path, find_config_file, get_default_config_dir, get_default_data_dir,
-    resolve_plugin_paths,
+    get_system_config_dirs, resolve_plugin_paths,
 };
+use tempfile::TempDir;
 
 #[test]
 fn test_get_default_config_dir() {
@@ -21,7 +22,7 @@ fn test_get_default_data_dir() {
 
 #[test]
 fn test_resolve_plugin_paths_returns_default_directories() {
-    let paths = resolve_plugin_paths().expect("Should resolve paths");
+    let paths = resolve_plugin_paths(&[]).expect("Should resolve paths");
 
     assert_eq!(paths.len(), 2);
     assert!(paths[0].ends_with("syntropy/plugins"));
@@ -419,3 +420,199 @@ fn test_expand_path_relative_unchanged() {
     // Should remain unchanged
     assert_eq!(expanded, path);
 }
+
+#[test]
+fn test_resolve_plugin_paths_places_configured_paths_between_defaults() {
+    let extra = vec![PathBuf::from("/opt/plugins"), PathBuf::from("/srv/plugins")];
+    let paths = resolve_plugin_paths(&extra).expect("Should resolve paths");
+
+    assert_eq!(paths.len(), 4);
+    assert!(paths[0].ends_with("syntropy/plugins"));
+    assert_eq!(paths[1..3], extra[..]);
+    assert!(paths[3].ends_with("syntropy/plugins"));
+}
+
+// ============================================================================
+// XDG_CONFIG_DIRS Tests
+// ============================================================================
+
+// Sets XDG_CONFIG_HOME and XDG_CONFIG_DIRS for the duration of `f`, restoring them after
+fn with_xdg_config(home: &std::path::Path, dirs: Option<&str>, f: impl FnOnce()) {
+    let saved_home = env::var("XDG_CONFIG_HOME").ok();
+    let saved_dirs = env::var("XDG_CONFIG_DIRS").ok();
+    unsafe {
+        env::set_var("XDG_CONFIG_HOME", home);
+        match dirs {
+            Some(dirs) => env::set_var("XDG_CONFIG_DIRS", dirs),
+            None => env::remove_var("XDG_CONFIG_DIRS"),
+        }
+    }
+
+    f();
+
+    unsafe {
+        match saved_home {
+            Some(val) => env::set_var("XDG_CONFIG_HOME", val),
+            None => env::remove_var("XDG_CONFIG_HOME"),
+        }
+        match saved_dirs {
+            Some(val) => env::set_var("XDG_CONFIG_DIRS", val),
+            None => env::remove_var("XDG_CONFIG_DIRS"),
+        }
+    }
+}
+
+fn write_config(dir: &std::path::Path) -> PathBuf {
+    let config_dir = dir.join("syntropy");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    let config_path = config_dir.join("syntropy.toml");
+    fs::write(&config_path, "# test config").expect("Failed to write config");
+    config_path
+}
+
+#[test]
+#[serial]
+fn test_system_config_dirs_splits_on_colon_and_skips_relative_entries() {
+    let temp_dir = TempDir::new().unwrap();
+
+    with_xdg_config(
+        temp_dir.path(),
+        Some("/opt/xdg:relative/xdg::/etc/xdg"),
+        || {
+            assert_eq!(
+                get_system_config_dirs(),
+                vec![
+                    PathBuf::from("/opt/xdg/syntropy"),
+                    PathBuf::from("/etc/xdg/syntropy"),
+                ]
+            );
+        },
+    );
+}
+
+#[test]
+#[serial]
+fn test_system_config_dirs_defaults_to_etc_xdg() {
+    let temp_dir = TempDir::new().unwrap();
+
+    with_xdg_config(temp_dir.path(), None, || {
+        assert_eq!(
+            get_system_config_dirs(),
+            vec![PathBuf::from("/etc/xdg/syntropy")]
+        );
+    });
+    with_xdg_config(temp_dir.path(), Some(""), || {
+        assert_eq!(
+            get_system_config_dirs(),
+            vec![PathBuf::from("/etc/xdg/syntropy")]
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_find_config_file_falls_back_to_xdg_config_dirs_in_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let empty = temp_dir.path().join("empty");
+    let first = temp_dir.path().join("first");
+    let second = temp_dir.path().join("second");
+    let first_config = write_config(&first);
+    write_config(&second);
+    let dirs = format!(
+        "{}:{}:{}",
+        empty.display(),
+        first.display(),
+        second.display()
+    );
+
+    with_xdg_config(&temp_dir.path().join("home"), Some(&dirs), || {
+        assert_eq!(find_config_file(None).unwrap(), Some(first_config.clone()));
+    });
+}
+
+#[test]
+#[serial]
+fn test_find_config_file_prefers_xdg_config_home_over_xdg_config_dirs() {
+    let temp_dir = TempDir::new().unwrap();
+    let home = temp_dir.path().join("home");
+    let system = temp_dir.path().join("system");
+    let home_config = write_config(&home);
+    write_config(&system);
+
+    with_xdg_config(&home, Some(system.to_str().unwrap()), || {
+        assert_eq!(find_config_file(None).unwrap(), Some(home_config.clone()));
+    });
+}
+
+#[test]
+#[serial]
+fn test_resolve_plugin_paths_includes_existing_system_plugin_dirs() {
+    let temp_dir = TempDir::new().unwrap();
+    let home = temp_dir.path().join("home");
+    let first = temp_dir.path().join("first");
+    let missing = temp_dir.path().join("missing");
+    let second = temp_dir.path().join("second");
+    for dir in [&first, &second] {
+        fs::create_dir_all(dir.join("syntropy").join("plugins")).unwrap();
+    }
+    let dirs = format!(
+        "{}:{}:{}",
+        first.display(),
+        missing.display(),
+        second.display()
+    );
+    let extra = vec![PathBuf::from("/opt/plugins")];
+
+    with_xdg_config(&home, Some(&dirs), || {
+        let paths = resolve_plugin_paths(&extra).expect("Should resolve paths");
+
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths[0], home.join("syntropy").join("plugins"));
+        assert_eq!(paths[1], first.join("syntropy").join("plugins"));
+        assert_eq!(paths[2], second.join("syntropy").join("plugins"));
+        assert_eq!(paths[3], extra[0]);
+        assert!(paths[4].ends_with("syntropy/plugins"));
+    });
+}
+
+// ============================================================================
+// Glob Plugin Path Tests
+// ============================================================================
+
+#[test]
+fn test_resolve_plugin_paths_expands_glob_to_sorted_directories() {
+    let temp_dir = TempDir::new().unwrap();
+    let groups = temp_dir.path().join("groups");
+    for group in ["work", "personal"] {
+        fs::create_dir_all(groups.join(group)).unwrap();
+    }
+    fs::write(groups.join("notes.txt"), "not a plugin directory").unwrap();
+
+    let paths = resolve_plugin_paths(&[groups.join("*")]).expect("Should resolve paths");
+
+    assert_eq!(paths.len(), 4);
+    assert_eq!(paths[1], groups.join("personal"));
+    assert_eq!(paths[2], groups.join("work"));
+}
+
+#[test]
+fn test_resolve_plugin_paths_skips_glob_without_matches() {
+    let temp_dir = TempDir::new().unwrap();
+    let extra = vec![
+        temp_dir.path().join("missing").join("*"),
+        PathBuf::from("/opt/plugins"),
+    ];
+
+    let paths = resolve_plugin_paths(&extra).expect("Should resolve paths");
+
+    assert_eq!(paths.len(), 3);
+    assert_eq!(paths[1], PathBuf::from("/opt/plugins"));
+}
+
+#[test]
+fn test_resolve_plugin_paths_rejects_invalid_glob() {
+    let result = resolve_plugin_paths(&[PathBuf::from("/opt/plugins/[")]);
+
+    let error = format!("{:#}", result.unwrap_err());
+    assert!(error.contains("Invalid glob pattern in plugin path '/opt/plugins/['"));
+}
@@ -0,0 +1,67 @@
+//! Unit tests for execution plans
+//!
+//! Tests the JSON round trip of ExecutionPlan used by the TUI plan dump and `execute --plan`.
+
+use syntropy::execution::ExecutionPlan;
+use tempfile::TempDir;
+
+// ============================================================================
+// Serialization Tests
+// ============================================================================
+
+#[test]
+fn test_plan_json_round_trip() {
+    let plan = ExecutionPlan::new(
+        "packages",
+        "install",
+        vec!["[pkg] git".to_string(), "[cask] Docker".to_string()],
+    );
+
+    let json = serde_json::to_string(&plan).unwrap();
+    let parsed: ExecutionPlan = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed, plan);
+}
+
+#[test]
+fn test_plan_items_default_to_empty() {
+    let plan: ExecutionPlan =
+        serde_json::from_str(r#"{"plugin": "packages", "task": "export"}"#).unwrap();
+
+    assert_eq!(plan, ExecutionPlan::new("packages", "export", vec![]));
+}
+
+// ============================================================================
+// File Tests
+// ============================================================================
+
+#[test]
+fn test_plan_save_and_load() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("plans").join("plan.json");
+    let plan = ExecutionPlan::new("packages", "info", vec!["git".to_string()]);
+
+    plan.save(&path).unwrap();
+
+    assert_eq!(ExecutionPlan::load(&path).unwrap(), plan);
+}
+
+#[test]
+fn test_plan_load_reports_invalid_json() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("plan.json");
+    std::fs::write(&path, r#"{"plugin": "packages"}"#).unwrap();
+
+    let error = ExecutionPlan::load(&path).unwrap_err();
+
+    assert!(format!("{:#}", error).contains("Failed to parse plan file"));
+}
+
+#[test]
+fn test_plan_load_reports_missing_file() {
+    let dir = TempDir::new().unwrap();
+
+    let error = ExecutionPlan::load(&dir.path().join("missing.json")).unwrap_err();
+
+    assert!(format!("{:#}", error).contains("Failed to read plan file"));
+}
@@ -73,6 +73,8 @@ fn test_plugin_declaration_valid_with_tag() {
         git: "https://github.com/user/repo".to_string(),
         tag: Some("v1.0.0".to_string()),
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     assert!(decl.validate().is_ok());
 }
@@ -83,6 +85,8 @@ fn test_plugin_declaration_valid_with_commit() {
         git: "https://github.com/user/repo".to_string(),
         tag: None,
         commit: Some("abc123def456".to_string()),
+        branch: None,
+        sync_interval: None,
     };
     assert!(decl.validate().is_ok());
 }
@@ -93,6 +97,8 @@ fn test_plugin_declaration_valid_git_ssh_format() {
         git: "git@github.com:user/repo".to_string(),
         tag: Some("v1.0.0".to_string()),
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     assert!(decl.validate().is_ok());
 }
@@ -103,6 +109,8 @@ fn test_plugin_declaration_empty_git_url_fails() {
         git: "".to_string(),
         tag: Some("v1.0.0".to_string()),
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     let result = decl.validate();
     assert!(result.is_err());
@@ -120,6 +128,8 @@ fn test_plugin_declaration_invalid_git_url_format() {
         git: "http://github.com/user/repo".to_string(), // http:// not allowed
         tag: Some("v1.0.0".to_string()),
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     let result = decl.validate();
     assert!(result.is_err());
@@ -137,6 +147,8 @@ fn test_plugin_declaration_both_tag_and_commit_fails() {
         git: "https://github.com/user/repo".to_string(),
         tag: Some("v1.0.0".to_string()),
         commit: Some("abc123".to_string()),
+        branch: None,
+        sync_interval: None,
     };
     let result = decl.validate();
     assert!(result.is_err());
@@ -154,6 +166,8 @@ fn test_plugin_declaration_neither_tag_nor_commit_fails() {
         git: "https://github.com/user/repo".to_string(),
         tag: None,
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     let result = decl.validate();
     assert!(result.is_err());
@@ -171,6 +185,8 @@ fn test_plugin_declaration_file_path_fails() {
         git: "/local/path/to/repo".to_string(),
         tag: Some("v1.0.0".to_string()),
         commit: None,
+        branch: None,
+        sync_interval: None,
     };
     let result = decl.validate();
     assert!(result.is_err());
@@ -181,3 +197,52 @@ fn test_plugin_declaration_file_path_fails() {
             .contains("Invalid git URL format")
     );
 }
+
+#[test]
+fn test_plugin_declaration_valid_with_branch() {
+    let decl = PluginDeclaration {
+        git: "https://github.com/user/repo".to_string(),
+        tag: None,
+        commit: None,
+        branch: Some("main".to_string()),
+        sync_interval: Some(86400),
+    };
+    assert!(decl.validate().is_ok());
+    assert_eq!(decl.ref_spec(), Some("main"));
+}
+
+#[test]
+fn test_plugin_declaration_branch_and_tag_fails() {
+    let decl = PluginDeclaration {
+        git: "https://github.com/user/repo".to_string(),
+        tag: Some("v1.0.0".to_string()),
+        commit: None,
+        branch: Some("main".to_string()),
+        sync_interval: None,
+    };
+    let result = decl.validate();
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("must not declare a branch together with a tag or commit")
+    );
+}
+
+#[test]
+fn test_plugin_declaration_sync_interval_requires_branch() {
+    let decl = PluginDeclaration {
+        git: "https://github.com/user/repo".to_string(),
+        tag: Some("v1.0.0".to_string()),
+        commit: None,
+        branch: None,
+        sync_interval: Some(3600),
+    };
+    let result = decl.validate();
+    assert!(
+        result
+            .unwrap_err()
+            .to_string()
+            .contains("sync_interval requires a branch")
+    );
+}
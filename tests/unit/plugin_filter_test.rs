@@ -0,0 +1,93 @@
+//! Unit tests for the plugin list filter
+//!
+//! Tests the filter cycle, the platform predicate with the platform passed in, and the
+//! name and description text match.
+
+use syntropy::{
+    plugins::Metadata,
+    tui::plugin_filter::{PluginFilter, matches_text},
+};
+
+fn metadata(name: &str, description: &str, platforms: &[&str]) -> Metadata {
+    Metadata {
+        name: name.to_string(),
+        description: description.to_string(),
+        platforms: platforms.iter().map(|p| p.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+// ============================================================================
+// Cycle Tests
+// ============================================================================
+
+#[test]
+fn test_default_filter_shows_all_plugins() {
+    assert_eq!(PluginFilter::default(), PluginFilter::All);
+}
+
+#[test]
+fn test_filter_cycles_between_all_and_current_platform() {
+    assert_eq!(PluginFilter::All.next(), PluginFilter::CurrentPlatform);
+    assert_eq!(PluginFilter::CurrentPlatform.next(), PluginFilter::All);
+}
+
+// ============================================================================
+// Platform Tests
+// ============================================================================
+
+#[test]
+fn test_current_platform_filter_hides_windows_only_plugin() {
+    let plugin = metadata("winget", "", &["windows"]);
+
+    assert!(!PluginFilter::CurrentPlatform.matches(&plugin, "linux"));
+    assert!(!PluginFilter::CurrentPlatform.matches(&plugin, "macos"));
+    assert!(PluginFilter::CurrentPlatform.matches(&plugin, "windows"));
+}
+
+#[test]
+fn test_current_platform_filter_shows_plugin_declaring_several_platforms() {
+    let plugin = metadata("brew", "", &["macos", "linux"]);
+
+    assert!(PluginFilter::CurrentPlatform.matches(&plugin, "linux"));
+    assert!(PluginFilter::CurrentPlatform.matches(&plugin, "macos"));
+}
+
+#[test]
+fn test_current_platform_filter_hides_plugin_without_platforms() {
+    let plugin = metadata("notes", "", &[]);
+
+    assert!(!PluginFilter::CurrentPlatform.matches(&plugin, "linux"));
+}
+
+#[test]
+fn test_all_filter_shows_every_plugin() {
+    assert!(PluginFilter::All.matches(&metadata("winget", "", &["windows"]), "linux"));
+    assert!(PluginFilter::All.matches(&metadata("notes", "", &[]), "linux"));
+}
+
+// ============================================================================
+// Text Tests
+// ============================================================================
+
+#[test]
+fn test_text_matches_description_ignoring_case() {
+    let plugin = metadata("pkgs", "Manage Homebrew packages", &[]);
+
+    assert!(matches_text(&plugin, "homebrew", false));
+    assert!(matches_text(&plugin, "PKGS", false));
+    assert!(!matches_text(&plugin, "docker", false));
+}
+
+#[test]
+fn test_case_sensitive_text_match() {
+    let plugin = metadata("pkgs", "Manage Homebrew packages", &[]);
+
+    assert!(matches_text(&plugin, "Homebrew", true));
+    assert!(!matches_text(&plugin, "homebrew", true));
+}
+
+#[test]
+fn test_empty_text_matches_every_plugin() {
+    assert!(matches_text(&metadata("pkgs", "", &[]), "", false));
+}
@@ -0,0 +1,109 @@
+//! Unit tests for the item list preview cache
+//!
+//! Tests least recently used eviction, recency updates and the hit/miss stats.
+
+use syntropy::tui::preview_cache::PreviewCache;
+
+fn cache_with(capacity: usize, items: &[&str]) -> PreviewCache {
+    let mut cache = PreviewCache::new(capacity);
+    for item in items {
+        cache.insert(item.to_string(), format!("preview of {}", item));
+    }
+    cache
+}
+
+// ============================================================================
+// Eviction Tests
+// ============================================================================
+
+#[test]
+fn test_least_recently_inserted_is_evicted() {
+    let cache = cache_with(2, &["a", "b", "c"]);
+
+    assert!(!cache.contains("a"));
+    assert!(cache.contains("b"));
+    assert!(cache.contains("c"));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_get_protects_from_eviction() {
+    let mut cache = cache_with(2, &["a", "b"]);
+
+    cache.get("a");
+    cache.insert("c".to_string(), "preview of c".to_string());
+
+    assert!(cache.contains("a"));
+    assert!(!cache.contains("b"));
+}
+
+#[test]
+fn test_peek_does_not_protect_from_eviction() {
+    let mut cache = cache_with(2, &["a", "b"]);
+
+    assert_eq!(cache.peek("a").map(String::as_str), Some("preview of a"));
+    cache.insert("c".to_string(), "preview of c".to_string());
+
+    assert!(!cache.contains("a"));
+}
+
+#[test]
+fn test_reinserting_replaces_without_evicting() {
+    let mut cache = cache_with(2, &["a", "b"]);
+
+    cache.insert("a".to_string(), "new preview".to_string());
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.peek("a").map(String::as_str), Some("new preview"));
+    assert!(cache.contains("b"));
+}
+
+#[test]
+fn test_zero_capacity_keeps_one_preview() {
+    let cache = cache_with(0, &["a", "b"]);
+
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains("b"));
+}
+
+// ============================================================================
+// Invalidation Tests
+// ============================================================================
+
+#[test]
+fn test_retain_only_keeps_focused_preview() {
+    let mut cache = cache_with(5, &["a", "b", "c"]);
+
+    cache.retain_only("b");
+
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains("b"));
+}
+
+#[test]
+fn test_clear_resets_stats() {
+    let mut cache = cache_with(5, &["a"]);
+    cache.get("a");
+    cache.get("missing");
+
+    cache.clear();
+
+    assert!(cache.is_empty());
+    assert_eq!(cache.stats(), (0, 0));
+}
+
+// ============================================================================
+// Stats Tests
+// ============================================================================
+
+#[test]
+fn test_get_counts_hits_and_misses() {
+    let mut cache = cache_with(5, &["a", "b"]);
+
+    cache.get("a");
+    cache.get("b");
+    cache.get("missing");
+    cache.peek("a");
+
+    assert_eq!(cache.stats(), (2, 1));
+}
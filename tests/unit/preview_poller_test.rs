@@ -0,0 +1,83 @@
+//! Unit tests for preview polling
+//!
+//! Tests the refresh schedule of PreviewPoller with explicit instants, no terminal needed.
+
+use std::time::{Duration, Instant};
+
+use syntropy::tui::preview_poller::PreviewPoller;
+
+const INTERVAL: Duration = Duration::from_millis(500);
+
+fn started_poller() -> (PreviewPoller, Instant) {
+    let mut poller = PreviewPoller::default();
+    poller.start(INTERVAL);
+    let start = Instant::now();
+    assert!(!poller.poll("git", start, false));
+    (poller, start)
+}
+
+// ============================================================================
+// Scheduling Tests
+// ============================================================================
+
+#[test]
+fn test_zero_interval_never_refreshes() {
+    let mut poller = PreviewPoller::default();
+    poller.start(Duration::ZERO);
+    let start = Instant::now();
+
+    assert!(!poller.poll("git", start, false));
+    assert!(!poller.poll("git", start + Duration::from_secs(60), false));
+}
+
+#[test]
+fn test_refreshes_once_per_interval() {
+    let (mut poller, start) = started_poller();
+
+    assert!(!poller.poll("git", start + Duration::from_millis(499), false));
+    assert!(poller.poll("git", start + INTERVAL, false));
+    assert!(!poller.poll("git", start + Duration::from_millis(700), false));
+    assert!(poller.poll("git", start + INTERVAL * 2, false));
+}
+
+#[test]
+fn test_focus_change_restarts_timer() {
+    let (mut poller, start) = started_poller();
+
+    assert!(!poller.poll("npm", start + Duration::from_millis(400), false));
+    assert!(!poller.poll("npm", start + INTERVAL, false));
+    assert!(poller.poll("npm", start + Duration::from_millis(900), false));
+}
+
+#[test]
+fn test_reset_stops_polling() {
+    let (mut poller, start) = started_poller();
+
+    poller.reset();
+
+    assert!(!poller.poll("git", start + INTERVAL, false));
+    assert!(!poller.poll("git", start + INTERVAL * 4, false));
+}
+
+// ============================================================================
+// Coalescing Tests
+// ============================================================================
+
+#[test]
+fn test_ticks_during_slow_preview_are_held_back() {
+    let (mut poller, start) = started_poller();
+
+    assert!(!poller.poll("git", start + INTERVAL, true));
+    assert!(!poller.poll("git", start + INTERVAL * 3, true));
+}
+
+#[test]
+fn test_missed_ticks_coalesce_into_one_refresh() {
+    let (mut poller, start) = started_poller();
+    assert!(!poller.poll("git", start + INTERVAL * 3, true));
+
+    let finished = start + INTERVAL * 3 + Duration::from_millis(10);
+    assert!(poller.poll("git", finished, false));
+    assert!(!poller.poll("git", finished + Duration::from_millis(10), false));
+    assert!(poller.poll("git", finished + INTERVAL, false));
+}
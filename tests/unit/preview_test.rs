@@ -0,0 +1,102 @@
+//! Unit tests for measuring the preview
+//!
+//! Tests how content wraps at a given width and how far the preview pans while it
+//! doesn't wrap lines.
+
+use syntropy::tui::views::{PreviewWrap, clamp_horizontal_offset, wrap_lines, wrapped_line_count};
+
+// ============================================================================
+// Wrapping Tests
+// ============================================================================
+
+#[test]
+fn test_short_lines_take_one_row_each() {
+    assert_eq!(wrapped_line_count("one\ntwo\nthree", 10), 3);
+}
+
+#[test]
+fn test_empty_content_has_no_rows() {
+    assert_eq!(wrapped_line_count("", 10), 0);
+}
+
+#[test]
+fn test_empty_lines_are_kept() {
+    assert_eq!(wrap_lines("a\n\nb", 10), vec!["a", "", "b"]);
+}
+
+#[test]
+fn test_long_line_wraps_between_words() {
+    assert_eq!(
+        wrap_lines("the quick brown fox", 10),
+        vec!["the quick", "brown fox"]
+    );
+    assert_eq!(wrapped_line_count("the quick brown fox", 10), 2);
+}
+
+#[test]
+fn test_row_fills_the_width_exactly() {
+    assert_eq!(wrap_lines("abcde fghij", 5), vec!["abcde", "fghij"]);
+}
+
+#[test]
+fn test_word_wider_than_the_width_is_split() {
+    assert_eq!(wrap_lines("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+}
+
+#[test]
+fn test_wide_characters_count_two_columns() {
+    assert_eq!(
+        wrap_lines("日本語テキスト", 6),
+        vec!["日本語", "テキス", "ト"]
+    );
+}
+
+#[test]
+fn test_wrapped_count_grows_as_the_width_shrinks() {
+    let content = "a line that is long enough to wrap\nshort";
+
+    assert_eq!(wrapped_line_count(content, 80), 2);
+    assert_eq!(wrapped_line_count(content, 20), 3);
+    assert_eq!(wrapped_line_count(content, 10), 6);
+}
+
+#[test]
+fn test_zero_width_wraps_every_character() {
+    assert_eq!(wrapped_line_count("abc", 0), 3);
+}
+
+// ============================================================================
+// Horizontal Offset Tests
+// ============================================================================
+
+#[test]
+fn test_offset_within_the_widest_line_is_kept() {
+    let content = "short\na line of twenty chr";
+
+    assert_eq!(clamp_horizontal_offset(5, content, 10), 5);
+}
+
+#[test]
+fn test_offset_stops_where_the_widest_line_ends() {
+    let content = "short\na line of twenty chr";
+
+    assert_eq!(clamp_horizontal_offset(50, content, 10), 10);
+}
+
+#[test]
+fn test_content_fitting_the_width_does_not_pan() {
+    assert_eq!(clamp_horizontal_offset(8, "fits\neasily", 20), 0);
+    assert_eq!(clamp_horizontal_offset(8, "", 20), 0);
+}
+
+// ============================================================================
+// Mode Tests
+// ============================================================================
+
+#[test]
+fn test_wrap_mode_toggles() {
+    assert_eq!(PreviewWrap::default(), PreviewWrap::Scroll);
+    assert_eq!(PreviewWrap::Scroll.toggle(), PreviewWrap::Wrap);
+    assert_eq!(PreviewWrap::Wrap.toggle(), PreviewWrap::Scroll);
+    assert_eq!(PreviewWrap::Wrap.to_string(), "wrap");
+}
@@ -0,0 +1,217 @@
+//! Unit tests for the search bar history
+//!
+//! Tests adding queries with deduplication and capacity, walking the history with
+//! older/newer, saving and loading it, and recalling queries into the search bar.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use syntropy::tui::{search_history::SearchHistory, views::SearchBar};
+use tempfile::TempDir;
+
+fn history_with(queries: &[&str]) -> SearchHistory {
+    let mut history = SearchHistory::new(10);
+    for query in queries {
+        history.push(query);
+    }
+    history
+}
+
+fn entries(history: &SearchHistory) -> Vec<&str> {
+    history.entries().collect()
+}
+
+fn type_text(search_bar: &mut SearchBar, text: &str) {
+    for c in text.chars() {
+        search_bar.handle_event(&Event::Key(KeyEvent::new(
+            KeyCode::Char(c),
+            KeyModifiers::NONE,
+        )));
+    }
+}
+
+// ============================================================================
+// Push Tests
+// ============================================================================
+
+#[test]
+fn test_push_appends_newest_last() {
+    let history = history_with(&["git", "cargo"]);
+
+    assert_eq!(entries(&history), vec!["git", "cargo"]);
+}
+
+#[test]
+fn test_push_collapses_consecutive_duplicates() {
+    let mut history = history_with(&["git"]);
+
+    assert!(!history.push("git"));
+    assert!(!history.push("  git "));
+    assert!(history.push("cargo"));
+    assert!(history.push("git"));
+
+    assert_eq!(entries(&history), vec!["git", "cargo", "git"]);
+}
+
+#[test]
+fn test_push_ignores_blank_queries() {
+    let mut history = SearchHistory::new(10);
+
+    assert!(!history.push(""));
+    assert!(!history.push("   "));
+    assert!(history.is_empty());
+}
+
+#[test]
+fn test_push_drops_oldest_beyond_capacity() {
+    let mut history = SearchHistory::new(2);
+    history.push("a");
+    history.push("b");
+    history.push("c");
+
+    assert_eq!(entries(&history), vec!["b", "c"]);
+}
+
+// ============================================================================
+// Navigation Tests
+// ============================================================================
+
+#[test]
+fn test_older_walks_from_newest_and_stops_at_oldest() {
+    let mut history = history_with(&["a", "b", "c"]);
+
+    assert_eq!(history.older(""), Some("c"));
+    assert_eq!(history.older("c"), Some("b"));
+    assert_eq!(history.older("b"), Some("a"));
+    assert_eq!(history.older("a"), Some("a"));
+}
+
+#[test]
+fn test_newer_walks_back_to_the_draft() {
+    let mut history = history_with(&["a", "b"]);
+
+    assert_eq!(history.older("typed"), Some("b"));
+    assert_eq!(history.older("b"), Some("a"));
+    assert_eq!(history.newer(), Some("b"));
+    assert_eq!(history.newer(), Some("typed"));
+    assert_eq!(history.newer(), None);
+}
+
+#[test]
+fn test_newer_without_walking_returns_none() {
+    let mut history = history_with(&["a"]);
+
+    assert_eq!(history.newer(), None);
+}
+
+#[test]
+fn test_older_on_empty_history_returns_none() {
+    let mut history = SearchHistory::new(10);
+
+    assert_eq!(history.older("typed"), None);
+    assert_eq!(history.newer(), None);
+}
+
+#[test]
+fn test_push_restarts_walk_at_newest() {
+    let mut history = history_with(&["a", "b"]);
+    history.older("");
+    history.older("b");
+
+    history.push("c");
+
+    assert_eq!(history.older(""), Some("c"));
+}
+
+// ============================================================================
+// Persistence Tests
+// ============================================================================
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("nested").join("search_history");
+    history_with(&["git", "cargo"]).save(&path).unwrap();
+
+    let loaded = SearchHistory::load(&path, 10).unwrap();
+
+    assert_eq!(entries(&loaded), vec!["git", "cargo"]);
+}
+
+#[test]
+fn test_load_missing_file_is_empty() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let loaded = SearchHistory::load(&temp_dir.path().join("missing"), 10).unwrap();
+
+    assert!(loaded.is_empty());
+}
+
+#[test]
+fn test_load_keeps_newest_within_capacity() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("search_history");
+    std::fs::write(&path, "a\nb\n\nc\n").unwrap();
+
+    let loaded = SearchHistory::load(&path, 2).unwrap();
+
+    assert_eq!(entries(&loaded), vec!["b", "c"]);
+}
+
+// ============================================================================
+// Search Bar Tests
+// ============================================================================
+
+#[test]
+fn test_search_bar_records_query_on_clear() {
+    let mut search_bar = SearchBar::default();
+    type_text(&mut search_bar, "git");
+
+    search_bar.clear();
+
+    assert!(search_bar.is_empty());
+    assert_eq!(entries(search_bar.history()), vec!["git"]);
+}
+
+#[test]
+fn test_search_bar_recalls_and_restores_typed_query() {
+    let mut search_bar = SearchBar::default();
+    type_text(&mut search_bar, "git");
+    search_bar.clear();
+    type_text(&mut search_bar, "car");
+
+    assert!(search_bar.recall_previous());
+    assert_eq!(search_bar.value(), "git");
+    assert!(search_bar.recall_next());
+    assert_eq!(search_bar.value(), "car");
+    assert!(!search_bar.recall_next());
+}
+
+#[test]
+fn test_search_bar_typing_after_recall_starts_new_walk() {
+    let mut search_bar = SearchBar::default();
+    for query in ["a", "b"] {
+        type_text(&mut search_bar, query);
+        search_bar.clear();
+    }
+
+    search_bar.recall_previous();
+    search_bar.recall_previous();
+    assert_eq!(search_bar.value(), "a");
+    type_text(&mut search_bar, "x");
+
+    assert!(search_bar.recall_previous());
+    assert_eq!(search_bar.value(), "b");
+    assert!(search_bar.recall_next());
+    assert_eq!(search_bar.value(), "ax");
+}
+
+#[test]
+fn test_search_bar_saves_history_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("search_history");
+    let mut search_bar = SearchBar::with_history(SearchHistory::new(10), Some(path.clone()));
+    type_text(&mut search_bar, "git");
+
+    search_bar.clear();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "git\n");
+}
@@ -0,0 +1,167 @@
+//! Unit tests for list cursor movement
+//!
+//! Tests next_index for single steps and page moves, with and without wrap-around, and
+//! the two-line items of lists with subtitles.
+
+use ratatui::{Terminal, backend::TestBackend, layout::Rect};
+use syntropy::{
+    configs::Styles,
+    tui::views::{ListMovement, SelectableList, next_index, style},
+};
+
+// ============================================================================
+// Without Wrap
+// ============================================================================
+
+#[test]
+fn test_step_moves_within_list() {
+    assert_eq!(next_index(2, 5, ListMovement::Down(1), false), 3);
+    assert_eq!(next_index(2, 5, ListMovement::Up(1), false), 1);
+}
+
+#[test]
+fn test_down_stops_at_last_item_without_wrap() {
+    assert_eq!(next_index(4, 5, ListMovement::Down(1), false), 4);
+}
+
+#[test]
+fn test_up_stops_at_first_item_without_wrap() {
+    assert_eq!(next_index(0, 5, ListMovement::Up(1), false), 0);
+}
+
+#[test]
+fn test_page_moves_clamp_to_ends() {
+    assert_eq!(next_index(3, 10, ListMovement::Down(4), false), 7);
+    assert_eq!(next_index(8, 10, ListMovement::Down(4), false), 9);
+    assert_eq!(next_index(2, 10, ListMovement::Up(4), false), 0);
+}
+
+// ============================================================================
+// With Wrap
+// ============================================================================
+
+#[test]
+fn test_down_from_last_item_wraps_to_first() {
+    assert_eq!(next_index(4, 5, ListMovement::Down(1), true), 0);
+}
+
+#[test]
+fn test_up_from_first_item_wraps_to_last() {
+    assert_eq!(next_index(0, 5, ListMovement::Up(1), true), 4);
+}
+
+#[test]
+fn test_wrap_leaves_moves_inside_the_list_unchanged() {
+    assert_eq!(next_index(2, 5, ListMovement::Down(1), true), 3);
+    assert_eq!(next_index(2, 5, ListMovement::Up(1), true), 1);
+}
+
+#[test]
+fn test_page_move_stops_at_end_before_wrapping() {
+    assert_eq!(next_index(8, 10, ListMovement::Down(4), true), 9);
+    assert_eq!(next_index(9, 10, ListMovement::Down(4), true), 0);
+    assert_eq!(next_index(2, 10, ListMovement::Up(4), true), 0);
+    assert_eq!(next_index(0, 10, ListMovement::Up(4), true), 9);
+}
+
+// ============================================================================
+// Edge Cases
+// ============================================================================
+
+#[test]
+fn test_empty_list_stays_at_zero() {
+    for wrap in [false, true] {
+        assert_eq!(next_index(0, 0, ListMovement::Down(1), wrap), 0);
+        assert_eq!(next_index(0, 0, ListMovement::Up(1), wrap), 0);
+    }
+}
+
+#[test]
+fn test_single_item_list_stays_on_it() {
+    for wrap in [false, true] {
+        assert_eq!(next_index(0, 1, ListMovement::Down(1), wrap), 0);
+        assert_eq!(next_index(0, 1, ListMovement::Up(1), wrap), 0);
+    }
+}
+
+#[test]
+fn test_index_past_shrunk_list_is_clamped() {
+    assert_eq!(next_index(7, 3, ListMovement::Up(1), false), 1);
+    assert_eq!(next_index(7, 3, ListMovement::Down(1), true), 0);
+}
+
+// ============================================================================
+// Subtitles
+// ============================================================================
+
+// Renders `items` borderless into a 20x6 terminal, returning its rows
+fn render_rows(list: &mut SelectableList, items: &[&str]) -> Vec<String> {
+    let styles: Styles = toml::from_str("[list]\nborders = []").unwrap();
+    let styles = style::Styles::try_from(&styles).unwrap();
+    let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+    let items: Vec<&String> = items.iter().collect();
+
+    let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+    terminal
+        .draw(|frame| {
+            list.render(
+                frame,
+                Rect::new(0, 0, 20, 6),
+                &items,
+                &[],
+                &styles.list,
+                &styles.colors,
+                None,
+            )
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..6)
+        .map(|y| {
+            (0..20)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_items_without_subtitles_take_one_line() {
+    let mut list = SelectableList::new(false);
+
+    let rows = render_rows(&mut list, &["build", "deploy"]);
+
+    assert!(rows[0].ends_with("build"));
+    assert!(rows[1].ends_with("deploy"));
+    assert_eq!(list.page_height(), 6);
+    assert_eq!(list.item_at(0, 1), Some(1));
+}
+
+#[test]
+fn test_subtitles_render_under_their_items() {
+    let mut list = SelectableList::new(false);
+    list.set_subtitles(vec!["Compiles it".to_string(), String::new()]);
+
+    let rows = render_rows(&mut list, &["build", "deploy"]);
+
+    assert!(rows[0].ends_with("build"));
+    assert!(rows[1].ends_with("Compiles it"));
+    assert!(rows[2].ends_with("deploy"));
+    assert_eq!(rows[3], "");
+}
+
+#[test]
+fn test_subtitles_count_two_lines_per_item() {
+    let mut list = SelectableList::new(false);
+    list.set_subtitles(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+
+    render_rows(&mut list, &["one", "two", "three"]);
+
+    assert_eq!(list.page_height(), 3);
+    assert_eq!(list.item_at(0, 1), Some(0));
+    assert_eq!(list.item_at(0, 2), Some(1));
+    assert_eq!(list.item_at(0, 5), Some(2));
+}
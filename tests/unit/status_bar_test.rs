@@ -0,0 +1,63 @@
+//! Unit tests for the TUI status bar
+//!
+//! Tests the messages plugins log with syntropy.log(), shown instead of the breadcrumbs.
+
+use std::time::Instant;
+
+use syntropy::tui::PluginLogLevel;
+use syntropy::tui::views::{STATUS_LOG_DURATION, StatusBar};
+
+// ============================================================================
+// Log Message Tests
+// ============================================================================
+
+#[test]
+fn test_status_bar_starts_without_log() {
+    assert!(StatusBar::default().log().is_none());
+}
+
+#[test]
+fn test_latest_log_replaces_previous() {
+    let mut status_bar = StatusBar::default();
+
+    status_bar.show_log(PluginLogLevel::Info, "first".to_string());
+    status_bar.show_log(PluginLogLevel::Error, "second".to_string());
+
+    let log = status_bar.log().unwrap();
+    assert_eq!(log.level, PluginLogLevel::Error);
+    assert_eq!(log.message, "second");
+}
+
+#[test]
+fn test_log_line_has_level_prefix_on_one_line() {
+    let mut status_bar = StatusBar::default();
+
+    status_bar.show_log(PluginLogLevel::Warn, "disk\nalmost   full\n".to_string());
+
+    assert_eq!(status_bar.log().unwrap().line(), "[warn] disk almost full");
+}
+
+#[test]
+fn test_log_expires_after_duration() {
+    let mut status_bar = StatusBar::default();
+    status_bar.show_log(PluginLogLevel::Info, "synced".to_string());
+
+    status_bar.expire_log(Instant::now());
+    assert!(status_bar.log().is_some());
+
+    status_bar.expire_log(Instant::now() + STATUS_LOG_DURATION);
+    assert!(status_bar.log().is_none());
+}
+
+// ============================================================================
+// Log Level Tests
+// ============================================================================
+
+#[test]
+fn test_log_level_names_round_trip() {
+    for level in PluginLogLevel::ALL {
+        assert_eq!(PluginLogLevel::from_name(&level.to_string()), Some(level));
+    }
+    assert_eq!(PluginLogLevel::from_name("debug"), None);
+    assert_eq!(PluginLogLevel::from_name("WARN"), None);
+}
@@ -0,0 +1,89 @@
+//! Unit tests for syntax highlighting of previews
+//!
+//! Tests that known syntaxes are found by name and extension, that highlighting keeps the
+//! text and colors it, and that the color mode is applied.
+
+use ratatui::{style::Color, text::Line};
+use syntropy::tui::{
+    color_mode::ColorMode,
+    views::syntax_highlight::{highlight, is_known_syntax},
+};
+
+const RUST_SOURCE: &str = "fn main() {\n    let answer = 42;\n}\n";
+
+fn plain_text(lines: &[Line]) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect()
+        })
+        .collect()
+}
+
+// ============================================================================
+// Syntax Lookup
+// ============================================================================
+
+#[test]
+fn test_syntax_found_by_name_or_extension() {
+    assert!(is_known_syntax("rust"));
+    assert!(is_known_syntax("Rust"));
+    assert!(is_known_syntax("rs"));
+    assert!(is_known_syntax("json"));
+    assert!(is_known_syntax("py"));
+}
+
+#[test]
+fn test_unknown_syntax_is_not_highlighted() {
+    assert!(!is_known_syntax("not-a-language"));
+    assert_eq!(
+        highlight(RUST_SOURCE, "not-a-language", ColorMode::TrueColor),
+        None
+    );
+}
+
+// ============================================================================
+// Highlighting
+// ============================================================================
+
+#[test]
+fn test_highlighting_keeps_the_text() {
+    let lines = highlight(RUST_SOURCE, "rust", ColorMode::TrueColor).unwrap();
+
+    assert_eq!(
+        plain_text(&lines),
+        ["fn main() {", "    let answer = 42;", "}"]
+    );
+}
+
+#[test]
+fn test_highlighting_colors_tokens() {
+    let lines = highlight(RUST_SOURCE, "rs", ColorMode::TrueColor).unwrap();
+
+    let colors: Vec<Option<Color>> = lines[0].spans.iter().map(|span| span.style.fg).collect();
+    assert!(
+        colors
+            .iter()
+            .all(|color| matches!(color, Some(Color::Rgb(..))))
+    );
+    assert!(
+        colors.windows(2).any(|pair| pair[0] != pair[1]),
+        "Expected several colors in {:?}",
+        colors
+    );
+}
+
+#[test]
+fn test_monochrome_drops_colors() {
+    let lines = highlight(RUST_SOURCE, "rust", ColorMode::Monochrome).unwrap();
+
+    assert!(
+        lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .all(|span| span.style.fg == Some(Color::Reset))
+    );
+}
@@ -0,0 +1,90 @@
+//! Unit tests for the TUI toast queue
+//!
+//! Tests ordering, dismissal and expiry of toast notifications.
+
+use std::time::Instant;
+
+use syntropy::tui::views::{TOAST_DURATION, ToastQueue};
+
+// ============================================================================
+// Queue Tests
+// ============================================================================
+
+#[test]
+fn test_empty_queue_has_no_current_toast() {
+    let toasts = ToastQueue::default();
+
+    assert!(toasts.is_empty());
+    assert!(toasts.current().is_none());
+}
+
+#[test]
+fn test_most_recent_toast_is_current() {
+    let mut toasts = ToastQueue::default();
+
+    toasts.push("first".to_string(), Some(0));
+    toasts.push("second".to_string(), None);
+
+    assert_eq!(toasts.len(), 2);
+    assert_eq!(toasts.current().unwrap().message, "second");
+}
+
+#[test]
+fn test_toast_title_includes_exit_code() {
+    let mut toasts = ToastQueue::default();
+
+    toasts.push("done".to_string(), Some(0));
+    assert_eq!(
+        toasts.current().unwrap().title(),
+        "Task result (exit code 0)"
+    );
+
+    toasts.push("started".to_string(), None);
+    assert_eq!(toasts.current().unwrap().title(), "Notification");
+}
+
+// ============================================================================
+// Dismiss Tests
+// ============================================================================
+
+#[test]
+fn test_dismiss_reveals_previous_toast() {
+    let mut toasts = ToastQueue::default();
+    toasts.push("first".to_string(), Some(0));
+    toasts.push("second".to_string(), Some(0));
+
+    assert!(toasts.dismiss());
+
+    assert_eq!(toasts.current().unwrap().message, "first");
+}
+
+#[test]
+fn test_dismiss_empty_queue() {
+    let mut toasts = ToastQueue::default();
+
+    assert!(!toasts.dismiss());
+}
+
+// ============================================================================
+// Expiry Tests
+// ============================================================================
+
+#[test]
+fn test_fresh_toast_is_not_expired() {
+    let mut toasts = ToastQueue::default();
+    toasts.push("fresh".to_string(), Some(0));
+
+    toasts.expire(Instant::now());
+
+    assert_eq!(toasts.len(), 1);
+}
+
+#[test]
+fn test_toast_expires_after_duration() {
+    let mut toasts = ToastQueue::default();
+    toasts.push("stale".to_string(), Some(0));
+
+    toasts.expire(Instant::now() + TOAST_DURATION);
+
+    assert!(toasts.is_empty());
+}
@@ -0,0 +1,70 @@
+//! Unit tests for the file watch debouncer
+//!
+//! Tests that bursts of `.lua` changes collapse into a single trigger.
+
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use syntropy::cli::watch::Debouncer;
+
+const DELAY: Duration = Duration::from_millis(200);
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+// ============================================================================
+// Debounce Tests
+// ============================================================================
+
+#[test]
+fn test_burst_of_changes_triggers_once() {
+    let mut debouncer = Debouncer::new(DELAY);
+    let start = Instant::now();
+    let plugin = [PathBuf::from("plugins/notes/plugin.lua")];
+
+    assert!(debouncer.record(&plugin, start));
+    assert!(debouncer.record(&plugin, start + ms(10)));
+    assert!(debouncer.record(&plugin, start + ms(50)));
+
+    // Still within the quiet period of the last change
+    assert!(!debouncer.take_ready(start + ms(200)));
+    assert_eq!(debouncer.remaining(start + ms(200)), Some(ms(50)));
+
+    assert!(debouncer.take_ready(start + ms(250)));
+    assert!(!debouncer.take_ready(start + ms(500)));
+    assert_eq!(debouncer.remaining(start + ms(500)), None);
+}
+
+#[test]
+fn test_non_lua_changes_are_ignored() {
+    let mut debouncer = Debouncer::new(DELAY);
+    let start = Instant::now();
+
+    assert!(!debouncer.record(
+        &[
+            PathBuf::from("plugins/notes/README.md"),
+            PathBuf::from("plugins/notes/.plugin.lua.swp"),
+        ],
+        start
+    ));
+
+    assert_eq!(debouncer.remaining(start), None);
+    assert!(!debouncer.take_ready(start + ms(1000)));
+}
+
+#[test]
+fn test_change_after_trigger_starts_new_burst() {
+    let mut debouncer = Debouncer::new(DELAY);
+    let start = Instant::now();
+    let module = [PathBuf::from("plugins/notes/lib/util.lua")];
+
+    debouncer.record(&module, start);
+    assert!(debouncer.take_ready(start + ms(200)));
+
+    debouncer.record(&module, start + ms(300));
+    assert!(!debouncer.take_ready(start + ms(400)));
+    assert!(debouncer.take_ready(start + ms(500)));
+}